@@ -0,0 +1,199 @@
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use anyhow::{Context, Result};
+use chrono::TimeZone;
+use serde::{Deserialize, Serialize};
+
+/// Who changes data in this process; set once at startup by the app or CLI.
+static ORIGIN: AtomicU8 = AtomicU8::new(ActivityOrigin::Uniffi as u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    Create,
+    Update,
+    Delete,
+    Import,
+    Export,
+}
+
+impl ActivityKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ActivityKind::Create => "create",
+            ActivityKind::Update => "update",
+            ActivityKind::Delete => "delete",
+            ActivityKind::Import => "import",
+            ActivityKind::Export => "export",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum ActivityOrigin {
+    /// The macOS app.
+    Gui,
+    /// Any other caller of the Swift bindings.
+    Uniffi,
+    /// The `alfred_alt` command line.
+    Cli,
+    /// Changes picked up from the Obsidian vault mirror.
+    Sync,
+}
+
+impl ActivityOrigin {
+    const ALL: [ActivityOrigin; 4] = [
+        ActivityOrigin::Gui,
+        ActivityOrigin::Uniffi,
+        ActivityOrigin::Cli,
+        ActivityOrigin::Sync,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ActivityOrigin::Gui => "gui",
+            ActivityOrigin::Uniffi => "uniffi",
+            ActivityOrigin::Cli => "cli",
+            ActivityOrigin::Sync => "sync",
+        }
+    }
+}
+
+pub fn set_origin(origin: ActivityOrigin) {
+    ORIGIN.store(origin as u8, Ordering::Relaxed);
+}
+
+pub fn current_origin() -> ActivityOrigin {
+    let raw = ORIGIN.load(Ordering::Relaxed);
+    ActivityOrigin::ALL
+        .into_iter()
+        .find(|origin| *origin as u8 == raw)
+        .unwrap_or(ActivityOrigin::Uniffi)
+}
+
+/// One line of the activity log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    pub at_unix_seconds: u64,
+    pub kind: ActivityKind,
+    pub origin: ActivityOrigin,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// What an import or export covered, or how an item changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// The log is append-only: entries are never rewritten or dropped.
+pub fn append_entry(path: &Path, entry: &ActivityEntry) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create activity log dir {}", dir.display()))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open activity log {}", path.display()))?;
+    let line = serde_json::to_string(entry).context("failed to serialize activity entry")?;
+    writeln!(file, "{line}")
+        .with_context(|| format!("failed to append activity log {}", path.display()))
+}
+
+/// The newest `limit` entries, newest first; unreadable lines are skipped.
+pub fn read_recent(path: &Path, limit: usize) -> Result<Vec<ActivityEntry>> {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(err)
+                .with_context(|| format!("failed to open activity log {}", path.display()));
+        }
+    };
+    let mut entries: Vec<ActivityEntry> = BufReader::new(file)
+        .lines()
+        .map_while(std::io::Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect();
+    entries.reverse();
+    entries.truncate(limit);
+    Ok(entries)
+}
+
+/// One line for the `activity` command, like
+/// `2024-01-03 09:30  update  gui   #7 "Groceries" (renamed)`.
+pub fn describe<Tz: TimeZone>(entry: &ActivityEntry, tz: &Tz) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    let at = i64::try_from(entry.at_unix_seconds)
+        .ok()
+        .and_then(|seconds| tz.timestamp_opt(seconds, 0).single())
+        .map(|at| at.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "????-??-?? ??:??".to_string());
+    let mut line = format!(
+        "{at}  {:<6}  {:<6}",
+        entry.kind.as_str(),
+        entry.origin.as_str()
+    );
+    if let Some(id) = entry.item_id {
+        line.push_str(&format!(" #{id}"));
+    }
+    if let Some(title) = &entry.title {
+        line.push_str(&format!(" {title:?}"));
+    }
+    if let Some(detail) = &entry.detail {
+        line.push_str(&format!(" ({detail})"));
+    }
+    line.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActivityEntry, ActivityKind, ActivityOrigin, describe};
+    use chrono::Utc;
+
+    #[test]
+    fn describe_lists_time_kind_origin_and_what_changed() {
+        // 2024-01-03 09:30 UTC.
+        let at = 1_704_274_200;
+        let renamed = ActivityEntry {
+            at_unix_seconds: at,
+            kind: ActivityKind::Update,
+            origin: ActivityOrigin::Gui,
+            item_id: Some(7),
+            title: Some("Groceries".to_string()),
+            detail: Some("renamed".to_string()),
+        };
+        assert_eq!(
+            describe(&renamed, &Utc),
+            "2024-01-03 09:30  update  gui    #7 \"Groceries\" (renamed)"
+        );
+
+        let export = ActivityEntry {
+            at_unix_seconds: at,
+            kind: ActivityKind::Export,
+            origin: ActivityOrigin::Cli,
+            item_id: None,
+            title: None,
+            detail: Some("3 items as CSV".to_string()),
+        };
+        assert_eq!(
+            describe(&export, &Utc),
+            "2024-01-03 09:30  export  cli    (3 items as CSV)"
+        );
+
+        let line = serde_json::to_string(&export).unwrap();
+        assert!(!line.contains("item_id"));
+        assert_eq!(
+            serde_json::from_str::<ActivityEntry>(&line).unwrap(),
+            export
+        );
+    }
+}
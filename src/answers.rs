@@ -0,0 +1,108 @@
+/// Opening fence of an answer block, followed by optional keywords.
+pub const ANSWER_FENCE: &str = "::: answer";
+const CLOSING_FENCE: &str = ":::";
+
+/// A part of a note shown directly in launcher results, like an Alfred snippet.
+///
+/// Written in the note as
+///
+/// ```text
+/// ::: answer wifi password
+/// correct-horse-battery
+/// :::
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerBlock {
+    /// Words the block is found by; empty means the note title is used.
+    pub keywords: String,
+    pub content: String,
+}
+
+/// Every answer block in `note`. A block left open runs to the end of the note;
+/// blocks without content are skipped.
+pub fn parse_answer_blocks(note: &str) -> Vec<AnswerBlock> {
+    let mut blocks = Vec::new();
+    let mut open: Option<(String, Vec<&str>)> = None;
+
+    for line in note.lines() {
+        let trimmed = line.trim();
+        match open.take() {
+            Some((keywords, content)) if trimmed == CLOSING_FENCE => {
+                push_block(&mut blocks, keywords, &content);
+            }
+            Some((keywords, mut content)) => {
+                content.push(line);
+                open = Some((keywords, content));
+            }
+            None => {
+                if let Some(keywords) = answer_fence_keywords(trimmed) {
+                    open = Some((keywords, Vec::new()));
+                }
+            }
+        }
+    }
+    if let Some((keywords, content)) = open {
+        push_block(&mut blocks, keywords, &content);
+    }
+
+    blocks
+}
+
+fn answer_fence_keywords(line: &str) -> Option<String> {
+    let rest = line.strip_prefix(ANSWER_FENCE)?;
+    // `::: answers` is not a fence.
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some(rest.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+fn push_block(blocks: &mut Vec<AnswerBlock>, keywords: String, content: &[&str]) {
+    let content = content.join("\n").trim_matches('\n').to_string();
+    if !content.trim().is_empty() {
+        blocks.push(AnswerBlock { keywords, content });
+    }
+}
+
+/// Whether every word of `query` starts a word of `keywords`, ignoring case.
+pub fn answer_matches(keywords: &str, query: &str) -> bool {
+    let keyword_words: Vec<String> = keywords.split_whitespace().map(str::to_lowercase).collect();
+    let mut query_words = query.split_whitespace().peekable();
+    query_words.peek().is_some()
+        && query_words.all(|word| {
+            let word = word.to_lowercase();
+            keyword_words
+                .iter()
+                .any(|keyword| keyword.starts_with(&word))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answer_blocks_reads_keywords_and_multiline_content() {
+        let note = "intro\n::: answer  Wifi   password\ncorrect-horse\nbattery\n:::\n::: answers\nnot a block\n:::\n::: answer\n\n:::\n::: answer\nunclosed";
+        assert_eq!(
+            parse_answer_blocks(note),
+            vec![
+                AnswerBlock {
+                    keywords: "Wifi password".into(),
+                    content: "correct-horse\nbattery".into(),
+                },
+                AnswerBlock {
+                    keywords: String::new(),
+                    content: "unclosed".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn answer_matches_requires_every_query_word_as_a_prefix() {
+        assert!(answer_matches("Wifi password", "wi pass"));
+        assert!(!answer_matches("Wifi password", "wifi code"));
+        assert!(!answer_matches("Wifi password", "  "));
+    }
+}
@@ -0,0 +1,87 @@
+//! Locking of secure items. The host enrolls a random key it keeps behind
+//! Touch ID or Face ID and hands the key back to unlock; only a salted SHA-256
+//! digest of it is stored. Unlocking lasts until `lock` or the app quits.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::{Result, anyhow};
+use ring::digest::{Context, SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
+
+/// Shortest key accepted, so the stored digest can't be guessed back.
+pub const MIN_KEY_LEN: usize = 16;
+const SALT_LEN: usize = 16;
+
+static UNLOCKED: AtomicBool = AtomicBool::new(false);
+
+pub fn is_unlocked() -> bool {
+    UNLOCKED.load(Ordering::SeqCst)
+}
+
+pub fn set_unlocked(unlocked: bool) {
+    UNLOCKED.store(unlocked, Ordering::SeqCst);
+}
+
+/// `salt:digest` in hex, to store in place of `key`.
+pub fn make_verifier(key: &[u8]) -> Result<String> {
+    let mut salt = [0u8; SALT_LEN];
+    SystemRandom::new()
+        .fill(&mut salt)
+        .map_err(|_| anyhow!("no system randomness for the lock key salt"))?;
+    Ok(format!("{}:{}", to_hex(&salt), salted_digest(&salt, key)))
+}
+
+/// Whether `key` is the one `verifier` was made from.
+pub fn verify(verifier: &str, key: &[u8]) -> bool {
+    let Some((salt, expected)) = verifier.split_once(':') else {
+        return false;
+    };
+    let Some(salt) = from_hex(salt) else {
+        return false;
+    };
+    let actual = salted_digest(&salt, key);
+    // Compare every byte so timing doesn't tell how much of the key matched.
+    actual.len() == expected.len()
+        && actual
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn salted_digest(salt: &[u8], key: &[u8]) -> String {
+    let mut context = Context::new(&SHA256);
+    context.update(salt);
+    context.update(key);
+    to_hex(context.finish().as_ref())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(text.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifier_accepts_only_its_own_key() {
+        let key = [7u8; 32];
+        let verifier = make_verifier(&key).unwrap();
+        assert!(verify(&verifier, &key));
+        assert!(!verify(&verifier, &[8u8; 32]));
+        assert!(!verify(&verifier, &key[..31]));
+        assert!(!verify("not a verifier", &key));
+        assert_ne!(make_verifier(&key).unwrap(), verifier, "salts differ");
+    }
+}
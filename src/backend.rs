@@ -1,8 +1,48 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::activity::{self, ActivityOrigin};
+use crate::app_lock;
+use crate::checklist::ChecklistEntry;
+use crate::commands;
 use crate::db;
-use crate::models::{EditableItem, NoteImage, SearchResult};
+use crate::deep_link;
+use crate::forge::{ForgeAccount, ForgeKind, ForgeProvider};
+use crate::image_import::{self, ImageImportPlan, SkippedImport};
+use crate::logging::log_error;
+use crate::markdown_input::{self, ListContinuation, SelectionEdit};
+use crate::markdown_preview::{self, OutlineEntry, PreviewBlock, PreviewBlockKind};
+use crate::markdown_table::{self, TableCommand};
+use crate::merge;
+use crate::models::{
+    ActivityDay, AlertLevel, BoardCard, BrowseSort, CalendarEntry, ChecklistProgress, DiffKind,
+    DiffSegment, EditableItem, ImageLimits, ImageReplacement, ImageText, ImportedNote,
+    ItemMarkdown, ItemMetadata, ItemStats, ItemStorageUsage, NoteImage, NoteVersionSummary,
+    ResultKind, ResultSection, RunningTimer, SearchResult, StorageAlert, StorageReport,
+    StoredImage, UsageStats,
+};
+use crate::note_links;
+use crate::notion_import;
+use crate::preview_style;
+use crate::providers::{self, CapturedNote, ProviderOutcome};
+use crate::read_later::{self, FetchError};
+use crate::router::{self, QueryRoute, TrackQuery};
+use crate::schedules::{self, Recurrence, Schedule, SchedulerWakeups};
+use crate::tags;
+use crate::text_transform::{self, TextTransform};
+use crate::time_tracking::WeekReport;
+use crate::trackers::{TrackerAccount, TrackerKind, TrackerProvider};
+use crate::triggers::{Trigger, TriggerAction, TriggerEvent, TriggerLogEntry};
+use crate::vault;
 
 const DEFAULT_SEARCH_LIMIT: u32 = 8;
 const MAX_SEARCH_LIMIT: u32 = 64;
+/// Answer blocks shown at most above the other results.
+const MAX_ANSWER_RESULTS: usize = 3;
+/// Matching commands shown at most above notes.
+const MAX_COMMAND_RESULTS: usize = 5;
+/// Results of federated providers listed after the notes of a plain query.
+const MAX_PROVIDER_RESULTS: usize = 5;
 
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 pub enum BackendError {
@@ -12,6 +52,31 @@ pub enum BackendError {
     NotFound(String),
     #[error("storage error: {0}")]
     Storage(String),
+    #[error("conflict: {0}")]
+    Conflict(String),
+    /// A secure item was asked for while the app is locked.
+    #[error("locked: {0}")]
+    Locked(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum SearchResultKind {
+    Item,
+    SystemCommand,
+    AppCommand,
+    QueryPrefix,
+    Answer,
+    ReadLater,
+    Provider,
+    Timer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum SearchResultSection {
+    Answers,
+    Commands,
+    Notes,
+    Providers,
 }
 
 #[derive(Debug, Clone, uniffi::Record)]
@@ -21,6 +86,43 @@ pub struct SearchResultRecord {
     pub subtitle: String,
     pub snippet: Option<String>,
     pub snippet_source: Option<String>,
+    pub kind: SearchResultKind,
+    /// Group the launcher lists the result under.
+    pub section: SearchResultSection,
+    pub icon: Option<String>,
+    pub command_key: Option<String>,
+    pub pinned: bool,
+    pub thumbnail_key: Option<String>,
+    /// Parts of `title` matching the query, for highlighting.
+    pub title_match_ranges: Vec<TextRangeRecord>,
+    pub checklist_progress: Option<ChecklistProgressRecord>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ChecklistProgressRecord {
+    pub done: u32,
+    pub total: u32,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ChecklistEntryRecord {
+    pub text: String,
+    pub done: bool,
+}
+
+/// A range of a string in UTF-16 code units, as Swift strings index them.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TextRangeRecord {
+    pub start: u32,
+    pub length: u32,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct HashtagRecord {
+    /// Lowercased tag name without the `#`.
+    pub tag: String,
+    /// Where the tag, including its `#`, sits in the note.
+    pub range: TextRangeRecord,
 }
 
 #[derive(Debug, Clone, uniffi::Record)]
@@ -33,8 +135,22 @@ pub struct NoteImageRecord {
 pub struct EditableItemRecord {
     pub id: i64,
     pub title: String,
+    /// Shown under the title in the launcher's listing.
+    pub subtitle: String,
+    /// Extra words the item is found by; the title by default.
+    pub keywords: String,
     pub note: String,
     pub images: Vec<NoteImageRecord>,
+    pub revision: u64,
+    pub secure: bool,
+    /// Reason the item is not mirrored to the JSON storage folder, if excluded.
+    pub mirror_exclusion: Option<String>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ItemMetadataRecord {
+    pub subtitle: String,
+    pub keywords: String,
 }
 
 #[derive(Debug, Clone, uniffi::Record)]
@@ -66,85 +182,1126 @@ pub struct DeletedItemPreviewRecord {
     pub image_count: i64,
 }
 
-impl From<SearchResult> for SearchResultRecord {
-    fn from(value: SearchResult) -> Self {
-        Self {
-            id: value.id,
-            title: value.title,
-            subtitle: value.subtitle,
-            snippet: value.snippet,
-            snippet_source: value.snippet_source,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum TriggerEventKind {
+    Created,
+    Saved,
+    Deleted,
+}
+
+/// Who is changing data, as shown in the activity log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum ActivityOriginKind {
+    Gui,
+    Uniffi,
+    Cli,
+    Sync,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum TriggerActionKind {
+    RunScript,
+    PostUrl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum ForgeKindRecord {
+    GitHub,
+    GitLab,
+}
+
+/// A GitHub or GitLab account; `host` is set for self-hosted instances.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ForgeAccountRecord {
+    pub kind: ForgeKindRecord,
+    pub host: Option<String>,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum TrackerKindRecord {
+    Jira,
+    Linear,
+}
+
+/// A Jira or Linear workspace; Jira also needs `site` and `email`.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TrackerAccountRecord {
+    pub kind: TrackerKindRecord,
+    pub site: Option<String>,
+    pub email: Option<String>,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum ProviderOutcomeKind {
+    Done,
+    OpenUrl,
+    CopyText,
+    /// A note was captured from the result; open `item_id`.
+    OpenItem,
+}
+
+/// What the app does after a provider result was picked; `value` holds the
+/// URL or text.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ProviderOutcomeRecord {
+    pub kind: ProviderOutcomeKind,
+    pub value: Option<String>,
+    pub item_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TriggerRecord {
+    pub id: String,
+    pub name: String,
+    pub event: TriggerEventKind,
+    pub tag: Option<String>,
+    pub action_kind: TriggerActionKind,
+    pub target: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TriggerLogEntryRecord {
+    pub at_unix_seconds: i64,
+    pub event: TriggerEventKind,
+    pub item_id: i64,
+    pub attempt: u32,
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct BoardCardRecord {
+    pub id: i64,
+    pub title: String,
+    /// One of `board_statuses()`, without the leading `#`.
+    pub status: String,
+    pub preview: String,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct CalendarEntryRecord {
+    pub day: u32,
+    pub item_id: i64,
+    pub title: String,
+    pub is_journal: bool,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct RunningTimerRecord {
+    pub item_id: i64,
+    pub title: String,
+    pub started_at_unix_seconds: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TimeReportRowRecord {
+    pub item_id: i64,
+    pub title: String,
+    /// Seconds per day, Monday first.
+    pub day_seconds: Vec<u64>,
+    pub total_seconds: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TimeReportRecord {
+    /// Local dates of the week as `YYYY-MM-DD`, Monday first.
+    pub days: Vec<String>,
+    /// Notes with time in the week, most tracked first.
+    pub rows: Vec<TimeReportRowRecord>,
+    pub day_totals: Vec<u64>,
+    pub total_seconds: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ActivityDayRecord {
+    /// Local date as `YYYY-MM-DD`.
+    pub date: String,
+    pub saves: u32,
+    /// Note length in characters at the end of the day.
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ItemStatsRecord {
+    pub word_count: u64,
+    pub char_count: u64,
+    /// 0 when the item predates timestamp tracking.
+    pub created_at_unix_seconds: i64,
+    pub updated_at_unix_seconds: i64,
+    pub version_count: u32,
+    /// Days with at least one save, oldest first.
+    pub days: Vec<ActivityDayRecord>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct UsageStatsRecord {
+    pub item_count: u64,
+    pub image_count: u64,
+    pub image_bytes: u64,
+    /// One entry per day, oldest first.
+    pub searches_per_day: Vec<DailySearchesRecord>,
+    /// Most opened first.
+    pub most_opened: Vec<OpenedItemRecord>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct DailySearchesRecord {
+    /// Local date as `YYYY-MM-DD`.
+    pub date: String,
+    pub searches: u32,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct OpenedItemRecord {
+    pub id: i64,
+    pub title: String,
+    pub opens: u32,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct NoteVersionRecord {
+    /// Position in the item's history, oldest first.
+    pub index: u32,
+    pub at_unix_seconds: i64,
+    /// Number of saves folded into this version.
+    pub saves: u32,
+    pub char_count: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum DiffSegmentKind {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct DiffSegmentRecord {
+    pub kind: DiffSegmentKind,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct OutlineEntryRecord {
+    /// 1 to 3, like preview headings.
+    pub level: u32,
+    pub title: String,
+    /// 0-based line of the note the heading is on.
+    pub line: u32,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MergedNoteRecord {
+    pub text: String,
+    /// Overlapping edits left between conflict markers.
+    pub conflict_count: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum BrowseSortOrder {
+    Relevance,
+    Updated,
+    Created,
+    Title,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum PreviewBlockKindRecord {
+    Paragraph,
+    Heading,
+    ListItem,
+    ChecklistItem,
+    Quote,
+    Code,
+    Divider,
+    Image,
+    Footnote,
+}
+
+/// A block of the editor's rendered preview; `text` keeps inline Markdown.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct PreviewBlockRecord {
+    pub kind: PreviewBlockKindRecord,
+    pub text: String,
+    /// Heading level, or list nesting depth.
+    pub level: u32,
+    pub marker: String,
+    pub done: bool,
+    pub image_key: Option<String>,
+    pub image_width: Option<u32>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct NoteTitleRecord {
+    pub item_id: i64,
+    pub title: String,
+}
+
+/// What Return does on a Markdown list line in the editor.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ListContinuationRecord {
+    /// Typed after the line break to start the next item; empty when `ends_list`.
+    pub prefix: String,
+    /// The item was empty: its marker is removed and no new item is started.
+    pub ends_list: bool,
+}
+
+/// Markers to put around the editor's selection, or how many characters to
+/// take off each of its ends.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct SelectionWrapRecord {
+    pub open: String,
+    pub close: String,
+    /// UTF-16 units removed from each end of the selection; 0 when wrapping.
+    pub unwrap_length: u32,
+}
+
+/// Style overrides for the rendered preview from the user's style file; unset
+/// fields keep the built-in look.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct PreviewStyleRecord {
+    pub font_family: Option<String>,
+    pub font_size: Option<f64>,
+    pub max_width: Option<f64>,
+    /// `h1` to `h3`.
+    pub heading_sizes: Vec<Option<f64>>,
+    pub code_font_family: Option<String>,
+    /// 0xRRGGBBAA.
+    pub code_color: Option<u32>,
+    pub code_background: Option<u32>,
+    /// Rules and declarations that were skipped, for the settings screen.
+    pub warnings: Vec<String>,
+}
+
+/// A Markdown table in the editor, as its rows' cells between their pipes.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TableLayoutRecord {
+    pub rows: Vec<Vec<TextRangeRecord>>,
+}
+
+/// An editor command on the Markdown table at the caret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum TableCommandKind {
+    NextCell,
+    PreviousCell,
+    InsertRow,
+    InsertColumn,
+}
+
+/// The table's lines after a table command, and what to select in line `row`.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct TableEditRecord {
+    pub lines: Vec<String>,
+    pub row: u32,
+    /// UTF-16 range within line `row`.
+    pub selection: TextRangeRecord,
+}
+
+/// An editor command that rewrites the selected text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum TextTransformKind {
+    Uppercase,
+    Lowercase,
+    TitleCase,
+    SortLines,
+    DeduplicateLines,
+    JoinLines,
+    UrlEncode,
+    UrlDecode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum StorageAlertLevel {
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct StorageAlertRecord {
+    pub level: StorageAlertLevel,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ItemStorageRecord {
+    pub item_id: i64,
+    pub title: String,
+    pub bytes: u64,
+    pub mirrored: bool,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct StorageReportRecord {
+    pub root: String,
+    /// Name of the sync service the root lives in, if any.
+    pub cloud_provider: Option<String>,
+    pub available_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+    pub mirror_bytes: u64,
+    pub budget_bytes: Option<u64>,
+    /// Most severe first; empty when storage is healthy.
+    pub alerts: Vec<StorageAlertRecord>,
+    pub largest_items: Vec<ItemStorageRecord>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ImageLimitsRecord {
+    pub max_images_per_note: u32,
+    pub max_image_bytes: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct StoredImageRecord {
+    pub item_id: i64,
+    pub image_key: String,
+    pub bytes: u64,
+    pub fingerprint: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ImageReplacementRecord {
+    pub item_id: i64,
+    pub image_key: String,
+    /// Fingerprint from `list_stored_images`; the image is skipped if it changed.
+    pub fingerprint: u64,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ImageTextRecord {
+    pub item_id: i64,
+    pub image_key: String,
+    /// Fingerprint from `list_images_without_text`; the image is skipped if it changed.
+    pub fingerprint: u64,
+    /// Recognized text; empty marks an image without text so it isn't scanned again.
+    pub text: String,
+}
+
+/// Background wakeups since launch, for checking that the app idles when hidden.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct IdleStatsRecord {
+    pub scheduler_wakeups: u64,
+    pub power_saving_scheduler_wakeups: u64,
+    pub power_saving: bool,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ItemMarkdownRecord {
+    pub file_name: String,
+    pub markdown: String,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct SkippedImageRecord {
+    pub file_name: String,
+    pub reason: String,
+}
+
+/// Image files to read, in insertion order, and the files left out.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ImageImportPlanRecord {
+    pub paths: Vec<String>,
+    pub skipped: Vec<SkippedImageRecord>,
+}
+
+/// Outcome of importing a Notion export.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct NotionImportRecord {
+    pub imported_count: u32,
+    /// Embedded images left out because they were missing, unreachable or
+    /// over the image limits.
+    pub skipped_image_count: u32,
+}
+
+/// Outcome of `read <url>`: the new item, or that the page was unreachable
+/// and queued to be fetched again.
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ReadLaterRecord {
+    pub item_id: Option<i64>,
+    pub queued: bool,
+    /// Why the page was queued.
+    pub message: Option<String>,
+    pub skipped_image_count: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum RecurrenceKind {
+    Daily,
+    Weekly,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ScheduleRecord {
+    pub id: String,
+    pub name: String,
+    pub title_template: String,
+    pub note_template: String,
+    pub recurrence: RecurrenceKind,
+    /// Day of week for weekly schedules, Monday = 0; ignored for daily ones.
+    pub weekday: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub enabled: bool,
+    /// Read-only; ignored by `save_schedule`.
+    pub next_run_unix_seconds: Option<i64>,
+}
+
+impl From<ResultKind> for SearchResultKind {
+    fn from(value: ResultKind) -> Self {
+        match value {
+            ResultKind::Item => Self::Item,
+            ResultKind::SystemCommand => Self::SystemCommand,
+            ResultKind::AppCommand => Self::AppCommand,
+            ResultKind::QueryPrefix => Self::QueryPrefix,
+            ResultKind::Answer => Self::Answer,
+            ResultKind::ReadLater => Self::ReadLater,
+            ResultKind::Provider => Self::Provider,
+            ResultKind::Timer => Self::Timer,
         }
     }
 }
 
-impl From<NoteImage> for NoteImageRecord {
-    fn from(value: NoteImage) -> Self {
+impl From<ForgeKind> for ForgeKindRecord {
+    fn from(value: ForgeKind) -> Self {
+        match value {
+            ForgeKind::GitHub => Self::GitHub,
+            ForgeKind::GitLab => Self::GitLab,
+        }
+    }
+}
+
+impl From<ForgeKindRecord> for ForgeKind {
+    fn from(value: ForgeKindRecord) -> Self {
+        match value {
+            ForgeKindRecord::GitHub => Self::GitHub,
+            ForgeKindRecord::GitLab => Self::GitLab,
+        }
+    }
+}
+
+impl From<ForgeAccount> for ForgeAccountRecord {
+    fn from(value: ForgeAccount) -> Self {
         Self {
-            image_key: value.image_key,
-            bytes: value.bytes,
+            kind: value.kind.into(),
+            host: value.host,
+            token: value.token,
         }
     }
 }
 
-impl From<NoteImageRecord> for NoteImage {
-    fn from(value: NoteImageRecord) -> Self {
+impl From<ForgeAccountRecord> for ForgeAccount {
+    fn from(value: ForgeAccountRecord) -> Self {
         Self {
-            image_key: value.image_key,
-            bytes: value.bytes,
+            kind: value.kind.into(),
+            host: value.host,
+            token: value.token,
         }
     }
 }
 
-impl From<EditableItem> for EditableItemRecord {
-    fn from(value: EditableItem) -> Self {
+impl From<TrackerKind> for TrackerKindRecord {
+    fn from(value: TrackerKind) -> Self {
+        match value {
+            TrackerKind::Jira => Self::Jira,
+            TrackerKind::Linear => Self::Linear,
+        }
+    }
+}
+
+impl From<TrackerKindRecord> for TrackerKind {
+    fn from(value: TrackerKindRecord) -> Self {
+        match value {
+            TrackerKindRecord::Jira => Self::Jira,
+            TrackerKindRecord::Linear => Self::Linear,
+        }
+    }
+}
+
+impl From<TrackerAccount> for TrackerAccountRecord {
+    fn from(value: TrackerAccount) -> Self {
         Self {
-            id: value.id,
-            title: value.title,
-            note: value.note,
-            images: value
-                .images
-                .into_iter()
-                .map(NoteImageRecord::from)
-                .collect(),
+            kind: value.kind.into(),
+            site: value.site,
+            email: value.email,
+            token: value.token,
         }
     }
 }
 
-impl From<db::ExportItem> for ExportItemRecord {
-    fn from(value: db::ExportItem) -> Self {
+impl From<TrackerAccountRecord> for TrackerAccount {
+    fn from(value: TrackerAccountRecord) -> Self {
         Self {
-            id: value.id,
-            title: value.title,
-            subtitle: value.subtitle,
-            keywords: value.keywords,
-            note: value.note,
-            image_count: value.image_count,
+            kind: value.kind.into(),
+            site: value.site,
+            email: value.email,
+            token: value.token,
         }
     }
 }
 
-impl From<db::DeletedItemSummary> for DeletedItemRecord {
-    fn from(value: db::DeletedItemSummary) -> Self {
+impl From<ResultSection> for SearchResultSection {
+    fn from(value: ResultSection) -> Self {
+        match value {
+            ResultSection::Answers => Self::Answers,
+            ResultSection::Commands => Self::Commands,
+            ResultSection::Notes => Self::Notes,
+            ResultSection::Providers => Self::Providers,
+        }
+    }
+}
+
+impl From<SearchResult> for SearchResultRecord {
+    fn from(value: SearchResult) -> Self {
+        let utf16_len = |text: &str| text.encode_utf16().count() as u32;
+        let title_match_ranges = value
+            .title_match_ranges
+            .iter()
+            .map(|&(start, end)| TextRangeRecord {
+                start: utf16_len(&value.title[..start]),
+                length: utf16_len(&value.title[start..end]),
+            })
+            .collect();
         Self {
-            archive_key: value.archive_key,
             id: value.id,
             title: value.title,
-            deleted_at_unix_seconds: value.deleted_at_unix_seconds,
-            image_count: value.image_count,
+            subtitle: value.subtitle,
+            snippet: value.snippet,
+            snippet_source: value.snippet_source,
+            kind: value.kind.into(),
+            section: value.kind.section().into(),
+            icon: value.icon,
+            command_key: value.command_key,
+            pinned: value.pinned,
+            thumbnail_key: value.thumbnail_key,
+            title_match_ranges,
+            checklist_progress: value.checklist_progress.map(ChecklistProgressRecord::from),
         }
     }
 }
 
-impl From<db::DeletedItemPreview> for DeletedItemPreviewRecord {
-    fn from(value: db::DeletedItemPreview) -> Self {
+impl From<ChecklistProgress> for ChecklistProgressRecord {
+    fn from(value: ChecklistProgress) -> Self {
         Self {
-            archive_key: value.archive_key,
-            id: value.id,
-            title: value.title,
-            note: value.note,
-            deleted_at_unix_seconds: value.deleted_at_unix_seconds,
-            image_count: value.image_count,
+            done: value.done,
+            total: value.total,
+        }
+    }
+}
+
+impl From<ChecklistEntry> for ChecklistEntryRecord {
+    fn from(value: ChecklistEntry) -> Self {
+        Self {
+            text: value.text,
+            done: value.done,
+        }
+    }
+}
+
+impl From<TriggerEvent> for TriggerEventKind {
+    fn from(value: TriggerEvent) -> Self {
+        match value {
+            TriggerEvent::Created => Self::Created,
+            TriggerEvent::Saved => Self::Saved,
+            TriggerEvent::Deleted => Self::Deleted,
+        }
+    }
+}
+
+impl From<TriggerEventKind> for TriggerEvent {
+    fn from(value: TriggerEventKind) -> Self {
+        match value {
+            TriggerEventKind::Created => Self::Created,
+            TriggerEventKind::Saved => Self::Saved,
+            TriggerEventKind::Deleted => Self::Deleted,
+        }
+    }
+}
+
+impl From<ActivityOriginKind> for ActivityOrigin {
+    fn from(value: ActivityOriginKind) -> Self {
+        match value {
+            ActivityOriginKind::Gui => Self::Gui,
+            ActivityOriginKind::Uniffi => Self::Uniffi,
+            ActivityOriginKind::Cli => Self::Cli,
+            ActivityOriginKind::Sync => Self::Sync,
+        }
+    }
+}
+
+impl From<TextTransformKind> for TextTransform {
+    fn from(value: TextTransformKind) -> Self {
+        match value {
+            TextTransformKind::Uppercase => Self::Uppercase,
+            TextTransformKind::Lowercase => Self::Lowercase,
+            TextTransformKind::TitleCase => Self::TitleCase,
+            TextTransformKind::SortLines => Self::SortLines,
+            TextTransformKind::DeduplicateLines => Self::DeduplicateLines,
+            TextTransformKind::JoinLines => Self::JoinLines,
+            TextTransformKind::UrlEncode => Self::UrlEncode,
+            TextTransformKind::UrlDecode => Self::UrlDecode,
+        }
+    }
+}
+
+impl From<TableCommandKind> for TableCommand {
+    fn from(value: TableCommandKind) -> Self {
+        match value {
+            TableCommandKind::NextCell => Self::NextCell,
+            TableCommandKind::PreviousCell => Self::PreviousCell,
+            TableCommandKind::InsertRow => Self::InsertRow,
+            TableCommandKind::InsertColumn => Self::InsertColumn,
+        }
+    }
+}
+
+impl From<Trigger> for TriggerRecord {
+    fn from(value: Trigger) -> Self {
+        let (action_kind, target) = match value.action {
+            TriggerAction::RunScript(command) => (TriggerActionKind::RunScript, command),
+            TriggerAction::PostUrl(url) => (TriggerActionKind::PostUrl, url),
+        };
+        Self {
+            id: value.id,
+            name: value.name,
+            event: value.event.into(),
+            tag: value.tag,
+            action_kind,
+            target,
+            enabled: value.enabled,
+        }
+    }
+}
+
+impl From<TriggerLogEntry> for TriggerLogEntryRecord {
+    fn from(value: TriggerLogEntry) -> Self {
+        Self {
+            at_unix_seconds: i64::try_from(value.at_unix_seconds).unwrap_or(i64::MAX),
+            event: value.event.into(),
+            item_id: value.item_id,
+            attempt: value.attempt,
+            success: value.success,
+            message: value.message,
+        }
+    }
+}
+
+impl From<BoardCard> for BoardCardRecord {
+    fn from(value: BoardCard) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            status: value.status,
+            preview: value.preview,
+        }
+    }
+}
+
+impl From<RunningTimer> for RunningTimerRecord {
+    fn from(value: RunningTimer) -> Self {
+        Self {
+            item_id: value.item_id,
+            title: value.title,
+            started_at_unix_seconds: value.started_at,
+        }
+    }
+}
+
+impl From<WeekReport> for TimeReportRecord {
+    fn from(value: WeekReport) -> Self {
+        let day_totals = value.day_totals();
+        Self {
+            days: value
+                .days()
+                .iter()
+                .map(|day| day.format("%Y-%m-%d").to_string())
+                .collect(),
+            rows: value
+                .rows
+                .into_iter()
+                .map(|row| TimeReportRowRecord {
+                    total_seconds: row.total_seconds(),
+                    item_id: row.item_id,
+                    title: row.title,
+                    day_seconds: row.day_seconds.to_vec(),
+                })
+                .collect(),
+            total_seconds: day_totals.iter().sum(),
+            day_totals: day_totals.to_vec(),
+        }
+    }
+}
+
+impl From<CalendarEntry> for CalendarEntryRecord {
+    fn from(value: CalendarEntry) -> Self {
+        Self {
+            day: value.day,
+            item_id: value.item_id,
+            title: value.title,
+            is_journal: value.is_journal,
+        }
+    }
+}
+
+impl From<ActivityDay> for ActivityDayRecord {
+    fn from(value: ActivityDay) -> Self {
+        Self {
+            date: value.date.format("%Y-%m-%d").to_string(),
+            saves: value.saves,
+            length: value.length as u64,
+        }
+    }
+}
+
+impl From<ItemStats> for ItemStatsRecord {
+    fn from(value: ItemStats) -> Self {
+        Self {
+            word_count: value.word_count as u64,
+            char_count: value.char_count as u64,
+            created_at_unix_seconds: i64::try_from(value.created_at).unwrap_or(i64::MAX),
+            updated_at_unix_seconds: i64::try_from(value.updated_at).unwrap_or(i64::MAX),
+            version_count: u32::try_from(value.version_count).unwrap_or(u32::MAX),
+            days: value
+                .days
+                .into_iter()
+                .map(ActivityDayRecord::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<UsageStats> for UsageStatsRecord {
+    fn from(value: UsageStats) -> Self {
+        Self {
+            item_count: value.item_count as u64,
+            image_count: value.image_count as u64,
+            image_bytes: value.image_bytes,
+            searches_per_day: value
+                .searches_per_day
+                .into_iter()
+                .map(|(date, searches)| DailySearchesRecord {
+                    date: date.format("%Y-%m-%d").to_string(),
+                    searches,
+                })
+                .collect(),
+            most_opened: value
+                .most_opened
+                .into_iter()
+                .map(|item| OpenedItemRecord {
+                    id: item.id,
+                    title: item.title,
+                    opens: item.opens,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<NoteVersionSummary> for NoteVersionRecord {
+    fn from(value: NoteVersionSummary) -> Self {
+        Self {
+            index: u32::try_from(value.index).unwrap_or(u32::MAX),
+            at_unix_seconds: i64::try_from(value.at_unix_seconds).unwrap_or(i64::MAX),
+            saves: value.saves,
+            char_count: value.char_count as u64,
+        }
+    }
+}
+
+impl From<OutlineEntry> for OutlineEntryRecord {
+    fn from(value: OutlineEntry) -> Self {
+        Self {
+            level: value.level,
+            title: value.title,
+            line: u32::try_from(value.line).unwrap_or(u32::MAX),
+        }
+    }
+}
+
+impl From<DiffSegment> for DiffSegmentRecord {
+    fn from(value: DiffSegment) -> Self {
+        Self {
+            kind: match value.kind {
+                DiffKind::Equal => DiffSegmentKind::Equal,
+                DiffKind::Insert => DiffSegmentKind::Insert,
+                DiffKind::Delete => DiffSegmentKind::Delete,
+            },
+            text: value.text,
+        }
+    }
+}
+
+impl From<BrowseSortOrder> for BrowseSort {
+    fn from(value: BrowseSortOrder) -> Self {
+        match value {
+            BrowseSortOrder::Relevance => Self::Relevance,
+            BrowseSortOrder::Updated => Self::Updated,
+            BrowseSortOrder::Created => Self::Created,
+            BrowseSortOrder::Title => Self::Title,
+        }
+    }
+}
+
+impl From<PreviewBlock> for PreviewBlockRecord {
+    fn from(value: PreviewBlock) -> Self {
+        Self {
+            kind: match value.kind {
+                PreviewBlockKind::Paragraph => PreviewBlockKindRecord::Paragraph,
+                PreviewBlockKind::Heading => PreviewBlockKindRecord::Heading,
+                PreviewBlockKind::ListItem => PreviewBlockKindRecord::ListItem,
+                PreviewBlockKind::ChecklistItem => PreviewBlockKindRecord::ChecklistItem,
+                PreviewBlockKind::Quote => PreviewBlockKindRecord::Quote,
+                PreviewBlockKind::Code => PreviewBlockKindRecord::Code,
+                PreviewBlockKind::Divider => PreviewBlockKindRecord::Divider,
+                PreviewBlockKind::Image => PreviewBlockKindRecord::Image,
+                PreviewBlockKind::Footnote => PreviewBlockKindRecord::Footnote,
+            },
+            text: value.text,
+            level: value.level,
+            marker: value.marker,
+            done: value.done,
+            image_key: value.image_key,
+            image_width: value.image_width,
+        }
+    }
+}
+
+impl From<StorageAlert> for StorageAlertRecord {
+    fn from(value: StorageAlert) -> Self {
+        Self {
+            level: match value.level {
+                AlertLevel::Warning => StorageAlertLevel::Warning,
+                AlertLevel::Critical => StorageAlertLevel::Critical,
+            },
+            message: value.message,
+        }
+    }
+}
+
+impl From<ItemStorageUsage> for ItemStorageRecord {
+    fn from(value: ItemStorageUsage) -> Self {
+        Self {
+            item_id: value.id,
+            title: value.title,
+            bytes: value.bytes,
+            mirrored: value.mirrored,
+        }
+    }
+}
+
+impl From<ImageLimits> for ImageLimitsRecord {
+    fn from(value: ImageLimits) -> Self {
+        Self {
+            max_images_per_note: value.max_images_per_note as u32,
+            max_image_bytes: value.max_image_bytes as u64,
+        }
+    }
+}
+
+impl From<StoredImage> for StoredImageRecord {
+    fn from(value: StoredImage) -> Self {
+        Self {
+            item_id: value.item_id,
+            image_key: value.image_key,
+            bytes: value.bytes,
+            fingerprint: value.fingerprint,
+        }
+    }
+}
+
+impl From<SchedulerWakeups> for IdleStatsRecord {
+    fn from(value: SchedulerWakeups) -> Self {
+        Self {
+            scheduler_wakeups: value.total,
+            power_saving_scheduler_wakeups: value.power_saving,
+            power_saving: value.power_saving_active,
+        }
+    }
+}
+
+impl From<ItemMarkdown> for ItemMarkdownRecord {
+    fn from(value: ItemMarkdown) -> Self {
+        Self {
+            file_name: value.file_name,
+            markdown: value.markdown,
+        }
+    }
+}
+
+impl From<ImageReplacementRecord> for ImageReplacement {
+    fn from(value: ImageReplacementRecord) -> Self {
+        Self {
+            item_id: value.item_id,
+            image_key: value.image_key,
+            fingerprint: value.fingerprint,
+            bytes: value.bytes,
+        }
+    }
+}
+
+impl From<ImageTextRecord> for ImageText {
+    fn from(value: ImageTextRecord) -> Self {
+        Self {
+            item_id: value.item_id,
+            image_key: value.image_key,
+            fingerprint: value.fingerprint,
+            text: value.text,
+        }
+    }
+}
+
+impl From<SkippedImport> for SkippedImageRecord {
+    fn from(value: SkippedImport) -> Self {
+        Self {
+            file_name: value.file_name,
+            reason: value.reason,
+        }
+    }
+}
+
+impl From<ImageImportPlan> for ImageImportPlanRecord {
+    fn from(value: ImageImportPlan) -> Self {
+        Self {
+            paths: value
+                .paths
+                .into_iter()
+                .map(|path| path.to_string_lossy().to_string())
+                .collect(),
+            skipped: value
+                .skipped
+                .into_iter()
+                .map(SkippedImageRecord::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<StorageReport> for StorageReportRecord {
+    fn from(value: StorageReport) -> Self {
+        Self {
+            root: value.root,
+            cloud_provider: value
+                .snapshot
+                .cloud_provider
+                .map(|provider| provider.name().to_string()),
+            available_bytes: value.snapshot.available_bytes,
+            total_bytes: value.snapshot.total_bytes,
+            mirror_bytes: value.snapshot.mirror_bytes,
+            budget_bytes: value.snapshot.budget_bytes,
+            alerts: value
+                .alerts
+                .into_iter()
+                .map(StorageAlertRecord::from)
+                .collect(),
+            largest_items: value
+                .largest_items
+                .into_iter()
+                .map(ItemStorageRecord::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<Schedule> for ScheduleRecord {
+    fn from(value: Schedule) -> Self {
+        let next_run_unix_seconds = value
+            .enabled
+            .then(|| value.next_occurrence(&chrono::Local::now()))
+            .flatten()
+            .map(|next| next.timestamp());
+        let (recurrence, weekday) = match value.recurrence {
+            Recurrence::Daily => (RecurrenceKind::Daily, 0),
+            Recurrence::Weekly { weekday } => (RecurrenceKind::Weekly, weekday),
+        };
+        Self {
+            id: value.id,
+            name: value.name,
+            title_template: value.title_template,
+            note_template: value.note_template,
+            recurrence,
+            weekday,
+            hour: value.hour,
+            minute: value.minute,
+            enabled: value.enabled,
+            next_run_unix_seconds,
+        }
+    }
+}
+
+impl From<NoteImage> for NoteImageRecord {
+    fn from(value: NoteImage) -> Self {
+        Self {
+            image_key: value.image_key,
+            bytes: value.bytes,
+        }
+    }
+}
+
+impl From<NoteImageRecord> for NoteImage {
+    fn from(value: NoteImageRecord) -> Self {
+        Self {
+            image_key: value.image_key,
+            bytes: value.bytes,
+        }
+    }
+}
+
+impl From<EditableItem> for EditableItemRecord {
+    fn from(value: EditableItem) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            subtitle: value.subtitle,
+            keywords: value.keywords,
+            note: value.note,
+            images: value
+                .images
+                .into_iter()
+                .map(NoteImageRecord::from)
+                .collect(),
+            revision: value.revision,
+            secure: value.secure,
+            mirror_exclusion: value.mirror_exclusion,
+        }
+    }
+}
+
+impl From<db::ExportItem> for ExportItemRecord {
+    fn from(value: db::ExportItem) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            subtitle: value.subtitle,
+            keywords: value.keywords,
+            note: value.note,
+            image_count: value.image_count,
+        }
+    }
+}
+
+impl From<db::DeletedItemSummary> for DeletedItemRecord {
+    fn from(value: db::DeletedItemSummary) -> Self {
+        Self {
+            archive_key: value.archive_key,
+            id: value.id,
+            title: value.title,
+            deleted_at_unix_seconds: value.deleted_at_unix_seconds,
+            image_count: value.image_count,
+        }
+    }
+}
+
+impl From<db::DeletedItemPreview> for DeletedItemPreviewRecord {
+    fn from(value: db::DeletedItemPreview) -> Self {
+        Self {
+            archive_key: value.archive_key,
+            id: value.id,
+            title: value.title,
+            note: value.note,
+            deleted_at_unix_seconds: value.deleted_at_unix_seconds,
+            image_count: value.image_count,
         }
     }
 }
@@ -154,22 +1311,217 @@ pub fn backend_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+/// Opens the store and index ahead of the first search; call off the main thread.
+#[uniffi::export]
+pub fn warm_up_store() -> Result<(), BackendError> {
+    db::warm_up_store().map_err(map_anyhow)?;
+    Ok(())
+}
+
+#[uniffi::export]
+pub fn is_store_ready() -> bool {
+    db::is_store_ready()
+}
+
+#[uniffi::export]
+pub fn search_items(
+    query: String,
+    limit: Option<u32>,
+) -> Result<Vec<SearchResultRecord>, BackendError> {
+    // Limit query length to prevent potential issues
+    const MAX_QUERY_LENGTH: usize = 1024;
+    let query = if query.len() > MAX_QUERY_LENGTH {
+        query.chars().take(MAX_QUERY_LENGTH).collect()
+    } else {
+        query
+    };
+
+    let limit = normalize_limit(limit)?;
+    if let Some((provider, rest)) = providers::route_prefixed(&query) {
+        return Ok(providers::provider_results(&provider, rest, limit as usize)
+            .into_iter()
+            .map(SearchResultRecord::from)
+            .collect());
+    }
+    if db::is_launcher_only() {
+        return Ok(search_without_store(&query)
+            .into_iter()
+            .take(limit as usize)
+            .map(SearchResultRecord::from)
+            .collect());
+    }
+    let results = match router::route_query(&query) {
+        QueryRoute::Items(query) => search_items_and_commands(query, limit)?,
+        QueryRoute::Commands(query) => commands::list_commands(query),
+        QueryRoute::Tag(tag) => db::search_by_tag(tag, limit as i64).map_err(map_anyhow)?,
+        QueryRoute::ReadLater(url) => {
+            let queued = db::load_read_later_queue().map_err(map_anyhow)?.len();
+            router::read_later_results(url, queued)
+        }
+        QueryRoute::Track(query) => track_results(query, limit)?,
+        QueryRoute::Help => with_provider_help(router::help_results()),
+    };
+    Ok(without_locked_content(results, &locked_item_ids()?)
+        .into_iter()
+        .take(limit as usize)
+        .map(SearchResultRecord::from)
+        .collect())
+}
+
+/// Titles of locked items stay listed; their note text, images and answer
+/// blocks don't.
+fn without_locked_content(
+    results: Vec<SearchResult>,
+    locked_ids: &HashSet<i64>,
+) -> Vec<SearchResult> {
+    results
+        .into_iter()
+        .filter(|result| !(result.kind == ResultKind::Answer && locked_ids.contains(&result.id)))
+        .map(|mut result| {
+            if result.kind == ResultKind::Item && locked_ids.contains(&result.id) {
+                result.snippet = None;
+                result.snippet_source = None;
+                result.thumbnail_key = None;
+                result.checklist_progress = None;
+            }
+            result
+        })
+        .collect()
+}
+
+fn search_items_and_commands(query: &str, limit: u32) -> Result<Vec<SearchResult>, BackendError> {
+    // Answer blocks, then built-in commands, are listed ahead of notes when they
+    // match, each group capped so notes stay visible. Federated providers'
+    // results come last but keep their room.
+    let mut results = db::search_answers(query, MAX_ANSWER_RESULTS).map_err(map_anyhow)?;
+    results.extend(
+        commands::search_commands(query)
+            .into_iter()
+            .take(MAX_COMMAND_RESULTS),
+    );
+    results.truncate(limit as usize);
+    let remaining = limit as usize - results.len();
+    let federated = providers::federated_results(query, remaining.min(MAX_PROVIDER_RESULTS));
+    let remaining = remaining - federated.len();
+    if remaining > 0 {
+        results.extend(db::search(query, remaining as i64).map_err(map_anyhow)?);
+    }
+    results.extend(federated);
+    Ok(results)
+}
+
+fn track_results(query: TrackQuery<'_>, limit: u32) -> Result<Vec<SearchResult>, BackendError> {
+    let running = db::running_timer().map_err(map_anyhow)?;
+    let notes = match query {
+        TrackQuery::Start(query) => db::search(query, limit as i64)
+            .map_err(map_anyhow)?
+            .into_iter()
+            .map(|note| {
+                let tracked = db::tracked_seconds(note.id).map_err(map_anyhow)?;
+                Ok((note, tracked))
+            })
+            .collect::<Result<Vec<_>, BackendError>>()?,
+        TrackQuery::Status | TrackQuery::Stop => Vec::new(),
+    };
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    Ok(router::track_results(running, notes, now))
+}
+
+fn with_provider_help(mut results: Vec<SearchResult>) -> Vec<SearchResult> {
+    results.extend(providers::help_results());
+    results
+}
+
+/// Results in launcher-only mode: system commands and the prefixes that work
+/// without notes.
+fn search_without_store(query: &str) -> Vec<SearchResult> {
+    match router::route_query(query) {
+        QueryRoute::Items(query) => commands::search_system_commands(query),
+        QueryRoute::Commands(query) => commands::list_system_commands(query),
+        QueryRoute::Help => with_provider_help(router::help_results_without_store()),
+        QueryRoute::Tag(_) | QueryRoute::ReadLater(_) | QueryRoute::Track(_) => Vec::new(),
+    }
+}
+
+/// Carries out a picked `Provider` result, given its `command_key`.
 #[uniffi::export]
-pub fn search_items(
-    query: String,
-    limit: Option<u32>,
-) -> Result<Vec<SearchResultRecord>, BackendError> {
-    // Limit query length to prevent potential issues
-    const MAX_QUERY_LENGTH: usize = 1024;
-    let query = if query.len() > MAX_QUERY_LENGTH {
-        query.chars().take(MAX_QUERY_LENGTH).collect()
+pub fn perform_provider_action(command_key: String) -> Result<ProviderOutcomeRecord, BackendError> {
+    let outcome = providers::perform(&command_key).map_err(map_anyhow)?;
+    let (kind, value) = match outcome {
+        ProviderOutcome::Done => (ProviderOutcomeKind::Done, None),
+        ProviderOutcome::OpenUrl(url) => (ProviderOutcomeKind::OpenUrl, Some(url)),
+        ProviderOutcome::CopyText(text) => (ProviderOutcomeKind::CopyText, Some(text)),
+        ProviderOutcome::CaptureNote(captured) => {
+            return Ok(ProviderOutcomeRecord {
+                kind: ProviderOutcomeKind::OpenItem,
+                value: None,
+                item_id: Some(capture_note(captured)?),
+            });
+        }
+    };
+    Ok(ProviderOutcomeRecord {
+        kind,
+        value,
+        item_id: None,
+    })
+}
+
+/// Saves a captured item as a note, or finds the note captured from it
+/// before.
+fn capture_note(captured: CapturedNote) -> Result<i64, BackendError> {
+    if let Some(id) = db::find_item_by_title(&captured.title).map_err(map_anyhow)? {
+        return Ok(id);
+    }
+    let body = captured.body.trim();
+    let mut note = if body.is_empty() {
+        format!("Source: {}", captured.url)
     } else {
-        query
+        format!("Source: {}\n\n{body}", captured.url)
+    };
+    for tag in captured.tags.iter().map(|tag| tags::normalize_tag(tag)) {
+        if tags::is_valid_tag(&tag) {
+            note = tags::add_tag(&note, &tag);
+        }
+    }
+    let imported = ImportedNote {
+        title: captured.title,
+        note,
+        images: Vec::new(),
     };
+    let ids =
+        db::import_notes(std::slice::from_ref(&imported), &captured.url).map_err(map_anyhow)?;
+    ids.first()
+        .copied()
+        .ok_or_else(|| BackendError::Storage("captured note was not saved".to_string()))
+}
 
-    let limit = normalize_limit(limit)?;
-    let results = db::search(&query, i64::from(limit)).map_err(map_anyhow)?;
-    Ok(results.into_iter().map(SearchResultRecord::from).collect())
+/// Whether this run has the note store turned off.
+#[uniffi::export]
+pub fn is_launcher_only() -> bool {
+    db::is_launcher_only()
+}
+
+/// The launcher-only setting for the next launch.
+#[uniffi::export]
+pub fn load_launcher_only() -> Result<bool, BackendError> {
+    db::launcher_only_saved().map_err(map_anyhow)
+}
+
+/// Turns the note store off or on; takes effect when the app next starts.
+#[uniffi::export]
+pub fn save_launcher_only(enabled: bool) -> Result<(), BackendError> {
+    db::save_launcher_only(enabled).map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn run_system_command(command_key: String) -> Result<(), BackendError> {
+    let command_key = command_key.trim();
+    if command_key.is_empty() {
+        return Err(BackendError::Validation(
+            "command_key must not be empty".to_string(),
+        ));
+    }
+    commands::run_command(command_key).map_err(map_anyhow)
 }
 
 #[uniffi::export]
@@ -219,17 +1571,26 @@ fn sanitize_title(title: &str) -> String {
 #[uniffi::export]
 pub fn get_item(item_id: i64) -> Result<EditableItemRecord, BackendError> {
     ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
     let item = db::fetch_item(item_id).map_err(map_anyhow)?;
     Ok(item.into())
 }
 
-#[uniffi::export]
+/// Saves the note and images of an item, and its subtitle and keywords when
+/// `metadata` is given, and returns the new revision.
+///
+/// Pass the revision obtained from `get_item` as `expected_revision` to fail with
+/// `BackendError::Conflict` instead of overwriting a newer save from another client.
+#[uniffi::export(default(metadata = None))]
 pub fn save_item(
     item_id: i64,
     note: String,
     images: Vec<NoteImageRecord>,
-) -> Result<(), BackendError> {
+    expected_revision: Option<u64>,
+    metadata: Option<ItemMetadataRecord>,
+) -> Result<u64, BackendError> {
     ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
 
     // Validate note length (prevent excessively large notes that could cause issues)
     const MAX_NOTE_LENGTH: usize = 10_000_000; // 10MB limit
@@ -242,13 +1603,35 @@ pub fn save_item(
     // Sanitize note: remove null bytes and other control characters that could cause issues
     let sanitized_note = sanitize_note_for_storage(&note);
 
+    const MAX_METADATA_LENGTH: usize = 10_000;
+    let metadata = metadata.map(|metadata| ItemMetadata {
+        subtitle: sanitize_title(&metadata.subtitle).trim().to_string(),
+        keywords: sanitize_title(&metadata.keywords).trim().to_string(),
+    });
+    if metadata.as_ref().is_some_and(|metadata| {
+        metadata.subtitle.len() > MAX_METADATA_LENGTH
+            || metadata.keywords.len() > MAX_METADATA_LENGTH
+    }) {
+        return Err(BackendError::Validation(
+            "subtitle or keywords exceed maximum length".to_string(),
+        ));
+    }
+
     let image_models: Vec<NoteImage> = images.into_iter().map(NoteImage::from).collect();
-    db::update_item(item_id, &sanitized_note, Some(&image_models)).map_err(map_anyhow)
+    db::update_item(
+        item_id,
+        &sanitized_note,
+        Some(&image_models),
+        metadata.as_ref(),
+        expected_revision,
+    )
+    .map_err(map_anyhow)
 }
 
 #[uniffi::export]
 pub fn rename_item(item_id: i64, title: String) -> Result<(), BackendError> {
     ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
 
     const MAX_TITLE_LENGTH: usize = 10_000; // 10KB limit for title
     let title = sanitize_title(&title);
@@ -260,13 +1643,646 @@ pub fn rename_item(item_id: i64, title: String) -> Result<(), BackendError> {
         ));
     }
 
-    if title.len() > MAX_TITLE_LENGTH {
-        return Err(BackendError::Validation(
-            "title exceeds maximum length".to_string(),
-        ));
-    }
+    if title.len() > MAX_TITLE_LENGTH {
+        return Err(BackendError::Validation(
+            "title exceeds maximum length".to_string(),
+        ));
+    }
+
+    db::rename_item(item_id, title).map_err(map_anyhow)
+}
+
+/// Lists pinned items, then the most recently opened or edited ones, for the
+/// empty-query launcher state.
+#[uniffi::export]
+pub fn list_recent_items(limit: Option<u32>) -> Result<Vec<SearchResultRecord>, BackendError> {
+    let limit = normalize_limit(limit)?;
+    let rows = db::recent_items(i64::from(limit)).map_err(map_anyhow)?;
+    Ok(rows.into_iter().map(SearchResultRecord::from).collect())
+}
+
+/// Every item matching `query` (filter modifiers like `tag:` included; an
+/// empty query lists all) for the browse window, in `sort` order.
+#[uniffi::export]
+pub fn browse_items(
+    query: String,
+    sort: BrowseSortOrder,
+) -> Result<Vec<SearchResultRecord>, BackendError> {
+    let rows = db::browse_items(&query, sort.into()).map_err(map_anyhow)?;
+    Ok(without_locked_content(rows, &locked_item_ids()?)
+        .into_iter()
+        .map(SearchResultRecord::from)
+        .collect())
+}
+
+/// Word counts plus per-day save activity from the item's version history.
+#[uniffi::export]
+pub fn get_item_stats(item_id: i64) -> Result<ItemStatsRecord, BackendError> {
+    ensure_item_id(item_id)?;
+    let stats = db::item_stats(item_id).map_err(map_anyhow)?;
+    Ok(stats.into())
+}
+
+/// Counts a launcher search the user acted on, for `get_usage_stats`.
+#[uniffi::export]
+pub fn record_search() {
+    db::record_search();
+}
+
+/// Library totals, searches per day over the last `days` days and the
+/// `top_limit` most opened items.
+#[uniffi::export]
+pub fn get_usage_stats(days: u32, top_limit: u32) -> Result<UsageStatsRecord, BackendError> {
+    let stats = db::usage_stats(days.clamp(1, 366), top_limit as usize).map_err(map_anyhow)?;
+    Ok(stats.into())
+}
+
+/// Recorded versions of an item, oldest first.
+#[uniffi::export]
+pub fn list_item_versions(item_id: i64) -> Result<Vec<NoteVersionRecord>, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    let versions = db::item_versions(item_id).map_err(map_anyhow)?;
+    Ok(versions.into_iter().map(NoteVersionRecord::from).collect())
+}
+
+/// Plain text of one recorded version, e.g. to copy text back from it.
+#[uniffi::export]
+pub fn get_item_version_text(item_id: i64, index: u32) -> Result<String, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    db::item_version_text(item_id, index as usize).map_err(map_anyhow)
+}
+
+/// Word-level diff from version `old_index` to version `new_index`.
+#[uniffi::export]
+pub fn diff_item_versions(
+    item_id: i64,
+    old_index: u32,
+    new_index: u32,
+) -> Result<Vec<DiffSegmentRecord>, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    let segments = db::diff_item_versions(item_id, old_index as usize, new_index as usize)
+        .map_err(map_anyhow)?;
+    Ok(segments.into_iter().map(DiffSegmentRecord::from).collect())
+}
+
+/// Merges the editor's text with a version saved elsewhere since `base`,
+/// the note both started from.
+#[uniffi::export]
+pub fn merge_note_edits(base: String, ours: String, theirs: String) -> MergedNoteRecord {
+    let merged = merge::three_way_merge(&base, &ours, &theirs, "this editor", "saved elsewhere");
+    MergedNoteRecord {
+        text: merged.text,
+        conflict_count: merged.conflicts as u32,
+    }
+}
+
+/// The headings of unsaved note text for the editor's outline sidebar.
+#[uniffi::export]
+pub fn note_outline(note: String) -> Vec<OutlineEntryRecord> {
+    markdown_preview::outline(&note)
+        .into_iter()
+        .map(OutlineEntryRecord::from)
+        .collect()
+}
+
+/// The editor preview's blocks for unsaved note text.
+#[uniffi::export]
+pub fn note_preview_blocks(note: String) -> Vec<PreviewBlockRecord> {
+    markdown_preview::preview_blocks(&note)
+        .into_iter()
+        .map(PreviewBlockRecord::from)
+        .collect()
+}
+
+/// The `#tag`s in note text with their UTF-16 ranges, for the editor's tag chips.
+#[uniffi::export]
+pub fn note_hashtags(text: String) -> Vec<HashtagRecord> {
+    let mut hashtags = Vec::new();
+    let mut byte_offset = 0;
+    let mut utf16_offset = 0;
+    for (range, tag) in tags::hashtag_spans(&text) {
+        utf16_offset += text[byte_offset..range.start].encode_utf16().count() as u32;
+        byte_offset = range.start;
+        hashtags.push(HashtagRecord {
+            range: TextRangeRecord {
+                start: utf16_offset,
+                length: text[range].encode_utf16().count() as u32,
+            },
+            tag,
+        });
+    }
+    hashtags
+}
+
+/// Launcher query that lists the items tagged `tag`.
+#[uniffi::export]
+pub fn tag_search_query(tag: String) -> String {
+    format!(
+        "{}{}",
+        router::QueryPrefix::Tag.prefix(),
+        tags::normalize_tag(&tag)
+    )
+}
+
+/// Selected note text rewritten by an editor command; style and image tokens
+/// are kept.
+#[uniffi::export]
+pub fn transform_note_text(text: String, transform: TextTransformKind) -> String {
+    text_transform::transform_text(&text, transform.into())
+}
+
+/// What Return does at the editor's caret, given its line up to the caret;
+/// `None` when the line isn't a Markdown list item or checkbox.
+#[uniffi::export]
+pub fn continue_markdown_list(line_before_caret: String) -> Option<ListContinuationRecord> {
+    markdown_input::continue_list(&line_before_caret).map(|continuation| match continuation {
+        ListContinuation::NextItem(prefix) => ListContinuationRecord {
+            prefix,
+            ends_list: false,
+        },
+        ListContinuation::EndList => ListContinuationRecord {
+            prefix: String::new(),
+            ends_list: true,
+        },
+    })
+}
+
+/// How to wrap the selected text in `marker` (`*`, `**`, `_` or a backtick);
+/// with `toggle`, text already wrapped in it is unwrapped instead.
+#[uniffi::export]
+pub fn wrap_markdown_selection(
+    selected: String,
+    marker: String,
+    toggle: bool,
+) -> Result<SelectionWrapRecord, BackendError> {
+    if !markdown_input::WRAP_MARKERS.contains(&marker.as_str()) {
+        return Err(BackendError::Validation(format!(
+            "marker must be one of {}",
+            markdown_input::WRAP_MARKERS.join(" ")
+        )));
+    }
+    Ok(
+        match markdown_input::wrap_selection(&selected, &marker, toggle) {
+            SelectionEdit::Wrap { open, close } => SelectionWrapRecord {
+                open,
+                close,
+                unwrap_length: 0,
+            },
+            SelectionEdit::Unwrap(length) => SelectionWrapRecord {
+                open: String::new(),
+                close: String::new(),
+                unwrap_length: length as u32,
+            },
+        },
+    )
+}
+
+/// Parses a CSS-like preview style file (`body`, `h1`–`h3` and `code` rules).
+#[uniffi::export]
+pub fn parse_preview_style(source: String) -> PreviewStyleRecord {
+    let (style, warnings) = preview_style::parse_preview_style(&source);
+    PreviewStyleRecord {
+        font_family: style.font_family,
+        font_size: style.font_size,
+        max_width: style.max_width,
+        heading_sizes: style.heading_sizes.to_vec(),
+        code_font_family: style.code_font_family,
+        code_color: style.code_color,
+        code_background: style.code_background,
+        warnings,
+    }
+}
+
+/// The Markdown tables in note text with their cells' UTF-16 ranges, for
+/// lining up the editor's table columns.
+#[uniffi::export]
+pub fn note_table_layouts(text: String) -> Vec<TableLayoutRecord> {
+    let mut byte_offset = 0;
+    let mut utf16_offset = 0;
+    let mut utf16_range = |range: std::ops::Range<usize>| {
+        utf16_offset += text[byte_offset..range.start].encode_utf16().count() as u32;
+        byte_offset = range.start;
+        TextRangeRecord {
+            start: utf16_offset,
+            length: text[range].encode_utf16().count() as u32,
+        }
+    };
+    markdown_table::table_cells(&text)
+        .into_iter()
+        .map(|rows| TableLayoutRecord {
+            rows: rows
+                .into_iter()
+                .map(|cells| cells.into_iter().map(&mut utf16_range).collect())
+                .collect(),
+        })
+        .collect()
+}
+
+/// Runs a table command in the table made of `lines`, with the caret at UTF-16
+/// offset `caret` of line `row`; `None` when that line isn't a table row.
+#[uniffi::export]
+pub fn edit_markdown_table(
+    lines: Vec<String>,
+    row: u32,
+    caret: u32,
+    command: TableCommandKind,
+) -> Option<TableEditRecord> {
+    let row = row as usize;
+    let line = lines.get(row)?;
+    let caret = utf16_to_byte_offset(line, caret as usize);
+    let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+    let edit = markdown_table::edit_table(&lines, row, caret, command.into())?;
+    let line = &edit.lines[edit.row];
+    let selection = TextRangeRecord {
+        start: line[..edit.selection.start].encode_utf16().count() as u32,
+        length: line[edit.selection.clone()].encode_utf16().count() as u32,
+    };
+    Some(TableEditRecord {
+        row: edit.row as u32,
+        lines: edit.lines,
+        selection,
+    })
+}
+
+/// The byte offset of UTF-16 offset `offset` in `text`, clamped to its end
+/// and moved back to a character boundary.
+fn utf16_to_byte_offset(text: &str, offset: usize) -> usize {
+    let mut utf16 = 0;
+    for (index, character) in text.char_indices() {
+        utf16 += character.len_utf16();
+        if utf16 > offset {
+            return index;
+        }
+    }
+    text.len()
+}
+
+/// Unsaved note text with image `image_index` moved before line `target_line`,
+/// for dragging an image in the editor.
+#[uniffi::export]
+pub fn move_note_image_to_line(
+    note: String,
+    image_index: u32,
+    target_line: u32,
+) -> Result<String, BackendError> {
+    vault::move_inline_image_to_line(&note, image_index as usize, target_line as usize)
+        .ok_or_else(|| BackendError::Validation(format!("note has no image {}", image_index + 1)))
+}
+
+#[uniffi::export]
+pub fn mark_item_opened(item_id: i64) -> Result<(), BackendError> {
+    ensure_item_id(item_id)?;
+    db::mark_item_opened(item_id).map_err(map_anyhow)
+}
+
+/// Secure items are never written to the JSON storage folder.
+#[uniffi::export]
+pub fn set_item_secure(item_id: i64, secure: bool) -> Result<(), BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    db::set_item_secure(item_id, secure).map_err(map_anyhow)
+}
+
+/// Whether secure items are locked: a lock key is enrolled and hasn't been
+/// presented since launch or the last `lock`.
+#[uniffi::export]
+pub fn is_locked() -> Result<bool, BackendError> {
+    Ok(has_lock_key()? && !app_lock::is_unlocked())
+}
+
+/// Whether a lock key is enrolled.
+#[uniffi::export]
+pub fn has_lock_key() -> Result<bool, BackendError> {
+    Ok(db::load_app_lock_verifier().map_err(map_anyhow)?.is_some())
+}
+
+/// Unlocks secure items until `lock` is called or the app quits.
+#[uniffi::export]
+pub fn unlock_with_key(key: Vec<u8>) -> Result<(), BackendError> {
+    let verifier = db::load_app_lock_verifier()
+        .map_err(map_anyhow)?
+        .ok_or_else(|| BackendError::NotFound("no lock key is set".to_string()))?;
+    if !app_lock::verify(&verifier, &key) {
+        return Err(BackendError::Validation(
+            "the key does not unlock secure items".to_string(),
+        ));
+    }
+    app_lock::set_unlocked(true);
+    Ok(())
+}
+
+#[uniffi::export]
+pub fn lock() {
+    app_lock::set_unlocked(false);
+}
+
+/// Enrolls `key` as the lock key, replacing the current one. Secure items stay
+/// unlocked until the next `lock`.
+#[uniffi::export]
+pub fn set_lock_key(key: Vec<u8>) -> Result<(), BackendError> {
+    if key.len() < app_lock::MIN_KEY_LEN {
+        return Err(BackendError::Validation(format!(
+            "the lock key must be at least {} bytes",
+            app_lock::MIN_KEY_LEN
+        )));
+    }
+    if is_locked()? {
+        return Err(BackendError::Locked(
+            "unlock before changing the lock key".to_string(),
+        ));
+    }
+    let verifier = app_lock::make_verifier(&key).map_err(map_anyhow)?;
+    db::save_app_lock_verifier(Some(&verifier)).map_err(map_anyhow)?;
+    app_lock::set_unlocked(true);
+    Ok(())
+}
+
+/// Turns the lock off, which needs the app to be unlocked.
+#[uniffi::export]
+pub fn remove_lock_key() -> Result<(), BackendError> {
+    if is_locked()? {
+        return Err(BackendError::Locked(
+            "unlock before removing the lock key".to_string(),
+        ));
+    }
+    db::save_app_lock_verifier(None).map_err(map_anyhow)?;
+    app_lock::set_unlocked(false);
+    Ok(())
+}
+
+/// Image count and size limits enforced when saving a note.
+#[uniffi::export]
+pub fn load_image_limits() -> Result<ImageLimitsRecord, BackendError> {
+    Ok(db::load_image_limits().map_err(map_anyhow)?.into())
+}
+
+/// Overrides the image limits; `None` restores a default. Values are clamped to
+/// the supported range and the limits in effect are returned.
+#[uniffi::export]
+pub fn save_image_limits(
+    max_images_per_note: Option<u32>,
+    max_image_bytes: Option<u64>,
+) -> Result<ImageLimitsRecord, BackendError> {
+    let limits = db::save_image_limits(
+        max_images_per_note.map(|count| count as usize),
+        max_image_bytes.map(|bytes| usize::try_from(bytes).unwrap_or(usize::MAX)),
+    )
+    .map_err(map_anyhow)?;
+    Ok(limits.into())
+}
+
+/// All stored note images, largest first, for background re-encoding.
+#[uniffi::export]
+pub fn list_stored_images() -> Result<Vec<StoredImageRecord>, BackendError> {
+    let images = db::list_stored_images().map_err(map_anyhow)?;
+    Ok(images.into_iter().map(StoredImageRecord::from).collect())
+}
+
+/// Replaces stored images with re-encoded bytes and returns how many were applied.
+#[uniffi::export]
+pub fn replace_stored_images(
+    replacements: Vec<ImageReplacementRecord>,
+) -> Result<u32, BackendError> {
+    let replacements: Vec<ImageReplacement> = replacements
+        .into_iter()
+        .map(ImageReplacement::from)
+        .collect();
+    let replaced = db::replace_stored_images(&replacements).map_err(map_anyhow)?;
+    Ok(replaced as u32)
+}
+
+/// Images whose text hasn't been recognized yet, for background OCR.
+#[uniffi::export]
+pub fn list_images_without_text(limit: u32) -> Result<Vec<StoredImageRecord>, BackendError> {
+    let images = db::list_images_without_text(limit as usize).map_err(map_anyhow)?;
+    Ok(images.into_iter().map(StoredImageRecord::from).collect())
+}
+
+/// Stores text recognized in images, searched like note text, and returns how
+/// many images were updated.
+#[uniffi::export]
+pub fn save_image_texts(texts: Vec<ImageTextRecord>) -> Result<u32, BackendError> {
+    let texts: Vec<ImageText> = texts.into_iter().map(ImageText::from).collect();
+    let updated = db::save_image_texts(&texts).map_err(map_anyhow)?;
+    Ok(updated as u32)
+}
+
+/// Picks the images in `folder` that still fit into a note holding
+/// `existing_image_count` images; the editor reads and inserts them.
+#[uniffi::export]
+pub fn plan_image_folder_import(
+    folder: String,
+    existing_image_count: u32,
+) -> Result<ImageImportPlanRecord, BackendError> {
+    let folder = PathBuf::from(folder.trim());
+    if !folder.is_dir() {
+        return Err(BackendError::Validation(
+            "import folder must be an existing directory".to_string(),
+        ));
+    }
+    let limits = db::load_image_limits().map_err(map_anyhow)?;
+    let plan = image_import::plan_folder_import(&folder, existing_image_count as usize, limits)
+        .map_err(map_anyhow)?;
+    Ok(plan.into())
+}
+
+/// Adds every page of a Notion HTML export (the ZIP or its unpacked folder)
+/// as a new item.
+#[uniffi::export]
+pub fn import_notion_export(path: String) -> Result<NotionImportRecord, BackendError> {
+    let path = PathBuf::from(path.trim());
+    if !path.exists() {
+        return Err(BackendError::Validation(
+            "Notion export must be an existing folder or ZIP file".to_string(),
+        ));
+    }
+    let limits = db::load_image_limits().map_err(map_anyhow)?;
+    let import = notion_import::read_notion_export(&path, limits).map_err(map_anyhow)?;
+    let ids = db::import_notes(&import.notes, "Notion export").map_err(map_anyhow)?;
+    Ok(NotionImportRecord {
+        imported_count: ids.len() as u32,
+        skipped_image_count: import.skipped_images as u32,
+    })
+}
+
+/// Fetches the page at `url` and adds its article as a note tagged
+/// `#readlater`. Unreachable pages are queued for `retry_read_later_queue`;
+/// pages that can't be saved (like a 404 or a PDF) are a validation error.
+#[uniffi::export]
+pub fn save_read_later_article(url: String) -> Result<ReadLaterRecord, BackendError> {
+    let url = read_later::normalize_url(&url)
+        .ok_or_else(|| BackendError::Validation(format!("not a web address: {}", url.trim())))?;
+    let limits = db::load_image_limits().map_err(map_anyhow)?;
+    match read_later::fetch_article(&url, limits) {
+        Ok(article) => {
+            let ids =
+                db::import_notes(std::slice::from_ref(&article.note), &url).map_err(map_anyhow)?;
+            db::remove_queued_read_later_article(&url).map_err(map_anyhow)?;
+            Ok(ReadLaterRecord {
+                item_id: ids.first().copied(),
+                queued: false,
+                message: None,
+                skipped_image_count: article.skipped_images as u32,
+            })
+        }
+        Err(FetchError::Unreachable(reason)) => {
+            db::queue_read_later_article(&url, &reason).map_err(map_anyhow)?;
+            Ok(ReadLaterRecord {
+                item_id: None,
+                queued: true,
+                message: Some(reason),
+                skipped_image_count: 0,
+            })
+        }
+        Err(FetchError::Rejected(reason)) => Err(BackendError::Validation(reason)),
+    }
+}
+
+/// A Markdown link to the web page at `url`, named after its title, for
+/// links pasted into the editor. Pages that can't be fetched are an error.
+#[uniffi::export]
+pub fn fetch_link_markdown(url: String) -> Result<String, BackendError> {
+    let url = url.trim();
+    if read_later::normalize_url(url).as_deref() != Some(url) {
+        return Err(BackendError::Validation(format!(
+            "not a web address: {url}"
+        )));
+    }
+    read_later::fetch_link_markdown(url).map_err(|err| BackendError::Validation(err.to_string()))
+}
+
+/// Fetches the queued read-later pages again and returns how many were
+/// saved. Pages that turn out unsavable leave the queue.
+#[uniffi::export]
+pub fn retry_read_later_queue() -> Result<u32, BackendError> {
+    let queue = db::load_read_later_queue().map_err(map_anyhow)?;
+    if queue.is_empty() {
+        return Ok(0);
+    }
+    let limits = db::load_image_limits().map_err(map_anyhow)?;
+    let mut saved = 0;
+    for queued in queue {
+        match read_later::fetch_article(&queued.url, limits) {
+            Ok(article) => {
+                db::import_notes(std::slice::from_ref(&article.note), &queued.url)
+                    .map_err(map_anyhow)?;
+                db::remove_queued_read_later_article(&queued.url).map_err(map_anyhow)?;
+                saved += 1;
+            }
+            Err(FetchError::Unreachable(reason)) => {
+                db::queue_read_later_article(&queued.url, &reason).map_err(map_anyhow)?;
+            }
+            Err(FetchError::Rejected(reason)) => {
+                log_error(
+                    format_args!("dropped queued article {}", queued.url),
+                    reason,
+                );
+                db::remove_queued_read_later_article(&queued.url).map_err(map_anyhow)?;
+            }
+        }
+    }
+    Ok(saved)
+}
+
+/// Free space, mirror size and budget alerts for the JSON storage folder.
+#[uniffi::export]
+pub fn get_storage_report(largest_limit: Option<u32>) -> Result<StorageReportRecord, BackendError> {
+    let largest_limit = largest_limit.unwrap_or(10).clamp(1, 100);
+    let report = db::storage_report(largest_limit as usize).map_err(map_anyhow)?;
+    Ok(report.into())
+}
+
+/// Sets the mirror size budget in bytes; `None` or 0 removes it.
+#[uniffi::export]
+pub fn save_storage_budget(budget_bytes: Option<u64>) -> Result<(), BackendError> {
+    db::save_storage_budget(budget_bytes).map_err(map_anyhow)
+}
+
+/// Tags whose items are kept out of the JSON storage folder.
+#[uniffi::export]
+pub fn load_mirror_excluded_tags() -> Result<Vec<String>, BackendError> {
+    db::load_mirror_excluded_tags().map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn save_mirror_excluded_tags(tags: Vec<String>) -> Result<(), BackendError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tags::normalize_tag(&tag);
+        if tag.is_empty() || normalized.contains(&tag) {
+            continue;
+        }
+        if !tags::is_valid_tag(&tag) {
+            return Err(BackendError::Validation(format!("invalid tag: {tag}")));
+        }
+        normalized.push(tag);
+    }
+    db::save_mirror_excluded_tags(&normalized).map_err(map_anyhow)
+}
+
+/// Pins or unpins an item; pinned items are listed first among matching results.
+#[uniffi::export]
+pub fn set_item_pinned(item_id: i64, pinned: bool) -> Result<(), BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    db::set_item_pinned(item_id, pinned).map_err(map_anyhow)
+}
+
+/// Starts a timer on an item, stopping the timer running on another item.
+#[uniffi::export]
+pub fn start_timer(item_id: i64) -> Result<(), BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    db::start_timer(item_id).map_err(map_anyhow)
+}
+
+/// Stops the running timer and returns the seconds it ran, or 0 when no timer
+/// was running.
+#[uniffi::export]
+pub fn stop_timer() -> Result<u64, BackendError> {
+    let stopped = db::stop_timer().map_err(map_anyhow)?;
+    Ok(stopped.map(|(_, seconds)| seconds).unwrap_or(0))
+}
+
+#[uniffi::export]
+pub fn running_timer() -> Result<Option<RunningTimerRecord>, BackendError> {
+    let timer = db::running_timer().map_err(map_anyhow)?;
+    Ok(timer.map(RunningTimerRecord::from))
+}
+
+/// Total seconds tracked on an item, including its running timer.
+#[uniffi::export]
+pub fn tracked_seconds(item_id: i64) -> Result<u64, BackendError> {
+    ensure_item_id(item_id)?;
+    db::tracked_seconds(item_id).map_err(map_anyhow)
+}
+
+/// Tracked time per note in the local week (Monday to Sunday) containing a date.
+#[uniffi::export]
+pub fn weekly_time_report(
+    year: i32,
+    month: u32,
+    day: u32,
+) -> Result<TimeReportRecord, BackendError> {
+    let date = report_date(year, month, day)?;
+    let report = db::weekly_time_report(date).map_err(map_anyhow)?;
+    Ok(TimeReportRecord::from(report))
+}
+
+/// Returns the time entries of the week containing a date as CSV, one row per
+/// timer run, for invoicing.
+#[uniffi::export]
+pub fn export_time_entries_csv(year: i32, month: u32, day: u32) -> Result<String, BackendError> {
+    let date = report_date(year, month, day)?;
+    db::export_time_entries_csv(date).map_err(map_anyhow)
+}
 
-    db::rename_item(item_id, title).map_err(map_anyhow)
+fn report_date(year: i32, month: u32, day: u32) -> Result<chrono::NaiveDate, BackendError> {
+    chrono::NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| BackendError::Validation("invalid report date".to_string()))
 }
 
 /// Sanitize note text by removing problematic characters
@@ -292,7 +2308,7 @@ fn sanitize_note_for_storage(note: &str) -> String {
 
 #[uniffi::export]
 pub fn export_items() -> Result<Vec<ExportItemRecord>, BackendError> {
-    let items = db::export_items_snapshot().map_err(map_anyhow)?;
+    let items = db::export_items_snapshot(&locked_item_ids()?).map_err(map_anyhow)?;
     Ok(items.into_iter().map(ExportItemRecord::from).collect())
 }
 
@@ -313,6 +2329,167 @@ pub fn save_hotkey(hotkey: String) -> Result<(), BackendError> {
     db::save_hotkey_setting(hotkey).map_err(map_anyhow)
 }
 
+/// Whether launcher queries match items containing any term rather than all.
+#[uniffi::export]
+pub fn load_search_match_any() -> Result<bool, BackendError> {
+    db::load_search_match_any_setting().map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn save_search_match_any(match_any: bool) -> Result<(), BackendError> {
+    db::save_search_match_any_setting(match_any).map_err(map_anyhow)
+}
+
+/// The Rhai script that reorders launcher results; empty for the built-in order.
+#[uniffi::export]
+pub fn load_ranking_script() -> Result<String, BackendError> {
+    db::load_ranking_script().map_err(map_anyhow)
+}
+
+/// Rejects a script that doesn't compile and keeps the previous one.
+#[uniffi::export]
+pub fn save_ranking_script(source: String) -> Result<(), BackendError> {
+    db::save_ranking_script(&source).map_err(map_anyhow)
+}
+
+/// The GitHub and GitLab accounts behind the "gh " and "gl " prefixes. Their
+/// tokens are empty: the app keeps them in the Keychain and passes them in.
+#[uniffi::export]
+pub fn load_forge_accounts() -> Result<Vec<ForgeAccountRecord>, BackendError> {
+    Ok(db::load_forge_accounts()
+        .map_err(map_anyhow)?
+        .into_iter()
+        .map(ForgeAccountRecord::from)
+        .collect())
+}
+
+/// Saves the accounts, at most one per forge, and updates the launcher's
+/// prefixes to match.
+#[uniffi::export]
+pub fn save_forge_accounts(accounts: Vec<ForgeAccountRecord>) -> Result<(), BackendError> {
+    let accounts = accounts.into_iter().map(ForgeAccount::from).collect();
+    let saved = db::save_forge_accounts(accounts).map_err(map_anyhow)?;
+    register_forge_providers(&saved);
+    Ok(())
+}
+
+/// Turns on the prefixes of saved accounts with the tokens the app kept for
+/// them, without saving anything; call after `warm_up_store`.
+#[uniffi::export]
+pub fn connect_forge_accounts(accounts: Vec<ForgeAccountRecord>) -> Result<(), BackendError> {
+    if db::is_launcher_only() {
+        return Ok(());
+    }
+    let accounts = accounts
+        .into_iter()
+        .map(|account| ForgeAccount::from(account).normalized())
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map_err(map_anyhow)?;
+    register_forge_providers(&accounts);
+    Ok(())
+}
+
+fn register_forge_providers(accounts: &[ForgeAccount]) {
+    for kind in ForgeKind::ALL {
+        let account = accounts.iter().find(|account| account.kind == kind);
+        let cache_path = db::forge_cache_path(kind);
+        match (account, cache_path) {
+            (Some(account), Ok(cache_path)) => {
+                let provider = ForgeProvider::new(account.clone(), cache_path);
+                if let Err(err) = providers::register_provider(provider) {
+                    log_error(
+                        format_args!("failed to add the {} provider", kind.provider_id()),
+                        err,
+                    );
+                }
+            }
+            (Some(_), Err(err)) => {
+                log_error(
+                    format_args!("failed to add the {} provider", kind.provider_id()),
+                    err,
+                );
+            }
+            (None, _) => {
+                providers::unregister_provider(kind.provider_id());
+            }
+        }
+    }
+}
+
+/// The Jira and Linear workspaces behind the "jira " and "lin " prefixes.
+/// Their tokens are empty, as with forge accounts.
+#[uniffi::export]
+pub fn load_tracker_accounts() -> Result<Vec<TrackerAccountRecord>, BackendError> {
+    Ok(db::load_tracker_accounts()
+        .map_err(map_anyhow)?
+        .into_iter()
+        .map(TrackerAccountRecord::from)
+        .collect())
+}
+
+/// Saves the accounts, at most one per tracker, and updates the launcher's
+/// prefixes to match.
+#[uniffi::export]
+pub fn save_tracker_accounts(accounts: Vec<TrackerAccountRecord>) -> Result<(), BackendError> {
+    let accounts = accounts.into_iter().map(TrackerAccount::from).collect();
+    let saved = db::save_tracker_accounts(accounts).map_err(map_anyhow)?;
+    register_tracker_providers(&saved);
+    Ok(())
+}
+
+/// Turns on the prefixes of saved workspaces with the tokens the app kept
+/// for them, like `connect_forge_accounts`.
+#[uniffi::export]
+pub fn connect_tracker_accounts(accounts: Vec<TrackerAccountRecord>) -> Result<(), BackendError> {
+    if db::is_launcher_only() {
+        return Ok(());
+    }
+    let accounts = accounts
+        .into_iter()
+        .map(|account| TrackerAccount::from(account).normalized())
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map_err(map_anyhow)?;
+    register_tracker_providers(&accounts);
+    Ok(())
+}
+
+fn register_tracker_providers(accounts: &[TrackerAccount]) {
+    for kind in TrackerKind::ALL {
+        match accounts.iter().find(|account| account.kind == kind) {
+            Some(account) => {
+                let provider = TrackerProvider::new(account.clone());
+                if let Err(err) = providers::register_provider(provider) {
+                    log_error(
+                        format_args!("failed to add the {} provider", kind.provider_id()),
+                        err,
+                    );
+                }
+            }
+            None => {
+                providers::unregister_provider(kind.provider_id());
+            }
+        }
+    }
+}
+
+/// Whether the storage folder is kept as an Obsidian vault of Markdown notes
+/// instead of JSON files.
+#[uniffi::export]
+pub fn load_obsidian_vault_mode() -> Result<bool, BackendError> {
+    db::load_obsidian_vault_setting().map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn save_obsidian_vault_mode(enabled: bool) -> Result<(), BackendError> {
+    db::save_obsidian_vault_setting(enabled).map_err(map_anyhow)
+}
+
+/// Imports notes edited or added in the vault; returns how many items changed.
+#[uniffi::export]
+pub fn import_obsidian_vault_changes() -> Result<u32, BackendError> {
+    db::sync_obsidian_vault().map_err(map_anyhow)
+}
+
 #[uniffi::export]
 pub fn load_json_storage_path() -> Result<String, BackendError> {
     db::load_json_storage_path_setting().map_err(map_anyhow)
@@ -323,15 +2500,61 @@ pub fn save_json_storage_path(path: String) -> Result<(), BackendError> {
     db::save_json_storage_path_setting(path.trim()).map_err(map_anyhow)
 }
 
+/// Deletes an item and returns the archive key that `restore_deleted_item` takes
+/// to undo it.
 #[uniffi::export]
-pub fn delete_item(item_id: i64) -> Result<(), BackendError> {
+pub fn delete_item(item_id: i64) -> Result<String, BackendError> {
     ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
     db::delete_item(item_id).map_err(map_anyhow)
 }
 
+/// Deletes several items at once; nothing is deleted if one id is unknown.
+#[uniffi::export]
+pub fn delete_items(item_ids: Vec<i64>) -> Result<(), BackendError> {
+    let item_ids = normalize_item_ids(item_ids)?;
+    for &item_id in &item_ids {
+        ensure_item_unlocked(item_id)?;
+    }
+    db::delete_items(&item_ids).map_err(map_anyhow)?;
+    Ok(())
+}
+
+/// Adds `#tag` to each item's note and returns how many notes changed.
+#[uniffi::export]
+pub fn tag_items(item_ids: Vec<i64>, tag: String) -> Result<u32, BackendError> {
+    let item_ids = normalize_item_ids(item_ids)?;
+    for &item_id in &item_ids {
+        ensure_item_unlocked(item_id)?;
+    }
+    let tag = tags::normalize_tag(&tag);
+    if !tags::is_valid_tag(&tag) {
+        return Err(BackendError::Validation(format!("invalid tag: {tag}")));
+    }
+    let changed = db::add_tag_to_items(&item_ids, &tag).map_err(map_anyhow)?;
+    Ok(u32::try_from(changed).unwrap_or(u32::MAX))
+}
+
+/// Returns every item as a CSV inventory (no note bodies) for spreadsheets.
+/// While locked, secure items are listed by title only.
+#[uniffi::export]
+pub fn export_items_csv() -> Result<String, BackendError> {
+    db::export_items_csv(&locked_item_ids()?).map_err(map_anyhow)
+}
+
+/// Returns the selected items as pretty-printed JSON for saving to a file.
+#[uniffi::export]
+pub fn export_items_json(item_ids: Vec<i64>) -> Result<String, BackendError> {
+    let item_ids = normalize_item_ids(item_ids)?;
+    for &item_id in &item_ids {
+        ensure_item_unlocked(item_id)?;
+    }
+    db::export_items_json(&item_ids).map_err(map_anyhow)
+}
+
 #[uniffi::export]
 pub fn list_deleted_items(limit: Option<u32>) -> Result<Vec<DeletedItemRecord>, BackendError> {
-    let limit = limit.unwrap_or(50).max(1).min(256);
+    let limit = limit.unwrap_or(50).clamp(1, 256);
     let items = db::list_deleted_items(i64::from(limit)).map_err(map_anyhow)?;
     Ok(items.into_iter().map(DeletedItemRecord::from).collect())
 }
@@ -344,6 +2567,7 @@ pub fn restore_deleted_item(archive_key: String) -> Result<i64, BackendError> {
             "archive_key must not be empty".to_string(),
         ));
     }
+    ensure_archive_unlocked(archive_key)?;
     db::restore_deleted_item(archive_key).map_err(map_anyhow)
 }
 
@@ -355,6 +2579,7 @@ pub fn permanently_delete_deleted_item(archive_key: String) -> Result<(), Backen
             "archive_key must not be empty".to_string(),
         ));
     }
+    ensure_archive_unlocked(archive_key)?;
     db::permanently_delete_deleted_item(archive_key).map_err(map_anyhow)
 }
 
@@ -369,15 +2594,395 @@ pub fn get_deleted_item_preview(
         ));
     }
     let preview = db::get_deleted_item_preview(archive_key).map_err(map_anyhow)?;
+    check_item_unlocked(preview.id, is_locked()?, preview.secure)?;
     Ok(DeletedItemPreviewRecord::from(preview))
 }
 
+/// Deleted secure items can't be previewed, restored or purged while locked.
+fn ensure_archive_unlocked(archive_key: &str) -> Result<(), BackendError> {
+    if !is_locked()? {
+        return Ok(());
+    }
+    let preview = db::get_deleted_item_preview(archive_key).map_err(map_anyhow)?;
+    check_item_unlocked(preview.id, true, preview.secure)
+}
+
 #[uniffi::export]
 pub fn get_item_json_path(item_id: i64) -> Result<String, BackendError> {
     ensure_item_id(item_id)?;
     db::get_item_json_path(item_id).map_err(map_anyhow)
 }
 
+/// The `alfredalt://item/<id>` link that opens the item.
+#[uniffi::export]
+pub fn get_item_deep_link(item_id: i64) -> Result<String, BackendError> {
+    ensure_item_id(item_id)?;
+    Ok(deep_link::item_link(item_id))
+}
+
+/// The item an `alfredalt://` link opens, or `None` for other links.
+#[uniffi::export]
+pub fn item_id_for_deep_link(link: String) -> Option<i64> {
+    deep_link::item_id_from_link(&link)
+}
+
+/// The `alfredalt://note/<title>` link a `[[title]]` note link opens.
+#[uniffi::export]
+pub fn note_title_deep_link(title: String) -> String {
+    deep_link::note_title_link(&title)
+}
+
+/// The note title of an `alfredalt://note/<title>` link, or `None` for other
+/// links.
+#[uniffi::export]
+pub fn note_title_for_deep_link(link: String) -> Option<String> {
+    deep_link::note_title_from_link(&link)
+}
+
+/// The note titled `title`, ignoring case, or `None` when there is none.
+#[uniffi::export]
+pub fn item_id_for_note_title(title: String) -> Result<Option<i64>, BackendError> {
+    db::find_item_by_title(&title).map_err(map_anyhow)
+}
+
+/// The title typed so far when the editor's caret is inside an unclosed
+/// `[[`, given the note text before the caret.
+#[uniffi::export]
+pub fn pending_note_link_query(text_before_caret: String) -> Option<String> {
+    note_links::pending_link_query(&text_before_caret).map(str::to_string)
+}
+
+/// Note titles matching what was typed after `[[`, best match first.
+#[uniffi::export]
+pub fn suggest_note_link_titles(
+    query: String,
+    limit: u32,
+) -> Result<Vec<NoteTitleRecord>, BackendError> {
+    let titles = db::suggest_note_titles(&query, limit as usize).map_err(map_anyhow)?;
+    Ok(titles
+        .into_iter()
+        .map(|(item_id, title)| NoteTitleRecord { item_id, title })
+        .collect())
+}
+
+/// Returns the note of an item as plain text, without inline images or style markup.
+#[uniffi::export]
+pub fn get_item_plain_text(item_id: i64) -> Result<String, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    db::get_item_plain_text(item_id).map_err(map_anyhow)
+}
+
+/// Returns the item as a Markdown document plus a file name to save it under.
+#[uniffi::export]
+pub fn get_item_markdown(item_id: i64) -> Result<ItemMarkdownRecord, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    db::get_item_markdown(item_id)
+        .map(ItemMarkdownRecord::from)
+        .map_err(map_anyhow)
+}
+
+/// Returns the bytes of one image of an item, e.g. a result row thumbnail.
+#[uniffi::export]
+pub fn get_item_image(item_id: i64, image_key: String) -> Result<Vec<u8>, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    db::get_item_image(item_id, &image_key).map_err(map_anyhow)
+}
+
+/// Image keys of an item for the gallery: in note order, then any images the
+/// note no longer references.
+#[uniffi::export]
+pub fn list_item_image_keys(item_id: i64) -> Result<Vec<String>, BackendError> {
+    ensure_item_id(item_id)?;
+    db::list_item_image_keys(item_id).map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn list_triggers() -> Result<Vec<TriggerRecord>, BackendError> {
+    let triggers = db::load_triggers().map_err(map_anyhow)?;
+    Ok(triggers.into_iter().map(TriggerRecord::from).collect())
+}
+
+/// Creates (empty `id`) or replaces a trigger and returns its id.
+#[uniffi::export]
+pub fn save_trigger(trigger: TriggerRecord) -> Result<String, BackendError> {
+    let name = trigger.name.trim();
+    if name.is_empty() {
+        return Err(BackendError::Validation(
+            "trigger name must not be empty".to_string(),
+        ));
+    }
+
+    let target = trigger.target.trim();
+    if target.is_empty() {
+        return Err(BackendError::Validation(
+            "trigger target must not be empty".to_string(),
+        ));
+    }
+
+    let action = match trigger.action_kind {
+        TriggerActionKind::RunScript => TriggerAction::RunScript(target.to_string()),
+        TriggerActionKind::PostUrl => {
+            if !(target.starts_with("http://") || target.starts_with("https://")) {
+                return Err(BackendError::Validation(
+                    "trigger URL must start with http:// or https://".to_string(),
+                ));
+            }
+            TriggerAction::PostUrl(target.to_string())
+        }
+    };
+
+    let tag = trigger
+        .tag
+        .as_deref()
+        .map(tags::normalize_tag)
+        .filter(|tag| !tag.is_empty());
+
+    db::save_trigger(&Trigger {
+        id: trigger.id.trim().to_string(),
+        name: name.to_string(),
+        event: trigger.event.into(),
+        tag,
+        action,
+        enabled: trigger.enabled,
+    })
+    .map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn delete_trigger(trigger_id: String) -> Result<(), BackendError> {
+    let trigger_id = trigger_id.trim();
+    if trigger_id.is_empty() {
+        return Err(BackendError::Validation(
+            "trigger_id must not be empty".to_string(),
+        ));
+    }
+    db::delete_trigger(trigger_id).map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn list_trigger_log(
+    trigger_id: String,
+    limit: Option<u32>,
+) -> Result<Vec<TriggerLogEntryRecord>, BackendError> {
+    let limit = limit.unwrap_or(50).clamp(1, 500);
+    let entries = db::trigger_log(trigger_id.trim(), limit as usize).map_err(map_anyhow)?;
+    Ok(entries
+        .into_iter()
+        .map(TriggerLogEntryRecord::from)
+        .collect())
+}
+
+/// Marks the data changes this process makes from now on in the activity
+/// log; callers that never set it are logged as `uniffi`.
+#[uniffi::export]
+pub fn set_activity_origin(origin: ActivityOriginKind) {
+    activity::set_origin(origin.into());
+}
+
+/// The newest entries of the append-only log of creates, updates, deletes,
+/// imports and exports, newest first, one line each in local time.
+#[uniffi::export]
+pub fn list_activity_lines(limit: Option<u32>) -> Result<Vec<String>, BackendError> {
+    let limit = limit.unwrap_or(50).clamp(1, 10_000);
+    let entries = db::recent_activity(limit as usize).map_err(map_anyhow)?;
+    Ok(entries
+        .iter()
+        .map(|entry| activity::describe(entry, &chrono::Local))
+        .collect())
+}
+
+/// Board column tags in workflow order.
+#[uniffi::export]
+pub fn board_statuses() -> Vec<String> {
+    db::BOARD_STATUS_TAGS
+        .iter()
+        .map(|status| status.to_string())
+        .collect()
+}
+
+#[uniffi::export]
+pub fn list_board_cards() -> Result<Vec<BoardCardRecord>, BackendError> {
+    let cards = db::board_cards().map_err(map_anyhow)?;
+    let locked_ids = locked_item_ids()?;
+    Ok(cards
+        .into_iter()
+        .map(|mut card| {
+            // Like search results, locked cards keep their title only.
+            if locked_ids.contains(&card.id) {
+                card.preview.clear();
+            }
+            BoardCardRecord::from(card)
+        })
+        .collect())
+}
+
+/// Moves an item to the given board column and returns its new revision.
+#[uniffi::export]
+pub fn move_board_card(item_id: i64, status: String) -> Result<u64, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    let status = tags::normalize_tag(&status);
+    if !db::BOARD_STATUS_TAGS.contains(&status.as_str()) {
+        return Err(BackendError::Validation(format!(
+            "status must be one of: {}",
+            db::BOARD_STATUS_TAGS.join(", ")
+        )));
+    }
+    db::set_item_board_status(item_id, &status).map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn list_checklist_entries(item_id: i64) -> Result<Vec<ChecklistEntryRecord>, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    let entries = db::item_checklist(item_id).map_err(map_anyhow)?;
+    Ok(entries
+        .into_iter()
+        .map(ChecklistEntryRecord::from)
+        .collect())
+}
+
+/// Checks or unchecks entry `index` of the item's checklist and returns the
+/// item's new revision.
+#[uniffi::export]
+pub fn toggle_checklist_entry(item_id: i64, index: u32) -> Result<u64, BackendError> {
+    ensure_item_id(item_id)?;
+    ensure_item_unlocked(item_id)?;
+    db::toggle_item_checklist_entry(item_id, index as usize).map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn list_calendar_month(
+    year: i32,
+    month: u32,
+) -> Result<Vec<CalendarEntryRecord>, BackendError> {
+    if !(1..=12).contains(&month) {
+        return Err(BackendError::Validation(
+            "month must be between 1 and 12".to_string(),
+        ));
+    }
+    let entries = db::calendar_month(year, month).map_err(map_anyhow)?;
+    Ok(entries.into_iter().map(CalendarEntryRecord::from).collect())
+}
+
+/// Returns the id of the daily journal entry for a local date, creating it if needed.
+#[uniffi::export]
+pub fn open_journal_entry(year: i32, month: u32, day: u32) -> Result<i64, BackendError> {
+    let date = chrono::NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| BackendError::Validation("invalid journal date".to_string()))?;
+    db::open_or_create_journal_entry(date).map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn list_schedules() -> Result<Vec<ScheduleRecord>, BackendError> {
+    let schedules = db::load_schedules().map_err(map_anyhow)?;
+    Ok(schedules.into_iter().map(ScheduleRecord::from).collect())
+}
+
+/// Creates or updates a recurring note schedule and returns its id.
+#[uniffi::export]
+pub fn save_schedule(schedule: ScheduleRecord) -> Result<String, BackendError> {
+    let name = schedule.name.trim();
+    if name.is_empty() {
+        return Err(BackendError::Validation(
+            "schedule name must not be empty".to_string(),
+        ));
+    }
+
+    let title_template = schedule.title_template.trim();
+    if title_template.is_empty() {
+        return Err(BackendError::Validation(
+            "schedule title must not be empty".to_string(),
+        ));
+    }
+
+    if schedule.hour > 23 || schedule.minute > 59 {
+        return Err(BackendError::Validation(
+            "schedule time must be between 00:00 and 23:59".to_string(),
+        ));
+    }
+
+    let recurrence = match schedule.recurrence {
+        RecurrenceKind::Daily => Recurrence::Daily,
+        RecurrenceKind::Weekly => {
+            if schedule.weekday > 6 {
+                return Err(BackendError::Validation(
+                    "weekday must be between 0 (Monday) and 6 (Sunday)".to_string(),
+                ));
+            }
+            Recurrence::Weekly {
+                weekday: schedule.weekday,
+            }
+        }
+    };
+
+    db::save_schedule(&Schedule {
+        id: schedule.id.trim().to_string(),
+        name: name.to_string(),
+        title_template: title_template.to_string(),
+        note_template: schedule.note_template,
+        recurrence,
+        hour: schedule.hour,
+        minute: schedule.minute,
+        enabled: schedule.enabled,
+        last_run_unix_seconds: 0,
+    })
+    .map_err(map_anyhow)
+}
+
+#[uniffi::export]
+pub fn delete_schedule(schedule_id: String) -> Result<(), BackendError> {
+    let schedule_id = schedule_id.trim();
+    if schedule_id.is_empty() {
+        return Err(BackendError::Validation(
+            "schedule_id must not be empty".to_string(),
+        ));
+    }
+    db::delete_schedule(schedule_id).map_err(map_anyhow)
+}
+
+/// Lets background workers sleep until they have work while the app is hidden.
+#[uniffi::export]
+pub fn set_power_saving(enabled: bool) {
+    schedules::set_power_saving(enabled);
+}
+
+#[uniffi::export]
+pub fn get_idle_stats() -> IdleStatsRecord {
+    schedules::scheduler_wakeups().into()
+}
+
+/// Secure items can't be read or changed while the app is locked.
+fn ensure_item_unlocked(item_id: i64) -> Result<(), BackendError> {
+    let locked = is_locked()?;
+    check_item_unlocked(
+        item_id,
+        locked,
+        locked && db::is_item_secure(item_id).map_err(map_anyhow)?,
+    )
+}
+
+fn check_item_unlocked(item_id: i64, locked: bool, secure: bool) -> Result<(), BackendError> {
+    if locked && secure {
+        return Err(BackendError::Locked(format!(
+            "item {item_id} is secure; unlock to open or change it"
+        )));
+    }
+    Ok(())
+}
+
+/// Ids of the secure items while the app is locked; empty when unlocked.
+fn locked_item_ids() -> Result<HashSet<i64>, BackendError> {
+    if is_locked()? {
+        db::secure_item_ids().map_err(map_anyhow)
+    } else {
+        Ok(HashSet::new())
+    }
+}
+
 fn ensure_item_id(item_id: i64) -> Result<(), BackendError> {
     if item_id <= 0 {
         return Err(BackendError::Validation(
@@ -387,6 +2992,23 @@ fn ensure_item_id(item_id: i64) -> Result<(), BackendError> {
     Ok(())
 }
 
+/// Validates ids for bulk actions and drops duplicates, keeping the first occurrence.
+fn normalize_item_ids(item_ids: Vec<i64>) -> Result<Vec<i64>, BackendError> {
+    if item_ids.is_empty() {
+        return Err(BackendError::Validation(
+            "item_ids must not be empty".to_string(),
+        ));
+    }
+    let mut unique = Vec::with_capacity(item_ids.len());
+    for item_id in item_ids {
+        ensure_item_id(item_id)?;
+        if !unique.contains(&item_id) {
+            unique.push(item_id);
+        }
+    }
+    Ok(unique)
+}
+
 fn normalize_limit(limit: Option<u32>) -> Result<u32, BackendError> {
     let limit = limit.unwrap_or(DEFAULT_SEARCH_LIMIT);
     if limit == 0 {
@@ -400,11 +3022,23 @@ fn normalize_limit(limit: Option<u32>) -> Result<u32, BackendError> {
 
 fn map_anyhow(err: anyhow::Error) -> BackendError {
     let message = err.to_string();
-    if message.contains("item not found") || message.contains("deleted archive not found") {
+    if message.contains("item not found")
+        || message.contains("deleted archive not found")
+        || message.contains("command not found")
+        || message.contains("trigger not found")
+        || message.contains("schedule not found")
+        || message.contains("image not found")
+        || message.contains("version not found")
+    {
         return BackendError::NotFound("requested item does not exist".to_string());
     }
 
+    if message.contains("revision conflict") {
+        return BackendError::Conflict(message);
+    }
+
     if message.contains("too many note images")
+        || message.contains("invalid ranking script")
         || message.contains("exceeds")
         || message.contains("must not")
     {
@@ -413,3 +3047,50 @@ fn map_anyhow(err: anyhow::Error) -> BackendError {
 
     BackendError::Storage(message)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{BackendError, check_item_unlocked, without_locked_content};
+    use crate::models::{ResultKind, SearchResult};
+
+    #[test]
+    fn secure_items_are_refused_while_locked() {
+        assert!(matches!(
+            check_item_unlocked(7, true, true),
+            Err(BackendError::Locked(message)) if message.contains("item 7")
+        ));
+        assert!(check_item_unlocked(7, true, false).is_ok());
+        assert!(check_item_unlocked(7, false, true).is_ok());
+    }
+
+    #[test]
+    fn locked_results_keep_titles_only() {
+        let row = |id, kind| SearchResult {
+            id,
+            title: "Bank".into(),
+            subtitle: String::new(),
+            snippet: Some("pin 1234".into()),
+            snippet_source: None,
+            kind,
+            icon: None,
+            command_key: Some("pin 1234".into()),
+            pinned: false,
+            thumbnail_key: Some("img-1".into()),
+            title_match_ranges: Vec::new(),
+            checklist_progress: None,
+        };
+        let results = vec![
+            row(7, ResultKind::Answer),
+            row(7, ResultKind::Item),
+            row(8, ResultKind::Item),
+        ];
+        let scrubbed = without_locked_content(results, &HashSet::from([7]));
+        assert_eq!(scrubbed.len(), 2);
+        assert_eq!((scrubbed[0].id, scrubbed[0].kind), (7, ResultKind::Item));
+        assert_eq!(scrubbed[0].snippet, None);
+        assert_eq!(scrubbed[0].thumbnail_key, None);
+        assert_eq!(scrubbed[1].snippet.as_deref(), Some("pin 1234"));
+    }
+}
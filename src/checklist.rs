@@ -0,0 +1,116 @@
+use crate::models::ChecklistProgress;
+
+/// A `- [ ] text` or `- [x] text` line of a note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecklistEntry {
+    pub text: String,
+    pub done: bool,
+}
+
+/// Byte offset of the box mark (the space or `x`) and whether it is checked,
+/// for a line that is a checklist entry.
+fn checkbox_mark(line: &str) -> Option<(usize, bool)> {
+    let indent = line.len() - line.trim_start().len();
+    let rest = &line[indent..];
+    let bullet = rest.chars().next().filter(|c| matches!(c, '-' | '*'))?;
+    let rest = rest[bullet.len_utf8()..].strip_prefix(' ')?;
+    let done = match rest.get(..3)? {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+    if !rest[3..].is_empty() && !rest[3..].starts_with(' ') {
+        return None;
+    }
+    Some((indent + bullet.len_utf8() + 2, done))
+}
+
+/// Every checklist entry of `note`, in order.
+pub fn parse_checklist(note: &str) -> Vec<ChecklistEntry> {
+    note.lines()
+        .filter_map(|line| {
+            let (mark, done) = checkbox_mark(line)?;
+            Some(ChecklistEntry {
+                text: line[mark + 2..].trim().to_string(),
+                done,
+            })
+        })
+        .collect()
+}
+
+/// Checked and total entries, or `None` for notes without a checklist.
+pub fn checklist_progress(note: &str) -> Option<ChecklistProgress> {
+    let entries = parse_checklist(note);
+    if entries.is_empty() {
+        return None;
+    }
+    Some(ChecklistProgress {
+        done: entries.iter().filter(|entry| entry.done).count() as u32,
+        total: entries.len() as u32,
+    })
+}
+
+/// `note` with the checklist entry at `index` checked or unchecked, or `None`
+/// when there is no such entry.
+pub fn toggle_checklist_entry(note: &str, index: usize) -> Option<String> {
+    let mut offset = 0;
+    let mut seen = 0;
+    for line in note.split_inclusive('\n') {
+        if let Some((mark, done)) = checkbox_mark(line) {
+            if seen == index {
+                let position = offset + mark;
+                let mut toggled = note.to_string();
+                toggled.replace_range(position..position + 1, if done { " " } else { "x" });
+                return Some(toggled);
+            }
+            seen += 1;
+        }
+        offset += line.len();
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTE: &str =
+        "Trip\n- [ ] passport\n  * [x] tickets\n- [] not an entry\n-[ ] nor this\n- [X] charger\n";
+
+    #[test]
+    fn parse_checklist_reads_checked_and_unchecked_entries() {
+        assert_eq!(
+            parse_checklist(NOTE),
+            vec![
+                ChecklistEntry {
+                    text: "passport".into(),
+                    done: false,
+                },
+                ChecklistEntry {
+                    text: "tickets".into(),
+                    done: true,
+                },
+                ChecklistEntry {
+                    text: "charger".into(),
+                    done: true,
+                },
+            ]
+        );
+        assert_eq!(
+            checklist_progress(NOTE),
+            Some(ChecklistProgress { done: 2, total: 3 })
+        );
+        assert_eq!(checklist_progress("no boxes"), None);
+    }
+
+    #[test]
+    fn toggle_checklist_entry_flips_only_the_indexed_box() {
+        let toggled = toggle_checklist_entry(NOTE, 0).unwrap();
+        assert!(toggled.contains("- [x] passport"));
+        assert!(toggled.contains("* [x] tickets"));
+
+        let toggled = toggle_checklist_entry(&toggled, 1).unwrap();
+        assert!(toggled.contains("* [ ] tickets"));
+        assert_eq!(toggle_checklist_entry(NOTE, 3), None);
+    }
+}
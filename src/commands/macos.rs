@@ -0,0 +1,32 @@
+use std::process::Command;
+
+use anyhow::{Context, Result, ensure};
+
+use super::SystemCommand;
+
+pub fn execute(command: SystemCommand) -> Result<()> {
+    match command {
+        SystemCommand::Sleep => run("pmset", &["sleepnow"]),
+        SystemCommand::LockScreen => osascript(
+            "tell application \"System Events\" to keystroke \"q\" using {control down, command down}",
+        ),
+        SystemCommand::EmptyTrash => osascript("tell application \"Finder\" to empty trash"),
+        SystemCommand::Restart => osascript("tell application \"System Events\" to restart"),
+        SystemCommand::ToggleDarkMode => osascript(
+            "tell application \"System Events\" to tell appearance preferences to set dark mode to not dark mode",
+        ),
+    }
+}
+
+fn osascript(script: &str) -> Result<()> {
+    run("osascript", &["-e", script])
+}
+
+fn run(program: &str, args: &[&str]) -> Result<()> {
+    let status = Command::new(program)
+        .args(args)
+        .status()
+        .with_context(|| format!("failed to launch {program}"))?;
+    ensure!(status.success(), "{program} exited with {status}");
+    Ok(())
+}
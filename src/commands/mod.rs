@@ -0,0 +1,345 @@
+use anyhow::{Result, anyhow};
+
+use crate::models::{ResultKind, SearchResult};
+
+#[cfg(target_os = "macos")]
+#[path = "macos.rs"]
+mod executor;
+
+#[cfg(not(target_os = "macos"))]
+#[path = "unsupported.rs"]
+mod executor;
+
+const COMMAND_QUERY_MIN_CHARS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemCommand {
+    Sleep,
+    LockScreen,
+    EmptyTrash,
+    Restart,
+    ToggleDarkMode,
+}
+
+impl SystemCommand {
+    pub const ALL: [SystemCommand; 5] = [
+        SystemCommand::Sleep,
+        SystemCommand::LockScreen,
+        SystemCommand::EmptyTrash,
+        SystemCommand::Restart,
+        SystemCommand::ToggleDarkMode,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            SystemCommand::Sleep => "sleep",
+            SystemCommand::LockScreen => "lock_screen",
+            SystemCommand::EmptyTrash => "empty_trash",
+            SystemCommand::Restart => "restart",
+            SystemCommand::ToggleDarkMode => "toggle_dark_mode",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|command| command.key() == key)
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            SystemCommand::Sleep => "Sleep",
+            SystemCommand::LockScreen => "Lock Screen",
+            SystemCommand::EmptyTrash => "Empty Trash",
+            SystemCommand::Restart => "Restart",
+            SystemCommand::ToggleDarkMode => "Toggle Dark Mode",
+        }
+    }
+
+    fn subtitle(self) -> &'static str {
+        match self {
+            SystemCommand::Sleep => "Put the computer to sleep",
+            SystemCommand::LockScreen => "Lock the screen",
+            SystemCommand::EmptyTrash => "Permanently erase items in the Trash",
+            SystemCommand::Restart => "Restart the computer",
+            SystemCommand::ToggleDarkMode => "Switch between light and dark appearance",
+        }
+    }
+
+    /// SF Symbol name rendered next to the result row.
+    fn icon(self) -> &'static str {
+        match self {
+            SystemCommand::Sleep => "moon.zzz",
+            SystemCommand::LockScreen => "lock",
+            SystemCommand::EmptyTrash => "trash",
+            SystemCommand::Restart => "arrow.clockwise",
+            SystemCommand::ToggleDarkMode => "circle.lefthalf.filled",
+        }
+    }
+
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            SystemCommand::Sleep => &["suspend"],
+            SystemCommand::LockScreen => &["lock"],
+            SystemCommand::EmptyTrash => &["trash"],
+            SystemCommand::Restart => &["reboot"],
+            SystemCommand::ToggleDarkMode => &["dark mode", "light mode", "appearance"],
+        }
+    }
+
+    /// Command results use negative ids so they never collide with item ids.
+    fn result_id(self) -> i64 {
+        let position = Self::ALL
+            .iter()
+            .position(|command| *command == self)
+            .unwrap_or(0);
+        -(position as i64) - 1
+    }
+
+    fn matches(self, query_lower: &str) -> bool {
+        names_match(self.title(), self.aliases(), query_lower)
+    }
+
+    fn to_search_result(self) -> SearchResult {
+        SearchResult {
+            id: self.result_id(),
+            title: self.title().to_string(),
+            subtitle: self.subtitle().to_string(),
+            snippet: None,
+            snippet_source: None,
+            kind: ResultKind::SystemCommand,
+            icon: Some(self.icon().to_string()),
+            command_key: Some(self.key().to_string()),
+            pinned: false,
+            thumbnail_key: None,
+            title_match_ranges: Vec::new(),
+            checklist_progress: None,
+        }
+    }
+}
+
+/// Commands handled by the app itself, typically by opening one of its views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppCommand {
+    Browse,
+    Board,
+    Calendar,
+    Stats,
+    TimeReport,
+    ExportCsv,
+}
+
+impl AppCommand {
+    pub const ALL: [AppCommand; 6] = [
+        AppCommand::Browse,
+        AppCommand::Board,
+        AppCommand::Calendar,
+        AppCommand::Stats,
+        AppCommand::TimeReport,
+        AppCommand::ExportCsv,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            AppCommand::Browse => "browse",
+            AppCommand::Board => "board",
+            AppCommand::Calendar => "calendar",
+            AppCommand::Stats => "stats",
+            AppCommand::TimeReport => "time_report",
+            AppCommand::ExportCsv => "export_csv",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            AppCommand::Browse => "Browse Notes",
+            AppCommand::Board => "Board",
+            AppCommand::Calendar => "Calendar",
+            AppCommand::Stats => "Stats",
+            AppCommand::TimeReport => "Timesheet",
+            AppCommand::ExportCsv => "Export CSV",
+        }
+    }
+
+    fn subtitle(self) -> &'static str {
+        match self {
+            AppCommand::Browse => "List every note next to a rendered preview",
+            AppCommand::Board => "Show #todo, #doing and #done items as cards",
+            AppCommand::Calendar => "Browse notes by day and open daily journal entries",
+            AppCommand::Stats => "Show searches per day, most opened notes and storage use",
+            AppCommand::TimeReport => "Show the week's tracked time per note and export it",
+            AppCommand::ExportCsv => {
+                "Save a spreadsheet of every note's title, tags, dates and counts"
+            }
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            AppCommand::Browse => "sidebar.left",
+            AppCommand::Board => "rectangle.split.3x1",
+            AppCommand::Calendar => "calendar",
+            AppCommand::Stats => "chart.bar",
+            AppCommand::TimeReport => "clock",
+            AppCommand::ExportCsv => "tablecells",
+        }
+    }
+
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            AppCommand::Browse => &["library", "all notes", "list"],
+            AppCommand::Board => &["kanban", "todo"],
+            AppCommand::Calendar => &["journal", "month"],
+            AppCommand::Stats => &["statistics", "usage"],
+            AppCommand::TimeReport => &["time tracking", "hours", "weekly time"],
+            AppCommand::ExportCsv => &["csv", "spreadsheet", "inventory"],
+        }
+    }
+
+    /// Numbered after the system commands so result ids stay unique.
+    fn result_id(self) -> i64 {
+        let position = Self::ALL
+            .iter()
+            .position(|command| *command == self)
+            .unwrap_or(0);
+        -((SystemCommand::ALL.len() + position) as i64) - 1
+    }
+
+    fn to_search_result(self) -> SearchResult {
+        SearchResult {
+            id: self.result_id(),
+            title: self.title().to_string(),
+            subtitle: self.subtitle().to_string(),
+            snippet: None,
+            snippet_source: None,
+            kind: ResultKind::AppCommand,
+            icon: Some(self.icon().to_string()),
+            command_key: Some(self.key().to_string()),
+            pinned: false,
+            thumbnail_key: None,
+            title_match_ranges: Vec::new(),
+            checklist_progress: None,
+        }
+    }
+}
+
+fn names_match(title: &str, aliases: &[&str], query_lower: &str) -> bool {
+    std::iter::once(title)
+        .chain(aliases.iter().copied())
+        .any(|name| {
+            let name = name.to_lowercase();
+            name.starts_with(query_lower)
+                || name
+                    .split_whitespace()
+                    .any(|word| word.starts_with(query_lower))
+        })
+}
+
+/// Returns the built-in commands whose name (or an alias) starts with the query.
+pub fn search_commands(query: &str) -> Vec<SearchResult> {
+    let query_lower = query.trim().to_lowercase();
+    if query_lower.chars().count() < COMMAND_QUERY_MIN_CHARS {
+        return Vec::new();
+    }
+    matching_commands(&query_lower, true)
+}
+
+/// Like `search_commands`, but without a minimum query length, so an empty
+/// query lists every command. Used by the command prefix.
+pub fn list_commands(query: &str) -> Vec<SearchResult> {
+    matching_commands(&query.trim().to_lowercase(), true)
+}
+
+/// `search_commands` without the app commands, which all open note views;
+/// used when the note store is turned off.
+pub fn search_system_commands(query: &str) -> Vec<SearchResult> {
+    let query_lower = query.trim().to_lowercase();
+    if query_lower.chars().count() < COMMAND_QUERY_MIN_CHARS {
+        return Vec::new();
+    }
+    matching_commands(&query_lower, false)
+}
+
+/// `list_commands` without the app commands.
+pub fn list_system_commands(query: &str) -> Vec<SearchResult> {
+    matching_commands(&query.trim().to_lowercase(), false)
+}
+
+fn matching_commands(query_lower: &str, include_app_commands: bool) -> Vec<SearchResult> {
+    let app_commands = AppCommand::ALL
+        .into_iter()
+        .filter(|_| include_app_commands)
+        .filter(|command| names_match(command.title(), command.aliases(), query_lower))
+        .map(AppCommand::to_search_result);
+    SystemCommand::ALL
+        .into_iter()
+        .filter(|command| command.matches(query_lower))
+        .map(SystemCommand::to_search_result)
+        .chain(app_commands)
+        .collect()
+}
+
+pub fn run_command(key: &str) -> Result<()> {
+    let command =
+        SystemCommand::from_key(key).ok_or_else(|| anyhow!("command not found: {key}"))?;
+    executor::execute(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AppCommand, SystemCommand, list_commands, list_system_commands, search_commands,
+        search_system_commands,
+    };
+
+    #[test]
+    fn search_commands_matches_title_word_prefixes_and_aliases() {
+        let keys: Vec<_> = search_commands("lock")
+            .into_iter()
+            .filter_map(|row| row.command_key)
+            .collect();
+        assert_eq!(keys, vec!["lock_screen".to_string()]);
+
+        let keys: Vec<_> = search_commands("dark")
+            .into_iter()
+            .filter_map(|row| row.command_key)
+            .collect();
+        assert_eq!(keys, vec!["toggle_dark_mode".to_string()]);
+    }
+
+    #[test]
+    fn search_commands_ignores_single_character_queries() {
+        assert!(search_commands("s").is_empty());
+    }
+
+    #[test]
+    fn list_commands_returns_everything_for_an_empty_query() {
+        assert_eq!(
+            list_commands("").len(),
+            SystemCommand::ALL.len() + AppCommand::ALL.len()
+        );
+        assert_eq!(list_commands("r").len(), 1);
+    }
+
+    #[test]
+    fn system_command_lists_leave_out_app_commands() {
+        assert_eq!(list_system_commands("").len(), SystemCommand::ALL.len());
+        assert!(search_system_commands("board").is_empty());
+        assert_eq!(search_system_commands("lock").len(), 1);
+    }
+
+    #[test]
+    fn command_result_ids_are_negative_and_unique() {
+        let mut ids: Vec<i64> = SystemCommand::ALL
+            .into_iter()
+            .map(|command| command.result_id())
+            .chain(
+                AppCommand::ALL
+                    .into_iter()
+                    .map(|command| command.result_id()),
+            )
+            .collect();
+        assert!(ids.iter().all(|id| *id < 0));
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), SystemCommand::ALL.len() + AppCommand::ALL.len());
+    }
+}
@@ -0,0 +1,10 @@
+use anyhow::{Result, bail};
+
+use super::SystemCommand;
+
+pub fn execute(command: SystemCommand) -> Result<()> {
+    bail!(
+        "command '{}' is not supported on this platform",
+        command.title()
+    )
+}
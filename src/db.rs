@@ -1,33 +1,95 @@
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::ops::Range;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result, anyhow, ensure};
+use chrono::{Datelike, Local, NaiveDate, TimeZone};
 use directories::{ProjectDirs, UserDirs};
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
-use tantivy::collector::TopDocs;
-use tantivy::query::{AllQuery, BooleanQuery, Occur, QueryParser, TermQuery};
+use tantivy::collector::{DocSetCollector, TopDocs};
+use tantivy::query::{AllQuery, BooleanQuery, Occur, QueryParser, RegexQuery, TermQuery};
 use tantivy::schema::{Field, INDEXED, IndexRecordOption, STORED, STRING, Schema, TEXT, Value};
 use tantivy::snippet::{Snippet, SnippetGenerator};
 use tantivy::{Index, IndexReader, IndexWriter, TantivyDocument, Term, doc};
 
-use crate::models::{EditableItem, NoteImage, SearchResult};
+use crate::activity::{self, ActivityEntry, ActivityKind, ActivityOrigin};
+use crate::answers::{self, ANSWER_FENCE};
+use crate::checklist::{self, ChecklistEntry};
+use crate::diff;
+use crate::forge::{ForgeAccount, ForgeKind};
+use crate::history::{self, NoteVersion};
+use crate::logging::log_error;
+use crate::merge;
+use crate::models::{
+    BoardCard, BrowseSort, CalendarEntry, DiffSegment, EditableItem, ImageLimits, ImageReplacement,
+    ImageText, ImportedNote, ItemMarkdown, ItemMetadata, ItemStats, ItemStorageUsage, NoteImage,
+    NoteVersionSummary, OpenedItem, ResultKind, RunningTimer, SearchResult, StorageReport,
+    StorageSnapshot, StoredImage, UsageStats,
+};
+use crate::note_links;
+use crate::org;
+use crate::query_filters::{self, ParsedQuery, QueryFilter};
+use crate::ranking_script::{RankingFeatures, RankingScript};
+use crate::read_later::{self, QueuedArticle};
+use crate::schedules::{self, Schedule};
+use crate::storage_budget;
+use crate::tags::{self, extract_hashtags};
+use crate::time_tracking::{self, TimeEntry, WeekReport};
+use crate::trackers::{TrackerAccount, TrackerKind};
+use crate::triggers::{self, ItemEventPayload, Trigger, TriggerEvent, TriggerLogEntry};
+use crate::usage::{self, UsageEvent, UsageEventKind};
+use crate::vault::{self, VaultFormat, VaultNote};
 
 static STORE: OnceCell<Mutex<Store>> = OnceCell::new();
+static LAUNCHER_ONLY: OnceCell<bool> = OnceCell::new();
+/// Default image limits, used until overridden in settings.
 pub const MAX_SCREENSHOT_BYTES: usize = 12_000_000;
 pub const MAX_NOTE_IMAGE_COUNT: usize = 24;
+const IMAGE_COUNT_LIMIT_BOUNDS: (usize, usize) = (1, 500);
+const IMAGE_BYTES_LIMIT_BOUNDS: (usize, usize) = (256 * 1024, 100_000_000);
+const MAX_NOTE_IMAGE_COUNT_SETTING_KEY: &str = "max_note_image_count";
+const MAX_NOTE_IMAGE_BYTES_SETTING_KEY: &str = "max_note_image_bytes";
 pub const DEFAULT_HOTKEY: &str = "super+Space";
 const HOTKEY_SETTING_KEY: &str = "launcher_hotkey";
 const JSON_STORAGE_PATH_SETTING_KEY: &str = "json_storage_path";
+const TRIGGERS_SETTING_KEY: &str = "automation_triggers";
+const TRIGGER_LOG_DIR_NAME: &str = "trigger_logs";
+const NOTE_HISTORY_DIR_NAME: &str = "note_history";
+const VAULT_SYNC_BASE_DIR_NAME: &str = "vault_sync_base";
+const FORGE_CACHE_DIR_NAME: &str = "forge_cache";
+const SECURE_ARCHIVE_DIR_NAME: &str = "secure_archive";
+const USAGE_LOG_FILE_NAME: &str = "usage_log.jsonl";
+const ACTIVITY_LOG_FILE_NAME: &str = "activity_log.jsonl";
+const SCHEDULES_SETTING_KEY: &str = "recurring_schedules";
+const MIRROR_EXCLUDED_TAGS_SETTING_KEY: &str = "json_mirror_excluded_tags";
+const STORAGE_BUDGET_SETTING_KEY: &str = "storage_budget_bytes";
+const SEARCH_MATCH_ANY_SETTING_KEY: &str = "search_match_any_term";
+const RANKING_SCRIPT_SETTING_KEY: &str = "ranking_script";
+const FORGE_ACCOUNTS_SETTING_KEY: &str = "forge_accounts";
+const TRACKER_ACCOUNTS_SETTING_KEY: &str = "tracker_accounts";
+/// With a ranking script, this many times the requested rows are scored so
+/// the script can lift results from below the cut-off.
+const RANKING_SCRIPT_CANDIDATE_FACTOR: i64 = 4;
+const OBSIDIAN_VAULT_SETTING_KEY: &str = "json_mirror_obsidian_vault";
+const APP_LOCK_VERIFIER_SETTING_KEY: &str = "app_lock_key_verifier";
+const READ_LATER_QUEUE_SETTING_KEY: &str = "read_later_queue";
+/// Status tags that place an item on the board, in workflow order.
+pub const BOARD_STATUS_TAGS: [&str; 3] = ["todo", "doing", "done"];
+const BOARD_PREVIEW_MAX_CHARS: usize = 140;
+const JOURNAL_DATE_FORMAT: &str = "%Y-%m-%d";
 const FUZZY_QUERY_TERM_MIN_CHARS: usize = 4;
 const FUZZY_SIMILARITY_THRESHOLD: f32 = 0.62;
 const FUZZY_SCAN_MULTIPLIER: i64 = 64;
 const FUZZY_SCAN_MAX_ROWS: i64 = 2048;
 const INDEX_DIR_NAME: &str = "alfred_lucene_index";
+/// Marker file in the data directory that turns the note store off. It lives
+/// outside the store because the store's own settings are what it disables.
+const LAUNCHER_ONLY_MARKER_FILE_NAME: &str = "launcher_only";
 const DEFAULT_JSON_STORAGE_DIR_NAME: &str = "AlfredAlternativeData";
 const JSON_STORAGE_IMAGES_DIR_NAME: &str = "images";
 const JSON_STORAGE_DELETED_DIR_NAME: &str = "deleted";
@@ -41,6 +103,11 @@ const INDEX_WRITER_HEAP_BYTES: usize = 50_000_000;
 const DOC_TYPE_ITEM: &str = "item";
 const DOC_TYPE_SETTING: &str = "setting";
 const LUCENE_SNIPPET_MAX_CHARS: usize = 120;
+/// Snippet source of text recognized in an image, followed by the image key.
+const IMAGE_TEXT_SNIPPET_SOURCE_PREFIX: &str = "image:";
+/// Joins the fragments of a note snippet; the launcher shows each on its own line.
+const SNIPPET_FRAGMENT_SEPARATOR: &str = " … ";
+const INLINE_IMAGE_URL_PREFIX: &str = "alfred://image/";
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ExportItem {
@@ -69,12 +136,17 @@ pub struct DeletedItemPreview {
     pub note: String,
     pub deleted_at_unix_seconds: i64,
     pub image_count: i64,
+    pub secure: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct PersistedImage {
     image_key: String,
     bytes: Vec<u8>,
+    /// Text recognized in the image; `None` until it has been scanned and empty
+    /// when the image has no text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -85,6 +157,61 @@ struct PersistedItem {
     keywords: String,
     note: String,
     images: Vec<PersistedImage>,
+    #[serde(default)]
+    meta: PersistedItemMeta,
+}
+
+/// Per-item bookkeeping stored alongside the item document as JSON so new
+/// attributes can be added without another schema migration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct PersistedItemMeta {
+    /// Bumped on every note/image save; used as an optimistic concurrency token.
+    revision: u64,
+    /// Unix seconds; 0 for items written before timestamps were tracked.
+    created_at: u64,
+    updated_at: u64,
+    /// Local date (`YYYY-MM-DD`) this item is the daily journal entry for.
+    journal_date: Option<String>,
+    /// Pinned items are listed ahead of other matches.
+    pinned: bool,
+    /// Unix seconds the item was last opened in the editor.
+    last_opened_at: u64,
+    /// Secure items are kept in the index only and never written to the JSON mirror.
+    secure: bool,
+    /// Finished timer runs on this item.
+    time_entries: Vec<TimeEntry>,
+    /// Unix seconds the running timer on this item started.
+    timer_started_at: Option<u64>,
+}
+
+impl PersistedItemMeta {
+    fn new_item() -> Self {
+        let now = unix_timestamp();
+        Self {
+            revision: 1,
+            created_at: now,
+            updated_at: now,
+            journal_date: None,
+            pinned: false,
+            last_opened_at: 0,
+            secure: false,
+            time_entries: Vec::new(),
+            timer_started_at: None,
+        }
+    }
+
+    /// Most recent open or edit, used to order the recent-items listing.
+    fn last_used_at(&self) -> u64 {
+        self.last_opened_at.max(self.updated_at)
+    }
+
+    /// Records a note/image save and returns the new revision.
+    fn mark_saved(&mut self) -> u64 {
+        self.revision = self.revision.saturating_add(1);
+        self.updated_at = unix_timestamp();
+        self.revision
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -112,6 +239,8 @@ struct DeletedJsonItemFile {
     note: String,
     deleted_at_unix_seconds: u64,
     images: Vec<JsonImageEntry>,
+    #[serde(default)]
+    secure: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -130,6 +259,10 @@ struct SearchFields {
     keywords: Field,
     note: Field,
     images_json: Field,
+    // `None` only while reading an index created before this field existed.
+    item_meta: Option<Field>,
+    // Same as `item_meta`; one untokenized term per `#tag` of the item.
+    tags: Option<Field>,
     setting_key: Field,
     setting_value: Field,
 }
@@ -140,6 +273,8 @@ struct Store {
     writer: IndexWriter,
     reader: IndexReader,
     fields: SearchFields,
+    /// Compiled from the ranking script setting.
+    ranking_script: Option<RankingScript>,
 }
 
 fn project_data_dir() -> Result<PathBuf> {
@@ -152,6 +287,116 @@ fn index_path() -> Result<PathBuf> {
     Ok(project_data_dir()?.join(INDEX_DIR_NAME))
 }
 
+fn trigger_log_dir() -> Result<PathBuf> {
+    Ok(project_data_dir()?.join(TRIGGER_LOG_DIR_NAME))
+}
+
+fn note_history_dir() -> Result<PathBuf> {
+    Ok(project_data_dir()?.join(NOTE_HISTORY_DIR_NAME))
+}
+
+/// Deleted secure items are archived here rather than in the mirror, which
+/// may be synced.
+fn secure_archive_root() -> Result<PathBuf> {
+    Ok(project_data_dir()?.join(SECURE_ARCHIVE_DIR_NAME))
+}
+
+fn vault_sync_base_path(item_id: i64) -> Result<PathBuf> {
+    Ok(project_data_dir()?
+        .join(VAULT_SYNC_BASE_DIR_NAME)
+        .join(format!("{item_id}.txt")))
+}
+
+/// The vault note as of the last sync: the common ancestor when a note was
+/// edited both in the app and in the vault since.
+fn load_vault_sync_base(item_id: i64) -> Option<String> {
+    std::fs::read_to_string(vault_sync_base_path(item_id).ok()?).ok()
+}
+
+/// Without a base, sync falls back to comparing modification times, so
+/// failing to record one never fails the sync.
+fn save_vault_sync_base(item_id: i64, content: &str) {
+    let saved = vault_sync_base_path(item_id).and_then(|path| {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        write_bytes_atomic(&path, content.as_bytes())
+    });
+    if let Err(err) = saved {
+        log_error(
+            format_args!("failed to record vault sync base for item {item_id}"),
+            err,
+        );
+    }
+}
+
+fn forget_vault_sync_base(item_id: i64) {
+    if let Ok(path) = vault_sync_base_path(item_id) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+fn usage_log_path() -> Result<PathBuf> {
+    Ok(project_data_dir()?.join(USAGE_LOG_FILE_NAME))
+}
+
+fn activity_log_path() -> Result<PathBuf> {
+    Ok(project_data_dir()?.join(ACTIVITY_LOG_FILE_NAME))
+}
+
+/// Like the usage log, the activity log never fails the change it records.
+fn record_activity(
+    kind: ActivityKind,
+    origin: ActivityOrigin,
+    item: Option<(i64, &str)>,
+    detail: Option<String>,
+) {
+    let entry = ActivityEntry {
+        at_unix_seconds: unix_timestamp(),
+        kind,
+        origin,
+        item_id: item.map(|(id, _)| id),
+        title: item.map(|(_, title)| title.to_string()),
+        detail,
+    };
+    if let Err(err) = activity_log_path().and_then(|path| activity::append_entry(&path, &entry)) {
+        log_error("failed to record activity", err);
+    }
+}
+
+pub fn recent_activity(limit: usize) -> Result<Vec<ActivityEntry>> {
+    activity::read_recent(&activity_log_path()?, limit)
+}
+
+/// Like note history, the usage log never fails the action it records.
+fn record_usage_event(kind: UsageEventKind, item_id: Option<i64>) {
+    let event = UsageEvent {
+        at_unix_seconds: unix_timestamp(),
+        kind,
+        item_id,
+    };
+    if let Err(err) = usage_log_path().and_then(|path| usage::append_event(&path, &event)) {
+        log_error("failed to record usage event", err);
+    }
+}
+
+/// History is a convenience on top of the index, so failing to record a
+/// version never fails the save itself. Like the mirror, it never holds the
+/// text of secure items.
+fn record_note_history(item: &PersistedItem) {
+    if item.meta.secure {
+        return;
+    }
+    let recorded = note_history_dir()
+        .and_then(|dir| history::record_save(&dir, item.id, &item.note, unix_timestamp()));
+    if let Err(err) = recorded {
+        log_error(
+            format_args!("failed to record note history for item {}", item.id),
+            err,
+        );
+    }
+}
+
 fn default_json_storage_root() -> PathBuf {
     if let Some(user_dirs) = UserDirs::new() {
         if let Some(documents_dir) = user_dirs.document_dir() {
@@ -270,11 +515,27 @@ fn write_bytes_atomic(path: &Path, bytes: &[u8]) -> Result<()> {
     Ok(())
 }
 
-fn archive_deleted_item(root: &Path, item: &PersistedItem) -> Result<()> {
+/// Archives `item` under `mirror_root`, or under `secure_root` when it's
+/// secure, and returns its archive key.
+fn archive_deleted_item_in(
+    mirror_root: &Path,
+    secure_root: &Path,
+    item: &PersistedItem,
+) -> Result<String> {
+    let root = if item.meta.secure {
+        secure_root
+    } else {
+        mirror_root
+    };
+    archive_deleted_item(root, item)
+}
+
+/// Writes `item` into the deleted-items folder and returns its archive key.
+fn archive_deleted_item(root: &Path, item: &PersistedItem) -> Result<String> {
     let deleted_at_unix_seconds = unix_timestamp();
     let deleted_root = root.join(JSON_STORAGE_DELETED_DIR_NAME);
-    let deleted_item_dir =
-        deleted_root.join(deleted_item_dir_name(item.id, deleted_at_unix_seconds));
+    let archive_key = deleted_item_dir_name(item.id, deleted_at_unix_seconds);
+    let deleted_item_dir = deleted_root.join(&archive_key);
     let deleted_images_dir = deleted_item_dir.join(JSON_STORAGE_IMAGES_DIR_NAME);
 
     std::fs::create_dir_all(&deleted_images_dir).with_context(|| {
@@ -309,6 +570,7 @@ fn archive_deleted_item(root: &Path, item: &PersistedItem) -> Result<()> {
         note: item.note.clone(),
         deleted_at_unix_seconds,
         images: image_entries,
+        secure: item.meta.secure,
     };
 
     let deleted_item_json_path = deleted_item_dir.join(item_json_file_name(item.id));
@@ -319,7 +581,7 @@ fn archive_deleted_item(root: &Path, item: &PersistedItem) -> Result<()> {
         )
     })?;
 
-    Ok(())
+    Ok(archive_key)
 }
 
 #[derive(Debug, Clone)]
@@ -329,7 +591,15 @@ struct DeletedArchive {
     payload: DeletedJsonItemFile,
 }
 
-fn read_deleted_archives(root: &Path) -> Result<Vec<DeletedArchive>> {
+fn read_deleted_archives(roots: &[PathBuf]) -> Result<Vec<DeletedArchive>> {
+    let mut archives = Vec::new();
+    for root in roots {
+        archives.extend(read_deleted_archives_in(root)?);
+    }
+    Ok(archives)
+}
+
+fn read_deleted_archives_in(root: &Path) -> Result<Vec<DeletedArchive>> {
     let deleted_root = root.join(JSON_STORAGE_DELETED_DIR_NAME);
     if !deleted_root.exists() {
         return Ok(Vec::new());
@@ -394,8 +664,8 @@ fn read_deleted_archives(root: &Path) -> Result<Vec<DeletedArchive>> {
     Ok(archives)
 }
 
-fn find_deleted_archive(root: &Path, archive_key: &str) -> Result<DeletedArchive> {
-    let archives = read_deleted_archives(root)?;
+fn find_deleted_archive(roots: &[PathBuf], archive_key: &str) -> Result<DeletedArchive> {
+    let archives = read_deleted_archives(roots)?;
     archives
         .into_iter()
         .find(|archive| archive.archive_key == archive_key)
@@ -416,6 +686,7 @@ fn restore_deleted_archive(store: &mut Store, archive: &DeletedArchive) -> Resul
         restored_images.push(PersistedImage {
             image_key: image.image_key.clone(),
             bytes,
+            text: None,
         });
     }
 
@@ -439,10 +710,20 @@ fn restore_deleted_archive(store: &mut Store, archive: &DeletedArchive) -> Resul
             keywords: archive.payload.keywords.clone(),
             note: archive.payload.note.clone(),
             images: restored_images,
+            meta: PersistedItemMeta {
+                secure: archive.payload.secure,
+                ..PersistedItemMeta::new_item()
+            },
         },
     );
 
     store.flush_all()?;
+    record_activity(
+        ActivityKind::Create,
+        activity::current_origin(),
+        Some((restored_id, &archive.payload.title)),
+        Some("restored".to_string()),
+    );
 
     std::fs::remove_dir_all(&archive.archive_dir).with_context(|| {
         format!(
@@ -455,14 +736,63 @@ fn restore_deleted_archive(store: &mut Store, archive: &DeletedArchive) -> Resul
 }
 
 fn get_store() -> Result<&'static Mutex<Store>> {
+    ensure!(
+        !is_launcher_only(),
+        "note storage is turned off in launcher-only mode"
+    );
     STORE.get_or_try_init(|| {
         let mut store = Store::open()?;
         store.ensure_seed_data();
         store.flush_all()?;
+        schedules::spawn_scheduler(run_due_schedules_on_tick);
         Ok(Mutex::new(store))
     })
 }
 
+/// Opens the store and warms the index reader, so it can run on a background
+/// thread at launch instead of stalling the first search.
+pub fn warm_up_store() -> Result<()> {
+    if is_launcher_only() {
+        return Ok(());
+    }
+    run_with_store(|store| store.warm_index_reader())
+}
+
+/// Whether the store has been opened; searches before that wait for it.
+/// Without a store there is nothing to wait for.
+pub fn is_store_ready() -> bool {
+    is_launcher_only() || STORE.get().is_some()
+}
+
+/// Whether this run has the note store turned off, leaving only the command
+/// launcher. Read once per run; changes apply at the next launch.
+pub fn is_launcher_only() -> bool {
+    *LAUNCHER_ONLY.get_or_init(|| launcher_only_saved().unwrap_or(false))
+}
+
+/// The launcher-only setting the next launch will use.
+pub fn launcher_only_saved() -> Result<bool> {
+    Ok(project_data_dir()?
+        .join(LAUNCHER_ONLY_MARKER_FILE_NAME)
+        .exists())
+}
+
+/// Turns the note store off (or back on) from the next launch. Notes already
+/// stored are left on disk either way.
+pub fn save_launcher_only(enabled: bool) -> Result<()> {
+    let data_dir = project_data_dir()?;
+    let marker = data_dir.join(LAUNCHER_ONLY_MARKER_FILE_NAME);
+    if enabled {
+        std::fs::create_dir_all(&data_dir)?;
+        std::fs::write(&marker, b"")
+            .with_context(|| format!("failed writing {}", marker.display()))?;
+    } else if marker.exists() {
+        std::fs::remove_file(&marker)
+            .with_context(|| format!("failed removing {}", marker.display()))?;
+    }
+    Ok(())
+}
+
 fn run_with_store<T, F>(mut operation: F) -> Result<T>
 where
     F: FnMut(&mut Store) -> Result<T>,
@@ -479,15 +809,29 @@ impl Store {
         purge_legacy_storage_files(&data_dir)?;
 
         let index_path = index_path()?;
-        let (index, fields) = open_or_rebuild_index(&index_path)?;
+        let (index, fields, migrated_data) = open_or_rebuild_index(&index_path)?;
         let writer = index
             .writer(INDEX_WRITER_HEAP_BYTES)
             .context("failed to create Lucene writer")?;
         let reader = index.reader().context("failed to create Lucene reader")?;
-        let mut data = load_data_from_lucene(&reader, &fields)?;
+        let mut data = match migrated_data {
+            Some(data) => data,
+            None => load_data_from_lucene(&reader, &fields)?,
+        };
         if data.next_item_id <= 0 {
             data.next_item_id = 1;
         }
+        // A script that no longer compiles leaves the built-in order in place.
+        let ranking_script = data
+            .settings
+            .get(RANKING_SCRIPT_SETTING_KEY)
+            .and_then(|source| match RankingScript::compile(source) {
+                Ok(script) => Some(script),
+                Err(err) => {
+                    log_error("keeping the built-in order", err);
+                    None
+                }
+            });
 
         Ok(Self {
             data,
@@ -495,6 +839,7 @@ impl Store {
             writer,
             reader,
             fields,
+            ranking_script,
         })
     }
 
@@ -544,8 +889,17 @@ impl Store {
         json_storage_root_from_settings(&self.data.settings)
     }
 
+    /// The mirror, then the app's own folder for deleted secure items.
+    fn deleted_archive_roots(&self) -> Result<[PathBuf; 2]> {
+        Ok([self.json_storage_root(), secure_archive_root()?])
+    }
+
     fn sync_json_storage(&self) -> Result<()> {
         let root = self.json_storage_root();
+        if self.obsidian_vault_mode() {
+            return self.sync_obsidian_vault(&root);
+        }
+
         let images_dir = root.join(JSON_STORAGE_IMAGES_DIR_NAME);
         std::fs::create_dir_all(&root)
             .with_context(|| format!("failed to create JSON storage root {}", root.display()))?;
@@ -558,8 +912,15 @@ impl Store {
 
         let mut expected_item_files = HashSet::new();
         let mut expected_image_files = HashSet::new();
+        let excluded_tags = self.mirror_excluded_tags();
 
+        // Excluded items are skipped, so the pruning below also removes copies
+        // mirrored before the item was excluded.
         for item in self.data.items.values() {
+            if mirror_exclusion(item, &excluded_tags).is_some() {
+                continue;
+            }
+
             let mut image_entries = Vec::with_capacity(item.images.len());
             for image in &item.images {
                 let file_name = image_file_name(&image.image_key);
@@ -657,9 +1018,216 @@ impl Store {
         Ok(())
     }
 
+    /// Writes every mirrored item as a Markdown note, with its images in the
+    /// attachments folder. Notes edited in the vault since the last sync are
+    /// left for `import_obsidian_vault` to merge instead of being overwritten.
+    fn sync_obsidian_vault(&self, root: &Path) -> Result<()> {
+        let attachments_dir = root.join(vault::ATTACHMENTS_DIR_NAME);
+        std::fs::create_dir_all(&attachments_dir).with_context(|| {
+            format!(
+                "failed to create vault attachments directory {}",
+                attachments_dir.display()
+            )
+        })?;
+
+        let existing_files = scan_vault_files(root)?;
+        let mut file_by_id: HashMap<i64, &VaultFile> = HashMap::new();
+        for file in &existing_files {
+            if let Some(id) = file.note.id {
+                file_by_id.entry(id).or_insert(file);
+            }
+        }
+        let mut taken_paths: HashSet<PathBuf> = existing_files
+            .iter()
+            .map(|file| file.path.clone())
+            .collect();
+        let mut written_ids = HashSet::new();
+        let mut expected_attachments = HashSet::new();
+        let excluded_tags = self.mirror_excluded_tags();
+
+        for item in self.data.items.values() {
+            if mirror_exclusion(item, &excluded_tags).is_some() {
+                continue;
+            }
+            written_ids.insert(item.id);
+
+            for image in &item.images {
+                let file_name = vault_attachment_file_name(image);
+                let image_path = attachments_dir.join(&file_name);
+                let unchanged = std::fs::metadata(&image_path)
+                    .is_ok_and(|meta| meta.len() == image.bytes.len() as u64);
+                if !unchanged {
+                    write_bytes_atomic(&image_path, &image.bytes).with_context(|| {
+                        format!("failed to write vault attachment {}", image_path.display())
+                    })?;
+                }
+                expected_attachments.insert(file_name);
+            }
+
+            let format = file_by_id
+                .get(&item.id)
+                .map_or(VaultFormat::Markdown, |file| file.format);
+            let content = render_item_vault_note(item, format);
+            let path = match file_by_id.get(&item.id) {
+                Some(file) => {
+                    let base = load_vault_sync_base(item.id);
+                    let edited_in_vault = match &base {
+                        Some(base) => file.content != *base,
+                        None => file.modified_at > item.meta.updated_at,
+                    };
+                    if file.content != content && edited_in_vault {
+                        continue;
+                    }
+                    if vault_file_matches_title(&file.path, item) {
+                        if file.content == content {
+                            if base.as_deref() != Some(content.as_str()) {
+                                save_vault_sync_base(item.id, &content);
+                            }
+                            continue;
+                        }
+                        file.path.clone()
+                    } else {
+                        // Renamed in the app; the note moves but stays in its folder.
+                        let dir = file.path.parent().unwrap_or(root);
+                        let path = vault_note_path(dir, item, format, &taken_paths);
+                        std::fs::remove_file(&file.path).with_context(|| {
+                            format!("failed to rename vault note {}", file.path.display())
+                        })?;
+                        path
+                    }
+                }
+                None => vault_note_path(root, item, format, &taken_paths),
+            };
+
+            write_bytes_atomic(&path, content.as_bytes())
+                .with_context(|| format!("failed to write vault note {}", path.display()))?;
+            save_vault_sync_base(item.id, &content);
+            taken_paths.insert(path);
+        }
+
+        // Notes without an id were added in the vault and wait to be imported.
+        for file in &existing_files {
+            let Some(id) = file.note.id.filter(|id| !written_ids.contains(id)) else {
+                continue;
+            };
+            std::fs::remove_file(&file.path).with_context(|| {
+                format!("failed removing stale vault note {}", file.path.display())
+            })?;
+            forget_vault_sync_base(id);
+        }
+
+        for entry in std::fs::read_dir(&attachments_dir)
+            .with_context(|| format!("failed to scan {}", attachments_dir.display()))?
+        {
+            let path = entry?.path();
+            let Some(file_name) = path.file_name().and_then(|value| value.to_str()) else {
+                continue;
+            };
+            if path.is_file()
+                && file_name.starts_with(vault::ATTACHMENT_FILE_PREFIX)
+                && !expected_attachments.contains(file_name)
+            {
+                std::fs::remove_file(&path).with_context(|| {
+                    format!("failed removing stale vault attachment {}", path.display())
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies notes edited or added in the Obsidian vault since the last sync
+    /// and returns the event for each changed item. A note also edited in the
+    /// app is merged line by line, with conflict markers only where both sides
+    /// changed the same lines. The caller flushes.
+    fn import_obsidian_vault(&mut self) -> Result<Vec<(TriggerEvent, i64)>> {
+        let root = self.json_storage_root();
+        let limits = self.image_limits();
+        let excluded_tags = self.mirror_excluded_tags();
+        let mut changes = Vec::new();
+
+        for file in scan_vault_files(&root)? {
+            let title = vault_file_title(&file.path, file.note.id);
+            match file.note.id {
+                Some(id) => {
+                    let Some(item) = self.item_by_id(id) else {
+                        // Deleted in the app; the next sync removes the note.
+                        continue;
+                    };
+                    let base = load_vault_sync_base(id);
+                    let rendered = render_item_vault_note(item, file.format);
+                    let edited_in_vault = match &base {
+                        Some(base) => file.content != *base,
+                        None => file.modified_at > item.meta.updated_at,
+                    };
+                    if mirror_exclusion(item, &excluded_tags).is_some()
+                        || !edited_in_vault
+                        || (file.content == rendered && vault_file_matches_title(&file.path, item))
+                    {
+                        continue;
+                    }
+
+                    let mut images = item.images.clone();
+                    let note = match base.filter(|base| *base != rendered) {
+                        Some(base) => {
+                            let merged = merge::three_way_merge(
+                                &base,
+                                &rendered,
+                                &file.content,
+                                "app",
+                                "vault",
+                            );
+                            let merged_file = VaultFile {
+                                path: file.path.clone(),
+                                format: file.format,
+                                note: parse_vault_file_note(file.format, &merged.text),
+                                content: merged.text,
+                                modified_at: file.modified_at,
+                            };
+                            vault_body_to_note(&root, &merged_file, &mut images, limits)
+                        }
+                        None => vault_body_to_note(&root, &file, &mut images, limits),
+                    };
+                    // The vault copy is now part of the item, so the next sync
+                    // may rewrite the file with the merged note.
+                    save_vault_sync_base(id, &file.content);
+                    let renamed = !vault_file_matches_title(&file.path, item);
+                    let Some(item) = self.item_by_id_mut(id) else {
+                        continue;
+                    };
+                    if renamed {
+                        item.title = title;
+                        item.keywords = item.title.clone();
+                    }
+                    item.note = note;
+                    item.images = images;
+                    item.meta.mark_saved();
+                    record_note_history(item);
+                    changes.push((TriggerEvent::Saved, id));
+                }
+                None => {
+                    let mut images = Vec::new();
+                    let note = vault_body_to_note(&root, &file, &mut images, limits);
+                    let id = self.push_new_item(&title, &note);
+                    let Some(item) = self.item_by_id_mut(id) else {
+                        continue;
+                    };
+                    item.images = images;
+                    // Tag the note with its id so it isn't imported again.
+                    let content = render_item_vault_note(item, file.format);
+                    write_bytes_atomic(&file.path, content.as_bytes()).with_context(|| {
+                        format!("failed to write vault note {}", file.path.display())
+                    })?;
+                    save_vault_sync_base(id, &content);
+                    changes.push((TriggerEvent::Created, id));
+                }
+            }
+        }
+        Ok(changes)
+    }
+
     fn build_item_document(&self, item: &PersistedItem) -> TantivyDocument {
         let images_json = serde_json::to_string(&item.images).unwrap_or_else(|_| "[]".to_string());
-        doc!(
+        let mut document = doc!(
             self.fields.doc_type => DOC_TYPE_ITEM,
             self.fields.id => item.id,
             self.fields.title => item.title.clone(),
@@ -667,7 +1235,17 @@ impl Store {
             self.fields.keywords => item.keywords.clone(),
             self.fields.note => item.note.clone(),
             self.fields.images_json => images_json
-        )
+        );
+        if let Some(item_meta) = self.fields.item_meta {
+            let meta_json = serde_json::to_string(&item.meta).unwrap_or_else(|_| "{}".to_string());
+            document.add_text(item_meta, meta_json);
+        }
+        if let Some(tags) = self.fields.tags {
+            for tag in item_tags(item) {
+                document.add_text(tags, tag);
+            }
+        }
+        document
     }
 
     fn build_setting_document(&self, key: &str, value: &str) -> TantivyDocument {
@@ -678,6 +1256,179 @@ impl Store {
         )
     }
 
+    fn configured_triggers(&self) -> Vec<Trigger> {
+        self.data
+            .settings
+            .get(TRIGGERS_SETTING_KEY)
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    fn set_triggers(&mut self, triggers: &[Trigger]) -> Result<()> {
+        let payload = serde_json::to_string(triggers).context("failed to serialize triggers")?;
+        self.data
+            .settings
+            .insert(TRIGGERS_SETTING_KEY.to_string(), payload);
+        self.flush_all()
+    }
+
+    fn forge_accounts(&self) -> Vec<ForgeAccount> {
+        self.data
+            .settings
+            .get(FORGE_ACCOUNTS_SETTING_KEY)
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    fn tracker_accounts(&self) -> Vec<TrackerAccount> {
+        self.data
+            .settings
+            .get(TRACKER_ACCOUNTS_SETTING_KEY)
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    fn read_later_queue(&self) -> Vec<QueuedArticle> {
+        self.data
+            .settings
+            .get(READ_LATER_QUEUE_SETTING_KEY)
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    fn set_read_later_queue(&mut self, queue: &[QueuedArticle]) -> Result<()> {
+        let payload =
+            serde_json::to_string(queue).context("failed to serialize read-later queue")?;
+        self.data
+            .settings
+            .insert(READ_LATER_QUEUE_SETTING_KEY.to_string(), payload);
+        self.flush_all()
+    }
+
+    fn mirror_excluded_tags(&self) -> Vec<String> {
+        self.data
+            .settings
+            .get(MIRROR_EXCLUDED_TAGS_SETTING_KEY)
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    /// Whether query terms are OR'ed by default instead of all being required.
+    fn search_match_any(&self) -> bool {
+        self.data
+            .settings
+            .get(SEARCH_MATCH_ANY_SETTING_KEY)
+            .is_some_and(|raw| raw == "true")
+    }
+
+    /// Whether the mirror is written as an Obsidian vault instead of JSON files.
+    fn obsidian_vault_mode(&self) -> bool {
+        self.data
+            .settings
+            .get(OBSIDIAN_VAULT_SETTING_KEY)
+            .is_some_and(|raw| raw == "true")
+    }
+
+    fn storage_budget_bytes(&self) -> Option<u64> {
+        self.data
+            .settings
+            .get(STORAGE_BUDGET_SETTING_KEY)
+            .and_then(|raw| raw.parse().ok())
+            .filter(|bytes| *bytes > 0)
+    }
+
+    fn image_limits(&self) -> ImageLimits {
+        let setting = |key: &str| {
+            self.data
+                .settings
+                .get(key)
+                .and_then(|raw| raw.parse::<usize>().ok())
+        };
+        clamp_image_limits(ImageLimits {
+            max_images_per_note: setting(MAX_NOTE_IMAGE_COUNT_SETTING_KEY)
+                .unwrap_or(MAX_NOTE_IMAGE_COUNT),
+            max_image_bytes: setting(MAX_NOTE_IMAGE_BYTES_SETTING_KEY)
+                .unwrap_or(MAX_SCREENSHOT_BYTES),
+        })
+    }
+
+    fn configured_schedules(&self) -> Vec<Schedule> {
+        self.data
+            .settings
+            .get(SCHEDULES_SETTING_KEY)
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    fn set_schedules(&mut self, schedules: &[Schedule]) -> Result<()> {
+        let payload = serde_json::to_string(schedules).context("failed to serialize schedules")?;
+        self.data
+            .settings
+            .insert(SCHEDULES_SETTING_KEY.to_string(), payload);
+        self.flush_all()
+    }
+
+    fn fire_item_event(&self, event: TriggerEvent, item: &PersistedItem) {
+        self.fire_item_event_from(activity::current_origin(), event, item);
+    }
+
+    /// Logs the change as coming from `origin` and runs matching triggers.
+    fn fire_item_event_from(
+        &self,
+        origin: ActivityOrigin,
+        event: TriggerEvent,
+        item: &PersistedItem,
+    ) {
+        let kind = match event {
+            TriggerEvent::Created => ActivityKind::Create,
+            TriggerEvent::Saved => ActivityKind::Update,
+            TriggerEvent::Deleted => ActivityKind::Delete,
+        };
+        record_activity(kind, origin, Some((item.id, &item.title)), None);
+
+        let triggers = self.configured_triggers();
+        if triggers.is_empty() {
+            return;
+        }
+
+        let Ok(log_dir) = trigger_log_dir() else {
+            return;
+        };
+        // Webhooks and scripts only learn a secure item's id and title.
+        let (note, tags) = if item.meta.secure {
+            (String::new(), Vec::new())
+        } else {
+            (item.note.clone(), item_tags(item))
+        };
+        let payload = ItemEventPayload {
+            event,
+            item_id: item.id,
+            title: item.title.clone(),
+            note,
+            tags,
+            fired_at_unix_seconds: unix_timestamp(),
+        };
+        triggers::dispatch(&triggers, &payload, &log_dir);
+    }
+
+    /// Adds a new item in memory; callers flush and fire the created event.
+    fn push_new_item(&mut self, title: &str, note: &str) -> i64 {
+        let id = self.next_item_id();
+        self.data.items.insert(
+            id,
+            PersistedItem {
+                id,
+                title: title.to_string(),
+                subtitle: String::new(),
+                keywords: title.to_string(),
+                note: note.to_string(),
+                images: Vec::new(),
+                meta: PersistedItemMeta::new_item(),
+            },
+        );
+        id
+    }
+
     fn next_item_id(&mut self) -> i64 {
         let id = self.data.next_item_id.max(1);
         self.data.next_item_id = id.saturating_add(1);
@@ -692,6 +1443,47 @@ impl Store {
         self.data.items.get_mut(&id)
     }
 
+    /// Fills `thumbnail_key` for item rows whose note contains an image and
+    /// `checklist_progress` for those with a checklist.
+    fn attach_item_details(&self, rows: &mut [SearchResult]) {
+        for row in rows.iter_mut() {
+            if row.kind != ResultKind::Item {
+                continue;
+            }
+            let Some(item) = self.item_by_id(row.id) else {
+                continue;
+            };
+            row.thumbnail_key = thumbnail_image_key(&item.note, &item.images);
+            row.checklist_progress = checklist::checklist_progress(&item.note);
+        }
+    }
+
+    fn running_timer(&self) -> Option<RunningTimer> {
+        self.data.items.values().find_map(|item| {
+            item.meta.timer_started_at.map(|started_at| RunningTimer {
+                item_id: item.id,
+                title: item.title.clone(),
+                started_at,
+            })
+        })
+    }
+
+    /// Ends the timer running on `id` at `now` and returns the seconds it ran.
+    fn finish_timer(&mut self, id: i64, now: u64) -> u64 {
+        let Some(item) = self.item_by_id_mut(id) else {
+            return 0;
+        };
+        let Some(started_at) = item.meta.timer_started_at.take() else {
+            return 0;
+        };
+        let entry = TimeEntry {
+            started_at,
+            ended_at: now.max(started_at),
+        };
+        item.meta.time_entries.push(entry);
+        entry.seconds()
+    }
+
     fn ordered_items_for_listing(&self) -> Vec<&PersistedItem> {
         self.data.items.values().rev().collect()
     }
@@ -704,22 +1496,81 @@ impl Store {
         self.data.items.values().rev().collect()
     }
 
-    fn lucene_search_hits(&mut self, query: &str, limit: usize) -> Result<Vec<LuceneSearchHit>> {
-        if limit == 0 {
-            return Ok(Vec::new());
+    /// Opens the term dictionaries of the searched fields so the first query
+    /// doesn't pay for loading them.
+    fn warm_index_reader(&mut self) -> Result<()> {
+        self.reader
+            .reload()
+            .context("failed to refresh Lucene reader")?;
+        let searcher = self.reader.searcher();
+        for segment in searcher.segment_readers() {
+            for field in [
+                self.fields.title,
+                self.fields.subtitle,
+                self.fields.keywords,
+                self.fields.note,
+            ] {
+                segment
+                    .inverted_index(field)
+                    .context("failed to open Lucene term dictionary")?;
+            }
         }
+        Ok(())
+    }
 
-        let Some(lucene_query) = build_lucene_query(query) else {
-            return Ok(Vec::new());
+    /// Ids of the items with an indexed tag starting with `prefix`.
+    fn tagged_item_ids(&self, prefix: &str) -> Result<HashSet<i64>> {
+        let Some(query) = self
+            .fields
+            .tags
+            .and_then(|field| tag_prefix_query(field, prefix))
+        else {
+            return Ok(HashSet::new());
         };
 
         self.reader
             .reload()
             .context("failed to refresh Lucene reader")?;
         let searcher = self.reader.searcher();
+        let addresses = searcher
+            .search(&query, &DocSetCollector)
+            .context("failed to execute Lucene tag search")?;
 
-        let mut parser = QueryParser::for_index(
-            &self.index,
+        let mut ids = HashSet::with_capacity(addresses.len());
+        for addr in addresses {
+            let doc: TantivyDocument = searcher
+                .doc(addr)
+                .context("failed to load Lucene document")?;
+            if let Some(id) = doc
+                .get_first(self.fields.id)
+                .and_then(|value| value.as_i64())
+            {
+                ids.insert(id);
+            }
+        }
+        Ok(ids)
+    }
+
+    fn lucene_search_hits(
+        &mut self,
+        query_groups: &[Vec<String>],
+        limit: usize,
+    ) -> Result<Vec<LuceneSearchHit>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let Some(lucene_query) = build_lucene_query(query_groups) else {
+            return Ok(Vec::new());
+        };
+
+        self.reader
+            .reload()
+            .context("failed to refresh Lucene reader")?;
+        let searcher = self.reader.searcher();
+
+        let mut parser = QueryParser::for_index(
+            &self.index,
             vec![
                 self.fields.title,
                 self.fields.subtitle,
@@ -758,7 +1609,7 @@ impl Store {
             .context("failed to execute Lucene search")?;
 
         let mut hits = Vec::with_capacity(top_docs.len());
-        for (_, addr) in top_docs {
+        for (score, addr) in top_docs {
             let doc: TantivyDocument = searcher
                 .doc(addr)
                 .context("failed to load Lucene document")?;
@@ -777,27 +1628,76 @@ impl Store {
                 .as_ref()
                 .and_then(|generator| build_lucene_note_snippet(note, generator));
 
-            hits.push(LuceneSearchHit { id, note_snippet });
+            hits.push(LuceneSearchHit {
+                id,
+                score,
+                note_snippet,
+            });
         }
 
         Ok(hits)
     }
 }
 
-fn open_or_rebuild_index(path: &Path) -> Result<(Index, SearchFields)> {
+/// Why an item is kept out of the JSON mirror, if it is.
+fn mirror_exclusion(item: &PersistedItem, excluded_tags: &[String]) -> Option<String> {
+    if item.meta.secure {
+        return Some("secure item".to_string());
+    }
+    if excluded_tags.is_empty() {
+        return None;
+    }
+    item_tags(item)
+        .into_iter()
+        .find(|tag| excluded_tags.contains(tag))
+        .map(|tag| format!("tagged #{tag}"))
+}
+
+fn item_tags(item: &PersistedItem) -> Vec<String> {
+    let mut tags = extract_hashtags(&item.note);
+    for tag in extract_hashtags(&item.keywords) {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// Opens the index at `path`, creating a fresh one when missing or unreadable.
+///
+/// Indexes written by an older schema revision are read once, moved aside, and
+/// their data is returned so the caller can repopulate the new index.
+fn open_or_rebuild_index(path: &Path) -> Result<(Index, SearchFields, Option<PersistedData>)> {
     if path.exists() {
-        match Index::open_in_dir(path) {
-            Ok(index) => {
-                if let Some(fields) = resolve_fields(&index.schema()) {
-                    return Ok((index, fields));
+        if let Ok(index) = Index::open_in_dir(path) {
+            match resolve_fields(&index.schema()) {
+                Some(fields) if fields.item_meta.is_some() && fields.tags.is_some() => {
+                    return Ok((index, fields, None));
+                }
+                Some(legacy_fields) => {
+                    let reader = index
+                        .reader()
+                        .context("failed to create Lucene reader for schema migration")?;
+                    let data = load_data_from_lucene(&reader, &legacy_fields)?;
+                    drop(reader);
+                    drop(index);
+
+                    backup_superseded_path(path)?;
+                    let (index, fields) = create_index(path)?;
+                    return Ok((index, fields, Some(data)));
                 }
+                None => {}
             }
-            Err(_) => {}
         }
 
         backup_corrupt_path(path)?;
     }
 
+    let (index, fields) = create_index(path)?;
+    Ok((index, fields, None))
+}
+
+fn create_index(path: &Path) -> Result<(Index, SearchFields)> {
     std::fs::create_dir_all(path)?;
     let (schema, fields) = build_index_schema();
     let index = Index::create_in_dir(path, schema)?;
@@ -813,6 +1713,8 @@ fn build_index_schema() -> (Schema, SearchFields) {
     let keywords = builder.add_text_field("keywords", TEXT | STORED);
     let note = builder.add_text_field("note", TEXT | STORED);
     let images_json = builder.add_text_field("images_json", STORED);
+    let item_meta = builder.add_text_field("item_meta", STORED);
+    let tags = builder.add_text_field("tags", STRING);
     let setting_key = builder.add_text_field("setting_key", STRING | STORED);
     let setting_value = builder.add_text_field("setting_value", STORED);
     let schema = builder.build();
@@ -827,6 +1729,8 @@ fn build_index_schema() -> (Schema, SearchFields) {
             keywords,
             note,
             images_json,
+            item_meta: Some(item_meta),
+            tags: Some(tags),
             setting_key,
             setting_value,
         },
@@ -842,6 +1746,8 @@ fn resolve_fields(schema: &Schema) -> Option<SearchFields> {
         keywords: schema.get_field("keywords").ok()?,
         note: schema.get_field("note").ok()?,
         images_json: schema.get_field("images_json").ok()?,
+        item_meta: schema.get_field("item_meta").ok(),
+        tags: schema.get_field("tags").ok(),
         setting_key: schema.get_field("setting_key").ok()?,
         setting_value: schema.get_field("setting_value").ok()?,
     })
@@ -903,6 +1809,12 @@ fn load_data_from_lucene(reader: &IndexReader, fields: &SearchFields) -> Result<
                     .unwrap_or("[]");
                 let images = serde_json::from_str::<Vec<PersistedImage>>(images_json)
                     .unwrap_or_else(|_| Vec::new());
+                let meta = fields
+                    .item_meta
+                    .and_then(|field| doc.get_first(field))
+                    .and_then(|value| value.as_str())
+                    .and_then(|raw| serde_json::from_str::<PersistedItemMeta>(raw).ok())
+                    .unwrap_or_default();
 
                 data.items.insert(
                     id,
@@ -913,6 +1825,7 @@ fn load_data_from_lucene(reader: &IndexReader, fields: &SearchFields) -> Result<
                         keywords,
                         note,
                         images,
+                        meta,
                     },
                 );
                 data.next_item_id = data.next_item_id.max(id.saturating_add(1));
@@ -1010,6 +1923,19 @@ fn backup_corrupt_path(path: &Path) -> Result<()> {
     Ok(())
 }
 
+fn backup_superseded_path(path: &Path) -> Result<()> {
+    let stamp = unix_timestamp();
+    let backup = PathBuf::from(format!("{}.pre-migration.{stamp}", path.display()));
+    std::fs::rename(path, &backup).with_context(|| {
+        format!(
+            "failed to move superseded index from {} to {}",
+            path.display(),
+            backup.display()
+        )
+    })?;
+    Ok(())
+}
+
 fn unix_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -1038,423 +1964,2312 @@ pub fn save_hotkey_setting(value: &str) -> Result<()> {
     })
 }
 
-pub fn load_json_storage_path_setting() -> Result<String> {
-    run_with_store(|store| Ok(store.json_storage_root().to_string_lossy().to_string()))
+pub fn load_search_match_any_setting() -> Result<bool> {
+    run_with_store(|store| Ok(store.search_match_any()))
 }
 
-pub fn save_json_storage_path_setting(value: &str) -> Result<()> {
-    let normalized = normalize_storage_path(value);
+pub fn save_search_match_any_setting(match_any: bool) -> Result<()> {
     run_with_store(|store| {
         store.data.settings.insert(
-            JSON_STORAGE_PATH_SETTING_KEY.to_string(),
-            normalized.to_string_lossy().to_string(),
+            SEARCH_MATCH_ANY_SETTING_KEY.to_string(),
+            match_any.to_string(),
         );
         store.flush_all()
     })
 }
 
-pub fn search(query: &str, limit: i64) -> Result<Vec<SearchResult>> {
+/// The ranking script's source; empty when results keep the built-in order.
+pub fn load_ranking_script() -> Result<String> {
     run_with_store(|store| {
-        let limit = limit.max(0);
-        if limit == 0 {
-            return Ok(Vec::new());
-        }
+        Ok(store
+            .data
+            .settings
+            .get(RANKING_SCRIPT_SETTING_KEY)
+            .cloned()
+            .unwrap_or_default())
+    })
+}
 
-        let query = query.trim();
-        if query.is_empty() {
-            let rows = store
-                .ordered_items_for_listing()
-                .into_iter()
-                .take(limit as usize)
-                .map(|item| SearchResult {
-                    id: item.id,
-                    title: item.title.clone(),
-                    subtitle: item.subtitle.clone(),
-                    snippet: None,
-                    snippet_source: None,
-                })
-                .collect();
-            return Ok(rows);
+/// Compiles and stores a ranking script; an empty one restores the built-in
+/// order. A script that doesn't compile is rejected and the old one kept.
+pub fn save_ranking_script(source: &str) -> Result<()> {
+    let source = source.trim();
+    let mut script = if source.is_empty() {
+        None
+    } else {
+        Some(RankingScript::compile(source)?)
+    };
+    run_with_store(|store| {
+        if script.is_some() {
+            store
+                .data
+                .settings
+                .insert(RANKING_SCRIPT_SETTING_KEY.to_string(), source.to_string());
+        } else {
+            store.data.settings.remove(RANKING_SCRIPT_SETTING_KEY);
         }
+        store.flush_all()?;
+        store.ranking_script = script.take();
+        Ok(())
+    })
+}
 
-        let query_terms = parse_query_terms(query);
-        let mut results = Vec::with_capacity(limit as usize);
-        let mut seen_ids = HashSet::with_capacity(limit as usize);
+/// The GitHub and GitLab accounts whose repositories the launcher lists,
+/// without their tokens.
+pub fn load_forge_accounts() -> Result<Vec<ForgeAccount>> {
+    run_with_store(|store| Ok(store.forge_accounts()))
+}
 
-        let lucene_hits = store.lucene_search_hits(query, limit as usize)?;
-        for hit in lucene_hits {
-            if !seen_ids.insert(hit.id) {
-                continue;
+/// Replaces the forge accounts, at most one per forge. Tokens aren't stored,
+/// so a saved account may now use another user's token; the previous
+/// accounts' caches are dropped, since they may list that user's
+/// repositories.
+pub fn save_forge_accounts(accounts: Vec<ForgeAccount>) -> Result<Vec<ForgeAccount>> {
+    let accounts = accounts
+        .into_iter()
+        .map(ForgeAccount::normalized)
+        .collect::<Result<Vec<_>>>()?;
+    for kind in ForgeKind::ALL {
+        if accounts
+            .iter()
+            .filter(|account| account.kind == kind)
+            .count()
+            > 1
+        {
+            return Err(anyhow!(
+                "forge accounts must not list {} twice",
+                kind.provider_id()
+            ));
+        }
+    }
+    run_with_store(|store| {
+        let previous = store.forge_accounts();
+        let payload =
+            serde_json::to_string(&accounts).context("failed to serialize forge accounts")?;
+        if accounts.is_empty() {
+            store.data.settings.remove(FORGE_ACCOUNTS_SETTING_KEY);
+        } else {
+            store
+                .data
+                .settings
+                .insert(FORGE_ACCOUNTS_SETTING_KEY.to_string(), payload);
+        }
+        store.flush_all()?;
+        for account in &previous {
+            if let Ok(path) = forge_cache_path(account.kind) {
+                let _ = std::fs::remove_file(path);
             }
+        }
+        Ok(accounts.clone())
+    })
+}
 
-            let Some(item) = store.item_by_id(hit.id) else {
-                continue;
-            };
+/// The Jira and Linear workspaces whose issues the launcher searches,
+/// without their tokens.
+pub fn load_tracker_accounts() -> Result<Vec<TrackerAccount>> {
+    run_with_store(|store| Ok(store.tracker_accounts()))
+}
 
-            results.push(map_search_item(item, &query_terms, hit.note_snippet));
-            if results.len() as i64 >= limit {
-                return Ok(results);
-            }
+/// Replaces the tracker accounts, at most one per tracker.
+pub fn save_tracker_accounts(accounts: Vec<TrackerAccount>) -> Result<Vec<TrackerAccount>> {
+    let accounts = accounts
+        .into_iter()
+        .map(TrackerAccount::normalized)
+        .collect::<Result<Vec<_>>>()?;
+    for kind in TrackerKind::ALL {
+        if accounts
+            .iter()
+            .filter(|account| account.kind == kind)
+            .count()
+            > 1
+        {
+            return Err(anyhow!(
+                "tracker accounts must not list {} twice",
+                kind.provider_id()
+            ));
+        }
+    }
+    run_with_store(|store| {
+        if accounts.is_empty() {
+            store.data.settings.remove(TRACKER_ACCOUNTS_SETTING_KEY);
+        } else {
+            let payload =
+                serde_json::to_string(&accounts).context("failed to serialize tracker accounts")?;
+            store
+                .data
+                .settings
+                .insert(TRACKER_ACCOUNTS_SETTING_KEY.to_string(), payload);
         }
+        store.flush_all()?;
+        Ok(accounts.clone())
+    })
+}
 
-        if (results.len() as i64) < limit {
-            let remaining = (limit - results.len() as i64) as usize;
-            let substring_rows = substring_search_rows(
-                store.ordered_items_by_id_asc(),
-                query,
-                &query_terms,
-                remaining,
-                &seen_ids,
-            );
+/// Where a forge's repositories and issue searches are cached.
+pub fn forge_cache_path(kind: ForgeKind) -> Result<PathBuf> {
+    Ok(project_data_dir()?
+        .join(FORGE_CACHE_DIR_NAME)
+        .join(format!("{}.json", kind.provider_id())))
+}
 
-            for row in substring_rows {
-                if seen_ids.insert(row.id) {
-                    results.push(row);
-                    if results.len() as i64 >= limit {
-                        return Ok(results);
-                    }
-                }
-            }
-        }
+pub fn load_obsidian_vault_setting() -> Result<bool> {
+    run_with_store(|store| Ok(store.obsidian_vault_mode()))
+}
 
-        if (results.len() as i64) < limit {
-            let remaining = limit - results.len() as i64;
-            let fuzzy_rows = fuzzy_search_rows(
-                store.ordered_items_by_id_desc(),
-                &query_terms,
-                remaining,
-                &seen_ids,
-            );
+pub fn save_obsidian_vault_setting(enabled: bool) -> Result<()> {
+    run_with_store(|store| {
+        store
+            .data
+            .settings
+            .insert(OBSIDIAN_VAULT_SETTING_KEY.to_string(), enabled.to_string());
+        store.flush_all()
+    })
+}
 
-            for row in fuzzy_rows {
-                if seen_ids.insert(row.id) {
-                    results.push(row);
-                    if results.len() as i64 >= limit {
-                        break;
-                    }
-                }
+/// Imports notes edited or added in the Obsidian vault and returns how many
+/// items changed; does nothing unless the mirror is a vault.
+pub fn sync_obsidian_vault() -> Result<u32> {
+    run_with_store(|store| {
+        if !store.obsidian_vault_mode() {
+            return Ok(0);
+        }
+        let changes = store.import_obsidian_vault()?;
+        if changes.is_empty() {
+            return Ok(0);
+        }
+        store.flush_all()?;
+        for (event, id) in &changes {
+            if let Some(item) = store.item_by_id(*id) {
+                store.fire_item_event_from(ActivityOrigin::Sync, *event, item);
             }
         }
+        Ok(changes.len() as u32)
+    })
+}
+
+pub fn load_json_storage_path_setting() -> Result<String> {
+    run_with_store(|store| Ok(store.json_storage_root().to_string_lossy().to_string()))
+}
 
-        Ok(results)
+pub fn save_json_storage_path_setting(value: &str) -> Result<()> {
+    let normalized = normalize_storage_path(value);
+    run_with_store(|store| {
+        store.data.settings.insert(
+            JSON_STORAGE_PATH_SETTING_KEY.to_string(),
+            normalized.to_string_lossy().to_string(),
+        );
+        store.flush_all()
     })
 }
 
-fn substring_search_rows(
-    items: Vec<&PersistedItem>,
-    query: &str,
-    query_terms: &[String],
-    limit: usize,
-    seen_ids: &HashSet<i64>,
-) -> Vec<SearchResult> {
-    if limit == 0 {
-        return Vec::new();
-    }
+pub fn load_mirror_excluded_tags() -> Result<Vec<String>> {
+    run_with_store(|store| Ok(store.mirror_excluded_tags()))
+}
+
+/// Replaces the tags whose items are kept out of the JSON mirror and resyncs it,
+/// which removes mirrored copies of newly excluded items.
+pub fn save_mirror_excluded_tags(tags: &[String]) -> Result<()> {
+    let payload = serde_json::to_string(tags).context("failed to serialize excluded tags")?;
+    run_with_store(|store| {
+        store.data.settings.insert(
+            MIRROR_EXCLUDED_TAGS_SETTING_KEY.to_string(),
+            payload.clone(),
+        );
+        store.flush_all()
+    })
+}
 
-    let tokens: Vec<&str> = query.split_whitespace().collect();
+/// `None` or 0 removes the budget.
+pub fn save_storage_budget(budget_bytes: Option<u64>) -> Result<()> {
+    run_with_store(|store| {
+        match budget_bytes.filter(|bytes| *bytes > 0) {
+            Some(bytes) => store
+                .data
+                .settings
+                .insert(STORAGE_BUDGET_SETTING_KEY.to_string(), bytes.to_string()),
+            None => store.data.settings.remove(STORAGE_BUDGET_SETTING_KEY),
+        };
+        // Settings are only persisted in the index.
+        store.rebuild_index()
+    })
+}
 
-    let mut output = Vec::new();
-    for item in items {
-        if seen_ids.contains(&item.id) {
-            continue;
+pub fn load_image_limits() -> Result<ImageLimits> {
+    run_with_store(|store| Ok(store.image_limits()))
+}
+
+/// Overrides the image limits (`None` restores a default) and returns the
+/// limits in effect after clamping to the supported bounds.
+pub fn save_image_limits(
+    max_images_per_note: Option<usize>,
+    max_image_bytes: Option<usize>,
+) -> Result<ImageLimits> {
+    run_with_store(|store| {
+        let limits = clamp_image_limits(ImageLimits {
+            max_images_per_note: max_images_per_note.unwrap_or(MAX_NOTE_IMAGE_COUNT),
+            max_image_bytes: max_image_bytes.unwrap_or(MAX_SCREENSHOT_BYTES),
+        });
+        let settings = &mut store.data.settings;
+        for (key, value, default) in [
+            (
+                MAX_NOTE_IMAGE_COUNT_SETTING_KEY,
+                limits.max_images_per_note,
+                MAX_NOTE_IMAGE_COUNT,
+            ),
+            (
+                MAX_NOTE_IMAGE_BYTES_SETTING_KEY,
+                limits.max_image_bytes,
+                MAX_SCREENSHOT_BYTES,
+            ),
+        ] {
+            if value == default {
+                settings.remove(key);
+            } else {
+                settings.insert(key.to_string(), value.to_string());
+            }
         }
+        // Settings are only persisted in the index.
+        store.rebuild_index()?;
+        Ok(limits)
+    })
+}
 
-        let matches = if tokens.len() <= 1 {
-            contains_case_insensitive(&item.title, query)
-                || contains_case_insensitive(&item.note, query)
-        } else {
-            let title_lower = item.title.to_lowercase();
-            let note_lower = item.note.to_lowercase();
-            tokens.iter().all(|token| {
-                let t = token.to_lowercase();
-                title_lower.contains(&t) || note_lower.contains(&t)
+/// Every stored note image with its size, largest first.
+pub fn list_stored_images() -> Result<Vec<StoredImage>> {
+    run_with_store(|store| {
+        let mut images: Vec<StoredImage> = store
+            .data
+            .items
+            .values()
+            .flat_map(|item| {
+                item.images.iter().map(|image| StoredImage {
+                    item_id: item.id,
+                    image_key: image.image_key.clone(),
+                    bytes: image.bytes.len() as u64,
+                    fingerprint: image_fingerprint(&image.bytes),
+                })
             })
-        };
+            .collect();
+        images.sort_by_key(|image| std::cmp::Reverse(image.bytes));
+        Ok(images)
+    })
+}
 
-        if matches {
-            output.push(map_search_item(item, query_terms, None));
-            if output.len() >= limit {
-                break;
+/// Swaps in re-encoded image bytes without touching note revisions and returns
+/// how many images were replaced. Images changed since they were listed are skipped.
+pub fn replace_stored_images(replacements: &[ImageReplacement]) -> Result<usize> {
+    run_with_store(|store| {
+        let max_image_bytes = store.image_limits().max_image_bytes;
+        let mut replaced = 0;
+        for replacement in replacements {
+            if replacement.bytes.is_empty() || replacement.bytes.len() > max_image_bytes {
+                continue;
+            }
+            if let Some(item) = store.item_by_id_mut(replacement.item_id)
+                && replace_image_if_unchanged(item, replacement)
+            {
+                replaced += 1;
             }
         }
-    }
 
-    output
+        if replaced > 0 {
+            store.flush_all()?;
+        }
+        Ok(replaced)
+    })
 }
 
-fn contains_case_insensitive(text: &str, needle: &str) -> bool {
-    text.to_lowercase().contains(&needle.to_lowercase())
+/// Up to `limit` images whose text hasn't been recognized yet, newest items first.
+pub fn list_images_without_text(limit: usize) -> Result<Vec<StoredImage>> {
+    run_with_store(|store| {
+        Ok(store
+            .ordered_items_by_id_desc()
+            .into_iter()
+            .flat_map(|item| {
+                item.images
+                    .iter()
+                    .filter(|image| image.text.is_none())
+                    .map(|image| StoredImage {
+                        item_id: item.id,
+                        image_key: image.image_key.clone(),
+                        bytes: image.bytes.len() as u64,
+                        fingerprint: image_fingerprint(&image.bytes),
+                    })
+            })
+            .take(limit)
+            .collect())
+    })
 }
 
-fn map_search_item(
-    item: &PersistedItem,
-    query_terms: &[String],
-    preferred_snippet: Option<String>,
-) -> SearchResult {
-    let snippet_data = preferred_snippet
-        .map(|snippet| ("note".to_string(), snippet))
-        .or_else(|| {
-            build_snippet_with_terms(
-                &item.title,
-                &item.subtitle,
-                &item.keywords,
-                &item.note,
-                query_terms,
-            )
-        });
+/// Stores recognized image text and returns how many images were updated.
+/// Images changed since they were listed are skipped.
+pub fn save_image_texts(texts: &[ImageText]) -> Result<usize> {
+    run_with_store(|store| {
+        let mut updated = 0;
+        for text in texts {
+            let Some(item) = store.item_by_id_mut(text.item_id) else {
+                continue;
+            };
+            let Some(image) = item.images.iter_mut().find(|image| {
+                image.image_key == text.image_key
+                    && image_fingerprint(&image.bytes) == text.fingerprint
+            }) else {
+                continue;
+            };
+            image.text = Some(text.text.split_whitespace().collect::<Vec<_>>().join(" "));
+            updated += 1;
+        }
 
-    let (snippet_source, snippet) = match snippet_data {
-        Some((source, snippet)) => (Some(source), Some(snippet)),
-        None => (None, None),
-    };
+        if updated > 0 {
+            store.flush_all()?;
+        }
+        Ok(updated)
+    })
+}
 
-    SearchResult {
-        id: item.id,
-        title: item.title.clone(),
-        subtitle: String::new(),
-        snippet,
-        snippet_source,
+fn image_fingerprint(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn replace_image_if_unchanged(item: &mut PersistedItem, replacement: &ImageReplacement) -> bool {
+    let Some(image) = item
+        .images
+        .iter_mut()
+        .find(|image| image.image_key == replacement.image_key)
+    else {
+        return false;
+    };
+    if image_fingerprint(&image.bytes) != replacement.fingerprint {
+        return false;
     }
+    image.bytes = replacement.bytes.clone();
+    true
 }
 
-fn fuzzy_search_rows(
-    items_by_recent_id: Vec<&PersistedItem>,
-    query_terms: &[String],
-    limit: i64,
-    seen_ids: &HashSet<i64>,
-) -> Vec<SearchResult> {
-    if limit <= 0 {
-        return Vec::new();
+fn clamp_image_limits(limits: ImageLimits) -> ImageLimits {
+    ImageLimits {
+        max_images_per_note: limits
+            .max_images_per_note
+            .clamp(IMAGE_COUNT_LIMIT_BOUNDS.0, IMAGE_COUNT_LIMIT_BOUNDS.1),
+        max_image_bytes: limits
+            .max_image_bytes
+            .clamp(IMAGE_BYTES_LIMIT_BOUNDS.0, IMAGE_BYTES_LIMIT_BOUNDS.1),
     }
+}
 
-    let has_fuzzy_term = query_terms
-        .iter()
-        .any(|term| term.chars().count() >= FUZZY_QUERY_TERM_MIN_CHARS);
-    if !has_fuzzy_term {
+/// Free space and mirror size for the JSON storage root, with budget alerts and
+/// the `largest_limit` largest items.
+pub fn storage_report(largest_limit: usize) -> Result<StorageReport> {
+    let (root, budget_bytes, mut largest_items) = run_with_store(|store| {
+        let excluded_tags = store.mirror_excluded_tags();
+        let usage: Vec<ItemStorageUsage> = store
+            .data
+            .items
+            .values()
+            .map(|item| ItemStorageUsage {
+                id: item.id,
+                title: item.title.clone(),
+                bytes: item_storage_bytes(item),
+                mirrored: mirror_exclusion(item, &excluded_tags).is_none(),
+            })
+            .collect();
+        Ok((
+            store.json_storage_root(),
+            store.storage_budget_bytes(),
+            usage,
+        ))
+    })?;
+
+    largest_items.sort_by_key(|item| std::cmp::Reverse(item.bytes));
+    largest_items.truncate(largest_limit);
+
+    // The volume is queried through the nearest existing ancestor so a root
+    // that was never written yet still reports free space.
+    let volume_path = root.ancestors().find(|path| path.exists());
+    let snapshot = StorageSnapshot {
+        cloud_provider: storage_budget::detect_cloud_provider(&root),
+        available_bytes: volume_path.and_then(|path| fs4::available_space(path).ok()),
+        total_bytes: volume_path.and_then(|path| fs4::total_space(path).ok()),
+        mirror_bytes: directory_size(&root),
+        budget_bytes,
+    };
+    Ok(StorageReport {
+        root: root.to_string_lossy().to_string(),
+        alerts: storage_budget::evaluate(&snapshot),
+        snapshot,
+        largest_items,
+    })
+}
+
+fn item_storage_bytes(item: &PersistedItem) -> u64 {
+    let text_bytes = item.title.len() + item.subtitle.len() + item.keywords.len() + item.note.len();
+    let image_bytes: usize = item.images.iter().map(|image| image.bytes.len()).sum();
+    (text_bytes + image_bytes) as u64
+}
+
+/// Total size of the files below `path`; unreadable entries are skipped.
+fn directory_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => directory_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+pub fn load_triggers() -> Result<Vec<Trigger>> {
+    run_with_store(|store| Ok(store.configured_triggers()))
+}
+
+/// Inserts or replaces a trigger by id, assigning a fresh id when empty.
+pub fn save_trigger(trigger: &Trigger) -> Result<String> {
+    run_with_store(|store| {
+        let mut trigger = trigger.clone();
+        let mut triggers = store.configured_triggers();
+        if trigger.id.is_empty() {
+            let stamp = unix_timestamp();
+            let mut suffix = triggers.len();
+            trigger.id = loop {
+                let candidate = format!("trigger-{stamp}-{suffix}");
+                if !triggers.iter().any(|existing| existing.id == candidate) {
+                    break candidate;
+                }
+                suffix += 1;
+            };
+        }
+
+        let id = trigger.id.clone();
+        match triggers.iter_mut().find(|existing| existing.id == id) {
+            Some(existing) => *existing = trigger,
+            None => triggers.push(trigger),
+        }
+
+        store.set_triggers(&triggers)?;
+        Ok(id)
+    })
+}
+
+pub fn delete_trigger(trigger_id: &str) -> Result<()> {
+    run_with_store(|store| {
+        let mut triggers = store.configured_triggers();
+        let before = triggers.len();
+        triggers.retain(|trigger| trigger.id != trigger_id);
+        if triggers.len() == before {
+            return Err(anyhow!("trigger not found: {trigger_id}"));
+        }
+
+        store.set_triggers(&triggers)?;
+        triggers::remove_log(&trigger_log_dir()?, trigger_id)
+    })
+}
+
+pub fn trigger_log(trigger_id: &str, limit: usize) -> Result<Vec<TriggerLogEntry>> {
+    triggers::read_log(&trigger_log_dir()?, trigger_id, limit)
+}
+
+/// Pages whose `read <url>` fetch failed, oldest first.
+pub fn load_read_later_queue() -> Result<Vec<QueuedArticle>> {
+    run_with_store(|store| Ok(store.read_later_queue()))
+}
+
+/// Queues `url` for another fetch, or counts another failed attempt when it
+/// is queued already.
+pub fn queue_read_later_article(url: &str, error: &str) -> Result<()> {
+    run_with_store(|store| {
+        let mut queue = store.read_later_queue();
+        match queue.iter_mut().find(|queued| queued.url == url) {
+            Some(queued) => {
+                queued.attempts += 1;
+                queued.last_error = error.to_string();
+            }
+            None => {
+                if queue.len() >= read_later::MAX_QUEUED_ARTICLES {
+                    return Err(anyhow!(
+                        "the read-later queue is full ({} pages)",
+                        read_later::MAX_QUEUED_ARTICLES
+                    ));
+                }
+                queue.push(QueuedArticle {
+                    url: url.to_string(),
+                    queued_at_unix_seconds: unix_timestamp(),
+                    attempts: 1,
+                    last_error: error.to_string(),
+                });
+            }
+        }
+        store.set_read_later_queue(&queue)
+    })
+}
+
+pub fn remove_queued_read_later_article(url: &str) -> Result<()> {
+    run_with_store(|store| {
+        let mut queue = store.read_later_queue();
+        let before = queue.len();
+        queue.retain(|queued| queued.url != url);
+        if queue.len() == before {
+            return Ok(());
+        }
+        store.set_read_later_queue(&queue)
+    })
+}
+
+pub fn load_schedules() -> Result<Vec<Schedule>> {
+    run_with_store(|store| Ok(store.configured_schedules()))
+}
+
+/// Inserts or replaces a schedule by id, assigning a fresh id when empty. The
+/// last-run marker of an existing schedule is kept; new schedules start now.
+pub fn save_schedule(schedule: &Schedule) -> Result<String> {
+    run_with_store(|store| {
+        let mut schedule = schedule.clone();
+        let mut schedules = store.configured_schedules();
+        if schedule.id.is_empty() {
+            let stamp = unix_timestamp();
+            let mut suffix = schedules.len();
+            schedule.id = loop {
+                let candidate = format!("schedule-{stamp}-{suffix}");
+                if !schedules.iter().any(|existing| existing.id == candidate) {
+                    break candidate;
+                }
+                suffix += 1;
+            };
+        }
+
+        let id = schedule.id.clone();
+        match schedules.iter_mut().find(|existing| existing.id == id) {
+            Some(existing) => {
+                schedule.last_run_unix_seconds = existing.last_run_unix_seconds;
+                *existing = schedule;
+            }
+            None => {
+                schedule.last_run_unix_seconds = Local::now().timestamp();
+                schedules.push(schedule);
+            }
+        }
+
+        store.set_schedules(&schedules)?;
+        schedules::wake_scheduler();
+        Ok(id)
+    })
+}
+
+pub fn delete_schedule(schedule_id: &str) -> Result<()> {
+    run_with_store(|store| {
+        let mut schedules = store.configured_schedules();
+        let before = schedules.len();
+        schedules.retain(|schedule| schedule.id != schedule_id);
+        if schedules.len() == before {
+            return Err(anyhow!("schedule not found: {schedule_id}"));
+        }
+        store.set_schedules(&schedules)?;
+        schedules::wake_scheduler();
+        Ok(())
+    })
+}
+
+/// Creates an instance note for every schedule whose occurrence passed since its
+/// last run and returns the new item ids.
+pub fn run_due_schedules() -> Result<Vec<i64>> {
+    run_with_store(|store| {
+        let now = Local::now();
+        let mut schedules = store.configured_schedules();
+        let mut created = Vec::new();
+        for schedule in schedules.iter_mut() {
+            let Some(occurrence) = schedule.due_occurrence(&now) else {
+                continue;
+            };
+            let date = occurrence.date_naive();
+            let title = schedules::render_template(&schedule.title_template, date);
+            let note = schedules::render_template(&schedule.note_template, date);
+            created.push(store.push_new_item(&title, &note));
+            schedule.last_run_unix_seconds = now.timestamp();
+        }
+
+        if created.is_empty() {
+            return Ok(created);
+        }
+
+        store.set_schedules(&schedules)?;
+        for id in &created {
+            if let Some(item) = store.item_by_id(*id) {
+                store.fire_item_event(TriggerEvent::Created, item);
+            }
+        }
+        Ok(created)
+    })
+}
+
+/// Next occurrence of any enabled schedule, in unix seconds.
+fn next_schedule_occurrence() -> Result<Option<i64>> {
+    run_with_store(|store| {
+        let now = Local::now();
+        Ok(store
+            .configured_schedules()
+            .iter()
+            .filter(|schedule| schedule.enabled)
+            .filter_map(|schedule| schedule.next_occurrence(&now))
+            .map(|occurrence| occurrence.timestamp())
+            .min())
+    })
+}
+
+fn run_due_schedules_on_tick() -> Option<i64> {
+    if let Err(err) = run_due_schedules() {
+        log_error("failed to run scheduled notes", err);
+    }
+    next_schedule_occurrence().unwrap_or_else(|err| {
+        log_error("failed to find the next scheduled note", err);
+        None
+    })
+}
+
+pub fn search(query: &str, limit: i64) -> Result<Vec<SearchResult>> {
+    run_with_store(|store| {
+        let mut rows = search_rows(store, query, limit)?;
+        store.attach_item_details(&mut rows);
+        Ok(rows)
+    })
+}
+
+/// Every item matching `query`, filter modifiers included (an empty query
+/// lists all), for the browse window. Pinned items stay first in every order.
+pub fn browse_items(query: &str, sort: BrowseSort) -> Result<Vec<SearchResult>> {
+    run_with_store(|store| {
+        let item_count = store.data.items.len() as i64;
+        let mut rows = search_rows(store, query, item_count.max(1))?;
+        sort_browse_rows(&mut rows, sort, |id| {
+            store
+                .item_by_id(id)
+                .map_or((0, 0), |item| (item.meta.created_at, item.meta.updated_at))
+        });
+        store.attach_item_details(&mut rows);
+        Ok(rows)
+    })
+}
+
+/// Stable sort of `rows` by `sort`; `timestamps` gives an item's created and
+/// updated unix seconds.
+fn sort_browse_rows(
+    rows: &mut [SearchResult],
+    sort: BrowseSort,
+    timestamps: impl Fn(i64) -> (u64, u64),
+) {
+    match sort {
+        BrowseSort::Relevance => rows.sort_by_key(|row| !row.pinned),
+        BrowseSort::Updated => {
+            rows.sort_by_key(|row| (!row.pinned, std::cmp::Reverse(timestamps(row.id).1)))
+        }
+        BrowseSort::Created => {
+            rows.sort_by_key(|row| (!row.pinned, std::cmp::Reverse(timestamps(row.id).0)))
+        }
+        BrowseSort::Title => rows.sort_by_cached_key(|row| (!row.pinned, row.title.to_lowercase())),
+    }
+}
+
+/// Answer blocks whose keywords (or, without keywords, note title) match every
+/// word of `query`, from the most recently created notes first.
+pub fn search_answers(query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+    if query.trim().is_empty() || limit == 0 {
+        return Ok(Vec::new());
+    }
+    run_with_store(|store| {
+        let mut rows = Vec::new();
+        for item in store.ordered_items_by_id_desc() {
+            if !item.note.contains(ANSWER_FENCE) {
+                continue;
+            }
+            for block in answers::parse_answer_blocks(&item.note) {
+                let keywords = if block.keywords.is_empty() {
+                    &item.title
+                } else {
+                    &block.keywords
+                };
+                if !answers::answer_matches(keywords, query) {
+                    continue;
+                }
+                let content = note_plain_text(&block.content);
+                rows.push(SearchResult {
+                    id: item.id,
+                    title: content.lines().next().unwrap_or_default().to_string(),
+                    subtitle: format!("{keywords} · {}", item.title),
+                    snippet: None,
+                    snippet_source: None,
+                    kind: ResultKind::Answer,
+                    icon: Some("text.quote".to_string()),
+                    command_key: Some(content),
+                    pinned: false,
+                    thumbnail_key: None,
+                    title_match_ranges: Vec::new(),
+                    checklist_progress: None,
+                });
+                if rows.len() >= limit {
+                    return Ok(rows);
+                }
+            }
+        }
+        Ok(rows)
+    })
+}
+
+fn search_rows(store: &mut Store, query: &str, limit: i64) -> Result<Vec<SearchResult>> {
+    let limit = limit.max(0);
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let query = query.trim();
+    let parsed = query_filters::parse_query_filters(query);
+    if !parsed.filters.is_empty() {
+        return filtered_search_rows(store, &parsed, limit);
+    }
+
+    if query.is_empty() {
+        let mut items = store.ordered_items_for_listing();
+        items.sort_by_key(|item| !item.meta.pinned);
+        let rows = items
+            .into_iter()
+            .take(limit as usize)
+            .map(|item| SearchResult {
+                id: item.id,
+                title: item.title.clone(),
+                subtitle: item.subtitle.clone(),
+                snippet: None,
+                snippet_source: None,
+                kind: ResultKind::Item,
+                icon: None,
+                command_key: None,
+                pinned: item.meta.pinned,
+                thumbnail_key: None,
+                title_match_ranges: Vec::new(),
+                checklist_progress: None,
+            })
+            .collect();
+        return Ok(rows);
+    }
+
+    let query_terms = parse_query_terms(query);
+    let query_groups = parse_query_groups(query, store.search_match_any());
+    let results = ranked_search_rows(store, &query_groups, &query_terms, limit)?;
+
+    // Pinned matches ranked below the cut-off would otherwise be lost, so
+    // pinned items are matched separately before ordering them first.
+    let pinned_items: Vec<&PersistedItem> = store
+        .ordered_items_by_id_asc()
+        .into_iter()
+        .filter(|item| item.meta.pinned)
+        .collect();
+    if pinned_items.is_empty() {
+        return Ok(results);
+    }
+
+    let mut seen_ids: HashSet<i64> = results.iter().map(|row| row.id).collect();
+    let mut extra_pinned = substring_search_rows(
+        pinned_items.clone(),
+        &query_groups,
+        &query_terms,
+        pinned_items.len(),
+        &seen_ids,
+    );
+    seen_ids.extend(extra_pinned.iter().map(|row| row.id));
+    extra_pinned.extend(fuzzy_search_rows(
+        pinned_items.iter().rev().copied().collect(),
+        &query_terms,
+        pinned_items.len() as i64,
+        &seen_ids,
+    ));
+
+    Ok(pinned_first(results, extra_pinned, limit as usize))
+}
+
+/// Rows for a query with filter modifiers: the remaining text is searched as
+/// usual (an empty one lists every item) and only items passing all filters
+/// are kept.
+fn filtered_search_rows(
+    store: &mut Store,
+    parsed: &ParsedQuery,
+    limit: i64,
+) -> Result<Vec<SearchResult>> {
+    let item_count = store.data.items.len() as i64;
+    let rows = search_rows(store, &parsed.text, item_count.max(1))?;
+    Ok(rows
+        .into_iter()
+        .filter(|row| {
+            store
+                .item_by_id(row.id)
+                .is_some_and(|item| item_matches_filters(item, &parsed.filters))
+        })
+        .take(limit as usize)
+        .collect())
+}
+
+fn item_matches_filters(item: &PersistedItem, filters: &[QueryFilter]) -> bool {
+    let local_date = |unix_seconds: u64| {
+        i64::try_from(unix_seconds)
+            .ok()
+            .filter(|seconds| *seconds > 0)
+            .and_then(|seconds| Local.timestamp_opt(seconds, 0).single())
+            .map(|time| time.date_naive())
+    };
+    filters.iter().all(|filter| match filter {
+        QueryFilter::Tag(tag) => query_filters::tag_matches(&item_tags(item), tag),
+        QueryFilter::HasImage => !item.images.is_empty(),
+        QueryFilter::HasChecklist => checklist::checklist_progress(&item.note).is_some(),
+        QueryFilter::Pinned => item.meta.pinned,
+        QueryFilter::Created(bound) => {
+            local_date(item.meta.created_at).is_some_and(|date| bound.matches(date))
+        }
+        QueryFilter::Updated(bound) => {
+            local_date(item.meta.updated_at).is_some_and(|date| bound.matches(date))
+        }
+    })
+}
+
+/// Orders pinned rows (in their ranked order, then `extra_pinned`) ahead of the
+/// remaining rows and truncates to `limit`.
+fn pinned_first(
+    results: Vec<SearchResult>,
+    extra_pinned: Vec<SearchResult>,
+    limit: usize,
+) -> Vec<SearchResult> {
+    let (pinned, unpinned): (Vec<_>, Vec<_>) = results.into_iter().partition(|row| row.pinned);
+    pinned
+        .into_iter()
+        .chain(extra_pinned)
+        .chain(unpinned)
+        .take(limit)
+        .collect()
+}
+
+/// Full-text, then substring, then fuzzy matches, reordered by the ranking
+/// script when there is one.
+fn ranked_search_rows(
+    store: &mut Store,
+    query_groups: &[Vec<String>],
+    query_terms: &[String],
+    limit: i64,
+) -> Result<Vec<SearchResult>> {
+    if store.ranking_script.is_none() {
+        let candidates = search_candidates(store, query_groups, query_terms, limit)?;
+        return Ok(candidates
+            .into_iter()
+            .map(|candidate| candidate.row)
+            .collect());
+    }
+
+    let candidates = search_candidates(
+        store,
+        query_groups,
+        query_terms,
+        limit.saturating_mul(RANKING_SCRIPT_CANDIDATE_FACTOR),
+    )?;
+    let now = unix_timestamp();
+    let mut featured = Vec::with_capacity(candidates.len());
+    for (position, candidate) in candidates.into_iter().enumerate() {
+        let Some(item) = store.item_by_id(candidate.row.id) else {
+            continue;
+        };
+        let tags = item_tags(item);
+        let features = RankingFeatures {
+            tier: candidate.tier as i64,
+            score: f64::from(candidate.score),
+            position: position as i64,
+            age_days: now.saturating_sub(item.meta.updated_at) as f64 / 86_400.0,
+            tag_matches: query_terms
+                .iter()
+                .filter(|term| tags.contains(&term.trim_start_matches('#').to_lowercase()))
+                .count() as i64,
+            pinned: item.meta.pinned,
+        };
+        featured.push((candidate.row, features));
+    }
+
+    let fallback: Vec<SearchResult> = featured.iter().map(|(row, _)| row.clone()).collect();
+    let ranked = match &store.ranking_script {
+        Some(script) => script.rank(featured),
+        None => Ok(fallback.clone()),
+    };
+    let mut rows = ranked.unwrap_or_else(|err| {
+        log_error("keeping the built-in order", err);
+        fallback
+    });
+    rows.truncate(limit as usize);
+    Ok(rows)
+}
+
+/// A search match with the tier that found it (0 full-text, 1 substring,
+/// 2 fuzzy) and that tier's relevance score.
+struct SearchCandidate {
+    row: SearchResult,
+    tier: u8,
+    score: f32,
+}
+
+fn search_candidates(
+    store: &mut Store,
+    query_groups: &[Vec<String>],
+    query_terms: &[String],
+    limit: i64,
+) -> Result<Vec<SearchCandidate>> {
+    let mut results = Vec::with_capacity(limit as usize);
+    let mut seen_ids = HashSet::with_capacity(limit as usize);
+
+    let lucene_hits = store.lucene_search_hits(query_groups, limit as usize)?;
+    for hit in lucene_hits {
+        if !seen_ids.insert(hit.id) {
+            continue;
+        }
+
+        let Some(item) = store.item_by_id(hit.id) else {
+            continue;
+        };
+
+        results.push(SearchCandidate {
+            row: map_search_item(item, query_terms, hit.note_snippet),
+            tier: 0,
+            score: hit.score,
+        });
+        if results.len() as i64 >= limit {
+            return Ok(results);
+        }
+    }
+
+    if (results.len() as i64) < limit {
+        let remaining = (limit - results.len() as i64) as usize;
+        let substring_rows = substring_search_rows(
+            store.ordered_items_by_id_asc(),
+            query_groups,
+            query_terms,
+            remaining,
+            &seen_ids,
+        );
+
+        for row in substring_rows {
+            if seen_ids.insert(row.id) {
+                results.push(SearchCandidate {
+                    row,
+                    tier: 1,
+                    score: 0.0,
+                });
+                if results.len() as i64 >= limit {
+                    return Ok(results);
+                }
+            }
+        }
+    }
+
+    if (results.len() as i64) < limit {
+        let remaining = limit - results.len() as i64;
+        let fuzzy_rows = fuzzy_search_rows(
+            store.ordered_items_by_id_desc(),
+            query_terms,
+            remaining,
+            &seen_ids,
+        );
+
+        for row in fuzzy_rows {
+            if seen_ids.insert(row.id) {
+                let score = store.item_by_id(row.id).map_or(0.0, |item| {
+                    fuzzy_row_score(&item.title, &item.note, query_terms)
+                });
+                results.push(SearchCandidate {
+                    row,
+                    tier: 2,
+                    score,
+                });
+                if results.len() as i64 >= limit {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+fn substring_search_rows(
+    items: Vec<&PersistedItem>,
+    query_groups: &[Vec<String>],
+    query_terms: &[String],
+    limit: usize,
+    seen_ids: &HashSet<i64>,
+) -> Vec<SearchResult> {
+    if limit == 0 {
         return Vec::new();
     }
 
-    let scan_limit = (limit.max(8) * FUZZY_SCAN_MULTIPLIER).min(FUZZY_SCAN_MAX_ROWS);
+    let lowered_groups: Vec<Vec<String>> = query_groups
+        .iter()
+        .map(|group| group.iter().map(|token| token.to_lowercase()).collect())
+        .collect();
+
+    let mut output = Vec::new();
+    for item in items {
+        if seen_ids.contains(&item.id) {
+            continue;
+        }
+
+        let title_lower = item.title.to_lowercase();
+        let note_lower = item.note.to_lowercase();
+        let image_text_lower: Vec<String> = item
+            .images
+            .iter()
+            .filter_map(|image| image.text.as_deref())
+            .filter(|text| !text.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        let matches = lowered_groups.iter().any(|group| {
+            group.iter().all(|token| {
+                title_lower.contains(token)
+                    || note_lower.contains(token)
+                    || image_text_lower.iter().any(|text| text.contains(token))
+            })
+        });
+
+        if matches {
+            output.push(map_search_item(item, query_terms, None));
+            if output.len() >= limit {
+                break;
+            }
+        }
+    }
+
+    output
+}
+
+fn map_search_item(
+    item: &PersistedItem,
+    query_terms: &[String],
+    preferred_snippet: Option<String>,
+) -> SearchResult {
+    let snippet_data = preferred_snippet
+        .map(|snippet| ("note".to_string(), snippet))
+        .or_else(|| {
+            build_snippet_with_terms(
+                &item.title,
+                &item.subtitle,
+                &item.keywords,
+                &item.note,
+                query_terms,
+            )
+        });
+
+    let snippet_data = snippet_data.or_else(|| build_image_text_snippet(&item.images, query_terms));
+    let (snippet_source, snippet) = match snippet_data {
+        Some((source, snippet)) => (Some(source), Some(snippet)),
+        None => (None, None),
+    };
+
+    SearchResult {
+        id: item.id,
+        title: item.title.clone(),
+        subtitle: String::new(),
+        snippet,
+        snippet_source,
+        kind: ResultKind::Item,
+        icon: None,
+        command_key: None,
+        pinned: item.meta.pinned,
+        thumbnail_key: None,
+        title_match_ranges: query_match_ranges(&item.title, query_terms),
+        checklist_progress: None,
+    }
+}
+
+fn fuzzy_search_rows(
+    items_by_recent_id: Vec<&PersistedItem>,
+    query_terms: &[String],
+    limit: i64,
+    seen_ids: &HashSet<i64>,
+) -> Vec<SearchResult> {
+    if limit <= 0 {
+        return Vec::new();
+    }
+
+    let has_fuzzy_term = query_terms
+        .iter()
+        .any(|term| term.chars().count() >= FUZZY_QUERY_TERM_MIN_CHARS);
+    if !has_fuzzy_term {
+        return Vec::new();
+    }
+
+    let scan_limit = (limit.max(8) * FUZZY_SCAN_MULTIPLIER).min(FUZZY_SCAN_MAX_ROWS);
+
+    let mut scored: Vec<FuzzyCandidate> = Vec::new();
+    for item in items_by_recent_id.into_iter().take(scan_limit as usize) {
+        if seen_ids.contains(&item.id) {
+            continue;
+        }
+
+        let score = fuzzy_row_score(&item.title, &item.note, query_terms);
+        if score < FUZZY_SIMILARITY_THRESHOLD {
+            continue;
+        }
+
+        scored.push(FuzzyCandidate {
+            score,
+            id: item.id,
+            title: item.title.clone(),
+            subtitle: item.subtitle.clone(),
+            keywords: item.keywords.clone(),
+            note: item.note.clone(),
+            pinned: item.meta.pinned,
+        });
+    }
+
+    scored.sort_by(|left, right| {
+        right
+            .score
+            .partial_cmp(&left.score)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| left.title.cmp(&right.title))
+            .then_with(|| left.id.cmp(&right.id))
+    });
+
+    scored
+        .into_iter()
+        .take(limit as usize)
+        .map(|candidate| {
+            let snippet_data = build_snippet_with_terms(
+                &candidate.title,
+                &candidate.subtitle,
+                &candidate.keywords,
+                &candidate.note,
+                query_terms,
+            );
+            let (snippet_source, snippet) = match snippet_data {
+                Some((source, snippet)) => (Some(source), Some(snippet)),
+                None => (None, None),
+            };
+            let title_match_ranges = query_match_ranges(&candidate.title, query_terms);
+            SearchResult {
+                id: candidate.id,
+                title: candidate.title,
+                subtitle: String::new(),
+                snippet,
+                snippet_source,
+                kind: ResultKind::Item,
+                icon: None,
+                command_key: None,
+                pinned: candidate.pinned,
+                thumbnail_key: None,
+                title_match_ranges,
+                checklist_progress: None,
+            }
+        })
+        .collect()
+}
+
+struct LuceneSearchHit {
+    id: i64,
+    score: f32,
+    note_snippet: Option<String>,
+}
+
+struct FuzzyCandidate {
+    score: f32,
+    id: i64,
+    title: String,
+    subtitle: String,
+    keywords: String,
+    note: String,
+    pinned: bool,
+}
+
+pub fn insert_item(title: &str) -> Result<i64> {
+    run_with_store(|store| {
+        let id = store.push_new_item(title, "");
+        store.flush_all()?;
+        if let Some(item) = store.item_by_id(id) {
+            store.fire_item_event(TriggerEvent::Created, item);
+        }
+        Ok(id)
+    })
+}
+
+/// Adds imported notes as new items with a single flush and returns their ids.
+/// `source` names where they came from in the activity log.
+pub fn import_notes(notes: &[ImportedNote], source: &str) -> Result<Vec<i64>> {
+    if notes.is_empty() {
+        return Ok(Vec::new());
+    }
+    run_with_store(|store| {
+        let mut ids = Vec::with_capacity(notes.len());
+        for imported in notes {
+            let id = store.push_new_item(&imported.title, &imported.note);
+            if let Some(item) = store.item_by_id_mut(id) {
+                item.images = imported
+                    .images
+                    .iter()
+                    .map(|image| PersistedImage {
+                        image_key: image.image_key.clone(),
+                        bytes: image.bytes.clone(),
+                        text: None,
+                    })
+                    .collect();
+            }
+            ids.push(id);
+        }
+        store.flush_all()?;
+        record_activity(
+            ActivityKind::Import,
+            activity::current_origin(),
+            None,
+            Some(format!("{} from {source}", count_label(ids.len(), "note"))),
+        );
+        for id in &ids {
+            if let Some(item) = store.item_by_id(*id) {
+                store.fire_item_event(TriggerEvent::Created, item);
+            }
+        }
+        Ok(ids.clone())
+    })
+}
+
+pub fn fetch_item(id: i64) -> Result<EditableItem> {
+    run_with_store(|store| {
+        let item = store
+            .item_by_id(id)
+            .ok_or_else(|| anyhow!("item not found: {id}"))?;
+
+        let images = item
+            .images
+            .iter()
+            .map(|image| NoteImage {
+                image_key: image.image_key.clone(),
+                bytes: image.bytes.clone(),
+            })
+            .collect();
+
+        Ok(EditableItem {
+            id: item.id,
+            title: item.title.clone(),
+            subtitle: item.subtitle.clone(),
+            keywords: item.keywords.clone(),
+            note: item.note.clone(),
+            images,
+            revision: item.meta.revision,
+            secure: item.meta.secure,
+            mirror_exclusion: mirror_exclusion(item, &store.mirror_excluded_tags()),
+        })
+    })
+}
+
+/// Every item except `locked`, the secure items that can't be read right now.
+pub fn export_items_snapshot(locked: &HashSet<i64>) -> Result<Vec<ExportItem>> {
+    let rows = export_items_matching(|id| !locked.contains(&id))?;
+    record_export(rows.len(), "JSON");
+    Ok(rows)
+}
+
+/// Pretty-printed JSON of the selected items, in the `export_items_snapshot` shape.
+pub fn export_items_json(ids: &[i64]) -> Result<String> {
+    let rows = export_items_matching(|id| ids.contains(&id))?;
+    if let Some(missing) = ids.iter().find(|id| !rows.iter().any(|row| row.id == **id)) {
+        return Err(anyhow!("item not found: {missing}"));
+    }
+    let json = serde_json::to_string_pretty(&rows).context("failed to serialize exported items")?;
+    record_export(rows.len(), "JSON");
+    Ok(json)
+}
+
+fn record_export(count: usize, format: &str) {
+    record_activity(
+        ActivityKind::Export,
+        activity::current_origin(),
+        None,
+        Some(format!("{} as {format}", count_label(count, "item"))),
+    );
+}
+
+/// `1 note`, `3 notes`.
+fn count_label(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
+
+fn export_items_matching(include: impl Fn(i64) -> bool) -> Result<Vec<ExportItem>> {
+    run_with_store(|store| {
+        let mut rows: Vec<ExportItem> = store
+            .data
+            .items
+            .values()
+            .filter(|item| include(item.id))
+            .map(|item| ExportItem {
+                id: item.id,
+                title: item.title.clone(),
+                subtitle: item.subtitle.clone(),
+                keywords: item.keywords.clone(),
+                note: item.note.clone(),
+                image_count: item.images.len() as i64,
+            })
+            .collect();
+
+        rows.sort_by(|left, right| {
+            left.title
+                .to_lowercase()
+                .cmp(&right.title.to_lowercase())
+                .then_with(|| left.id.cmp(&right.id))
+        });
+        Ok(rows)
+    })
+}
+
+/// Every item as CSV with a header row: id, title, tags, created and updated
+/// local times, word count and image count. Note bodies are left out, and
+/// items in `locked` only get their id, title and dates.
+pub fn export_items_csv(locked: &HashSet<i64>) -> Result<String> {
+    run_with_store(|store| {
+        let mut csv = String::from("id,title,tags,created,updated,words,images\r\n");
+        for item in store.ordered_items_by_id_asc() {
+            let is_locked = locked.contains(&item.id);
+            let tags = if is_locked {
+                String::new()
+            } else {
+                item_tags(item)
+                    .iter()
+                    .map(|tag| format!("#{tag}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            let (words, images) = if is_locked {
+                (String::new(), String::new())
+            } else {
+                (
+                    note_plain_text(&item.note)
+                        .split_whitespace()
+                        .count()
+                        .to_string(),
+                    item.images.len().to_string(),
+                )
+            };
+            let fields = [
+                item.id.to_string(),
+                item.title.clone(),
+                tags,
+                csv_local_time(item.meta.created_at),
+                csv_local_time(item.meta.updated_at),
+                words,
+                images,
+            ];
+            let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push_str("\r\n");
+        }
+        record_export(store.data.items.len(), "CSV");
+        Ok(csv)
+    })
+}
+
+/// Quotes `value` when it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Empty for items that predate timestamp tracking.
+fn csv_local_time(unix_seconds: u64) -> String {
+    if unix_seconds == 0 {
+        return String::new();
+    }
+    i64::try_from(unix_seconds)
+        .ok()
+        .and_then(|seconds| Local.timestamp_opt(seconds, 0).single())
+        .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// Replaces the note (and optionally images, subtitle and keywords) of an item and
+/// returns its new revision.
+///
+/// When `expected_revision` is set and the stored revision has moved on, the save is
+/// rejected with a revision conflict instead of overwriting the newer content.
+pub fn update_item(
+    id: i64,
+    note: &str,
+    images: Option<&[NoteImage]>,
+    metadata: Option<&ItemMetadata>,
+    expected_revision: Option<u64>,
+) -> Result<u64> {
+    run_with_store(|store| {
+        if let Some(images) = images {
+            let limits = store.image_limits();
+            ensure!(
+                images.len() <= limits.max_images_per_note,
+                "too many note images (max {})",
+                limits.max_images_per_note
+            );
+
+            for image in images {
+                ensure!(
+                    image.bytes.len() <= limits.max_image_bytes,
+                    "image '{}' exceeds {} KB storage limit",
+                    image.image_key,
+                    limits.max_image_bytes / 1024
+                );
+            }
+        }
+
+        let Some(item) = store.item_by_id_mut(id) else {
+            if matches!(images, Some(imgs) if !imgs.is_empty()) {
+                return Err(anyhow!("item not found: {id}"));
+            }
+            return Ok(0);
+        };
+
+        if let Some(expected) = expected_revision {
+            ensure!(
+                item.meta.revision == expected,
+                "revision conflict: item {id} is at revision {}, expected {expected}",
+                item.meta.revision
+            );
+        }
+
+        item.note = note.to_string();
+        if let Some(metadata) = metadata {
+            item.subtitle = metadata.subtitle.clone();
+            item.keywords = metadata.keywords.clone();
+        }
+        let revision = item.meta.mark_saved();
+
+        if let Some(images) = images {
+            // Recognized text stays with images whose bytes didn't change.
+            let previous: HashMap<&str, &PersistedImage> = item
+                .images
+                .iter()
+                .map(|image| (image.image_key.as_str(), image))
+                .collect();
+            let images = images
+                .iter()
+                .map(|image| PersistedImage {
+                    image_key: image.image_key.clone(),
+                    bytes: image.bytes.clone(),
+                    text: previous
+                        .get(image.image_key.as_str())
+                        .filter(|old| old.bytes == image.bytes)
+                        .and_then(|old| old.text.clone()),
+                })
+                .collect();
+            item.images = images;
+        }
+
+        store.flush_all()?;
+        if let Some(item) = store.item_by_id(id) {
+            record_note_history(item);
+            store.fire_item_event(TriggerEvent::Saved, item);
+        }
+        Ok(revision)
+    })
+}
+
+pub fn rename_item(id: i64, title: &str) -> Result<()> {
+    run_with_store(|store| {
+        let Some(item) = store.item_by_id_mut(id) else {
+            return Err(anyhow!("item not found: {id}"));
+        };
+
+        // Keep keyword defaults aligned with title for discoverability, but
+        // leave keywords edited in the editor alone.
+        if item.keywords == item.title {
+            item.keywords = title.to_string();
+        }
+        item.title = title.to_string();
+        item.meta.updated_at = unix_timestamp();
+
+        store.flush_all()?;
+        record_activity(
+            ActivityKind::Update,
+            activity::current_origin(),
+            Some((id, title)),
+            Some("renamed".to_string()),
+        );
+        Ok(())
+    })
+}
+
+pub fn item_stats(id: i64) -> Result<ItemStats> {
+    let (note, created_at, updated_at) = run_with_store(|store| {
+        let item = store
+            .item_by_id(id)
+            .ok_or_else(|| anyhow!("item not found: {id}"))?;
+        Ok((
+            item.note.clone(),
+            item.meta.created_at,
+            item.meta.updated_at,
+        ))
+    })?;
+
+    let plain_text = note_plain_text(&note);
+    let versions = history::load_versions(&note_history_dir()?, id)?;
+    Ok(ItemStats {
+        word_count: plain_text.split_whitespace().count(),
+        char_count: plain_text.chars().count(),
+        created_at,
+        updated_at,
+        version_count: versions.len(),
+        days: history::daily_activity(&versions, &Local),
+    })
+}
+
+fn load_item_versions(id: i64) -> Result<Vec<NoteVersion>> {
+    run_with_store(|store| {
+        store
+            .item_by_id(id)
+            .map(|_| ())
+            .ok_or_else(|| anyhow!("item not found: {id}"))
+    })?;
+    history::load_versions(&note_history_dir()?, id)
+}
+
+fn version_at(versions: &[NoteVersion], index: usize) -> Result<&NoteVersion> {
+    versions
+        .get(index)
+        .ok_or_else(|| anyhow!("version not found: {index}"))
+}
+
+/// Recorded versions of an item, oldest first.
+pub fn item_versions(id: i64) -> Result<Vec<NoteVersionSummary>> {
+    let versions = load_item_versions(id)?;
+    Ok(versions
+        .iter()
+        .enumerate()
+        .map(|(index, version)| NoteVersionSummary {
+            index,
+            at_unix_seconds: version.at_unix_seconds,
+            saves: version.saves,
+            char_count: note_plain_text(&version.note).chars().count(),
+        })
+        .collect())
+}
+
+pub fn item_version_text(id: i64, index: usize) -> Result<String> {
+    let versions = load_item_versions(id)?;
+    Ok(note_plain_text(&version_at(&versions, index)?.note))
+}
+
+/// Word diff between the plain text of two versions of an item.
+pub fn diff_item_versions(id: i64, old_index: usize, new_index: usize) -> Result<Vec<DiffSegment>> {
+    let versions = load_item_versions(id)?;
+    let old = note_plain_text(&version_at(&versions, old_index)?.note);
+    let new = note_plain_text(&version_at(&versions, new_index)?.note);
+    Ok(diff::diff_words(&old, &new))
+}
+
+/// Records that an item was opened so it surfaces in `recent_items`.
+pub fn mark_item_opened(id: i64) -> Result<()> {
+    run_with_store(|store| {
+        let Some(item) = store.item_by_id_mut(id) else {
+            return Err(anyhow!("item not found: {id}"));
+        };
+        item.meta.last_opened_at = unix_timestamp();
+        record_usage_event(UsageEventKind::Open, Some(id));
+        // Item meta is not part of the JSON mirror, so only the index needs rewriting.
+        store.rebuild_index()
+    })
+}
+
+/// Counts a launcher search the user acted on.
+pub fn record_search() {
+    record_usage_event(UsageEventKind::Search, None);
+}
+
+/// Item and image totals, searches over the last `days` days and the
+/// `top_limit` most opened items that still exist.
+pub fn usage_stats(days: u32, top_limit: usize) -> Result<UsageStats> {
+    let summary = usage::summarize(
+        &usage::read_events(&usage_log_path()?)?,
+        &Local,
+        Local::now().date_naive(),
+        days,
+    );
+    run_with_store(|store| {
+        let images = store.data.items.values().flat_map(|item| &item.images);
+        Ok(UsageStats {
+            item_count: store.data.items.len(),
+            image_count: images.clone().count(),
+            image_bytes: images.map(|image| image.bytes.len() as u64).sum(),
+            searches_per_day: summary.searches_per_day.clone(),
+            most_opened: summary
+                .opens_by_item
+                .iter()
+                .filter_map(|&(id, opens)| {
+                    let item = store.item_by_id(id)?;
+                    Some(OpenedItem {
+                        id,
+                        title: item.title.clone(),
+                        opens,
+                    })
+                })
+                .take(top_limit)
+                .collect(),
+        })
+    })
+}
+
+/// Pinned items followed by the most recently opened or edited ones.
+pub fn recent_items(limit: i64) -> Result<Vec<SearchResult>> {
+    run_with_store(|store| {
+        let mut items = store.ordered_items_by_id_desc();
+        items.sort_by_key(|item| {
+            (
+                !item.meta.pinned,
+                std::cmp::Reverse(item.meta.last_used_at()),
+            )
+        });
+        let mut rows: Vec<SearchResult> = items
+            .into_iter()
+            .take(limit.max(0) as usize)
+            .map(|item| SearchResult {
+                id: item.id,
+                title: item.title.clone(),
+                subtitle: item.subtitle.clone(),
+                snippet: None,
+                snippet_source: None,
+                kind: ResultKind::Item,
+                icon: None,
+                command_key: None,
+                pinned: item.meta.pinned,
+                thumbnail_key: None,
+                title_match_ranges: Vec::new(),
+                checklist_progress: None,
+            })
+            .collect();
+        store.attach_item_details(&mut rows);
+        Ok(rows)
+    })
+}
+
+/// Items with a tag starting with `tag`; exact matches first, then by recent use.
+/// An empty `tag` lists every tagged item.
+pub fn search_by_tag(tag: &str, limit: i64) -> Result<Vec<SearchResult>> {
+    let tag = tags::normalize_tag(tag);
+    run_with_store(|store| {
+        let tagged = store.tagged_item_ids(&tag)?;
+        let mut matches: Vec<(bool, &PersistedItem, Vec<String>)> = store
+            .ordered_items_by_id_desc()
+            .into_iter()
+            .filter(|item| tagged.contains(&item.id))
+            .filter_map(|item| {
+                let matched = matching_tags(&item_tags(item), &tag);
+                let exact = matched.contains(&tag);
+                (!matched.is_empty()).then_some((exact, item, matched))
+            })
+            .collect();
+        matches.sort_by_key(|(exact, item, _)| {
+            (
+                !exact,
+                !item.meta.pinned,
+                std::cmp::Reverse(item.meta.last_used_at()),
+            )
+        });
+        let mut rows: Vec<SearchResult> = matches
+            .into_iter()
+            .take(limit.max(0) as usize)
+            .map(|(_, item, matched)| SearchResult {
+                id: item.id,
+                title: item.title.clone(),
+                subtitle: item.subtitle.clone(),
+                snippet: Some(
+                    matched
+                        .iter()
+                        .map(|tag| format!("#{tag}"))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                ),
+                snippet_source: None,
+                kind: ResultKind::Item,
+                icon: None,
+                command_key: None,
+                pinned: item.meta.pinned,
+                thumbnail_key: None,
+                title_match_ranges: Vec::new(),
+                checklist_progress: None,
+            })
+            .collect();
+        store.attach_item_details(&mut rows);
+        Ok(rows)
+    })
+}
+
+/// Query for documents with a term in the `tags` field starting with `prefix`.
+/// `None` when `prefix` holds characters no tag can contain.
+fn tag_prefix_query(field: Field, prefix: &str) -> Option<RegexQuery> {
+    let mut pattern = String::with_capacity(prefix.len() + 8);
+    for ch in prefix.chars() {
+        match ch {
+            _ if ch.is_alphanumeric() => pattern.push(ch),
+            '-' | '_' | '/' => {
+                pattern.push('[');
+                pattern.push(ch);
+                pattern.push(']');
+            }
+            _ => return None,
+        }
+    }
+    pattern.push_str(".*");
+    RegexQuery::from_pattern(&pattern, field).ok()
+}
+
+fn matching_tags(tags: &[String], prefix: &str) -> Vec<String> {
+    tags.iter()
+        .filter(|tag| tag.starts_with(prefix))
+        .cloned()
+        .collect()
+}
+
+/// Whether the item is marked secure; unknown ids are not.
+pub fn is_item_secure(id: i64) -> Result<bool> {
+    run_with_store(|store| Ok(store.item_by_id(id).is_some_and(|item| item.meta.secure)))
+}
+
+/// Ids of the items marked secure.
+pub fn secure_item_ids() -> Result<HashSet<i64>> {
+    run_with_store(|store| {
+        Ok(store
+            .data
+            .items
+            .values()
+            .filter(|item| item.meta.secure)
+            .map(|item| item.id)
+            .collect())
+    })
+}
+
+/// The stored digest of the app lock key, if one is enrolled.
+pub fn load_app_lock_verifier() -> Result<Option<String>> {
+    run_with_store(|store| {
+        Ok(store
+            .data
+            .settings
+            .get(APP_LOCK_VERIFIER_SETTING_KEY)
+            .cloned())
+    })
+}
+
+/// `None` removes the app lock.
+pub fn save_app_lock_verifier(verifier: Option<&str>) -> Result<()> {
+    run_with_store(|store| {
+        match verifier {
+            Some(verifier) => store.data.settings.insert(
+                APP_LOCK_VERIFIER_SETTING_KEY.to_string(),
+                verifier.to_string(),
+            ),
+            None => store.data.settings.remove(APP_LOCK_VERIFIER_SETTING_KEY),
+        };
+        store.flush_all()
+    })
+}
+
+/// Marks an item secure, which keeps it (and its images) out of the JSON mirror.
+pub fn set_item_secure(id: i64, secure: bool) -> Result<()> {
+    run_with_store(|store| {
+        let Some(item) = store.item_by_id_mut(id) else {
+            return Err(anyhow!("item not found: {id}"));
+        };
+        if item.meta.secure == secure {
+            return Ok(());
+        }
+        item.meta.secure = secure;
+        store.flush_all()?;
+        if secure {
+            // Versions saved before the item became secure are plain text.
+            history::remove_history(&note_history_dir()?, id)?;
+        }
+        Ok(())
+    })
+}
+
+pub fn set_item_pinned(id: i64, pinned: bool) -> Result<()> {
+    run_with_store(|store| {
+        let Some(item) = store.item_by_id_mut(id) else {
+            return Err(anyhow!("item not found: {id}"));
+        };
+        if item.meta.pinned == pinned {
+            return Ok(());
+        }
+
+        item.meta.pinned = pinned;
+        store.flush_all()
+    })
+}
+
+/// Starts a timer on an item, stopping the one running on another item.
+/// Does nothing when the item's timer is already running.
+pub fn start_timer(id: i64) -> Result<()> {
+    run_with_store(|store| {
+        if store.item_by_id(id).is_none() {
+            return Err(anyhow!("item not found: {id}"));
+        }
+        let now = unix_timestamp();
+        match store.running_timer() {
+            Some(running) if running.item_id == id => return Ok(()),
+            Some(running) => {
+                store.finish_timer(running.item_id, now);
+            }
+            None => {}
+        }
+        if let Some(item) = store.item_by_id_mut(id) {
+            item.meta.timer_started_at = Some(now);
+        }
+        store.flush_all()
+    })
+}
+
+/// Stops the running timer and returns its item and the seconds it ran.
+pub fn stop_timer() -> Result<Option<(i64, u64)>> {
+    run_with_store(|store| {
+        let Some(running) = store.running_timer() else {
+            return Ok(None);
+        };
+        let seconds = store.finish_timer(running.item_id, unix_timestamp());
+        store.flush_all()?;
+        Ok(Some((running.item_id, seconds)))
+    })
+}
+
+pub fn running_timer() -> Result<Option<RunningTimer>> {
+    run_with_store(|store| Ok(store.running_timer()))
+}
+
+/// Total tracked seconds of an item, including its running timer.
+pub fn tracked_seconds(id: i64) -> Result<u64> {
+    run_with_store(|store| {
+        let Some(item) = store.item_by_id(id) else {
+            return Err(anyhow!("item not found: {id}"));
+        };
+        Ok(item_time_entries(item, unix_timestamp())
+            .iter()
+            .map(TimeEntry::seconds)
+            .sum())
+    })
+}
+
+/// Tracked time per note in the local week starting on the Monday of
+/// `date`'s week. A running timer counts up to now.
+pub fn weekly_time_report(date: NaiveDate) -> Result<WeekReport> {
+    run_with_store(|store| {
+        let now = unix_timestamp();
+        let items = store
+            .data
+            .items
+            .values()
+            .map(|item| (item.id, item.title.clone(), item_time_entries(item, now)));
+        Ok(time_tracking::weekly_report(
+            items,
+            time_tracking::week_start(date),
+            &Local,
+        ))
+    })
+}
+
+/// The time entries of the local week starting on the Monday of `date`'s
+/// week as CSV, one row per entry in start order.
+pub fn export_time_entries_csv(date: NaiveDate) -> Result<String> {
+    run_with_store(|store| {
+        let now = unix_timestamp();
+        let week_start = time_tracking::week_start(date);
+        let from = time_tracking::day_start(week_start, &Local);
+        let until = time_tracking::day_start(week_start + chrono::Duration::days(7), &Local);
+        let mut rows: Vec<(TimeEntry, &PersistedItem)> = store
+            .data
+            .items
+            .values()
+            .flat_map(|item| {
+                item_time_entries(item, now)
+                    .into_iter()
+                    .filter(|entry| entry.started_at < until && entry.ended_at > from)
+                    .map(move |entry| (entry, item))
+            })
+            .collect();
+        rows.sort_by_key(|(entry, item)| (entry.started_at, item.id));
+
+        let mut csv = String::from("date,start,end,hours,id,title,tags\r\n");
+        for (entry, item) in &rows {
+            let local = |seconds: u64, format: &str| {
+                Local
+                    .timestamp_opt(seconds as i64, 0)
+                    .single()
+                    .map(|time| time.format(format).to_string())
+                    .unwrap_or_default()
+            };
+            let tags = item_tags(item)
+                .iter()
+                .map(|tag| format!("#{tag}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let fields = [
+                local(entry.started_at, JOURNAL_DATE_FORMAT),
+                local(entry.started_at, "%H:%M"),
+                local(entry.ended_at, "%H:%M"),
+                format!("{:.2}", entry.seconds() as f64 / 3600.0),
+                item.id.to_string(),
+                item.title.clone(),
+                tags,
+            ];
+            let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push_str("\r\n");
+        }
+        let mut item_ids: Vec<i64> = rows.iter().map(|(_, item)| item.id).collect();
+        item_ids.sort_unstable();
+        item_ids.dedup();
+        record_export(item_ids.len(), "time CSV");
+        Ok(csv)
+    })
+}
+
+/// An item's finished time entries plus its running timer up to `now`.
+fn item_time_entries(item: &PersistedItem, now: u64) -> Vec<TimeEntry> {
+    let mut entries = item.meta.time_entries.clone();
+    if let Some(started_at) = item.meta.timer_started_at {
+        entries.push(TimeEntry {
+            started_at,
+            ended_at: now.max(started_at),
+        });
+    }
+    entries
+}
+
+/// Deletes one item and returns the archive key that restores it.
+pub fn delete_item(id: i64) -> Result<String> {
+    delete_items(&[id])?
+        .pop()
+        .ok_or_else(|| anyhow!("item not found: {id}"))
+}
 
-    let mut scored: Vec<FuzzyCandidate> = Vec::new();
-    for item in items_by_recent_id.into_iter().take(scan_limit as usize) {
-        if seen_ids.contains(&item.id) {
-            continue;
-        }
+/// Archives and removes several items with a single index rebuild. Fails
+/// without deleting anything when one of the ids is unknown. Returns the
+/// archive keys in the order of `ids`.
+pub fn delete_items(ids: &[i64]) -> Result<Vec<String>> {
+    run_with_store(|store| {
+        let items = ids
+            .iter()
+            .map(|id| {
+                store
+                    .item_by_id(*id)
+                    .cloned()
+                    .ok_or_else(|| anyhow!("item not found: {id}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-        let score = fuzzy_row_score(&item.title, &item.note, query_terms);
-        if score < FUZZY_SIMILARITY_THRESHOLD {
-            continue;
+        let root = store.json_storage_root();
+        let secure_root = secure_archive_root()?;
+        let mut archive_keys = Vec::with_capacity(items.len());
+        for item in &items {
+            archive_keys.push(archive_deleted_item_in(&root, &secure_root, item)?);
+            store.data.items.remove(&item.id);
         }
 
-        scored.push(FuzzyCandidate {
-            score,
-            id: item.id,
-            title: item.title.clone(),
-            subtitle: item.subtitle.clone(),
-            keywords: item.keywords.clone(),
-            note: item.note.clone(),
-        });
-    }
+        store.flush_all()?;
+        for item in &items {
+            store.fire_item_event(TriggerEvent::Deleted, item);
+        }
+        Ok(archive_keys)
+    })
+}
 
-    scored.sort_by(|left, right| {
-        right
-            .score
-            .partial_cmp(&left.score)
-            .unwrap_or(Ordering::Equal)
-            .then_with(|| left.title.cmp(&right.title))
-            .then_with(|| left.id.cmp(&right.id))
-    });
+/// Appends `#tag` to the notes of the given items that don't carry it yet.
+/// Returns how many items changed.
+pub fn add_tag_to_items(ids: &[i64], tag: &str) -> Result<usize> {
+    run_with_store(|store| {
+        if let Some(missing) = ids.iter().find(|id| store.item_by_id(**id).is_none()) {
+            return Err(anyhow!("item not found: {missing}"));
+        }
 
-    scored
-        .into_iter()
-        .take(limit as usize)
-        .map(|candidate| {
-            let snippet_data = build_snippet_with_terms(
-                &candidate.title,
-                &candidate.subtitle,
-                &candidate.keywords,
-                &candidate.note,
-                query_terms,
-            );
-            let (snippet_source, snippet) = match snippet_data {
-                Some((source, snippet)) => (Some(source), Some(snippet)),
-                None => (None, None),
+        let mut changed = Vec::new();
+        for id in ids {
+            let Some(item) = store.item_by_id_mut(*id) else {
+                continue;
             };
-            SearchResult {
-                id: candidate.id,
-                title: candidate.title,
-                subtitle: String::new(),
-                snippet,
-                snippet_source,
+            let note = tags::add_tag(&item.note, tag);
+            if note != item.note {
+                item.note = note;
+                item.meta.mark_saved();
+                changed.push(*id);
             }
-        })
-        .collect()
+        }
+        if changed.is_empty() {
+            return Ok(0);
+        }
+
+        store.flush_all()?;
+        for id in &changed {
+            if let Some(item) = store.item_by_id(*id) {
+                record_note_history(item);
+                store.fire_item_event(TriggerEvent::Saved, item);
+            }
+        }
+        Ok(changed.len())
+    })
 }
 
-struct LuceneSearchHit {
-    id: i64,
-    note_snippet: Option<String>,
+pub fn get_item_json_path(id: i64) -> Result<String> {
+    run_with_store(|store| {
+        let Some(item) = store.item_by_id(id) else {
+            return Err(anyhow!("item not found: {id}"));
+        };
+        if let Some(reason) = mirror_exclusion(item, &store.mirror_excluded_tags()) {
+            return Err(anyhow!(
+                "item {id} is not mirrored to JSON storage ({reason})"
+            ));
+        }
+        let root = store.json_storage_root();
+        let file_name = item_json_file_name(id);
+        Ok(root.join(file_name).to_string_lossy().to_string())
+    })
 }
 
-struct FuzzyCandidate {
-    score: f32,
-    id: i64,
-    title: String,
-    subtitle: String,
-    keywords: String,
-    note: String,
+/// Items carrying one of the board status tags. An item tagged with several
+/// statuses is placed in the furthest one along the workflow.
+pub fn board_cards() -> Result<Vec<BoardCard>> {
+    run_with_store(|store| {
+        let cards = store
+            .ordered_items_by_id_desc()
+            .into_iter()
+            .filter_map(|item| {
+                let tags = item_tags(item);
+                let status = BOARD_STATUS_TAGS
+                    .iter()
+                    .rev()
+                    .find(|status| tags.iter().any(|tag| tag == *status))?;
+                let preview = sanitize_note_for_preview(&item.note)
+                    .chars()
+                    .take(BOARD_PREVIEW_MAX_CHARS)
+                    .collect();
+                Some(BoardCard {
+                    id: item.id,
+                    title: item.title.clone(),
+                    status: status.to_string(),
+                    preview,
+                })
+            })
+            .collect();
+        Ok(cards)
+    })
 }
 
-pub fn insert_item(title: &str) -> Result<i64> {
+/// Moves an item to another board column by rewriting its status tag in the
+/// note. Returns the item's revision after the change.
+pub fn set_item_board_status(id: i64, status: &str) -> Result<u64> {
+    ensure!(
+        BOARD_STATUS_TAGS.contains(&status),
+        "unknown board status: {status}"
+    );
+
     run_with_store(|store| {
-        let id = store.next_item_id();
-        store.data.items.insert(
-            id,
-            PersistedItem {
-                id,
-                title: title.to_string(),
-                subtitle: String::new(),
-                keywords: title.to_string(),
-                note: String::new(),
-                images: Vec::new(),
-            },
-        );
+        let item = store
+            .item_by_id_mut(id)
+            .ok_or_else(|| anyhow!("item not found: {id}"))?;
+        let note = tags::replace_tag_in_group(&item.note, &BOARD_STATUS_TAGS, status);
+        if note == item.note {
+            return Ok(item.meta.revision);
+        }
+
+        item.note = note;
+        let revision = item.meta.mark_saved();
+
         store.flush_all()?;
-        Ok(id)
+        if let Some(item) = store.item_by_id(id) {
+            record_note_history(item);
+            store.fire_item_event(TriggerEvent::Saved, item);
+        }
+        Ok(revision)
     })
 }
 
-pub fn fetch_item(id: i64) -> Result<EditableItem> {
+/// Checklist entries of item `id`, with their text as plain text.
+pub fn item_checklist(id: i64) -> Result<Vec<ChecklistEntry>> {
     run_with_store(|store| {
         let item = store
             .item_by_id(id)
             .ok_or_else(|| anyhow!("item not found: {id}"))?;
-
-        let images = item
-            .images
-            .iter()
-            .map(|image| NoteImage {
-                image_key: image.image_key.clone(),
-                bytes: image.bytes.clone(),
+        Ok(checklist::parse_checklist(&item.note)
+            .into_iter()
+            .map(|entry| ChecklistEntry {
+                text: note_plain_text(&entry.text),
+                ..entry
             })
-            .collect();
+            .collect())
+    })
+}
 
-        Ok(EditableItem {
-            id: item.id,
-            title: item.title.clone(),
-            note: item.note.clone(),
-            images,
-        })
+/// Checks or unchecks checklist entry `index` of item `id` and returns the new
+/// revision.
+pub fn toggle_item_checklist_entry(id: i64, index: usize) -> Result<u64> {
+    run_with_store(|store| {
+        let item = store
+            .item_by_id_mut(id)
+            .ok_or_else(|| anyhow!("item not found: {id}"))?;
+        item.note = checklist::toggle_checklist_entry(&item.note, index)
+            .ok_or_else(|| anyhow!("item {id} has no checklist entry {index}"))?;
+        let revision = item.meta.mark_saved();
+
+        store.flush_all()?;
+        if let Some(item) = store.item_by_id(id) {
+            record_note_history(item);
+            store.fire_item_event(TriggerEvent::Saved, item);
+        }
+        Ok(revision)
     })
 }
 
-pub fn export_items_snapshot() -> Result<Vec<ExportItem>> {
+/// Notes placed on the days of a month: journal entries on their journal date,
+/// other items on the local days they were created and last updated.
+pub fn calendar_month(year: i32, month: u32) -> Result<Vec<CalendarEntry>> {
     run_with_store(|store| {
-        let mut rows: Vec<ExportItem> = store
-            .data
-            .items
-            .values()
-            .map(|item| ExportItem {
-                id: item.id,
-                title: item.title.clone(),
-                subtitle: item.subtitle.clone(),
-                keywords: item.keywords.clone(),
-                note: item.note.clone(),
-                image_count: item.images.len() as i64,
+        let mut entries: Vec<CalendarEntry> = store
+            .ordered_items_by_id_asc()
+            .into_iter()
+            .flat_map(|item| {
+                item_calendar_dates(&item.meta, &Local)
+                    .into_iter()
+                    .filter(|(date, _)| date.year() == year && date.month() == month)
+                    .map(|(date, is_journal)| CalendarEntry {
+                        day: date.day(),
+                        item_id: item.id,
+                        title: item.title.clone(),
+                        is_journal,
+                    })
             })
             .collect();
-
-        rows.sort_by(|left, right| {
-            left.title
-                .to_lowercase()
-                .cmp(&right.title.to_lowercase())
-                .then_with(|| left.id.cmp(&right.id))
-        });
-        Ok(rows)
+        entries.sort_by_key(|entry| (entry.day, !entry.is_journal, entry.item_id));
+        Ok(entries)
     })
 }
 
-pub fn update_item(id: i64, note: &str, images: Option<&[NoteImage]>) -> Result<()> {
-    if let Some(images) = images {
-        ensure!(
-            images.len() <= MAX_NOTE_IMAGE_COUNT,
-            "too many note images (max {MAX_NOTE_IMAGE_COUNT})"
-        );
+fn item_calendar_dates<Tz: TimeZone>(meta: &PersistedItemMeta, tz: &Tz) -> Vec<(NaiveDate, bool)> {
+    if let Some(date) = meta
+        .journal_date
+        .as_deref()
+        .and_then(|raw| NaiveDate::parse_from_str(raw, JOURNAL_DATE_FORMAT).ok())
+    {
+        return vec![(date, true)];
+    }
 
-        for image in images {
-            ensure!(
-                image.bytes.len() <= MAX_SCREENSHOT_BYTES,
-                "image '{}' exceeds {} KB storage limit",
-                image.image_key,
-                MAX_SCREENSHOT_BYTES / 1024
-            );
+    let mut dates: Vec<(NaiveDate, bool)> = Vec::new();
+    for timestamp in [meta.created_at, meta.updated_at] {
+        let Ok(seconds) = i64::try_from(timestamp) else {
+            continue;
+        };
+        if seconds == 0 {
+            continue;
+        }
+        if let Some(local) = tz.timestamp_opt(seconds, 0).single() {
+            let date = local.date_naive();
+            if !dates.iter().any(|(existing, _)| *existing == date) {
+                dates.push((date, false));
+            }
         }
     }
+    dates
+}
 
+/// Returns the journal entry for `date`, creating it when the day has none yet.
+pub fn open_or_create_journal_entry(date: NaiveDate) -> Result<i64> {
+    let date_key = date.format(JOURNAL_DATE_FORMAT).to_string();
     run_with_store(|store| {
-        let Some(item) = store.item_by_id_mut(id) else {
-            if matches!(images, Some(imgs) if !imgs.is_empty()) {
-                return Err(anyhow!("item not found: {id}"));
-            }
-            return Ok(());
-        };
-
-        item.note = note.to_string();
-
-        if let Some(images) = images {
-            item.images = images
-                .iter()
-                .map(|image| PersistedImage {
-                    image_key: image.image_key.clone(),
-                    bytes: image.bytes.clone(),
-                })
-                .collect();
+        if let Some(existing) = store
+            .data
+            .items
+            .values()
+            .find(|item| item.meta.journal_date.as_deref() == Some(date_key.as_str()))
+        {
+            return Ok(existing.id);
         }
 
-        store.flush_all()
+        let id = store.push_new_item(&format!("Journal {date_key}"), "");
+        if let Some(item) = store.item_by_id_mut(id) {
+            item.meta.journal_date = Some(date_key.clone());
+        }
+        store.flush_all()?;
+        if let Some(item) = store.item_by_id(id) {
+            store.fire_item_event(TriggerEvent::Created, item);
+        }
+        Ok(id)
     })
 }
 
-pub fn rename_item(id: i64, title: &str) -> Result<()> {
+/// Up to `limit` notes whose title matches `query`, for completing `[[` note
+/// links; see `note_links::rank_titles`. Newer notes come first on ties.
+pub fn suggest_note_titles(query: &str, limit: usize) -> Result<Vec<(i64, String)>> {
     run_with_store(|store| {
-        let Some(item) = store.item_by_id_mut(id) else {
-            return Err(anyhow!("item not found: {id}"));
-        };
-
-        item.title = title.to_string();
-        // Keep keyword defaults aligned with title for discoverability.
-        item.keywords = title.to_string();
+        let notes = store
+            .ordered_items_by_id_desc()
+            .into_iter()
+            .map(|item| (item.id, item.title.as_str()));
+        Ok(note_links::rank_titles(notes, query, limit))
+    })
+}
 
-        store.flush_all()
+/// The note a `[[title]]` link opens: the one with that title, ignoring case
+/// and surrounding spaces, the newest when several share it.
+pub fn find_item_by_title(title: &str) -> Result<Option<i64>> {
+    let title = title.trim().to_lowercase();
+    run_with_store(|store| {
+        Ok(store
+            .ordered_items_by_id_desc()
+            .into_iter()
+            .find(|item| item.title.trim().to_lowercase() == title)
+            .map(|item| item.id))
     })
 }
 
-pub fn delete_item(id: i64) -> Result<()> {
+pub fn get_item_plain_text(id: i64) -> Result<String> {
     run_with_store(|store| {
         let item = store
             .item_by_id(id)
-            .cloned()
             .ok_or_else(|| anyhow!("item not found: {id}"))?;
+        Ok(note_plain_text(&item.note))
+    })
+}
 
-        archive_deleted_item(&store.json_storage_root(), &item)?;
-
-        if store.data.items.remove(&id).is_none() {
-            return Err(anyhow!("item not found: {id}"));
-        }
+/// The item as a Markdown file: the title as a heading followed by the note,
+/// with bold text kept and inline images left out.
+pub fn get_item_markdown(id: i64) -> Result<ItemMarkdown> {
+    run_with_store(|store| {
+        let item = store
+            .item_by_id(id)
+            .ok_or_else(|| anyhow!("item not found: {id}"))?;
+        Ok(ItemMarkdown {
+            file_name: markdown_file_name(&item.title),
+            markdown: note_markdown(&item.title, &item.note),
+        })
+    })
+}
 
-        store.flush_all()
+/// Bytes of one image of an item, served from memory for result thumbnails.
+pub fn get_item_image(id: i64, image_key: &str) -> Result<Vec<u8>> {
+    run_with_store(|store| {
+        let item = store
+            .item_by_id(id)
+            .ok_or_else(|| anyhow!("item not found: {id}"))?;
+        item.images
+            .iter()
+            .find(|image| image.image_key == image_key)
+            .map(|image| image.bytes.clone())
+            .ok_or_else(|| anyhow!("image not found: {image_key}"))
     })
 }
 
-pub fn get_item_json_path(id: i64) -> Result<String> {
+/// Image keys of an item in gallery order, see `gallery_image_keys`.
+pub fn list_item_image_keys(id: i64) -> Result<Vec<String>> {
     run_with_store(|store| {
-        if store.item_by_id(id).is_none() {
-            return Err(anyhow!("item not found: {id}"));
-        }
-        let root = store.json_storage_root();
-        let file_name = item_json_file_name(id);
-        Ok(root.join(file_name).to_string_lossy().to_string())
+        let item = store
+            .item_by_id(id)
+            .ok_or_else(|| anyhow!("item not found: {id}"))?;
+        Ok(gallery_image_keys(&item.note, &item.images))
     })
 }
 
@@ -1465,8 +4280,8 @@ pub fn list_deleted_items(limit: i64) -> Result<Vec<DeletedItemSummary>> {
             return Ok(Vec::new());
         }
 
-        let root = store.json_storage_root();
-        let mut rows: Vec<DeletedItemSummary> = read_deleted_archives(&root)?
+        let roots = store.deleted_archive_roots()?;
+        let mut rows: Vec<DeletedItemSummary> = read_deleted_archives(&roots)?
             .into_iter()
             .map(|archive| DeletedItemSummary {
                 archive_key: archive.archive_key,
@@ -1491,30 +4306,39 @@ pub fn list_deleted_items(limit: i64) -> Result<Vec<DeletedItemSummary>> {
 
 pub fn restore_deleted_item(archive_key: &str) -> Result<i64> {
     run_with_store(|store| {
-        let root = store.json_storage_root();
-        let archive = find_deleted_archive(&root, archive_key)?;
+        let roots = store.deleted_archive_roots()?;
+        let archive = find_deleted_archive(&roots, archive_key)?;
         restore_deleted_archive(store, &archive)
     })
 }
 
 pub fn permanently_delete_deleted_item(archive_key: &str) -> Result<()> {
     run_with_store(|store| {
-        let root = store.json_storage_root();
-        let archive = find_deleted_archive(&root, archive_key)?;
+        let roots = store.deleted_archive_roots()?;
+        let archive = find_deleted_archive(&roots, archive_key)?;
         std::fs::remove_dir_all(&archive.archive_dir).with_context(|| {
             format!(
                 "failed removing deleted archive permanently {}",
                 archive.archive_dir.display()
             )
         })?;
+        if store.item_by_id(archive.payload.id).is_none() {
+            history::remove_history(&note_history_dir()?, archive.payload.id)?;
+        }
+        record_activity(
+            ActivityKind::Delete,
+            activity::current_origin(),
+            Some((archive.payload.id, &archive.payload.title)),
+            Some("permanently deleted".to_string()),
+        );
         Ok(())
     })
 }
 
 pub fn get_deleted_item_preview(archive_key: &str) -> Result<DeletedItemPreview> {
     run_with_store(|store| {
-        let root = store.json_storage_root();
-        let archive = find_deleted_archive(&root, archive_key)?;
+        let roots = store.deleted_archive_roots()?;
+        let archive = find_deleted_archive(&roots, archive_key)?;
         Ok(DeletedItemPreview {
             archive_key: archive.archive_key,
             id: archive.payload.id,
@@ -1523,6 +4347,7 @@ pub fn get_deleted_item_preview(archive_key: &str) -> Result<DeletedItemPreview>
             deleted_at_unix_seconds: i64::try_from(archive.payload.deleted_at_unix_seconds)
                 .unwrap_or(i64::MAX),
             image_count: archive.payload.images.len() as i64,
+            secure: archive.payload.secure,
         })
     })
 }
@@ -1559,7 +4384,27 @@ fn build_snippet_with_terms(
     if let Some(snippet) = build_field_snippet("subtitle", subtitle, query_terms, 32) {
         return Some(snippet);
     }
-    build_field_snippet("keywords", keywords, query_terms, 32)
+    build_field_snippet("keywords", keywords, query_terms, 32)
+}
+
+/// Snippet from the first image whose recognized text matches, with the source
+/// `image:<image key>` so opening the result can scroll to that image.
+fn build_image_text_snippet(
+    images: &[PersistedImage],
+    query_terms: &[String],
+) -> Option<(String, String)> {
+    if query_terms.is_empty() {
+        return None;
+    }
+    images.iter().find_map(|image| {
+        let text = image.text.as_deref()?;
+        build_field_snippet(
+            &format!("{IMAGE_TEXT_SNIPPET_SOURCE_PREFIX}{}", image.image_key),
+            text,
+            query_terms,
+            24,
+        )
+    })
 }
 
 fn build_field_snippet(
@@ -1605,13 +4450,298 @@ fn build_field_snippet(
 }
 
 fn sanitize_note_for_preview(note: &str) -> String {
-    let without_images = strip_inline_image_refs(note);
+    let without_images = strip_inline_image_refs(note, true);
     let without_styles = strip_inline_style_tokens(&without_images);
     let collapsed = collapse_whitespace(&without_styles);
     strip_image_residue_tokens(&collapsed)
 }
 
-fn strip_inline_image_refs(text: &str) -> String {
+/// Note text suitable for the clipboard: inline images and style tokens are
+/// removed but line breaks are kept.
+fn note_plain_text(note: &str) -> String {
+    let without_images = strip_inline_image_refs(note, false);
+    let without_styles = strip_inline_style_tokens(&without_images);
+    let lines = without_styles
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    lines.trim_matches('\n').to_string()
+}
+
+fn note_markdown(title: &str, note: &str) -> String {
+    let body = note_plain_text(&note.replace("[[b]]", "**").replace("[[/b]]", "**"));
+    let title = title.trim();
+    match (title.is_empty(), body.is_empty()) {
+        (true, _) => format!("{body}\n"),
+        (false, true) => format!("# {title}\n"),
+        (false, false) => format!("# {title}\n\n{body}\n"),
+    }
+}
+
+/// A file name for the item's Markdown export, without path separators or
+/// characters Finder rejects.
+fn markdown_file_name(title: &str) -> String {
+    const MAX_STEM_CHARS: usize = 100;
+    let stem: String = title
+        .chars()
+        .map(|ch| match ch {
+            '/' | '\\' | ':' => '-',
+            ch if ch.is_control() => ' ',
+            ch => ch,
+        })
+        .take(MAX_STEM_CHARS)
+        .collect();
+    let stem = stem.trim().trim_start_matches('.');
+    if stem.is_empty() {
+        "Untitled.md".to_string()
+    } else {
+        format!("{stem}.md")
+    }
+}
+
+/// A Markdown or org note found in the Obsidian vault.
+struct VaultFile {
+    path: PathBuf,
+    format: VaultFormat,
+    content: String,
+    note: VaultNote,
+    /// Unix seconds.
+    modified_at: u64,
+}
+
+/// Every Markdown and org note in the vault, skipping hidden folders (like
+/// `.obsidian`) and the folders the app writes images and deleted items to.
+fn scan_vault_files(root: &Path) -> Result<Vec<VaultFile>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in
+            std::fs::read_dir(&dir).with_context(|| format!("failed to scan {}", dir.display()))?
+        {
+            let entry = entry?;
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|value| value.to_str()) else {
+                continue;
+            };
+            if name.starts_with('.') {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                if !matches!(
+                    name,
+                    vault::ATTACHMENTS_DIR_NAME
+                        | JSON_STORAGE_IMAGES_DIR_NAME
+                        | JSON_STORAGE_DELETED_DIR_NAME
+                ) {
+                    dirs.push(path);
+                }
+                continue;
+            }
+            let Some(format) = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(VaultFormat::from_extension)
+            else {
+                continue;
+            };
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let modified_at = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |duration| duration.as_secs());
+            let note = parse_vault_file_note(format, &content);
+            files.push(VaultFile {
+                note,
+                path,
+                format,
+                content,
+                modified_at,
+            });
+        }
+    }
+    files.sort_by(|left, right| left.path.cmp(&right.path));
+    Ok(files)
+}
+
+fn parse_vault_file_note(format: VaultFormat, content: &str) -> VaultNote {
+    match format {
+        VaultFormat::Markdown => vault::parse_vault_note(content),
+        VaultFormat::Org => org::parse_org_note(content),
+    }
+}
+
+fn vault_attachment_file_name(image: &PersistedImage) -> String {
+    let encoded = image_file_name(&image.image_key);
+    let stem = encoded.strip_suffix(".bin").unwrap_or(&encoded);
+    format!(
+        "{}{stem}.{}",
+        vault::ATTACHMENT_FILE_PREFIX,
+        vault::image_extension(&image.bytes).unwrap_or("png")
+    )
+}
+
+fn render_item_vault_note(item: &PersistedItem, format: VaultFormat) -> String {
+    let file_for_key = |key: &str| {
+        item.images
+            .iter()
+            .find(|image| image.image_key == key)
+            .map(vault_attachment_file_name)
+    };
+    match format {
+        VaultFormat::Markdown => vault::render_vault_note(item.id, &item.note, file_for_key),
+        VaultFormat::Org => org::render_org_note(item.id, &item.note, file_for_key),
+    }
+}
+
+/// The title a vault note's file name gives its item; the ` (id)` suffix added
+/// to tell apart notes with the same title is dropped.
+fn vault_file_title(path: &Path, id: Option<i64>) -> String {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    match id.and_then(|id| stem.strip_suffix(&format!(" ({id})"))) {
+        Some(title) => title.to_string(),
+        None => stem,
+    }
+}
+
+fn vault_file_matches_title(path: &Path, item: &PersistedItem) -> bool {
+    format!("{}.md", vault_file_title(path, Some(item.id))) == markdown_file_name(&item.title)
+}
+
+/// A free path in `dir` for the item's note, named after its title.
+fn vault_note_path(
+    dir: &Path,
+    item: &PersistedItem,
+    format: VaultFormat,
+    taken: &HashSet<PathBuf>,
+) -> PathBuf {
+    let file_name = markdown_file_name(&item.title);
+    let stem = file_name.strip_suffix(".md").unwrap_or(&file_name);
+    let extension = format.extension();
+    let path = dir.join(format!("{stem}.{extension}"));
+    if !taken.contains(&path) {
+        return path;
+    }
+    dir.join(format!("{stem} ({}).{extension}", item.id))
+}
+
+/// The note for a vault file's body. Embeds of the item's own attachments map
+/// back to their images; other embedded images in the vault are added to
+/// `images` while they fit the limits, and the rest stay as text.
+fn vault_body_to_note(
+    root: &Path,
+    file: &VaultFile,
+    images: &mut Vec<PersistedImage>,
+    limits: ImageLimits,
+) -> String {
+    let attachments_dir = root.join(vault::ATTACHMENTS_DIR_NAME);
+    // Org links are relative to the file, Obsidian embeds to the vault.
+    let file_dir = file.path.parent().unwrap_or(root);
+    let canonical_root = root.canonicalize().ok();
+    let mut next_key_suffix = 0u32;
+    let key_for_file = |target: &str| {
+        let name = target
+            .strip_prefix(vault::ATTACHMENTS_DIR_NAME)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .unwrap_or(target);
+        if let Some(image) = images
+            .iter()
+            .find(|image| vault_attachment_file_name(image) == name)
+        {
+            return Some(image.image_key.clone());
+        }
+
+        // Only images inside the vault are imported, whatever the note links to.
+        if Path::new(target).is_absolute()
+            || target.starts_with(['/', '\\'])
+            || target.split(['/', '\\']).any(|part| part == "..")
+        {
+            return None;
+        }
+        let path = [
+            root.join(target),
+            file_dir.join(target),
+            attachments_dir.join(target),
+        ]
+        .into_iter()
+        .find(|path| path.is_file())?
+        .canonicalize()
+        .ok()?;
+        if !path.starts_with(canonical_root.as_ref()?) {
+            return None;
+        }
+        let bytes = std::fs::read(&path).ok()?;
+        vault::image_extension(&bytes)?;
+        if let Some(image) = images.iter().find(|image| image.bytes == bytes) {
+            return Some(image.image_key.clone());
+        }
+        if images.len() >= limits.max_images_per_note || bytes.len() > limits.max_image_bytes {
+            return None;
+        }
+
+        let timestamp = unix_timestamp();
+        let key = loop {
+            next_key_suffix += 1;
+            let mut hasher = DefaultHasher::new();
+            (target, timestamp, next_key_suffix).hash(&mut hasher);
+            let key = format!("img-{timestamp}-{:08x}", hasher.finish() as u32);
+            if images.iter().all(|image| image.image_key != key) {
+                break key;
+            }
+        };
+        images.push(PersistedImage {
+            image_key: key.clone(),
+            bytes,
+            text: None,
+        });
+        Some(key)
+    };
+    match file.format {
+        VaultFormat::Markdown => vault::markdown_to_note(&file.note.body, key_for_file),
+        VaultFormat::Org => org::org_to_note(&file.note.body, key_for_file),
+    }
+}
+
+/// The first image referenced in the note, falling back to the first stored
+/// image when the note does not reference any of them.
+fn thumbnail_image_key(note: &str, images: &[PersistedImage]) -> Option<String> {
+    gallery_image_keys(note, images).into_iter().next()
+}
+
+/// Every stored image once: those referenced in the note in reading order,
+/// then the ones the note no longer references.
+fn gallery_image_keys(note: &str, images: &[PersistedImage]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::with_capacity(images.len());
+    let mut cursor = 0usize;
+    while let Some(start_rel) = note[cursor..].find(INLINE_IMAGE_URL_PREFIX) {
+        let key_start = cursor + start_rel + INLINE_IMAGE_URL_PREFIX.len();
+        let key_end = note[key_start..]
+            .find(['?', ')'])
+            .map_or(note.len(), |end| key_start + end);
+        let key = &note[key_start..key_end];
+        if images.iter().any(|image| image.image_key == key) && !keys.iter().any(|k| k == key) {
+            keys.push(key.to_string());
+        }
+        cursor = key_end;
+    }
+    for image in images {
+        if !keys.contains(&image.image_key) {
+            keys.push(image.image_key.clone());
+        }
+    }
+    keys
+}
+
+/// `text` without its inline image tokens. With `keep_alt`, an image's own alt
+/// text takes the token's place so previews and snippets can show it.
+fn strip_inline_image_refs(text: &str, keep_alt: bool) -> String {
     let mut output = String::with_capacity(text.len());
     let mut cursor = 0usize;
 
@@ -1620,11 +4750,18 @@ fn strip_inline_image_refs(text: &str) -> String {
         output.push_str(&text[cursor..start]);
 
         let alt_search = start + 2;
-        let Some(alt_end_rel) = text[alt_search..].find("](") else {
+        let Some(alt_end_rel) = text[alt_search..].find(']') else {
             output.push_str(&text[start..]);
             return output;
         };
-        let url_start = alt_search + alt_end_rel + 2;
+        let alt_end = alt_search + alt_end_rel;
+        if !text[alt_end..].starts_with("](") {
+            // A reference-style image (`![alt][ref]`) or a stray `![`.
+            output.push_str(&text[start..=alt_end]);
+            cursor = alt_end + 1;
+            continue;
+        }
+        let url_start = alt_end + 2;
         let Some(url_end_rel) = text[url_start..].find(')') else {
             output.push_str(&text[start..]);
             return output;
@@ -1632,7 +4769,10 @@ fn strip_inline_image_refs(text: &str) -> String {
         let url_end = url_start + url_end_rel;
         let url = &text[url_start..url_end];
 
-        if url.starts_with("alfred://image/") {
+        if url.starts_with(INLINE_IMAGE_URL_PREFIX) {
+            if keep_alt && let Some(alt) = vault::inline_image_alt(&text[start..=url_end]) {
+                output.push_str(alt);
+            }
             cursor = url_end + 1;
             continue;
         }
@@ -1737,13 +4877,33 @@ fn looks_like_image_residue(token: &str) -> bool {
 
 fn parse_query_terms(query: &str) -> Vec<String> {
     query
-        .split_whitespace()
+        .split(|ch: char| ch.is_whitespace() || ch == '|')
         .map(str::trim)
         .filter(|term| !term.is_empty())
         .map(|term| term.to_lowercase())
         .collect()
 }
 
+/// Splits a query into alternatives: an item matches when it contains every term
+/// of at least one group. `|` separates groups; with `match_any` every term is a
+/// group of its own.
+fn parse_query_groups(query: &str, match_any: bool) -> Vec<Vec<String>> {
+    let groups = query
+        .split('|')
+        .map(|group| {
+            group
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .filter(|group| !group.is_empty());
+    if match_any {
+        groups.flatten().map(|term| vec![term]).collect()
+    } else {
+        groups.collect()
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct FieldMatch {
     start: usize,
@@ -2015,7 +5175,36 @@ fn build_lucene_note_snippet(note: &str, snippet_generator: &SnippetGenerator) -
     }
 
     let snippet = snippet_generator.snippet(&sanitized_note);
-    snippet_with_markers(&snippet)
+    let best = snippet_with_markers(&snippet)?;
+
+    // A second fragment is taken from the text before or after the best one, so
+    // the two never overlap; the one with more matches wins.
+    let Some(start) = sanitized_note.find(snippet.fragment()) else {
+        return Some(best);
+    };
+    let end = start + snippet.fragment().len();
+    let before = second_snippet_fragment(&sanitized_note[..start], snippet_generator);
+    let after = second_snippet_fragment(&sanitized_note[end..], snippet_generator);
+    let fragments = match (before, after) {
+        (Some(before), Some(after)) if after.1 > before.1 => [best, after.0],
+        (Some(before), _) => [before.0, best],
+        (None, Some(after)) => [best, after.0],
+        (None, None) => return Some(best),
+    };
+    Some(fragments.join(SNIPPET_FRAGMENT_SEPARATOR))
+}
+
+/// The marked snippet of `text` and how many matches it highlights.
+fn second_snippet_fragment(
+    text: &str,
+    snippet_generator: &SnippetGenerator,
+) -> Option<(String, usize)> {
+    if text.trim().is_empty() {
+        return None;
+    }
+    let snippet = snippet_generator.snippet(text);
+    let marked = snippet_with_markers(&snippet)?;
+    Some((marked, snippet.highlighted().len()))
 }
 
 fn snippet_with_markers(snippet: &Snippet) -> Option<String> {
@@ -2045,13 +5234,13 @@ fn snippet_with_markers(snippet: &Snippet) -> Option<String> {
 
     let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
     for range in ranges {
-        if let Some(last) = merged.last_mut() {
-            if range.start <= last.end {
-                if range.end > last.end {
-                    last.end = range.end;
-                }
-                continue;
+        if let Some(last) = merged.last_mut()
+            && range.start <= last.end
+        {
+            if range.end > last.end {
+                last.end = range.end;
             }
+            continue;
         }
         merged.push(range);
     }
@@ -2080,6 +5269,28 @@ fn snippet_with_markers(snippet: &Snippet) -> Option<String> {
 }
 
 fn highlight_query_terms(text: &str, query_terms: &[String]) -> String {
+    let merged = query_match_ranges(text, query_terms);
+    if merged.is_empty() {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len() + merged.len() * 4);
+    let mut cursor = 0usize;
+    for (start, end) in merged {
+        result.push_str(&text[cursor..start]);
+        result.push_str("**");
+        result.push_str(&text[start..end]);
+        result.push_str("**");
+        cursor = end;
+    }
+    result.push_str(&text[cursor..]);
+
+    result
+}
+
+/// Byte ranges of `text` matching any query term case-insensitively, sorted and
+/// with overlapping matches merged.
+fn query_match_ranges(text: &str, query_terms: &[String]) -> Vec<(usize, usize)> {
     let mut ranges: Vec<(usize, usize)> = Vec::new();
     let index = build_lowercase_index(text);
 
@@ -2110,36 +5321,20 @@ fn highlight_query_terms(text: &str, query_terms: &[String]) -> String {
         }
     }
 
-    if ranges.is_empty() {
-        return text.to_string();
-    }
-
     ranges.sort_unstable_by_key(|(start, _)| *start);
     let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
     for (start, end) in ranges {
-        if let Some((_, last_end)) = merged.last_mut() {
-            if start <= *last_end {
-                if end > *last_end {
-                    *last_end = end;
-                }
-                continue;
+        if let Some((_, last_end)) = merged.last_mut()
+            && start <= *last_end
+        {
+            if end > *last_end {
+                *last_end = end;
             }
+            continue;
         }
         merged.push((start, end));
     }
-
-    let mut result = String::with_capacity(text.len() + merged.len() * 4);
-    let mut cursor = 0usize;
-    for (start, end) in merged {
-        result.push_str(&text[cursor..start]);
-        result.push_str("**");
-        result.push_str(&text[start..end]);
-        result.push_str("**");
-        cursor = end;
-    }
-    result.push_str(&text[cursor..]);
-
-    result
+    merged
 }
 
 fn highlight_span(text: &str, start: usize, end: usize) -> String {
@@ -2158,31 +5353,60 @@ fn highlight_span(text: &str, start: usize, end: usize) -> String {
     output
 }
 
-fn build_lucene_query(query: &str) -> Option<String> {
-    let mut terms = Vec::new();
-    for token in query.split_whitespace().take(12) {
-        let sanitized: String = token
-            .chars()
-            .filter(|ch| ch.is_alphanumeric() || *ch == '_' || *ch == '-')
-            .take(64)
-            .collect();
-        if !sanitized.is_empty() {
-            terms.push(format!("{sanitized}*"));
+/// Builds the Lucene query for `query_groups`: the terms of a group are AND'ed
+/// and the groups OR'ed, so items matching more alternatives score higher.
+fn build_lucene_query(query_groups: &[Vec<String>]) -> Option<String> {
+    let mut remaining_terms = 12usize;
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    for group in query_groups {
+        let mut terms = Vec::new();
+        for token in group.iter().take(remaining_terms) {
+            let sanitized: String = token
+                .chars()
+                .filter(|ch| ch.is_alphanumeric() || *ch == '_' || *ch == '-')
+                .take(64)
+                .collect();
+            if !sanitized.is_empty() {
+                terms.push(format!("{sanitized}*"));
+            }
+        }
+        remaining_terms -= group.len().min(remaining_terms);
+        if !terms.is_empty() {
+            groups.push(terms);
         }
     }
 
-    if terms.is_empty() {
-        None
-    } else {
-        Some(terms.join(" AND "))
+    match groups.as_slice() {
+        [] => None,
+        [terms] => Some(terms.join(" AND ")),
+        _ => Some(
+            groups
+                .iter()
+                .map(|terms| match terms.as_slice() {
+                    [term] => term.clone(),
+                    _ => format!("({})", terms.join(" AND ")),
+                })
+                .collect::<Vec<_>>()
+                .join(" OR "),
+        ),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        build_snippet, fuzzy_term_similarity, highlight_query_terms, sanitize_note_for_preview,
+        BrowseSort, IMAGE_BYTES_LIMIT_BOUNDS, IMAGE_COUNT_LIMIT_BOUNDS, ImageLimits,
+        ImageReplacement, MAX_NOTE_IMAGE_COUNT, MAX_SCREENSHOT_BYTES, PersistedImage,
+        PersistedItem, PersistedItemMeta, ResultKind, SNIPPET_FRAGMENT_SEPARATOR, SearchResult,
+        archive_deleted_item_in, build_image_text_snippet, build_lucene_note_snippet,
+        build_lucene_query, build_snippet, clamp_image_limits, csv_field, fuzzy_term_similarity,
+        gallery_image_keys, highlight_query_terms, image_fingerprint, item_calendar_dates,
+        markdown_file_name, matching_tags, mirror_exclusion, note_markdown, note_plain_text,
+        parse_query_groups, parse_query_terms, pinned_first, query_match_ranges,
+        read_deleted_archives_in, replace_image_if_unchanged, sanitize_note_for_preview,
+        sort_browse_rows, tag_prefix_query, thumbnail_image_key,
     };
+    use chrono::{NaiveDate, TimeZone};
 
     #[test]
     fn highlight_query_terms_marks_multiple_case_insensitive_matches() {
@@ -2196,6 +5420,121 @@ mod tests {
         assert_eq!(highlighted, "İzmir zorlama **deneme**");
     }
 
+    #[test]
+    fn query_match_ranges_merges_overlapping_terms_in_byte_offsets() {
+        let ranges = query_match_ranges("Özel Swift notes", &["swi".into(), "wift".into()]);
+        assert_eq!(ranges, vec![(6, 11)]);
+        assert!(query_match_ranges("Launcher", &["swift".into()]).is_empty());
+    }
+
+    #[test]
+    fn parse_query_groups_splits_alternatives_on_pipes() {
+        assert_eq!(
+            parse_query_groups("rust swift | go|zig", false),
+            vec![
+                vec!["rust".to_string(), "swift".to_string()],
+                vec!["go".to_string()],
+                vec!["zig".to_string()],
+            ]
+        );
+        assert_eq!(parse_query_groups("rust swift", true).len(), 2);
+        assert_eq!(parse_query_terms("go|zig |"), vec!["go", "zig"]);
+    }
+
+    #[test]
+    fn build_lucene_query_ands_terms_and_ors_groups() {
+        let groups = |query: &str, match_any| parse_query_groups(query, match_any);
+        assert_eq!(
+            build_lucene_query(&groups("rust swift", false)).as_deref(),
+            Some("rust* AND swift*")
+        );
+        assert_eq!(
+            build_lucene_query(&groups("rust swift | go", false)).as_deref(),
+            Some("(rust* AND swift*) OR go*")
+        );
+        assert_eq!(
+            build_lucene_query(&groups("rust swift", true)).as_deref(),
+            Some("rust* OR swift*")
+        );
+        assert_eq!(build_lucene_query(&groups("| !", false)), None);
+    }
+
+    #[test]
+    fn tag_prefix_query_matches_indexed_tags_by_prefix() {
+        use tantivy::collector::Count;
+        use tantivy::schema::{STRING, Schema};
+        use tantivy::{Index, doc};
+
+        let mut schema = Schema::builder();
+        let tags = schema.add_text_field("tags", STRING);
+        let index = Index::create_in_ram(schema.build());
+        let mut writer = index.writer(15_000_000).unwrap();
+        writer
+            .add_document(doc!(tags => "work/q3", tags => "home"))
+            .unwrap();
+        writer.add_document(doc!(tags => "workout")).unwrap();
+        writer.add_document(doc!(tags => "side-project")).unwrap();
+        writer.commit().unwrap();
+
+        let searcher = index.reader().unwrap().searcher();
+        let count = |prefix: &str| {
+            tag_prefix_query(tags, prefix).map(|query| searcher.search(&query, &Count).unwrap())
+        };
+        assert_eq!(count("work"), Some(2));
+        assert_eq!(count("work/"), Some(1));
+        assert_eq!(count("side-p"), Some(1));
+        assert_eq!(count(""), Some(3));
+        assert_eq!(count("wo.*"), None);
+    }
+
+    #[test]
+    fn lucene_note_snippet_shows_two_fragments_for_distant_matches() {
+        use tantivy::query::QueryParser;
+        use tantivy::schema::{Schema, TEXT};
+        use tantivy::snippet::SnippetGenerator;
+        use tantivy::{Index, doc};
+
+        let mut schema = Schema::builder();
+        let note = schema.add_text_field("note", TEXT);
+        let index = Index::create_in_ram(schema.build());
+        let filler = "unrelated words fill this long note ".repeat(12);
+        let text = format!("rust starts here. {filler} and swift ends it.");
+        let mut writer = index.writer(15_000_000).unwrap();
+        writer.add_document(doc!(note => text.clone())).unwrap();
+        writer.commit().unwrap();
+
+        let searcher = index.reader().unwrap().searcher();
+        let query = QueryParser::for_index(&index, vec![note])
+            .parse_query("rust swift")
+            .unwrap();
+        let generator = SnippetGenerator::create(&searcher, &*query, note).unwrap();
+
+        let snippet = build_lucene_note_snippet(&text, &generator).expect("snippet");
+        let fragments: Vec<&str> = snippet.split(SNIPPET_FRAGMENT_SEPARATOR).collect();
+        assert_eq!(fragments.len(), 2, "snippet was: {snippet}");
+        assert!(fragments[0].contains("**rust**"), "snippet was: {snippet}");
+        assert!(fragments[1].contains("**swift**"), "snippet was: {snippet}");
+    }
+
+    #[test]
+    fn image_text_snippet_names_the_matching_image() {
+        let image = |key: &str, text: Option<&str>| PersistedImage {
+            image_key: key.to_string(),
+            bytes: Vec::new(),
+            text: text.map(str::to_string),
+        };
+        let images = vec![
+            image("img-1", None),
+            image("img-2", Some("Quarterly revenue chart")),
+        ];
+
+        let (source, snippet) =
+            build_image_text_snippet(&images, &["revenue".into()]).expect("snippet");
+        assert_eq!(source, "image:img-2");
+        assert!(snippet.contains("**revenue**"), "snippet was: {snippet}");
+        assert!(build_image_text_snippet(&images, &[]).is_none());
+    }
+
     #[test]
     fn build_snippet_handles_multi_word_queries_by_term() {
         let result = build_snippet(
@@ -2248,6 +5587,16 @@ mod tests {
         assert_eq!(sanitized, "line 1 line 2");
     }
 
+    #[test]
+    fn sanitize_note_for_preview_keeps_image_alt_text() {
+        let note = "line 1\n![Q3 revenue chart](alfred://image/img-1-aaaa?w=360)\nline 2";
+        assert_eq!(
+            sanitize_note_for_preview(note),
+            "line 1 Q3 revenue chart line 2"
+        );
+        assert_eq!(note_plain_text(note), "line 1\n\nline 2");
+    }
+
     #[test]
     fn sanitize_note_for_preview_drops_image_url_fragments() {
         let note = "...\n-387e204f?w=360)\n\ndeneme\n";
@@ -2255,6 +5604,15 @@ mod tests {
         assert_eq!(sanitized, "... deneme");
     }
 
+    #[test]
+    fn sanitize_note_for_preview_keeps_footnotes_and_reference_links() {
+        let note = "See ![logo][1] and ![image](alfred://image/img-1-aaaa?w=360) [docs][2].[^1]\n\n[1]: https://example.com/logo.png\n[2]: https://example.com/docs\n[^1]: A note.";
+        assert_eq!(
+            sanitize_note_for_preview(note),
+            "See ![logo][1] and [docs][2].[^1] [1]: https://example.com/logo.png [2]: https://example.com/docs [^1]: A note."
+        );
+    }
+
     #[test]
     fn sanitize_note_for_preview_removes_inline_style_tokens() {
         let note = "[[b]]bold[[/b]] [[fs=18]]large[[/fs]] text";
@@ -2262,6 +5620,266 @@ mod tests {
         assert_eq!(sanitized, "bold large text");
     }
 
+    #[test]
+    fn mirror_exclusion_checks_secure_flag_then_tags() {
+        let mut item = PersistedItem {
+            id: 1,
+            title: "Passwords".into(),
+            subtitle: String::new(),
+            keywords: String::new(),
+            note: "bank #Private".into(),
+            images: Vec::new(),
+            meta: PersistedItemMeta::default(),
+        };
+        let excluded = vec!["private".to_string()];
+
+        assert_eq!(mirror_exclusion(&item, &[]), None);
+        assert_eq!(
+            mirror_exclusion(&item, &excluded).as_deref(),
+            Some("tagged #private")
+        );
+        item.meta.secure = true;
+        assert_eq!(mirror_exclusion(&item, &[]).as_deref(), Some("secure item"));
+    }
+
+    #[test]
+    fn deleted_secure_items_are_archived_outside_the_mirror() {
+        let dir = std::env::temp_dir().join(format!(
+            "alfred_archive_test_{}_{}",
+            std::process::id(),
+            super::unix_timestamp()
+        ));
+        let mirror_root = dir.join("mirror");
+        let secure_root = dir.join("secure");
+        let mut item = PersistedItem {
+            id: 3,
+            title: "Bank".into(),
+            subtitle: String::new(),
+            keywords: String::new(),
+            note: "pin 1234".into(),
+            images: vec![PersistedImage {
+                image_key: "img-1".into(),
+                bytes: vec![0x89, b'P', b'N', b'G'],
+                text: None,
+            }],
+            meta: PersistedItemMeta::default(),
+        };
+        item.meta.secure = true;
+
+        let key = archive_deleted_item_in(&mirror_root, &secure_root, &item).unwrap();
+        assert!(!mirror_root.exists());
+        let archives = read_deleted_archives_in(&secure_root).unwrap();
+        assert_eq!(archives.len(), 1);
+        assert_eq!(archives[0].archive_key, key);
+        assert!(archives[0].payload.secure);
+
+        item.meta.secure = false;
+        archive_deleted_item_in(&mirror_root, &secure_root, &item).unwrap();
+        assert_eq!(read_deleted_archives_in(&mirror_root).unwrap().len(), 1);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn replace_image_if_unchanged_requires_matching_fingerprint() {
+        let mut item = PersistedItem {
+            id: 1,
+            title: "Photos".into(),
+            subtitle: String::new(),
+            keywords: String::new(),
+            note: String::new(),
+            images: vec![PersistedImage {
+                image_key: "img-1".into(),
+                bytes: vec![1, 2, 3],
+                text: None,
+            }],
+            meta: PersistedItemMeta::default(),
+        };
+        let mut replacement = ImageReplacement {
+            item_id: 1,
+            image_key: "img-1".into(),
+            fingerprint: image_fingerprint(&[9, 9, 9]),
+            bytes: vec![4],
+        };
+
+        assert!(!replace_image_if_unchanged(&mut item, &replacement));
+        assert_eq!(item.images[0].bytes, vec![1, 2, 3]);
+
+        replacement.fingerprint = image_fingerprint(&[1, 2, 3]);
+        assert!(replace_image_if_unchanged(&mut item, &replacement));
+        assert_eq!(item.images[0].bytes, vec![4]);
+    }
+
+    #[test]
+    fn matching_tags_uses_prefixes() {
+        let tags = vec![
+            "work".to_string(),
+            "work/q3".to_string(),
+            "home".to_string(),
+        ];
+        assert_eq!(matching_tags(&tags, "wo"), vec!["work", "work/q3"]);
+        assert_eq!(matching_tags(&tags, "").len(), 3);
+        assert!(matching_tags(&tags, "x").is_empty());
+    }
+
+    #[test]
+    fn clamp_image_limits_keeps_limits_within_bounds() {
+        let clamped = clamp_image_limits(ImageLimits {
+            max_images_per_note: 0,
+            max_image_bytes: usize::MAX,
+        });
+        assert_eq!(clamped.max_images_per_note, IMAGE_COUNT_LIMIT_BOUNDS.0);
+        assert_eq!(clamped.max_image_bytes, IMAGE_BYTES_LIMIT_BOUNDS.1);
+
+        let defaults = ImageLimits {
+            max_images_per_note: MAX_NOTE_IMAGE_COUNT,
+            max_image_bytes: MAX_SCREENSHOT_BYTES,
+        };
+        assert_eq!(clamp_image_limits(defaults), defaults);
+    }
+
+    #[test]
+    fn thumbnail_image_key_prefers_first_referenced_image() {
+        let image = |key: &str| PersistedImage {
+            image_key: key.to_string(),
+            bytes: Vec::new(),
+            text: None,
+        };
+        let images = vec![image("img-1"), image("img-2")];
+        let note = "![image](alfred://image/missing)\n![image](alfred://image/img-2?w=360)";
+
+        assert_eq!(thumbnail_image_key(note, &images).as_deref(), Some("img-2"));
+        assert_eq!(
+            thumbnail_image_key("no images", &images).as_deref(),
+            Some("img-1")
+        );
+        assert_eq!(thumbnail_image_key(note, &[]), None);
+    }
+
+    #[test]
+    fn gallery_image_keys_follow_the_note_then_list_unreferenced_images() {
+        let image = |key: &str| PersistedImage {
+            image_key: key.to_string(),
+            bytes: Vec::new(),
+            text: None,
+        };
+        let images = vec![image("img-1"), image("img-2"), image("img-3")];
+        let note = "![image](alfred://image/img-3)\n![image](alfred://image/missing)\n![image](alfred://image/img-1?w=360)\n![image](alfred://image/img-3)";
+
+        assert_eq!(
+            gallery_image_keys(note, &images),
+            vec!["img-3", "img-1", "img-2"]
+        );
+        assert!(gallery_image_keys(note, &[]).is_empty());
+    }
+
+    #[test]
+    fn note_markdown_keeps_bold_and_adds_the_title_heading() {
+        let note =
+            "[[b]]Bold[[/b]] and [[fs=18]]big[[/fs]]\n![image](alfred://image/img-1?w=360)\n";
+        assert_eq!(note_markdown("Plan", note), "# Plan\n\n**Bold** and big\n");
+        assert_eq!(note_markdown("Plan", ""), "# Plan\n");
+    }
+
+    #[test]
+    fn markdown_file_name_replaces_path_separators() {
+        assert_eq!(markdown_file_name("a/b: c"), "a-b- c.md");
+        assert_eq!(markdown_file_name(" .hidden "), "hidden.md");
+        assert_eq!(markdown_file_name("   "), "Untitled.md");
+    }
+
+    #[test]
+    fn note_plain_text_keeps_lines_and_drops_images_and_styles() {
+        let note = "[[b]]Title[[/b]]\n![image](alfred://image/img-1-aaaa?w=360)\n\nbody [[fs=18]]text[[/fs]]\n";
+        assert_eq!(note_plain_text(note), "Title\n\n\nbody text");
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain title"), "plain title");
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+        assert_eq!(csv_field("say \"hi\"\nthere"), "\"say \"\"hi\"\"\nthere\"");
+    }
+
+    fn result_row(id: i64, pinned: bool) -> SearchResult {
+        SearchResult {
+            id,
+            title: format!("item {id}"),
+            subtitle: String::new(),
+            snippet: None,
+            snippet_source: None,
+            kind: ResultKind::Item,
+            icon: None,
+            command_key: None,
+            pinned,
+            thumbnail_key: None,
+            title_match_ranges: Vec::new(),
+            checklist_progress: None,
+        }
+    }
+
+    #[test]
+    fn pinned_first_orders_ranked_then_extra_pinned_rows_ahead() {
+        let ranked = vec![
+            result_row(1, false),
+            result_row(2, true),
+            result_row(3, false),
+        ];
+        let ordered = pinned_first(ranked, vec![result_row(9, true)], 3);
+        let ids: Vec<i64> = ordered.iter().map(|row| row.id).collect();
+        assert_eq!(ids, vec![2, 9, 1]);
+    }
+
+    #[test]
+    fn sort_browse_rows_keeps_pinned_first_in_every_order() {
+        let mut rows = vec![
+            result_row(1, false),
+            result_row(2, false),
+            result_row(3, true),
+        ];
+        rows[0].title = "beta".into();
+        rows[1].title = "Alpha".into();
+        // Created at id * 10, updated at 100 - id.
+        let timestamps = |id: i64| ((id * 10) as u64, (100 - id) as u64);
+
+        sort_browse_rows(&mut rows, BrowseSort::Created, timestamps);
+        let ids: Vec<i64> = rows.iter().map(|row| row.id).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+
+        sort_browse_rows(&mut rows, BrowseSort::Updated, timestamps);
+        let ids: Vec<i64> = rows.iter().map(|row| row.id).collect();
+        assert_eq!(ids, vec![3, 1, 2]);
+
+        sort_browse_rows(&mut rows, BrowseSort::Title, timestamps);
+        let ids: Vec<i64> = rows.iter().map(|row| row.id).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn item_calendar_dates_prefers_journal_date_and_dedupes_days() {
+        let tz = chrono::FixedOffset::east_opt(3 * 3600).unwrap();
+        let created = tz.with_ymd_and_hms(2026, 10, 1, 23, 30, 0).unwrap();
+        let meta = PersistedItemMeta {
+            created_at: created.timestamp() as u64,
+            updated_at: created.timestamp() as u64 + 600,
+            ..PersistedItemMeta::default()
+        };
+        let dates = item_calendar_dates(&meta, &tz);
+        assert_eq!(
+            dates,
+            vec![(NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(), false)]
+        );
+
+        let journal = PersistedItemMeta {
+            journal_date: Some("2026-10-05".into()),
+            ..meta
+        };
+        let dates = item_calendar_dates(&journal, &tz);
+        assert_eq!(
+            dates,
+            vec![(NaiveDate::from_ymd_opt(2026, 10, 5).unwrap(), true)]
+        );
+    }
+
     #[test]
     fn build_snippet_note_preview_keeps_highlight_visible_after_newlines() {
         let result = build_snippet(
@@ -0,0 +1,74 @@
+//! `alfredalt://` links that open a note from other apps, e.g. pasted into a
+//! calendar event or another note.
+
+use crate::html_note::{percent_decode, percent_encode};
+
+pub const DEEP_LINK_SCHEME: &str = "alfredalt";
+
+/// The link that opens item `id`.
+pub fn item_link(id: i64) -> String {
+    format!("{DEEP_LINK_SCHEME}://item/{id}")
+}
+
+/// The item id of `alfredalt://item/<id>`, also accepting
+/// `alfredalt://open?id=<id>` and a trailing slash.
+pub fn item_id_from_link(link: &str) -> Option<i64> {
+    let (scheme, rest) = link.trim().split_once("://")?;
+    if !scheme.eq_ignore_ascii_case(DEEP_LINK_SCHEME) {
+        return None;
+    }
+    let raw_id = match rest.strip_prefix("item/") {
+        Some(id) => id.trim_end_matches('/'),
+        None => rest
+            .strip_prefix("open?")?
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("id="))?,
+    };
+    raw_id.parse().ok().filter(|id| *id > 0)
+}
+
+/// The link that opens the note titled `title`, which `[[title]]` note links
+/// turn into.
+pub fn note_title_link(title: &str) -> String {
+    format!("{DEEP_LINK_SCHEME}://note/{}", percent_encode(title.trim()))
+}
+
+/// The note title of `alfredalt://note/<title>`.
+pub fn note_title_from_link(link: &str) -> Option<String> {
+    let (scheme, rest) = link.trim().split_once("://")?;
+    if !scheme.eq_ignore_ascii_case(DEEP_LINK_SCHEME) {
+        return None;
+    }
+    let title = percent_decode(rest.strip_prefix("note/")?);
+    let title = title.trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_links_round_trip() {
+        assert_eq!(item_link(42), "alfredalt://item/42");
+        assert_eq!(item_id_from_link(&item_link(42)), Some(42));
+        assert_eq!(item_id_from_link("AlfredAlt://item/7/"), Some(7));
+        assert_eq!(item_id_from_link("alfredalt://open?x=1&id=9"), Some(9));
+        assert_eq!(item_id_from_link("alfredalt://item/0"), None);
+        assert_eq!(item_id_from_link("https://item/4"), None);
+        assert_eq!(item_id_from_link("alfredalt://item/abc"), None);
+    }
+
+    #[test]
+    fn note_title_links_round_trip() {
+        let link = note_title_link("Q3 plan & budget");
+        assert_eq!(link, "alfredalt://note/Q3%20plan%20%26%20budget");
+        assert_eq!(
+            note_title_from_link(&link).as_deref(),
+            Some("Q3 plan & budget")
+        );
+        assert_eq!(item_id_from_link(&link), None);
+        assert_eq!(note_title_from_link("alfredalt://note/"), None);
+        assert_eq!(note_title_from_link("alfredalt://item/4"), None);
+    }
+}
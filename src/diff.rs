@@ -0,0 +1,181 @@
+/// Pairs of elements beyond this are not matched one by one: word diffs
+/// report the changed middle as one deletion plus one insertion, and merges
+/// treat it as one replaced block.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffSegment {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+/// Word-level diff from `old` to `new`. Whitespace runs are tokens of their
+/// own, so concatenating the equal and deleted segments yields `old` and the
+/// equal and inserted segments yield `new`.
+pub fn diff_words(old: &str, new: &str) -> Vec<DiffSegment> {
+    let old_tokens = tokenize(old);
+    let new_tokens = tokenize(new);
+
+    let prefix = old_tokens
+        .iter()
+        .zip(&new_tokens)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old_tokens[prefix..]
+        .iter()
+        .rev()
+        .zip(new_tokens[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut segments = Vec::new();
+    push_tokens(&mut segments, DiffKind::Equal, &old_tokens[..prefix]);
+    diff_middle(
+        &mut segments,
+        &old_tokens[prefix..old_tokens.len() - suffix],
+        &new_tokens[prefix..new_tokens.len() - suffix],
+    );
+    push_tokens(
+        &mut segments,
+        DiffKind::Equal,
+        &old_tokens[old_tokens.len() - suffix..],
+    );
+    segments
+}
+
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = 0usize;
+    let mut previous_is_space: Option<bool> = None;
+    for (index, ch) in text.char_indices() {
+        let is_space = ch.is_whitespace();
+        if previous_is_space.is_some_and(|previous| previous != is_space) {
+            tokens.push(&text[start..index]);
+            start = index;
+        }
+        previous_is_space = Some(is_space);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+/// Longest-common-subsequence diff of the part that differs.
+fn diff_middle(segments: &mut Vec<DiffSegment>, old: &[&str], new: &[&str]) {
+    let Some(pairs) = lcs_pairs(old, new) else {
+        push_tokens(segments, DiffKind::Delete, old);
+        push_tokens(segments, DiffKind::Insert, new);
+        return;
+    };
+    let (mut i, mut j) = (0usize, 0usize);
+    for (old_index, new_index) in pairs {
+        push_tokens(segments, DiffKind::Delete, &old[i..old_index]);
+        push_tokens(segments, DiffKind::Insert, &new[j..new_index]);
+        push_tokens(segments, DiffKind::Equal, &old[old_index..=old_index]);
+        (i, j) = (old_index + 1, new_index + 1);
+    }
+    push_tokens(segments, DiffKind::Delete, &old[i..]);
+    push_tokens(segments, DiffKind::Insert, &new[j..]);
+}
+
+/// Index pairs of the elements `old` and `new` share under a longest common
+/// subsequence, in order; `None` when the two are too long to compare.
+pub fn lcs_pairs<T: Eq>(old: &[T], new: &[T]) -> Option<Vec<(usize, usize)>> {
+    if old.len().saturating_mul(new.len()) > MAX_LCS_CELLS {
+        return None;
+    }
+
+    // lengths[i][j] = LCS length of old[i..] and new[j..].
+    let width = new.len() + 1;
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0usize, 0usize);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    Some(pairs)
+}
+
+/// Appends tokens, merging into the last segment when it has the same kind.
+fn push_tokens(segments: &mut Vec<DiffSegment>, kind: DiffKind, tokens: &[&str]) {
+    if tokens.is_empty() {
+        return;
+    }
+    match segments.last_mut() {
+        Some(last) if last.kind == kind => last.text.extend(tokens.iter().copied()),
+        _ => segments.push(DiffSegment {
+            kind,
+            text: tokens.concat(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffKind, diff_words};
+
+    fn rebuild(segments: &[super::DiffSegment], skip: DiffKind) -> String {
+        segments
+            .iter()
+            .filter(|segment| segment.kind != skip)
+            .map(|segment| segment.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn diff_words_marks_replaced_words() {
+        let old = "the quick brown fox";
+        let new = "the slow brown fox jumps";
+        let segments = diff_words(old, new);
+
+        let changes: Vec<_> = segments
+            .iter()
+            .filter(|segment| segment.kind != DiffKind::Equal)
+            .map(|segment| (segment.kind, segment.text.as_str()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (DiffKind::Delete, "quick"),
+                (DiffKind::Insert, "slow"),
+                (DiffKind::Insert, " jumps"),
+            ]
+        );
+        assert_eq!(rebuild(&segments, DiffKind::Insert), old);
+        assert_eq!(rebuild(&segments, DiffKind::Delete), new);
+    }
+
+    #[test]
+    fn diff_words_of_identical_text_is_one_equal_segment() {
+        let segments = diff_words("same\ntext", "same\ntext");
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].kind, DiffKind::Equal);
+        assert!(diff_words("", "").is_empty());
+    }
+}
@@ -0,0 +1,875 @@
+//! Quick-open of GitHub and GitLab repositories. `gh <repo>` (`gl` for
+//! GitLab) lists the account's recently pushed and starred repositories and
+//! opens the picked one; `gh issues <repo> <query>` searches its issues.
+//!
+//! Repositories are cached on disk and refreshed in the background once the
+//! cache is an hour old. Issue searches are cached for a few minutes. When the
+//! forge can't be reached, the last cached answer is shown instead, along
+//! with a link to the same search on the web.
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::html_note::percent_encode;
+use crate::logging::log_error;
+use crate::providers::{ProviderAction, ProviderResult, ResultProvider, read_json_response};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Cached repositories older than this are fetched again in the background.
+const REPOSITORY_REFRESH_SECONDS: u64 = 60 * 60;
+/// A failed fetch is not tried again sooner, so an offline launcher doesn't
+/// wait on the network for every keystroke.
+const RETRY_AFTER_FAILURE_SECONDS: u64 = 60;
+/// Cached issue searches younger than this are shown without asking the forge.
+const ISSUE_SEARCH_FRESH_SECONDS: u64 = 5 * 60;
+const MAX_REPOSITORIES: usize = 500;
+const MAX_CACHED_ISSUE_SEARCHES: usize = 50;
+const ISSUES_PER_SEARCH: usize = 20;
+const ISSUES_KEYWORD: &str = "issues";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForgeKind {
+    GitHub,
+    GitLab,
+}
+
+impl ForgeKind {
+    pub const ALL: [ForgeKind; 2] = [ForgeKind::GitHub, ForgeKind::GitLab];
+
+    pub fn provider_id(self) -> &'static str {
+        match self {
+            ForgeKind::GitHub => "github",
+            ForgeKind::GitLab => "gitlab",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            ForgeKind::GitHub => "GitHub",
+            ForgeKind::GitLab => "GitLab",
+        }
+    }
+
+    fn prefix(self) -> &'static str {
+        match self {
+            ForgeKind::GitHub => "gh ",
+            ForgeKind::GitLab => "gl ",
+        }
+    }
+
+    fn default_host(self) -> &'static str {
+        match self {
+            ForgeKind::GitHub => "github.com",
+            ForgeKind::GitLab => "gitlab.com",
+        }
+    }
+}
+
+/// A forge account from settings; `host` is set for self-hosted instances.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForgeAccount {
+    pub kind: ForgeKind,
+    pub host: Option<String>,
+    /// Never written to settings; the app keeps it in the Keychain.
+    #[serde(skip)]
+    pub token: String,
+}
+
+impl ForgeAccount {
+    /// The account with its host reduced to a bare name, or an error when
+    /// the token or host can't be used.
+    pub fn normalized(mut self) -> Result<Self> {
+        self.token = self.token.trim().to_string();
+        if self.token.is_empty() {
+            return Err(anyhow!("{} token must not be empty", self.kind.title()));
+        }
+        if self.token.chars().any(char::is_control) {
+            return Err(anyhow!(
+                "{} token must not contain control characters",
+                self.kind.title()
+            ));
+        }
+        self.host = self
+            .host
+            .as_deref()
+            .map(|host| {
+                let host = host.trim();
+                let host = host
+                    .strip_prefix("https://")
+                    .or_else(|| host.strip_prefix("http://"))
+                    .unwrap_or(host);
+                host.trim_end_matches('/').to_ascii_lowercase()
+            })
+            .filter(|host| !host.is_empty() && host != self.kind.default_host());
+        if let Some(host) = &self.host
+            && !host
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | ':'))
+        {
+            return Err(anyhow!(
+                "{} host must not contain {host:?}",
+                self.kind.title()
+            ));
+        }
+        Ok(self)
+    }
+
+    fn host(&self) -> &str {
+        self.host.as_deref().unwrap_or(self.kind.default_host())
+    }
+
+    fn api_base(&self) -> String {
+        match (self.kind, self.host.as_deref()) {
+            (ForgeKind::GitHub, None) => "https://api.github.com".to_string(),
+            (ForgeKind::GitHub, Some(host)) => format!("https://{host}/api/v3"),
+            (ForgeKind::GitLab, _) => format!("https://{}/api/v4", self.host()),
+        }
+    }
+
+    fn repository_url(&self, full_name: &str) -> String {
+        format!("https://{}/{full_name}", self.host())
+    }
+
+    fn issues_url(&self, full_name: &str, query: &str) -> String {
+        let base = self.repository_url(full_name);
+        match (self.kind, query.is_empty()) {
+            (ForgeKind::GitHub, true) => format!("{base}/issues"),
+            (ForgeKind::GitHub, false) => {
+                format!(
+                    "{base}/issues?q={}",
+                    percent_encode(&format!("is:issue {query}"))
+                )
+            }
+            (ForgeKind::GitLab, true) => format!("{base}/-/issues"),
+            (ForgeKind::GitLab, false) => {
+                format!("{base}/-/issues?search={}", percent_encode(query))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Repository {
+    /// "owner/name", or the full group path on GitLab.
+    pub full_name: String,
+    pub url: String,
+    pub description: Option<String>,
+}
+
+impl Repository {
+    fn name(&self) -> &str {
+        self.full_name.rsplit('/').next().unwrap_or(&self.full_name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Issue {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub open: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ForgeCache {
+    repositories_fetched_at: u64,
+    repositories: Vec<Repository>,
+    /// Most recent last.
+    issue_searches: Vec<IssueSearch>,
+    /// When the last fetch of repositories failed, and why.
+    #[serde(skip)]
+    failure: Option<(u64, String)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IssueSearch {
+    repository: String,
+    query: String,
+    fetched_at: u64,
+    issues: Vec<Issue>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ForgeQuery<'a> {
+    Repositories(&'a str),
+    Issues { repository: &'a str, query: &'a str },
+}
+
+fn parse_query(query: &str) -> ForgeQuery<'_> {
+    let query = query.trim();
+    if query == ISSUES_KEYWORD {
+        return ForgeQuery::Issues {
+            repository: "",
+            query: "",
+        };
+    }
+    let Some(rest) = query
+        .strip_prefix(ISSUES_KEYWORD)
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+    else {
+        return ForgeQuery::Repositories(query);
+    };
+    let rest = rest.trim_start();
+    let (repository, query) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    ForgeQuery::Issues {
+        repository,
+        query: query.trim(),
+    }
+}
+
+/// Repositories whose path contains `filter`; those whose name starts with
+/// it come first, otherwise the cached order (recent, then starred) is kept.
+fn matching_repositories<'a>(repositories: &'a [Repository], filter: &str) -> Vec<&'a Repository> {
+    let filter = filter.to_lowercase();
+    let mut matches: Vec<(bool, &Repository)> = repositories
+        .iter()
+        .filter(|repository| repository.full_name.to_lowercase().contains(&filter))
+        .map(|repository| {
+            let name_match = repository.name().to_lowercase().starts_with(&filter);
+            (!name_match, repository)
+        })
+        .collect();
+    matches.sort_by_key(|(later, _)| *later);
+    matches
+        .into_iter()
+        .map(|(_, repository)| repository)
+        .collect()
+}
+
+/// The full name `argument` stands for: a cached repository with that path
+/// or name, else the argument itself when it is a full path.
+fn resolve_repository(repositories: &[Repository], argument: &str) -> Option<String> {
+    let argument = argument.trim_matches('/');
+    repositories
+        .iter()
+        .find(|repository| repository.full_name.eq_ignore_ascii_case(argument))
+        .or_else(|| {
+            repositories
+                .iter()
+                .find(|repository| repository.name().eq_ignore_ascii_case(argument))
+        })
+        .map(|repository| repository.full_name.clone())
+        .or_else(|| argument.contains('/').then(|| argument.to_string()))
+}
+
+fn parse_repositories(kind: ForgeKind, body: &Value) -> Vec<Repository> {
+    let (name_key, url_key) = match kind {
+        ForgeKind::GitHub => ("full_name", "html_url"),
+        ForgeKind::GitLab => ("path_with_namespace", "web_url"),
+    };
+    body.as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|repository| {
+            Some(Repository {
+                full_name: repository.get(name_key)?.as_str()?.to_string(),
+                url: repository.get(url_key)?.as_str()?.to_string(),
+                description: repository
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|description| !description.is_empty())
+                    .map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+fn parse_issues(kind: ForgeKind, body: &Value) -> Vec<Issue> {
+    let (items, number_key, url_key, open_state) = match kind {
+        ForgeKind::GitHub => (body.get("items"), "number", "html_url", "open"),
+        ForgeKind::GitLab => (Some(body), "iid", "web_url", "opened"),
+    };
+    items
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|issue| {
+            Some(Issue {
+                number: issue.get(number_key)?.as_u64()?,
+                title: issue.get("title")?.as_str()?.to_string(),
+                url: issue.get(url_key)?.as_str()?.to_string(),
+                open: issue.get("state").and_then(Value::as_str) == Some(open_state),
+            })
+        })
+        .collect()
+}
+
+/// Lists an account's repositories and issues in the launcher.
+pub struct ForgeProvider {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    account: ForgeAccount,
+    cache_path: PathBuf,
+    cache: Mutex<ForgeCache>,
+    refreshing: AtomicBool,
+}
+
+impl ForgeProvider {
+    /// A provider for `account`, starting from the cache at `cache_path`.
+    pub fn new(account: ForgeAccount, cache_path: PathBuf) -> Self {
+        let cache = std::fs::read(&cache_path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+        Self {
+            shared: Arc::new(Shared {
+                account,
+                cache_path,
+                cache: Mutex::new(cache),
+                refreshing: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    fn repository_results(&self, filter: &str, limit: usize) -> Vec<ProviderResult> {
+        let error = self.shared.ensure_repositories().err();
+        let account = &self.shared.account;
+        let cache = self.shared.lock_cache();
+        let mut results: Vec<ProviderResult> = matching_repositories(&cache.repositories, filter)
+            .into_iter()
+            .take(limit)
+            .map(|repository| ProviderResult {
+                title: repository.full_name.clone(),
+                subtitle: repository
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| repository.url.clone()),
+                icon: None,
+                action: ProviderAction::OpenUrl(repository.url.clone()),
+            })
+            .collect();
+        if results.is_empty() && filter.contains('/') {
+            results.push(ProviderResult {
+                title: format!("Open {filter}"),
+                subtitle: account.repository_url(filter),
+                icon: None,
+                action: ProviderAction::OpenUrl(account.repository_url(filter)),
+            });
+        }
+        if let Some(error) = error {
+            results.push(unreachable_result(account, &error));
+        }
+        results
+    }
+
+    fn issue_results(&self, argument: &str, query: &str, limit: usize) -> Vec<ProviderResult> {
+        let error = self.shared.ensure_repositories().err();
+        let account = &self.shared.account;
+        let repository = {
+            let cache = self.shared.lock_cache();
+            resolve_repository(&cache.repositories, argument)
+        };
+        let Some(repository) = repository else {
+            // Until a repository is named, suggest ones to search.
+            let cache = self.shared.lock_cache();
+            return matching_repositories(&cache.repositories, argument)
+                .into_iter()
+                .take(limit)
+                .map(|repository| ProviderResult {
+                    title: format!("Issues of {}", repository.full_name),
+                    subtitle: format!(
+                        "Type \"{}{ISSUES_KEYWORD} {} <query>\" to search them",
+                        account.kind.prefix(),
+                        repository.name()
+                    ),
+                    icon: Some("exclamationmark.circle".to_string()),
+                    action: ProviderAction::OpenUrl(account.issues_url(&repository.full_name, "")),
+                })
+                .chain(error.map(|error| unreachable_result(account, &error)))
+                .collect();
+        };
+
+        let (issues, stale) = match self.shared.search_issues(&repository, query) {
+            Ok(issues) => (issues, None),
+            Err(error) => (
+                self.shared
+                    .cached_issue_search(&repository, query)
+                    .unwrap_or_default(),
+                Some(error),
+            ),
+        };
+        let mut results: Vec<ProviderResult> = issues
+            .into_iter()
+            .take(limit.saturating_sub(1))
+            .map(|issue| ProviderResult {
+                title: format!("#{} {}", issue.number, issue.title),
+                subtitle: format!(
+                    "{repository} · {}",
+                    if issue.open { "open" } else { "closed" }
+                ),
+                icon: Some(
+                    if issue.open {
+                        "exclamationmark.circle"
+                    } else {
+                        "checkmark.circle"
+                    }
+                    .to_string(),
+                ),
+                action: ProviderAction::OpenUrl(issue.url),
+            })
+            .collect();
+        let subtitle = match &stale {
+            Some(error) => format!("Offline, showing cached issues: {error}"),
+            None if results.is_empty() => "No matching issues".to_string(),
+            None => account.issues_url(&repository, query),
+        };
+        results.push(ProviderResult {
+            title: format!("Search {repository} issues on {}", account.kind.title()),
+            subtitle,
+            icon: Some("safari".to_string()),
+            action: ProviderAction::OpenUrl(account.issues_url(&repository, query)),
+        });
+        results
+    }
+}
+
+impl ResultProvider for ForgeProvider {
+    fn id(&self) -> &str {
+        self.shared.account.kind.provider_id()
+    }
+
+    fn title(&self) -> &str {
+        self.shared.account.kind.title()
+    }
+
+    fn prefix(&self) -> Option<&str> {
+        Some(self.shared.account.kind.prefix())
+    }
+
+    fn icon(&self) -> &str {
+        "chevron.left.forwardslash.chevron.right"
+    }
+
+    fn results(&self, query: &str, limit: usize) -> Result<Vec<ProviderResult>> {
+        Ok(match parse_query(query) {
+            ForgeQuery::Repositories(filter) => self.repository_results(filter, limit),
+            ForgeQuery::Issues { repository, query } => {
+                self.issue_results(repository, query, limit)
+            }
+        })
+    }
+}
+
+fn unreachable_result(account: &ForgeAccount, error: &anyhow::Error) -> ProviderResult {
+    ProviderResult {
+        title: format!("{} can't be reached", account.kind.title()),
+        subtitle: format!("{error:#}"),
+        icon: Some("wifi.exclamationmark".to_string()),
+        action: ProviderAction::OpenUrl(format!("https://{}", account.host())),
+    }
+}
+
+impl Shared {
+    fn lock_cache(&self) -> std::sync::MutexGuard<'_, ForgeCache> {
+        self.cache.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Fetches the repositories when none are cached yet, and starts a
+    /// background refresh when the cache is stale.
+    fn ensure_repositories(self: &Arc<Self>) -> Result<()> {
+        let now = unix_timestamp();
+        let (empty, fetched_at, failure) = {
+            let cache = self.lock_cache();
+            (
+                cache.repositories.is_empty(),
+                cache.repositories_fetched_at,
+                cache.failure.clone(),
+            )
+        };
+        if let Some((failed_at, message)) = failure
+            && now.saturating_sub(failed_at) < RETRY_AFTER_FAILURE_SECONDS
+        {
+            return if empty { Err(anyhow!(message)) } else { Ok(()) };
+        }
+        if now.saturating_sub(fetched_at) < REPOSITORY_REFRESH_SECONDS {
+            return Ok(());
+        }
+        if empty {
+            return self.refresh_repositories();
+        }
+        if !self.refreshing.swap(true, Ordering::SeqCst) {
+            let shared = Arc::clone(self);
+            std::thread::spawn(move || {
+                if let Err(err) = shared.refresh_repositories() {
+                    log_error(
+                        format_args!(
+                            "failed to refresh {} repositories",
+                            shared.account.kind.title()
+                        ),
+                        err,
+                    );
+                }
+                shared.refreshing.store(false, Ordering::SeqCst);
+            });
+        }
+        Ok(())
+    }
+
+    fn refresh_repositories(&self) -> Result<()> {
+        let paths: [&str; 2] = match self.account.kind {
+            ForgeKind::GitHub => [
+                "/user/repos?sort=pushed&per_page=100",
+                "/user/starred?per_page=100",
+            ],
+            ForgeKind::GitLab => [
+                "/projects?membership=true&order_by=last_activity_at&simple=true&per_page=100",
+                "/projects?starred=true&simple=true&per_page=100",
+            ],
+        };
+        let result = paths
+            .iter()
+            .try_fold(Vec::<Repository>::new(), |mut all, path| {
+                for repository in parse_repositories(self.account.kind, &self.get(path)?) {
+                    if !all
+                        .iter()
+                        .any(|known| known.full_name == repository.full_name)
+                    {
+                        all.push(repository);
+                    }
+                }
+                Ok::<_, anyhow::Error>(all)
+            });
+        let mut cache = self.lock_cache();
+        match result {
+            Ok(mut repositories) => {
+                repositories.truncate(MAX_REPOSITORIES);
+                cache.repositories = repositories;
+                cache.repositories_fetched_at = unix_timestamp();
+                cache.failure = None;
+                self.save_cache(&cache);
+                Ok(())
+            }
+            Err(err) => {
+                cache.failure = Some((unix_timestamp(), format!("{err:#}")));
+                Err(err)
+            }
+        }
+    }
+
+    fn cached_issue_search(&self, repository: &str, query: &str) -> Option<Vec<Issue>> {
+        self.lock_cache()
+            .issue_searches
+            .iter()
+            .find(|search| search.repository == repository && search.query == query)
+            .map(|search| search.issues.clone())
+    }
+
+    fn search_issues(&self, repository: &str, query: &str) -> Result<Vec<Issue>> {
+        let now = unix_timestamp();
+        {
+            let cache = self.lock_cache();
+            let fresh = cache.issue_searches.iter().find(|search| {
+                search.repository == repository
+                    && search.query == query
+                    && now.saturating_sub(search.fetched_at) < ISSUE_SEARCH_FRESH_SECONDS
+            });
+            if let Some(search) = fresh {
+                return Ok(search.issues.clone());
+            }
+        }
+
+        // Without a query, the open issues are listed.
+        let path = match (self.account.kind, query.is_empty()) {
+            (ForgeKind::GitHub, true) => format!(
+                "/search/issues?per_page={ISSUES_PER_SEARCH}&q={}",
+                percent_encode(&format!("repo:{repository} is:issue is:open"))
+            ),
+            (ForgeKind::GitHub, false) => format!(
+                "/search/issues?per_page={ISSUES_PER_SEARCH}&q={}",
+                percent_encode(&format!("repo:{repository} is:issue {query}"))
+            ),
+            (ForgeKind::GitLab, true) => format!(
+                "/projects/{}/issues?per_page={ISSUES_PER_SEARCH}&state=opened",
+                percent_encode(repository)
+            ),
+            (ForgeKind::GitLab, false) => format!(
+                "/projects/{}/issues?per_page={ISSUES_PER_SEARCH}&search={}",
+                percent_encode(repository),
+                percent_encode(query)
+            ),
+        };
+        let issues = parse_issues(self.account.kind, &self.get(&path)?);
+
+        let mut cache = self.lock_cache();
+        cache
+            .issue_searches
+            .retain(|search| search.repository != repository || search.query != query);
+        cache.issue_searches.push(IssueSearch {
+            repository: repository.to_string(),
+            query: query.to_string(),
+            fetched_at: now,
+            issues: issues.clone(),
+        });
+        let excess = cache
+            .issue_searches
+            .len()
+            .saturating_sub(MAX_CACHED_ISSUE_SEARCHES);
+        cache.issue_searches.drain(..excess);
+        self.save_cache(&cache);
+        Ok(issues)
+    }
+
+    fn get(&self, path: &str) -> Result<Value> {
+        let url = format!("{}{path}", self.account.api_base());
+        let request = ureq::get(&url)
+            .timeout(REQUEST_TIMEOUT)
+            .set("User-Agent", "AlfredAlternative");
+        let request = match self.account.kind {
+            ForgeKind::GitHub => request
+                .set("Accept", "application/vnd.github+json")
+                .set("Authorization", &format!("Bearer {}", self.account.token)),
+            ForgeKind::GitLab => request.set("PRIVATE-TOKEN", &self.account.token),
+        };
+        read_json_response(request.call(), self.account.kind.title())
+    }
+
+    /// The cache only saves requests, so failing to write it is just logged.
+    fn save_cache(&self, cache: &ForgeCache) {
+        let saved = serde_json::to_vec(cache)
+            .map_err(anyhow::Error::from)
+            .and_then(|payload| {
+                if let Some(dir) = self.cache_path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                let temp_path = self.cache_path.with_extension("json.tmp");
+                std::fs::write(&temp_path, payload)?;
+                std::fs::rename(&temp_path, &self.cache_path)?;
+                Ok(())
+            });
+        if let Err(err) = saved {
+            log_error(
+                format_args!("failed to cache {} results", self.account.kind.title()),
+                err,
+            );
+        }
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        ForgeAccount, ForgeCache, ForgeKind, ForgeProvider, ForgeQuery, Issue, IssueSearch,
+        Repository, matching_repositories, parse_issues, parse_query, parse_repositories,
+        resolve_repository, unix_timestamp,
+    };
+    use crate::providers::{ProviderAction, ResultProvider};
+
+    fn repository(full_name: &str) -> Repository {
+        Repository {
+            full_name: full_name.to_string(),
+            url: format!("https://github.com/{full_name}"),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn queries_name_repositories_or_issue_searches() {
+        assert_eq!(parse_query(" alfred "), ForgeQuery::Repositories("alfred"));
+        assert_eq!(
+            parse_query("issuesbot"),
+            ForgeQuery::Repositories("issuesbot")
+        );
+        assert_eq!(
+            parse_query("issues"),
+            ForgeQuery::Issues {
+                repository: "",
+                query: ""
+            }
+        );
+        assert_eq!(
+            parse_query("issues alfredalt  crash on  launch "),
+            ForgeQuery::Issues {
+                repository: "alfredalt",
+                query: "crash on  launch"
+            }
+        );
+
+        let repositories = [
+            repository("someone/notes-alfred"),
+            repository("serkan/alfredalt"),
+            repository("serkan/dotfiles"),
+        ];
+        let names: Vec<&str> = matching_repositories(&repositories, "ALF")
+            .iter()
+            .map(|repository| repository.full_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["serkan/alfredalt", "someone/notes-alfred"]);
+        assert_eq!(
+            resolve_repository(&repositories, "AlfredAlt").as_deref(),
+            Some("serkan/alfredalt")
+        );
+        assert_eq!(
+            resolve_repository(&repositories, "rust-lang/rust").as_deref(),
+            Some("rust-lang/rust")
+        );
+        assert_eq!(resolve_repository(&repositories, "alfred"), None);
+    }
+
+    #[test]
+    fn responses_of_both_forges_are_parsed() {
+        let github = json!([
+            {"full_name": "serkan/alfredalt", "html_url": "https://github.com/serkan/alfredalt", "description": " Launcher "},
+            {"full_name": "broken"}
+        ]);
+        assert_eq!(
+            parse_repositories(ForgeKind::GitHub, &github),
+            vec![Repository {
+                full_name: "serkan/alfredalt".into(),
+                url: "https://github.com/serkan/alfredalt".into(),
+                description: Some("Launcher".into()),
+            }]
+        );
+        let gitlab = json!([
+            {"path_with_namespace": "group/sub/tool", "web_url": "https://gitlab.com/group/sub/tool", "description": ""}
+        ]);
+        let parsed = parse_repositories(ForgeKind::GitLab, &gitlab);
+        assert_eq!(parsed[0].full_name, "group/sub/tool");
+        assert_eq!(parsed[0].description, None);
+
+        let github_issues = json!({"items": [
+            {"number": 7, "title": "Crash", "html_url": "https://github.com/a/b/issues/7", "state": "open"}
+        ]});
+        assert_eq!(
+            parse_issues(ForgeKind::GitHub, &github_issues),
+            vec![Issue {
+                number: 7,
+                title: "Crash".into(),
+                url: "https://github.com/a/b/issues/7".into(),
+                open: true,
+            }]
+        );
+        let gitlab_issues = json!([
+            {"iid": 3, "title": "Fixed", "web_url": "https://gitlab.com/a/b/-/issues/3", "state": "closed"}
+        ]);
+        assert!(!parse_issues(ForgeKind::GitLab, &gitlab_issues)[0].open);
+    }
+
+    #[test]
+    fn accounts_are_normalized() {
+        let account = ForgeAccount {
+            kind: ForgeKind::GitLab,
+            host: Some(" https://Git.Example.com/ ".into()),
+            token: " secret ".into(),
+        }
+        .normalized()
+        .unwrap();
+        assert_eq!(account.host.as_deref(), Some("git.example.com"));
+        assert_eq!(account.token, "secret");
+        assert_eq!(account.api_base(), "https://git.example.com/api/v4");
+        assert_eq!(
+            account.issues_url("a/b", "crash log"),
+            "https://git.example.com/a/b/-/issues?search=crash%20log"
+        );
+
+        let github = ForgeAccount {
+            kind: ForgeKind::GitHub,
+            host: Some("github.com".into()),
+            token: "t".into(),
+        }
+        .normalized()
+        .unwrap();
+        assert_eq!(github.host, None);
+        assert_eq!(github.api_base(), "https://api.github.com");
+
+        let empty = ForgeAccount {
+            kind: ForgeKind::GitHub,
+            host: None,
+            token: "  ".into(),
+        };
+        assert!(empty.normalized().is_err());
+        let bad_host = ForgeAccount {
+            kind: ForgeKind::GitHub,
+            host: Some("evil.com/path?x".into()),
+            token: "t".into(),
+        };
+        assert!(bad_host.normalized().is_err());
+    }
+
+    #[test]
+    fn tokens_are_not_written_to_settings() {
+        let account = ForgeAccount {
+            kind: ForgeKind::GitHub,
+            host: None,
+            token: "secret".into(),
+        };
+        let saved = serde_json::to_string(&account).unwrap();
+        assert!(!saved.contains("secret"), "{saved}");
+        let loaded: ForgeAccount = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.token, "");
+    }
+
+    #[test]
+    fn cached_results_are_shown_while_the_forge_is_unreachable() {
+        let dir = std::env::temp_dir().join(format!(
+            "alfred_forge_test_{}_{}",
+            std::process::id(),
+            unix_timestamp()
+        ));
+        let cache_path = dir.join("github.json");
+        let cache = ForgeCache {
+            repositories_fetched_at: unix_timestamp(),
+            repositories: vec![repository("serkan/alfredalt")],
+            issue_searches: vec![IssueSearch {
+                repository: "serkan/alfredalt".into(),
+                query: "crash".into(),
+                // Stale, so the forge is asked first.
+                fetched_at: 0,
+                issues: vec![Issue {
+                    number: 7,
+                    title: "Crash".into(),
+                    url: "https://github.com/serkan/alfredalt/issues/7".into(),
+                    open: true,
+                }],
+            }],
+            failure: None,
+        };
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&cache_path, serde_json::to_vec(&cache).unwrap()).unwrap();
+
+        // Nothing listens on the discard port, so every request fails fast.
+        let account = ForgeAccount {
+            kind: ForgeKind::GitHub,
+            host: Some("127.0.0.1:9".into()),
+            token: "t".into(),
+        };
+        let provider = ForgeProvider::new(account, cache_path);
+
+        let repositories = provider.results("alfred", 5).unwrap();
+        assert_eq!(repositories.len(), 1);
+        assert_eq!(
+            repositories[0].action,
+            ProviderAction::OpenUrl("https://github.com/serkan/alfredalt".into())
+        );
+
+        let issues = provider.results("issues alfredalt crash", 5).unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].title, "#7 Crash");
+        assert!(issues[1].subtitle.starts_with("Offline"));
+        assert_eq!(
+            issues[1].action,
+            ProviderAction::OpenUrl(
+                "https://127.0.0.1:9/serkan/alfredalt/issues?q=is%3Aissue%20crash".into()
+            )
+        );
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+
+/// Saves closer together than this are folded into the previous version so a
+/// burst of autosaves produces a single entry.
+const COALESCE_WINDOW_SECONDS: u64 = 10 * 60;
+const MAX_VERSIONS_PER_ITEM: usize = 200;
+
+/// A snapshot of an item's note after one or more saves.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteVersion {
+    pub at_unix_seconds: u64,
+    /// Number of saves folded into this version.
+    pub saves: u32,
+    pub note: String,
+}
+
+/// Saves and note length for one local day, derived from the version history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityDay {
+    pub date: NaiveDate,
+    pub saves: u32,
+    /// Note length in characters at the end of the day.
+    pub length: usize,
+}
+
+fn history_path(dir: &Path, item_id: i64) -> PathBuf {
+    dir.join(format!("{item_id}.json"))
+}
+
+/// Oldest first; a missing file means the item has no recorded history yet.
+pub fn load_versions(dir: &Path, item_id: i64) -> Result<Vec<NoteVersion>> {
+    let path = history_path(dir, item_id);
+    match std::fs::read(&path) {
+        Ok(bytes) => serde_json::from_slice(&bytes)
+            .with_context(|| format!("failed to parse note history {}", path.display())),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => {
+            Err(err).with_context(|| format!("failed to read note history {}", path.display()))
+        }
+    }
+}
+
+pub fn record_save(dir: &Path, item_id: i64, note: &str, now: u64) -> Result<()> {
+    let mut versions = load_versions(dir, item_id)?;
+    append_version(&mut versions, note, now);
+
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("failed to create note history dir {}", dir.display()))?;
+    let path = history_path(dir, item_id);
+    let tmp_path = path.with_extension("json.tmp");
+    let payload = serde_json::to_vec(&versions).context("failed to serialize note history")?;
+    std::fs::write(&tmp_path, payload)
+        .with_context(|| format!("failed to write note history {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, &path)
+        .with_context(|| format!("failed to replace note history {}", path.display()))
+}
+
+pub fn remove_history(dir: &Path, item_id: i64) -> Result<()> {
+    let path = history_path(dir, item_id);
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => {
+            Err(err).with_context(|| format!("failed to remove note history {}", path.display()))
+        }
+    }
+}
+
+fn append_version(versions: &mut Vec<NoteVersion>, note: &str, now: u64) {
+    if let Some(last) = versions.last_mut()
+        && now.saturating_sub(last.at_unix_seconds) < COALESCE_WINDOW_SECONDS
+    {
+        last.at_unix_seconds = now;
+        last.saves = last.saves.saturating_add(1);
+        last.note = note.to_string();
+        return;
+    }
+
+    versions.push(NoteVersion {
+        at_unix_seconds: now,
+        saves: 1,
+        note: note.to_string(),
+    });
+    if versions.len() > MAX_VERSIONS_PER_ITEM {
+        let overflow = versions.len() - MAX_VERSIONS_PER_ITEM;
+        versions.drain(..overflow);
+    }
+}
+
+/// Groups versions by local day, oldest first.
+pub fn daily_activity<Tz: TimeZone>(versions: &[NoteVersion], tz: &Tz) -> Vec<ActivityDay> {
+    let mut days: BTreeMap<NaiveDate, ActivityDay> = BTreeMap::new();
+    for version in versions {
+        let Some(local) = i64::try_from(version.at_unix_seconds)
+            .ok()
+            .and_then(|seconds| tz.timestamp_opt(seconds, 0).single())
+        else {
+            continue;
+        };
+        let date = local.date_naive();
+        let day = days.entry(date).or_insert(ActivityDay {
+            date,
+            saves: 0,
+            length: 0,
+        });
+        day.saves = day.saves.saturating_add(version.saves);
+        day.length = version.note.chars().count();
+    }
+    days.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NoteVersion, append_version, daily_activity};
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+    #[test]
+    fn append_version_coalesces_saves_within_window() {
+        let mut versions = Vec::new();
+        append_version(&mut versions, "a", 1_000);
+        append_version(&mut versions, "ab", 1_200);
+        append_version(&mut versions, "abc", 1_000 + 60 * 60);
+
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].saves, 2);
+        assert_eq!(versions[0].note, "ab");
+        assert_eq!(versions[1].note, "abc");
+    }
+
+    #[test]
+    fn daily_activity_sums_saves_and_keeps_last_length() {
+        let tz = FixedOffset::east_opt(0).unwrap();
+        let at = |day, hour| {
+            tz.with_ymd_and_hms(2026, 10, day, hour, 0, 0)
+                .unwrap()
+                .timestamp() as u64
+        };
+        let versions = vec![
+            NoteVersion {
+                at_unix_seconds: at(1, 9),
+                saves: 3,
+                note: "one".into(),
+            },
+            NoteVersion {
+                at_unix_seconds: at(1, 18),
+                saves: 1,
+                note: "one two".into(),
+            },
+            NoteVersion {
+                at_unix_seconds: at(3, 9),
+                saves: 2,
+                note: "x".into(),
+            },
+        ];
+
+        let days = daily_activity(&versions, &tz);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date, NaiveDate::from_ymd_opt(2026, 10, 1).unwrap());
+        assert_eq!((days[0].saves, days[0].length), (4, 7));
+        assert_eq!((days[1].saves, days[1].length), (2, 1));
+    }
+}
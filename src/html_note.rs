@@ -0,0 +1,418 @@
+//! HTML to note text, shared by the Notion import and read-later articles.
+//!
+//! Headings keep a larger bold font, lists, to-dos, quotes and code blocks
+//! keep their Markdown-like form, and `img` sources are handed to a callback
+//! that stores the image and returns its key.
+
+use std::io::Read;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, ensure};
+
+const INLINE_IMAGE_URL_PREFIX: &str = "alfred://image/";
+const IMAGE_DISPLAY_WIDTH: u32 = 360;
+const IMAGE_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(20);
+/// Font sizes for `h1` to `h3`; deeper headings use the last one.
+const HEADING_FONT_SIZES: [u32; 3] = [24, 20, 17];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// `self_closing` is set for tags written as `<name ... />`.
+    Open {
+        name: String,
+        attrs: String,
+        self_closing: bool,
+    },
+    Close(String),
+    Text(String),
+}
+
+/// Splits `html` into tags and text; comments, doctypes and the contents of
+/// `style` and `script` are dropped.
+pub fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest.to_string()));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(rest[..start].to_string()));
+        }
+        rest = &rest[start..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+            continue;
+        }
+        if tag.starts_with(['!', '?']) {
+            continue;
+        }
+        let self_closing = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        let name = name.to_lowercase();
+        if matches!(name.as_str(), "style" | "script") {
+            let closing = format!("</{name}");
+            rest = rest.find(&closing).map_or("", |end| &rest[end..]);
+            continue;
+        }
+        tokens.push(Token::Open {
+            name,
+            attrs: attrs.to_string(),
+            self_closing,
+        });
+    }
+    tokens
+}
+
+pub fn attribute(attrs: &str, name: &str) -> Option<String> {
+    let mut offset = 0;
+    while let Some(position) = attrs[offset..].find(name) {
+        let start = offset + position;
+        offset = start + name.len();
+        if start > 0 && !attrs[..start].ends_with(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = attrs[offset..].trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let (body, end) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let body = &value[1..];
+                (body, body.find(quote))
+            }
+            _ => (value, value.find(char::is_whitespace)),
+        };
+        return Some(decode_entities(&body[..end.unwrap_or(body.len())]));
+    }
+    None
+}
+
+pub fn has_class(attrs: &str, class: &str) -> bool {
+    attribute(attrs, "class")
+        .is_some_and(|classes| classes.split_whitespace().any(|name| name == class))
+}
+
+pub fn decode_entities(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(position) = rest.find('&') {
+        output.push_str(&rest[..position]);
+        rest = &rest[position..];
+        let decoded = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let ch = match &rest[1..end] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                entity => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16).ok())
+                    .unwrap_or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            ch.map(|ch| (ch, end))
+        });
+        match decoded {
+            Some((ch, end)) => {
+                output.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Downloads an image from an http(s) URL, failing when it is larger than
+/// `max_bytes`.
+pub fn download_image(url: &str, max_bytes: usize) -> Result<Vec<u8>> {
+    let response = ureq::get(url)
+        .timeout(IMAGE_DOWNLOAD_TIMEOUT)
+        .call()
+        .map_err(|err| anyhow!("GET {url} failed: {err}"))?;
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .take(max_bytes as u64 + 1)
+        .read_to_end(&mut bytes)
+        .with_context(|| format!("failed to download {url}"))?;
+    ensure!(
+        bytes.len() <= max_bytes,
+        "larger than {} KB",
+        max_bytes / 1024
+    );
+    Ok(bytes)
+}
+
+/// Percent-encodes everything but unreserved URL characters.
+pub fn percent_encode(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            output.push(byte as char);
+        } else {
+            output.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    output
+}
+
+pub fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes
+            .get(index + 1..index + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListKind {
+    Bulleted,
+    Numbered(u32),
+    ToDo,
+}
+
+/// Builds note text from the tokens of a page body.
+#[derive(Debug, Default)]
+pub struct NoteWriter {
+    out: String,
+    lists: Vec<ListKind>,
+    /// Where the `[ ]` of the current to-do entry starts.
+    checkbox_at: Option<usize>,
+    /// External link target and where its text starts, per open `a`.
+    links: Vec<Option<(String, usize)>>,
+    preformatted: bool,
+    in_heading: bool,
+    row_cells: usize,
+}
+
+impl NoteWriter {
+    /// Ends the current line, and with `blank` leaves an empty line after it.
+    fn block_break(&mut self, blank: bool) {
+        if self.out.is_empty() {
+            return;
+        }
+        if !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+        if blank && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    pub fn push_text(&mut self, text: &str) {
+        if self.preformatted {
+            self.out.push_str(text);
+            return;
+        }
+        let words = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let at_line_start = self.out.is_empty() || self.out.ends_with([' ', '\n']);
+        if text.starts_with(char::is_whitespace) && !at_line_start {
+            self.out.push(' ');
+        }
+        self.out.push_str(&words);
+        if !words.is_empty() && text.ends_with(char::is_whitespace) {
+            self.out.push(' ');
+        }
+    }
+
+    pub fn open(
+        &mut self,
+        name: &str,
+        attrs: &str,
+        image_key: &mut impl FnMut(&str) -> Option<String>,
+    ) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.block_break(true);
+                let level = name[1..].parse::<usize>().unwrap_or(1);
+                let size = HEADING_FONT_SIZES[level.min(HEADING_FONT_SIZES.len()) - 1];
+                self.out.push_str(&format!("[[fs={size}]][[b]]"));
+                self.in_heading = true;
+            }
+            "p" | "div" | "summary" | "details" => {
+                if name == "div" && has_class(attrs, "checkbox-on") {
+                    if let Some(position) = self.checkbox_at {
+                        self.out.replace_range(position..position + 3, "[x]");
+                    }
+                } else if !self.in_heading && self.checkbox_at.is_none() {
+                    self.block_break(false);
+                }
+            }
+            "figure" | "table" | "hr" => {
+                self.block_break(true);
+                if name == "hr" {
+                    self.out.push_str("---");
+                    self.block_break(true);
+                }
+            }
+            "blockquote" => {
+                self.block_break(true);
+                self.out.push_str("> ");
+            }
+            "ul" | "ol" => {
+                self.block_break(false);
+                self.lists.push(match name {
+                    "ol" => ListKind::Numbered(
+                        attribute(attrs, "start")
+                            .and_then(|start| start.parse::<u32>().ok())
+                            .unwrap_or(1)
+                            .saturating_sub(1),
+                    ),
+                    _ if has_class(attrs, "to-do-list") => ListKind::ToDo,
+                    _ => ListKind::Bulleted,
+                });
+            }
+            "li" => {
+                self.block_break(false);
+                self.out
+                    .push_str(&"  ".repeat(self.lists.len().saturating_sub(1)));
+                match self.lists.last_mut() {
+                    Some(ListKind::Numbered(number)) => {
+                        *number += 1;
+                        self.out.push_str(&format!("{number}. "));
+                    }
+                    Some(ListKind::ToDo) => {
+                        self.out.push_str("- ");
+                        self.checkbox_at = Some(self.out.len());
+                        self.out.push_str("[ ] ");
+                    }
+                    _ => self.out.push_str("- "),
+                }
+            }
+            "pre" => {
+                self.block_break(true);
+                self.out.push_str("```\n");
+                self.preformatted = true;
+            }
+            "code" if !self.preformatted => self.out.push('`'),
+            "strong" | "b" if !self.in_heading => self.out.push_str("[[b]]"),
+            "br" => self.out.push('\n'),
+            "tr" => {
+                self.block_break(false);
+                self.row_cells = 0;
+            }
+            "td" | "th" => {
+                if self.row_cells > 0 {
+                    self.out.push_str(" | ");
+                }
+                self.row_cells += 1;
+            }
+            "img" => {
+                if let Some(key) = attribute(attrs, "src").and_then(|src| image_key(&src)) {
+                    self.block_break(false);
+                    self.out.push_str(&format!(
+                        "![image]({INLINE_IMAGE_URL_PREFIX}{key}?w={IMAGE_DISPLAY_WIDTH})"
+                    ));
+                    self.block_break(false);
+                }
+            }
+            "a" => {
+                let external = attribute(attrs, "href").filter(|href| {
+                    href.starts_with("https://")
+                        || href.starts_with("http://")
+                        || href.starts_with("mailto:")
+                });
+                self.links.push(external.map(|href| (href, self.out.len())));
+            }
+            _ => {}
+        }
+    }
+
+    pub fn close(&mut self, name: &str) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" if self.in_heading => {
+                self.out.push_str("[[/b]][[/fs]]");
+                self.in_heading = false;
+                self.block_break(true);
+            }
+            "p" | "div" | "summary" | "details" | "tr"
+                if !self.in_heading && self.checkbox_at.is_none() =>
+            {
+                self.block_break(false);
+            }
+            "figure" | "table" | "blockquote" => self.block_break(true),
+            "ul" | "ol" => {
+                self.lists.pop();
+                self.block_break(false);
+            }
+            "li" => {
+                self.checkbox_at = None;
+                self.block_break(false);
+            }
+            "pre" => {
+                if !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push_str("```");
+                self.preformatted = false;
+                self.block_break(true);
+            }
+            "code" if !self.preformatted => self.out.push('`'),
+            "strong" | "b" if !self.in_heading => self.out.push_str("[[/b]]"),
+            "a" => {
+                if let Some(Some((href, start))) = self.links.pop() {
+                    let text = self.out[start..].trim().to_string();
+                    if text != href && !text.is_empty() {
+                        self.out.push_str(&format!(" ({href})"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub fn finish(self) -> String {
+        let mut note = String::with_capacity(self.out.len());
+        let mut blank_lines = 0;
+        for line in self.out.lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                blank_lines += 1;
+                if blank_lines > 1 {
+                    continue;
+                }
+            } else {
+                blank_lines = 0;
+            }
+            note.push_str(line);
+            note.push('\n');
+        }
+        note.trim_matches('\n').to_string()
+    }
+}
@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::models::ImageLimits;
+
+/// Extensions the editor can decode and re-encode as PNG.
+const IMPORTABLE_IMAGE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "heic", "heif", "tif", "tiff", "bmp", "webp",
+];
+
+/// Files from a folder that fit into a note, plus the ones left out and why.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ImageImportPlan {
+    pub paths: Vec<PathBuf>,
+    pub skipped: Vec<SkippedImport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedImport {
+    pub file_name: String,
+    pub reason: String,
+}
+
+/// Lists the images in `folder` (not recursively) that can still be added to a
+/// note already holding `existing_image_count` images.
+pub fn plan_folder_import(
+    folder: &Path,
+    existing_image_count: usize,
+    limits: ImageLimits,
+) -> Result<ImageImportPlan> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(folder)
+        .with_context(|| format!("failed to read folder {}", folder.display()))?
+    {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        files.push((file_name, metadata.len()));
+    }
+
+    let plan = plan_import(files, existing_image_count, limits);
+    Ok(ImageImportPlan {
+        paths: plan.paths.iter().map(|name| folder.join(name)).collect(),
+        skipped: plan.skipped,
+    })
+}
+
+/// Orders `files` (name, size) by name and splits them into importable file
+/// names and skipped entries, keeping the note within `limits`.
+fn plan_import(
+    mut files: Vec<(String, u64)>,
+    existing_image_count: usize,
+    limits: ImageLimits,
+) -> ImageImportPlan {
+    files.retain(|(name, _)| !name.starts_with('.'));
+    files.sort_by(|left, right| {
+        left.0
+            .to_lowercase()
+            .cmp(&right.0.to_lowercase())
+            .then_with(|| left.0.cmp(&right.0))
+    });
+
+    let mut slots = limits
+        .max_images_per_note
+        .saturating_sub(existing_image_count);
+    let mut plan = ImageImportPlan::default();
+    for (file_name, size) in files {
+        let reason = if !is_importable_image(&file_name) {
+            Some("not a supported image".to_string())
+        } else if size > limits.max_image_bytes as u64 {
+            Some(format!(
+                "exceeds {} KB storage limit",
+                limits.max_image_bytes / 1024
+            ))
+        } else if slots == 0 {
+            Some(format!(
+                "note image limit reached (max {})",
+                limits.max_images_per_note
+            ))
+        } else {
+            None
+        };
+
+        match reason {
+            Some(reason) => plan.skipped.push(SkippedImport { file_name, reason }),
+            None => {
+                slots -= 1;
+                plan.paths.push(PathBuf::from(file_name));
+            }
+        }
+    }
+    plan
+}
+
+fn is_importable_image(file_name: &str) -> bool {
+    Path::new(file_name)
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .is_some_and(|ext| IMPORTABLE_IMAGE_EXTENSIONS.contains(&ext.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: ImageLimits = ImageLimits {
+        max_images_per_note: 24,
+        max_image_bytes: 1_000,
+    };
+
+    fn file(name: &str, size: u64) -> (String, u64) {
+        (name.to_string(), size)
+    }
+
+    #[test]
+    fn plan_import_orders_by_name_and_filters_non_images() {
+        let plan = plan_import(
+            vec![
+                file("b.JPG", 10),
+                file("notes.txt", 10),
+                file(".DS_Store", 10),
+                file("A.png", 10),
+                file("huge.png", LIMITS.max_image_bytes as u64 + 1),
+            ],
+            0,
+            LIMITS,
+        );
+
+        assert_eq!(
+            plan.paths,
+            vec![PathBuf::from("A.png"), PathBuf::from("b.JPG")]
+        );
+        let skipped: Vec<&str> = plan.skipped.iter().map(|s| s.file_name.as_str()).collect();
+        assert_eq!(skipped, vec!["huge.png", "notes.txt"]);
+    }
+
+    #[test]
+    fn plan_import_stops_at_the_note_image_limit() {
+        let files = (0..5).map(|i| file(&format!("{i}.png"), 10)).collect();
+        let plan = plan_import(files, LIMITS.max_images_per_note - 2, LIMITS);
+
+        assert_eq!(plan.paths.len(), 2);
+        assert_eq!(plan.skipped.len(), 3);
+        assert!(plan.skipped[0].reason.contains("limit reached"));
+    }
+}
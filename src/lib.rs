@@ -1,6 +1,40 @@
+mod activity;
+mod answers;
+mod app_lock;
 mod backend;
+mod checklist;
+mod commands;
 mod db;
+mod deep_link;
+mod diff;
+mod forge;
+mod history;
+mod html_note;
+mod image_import;
+mod logging;
+mod markdown_input;
+mod markdown_preview;
+mod markdown_table;
+mod merge;
 mod models;
+mod note_links;
+mod notion_import;
+mod org;
+mod preview_style;
+pub mod providers;
+mod query_filters;
+mod ranking_script;
+mod read_later;
+mod router;
+mod schedules;
+mod storage_budget;
+mod tags;
+mod text_transform;
+mod time_tracking;
+mod trackers;
+mod triggers;
+mod usage;
+mod vault;
 
 pub use backend::*;
 
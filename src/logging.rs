@@ -0,0 +1,9 @@
+//! Failures that have no caller to return to: background threads, and
+//! bookkeeping that must not fail the change it records.
+
+use std::fmt::Display;
+
+/// Reports `err`, with its causes, under `context`.
+pub fn log_error(context: impl Display, err: impl Display) {
+    eprintln!("{context}: {err:#}");
+}
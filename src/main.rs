@@ -1,5 +1,159 @@
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    alfred_alt::set_activity_origin(alfred_alt::ActivityOriginKind::Cli);
+
+    if let Some(command) = args.first()
+        && command == "activity"
+    {
+        let limit = match args.get(1).map(|raw| raw.parse::<u32>()) {
+            None => None,
+            Some(Ok(limit)) if args.len() == 2 => Some(limit),
+            _ => {
+                eprintln!("Usage: activity [count]");
+                std::process::exit(2);
+            }
+        };
+        match alfred_alt::list_activity_lines(limit) {
+            Ok(lines) if lines.is_empty() => println!("No activity recorded yet."),
+            Ok(lines) => {
+                for line in lines {
+                    println!("{line}");
+                }
+            }
+            Err(err) => {
+                eprintln!("Reading the activity log failed: {err}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    if let Some(command) = args.first()
+        && command == "ranking-script"
+    {
+        let source = match args.get(1).map(String::as_str) {
+            None => match alfred_alt::load_ranking_script() {
+                Ok(source) if source.is_empty() => {
+                    println!("No ranking script; results keep the built-in order.");
+                    return;
+                }
+                Ok(source) => {
+                    println!("{source}");
+                    return;
+                }
+                Err(err) => {
+                    eprintln!("Reading the ranking script failed: {err}");
+                    std::process::exit(1);
+                }
+            },
+            Some("--clear") if args.len() == 2 => String::new(),
+            Some(path) if args.len() == 2 => match std::fs::read_to_string(path) {
+                Ok(source) => source,
+                Err(err) => {
+                    eprintln!("Reading {path} failed: {err}");
+                    std::process::exit(1);
+                }
+            },
+            Some(_) => {
+                eprintln!("Usage: ranking-script [<script.rhai> | --clear]");
+                std::process::exit(2);
+            }
+        };
+        match alfred_alt::save_ranking_script(source.clone()) {
+            Ok(()) if source.trim().is_empty() => {
+                println!("Ranking script removed; results keep the built-in order.")
+            }
+            Ok(()) => println!("Ranking script saved."),
+            Err(err) => {
+                eprintln!("Saving the ranking script failed: {err}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    if let [command, path] = args.as_slice()
+        && command == "import-notion"
+    {
+        match alfred_alt::import_notion_export(path.clone()) {
+            Ok(report) => {
+                println!(
+                    "Imported {} notes ({} images skipped).",
+                    report.imported_count, report.skipped_image_count
+                );
+                return;
+            }
+            Err(err) => {
+                eprintln!("Notion import failed: {err}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let [command, url] = args.as_slice()
+        && command == "read"
+    {
+        match alfred_alt::save_read_later_article(url.clone()) {
+            Ok(report) if report.queued => {
+                println!(
+                    "Queued {url} to fetch again later: {}",
+                    report.message.unwrap_or_default()
+                );
+                return;
+            }
+            Ok(report) => {
+                println!(
+                    "Saved {url} as a #readlater note ({} images skipped).",
+                    report.skipped_image_count
+                );
+                return;
+            }
+            Err(err) => {
+                eprintln!("Saving {url} failed: {err}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(command) = args.first()
+        && command == "track"
+    {
+        let result = match args.get(1).map(String::as_str) {
+            None => alfred_alt::running_timer().map(|timer| match timer {
+                Some(timer) => println!("Tracking item {}: {}", timer.item_id, timer.title),
+                None => println!("No timer is running."),
+            }),
+            Some("start") if args.len() == 3 => match args[2].parse::<i64>() {
+                Ok(item_id) => alfred_alt::start_timer(item_id)
+                    .map(|()| println!("Started a timer on item {item_id}.")),
+                Err(_) => {
+                    eprintln!("Usage: track [start <item id> | stop]");
+                    std::process::exit(2);
+                }
+            },
+            Some("stop") if args.len() == 2 => alfred_alt::stop_timer().map(|seconds| {
+                if seconds == 0 {
+                    println!("No timer is running.");
+                } else {
+                    println!("Stopped the timer after {} minutes.", seconds / 60);
+                }
+            }),
+            Some(_) => {
+                eprintln!("Usage: track [start <item id> | stop]");
+                std::process::exit(2);
+            }
+        };
+        if let Err(err) = result {
+            eprintln!("Time tracking failed: {err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
     println!("Rust backend is active.");
     println!("Use the native SwiftUI frontend in `swift/App/`.");
     println!("Generate bridge files with `./scripts/generate_swift_bridge.sh`.");
+    println!("Import a Notion HTML export with `import-notion <export.zip|folder>`.");
+    println!("Save a web article as a #readlater note with `read <url>`.");
+    println!("List recent data changes, newest first, with `activity [count]`.");
+    println!("Reorder search results with `ranking-script [<script.rhai> | --clear]`.");
+    println!("Track time on a note with `track [start <item id> | stop]`.");
 }
@@ -0,0 +1,172 @@
+//! Markdown typing helpers for the editor: continuing a list item or checkbox
+//! when Return is pressed in it, and wrapping the selection in emphasis or
+//! code markers.
+
+/// Markers the selection can be wrapped in.
+pub const WRAP_MARKERS: [&str; 4] = ["*", "**", "_", "`"];
+
+/// What Return does on a list line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListContinuation {
+    /// Start the next item: a line break followed by this prefix.
+    NextItem(String),
+    /// The item is empty, so the list ends: its marker is removed instead.
+    EndList,
+}
+
+/// How to wrap or unwrap a selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectionEdit {
+    /// Insert `open` before the selection and `close` after it.
+    Wrap { open: String, close: String },
+    /// Remove this many characters from each end of the selection.
+    Unwrap(usize),
+}
+
+/// What Return does at the end of `line_before_caret`, the caret's line up to
+/// the caret; `None` when the line isn't a list item.
+///
+/// Bullets (`-`, `*`, `+`) are repeated, numbers count up and checkboxes start
+/// unchecked, all at the line's indentation.
+pub fn continue_list(line_before_caret: &str) -> Option<ListContinuation> {
+    let indent_len =
+        line_before_caret.len() - line_before_caret.trim_start_matches([' ', '\t']).len();
+    let (indent, rest) = line_before_caret.split_at(indent_len);
+
+    let (marker, rest) = if let Some(bullet) = ["- ", "* ", "+ "]
+        .into_iter()
+        .find(|bullet| rest.starts_with(bullet))
+    {
+        (bullet.to_string(), &rest[bullet.len()..])
+    } else {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 || digits > 9 {
+            return None;
+        }
+        let number: u64 = rest[..digits].parse().ok()?;
+        let after = &rest[digits..];
+        let separator = after.chars().next().filter(|c| *c == '.' || *c == ')')?;
+        let rest = after[1..].strip_prefix(' ')?;
+        (format!("{}{separator} ", number + 1), rest)
+    };
+
+    let (checkbox, content) = match rest
+        .strip_prefix("[ ]")
+        .or_else(|| rest.strip_prefix("[x]"))
+        .or_else(|| rest.strip_prefix("[X]"))
+    {
+        Some(after) if after.is_empty() || after.starts_with([' ', '\t']) => ("[ ] ", after),
+        _ => ("", rest),
+    };
+
+    if content.trim().is_empty() {
+        return Some(ListContinuation::EndList);
+    }
+    Some(ListContinuation::NextItem(format!(
+        "{indent}{marker}{checkbox}"
+    )))
+}
+
+/// Wraps `selected` in `marker`, or, when `toggle` is set and the selection
+/// is already wrapped in it, unwraps it. Multi-line selections are wrapped in
+/// a code fence instead of backticks.
+pub fn wrap_selection(selected: &str, marker: &str, toggle: bool) -> SelectionEdit {
+    if toggle && is_wrapped(selected, marker) {
+        return SelectionEdit::Unwrap(marker.encode_utf16().count());
+    }
+    if marker == "`" && selected.contains('\n') {
+        let close = if selected.ends_with('\n') {
+            "```\n"
+        } else {
+            "\n```"
+        };
+        return SelectionEdit::Wrap {
+            open: "```\n".to_string(),
+            close: close.to_string(),
+        };
+    }
+    SelectionEdit::Wrap {
+        open: marker.to_string(),
+        close: marker.to_string(),
+    }
+}
+
+fn is_wrapped(selected: &str, marker: &str) -> bool {
+    let Some(inner) = selected
+        .strip_prefix(marker)
+        .and_then(|rest| rest.strip_suffix(marker))
+    else {
+        return false;
+    };
+    if inner.is_empty() {
+        return false;
+    }
+    // "**bold**" isn't italic text wrapped in "*".
+    marker != "*" || !(inner.starts_with('*') && inner.ends_with('*'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ListContinuation, SelectionEdit, continue_list, wrap_selection};
+
+    fn next(prefix: &str) -> Option<ListContinuation> {
+        Some(ListContinuation::NextItem(prefix.to_string()))
+    }
+
+    #[test]
+    fn continue_list_repeats_bullets_numbers_and_checkboxes() {
+        assert_eq!(continue_list("- milk"), next("- "));
+        assert_eq!(continue_list("  * oat"), next("  * "));
+        assert_eq!(continue_list("\t+ rye"), next("\t+ "));
+        assert_eq!(continue_list("9. nine"), next("10. "));
+        assert_eq!(continue_list("  3) three"), next("  4) "));
+        assert_eq!(continue_list("- [x] done"), next("- [ ] "));
+        assert_eq!(continue_list("1. [ ] first"), next("2. [ ] "));
+        // A link at the start of an item isn't a checkbox.
+        assert_eq!(continue_list("- [link](url)"), next("- "));
+    }
+
+    #[test]
+    fn continue_list_ends_on_an_empty_item() {
+        assert_eq!(continue_list("- "), Some(ListContinuation::EndList));
+        assert_eq!(continue_list("  2. "), Some(ListContinuation::EndList));
+        assert_eq!(continue_list("- [ ] "), Some(ListContinuation::EndList));
+        assert_eq!(continue_list("- [ ]"), Some(ListContinuation::EndList));
+    }
+
+    #[test]
+    fn continue_list_ignores_other_lines() {
+        assert_eq!(continue_list(""), None);
+        assert_eq!(continue_list("plain text"), None);
+        assert_eq!(continue_list("---"), None);
+        assert_eq!(continue_list("**bold** start"), None);
+        assert_eq!(continue_list("2024 was a year"), None);
+        assert_eq!(continue_list("3.14 is pi"), None);
+        assert_eq!(continue_list("-no space"), None);
+    }
+
+    #[test]
+    fn wrap_selection_wraps_and_toggles() {
+        let wrap = |open: &str, close: &str| SelectionEdit::Wrap {
+            open: open.to_string(),
+            close: close.to_string(),
+        };
+        assert_eq!(wrap_selection("word", "**", true), wrap("**", "**"));
+        assert_eq!(wrap_selection("", "_", true), wrap("_", "_"));
+        assert_eq!(
+            wrap_selection("_word_", "_", true),
+            SelectionEdit::Unwrap(1)
+        );
+        assert_eq!(wrap_selection("_word_", "_", false), wrap("_", "_"));
+        assert_eq!(
+            wrap_selection("**word**", "**", true),
+            SelectionEdit::Unwrap(2)
+        );
+        assert_eq!(wrap_selection("**word**", "*", true), wrap("*", "*"));
+        assert_eq!(
+            wrap_selection("let a = 1;\nlet b = 2;", "`", false),
+            wrap("```\n", "\n```")
+        );
+        assert_eq!(wrap_selection("a\nb\n", "`", false), wrap("```\n", "```\n"));
+    }
+}
@@ -0,0 +1,634 @@
+//! Splits a note into the blocks of the editor's rendered preview. Inline
+//! Markdown (bold, italic, links, code) is left in the block text for the
+//! frontend to style; bold tokens become `**`, font size tokens are dropped
+//! and `[[Title]]` note links become Markdown links. Reference-style links
+//! are resolved to inline links, and footnotes are numbered and listed after
+//! the last block.
+
+use std::collections::HashMap;
+
+use crate::deep_link;
+use crate::note_links;
+use crate::vault::{
+    BOLD_END_TOKEN, BOLD_TOKEN, inline_image_alt, parse_inline_image, style_token_len,
+};
+
+/// Font size from which a line set entirely in one size reads as a heading.
+const MIN_HEADING_FONT_SIZE: f32 = 17.0;
+/// Spaces per nesting level of a list item.
+const LIST_INDENT_WIDTH: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewBlockKind {
+    Paragraph,
+    Heading,
+    ListItem,
+    ChecklistItem,
+    Quote,
+    Code,
+    Divider,
+    Image,
+    Footnote,
+}
+
+/// One block of the preview. `level` is the heading level (1–3) or the list
+/// nesting depth; `marker` is a list item's `•` or `3.`, or a footnote's
+/// number. An image block's text is its alt text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreviewBlock {
+    pub kind: PreviewBlockKind,
+    pub text: String,
+    pub level: u32,
+    pub marker: String,
+    pub done: bool,
+    pub image_key: Option<String>,
+    pub image_width: Option<u32>,
+}
+
+impl PreviewBlock {
+    fn new(kind: PreviewBlockKind, text: impl Into<String>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            level: 0,
+            marker: String::new(),
+            done: false,
+            image_key: None,
+            image_width: None,
+        }
+    }
+}
+
+/// The preview blocks of `note`. Consecutive plain lines form one paragraph
+/// (keeping their line breaks), and images get a block of their own even
+/// inside a line.
+pub fn preview_blocks(note: &str) -> Vec<PreviewBlock> {
+    let (references, lines) = collect_references(note);
+    let references = &references;
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+
+    for line in lines {
+        if let Some(code_lines) = code.as_mut() {
+            if line.trim_start().starts_with("```") {
+                blocks.push(PreviewBlock::new(
+                    PreviewBlockKind::Code,
+                    code_lines.join("\n"),
+                ));
+                code = None;
+            } else {
+                code_lines.push(line);
+            }
+            continue;
+        }
+        if line.trim_start().starts_with("```") {
+            flush_paragraph(&mut blocks, &mut paragraph);
+            code = Some(Vec::new());
+            continue;
+        }
+
+        let mut text_parts = Vec::new();
+        let mut rest = line;
+        let mut images = Vec::new();
+        while let Some(start) = rest.find("![") {
+            match parse_inline_image(&rest[start..]) {
+                Some((key, width, len)) => {
+                    text_parts.push(&rest[..start]);
+                    let alt = inline_image_alt(&rest[start..start + len]).unwrap_or_default();
+                    images.push((key.to_string(), width, alt.to_string()));
+                    rest = &rest[start + len..];
+                }
+                None => {
+                    text_parts.push(&rest[..start + 2]);
+                    rest = &rest[start + 2..];
+                }
+            }
+        }
+        text_parts.push(rest);
+        let text_line = text_parts.concat();
+
+        if images.is_empty() {
+            push_line(&mut blocks, &mut paragraph, line, references);
+        } else {
+            if !text_line.trim().is_empty() {
+                push_line(&mut blocks, &mut paragraph, &text_line, references);
+            }
+            flush_paragraph(&mut blocks, &mut paragraph);
+            for (key, width, alt) in images {
+                let mut block = PreviewBlock::new(PreviewBlockKind::Image, alt);
+                block.image_key = Some(key);
+                block.image_width = width;
+                blocks.push(block);
+            }
+        }
+    }
+
+    if let Some(code_lines) = code {
+        flush_paragraph(&mut blocks, &mut paragraph);
+        blocks.push(PreviewBlock::new(
+            PreviewBlockKind::Code,
+            code_lines.join("\n"),
+        ));
+    }
+    flush_paragraph(&mut blocks, &mut paragraph);
+
+    let mut footnotes: Vec<(&String, &usize)> = references.footnote_numbers.iter().collect();
+    footnotes.sort_by_key(|(_, number)| **number);
+    if !footnotes.is_empty() {
+        blocks.push(PreviewBlock::new(PreviewBlockKind::Divider, ""));
+    }
+    for (label, number) in footnotes {
+        let text = references.footnotes.get(label).map_or("", String::as_str);
+        let mut block =
+            PreviewBlock::new(PreviewBlockKind::Footnote, inline_text(text, references));
+        block.marker = format!("{number}.");
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// A heading of a note for the editor's outline. `line` is the 0-based line
+/// the heading is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    pub level: u32,
+    pub title: String,
+    pub line: usize,
+}
+
+/// The headings of `note` in order: `#` lines and lines set entirely in a
+/// heading-sized font, as the preview shows them, outside fenced code. Titles
+/// are plain text.
+pub fn outline(note: &str) -> Vec<OutlineEntry> {
+    let references = References::default();
+    let mut in_code = false;
+    let mut entries = Vec::new();
+    for (line_index, line) in note.split('\n').enumerate() {
+        let is_fence = line.trim_start().starts_with("```");
+        if in_code || is_fence {
+            in_code ^= is_fence;
+            continue;
+        }
+        let Some(block) = line_block(line, &references) else {
+            continue;
+        };
+        let title = block.text.replace("**", "").trim().to_string();
+        if block.kind == PreviewBlockKind::Heading && !title.is_empty() {
+            entries.push(OutlineEntry {
+                level: block.level,
+                title,
+                line: line_index,
+            });
+        }
+    }
+    entries
+}
+
+/// Link reference definitions (`[label]: url`) and footnotes (`[^label]: text`)
+/// of a note, keyed by normalized label.
+#[derive(Default)]
+struct References {
+    links: HashMap<String, String>,
+    footnotes: HashMap<String, String>,
+    /// Number of each referenced footnote, in order of first reference.
+    footnote_numbers: HashMap<String, usize>,
+}
+
+/// The definitions in `note` and its remaining lines. Lines indented by four
+/// spaces or a tab continue the footnote above them; nothing inside a fenced
+/// code block counts as a definition or reference.
+fn collect_references(note: &str) -> (References, Vec<&str>) {
+    let mut references = References::default();
+    let mut lines = Vec::new();
+    let mut in_code = false;
+    let mut open_footnote: Option<String> = None;
+
+    for line in note.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+        if in_code || is_fence {
+            in_code ^= is_fence;
+            open_footnote = None;
+            lines.push(line);
+            continue;
+        }
+        if let Some(label) = &open_footnote
+            && (line.starts_with("    ") || line.starts_with('\t'))
+            && !line.trim().is_empty()
+        {
+            if let Some(text) = references.footnotes.get_mut(label) {
+                text.push(' ');
+                text.push_str(line.trim());
+            }
+            continue;
+        }
+        open_footnote = None;
+        if let Some((label, text)) = footnote_definition(line) {
+            if !references.footnotes.contains_key(&label) {
+                references.footnotes.insert(label.clone(), text.to_string());
+                open_footnote = Some(label);
+            }
+            continue;
+        }
+        if let Some((label, url)) = link_definition(line) {
+            references.links.entry(label).or_insert(url);
+            continue;
+        }
+        lines.push(line);
+    }
+
+    in_code = false;
+    for line in &lines {
+        let is_fence = line.trim_start().starts_with("```");
+        if in_code || is_fence {
+            in_code ^= is_fence;
+            continue;
+        }
+        let mut rest = *line;
+        while let Some(start) = rest.find("[^") {
+            let Some((len, label)) = footnote_reference_at(&rest[start..]) else {
+                rest = &rest[start + 2..];
+                continue;
+            };
+            let label = normalize_label(label);
+            let next_number = references.footnote_numbers.len() + 1;
+            if references.footnotes.contains_key(&label) {
+                references
+                    .footnote_numbers
+                    .entry(label)
+                    .or_insert(next_number);
+            }
+            rest = &rest[start + len..];
+        }
+    }
+
+    (references, lines)
+}
+
+/// Labels match case-insensitively, with runs of whitespace as one space.
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// `[label]: url` with an optional quoted title, indented at most three spaces.
+fn link_definition(line: &str) -> Option<(String, String)> {
+    let unindented = line.trim_start_matches(' ');
+    if line.len() - unindented.len() > 3 {
+        return None;
+    }
+    let (label, rest) = unindented.strip_prefix('[')?.split_once("]:")?;
+    if label.trim().is_empty() || label.starts_with('^') || label.contains(['[', ']']) {
+        return None;
+    }
+    let rest = rest.trim();
+    let (destination, title) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let title = title.trim();
+    if !title.is_empty() && !title.starts_with(['"', '\'', '(']) {
+        return None;
+    }
+    let url = destination
+        .strip_prefix('<')
+        .and_then(|url| url.strip_suffix('>'))
+        .unwrap_or(destination);
+    (!url.is_empty()).then(|| (normalize_label(label), url.to_string()))
+}
+
+/// `[^label]: text`, as the normalized label and the text.
+fn footnote_definition(line: &str) -> Option<(String, &str)> {
+    let (len, label) = footnote_reference_at(line)?;
+    let text = line[len..].strip_prefix(':')?;
+    Some((normalize_label(label), text.trim()))
+}
+
+/// Length and label of a `[^label]` footnote reference at the start of `text`.
+fn footnote_reference_at(text: &str) -> Option<(usize, &str)> {
+    let rest = text.strip_prefix("[^")?;
+    let end = rest.find(']')?;
+    let label = &rest[..end];
+    if label.is_empty() || label.contains(|ch: char| ch.is_whitespace() || ch == '[') {
+        return None;
+    }
+    Some((end + 3, label))
+}
+
+fn push_line(
+    blocks: &mut Vec<PreviewBlock>,
+    paragraph: &mut Vec<String>,
+    line: &str,
+    references: &References,
+) {
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        flush_paragraph(blocks, paragraph);
+        return;
+    }
+    let Some(block) = line_block(line, references) else {
+        paragraph.push(inline_text(trimmed, references));
+        return;
+    };
+    flush_paragraph(blocks, paragraph);
+    blocks.push(block);
+}
+
+fn flush_paragraph(blocks: &mut Vec<PreviewBlock>, paragraph: &mut Vec<String>) {
+    if !paragraph.is_empty() {
+        blocks.push(PreviewBlock::new(
+            PreviewBlockKind::Paragraph,
+            paragraph.join("\n"),
+        ));
+        paragraph.clear();
+    }
+}
+
+/// The block a line forms on its own, or `None` for paragraph text.
+fn line_block(line: &str, references: &References) -> Option<PreviewBlock> {
+    let indent = line.len() - line.trim_start().len();
+    let trimmed = line.trim();
+
+    if matches!(trimmed, "---" | "***" | "___") {
+        return Some(PreviewBlock::new(PreviewBlockKind::Divider, ""));
+    }
+    if let Some(level) = (1..=6).find(|&level| {
+        trimmed
+            .strip_prefix(&"#".repeat(level))
+            .is_some_and(|rest| rest.starts_with(' '))
+    }) {
+        let mut block = PreviewBlock::new(
+            PreviewBlockKind::Heading,
+            inline_text(trimmed[level..].trim(), references),
+        );
+        block.level = level.min(3) as u32;
+        return Some(block);
+    }
+    if let Some(size) = sized_line_font_size(trimmed)
+        && size >= MIN_HEADING_FONT_SIZE
+    {
+        let mut block =
+            PreviewBlock::new(PreviewBlockKind::Heading, inline_text(trimmed, references));
+        block.level = if size >= 22.0 {
+            1
+        } else if size >= 19.0 {
+            2
+        } else {
+            3
+        };
+        return Some(block);
+    }
+    if let Some(quote) = trimmed.strip_prefix('>') {
+        return Some(PreviewBlock::new(
+            PreviewBlockKind::Quote,
+            inline_text(quote.trim(), references),
+        ));
+    }
+
+    let depth = (indent / LIST_INDENT_WIDTH) as u32;
+    if let Some(rest) = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+        .or_else(|| trimmed.strip_prefix("+ "))
+    {
+        let checkbox = match rest.get(..3) {
+            Some("[ ]") => Some(false),
+            Some("[x]" | "[X]") => Some(true),
+            _ => None,
+        };
+        let mut block = match checkbox {
+            Some(done) if rest.len() == 3 || rest[3..].starts_with(' ') => {
+                let mut block = PreviewBlock::new(
+                    PreviewBlockKind::ChecklistItem,
+                    inline_text(rest[3..].trim(), references),
+                );
+                block.done = done;
+                block
+            }
+            _ => {
+                let mut block =
+                    PreviewBlock::new(PreviewBlockKind::ListItem, inline_text(rest, references));
+                block.marker = "•".to_string();
+                block
+            }
+        };
+        block.level = depth;
+        return Some(block);
+    }
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0
+        && let Some(rest) = trimmed[digits..]
+            .strip_prefix(". ")
+            .or_else(|| trimmed[digits..].strip_prefix(") "))
+    {
+        let mut block =
+            PreviewBlock::new(PreviewBlockKind::ListItem, inline_text(rest, references));
+        block.marker = format!("{}.", &trimmed[..digits]);
+        block.level = depth;
+        return Some(block);
+    }
+    None
+}
+
+/// The size of a line wrapped entirely in one `[[fs=..]]` span.
+fn sized_line_font_size(line: &str) -> Option<f32> {
+    let rest = line.strip_prefix("[[fs=")?;
+    let (size, rest) = rest.split_once("]]")?;
+    let inner = rest.strip_suffix("[[/fs]]")?;
+    if inner.contains("[[fs=") || inner.contains("[[/fs]]") {
+        return None;
+    }
+    size.parse().ok()
+}
+
+/// `text` with bold tokens as `**`, font size tokens removed, `[[Title]]`
+/// note links as Markdown links to `alfredalt://note/<title>` and references
+/// resolved.
+fn inline_text(text: &str, references: &References) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut cursor = 0;
+    while let Some(start_rel) = text[cursor..].find("[[") {
+        let start = cursor + start_rel;
+        output.push_str(&text[cursor..start]);
+        match style_token_len(&text[start..]) {
+            Some(len) => {
+                let token = &text[start..start + len];
+                if token == BOLD_TOKEN || token == BOLD_END_TOKEN {
+                    output.push_str("**");
+                }
+                cursor = start + len;
+            }
+            None => match note_links::note_link_at(&text[start..]) {
+                Some((len, title)) => {
+                    output.push_str(&format!("[{title}]({})", deep_link::note_title_link(title)));
+                    cursor = start + len;
+                }
+                None => {
+                    output.push_str("[[");
+                    cursor = start + 2;
+                }
+            },
+        }
+    }
+    output.push_str(&text[cursor..]);
+    resolve_references(&output, references)
+}
+
+/// `text` with footnote references as superscript numbers and reference-style
+/// links (`[text][label]`, `[text][]`, `[label]`) as inline links. Brackets
+/// that don't resolve stay as typed.
+fn resolve_references(text: &str, references: &References) -> String {
+    if references.links.is_empty() && references.footnote_numbers.is_empty() {
+        return text.to_string();
+    }
+    let mut output = String::with_capacity(text.len());
+    let mut cursor = 0;
+    while let Some(start_rel) = text[cursor..].find('[') {
+        let start = cursor + start_rel;
+        output.push_str(&text[cursor..start]);
+        let rest = &text[start..];
+        if let Some((len, label)) = footnote_reference_at(rest)
+            && let Some(number) = references.footnote_numbers.get(&normalize_label(label))
+        {
+            output.push_str(&superscript(*number));
+            cursor = start + len;
+        } else if let Some((len, link)) = reference_link_at(rest, references) {
+            output.push_str(&link);
+            cursor = start + len;
+        } else {
+            output.push('[');
+            cursor = start + 1;
+        }
+    }
+    output.push_str(&text[cursor..]);
+    output
+}
+
+/// The inline link for a reference-style link at the start of `text`, with
+/// the length of text it replaces.
+fn reference_link_at(text: &str, references: &References) -> Option<(usize, String)> {
+    let text_len = text[1..].find(']')?;
+    let link_text = &text[1..1 + text_len];
+    if link_text.trim().is_empty() || link_text.contains('[') {
+        return None;
+    }
+    let after = &text[text_len + 2..];
+    if after.starts_with('(') {
+        return None;
+    }
+    let explicit_label = after
+        .strip_prefix('[')
+        .and_then(|rest| rest.find(']').map(|end| &rest[..end]));
+    let (label, len) = match explicit_label {
+        Some("") => (link_text, text_len + 4),
+        Some(label) => (label, text_len + 4 + label.len()),
+        None => (link_text, text_len + 2),
+    };
+    let url = references.links.get(&normalize_label(label))?;
+    Some((len, format!("[{link_text}]({url})")))
+}
+
+fn superscript(number: usize) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    number
+        .to_string()
+        .bytes()
+        .map(|digit| DIGITS[usize::from(digit - b'0')])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(blocks: &[PreviewBlock]) -> Vec<PreviewBlockKind> {
+        blocks.iter().map(|block| block.kind).collect()
+    }
+
+    #[test]
+    fn outline_lists_headings_with_their_lines() {
+        let note = "# Trip\nintro\n## Day **one**\n```\n# not a heading\n```\n[[fs=22]]Packing[[/fs]]\n#tag\n#\n### Notes";
+        let entries: Vec<_> = outline(note)
+            .into_iter()
+            .map(|entry| (entry.level, entry.title, entry.line))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (1, "Trip".to_string(), 0),
+                (2, "Day one".to_string(), 2),
+                (1, "Packing".to_string(), 6),
+                (3, "Notes".to_string(), 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn preview_blocks_split_headings_lists_and_images() {
+        let note = "[[fs=22]][[b]]Trip[[/b]][[/fs]]\nFirst line\nsecond *line*\n\n- milk\n  1. oat\n- [x] book\n> quoted\n---\n```\nlet a = 1;\n```\nsee ![image](alfred://image/img-1-ab?w=320) here\n# Done";
+        let blocks = preview_blocks(note);
+        use PreviewBlockKind::*;
+        assert_eq!(
+            kinds(&blocks),
+            vec![
+                Heading,
+                Paragraph,
+                ListItem,
+                ListItem,
+                ChecklistItem,
+                Quote,
+                Divider,
+                Code,
+                Paragraph,
+                Image,
+                Heading
+            ]
+        );
+        assert_eq!((blocks[0].text.as_str(), blocks[0].level), ("**Trip**", 1));
+        assert_eq!(blocks[1].text, "First line\nsecond *line*");
+        assert_eq!((blocks[3].marker.as_str(), blocks[3].level), ("1.", 1));
+        assert!(blocks[4].done);
+        assert_eq!(blocks[7].text, "let a = 1;");
+        assert_eq!(blocks[8].text, "see  here");
+        assert_eq!(blocks[9].image_key.as_deref(), Some("img-1-ab"));
+        assert_eq!(blocks[9].image_width, Some(320));
+    }
+
+    #[test]
+    fn small_font_lines_and_wiki_links_stay_paragraph_text() {
+        let blocks = preview_blocks("[[fs=12]]fine print[[/fs]] and [[Project X]]");
+        assert_eq!(kinds(&blocks), vec![PreviewBlockKind::Paragraph]);
+        assert_eq!(
+            blocks[0].text,
+            "fine print and [Project X](alfredalt://note/Project%20X)"
+        );
+    }
+
+    #[test]
+    fn reference_links_resolve_and_footnotes_are_listed_last() {
+        let note = "See [the docs][Docs], [home][] and [Home].[^b] Also[^a] and [^b].\n\n[docs]: https://example.com/docs \"Docs\"\n[home]: <https://example.com>\n[^a]: First **note**.\n[^b]: Second note,\n    continued.\n[^unused]: Never cited.\n```\n[x]: not-a-definition\n```";
+        let blocks = preview_blocks(note);
+        use PreviewBlockKind::*;
+        assert_eq!(
+            kinds(&blocks),
+            vec![Paragraph, Code, Divider, Footnote, Footnote]
+        );
+        assert_eq!(
+            blocks[0].text,
+            "See [the docs](https://example.com/docs), [home](https://example.com) and [Home](https://example.com).¹ Also² and ¹."
+        );
+        assert_eq!(blocks[1].text, "[x]: not-a-definition");
+        assert_eq!(
+            (blocks[3].marker.as_str(), blocks[3].text.as_str()),
+            ("1.", "Second note, continued.")
+        );
+        assert_eq!(
+            (blocks[4].marker.as_str(), blocks[4].text.as_str()),
+            ("2.", "First **note**.")
+        );
+        assert_eq!(
+            preview_blocks("[missing] and [^none]")[0].text,
+            "[missing] and [^none]"
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Markdown pipe tables: their cells, which the editor pads so columns line
+//! up, and the editor's table commands (moving between cells with Tab and
+//! inserting rows and columns).
+//!
+//! A row is a line that starts with `|` and has at least two pipes; a table
+//! is two or more rows in a row outside code blocks. Pipes escaped with `\`
+//! or inside backticks are cell text.
+
+use std::ops::Range;
+
+/// Inserted for the cells of a new column, after the pipe that starts it.
+const NEW_CELL: &str = "  ";
+const NEW_SEPARATOR_CELL: &str = " --- ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableCommand {
+    NextCell,
+    PreviousCell,
+    InsertRow,
+    InsertColumn,
+}
+
+/// A table command's result: the table's new lines and the part of row
+/// `row` to select, as a byte range of that line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableEdit {
+    pub lines: Vec<String>,
+    pub row: usize,
+    pub selection: Range<usize>,
+}
+
+/// Byte ranges of the cells of a table row, between its pipes; `None` when
+/// `line` isn't a row. A row without a closing pipe keeps its last cell.
+pub fn row_cells(line: &str) -> Option<Vec<Range<usize>>> {
+    if !line.trim_start().starts_with('|') {
+        return None;
+    }
+    let mut pipes = Vec::new();
+    let mut escaped = false;
+    let mut in_code = false;
+    for (index, character) in line.char_indices() {
+        match character {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '`' => in_code = !in_code,
+            '|' if !in_code => pipes.push(index),
+            _ => {}
+        }
+    }
+    if pipes.len() < 2 {
+        return None;
+    }
+    let mut cells: Vec<Range<usize>> = pipes.windows(2).map(|pair| pair[0] + 1..pair[1]).collect();
+    let last_pipe = pipes[pipes.len() - 1];
+    let rest = &line[last_pipe + 1..];
+    if !rest.trim().is_empty() {
+        cells.push(last_pipe + 1..last_pipe + 1 + rest.trim_end().len());
+    }
+    Some(cells)
+}
+
+/// Whether a row is the `| --- | :-: |` line under a table's header.
+pub fn is_separator_row(line: &str) -> bool {
+    row_cells(line).is_some_and(|cells| {
+        cells.iter().all(|cell| {
+            let cell = line[cell.clone()].trim();
+            let dashes = cell.strip_prefix(':').unwrap_or(cell);
+            let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+            !dashes.is_empty() && dashes.chars().all(|character| character == '-')
+        })
+    })
+}
+
+/// The tables in `text`, each as its rows' cells in byte ranges of `text`.
+pub fn table_cells(text: &str) -> Vec<Vec<Vec<Range<usize>>>> {
+    let mut tables = Vec::new();
+    let mut current: Vec<Vec<Range<usize>>> = Vec::new();
+    let mut in_code_block = false;
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        match row_cells(content).filter(|_| !in_code_block) {
+            Some(cells) => current.push(
+                cells
+                    .into_iter()
+                    .map(|cell| line_start + cell.start..line_start + cell.end)
+                    .collect(),
+            ),
+            None => {
+                if current.len() >= 2 {
+                    tables.push(std::mem::take(&mut current));
+                }
+                current.clear();
+            }
+        }
+        line_start += line.len();
+    }
+    if current.len() >= 2 {
+        tables.push(current);
+    }
+    tables
+}
+
+/// Runs `command` with the caret at byte `caret` of row `row` of the table
+/// made of `lines`; `None` when that line isn't a table row.
+///
+/// Tab past the last cell adds a row, as in spreadsheets. A new row goes
+/// under the caret's row, or under the separator when the caret is in the
+/// header; a new column goes right of the caret's cell.
+pub fn edit_table(
+    lines: &[&str],
+    row: usize,
+    caret: usize,
+    command: TableCommand,
+) -> Option<TableEdit> {
+    let cells = row_cells(lines.get(row)?)?;
+    let column = cells
+        .iter()
+        .position(|cell| caret <= cell.end)
+        .unwrap_or(cells.len() - 1);
+    let mut lines: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    let is_content_row = |line: &str| row_cells(line).is_some() && !is_separator_row(line);
+
+    let (row, column) = match command {
+        TableCommand::NextCell if column + 1 < cells.len() => (row, column + 1),
+        TableCommand::NextCell => {
+            match (row + 1..lines.len()).find(|&next| is_content_row(&lines[next])) {
+                Some(next) => (next, 0),
+                None => {
+                    lines.insert(row + 1, empty_row(&lines[row], cells.len()));
+                    (row + 1, 0)
+                }
+            }
+        }
+        TableCommand::PreviousCell if column > 0 => (row, column - 1),
+        TableCommand::PreviousCell => match (0..row)
+            .rev()
+            .find(|&previous| is_content_row(&lines[previous]))
+        {
+            Some(previous) => (previous, row_cells(&lines[previous])?.len() - 1),
+            None => (row, 0),
+        },
+        TableCommand::InsertRow => {
+            let below = if lines
+                .get(row + 1)
+                .is_some_and(|line| is_separator_row(line))
+            {
+                row + 2
+            } else {
+                row + 1
+            };
+            lines.insert(below, empty_row(&lines[row], cells.len()));
+            (below, 0)
+        }
+        TableCommand::InsertColumn => {
+            for line in lines.iter_mut() {
+                let Some(row_cells) = row_cells(line) else {
+                    continue;
+                };
+                let Some(cell) = row_cells.get(column) else {
+                    continue;
+                };
+                let new_cell = if is_separator_row(line) {
+                    NEW_SEPARATOR_CELL
+                } else {
+                    NEW_CELL
+                };
+                line.insert_str(cell.end, &format!("|{new_cell}"));
+            }
+            (row, column + 1)
+        }
+    };
+
+    let line = &lines[row];
+    let cell = row_cells(line)?.get(column)?.clone();
+    let selection = cell_content(line, cell);
+    Some(TableEdit {
+        lines,
+        row,
+        selection,
+    })
+}
+
+/// A row with `columns` empty cells at the indentation of `like`.
+fn empty_row(like: &str, columns: usize) -> String {
+    let indent = &like[..like.len() - like.trim_start().len()];
+    format!("{indent}|{}", format!("{NEW_CELL}|").repeat(columns))
+}
+
+/// The cell's text without its padding, or a caret after the padding's first
+/// character when the cell is empty. That character may be wider than a byte,
+/// such as a full-width or non-breaking space.
+fn cell_content(line: &str, cell: Range<usize>) -> Range<usize> {
+    let text = &line[cell.clone()];
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        let caret = cell.start + text.chars().next().map_or(0, char::len_utf8);
+        return caret..caret;
+    }
+    let start = cell.start + (text.len() - text.trim_start().len());
+    start..start + trimmed.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TableCommand, edit_table, is_separator_row, row_cells, table_cells};
+
+    const TABLE: [&str; 3] = ["| Name | Qty |", "| --- | :-: |", "| oat | 2 |"];
+
+    fn edit(
+        lines: &[&str],
+        row: usize,
+        caret: usize,
+        command: TableCommand,
+    ) -> (Vec<String>, usize, String) {
+        let edit = edit_table(lines, row, caret, command).unwrap();
+        let selected = edit.lines[edit.row][edit.selection.clone()].to_string();
+        (edit.lines, edit.row, selected)
+    }
+
+    #[test]
+    fn row_cells_split_on_unescaped_pipes() {
+        let cells = |line: &str| {
+            row_cells(line).map(|cells| {
+                cells
+                    .into_iter()
+                    .map(|cell| line[cell].to_string())
+                    .collect::<Vec<_>>()
+            })
+        };
+        assert_eq!(cells("| a | b |"), Some(vec![" a ".into(), " b ".into()]));
+        assert_eq!(cells("  |a|b"), Some(vec!["a".into(), "b".into()]));
+        assert_eq!(
+            cells(r"| a \| b | `x|y` |"),
+            Some(vec![r" a \| b ".into(), " `x|y` ".into()])
+        );
+        assert_eq!(cells("| lonely"), None);
+        assert_eq!(cells("a | b |"), None);
+        assert!(is_separator_row("| --- | :-: |"));
+        assert!(!is_separator_row("| - | x |"));
+    }
+
+    #[test]
+    fn table_cells_need_two_rows_outside_code() {
+        let text = "intro\n| a | b |\n| - | - |\nafter\n| lone |\n```\n| c | d |\n| e | f |\n```\n";
+        let tables = table_cells(text);
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].len(), 2);
+        assert_eq!(&text[tables[0][0][1].clone()], " b ");
+    }
+
+    #[test]
+    fn tab_moves_through_cells_and_adds_a_row_at_the_end() {
+        assert_eq!(edit(&TABLE, 0, 3, TableCommand::NextCell).2, "Qty");
+        // The separator is skipped.
+        let (_, row, selected) = edit(&TABLE, 0, 12, TableCommand::NextCell);
+        assert_eq!((row, selected.as_str()), (2, "oat"));
+
+        let (lines, row, selected) = edit(&TABLE, 2, 10, TableCommand::NextCell);
+        assert_eq!(lines[3], "|  |  |");
+        assert_eq!((row, selected.as_str()), (3, ""));
+
+        let (_, row, selected) = edit(&TABLE, 2, 2, TableCommand::PreviousCell);
+        assert_eq!((row, selected.as_str()), (0, "Qty"));
+        let (_, row, selected) = edit(&TABLE, 0, 2, TableCommand::PreviousCell);
+        assert_eq!((row, selected.as_str()), (0, "Name"));
+    }
+
+    #[test]
+    fn insert_row_and_column() {
+        let (lines, row, _) = edit(&TABLE, 0, 2, TableCommand::InsertRow);
+        assert_eq!(lines[2], "|  |  |");
+        assert_eq!(row, 2);
+
+        let (lines, row, selected) = edit(&TABLE, 2, 3, TableCommand::InsertColumn);
+        assert_eq!(
+            lines,
+            vec!["| Name |  | Qty |", "| --- | --- | :-: |", "| oat |  | 2 |"]
+        );
+        assert_eq!((row, selected.as_str()), (2, ""));
+
+        assert!(edit_table(&["plain"], 0, 0, TableCommand::NextCell).is_none());
+    }
+
+    #[test]
+    fn empty_cells_padded_with_wide_spaces_keep_the_caret_on_a_character() {
+        for table in [
+            ["| a |\u{3000}|", "| --- | --- |"],
+            ["| a |\u{a0}|", "| --- | --- |"],
+        ] {
+            let edit = edit_table(&table, 0, 2, TableCommand::NextCell).unwrap();
+            let line = &edit.lines[edit.row];
+            assert!(line.is_char_boundary(edit.selection.start), "{line:?}");
+            assert_eq!(edit.selection, line.len() - 1..line.len() - 1);
+        }
+    }
+}
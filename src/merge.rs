@@ -0,0 +1,191 @@
+use crate::diff::lcs_pairs;
+
+pub const CONFLICT_START: &str = "<<<<<<<";
+pub const CONFLICT_SEPARATOR: &str = "=======";
+pub const CONFLICT_END: &str = ">>>>>>>";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merge {
+    pub text: String,
+    /// Overlapping changes written out between conflict markers.
+    pub conflicts: usize,
+}
+
+/// Line-based three-way merge of two edits of `base`. Changes to different
+/// lines are combined; when both sides changed the same lines differently,
+/// both versions are kept between `<<<<<<< ours_label` / `=======` /
+/// `>>>>>>> theirs_label` lines.
+pub fn three_way_merge(
+    base: &str,
+    ours: &str,
+    theirs: &str,
+    ours_label: &str,
+    theirs_label: &str,
+) -> Merge {
+    if ours == theirs || theirs == base {
+        return Merge {
+            text: ours.to_string(),
+            conflicts: 0,
+        };
+    }
+    if ours == base {
+        return Merge {
+            text: theirs.to_string(),
+            conflicts: 0,
+        };
+    }
+
+    let base_lines: Vec<&str> = base.split_inclusive('\n').collect();
+    let our_lines: Vec<&str> = ours.split_inclusive('\n').collect();
+    let their_lines: Vec<&str> = theirs.split_inclusive('\n').collect();
+    let ours_at = match_lines(&base_lines, &our_lines);
+    let theirs_at = match_lines(&base_lines, &their_lines);
+
+    let mut merge = Merge {
+        text: String::new(),
+        conflicts: 0,
+    };
+    let (mut b, mut o, mut t) = (0usize, 0usize, 0usize);
+    loop {
+        // The next base line both sides kept.
+        let stable = (b..base_lines.len())
+            .find_map(|index| Some((index, ours_at[index]?, theirs_at[index]?)));
+        let Some((next_b, next_o, next_t)) = stable else {
+            merge.push_chunk(
+                &base_lines[b..],
+                &our_lines[o..],
+                &their_lines[t..],
+                ours_label,
+                theirs_label,
+            );
+            return merge;
+        };
+        if (next_b, next_o, next_t) == (b, o, t) {
+            merge.text.push_str(base_lines[b]);
+            b += 1;
+            o += 1;
+            t += 1;
+            continue;
+        }
+        merge.push_chunk(
+            &base_lines[b..next_b],
+            &our_lines[o..next_o],
+            &their_lines[t..next_t],
+            ours_label,
+            theirs_label,
+        );
+        (b, o, t) = (next_b, next_o, next_t);
+    }
+}
+
+impl Merge {
+    fn push_chunk(
+        &mut self,
+        base: &[&str],
+        ours: &[&str],
+        theirs: &[&str],
+        ours_label: &str,
+        theirs_label: &str,
+    ) {
+        if ours == theirs || theirs == base {
+            self.text.extend(ours.iter().copied());
+        } else if ours == base {
+            self.text.extend(theirs.iter().copied());
+        } else {
+            self.conflicts += 1;
+            self.push_line(&format!("{CONFLICT_START} {ours_label}"));
+            self.push_lines(ours);
+            self.push_line(CONFLICT_SEPARATOR);
+            self.push_lines(theirs);
+            self.push_line(&format!("{CONFLICT_END} {theirs_label}"));
+        }
+    }
+
+    fn push_lines(&mut self, lines: &[&str]) {
+        self.text.extend(lines.iter().copied());
+        if !self.text.is_empty() && !self.text.ends_with('\n') {
+            self.text.push('\n');
+        }
+    }
+
+    fn push_line(&mut self, line: &str) {
+        self.text.push_str(line);
+        self.text.push('\n');
+    }
+}
+
+/// For each base line, the index of the line it lines up with in `other`
+/// under a longest common subsequence, if any.
+fn match_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut matched = vec![None; base.len()];
+    let prefix = base.iter().zip(other).take_while(|(a, b)| a == b).count();
+    let suffix = base[prefix..]
+        .iter()
+        .rev()
+        .zip(other[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    for (index, slot) in matched.iter_mut().enumerate().take(prefix) {
+        *slot = Some(index);
+    }
+    for offset in 1..=suffix {
+        matched[base.len() - offset] = Some(other.len() - offset);
+    }
+
+    let old = &base[prefix..base.len() - suffix];
+    let new = &other[prefix..other.len() - suffix];
+    // Too long to match line by line: the middle is one replaced block.
+    for (i, j) in lcs_pairs(old, new).unwrap_or_default() {
+        matched[prefix + i] = Some(prefix + j);
+    }
+    matched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::three_way_merge;
+
+    #[test]
+    fn edits_to_different_lines_merge_cleanly() {
+        let base = "one\ntwo\nthree\nfour\nfive\n";
+        let ours = "ONE\ntwo\nthree\nfour\nfive\n";
+        let theirs = "one\ntwo\nthree\nfour\nfive\nsix\n";
+        let merged = three_way_merge(base, ours, theirs, "app", "vault");
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(merged.text, "ONE\ntwo\nthree\nfour\nfive\nsix\n");
+
+        // A deletion on one side and an edit elsewhere on the other.
+        let ours = "one\nthree\nfour\nfive\n";
+        let theirs = "one\ntwo\nthree\nfour\nFIVE";
+        let merged = three_way_merge(base, ours, theirs, "app", "vault");
+        assert_eq!(merged.conflicts, 0);
+        assert_eq!(merged.text, "one\nthree\nfour\nFIVE");
+
+        // The same change made on both sides is taken once.
+        let both = "one\ntwo\n3\nfour\nfive\n";
+        let merged = three_way_merge(base, both, both, "app", "vault");
+        assert_eq!(merged.text, both);
+        assert_eq!(merged.conflicts, 0);
+    }
+
+    #[test]
+    fn overlapping_edits_keep_both_sides_between_markers() {
+        let base = "title\nmilk\neggs";
+        let ours = "title\noat milk\neggs";
+        let theirs = "title\nwhole milk\neggs";
+        let merged = three_way_merge(base, ours, theirs, "app", "vault");
+        assert_eq!(merged.conflicts, 1);
+        assert_eq!(
+            merged.text,
+            "title\n<<<<<<< app\noat milk\n=======\nwhole milk\n>>>>>>> vault\neggs"
+        );
+
+        // Both sides appending different last lines without a newline.
+        let merged = three_way_merge("a\n", "a\nb", "a\nc", "app", "vault");
+        assert_eq!(merged.conflicts, 1);
+        assert_eq!(
+            merged.text,
+            "a\n<<<<<<< app\nb\n=======\nc\n>>>>>>> vault\n"
+        );
+    }
+}
@@ -1,3 +1,63 @@
+use chrono::NaiveDate;
+
+pub use crate::diff::{DiffKind, DiffSegment};
+pub use crate::history::ActivityDay;
+pub use crate::storage_budget::{AlertLevel, StorageAlert, StorageSnapshot};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultKind {
+    Item,
+    SystemCommand,
+    /// Opens an app view (e.g. the board) instead of running an OS action.
+    AppCommand,
+    /// A launcher query prefix; `command_key` holds the prefix text.
+    QueryPrefix,
+    /// An answer block of note `id`; `command_key` holds the text to copy.
+    Answer,
+    /// Saves the web page whose URL is in `command_key` as a read-later note.
+    ReadLater,
+    /// A result of a registered provider; `command_key` holds its action.
+    Provider,
+    /// Starts a timer on note `id` (`command_key` "start") or stops the
+    /// running timer ("stop").
+    Timer,
+}
+
+/// Order of the browse window's item list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowseSort {
+    /// Search ranking for a text query, otherwise newest first.
+    Relevance,
+    Updated,
+    Created,
+    Title,
+}
+
+/// The group a result is listed under when a query mixes providers; groups
+/// are listed in this order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultSection {
+    Answers,
+    Commands,
+    Notes,
+    Providers,
+}
+
+impl ResultKind {
+    pub fn section(self) -> ResultSection {
+        match self {
+            ResultKind::Answer => ResultSection::Answers,
+            ResultKind::SystemCommand
+            | ResultKind::AppCommand
+            | ResultKind::QueryPrefix
+            | ResultKind::ReadLater
+            | ResultKind::Timer => ResultSection::Commands,
+            ResultKind::Item => ResultSection::Notes,
+            ResultKind::Provider => ResultSection::Providers,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub id: i64,
@@ -5,6 +65,144 @@ pub struct SearchResult {
     pub subtitle: String,
     pub snippet: Option<String>,
     pub snippet_source: Option<String>,
+    pub kind: ResultKind,
+    pub icon: Option<String>,
+    pub command_key: Option<String>,
+    pub pinned: bool,
+    /// Key of the image shown as the row thumbnail; bytes are fetched separately.
+    pub thumbnail_key: Option<String>,
+    /// Byte ranges of `title` matching the query, sorted and non-overlapping.
+    pub title_match_ranges: Vec<(usize, usize)>,
+    /// Checked and total checklist entries of the note, if it has any.
+    pub checklist_progress: Option<ChecklistProgress>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChecklistProgress {
+    pub done: u32,
+    pub total: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardCard {
+    pub id: i64,
+    pub title: String,
+    pub status: String,
+    pub preview: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RunningTimer {
+    pub item_id: i64,
+    pub title: String,
+    /// Unix seconds.
+    pub started_at: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CalendarEntry {
+    pub day: u32,
+    pub item_id: i64,
+    pub title: String,
+    pub is_journal: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemStats {
+    pub word_count: usize,
+    pub char_count: usize,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub version_count: usize,
+    pub days: Vec<ActivityDay>,
+}
+
+/// Library-wide usage, for the stats view.
+#[derive(Debug, Clone)]
+pub struct UsageStats {
+    pub item_count: usize,
+    pub image_count: usize,
+    pub image_bytes: u64,
+    /// Oldest first, one entry per local day including days without searches.
+    pub searches_per_day: Vec<(NaiveDate, u32)>,
+    pub most_opened: Vec<OpenedItem>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenedItem {
+    pub id: i64,
+    pub title: String,
+    pub opens: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct NoteVersionSummary {
+    /// Position in the item's history, oldest first.
+    pub index: usize,
+    pub at_unix_seconds: u64,
+    pub saves: u32,
+    pub char_count: usize,
+}
+
+/// Approximate bytes an item takes in the JSON mirror (text plus images).
+#[derive(Debug, Clone)]
+pub struct ItemStorageUsage {
+    pub id: i64,
+    pub title: String,
+    pub bytes: u64,
+    /// False for items kept out of the mirror, which only use local index space.
+    pub mirrored: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct StorageReport {
+    pub root: String,
+    pub snapshot: StorageSnapshot,
+    pub alerts: Vec<StorageAlert>,
+    /// Largest items first.
+    pub largest_items: Vec<ItemStorageUsage>,
+}
+
+/// Limits applied to the images of every note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageLimits {
+    pub max_images_per_note: usize,
+    pub max_image_bytes: usize,
+}
+
+/// A stored note image as listed for re-encoding.
+#[derive(Debug, Clone)]
+pub struct StoredImage {
+    pub item_id: i64,
+    pub image_key: String,
+    pub bytes: u64,
+    /// Hash of the stored bytes, used to skip images edited in the meantime.
+    pub fingerprint: u64,
+}
+
+/// New bytes for a stored image, applied only if it still has `fingerprint`.
+#[derive(Debug, Clone)]
+pub struct ImageReplacement {
+    pub item_id: i64,
+    pub image_key: String,
+    pub fingerprint: u64,
+    pub bytes: Vec<u8>,
+}
+
+/// Text recognized in a stored image, applied only if it still has `fingerprint`.
+#[derive(Debug, Clone)]
+pub struct ImageText {
+    pub item_id: i64,
+    pub image_key: String,
+    pub fingerprint: u64,
+    pub text: String,
+}
+
+/// A note rendered as a standalone Markdown document, e.g. for drag and drop.
+#[derive(Debug, Clone)]
+pub struct ItemMarkdown {
+    pub file_name: String,
+    pub markdown: String,
 }
 
 #[derive(Debug, Clone)]
@@ -13,10 +211,31 @@ pub struct NoteImage {
     pub bytes: Vec<u8>,
 }
 
+/// A note read from another app's export, added as a new item.
+#[derive(Debug, Clone)]
+pub struct ImportedNote {
+    pub title: String,
+    pub note: String,
+    pub images: Vec<NoteImage>,
+}
+
+/// The launcher subtitle and extra search keywords of an item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemMetadata {
+    pub subtitle: String,
+    pub keywords: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct EditableItem {
     pub id: i64,
     pub title: String,
+    pub subtitle: String,
+    pub keywords: String,
     pub note: String,
     pub images: Vec<NoteImage>,
+    pub revision: u64,
+    pub secure: bool,
+    /// Reason the item is not mirrored to the JSON storage folder, if excluded.
+    pub mirror_exclusion: Option<String>,
 }
@@ -0,0 +1,115 @@
+//! `[[Note Title]]` links between notes: finding them in note text, the
+//! title typed after an unclosed `[[` for autocompletion, and ranking note
+//! titles against it.
+
+use crate::vault::style_token_len;
+
+/// Length and title of a `[[Title]]` link at the start of `text`. Style
+/// tokens like `[[b]]` aren't links, nor are empty titles or ones spanning
+/// lines or holding brackets.
+pub fn note_link_at(text: &str) -> Option<(usize, &str)> {
+    if style_token_len(text).is_some() {
+        return None;
+    }
+    let rest = text.strip_prefix("[[")?;
+    let end = rest.find("]]")?;
+    let inner = &rest[..end];
+    if inner.contains(['[', ']', '\n']) {
+        return None;
+    }
+    let title = inner.trim();
+    (!title.is_empty()).then_some((end + 4, title))
+}
+
+/// The title typed so far when `before_caret` ends inside an unclosed `[[`
+/// on its line, for suggesting note titles.
+pub fn pending_link_query(before_caret: &str) -> Option<&str> {
+    let line = before_caret.rsplit('\n').next().unwrap_or(before_caret);
+    let start = line.rfind("[[")?;
+    let query = &line[start + 2..];
+    if query.contains(['[', ']']) || style_token_len(&line[start..]).is_some() {
+        return None;
+    }
+    Some(query)
+}
+
+/// Up to `limit` of `notes` whose title contains `query`, ignoring case:
+/// exact titles first, then titles starting with it, then titles with a
+/// word starting with it. Ties keep the order of `notes`.
+pub fn rank_titles<'a>(
+    notes: impl IntoIterator<Item = (i64, &'a str)>,
+    query: &str,
+    limit: usize,
+) -> Vec<(i64, String)> {
+    let query = query.trim().to_lowercase();
+    let mut ranked: Vec<(u8, i64, &str)> = notes
+        .into_iter()
+        .filter_map(|(id, title)| {
+            let lower = title.to_lowercase();
+            let rank = if lower == query {
+                0
+            } else if lower.starts_with(&query) {
+                1
+            } else if lower
+                .split(|character: char| !character.is_alphanumeric())
+                .any(|word| word.starts_with(&query))
+            {
+                2
+            } else if lower.contains(&query) {
+                3
+            } else {
+                return None;
+            };
+            Some((rank, id, title))
+        })
+        .collect();
+    ranked.sort_by_key(|(rank, _, _)| *rank);
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(_, id, title)| (id, title.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn note_links_skip_style_tokens() {
+        assert_eq!(
+            note_link_at("[[ Project X ]] later"),
+            Some((15, "Project X"))
+        );
+        assert_eq!(note_link_at("[[b]]bold[[/b]]"), None);
+        assert_eq!(note_link_at("[[fs=18]]big"), None);
+        assert_eq!(note_link_at("[[  ]]"), None);
+        assert_eq!(note_link_at("[[two\nlines]]"), None);
+    }
+
+    #[test]
+    fn pending_query_is_the_text_after_an_unclosed_link() {
+        assert_eq!(pending_link_query("see [[Proj"), Some("Proj"));
+        assert_eq!(pending_link_query("see [["), Some(""));
+        assert_eq!(pending_link_query("[[Done]] and more"), None);
+        assert_eq!(pending_link_query("[[old\nnew line"), None);
+        assert_eq!(pending_link_query("[[b]]"), None);
+    }
+
+    #[test]
+    fn titles_rank_exact_then_prefix_then_word_then_substring() {
+        let notes = [
+            (1, "Workplan"),
+            (2, "Old plan"),
+            (3, "Planning"),
+            (4, "Plan"),
+            (5, "Recipes"),
+        ];
+        let ranked: Vec<i64> = rank_titles(notes, "plan", 10)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ranked, vec![4, 3, 2, 1]);
+        assert_eq!(rank_titles(notes, "", 2).len(), 2);
+    }
+}
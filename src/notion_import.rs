@@ -0,0 +1,523 @@
+//! Import of Notion's HTML export, either the ZIP Notion produces or the
+//! folder it unpacks to.
+//!
+//! Every page becomes a note: headings keep a larger bold font, lists, to-dos
+//! and code blocks keep their Markdown-like form, and images (exported files
+//! or remote URLs) become note images. A database's own page is skipped and
+//! its rows are tagged with the database name and their select values.
+
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result, ensure};
+
+use crate::html_note::{
+    NoteWriter, Token, attribute, decode_entities, download_image, has_class, percent_decode,
+    tokenize,
+};
+use crate::logging::log_error;
+use crate::models::{ImageLimits, ImportedNote, NoteImage};
+use crate::tags;
+use crate::vault;
+
+/// Class of the table listing a database's rows.
+const DATABASE_TABLE_CLASS: &str = "collection-content";
+const NOTION_ID_HEX_LEN: usize = 32;
+
+/// A page of the export converted to note text.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NotionPage {
+    pub title: String,
+    pub note: String,
+    /// Values of select, multi-select and status properties.
+    pub property_tags: Vec<String>,
+}
+
+/// Notes read from an export, plus the embedded images left out because they
+/// were missing, unreachable or over the limits.
+#[derive(Debug, Clone, Default)]
+pub struct NotionImport {
+    pub notes: Vec<ImportedNote>,
+    pub skipped_images: usize,
+}
+
+/// Reads a Notion HTML export from its ZIP file or unpacked folder.
+pub fn read_notion_export(path: &Path, limits: ImageLimits) -> Result<NotionImport> {
+    if path.is_dir() {
+        return read_export_folder(path, limits);
+    }
+    ensure!(
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("zip")),
+        "{} is not a folder or ZIP file",
+        path.display()
+    );
+
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis());
+    let dir = std::env::temp_dir().join(format!(
+        "alfred-notion-import-{}-{stamp}",
+        std::process::id()
+    ));
+    let result = unpack_export(path, &dir).and_then(|()| read_export_folder(&dir, limits));
+    let _ = std::fs::remove_dir_all(&dir);
+    result
+}
+
+/// Notion splits large exports into ZIPs inside the downloaded one, so those
+/// are unpacked as well.
+fn unpack_export(archive: &Path, dir: &Path) -> Result<()> {
+    unzip(archive, dir)?;
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("failed to scan {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+        {
+            unzip(&path, dir)?;
+            std::fs::remove_file(&path)
+                .with_context(|| format!("failed to remove {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+fn unzip(archive: &Path, dir: &Path) -> Result<()> {
+    let status = std::process::Command::new("/usr/bin/ditto")
+        .args(["-x", "-k"])
+        .arg(archive)
+        .arg(dir)
+        .status()
+        .context("failed to run ditto")?;
+    ensure!(status.success(), "failed to unpack {}", archive.display());
+    Ok(())
+}
+
+fn read_export_folder(folder: &Path, limits: ImageLimits) -> Result<NotionImport> {
+    let export_dir = folder
+        .canonicalize()
+        .with_context(|| format!("failed to find {}", folder.display()))?;
+    let mut pages = Vec::new();
+    collect_html_files(folder, &mut pages)?;
+    pages.sort();
+
+    let mut sources = Vec::with_capacity(pages.len());
+    for path in pages {
+        let html = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        sources.push((path, html));
+    }
+    // A database page's rows are exported to the folder named like the page.
+    let database_dirs: HashSet<PathBuf> = sources
+        .iter()
+        .filter(|(_, html)| html.contains(DATABASE_TABLE_CLASS))
+        .map(|(path, _)| path.with_extension(""))
+        .collect();
+
+    let mut import = NotionImport::default();
+    for (path, html) in &sources {
+        if database_dirs.contains(&path.with_extension("")) {
+            continue;
+        }
+        let page_dir = path.parent().unwrap_or(folder);
+        let mut images = Vec::new();
+        let page = parse_notion_page(html, |src| {
+            if images.len() >= limits.max_images_per_note {
+                import.skipped_images += 1;
+                return None;
+            }
+            match load_image(&export_dir, page_dir, src, limits.max_image_bytes) {
+                Ok(bytes) => {
+                    let key = image_key(path, images.len());
+                    images.push(NoteImage {
+                        image_key: key.clone(),
+                        bytes,
+                    });
+                    Some(key)
+                }
+                Err(err) => {
+                    log_error(format_args!("skipped Notion image {src}"), err);
+                    import.skipped_images += 1;
+                    None
+                }
+            }
+        });
+
+        let mut page_tags = Vec::new();
+        if database_dirs.contains(page_dir) {
+            let database_name = page_dir
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            page_tags.extend(tag_for_name(strip_notion_id(&database_name)));
+        }
+        page_tags.extend(
+            page.property_tags
+                .iter()
+                .filter_map(|value| tag_for_name(value)),
+        );
+
+        let mut note = page.note;
+        for tag in page_tags {
+            note = tags::add_tag(&note, &tag);
+        }
+        let title = if page.title.trim().is_empty() {
+            let stem = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            strip_notion_id(&stem).to_string()
+        } else {
+            page.title.trim().to_string()
+        };
+        import.notes.push(ImportedNote {
+            title,
+            note,
+            images,
+        });
+    }
+    Ok(import)
+}
+
+fn collect_html_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("failed to scan {}", dir.display()))?
+    {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        if entry.metadata()?.is_dir() {
+            collect_html_files(&path, files)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("html"))
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Bytes of an image embedded as `src` in a page in `page_dir`: an image file
+/// inside `export_dir`, which is canonical, or a remote URL.
+fn load_image(export_dir: &Path, page_dir: &Path, src: &str, max_bytes: usize) -> Result<Vec<u8>> {
+    if src.starts_with("https://") || src.starts_with("http://") {
+        return download_image(src, max_bytes);
+    }
+    let relative = percent_decode(src);
+    ensure!(
+        !Path::new(&relative).is_absolute()
+            && !relative.starts_with(['/', '\\'])
+            && !relative.contains(':')
+            && !relative.split(['/', '\\']).any(|part| part == ".."),
+        "not a file of the export"
+    );
+    let path = page_dir.join(&relative);
+    let path = path
+        .canonicalize()
+        .with_context(|| format!("failed to find {}", path.display()))?;
+    ensure!(path.starts_with(export_dir), "not a file of the export");
+    let bytes =
+        std::fs::read(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    ensure!(
+        bytes.len() <= max_bytes,
+        "larger than {} KB",
+        max_bytes / 1024
+    );
+    ensure!(vault::image_extension(&bytes).is_some(), "not an image");
+    Ok(bytes)
+}
+
+fn image_key(page: &Path, index: usize) -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let mut hasher = DefaultHasher::new();
+    (page, index, timestamp).hash(&mut hasher);
+    format!("img-{timestamp}-{:08x}", hasher.finish() as u32)
+}
+
+/// `name` without the 32-character id Notion appends to exported file names.
+pub fn strip_notion_id(name: &str) -> &str {
+    match name.trim().rsplit_once(' ') {
+        Some((stem, id))
+            if id.len() == NOTION_ID_HEX_LEN && id.chars().all(|ch| ch.is_ascii_hexdigit()) =>
+        {
+            stem.trim_end()
+        }
+        _ => name.trim(),
+    }
+}
+
+/// A hashtag for a database name or select value (`Reading List` → `reading-list`).
+pub fn tag_for_name(name: &str) -> Option<String> {
+    let mut tag = String::with_capacity(name.len());
+    for ch in name.trim().chars() {
+        if ch.is_alphanumeric() || matches!(ch, '_' | '/') {
+            tag.extend(ch.to_lowercase());
+        } else if !tag.is_empty() && !tag.ends_with('-') {
+            tag.push('-');
+        }
+    }
+    let tag = tags::normalize_tag(tag.trim_end_matches('-'));
+    tags::is_valid_tag(&tag).then_some(tag)
+}
+
+/// A row of a page's property table.
+#[derive(Debug, Default)]
+struct PropertyRow {
+    is_select: bool,
+    in_value: bool,
+    in_selected_value: bool,
+    name: String,
+    value: String,
+    selected_values: Vec<String>,
+}
+
+/// Converts an exported page. `image_key` stores the image at an `img` source
+/// and returns its key; images it declines are left out.
+pub fn parse_notion_page(
+    html: &str,
+    mut image_key: impl FnMut(&str) -> Option<String>,
+) -> NotionPage {
+    let mut page = NotionPage::default();
+    let mut writer = NoteWriter::default();
+    let mut document_title = String::new();
+    let mut in_document_title = false;
+    let mut in_page_title = false;
+    let mut in_head = false;
+    // Depth of an element left out of the note (like the table of contents).
+    let mut skipped_depth = 0usize;
+    let mut properties: Option<Vec<(String, String)>> = None;
+    let mut property_row: Option<PropertyRow> = None;
+    let mut finished_properties = Vec::new();
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open { name, attrs, .. } => {
+                if skipped_depth > 0 {
+                    if name == "nav" {
+                        skipped_depth += 1;
+                    }
+                    continue;
+                }
+                match name.as_str() {
+                    "head" => in_head = true,
+                    "title" if in_head => in_document_title = true,
+                    "nav" => skipped_depth = 1,
+                    "h1" if has_class(&attrs, "page-title") => in_page_title = true,
+                    "table" if has_class(&attrs, "properties") => properties = Some(Vec::new()),
+                    _ if properties.is_some() => match name.as_str() {
+                        "tr" => {
+                            let class = attribute(&attrs, "class").unwrap_or_default();
+                            property_row = Some(PropertyRow {
+                                is_select: [
+                                    "property-row-select",
+                                    "property-row-multi_select",
+                                    "property-row-status",
+                                ]
+                                .iter()
+                                .any(|select| class.split_whitespace().any(|name| name == *select)),
+                                ..PropertyRow::default()
+                            });
+                        }
+                        "td" => {
+                            if let Some(row) = property_row.as_mut() {
+                                row.in_value = true;
+                            }
+                        }
+                        "span" if has_class(&attrs, "selected-value") => {
+                            if let Some(row) = property_row.as_mut() {
+                                row.in_selected_value = true;
+                                row.selected_values.push(String::new());
+                            }
+                        }
+                        _ => {}
+                    },
+                    _ if !in_head && !in_page_title => writer.open(&name, &attrs, &mut image_key),
+                    _ => {}
+                }
+            }
+            Token::Close(name) => {
+                if skipped_depth > 0 {
+                    if name == "nav" {
+                        skipped_depth -= 1;
+                    }
+                    continue;
+                }
+                match name.as_str() {
+                    "head" => in_head = false,
+                    "title" => in_document_title = false,
+                    "h1" if in_page_title => in_page_title = false,
+                    "table" if properties.is_some() => {
+                        finished_properties = properties.take().unwrap_or_default();
+                    }
+                    _ if properties.is_some() => match name.as_str() {
+                        "tr" => {
+                            if let (Some(row), Some(properties)) =
+                                (property_row.take(), properties.as_mut())
+                            {
+                                let value = if row.selected_values.is_empty() {
+                                    row.value.split_whitespace().collect::<Vec<_>>().join(" ")
+                                } else {
+                                    row.selected_values.join(", ")
+                                };
+                                let name =
+                                    row.name.split_whitespace().collect::<Vec<_>>().join(" ");
+                                if row.is_select {
+                                    page.property_tags.extend(row.selected_values);
+                                }
+                                if !name.is_empty() && !value.is_empty() {
+                                    properties.push((name, value));
+                                }
+                            }
+                        }
+                        "span" => {
+                            if let Some(row) = property_row.as_mut() {
+                                row.in_selected_value = false;
+                            }
+                        }
+                        _ => {}
+                    },
+                    _ if !in_head && !in_page_title => writer.close(&name),
+                    _ => {}
+                }
+            }
+            Token::Text(text) => {
+                if skipped_depth > 0 {
+                    continue;
+                }
+                let text = decode_entities(&text);
+                if in_document_title {
+                    document_title.push_str(&text);
+                } else if in_page_title {
+                    page.title.push_str(&text);
+                } else if let Some(row) = property_row.as_mut() {
+                    if row.in_selected_value {
+                        if let Some(value) = row.selected_values.last_mut() {
+                            value.push_str(text.trim());
+                        }
+                    } else if row.in_value {
+                        row.value.push_str(&text);
+                    } else {
+                        row.name.push_str(&text);
+                    }
+                } else if !in_head && properties.is_none() {
+                    writer.push_text(&text);
+                }
+            }
+        }
+    }
+
+    if page.title.trim().is_empty() {
+        page.title = document_title;
+    }
+    page.title = page.title.split_whitespace().collect::<Vec<_>>().join(" ");
+    let body = writer.finish();
+    let property_lines: Vec<String> = finished_properties
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect();
+    page.note = match (property_lines.is_empty(), body.is_empty()) {
+        (true, _) => body,
+        (false, true) => property_lines.join("\n"),
+        (false, false) => format!("{}\n\n{body}", property_lines.join("\n")),
+    };
+    page
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_notion_page_keeps_headings_lists_code_and_images() {
+        let html = r##"<html><head><title>Doc</title><style>h1 { color: red; }</style></head>
+<body><article class="page"><header><h1 class="page-title">Trip &amp; plans</h1>
+<nav class="table_of_contents"><a href="#x">Packing</a></nav></header>
+<div class="page-body"><h2>Packing</h2>
+<ul class="bulleted-list"><li>Passport <strong>and</strong> visa</li></ul>
+<ul class="to-do-list"><li><div class="checkbox checkbox-on"></div> <span class="to-do-children-checked">Tickets</span></li></ul>
+<ul class="to-do-list"><li><div class="checkbox checkbox-off"></div> <span>Charger</span></li></ul>
+<ol class="numbered-list" start="1"><li>Leave</li></ol><ol class="numbered-list" start="2"><li>Arrive</li></ol>
+<pre class="code"><code>let  x = 1;
+x &lt; 2</code></pre>
+<p>See <a href="https://example.com">the site</a> and <a href="Other%20abc.html">Other</a>.</p>
+<figure class="image"><a href="Trip/map.png"><img src="Trip/map.png"/></a></figure>
+<figure class="image"><img src="missing.png"/></figure>
+</div></article></body></html>"##;
+        let page = parse_notion_page(html, |src| {
+            (src == "Trip/map.png").then(|| "img-1".to_string())
+        });
+        assert_eq!(page.title, "Trip & plans");
+        assert_eq!(
+            page.note,
+            "[[fs=20]][[b]]Packing[[/b]][[/fs]]\n\n- Passport [[b]]and[[/b]] visa\n- [x] Tickets\n- [ ] Charger\n1. Leave\n2. Arrive\n\n```\nlet  x = 1;\nx < 2\n```\n\nSee the site (https://example.com) and Other.\n\n![image](alfred://image/img-1?w=360)"
+        );
+    }
+
+    #[test]
+    fn parse_notion_page_reads_database_row_properties() {
+        let html = r#"<article class="page"><header><h1 class="page-title">Dune</h1>
+<table class="properties"><tbody>
+<tr class="property-row property-row-multi_select"><th><svg></svg>Genre</th><td><span class="selected-value select-value-color-blue">Sci Fi</span><span class="selected-value">Classic</span></td></tr>
+<tr class="property-row property-row-text"><th>Author</th><td>Frank   Herbert</td></tr>
+</tbody></table></header><div class="page-body"><p>Great.</p></div></article>"#;
+        let page = parse_notion_page(html, |_| None);
+        assert_eq!(page.title, "Dune");
+        assert_eq!(page.property_tags, vec!["Sci Fi", "Classic"]);
+        assert_eq!(
+            page.note,
+            "Genre: Sci Fi, Classic\nAuthor: Frank Herbert\n\nGreat."
+        );
+    }
+
+    #[test]
+    fn notion_names_map_to_titles_and_tags() {
+        assert_eq!(
+            strip_notion_id("Reading List 0123456789abcdef0123456789abcdef"),
+            "Reading List"
+        );
+        assert_eq!(strip_notion_id("Plain name"), "Plain name");
+        assert_eq!(
+            tag_for_name("Reading List").as_deref(),
+            Some("reading-list")
+        );
+        assert_eq!(tag_for_name("Sci-Fi!").as_deref(), Some("sci-fi"));
+        assert_eq!(tag_for_name("  ").as_deref(), None);
+        assert_eq!(percent_decode("Trip%20abc/map%2B.png"), "Trip abc/map+.png");
+    }
+
+    #[test]
+    fn load_image_only_reads_images_inside_the_export() {
+        let dir = std::env::temp_dir().join(format!(
+            "alfred_notion_test_{}_{}",
+            std::process::id(),
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_nanos())
+        ));
+        let export_dir = dir.join("export");
+        let page_dir = export_dir.join("Trip");
+        std::fs::create_dir_all(&page_dir).unwrap();
+        let secret = dir.join("id_rsa");
+        std::fs::write(&secret, b"-----BEGIN KEY-----").unwrap();
+        std::fs::write(page_dir.join("map.png"), b"\x89PNG\r\n").unwrap();
+        std::fs::write(page_dir.join("notes.png"), b"not an image").unwrap();
+        let export_dir = export_dir.canonicalize().unwrap();
+        let load = |src: &str| load_image(&export_dir, &page_dir, src, 1024);
+
+        assert_eq!(load("map.png").unwrap(), b"\x89PNG\r\n");
+        assert!(load(&secret.to_string_lossy()).is_err());
+        assert!(load("../../id_rsa").is_err());
+        assert!(load("notes.png").is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
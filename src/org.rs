@@ -0,0 +1,382 @@
+//! Conversion between notes and the Emacs org-mode files of the vault.
+//!
+//! An org note keeps the item id in an `#+ALFRED_ID:` keyword line. Bold text
+//! maps to `*bold*` and inline images to `[[file:attachments/file]]` links.
+//! When an org file is read, headlines become bold lines (larger for the top
+//! two levels), `TODO`/`DONE` headlines become checklist entries and headline
+//! tags become hashtags; other keywords, drawers and planning lines are
+//! dropped.
+
+use crate::vault::{self, VaultNote};
+
+/// Keyword line holding the item id.
+pub const ORG_ID_KEYWORD: &str = "#+ALFRED_ID:";
+/// Font sizes of level one and two headlines; deeper levels are only bold.
+const HEADLINE_FONT_SIZES: [&str; 2] = ["22", "18"];
+const PLANNING_KEYWORDS: [&str; 3] = ["SCHEDULED:", "DEADLINE:", "CLOSED:"];
+/// File links with these extensions are images; other files stay links.
+const IMAGE_EXTENSIONS: [&str; 8] = ["png", "jpg", "jpeg", "gif", "webp", "heic", "tiff", "bmp"];
+
+/// The org file for item `id`: the id keyword, then the note as org text.
+/// `file_for_key` names the attachment of an image key.
+pub fn render_org_note(
+    id: i64,
+    note: &str,
+    file_for_key: impl Fn(&str) -> Option<String>,
+) -> String {
+    format!("{ORG_ID_KEYWORD} {id}\n{}", note_to_org(note, file_for_key))
+}
+
+/// Splits the id keyword off an org file; it is looked for among the keyword
+/// lines at the top, and files without one have no id.
+pub fn parse_org_note(org: &str) -> VaultNote {
+    let org = org.strip_prefix('\u{feff}').unwrap_or(org);
+    let mut offset = 0;
+    for line in org.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if !trimmed.starts_with("#+") {
+            break;
+        }
+        if let Some(value) = strip_prefix_ignore_case(trimmed, ORG_ID_KEYWORD) {
+            return VaultNote {
+                id: value.trim().parse().ok(),
+                body: format!("{}{}", &org[..offset], &org[offset + line.len()..]),
+            };
+        }
+        offset += line.len();
+    }
+    VaultNote {
+        id: None,
+        body: org.to_string(),
+    }
+}
+
+/// The note as org text. Font size tokens have no org form and are dropped,
+/// as are image widths; `* ` bullets become `- ` so they don't read as
+/// headlines.
+pub fn note_to_org(note: &str, file_for_key: impl Fn(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(note.len());
+    for line in note.split_inclusive('\n') {
+        let line = match line.strip_prefix("* ") {
+            Some(rest) => {
+                output.push_str("- ");
+                rest
+            }
+            None => line,
+        };
+        push_line_as_org(&mut output, line, &file_for_key);
+    }
+    output
+}
+
+fn push_line_as_org(
+    output: &mut String,
+    line: &str,
+    file_for_key: &impl Fn(&str) -> Option<String>,
+) {
+    let mut cursor = 0;
+    while let Some(start_rel) = line[cursor..].find(['!', '[']) {
+        let start = cursor + start_rel;
+        output.push_str(&line[cursor..start]);
+        let rest = &line[start..];
+
+        if let Some((key, _, len)) = vault::parse_inline_image(rest) {
+            match file_for_key(key) {
+                Some(file) => {
+                    output.push_str(&format!("[[file:{}/{file}]]", vault::ATTACHMENTS_DIR_NAME))
+                }
+                None => output.push_str(&rest[..len]),
+            }
+            cursor = start + len;
+        } else if let Some(token_len) = vault::style_token_len(rest) {
+            let token = &rest[..token_len];
+            if token == vault::BOLD_TOKEN || token == vault::BOLD_END_TOKEN {
+                output.push('*');
+            }
+            cursor = start + token_len;
+        } else {
+            output.push_str(&rest[..1]);
+            cursor = start + 1;
+        }
+    }
+    output.push_str(&line[cursor..]);
+}
+
+/// The note for org text. `key_for_file` gives the image key of a linked file
+/// (as written in the link); links it can't resolve stay as text.
+pub fn org_to_note(org: &str, mut key_for_file: impl FnMut(&str) -> Option<String>) -> String {
+    let mut lines = Vec::new();
+    let mut in_drawer = false;
+    for line in org.lines() {
+        let trimmed = line.trim();
+        if in_drawer {
+            in_drawer = !trimmed.eq_ignore_ascii_case(":END:");
+            continue;
+        }
+        if is_drawer_start(trimmed) {
+            in_drawer = true;
+            continue;
+        }
+        if trimmed.starts_with("#+")
+            || PLANNING_KEYWORDS
+                .iter()
+                .any(|keyword| trimmed.starts_with(keyword))
+        {
+            continue;
+        }
+
+        let line = match parse_headline(line) {
+            Some(headline) => headline_to_note(&headline, &mut key_for_file),
+            None => inline_to_note(&list_item_to_note(line), &mut key_for_file),
+        };
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+struct Headline<'a> {
+    level: usize,
+    /// `Some(done)` for a `TODO` or `DONE` headline.
+    todo: Option<bool>,
+    text: &'a str,
+    tags: Vec<&'a str>,
+}
+
+fn parse_headline(line: &str) -> Option<Headline<'_>> {
+    let level = line.len() - line.trim_start_matches('*').len();
+    if level == 0 {
+        return None;
+    }
+    let mut text = line[level..].strip_prefix(' ')?.trim();
+
+    let mut todo = None;
+    for (keyword, done) in [("TODO", false), ("DONE", true)] {
+        if let Some(rest) = text.strip_prefix(keyword)
+            && (rest.is_empty() || rest.starts_with(' '))
+        {
+            todo = Some(done);
+            text = rest.trim_start();
+            break;
+        }
+    }
+    if text.starts_with("[#") && text.get(3..4) == Some("]") {
+        text = text[4..].trim_start();
+    }
+
+    let mut tags = Vec::new();
+    let (head, last) = text.rsplit_once(' ').unwrap_or(("", text));
+    if last.len() > 2
+        && last.starts_with(':')
+        && last.ends_with(':')
+        && !last.contains(char::is_whitespace)
+    {
+        tags = last.split(':').filter(|tag| !tag.is_empty()).collect();
+        text = head.trim_end();
+    }
+
+    Some(Headline {
+        level,
+        todo,
+        text,
+        tags,
+    })
+}
+
+fn headline_to_note(
+    headline: &Headline<'_>,
+    key_for_file: &mut impl FnMut(&str) -> Option<String>,
+) -> String {
+    let text = inline_to_note(headline.text, key_for_file);
+    let mut line = match headline.todo {
+        Some(done) => format!("- [{}] {text}", if done { 'x' } else { ' ' }),
+        None if text.is_empty() => String::new(),
+        None => {
+            let bold = format!("{}{text}{}", vault::BOLD_TOKEN, vault::BOLD_END_TOKEN);
+            match HEADLINE_FONT_SIZES.get(headline.level - 1) {
+                Some(size) => format!("[[fs={size}]]{bold}[[/fs]]"),
+                None => bold,
+            }
+        }
+    };
+    for tag in &headline.tags {
+        line.push_str(&format!(" #{tag}"));
+    }
+    line
+}
+
+/// `+ item` bullets become `- item` and partly done `[-]` boxes unchecked.
+fn list_item_to_note(line: &str) -> String {
+    let indent = line.len() - line.trim_start().len();
+    let rest = &line[indent..];
+    let Some(item) = rest.strip_prefix("+ ").or_else(|| rest.strip_prefix("- ")) else {
+        return line.to_string();
+    };
+    let item = match item.strip_prefix("[-]") {
+        Some(rest) => format!("[ ]{rest}"),
+        None => item.to_string(),
+    };
+    format!("{}- {item}", &line[..indent])
+}
+
+fn inline_to_note(text: &str, key_for_file: &mut impl FnMut(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut cursor = 0;
+
+    while let Some(start_rel) = text[cursor..].find(['[', '*']) {
+        let start = cursor + start_rel;
+        output.push_str(&text[cursor..start]);
+        let rest = &text[start..];
+
+        if let Some(link_end) = rest.strip_prefix("[[").and_then(|link| link.find("]]")) {
+            let len = link_end + 4;
+            output.push_str(&link_to_note(&rest[2..len - 2], &rest[..len], key_for_file));
+            cursor = start + len;
+        } else if let Some(bold_len) = bold_span_len(&text[..start], rest) {
+            output.push_str(vault::BOLD_TOKEN);
+            output.push_str(&inline_to_note(&rest[1..bold_len - 1], key_for_file));
+            output.push_str(vault::BOLD_END_TOKEN);
+            cursor = start + bold_len;
+        } else {
+            output.push_str(&rest[..1]);
+            cursor = start + 1;
+        }
+    }
+
+    output.push_str(&text[cursor..]);
+    output
+}
+
+/// `[[file:..]]` image links become inline images and `[[url][description]]`
+/// reads as `description (url)`; wiki-style `[[Note]]` links stay as they are.
+fn link_to_note(
+    link: &str,
+    raw: &str,
+    key_for_file: &mut impl FnMut(&str) -> Option<String>,
+) -> String {
+    let (target, description) = match link.split_once("][") {
+        Some((target, description)) => (target, Some(description)),
+        None => (link, None),
+    };
+    let file = target
+        .strip_prefix("file:")
+        .or_else(|| target.starts_with("./").then_some(target));
+    if let Some(file) = file {
+        let file = file.strip_prefix("./").unwrap_or(file);
+        let is_image = file.rsplit_once('.').is_some_and(|(_, extension)| {
+            IMAGE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+        });
+        if is_image && let Some(key) = key_for_file(file) {
+            return format!("![image]({}{key})", vault::INLINE_IMAGE_URL_PREFIX);
+        }
+    }
+    match description {
+        Some(description) => format!("{description} ({target})"),
+        None => raw.to_string(),
+    }
+}
+
+/// Length of a `*bold*` span at the start of `rest`, following org's rule
+/// that the markers sit next to text and not inside a word.
+fn bold_span_len(before: &str, rest: &str) -> Option<usize> {
+    if before
+        .chars()
+        .next_back()
+        .is_some_and(char::is_alphanumeric)
+    {
+        return None;
+    }
+    let inner = &rest[1..];
+    if inner.starts_with(char::is_whitespace) || inner.starts_with('*') {
+        return None;
+    }
+    inner.match_indices('*').find_map(|(index, _)| {
+        let closes = index > 0
+            && !inner[..index].ends_with(char::is_whitespace)
+            && !inner[index + 1..].starts_with(char::is_alphanumeric);
+        closes.then_some(index + 2)
+    })
+}
+
+/// `:PROPERTIES:`, `:LOGBOOK:` and other drawer openers.
+fn is_drawer_start(line: &str) -> bool {
+    line.len() > 2
+        && line.starts_with(':')
+        && line.ends_with(':')
+        && !line.eq_ignore_ascii_case(":END:")
+        && line[1..line.len() - 1]
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn strip_prefix_ignore_case<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    text.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &text[prefix.len()..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notes_round_trip_through_org() {
+        let note =
+            "[[b]]Plan[[/b]] ideas\n* starred\n![image](alfred://image/img-1-ab?w=320)\n- [ ] task";
+        let org = note_to_org(note, |key| Some(format!("alfred-{key}.png")));
+        assert_eq!(
+            org,
+            "*Plan* ideas\n- starred\n[[file:attachments/alfred-img-1-ab.png]]\n- [ ] task"
+        );
+
+        let restored = org_to_note(&org, |file| {
+            (file == "attachments/alfred-img-1-ab.png").then(|| "img-1-ab".to_string())
+        });
+        assert_eq!(
+            restored,
+            "[[b]]Plan[[/b]] ideas\n- starred\n![image](alfred://image/img-1-ab)\n- [ ] task"
+        );
+    }
+
+    #[test]
+    fn org_to_note_renders_headlines_todo_keywords_and_lists() {
+        let org = "#+TITLE: Notes\n\
+* Project :work:\n\
+:PROPERTIES:\n\
+:ID: 1\n\
+:END:\n\
+** TODO Write *draft*\n\
+   SCHEDULED: <2024-01-01 Mon>\n\
+** DONE [#A] Review\n\
+*** Details\n\
++ one\n\
+  - [-] two\n\
+See [[https://orgmode.org][Org]], [[Other note]] and 2*3*4.";
+        assert_eq!(
+            org_to_note(org, |_| None),
+            "[[fs=22]][[b]]Project[[/b]][[/fs]] #work\n\
+- [ ] Write [[b]]draft[[/b]]\n\
+- [x] Review\n\
+[[b]]Details[[/b]]\n\
+- one\n\
+  - [ ] two\n\
+See Org (https://orgmode.org), [[Other note]] and 2*3*4."
+        );
+    }
+
+    #[test]
+    fn parse_org_note_reads_the_id_keyword() {
+        let rendered = render_org_note(42, "body", |_| None);
+        assert_eq!(
+            parse_org_note(&rendered),
+            VaultNote {
+                id: Some(42),
+                body: "body".into(),
+            }
+        );
+        assert_eq!(
+            parse_org_note("#+TITLE: x\n#+alfred_id: 7\n* Head").body,
+            "#+TITLE: x\n* Head"
+        );
+        assert_eq!(parse_org_note("* Head\n#+ALFRED_ID: 7").id, None);
+    }
+}
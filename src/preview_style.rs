@@ -0,0 +1,192 @@
+//! Style overrides for the editor's rendered Markdown preview, read from a
+//! small CSS-like file:
+//!
+//! ```css
+//! body { font-family: "Iowan Old Style"; font-size: 16px; max-width: 720px; }
+//! h1 { font-size: 30px; }
+//! code { font-family: Menlo; color: #c7254e; background: #f9f2f4; }
+//! ```
+//!
+//! Only the selectors and properties below are understood; anything else is
+//! skipped with a warning, so a typo never blanks the preview.
+
+/// Preview styles set by the file; `None` keeps the built-in look.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PreviewStyle {
+    pub font_family: Option<String>,
+    pub font_size: Option<f64>,
+    pub max_width: Option<f64>,
+    /// `h1` to `h3`; deeper headings use `h3`.
+    pub heading_sizes: [Option<f64>; 3],
+    pub code_font_family: Option<String>,
+    /// 0xRRGGBBAA.
+    pub code_color: Option<u32>,
+    pub code_background: Option<u32>,
+}
+
+const SELECTORS: [&str; 5] = ["body", "h1", "h2", "h3", "code"];
+
+/// Sizes above this are taken for typos.
+const MAX_LENGTH: f64 = 10_000.0;
+
+/// The styles in `source` and a warning for each rule or declaration that
+/// was skipped.
+pub fn parse_preview_style(source: &str) -> (PreviewStyle, Vec<String>) {
+    let mut style = PreviewStyle::default();
+    let mut warnings = Vec::new();
+    let source = strip_comments(source);
+    let mut rest = source.as_str();
+    while let Some(open) = rest.find('{') {
+        let selectors = rest[..open].trim();
+        let Some(close) = rest[open..].find('}') else {
+            warnings.push(format!("'{selectors}' has no closing brace"));
+            return (style, warnings);
+        };
+        let body = &rest[open + 1..open + close];
+        rest = &rest[open + close + 1..];
+        for selector in selectors.split(',').map(str::trim) {
+            if !SELECTORS.contains(&selector) {
+                warnings.push(format!("{selector}: unsupported selector"));
+                continue;
+            }
+            for declaration in body.split(';').map(str::trim).filter(|d| !d.is_empty()) {
+                if let Err(warning) = apply_declaration(&mut style, selector, declaration) {
+                    warnings.push(format!("{selector}: {warning}"));
+                }
+            }
+        }
+    }
+    if !rest.trim().is_empty() {
+        warnings.push(format!("'{}' is outside any rule", rest.trim()));
+    }
+    (style, warnings)
+}
+
+fn apply_declaration(
+    style: &mut PreviewStyle,
+    selector: &str,
+    declaration: &str,
+) -> Result<(), String> {
+    let (property, value) = declaration
+        .split_once(':')
+        .ok_or_else(|| format!("'{declaration}' is not a property: value pair"))?;
+    let property = property.trim().to_ascii_lowercase();
+    let value = value.trim();
+    match (selector, property.as_str()) {
+        ("body", "font-family") => style.font_family = Some(font_family(value)?),
+        ("body", "font-size") => style.font_size = Some(length(value)?),
+        ("body", "max-width") => style.max_width = Some(length(value)?),
+        ("h1" | "h2" | "h3", "font-size") => {
+            let level = usize::from(selector.as_bytes()[1] - b'1');
+            style.heading_sizes[level] = Some(length(value)?);
+        }
+        ("code", "font-family") => style.code_font_family = Some(font_family(value)?),
+        ("code", "color") => style.code_color = Some(color(value)?),
+        ("code", "background" | "background-color") => style.code_background = Some(color(value)?),
+        _ => return Err(format!("unsupported property '{property}'")),
+    }
+    Ok(())
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .find("*/")
+            .map_or("", |end| &rest[start + 2 + end + 2..]);
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+/// The first family of a font list, unquoted.
+fn font_family(value: &str) -> Result<String, String> {
+    let first = value.split(',').next().unwrap_or_default().trim();
+    let family = first.trim_matches(|c| c == '"' || c == '\'').trim();
+    if family.is_empty() {
+        return Err("empty font-family".to_string());
+    }
+    Ok(family.to_string())
+}
+
+/// Points, written bare or with `px` or `pt`.
+fn length(value: &str) -> Result<f64, String> {
+    let number = value
+        .strip_suffix("px")
+        .or_else(|| value.strip_suffix("pt"))
+        .unwrap_or(value)
+        .trim();
+    match number.parse::<f64>() {
+        Ok(length) if length > 0.0 && length <= MAX_LENGTH => Ok(length),
+        _ => Err(format!("'{value}' is not a size in px")),
+    }
+}
+
+/// `#rgb`, `#rrggbb` or `#rrggbbaa` as 0xRRGGBBAA.
+fn color(value: &str) -> Result<u32, String> {
+    let invalid = || format!("'{value}' is not a #rgb, #rrggbb or #rrggbbaa color");
+    let hex = value.strip_prefix('#').ok_or_else(invalid)?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    let expanded = match hex.len() {
+        3 => hex.chars().flat_map(|c| [c, c]).collect::<String>() + "ff",
+        6 => format!("{hex}ff"),
+        8 => hex.to_string(),
+        _ => return Err(invalid()),
+    };
+    u32::from_str_radix(&expanded, 16).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PreviewStyle, parse_preview_style};
+
+    #[test]
+    fn parses_supported_rules() {
+        let (style, warnings) = parse_preview_style(
+            r#"
+            /* Reading layout */
+            body { font-family: "Iowan Old Style", serif; font-size: 16px; max-width: 720px }
+            h1, h2 { font-size: 28pt; }
+            h3 { font-size: 18 }
+            code { font-family: Menlo; color: #c25; background-color: #f9f2f480; }
+            "#,
+        );
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(
+            style,
+            PreviewStyle {
+                font_family: Some("Iowan Old Style".to_string()),
+                font_size: Some(16.0),
+                max_width: Some(720.0),
+                heading_sizes: [Some(28.0), Some(28.0), Some(18.0)],
+                code_font_family: Some("Menlo".to_string()),
+                code_color: Some(0xcc2255ff),
+                code_background: Some(0xf9f2f480),
+            }
+        );
+    }
+
+    #[test]
+    fn skips_what_it_does_not_understand() {
+        let (style, warnings) = parse_preview_style(
+            "p { color: red; font-size: 2px }\nbody { max-width: wide; font-size: 14px; margin: 0 }\ncode { color: red }\nh4 {",
+        );
+        assert_eq!(style.font_size, Some(14.0));
+        assert_eq!(style.max_width, None);
+        assert_eq!(style.code_color, None);
+        assert_eq!(
+            warnings,
+            vec![
+                "p: unsupported selector",
+                "body: 'wide' is not a size in px",
+                "body: unsupported property 'margin'",
+                "code: 'red' is not a #rgb, #rrggbb or #rrggbbaa color",
+                "'h4' has no closing brace",
+            ]
+        );
+    }
+}
@@ -0,0 +1,441 @@
+//! Result providers from other crates. A crate linked into the app implements
+//! [`ResultProvider`] and passes it to [`register_provider`] at startup,
+//! before the first search. Its results are listed under its own prefix, for
+//! example "gh " for GitHub issues. A federated provider is also asked about
+//! plain queries, and its results are listed after the notes.
+//!
+//! A provider only describes results. The app carries out a picked
+//! [`ProviderAction`], and calls [`ResultProvider::perform`] back for
+//! [`ProviderAction::Run`].
+
+use std::io::Read;
+use std::sync::{Arc, RwLock};
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::commands::{AppCommand, SystemCommand};
+use crate::logging::log_error;
+use crate::models::{ResultKind, SearchResult};
+use crate::router::QueryPrefix;
+
+/// SF Symbol for results of providers that don't name their own.
+pub const DEFAULT_PROVIDER_ICON: &str = "puzzlepiece.extension";
+
+/// Room in the negative result ids for each provider's results.
+const RESULT_IDS_PER_PROVIDER: i64 = 1_000;
+const MAX_RESPONSE_BYTES: u64 = 10 * 1024 * 1024;
+
+static PROVIDERS: RwLock<Vec<Arc<dyn ResultProvider>>> = RwLock::new(Vec::new());
+
+pub trait ResultProvider: Send + Sync {
+    /// Stable, unique name such as "github-issues". Picked results are sent
+    /// back to the provider by this name.
+    fn id(&self) -> &str;
+
+    /// Shown in the "? " help list.
+    fn title(&self) -> &str;
+
+    /// Typed text that sends the rest of the query only to this provider,
+    /// including the separating space, e.g. "gh ".
+    fn prefix(&self) -> Option<&str> {
+        None
+    }
+
+    /// SF Symbol name for results that don't set their own.
+    fn icon(&self) -> &str {
+        DEFAULT_PROVIDER_ICON
+    }
+
+    /// Whether plain queries are sent to the provider too.
+    fn federated(&self) -> bool {
+        false
+    }
+
+    /// Up to `limit` results for `query`, best first. Called off the main
+    /// thread for every keystroke, so slow sources should cache.
+    fn results(&self, query: &str, limit: usize) -> Result<Vec<ProviderResult>>;
+
+    /// Runs the `key` of a picked [`ProviderAction::Run`] result.
+    fn perform(&self, key: &str) -> Result<ProviderOutcome> {
+        Err(anyhow!("provider {} action {key:?} not found", self.id()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderResult {
+    pub title: String,
+    pub subtitle: String,
+    /// SF Symbol name; the provider's icon when `None`.
+    pub icon: Option<String>,
+    pub action: ProviderAction,
+}
+
+/// What picking a provider result does.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProviderAction {
+    OpenUrl(String),
+    CopyText(String),
+    /// Hands the key back to [`ResultProvider::perform`].
+    Run(String),
+}
+
+/// What the app does after a provider ran an action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProviderOutcome {
+    Done,
+    OpenUrl(String),
+    CopyText(String),
+    /// Saves a new note and opens it.
+    CaptureNote(CapturedNote),
+}
+
+/// A note made from a provider's item, such as an issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedNote {
+    pub title: String,
+    pub body: String,
+    /// Kept at the top of the note as a link back to the item.
+    pub url: String,
+    pub tags: Vec<String>,
+}
+
+/// Adds `provider` to the launcher. A provider with the same id is replaced;
+/// its prefix must not shadow a built-in one.
+pub fn register_provider(provider: impl ResultProvider + 'static) -> Result<()> {
+    let id = provider.id().trim();
+    if id.is_empty() {
+        return Err(anyhow!("provider id must not be empty"));
+    }
+    if let Some(prefix) = provider.prefix() {
+        if prefix.trim().is_empty() {
+            return Err(anyhow!("provider prefix must not be empty"));
+        }
+        if QueryPrefix::ALL
+            .iter()
+            .any(|builtin| builtin.prefix().trim_end() == prefix.trim_end())
+        {
+            return Err(anyhow!(
+                "provider prefix {prefix:?} must not shadow a built-in prefix"
+            ));
+        }
+    }
+
+    let mut providers = PROVIDERS.write().unwrap_or_else(|err| err.into_inner());
+    let provider: Arc<dyn ResultProvider> = Arc::new(provider);
+    match providers
+        .iter()
+        .position(|existing| existing.id() == provider.id())
+    {
+        Some(index) => providers[index] = provider,
+        None => providers.push(provider),
+    }
+    Ok(())
+}
+
+/// Removes the provider registered as `id`, if any.
+pub fn unregister_provider(id: &str) -> bool {
+    let mut providers = PROVIDERS.write().unwrap_or_else(|err| err.into_inner());
+    let count = providers.len();
+    providers.retain(|provider| provider.id() != id);
+    providers.len() != count
+}
+
+fn registered() -> Vec<Arc<dyn ResultProvider>> {
+    PROVIDERS
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone()
+}
+
+/// The provider whose prefix starts `query`, with the rest of the query.
+pub(crate) fn route_prefixed(query: &str) -> Option<(Arc<dyn ResultProvider>, &str)> {
+    let query = query.trim_start();
+    registered().into_iter().find_map(|provider| {
+        let prefix = provider.prefix()?.to_string();
+        let rest = query
+            .strip_prefix(prefix.as_str())
+            .or_else(|| (query == prefix.trim_end()).then_some(""))?;
+        Some((provider, rest.trim()))
+    })
+}
+
+/// Results of one provider, as launcher rows.
+pub(crate) fn provider_results(
+    provider: &Arc<dyn ResultProvider>,
+    query: &str,
+    limit: usize,
+) -> Vec<SearchResult> {
+    let index = registered()
+        .iter()
+        .position(|registered| registered.id() == provider.id())
+        .unwrap_or(0);
+    // A failing provider must not break the search it was asked to join.
+    let results = provider.results(query, limit).unwrap_or_else(|err| {
+        log_error(format_args!("provider {} failed", provider.id()), err);
+        Vec::new()
+    });
+    results
+        .into_iter()
+        .take(limit.min(RESULT_IDS_PER_PROVIDER as usize - 1))
+        .enumerate()
+        .map(|(position, result)| to_search_result(provider.as_ref(), index, position, result))
+        .collect()
+}
+
+/// Results of every federated provider for a plain query.
+pub(crate) fn federated_results(query: &str, limit: usize) -> Vec<SearchResult> {
+    if query.trim().is_empty() || limit == 0 {
+        return Vec::new();
+    }
+    let mut results = Vec::new();
+    for provider in registered().iter().filter(|provider| provider.federated()) {
+        let remaining = limit - results.len();
+        if remaining == 0 {
+            break;
+        }
+        results.extend(provider_results(provider, query, remaining));
+    }
+    results
+}
+
+/// Help rows for the registered prefixes.
+pub(crate) fn help_results() -> Vec<SearchResult> {
+    registered()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, provider)| {
+            let prefix = provider.prefix()?;
+            Some(SearchResult {
+                id: first_result_id(index),
+                title: format!("{}  {}", prefix.trim_end(), provider.title()),
+                subtitle: format!("\"{prefix}\" searches {}", provider.title()),
+                snippet: None,
+                snippet_source: None,
+                kind: ResultKind::QueryPrefix,
+                icon: Some(provider.icon().to_string()),
+                command_key: Some(prefix.to_string()),
+                pinned: false,
+                thumbnail_key: None,
+                title_match_ranges: Vec::new(),
+                checklist_progress: None,
+            })
+        })
+        .collect()
+}
+
+/// Carries out a picked provider result; `command_key` is the one it was
+/// listed with.
+pub(crate) fn perform(command_key: &str) -> Result<ProviderOutcome> {
+    let key: ActionKey = serde_json::from_str(command_key)
+        .map_err(|err| anyhow!("provider action must not be malformed: {err}"))?;
+    match key.action {
+        ProviderAction::OpenUrl(url) => Ok(ProviderOutcome::OpenUrl(url)),
+        ProviderAction::CopyText(text) => Ok(ProviderOutcome::CopyText(text)),
+        ProviderAction::Run(action) => {
+            let provider = registered()
+                .into_iter()
+                .find(|provider| provider.id() == key.provider)
+                .ok_or_else(|| anyhow!("provider {} not found", key.provider))?;
+            provider.perform(&action)
+        }
+    }
+}
+
+/// The JSON body of a provider's web request; failures name `service`.
+pub(crate) fn read_json_response(
+    response: std::result::Result<ureq::Response, ureq::Error>,
+    service: &str,
+) -> Result<Value> {
+    let response = match response {
+        Ok(response) => response,
+        Err(ureq::Error::Status(code @ (401 | 403), _)) => {
+            return Err(anyhow!("{service} rejected the token (HTTP {code})"));
+        }
+        Err(ureq::Error::Status(code, _)) => {
+            return Err(anyhow!("{service} returned HTTP {code}"));
+        }
+        Err(ureq::Error::Transport(err)) => {
+            return Err(anyhow!("{service} is unreachable: {err}"));
+        }
+    };
+    let mut body = String::new();
+    response
+        .into_reader()
+        .take(MAX_RESPONSE_BYTES)
+        .read_to_string(&mut body)
+        .context("failed to read the response")?;
+    serde_json::from_str(&body).context("failed to parse the response")
+}
+
+#[derive(Serialize, Deserialize)]
+struct ActionKey {
+    provider: String,
+    action: ProviderAction,
+}
+
+/// Provider ids are numbered after every built-in result, one block each;
+/// the first id of a block is kept for the provider's help row.
+fn first_result_id(index: usize) -> i64 {
+    let builtin =
+        (SystemCommand::ALL.len() + AppCommand::ALL.len() + QueryPrefix::ALL.len() + 2) as i64;
+    -builtin - 1 - index as i64 * RESULT_IDS_PER_PROVIDER
+}
+
+fn to_search_result(
+    provider: &dyn ResultProvider,
+    index: usize,
+    position: usize,
+    result: ProviderResult,
+) -> SearchResult {
+    let command_key = serde_json::to_string(&ActionKey {
+        provider: provider.id().to_string(),
+        action: result.action,
+    })
+    .unwrap_or_default();
+    SearchResult {
+        id: first_result_id(index) - 1 - position as i64,
+        title: result.title,
+        subtitle: result.subtitle,
+        snippet: None,
+        snippet_source: None,
+        kind: ResultKind::Provider,
+        icon: Some(result.icon.unwrap_or_else(|| provider.icon().to_string())),
+        command_key: Some(command_key),
+        pinned: false,
+        thumbnail_key: None,
+        title_match_ranges: Vec::new(),
+        checklist_progress: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::{
+        ProviderAction, ProviderOutcome, ProviderResult, ResultProvider, federated_results,
+        help_results, perform, provider_results, register_provider, route_prefixed,
+        unregister_provider,
+    };
+    use crate::models::ResultKind;
+
+    struct Issues {
+        id: &'static str,
+        prefix: Option<&'static str>,
+        federated: bool,
+    }
+
+    impl ResultProvider for Issues {
+        fn id(&self) -> &str {
+            self.id
+        }
+
+        fn title(&self) -> &str {
+            "Issues"
+        }
+
+        fn prefix(&self) -> Option<&str> {
+            self.prefix
+        }
+
+        fn federated(&self) -> bool {
+            self.federated
+        }
+
+        fn results(&self, query: &str, limit: usize) -> Result<Vec<ProviderResult>> {
+            // Stays out of other tests' plain searches.
+            if !query.starts_with("zzissue") {
+                return Ok(Vec::new());
+            }
+            Ok((1..=limit)
+                .map(|number| ProviderResult {
+                    title: format!("#{number} {query}"),
+                    subtitle: "open".to_string(),
+                    icon: (number == 1).then(|| "ladybug".to_string()),
+                    action: if number == 1 {
+                        ProviderAction::Run(format!("close {number}"))
+                    } else {
+                        ProviderAction::OpenUrl(format!("https://example.com/{number}"))
+                    },
+                })
+                .collect())
+        }
+
+        fn perform(&self, key: &str) -> Result<ProviderOutcome> {
+            Ok(ProviderOutcome::CopyText(format!("{}: {key}", self.id)))
+        }
+    }
+
+    #[test]
+    fn registered_providers_answer_their_prefix_and_plain_queries() {
+        register_provider(Issues {
+            id: "test-issues",
+            prefix: Some("iss "),
+            federated: false,
+        })
+        .unwrap();
+        register_provider(Issues {
+            id: "test-federated",
+            prefix: None,
+            federated: true,
+        })
+        .unwrap();
+
+        let (provider, rest) = route_prefixed("  iss zzissue crash").unwrap();
+        assert_eq!(provider.id(), "test-issues");
+        assert_eq!(rest, "zzissue crash");
+        assert_eq!(route_prefixed("iss").unwrap().1, "");
+        assert!(route_prefixed("issue tracker").is_none());
+        assert!(
+            help_results()
+                .iter()
+                .any(|row| row.command_key.as_deref() == Some("iss "))
+        );
+
+        let rows = provider_results(&provider, rest, 3);
+        assert_eq!(rows.len(), 3);
+        assert!(rows.iter().all(|row| row.kind == ResultKind::Provider));
+        assert_eq!(rows[0].icon.as_deref(), Some("ladybug"));
+        assert_eq!(rows[1].icon.as_deref(), Some(super::DEFAULT_PROVIDER_ICON));
+        assert_eq!(
+            perform(rows[0].command_key.as_deref().unwrap()).unwrap(),
+            ProviderOutcome::CopyText("test-issues: close 1".to_string())
+        );
+        assert_eq!(
+            perform(rows[1].command_key.as_deref().unwrap()).unwrap(),
+            ProviderOutcome::OpenUrl("https://example.com/2".to_string())
+        );
+
+        let federated = federated_results("zzissue", 2);
+        assert_eq!(federated.len(), 2);
+        let ids: Vec<i64> = rows.iter().chain(&federated).map(|row| row.id).collect();
+        assert!(ids.iter().all(|id| *id < 0));
+        assert_eq!(
+            ids.iter().collect::<std::collections::HashSet<_>>().len(),
+            ids.len()
+        );
+
+        assert!(unregister_provider("test-issues"));
+        assert!(unregister_provider("test-federated"));
+        assert!(route_prefixed("iss zzissue").is_none());
+        assert!(perform(rows[0].command_key.as_deref().unwrap()).is_err());
+    }
+
+    #[test]
+    fn providers_must_not_shadow_builtin_prefixes() {
+        let shadowing = Issues {
+            id: "test-shadowing",
+            prefix: Some("t "),
+            federated: false,
+        };
+        assert!(register_provider(shadowing).is_err());
+        let unnamed = Issues {
+            id: " ",
+            prefix: None,
+            federated: false,
+        };
+        assert!(register_provider(unnamed).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use chrono::NaiveDate;
+
+use crate::tags;
+
+/// A `key:value` modifier in a search query that narrows the matched items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryFilter {
+    /// `tag:work`; also matches nested tags like `#work/q3`.
+    Tag(String),
+    /// `has:image`
+    HasImage,
+    /// `has:checklist`
+    HasChecklist,
+    /// `is:pinned`
+    Pinned,
+    /// `created:>2024-01-01`, compared by local date.
+    Created(DateBound),
+    /// `updated:<=2024-06-30`
+    Updated(DateBound),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateComparison {
+    Before,
+    OnOrBefore,
+    On,
+    OnOrAfter,
+    After,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateBound {
+    pub comparison: DateComparison,
+    pub date: NaiveDate,
+}
+
+impl DateBound {
+    pub fn matches(self, date: NaiveDate) -> bool {
+        match self.comparison {
+            DateComparison::Before => date < self.date,
+            DateComparison::OnOrBefore => date <= self.date,
+            DateComparison::On => date == self.date,
+            DateComparison::OnOrAfter => date >= self.date,
+            DateComparison::After => date > self.date,
+        }
+    }
+}
+
+/// A query split into its filter modifiers and the text left for the
+/// full-text search.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ParsedQuery {
+    pub text: String,
+    pub filters: Vec<QueryFilter>,
+}
+
+/// Pulls the filter modifiers out of `query`. Words that only look like one
+/// (`note:`, `created:soon`) stay part of the text.
+pub fn parse_query_filters(query: &str) -> ParsedQuery {
+    let mut words = Vec::new();
+    let mut filters = Vec::new();
+    for word in query.split_whitespace() {
+        match parse_filter(word) {
+            Some(filter) => filters.push(filter),
+            None => words.push(word),
+        }
+    }
+    ParsedQuery {
+        text: words.join(" "),
+        filters,
+    }
+}
+
+fn parse_filter(word: &str) -> Option<QueryFilter> {
+    let (key, value) = word.split_once(':')?;
+    match (key.to_lowercase().as_str(), value.to_lowercase().as_str()) {
+        ("tag", _) => {
+            let tag = tags::normalize_tag(value);
+            (!tag.is_empty()).then_some(QueryFilter::Tag(tag))
+        }
+        ("has", "image" | "images") => Some(QueryFilter::HasImage),
+        ("has", "checklist" | "todo") => Some(QueryFilter::HasChecklist),
+        ("is", "pinned") => Some(QueryFilter::Pinned),
+        ("created", _) => parse_date_bound(value).map(QueryFilter::Created),
+        ("updated", _) => parse_date_bound(value).map(QueryFilter::Updated),
+        _ => None,
+    }
+}
+
+fn parse_date_bound(value: &str) -> Option<DateBound> {
+    let (comparison, date) = [
+        (">=", DateComparison::OnOrAfter),
+        ("<=", DateComparison::OnOrBefore),
+        (">", DateComparison::After),
+        ("<", DateComparison::Before),
+        ("=", DateComparison::On),
+    ]
+    .into_iter()
+    .find_map(|(operator, comparison)| value.strip_prefix(operator).map(|date| (comparison, date)))
+    .unwrap_or((DateComparison::On, value));
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    Some(DateBound { comparison, date })
+}
+
+/// Whether an item tagged `item_tags` passes a `tag:` filter for `tag`.
+pub fn tag_matches(item_tags: &[String], tag: &str) -> bool {
+    item_tags.iter().any(|item_tag| {
+        item_tag == tag
+            || item_tag
+                .strip_prefix(tag)
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn parse_query_filters_strips_modifiers_and_keeps_the_rest() {
+        let parsed = parse_query_filters(
+            "budget tag:#Work has:image created:>2024-01-01 note: created:soon",
+        );
+        assert_eq!(parsed.text, "budget note: created:soon");
+        assert_eq!(
+            parsed.filters,
+            vec![
+                QueryFilter::Tag("work".into()),
+                QueryFilter::HasImage,
+                QueryFilter::Created(DateBound {
+                    comparison: DateComparison::After,
+                    date: date(2024, 1, 1),
+                }),
+            ]
+        );
+
+        let parsed = parse_query_filters("updated:<=2024-06-30 is:pinned");
+        assert!(parsed.text.is_empty());
+        assert_eq!(
+            parsed.filters,
+            vec![
+                QueryFilter::Updated(DateBound {
+                    comparison: DateComparison::OnOrBefore,
+                    date: date(2024, 6, 30),
+                }),
+                QueryFilter::Pinned,
+            ]
+        );
+    }
+
+    #[test]
+    fn date_bounds_and_tags_match_as_documented() {
+        let on = DateBound {
+            comparison: DateComparison::On,
+            date: date(2024, 3, 1),
+        };
+        assert!(on.matches(date(2024, 3, 1)));
+        assert!(!on.matches(date(2024, 3, 2)));
+
+        let tags = vec!["work/q3".to_string(), "home".to_string()];
+        assert!(tag_matches(&tags, "work"));
+        assert!(tag_matches(&tags, "home"));
+        assert!(!tag_matches(&tags, "wo"));
+    }
+}
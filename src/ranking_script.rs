@@ -0,0 +1,125 @@
+//! User-defined ordering of search results. A small Rhai script gets each
+//! result's features as variables and evaluates to its final score; results
+//! are shown highest score first, ties keeping the built-in order.
+//!
+//! Variables: `tier` (0 full-text, 1 substring, 2 fuzzy match), `score` (the
+//! tier's own relevance score; 0 for substring matches), `position` (0-based
+//! place in the built-in order), `age_days` (days since the item was last
+//! saved), `tag_matches` (query words that are tags of the item) and `pinned`.
+
+use anyhow::{Result, anyhow};
+use rhai::{AST, Dynamic, Engine, Scope};
+
+/// Keeps a runaway script from stalling the launcher.
+const MAX_SCRIPT_OPERATIONS: u64 = 20_000;
+const MAX_SCRIPT_EXPR_DEPTH: usize = 32;
+const MAX_SCRIPT_STRING_SIZE: usize = 1_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankingFeatures {
+    pub tier: i64,
+    pub score: f64,
+    pub position: i64,
+    pub age_days: f64,
+    pub tag_matches: i64,
+    pub pinned: bool,
+}
+
+pub struct RankingScript {
+    engine: Engine,
+    ast: AST,
+}
+
+impl RankingScript {
+    pub fn compile(source: &str) -> Result<Self> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_SCRIPT_OPERATIONS);
+        engine.set_max_expr_depths(MAX_SCRIPT_EXPR_DEPTH, MAX_SCRIPT_EXPR_DEPTH);
+        engine.set_max_string_size(MAX_SCRIPT_STRING_SIZE);
+        engine.on_print(|_| {});
+        engine.on_debug(|_, _, _| {});
+        let ast = engine
+            .compile(source)
+            .map_err(|err| anyhow!("invalid ranking script: {err}"))?;
+        Ok(Self { engine, ast })
+    }
+
+    pub fn score(&self, features: &RankingFeatures) -> Result<f64> {
+        let mut scope = Scope::new();
+        scope.push_constant("tier", features.tier);
+        scope.push_constant("score", features.score);
+        scope.push_constant("position", features.position);
+        scope.push_constant("age_days", features.age_days);
+        scope.push_constant("tag_matches", features.tag_matches);
+        scope.push_constant("pinned", features.pinned);
+        let value: Dynamic = self
+            .engine
+            .eval_ast_with_scope(&mut scope, &self.ast)
+            .map_err(|err| anyhow!("ranking script failed: {err}"))?;
+        value
+            .as_float()
+            .or_else(|_| value.as_int().map(|int| int as f64))
+            .map_err(|kind| anyhow!("ranking script returned {kind}, not a number"))
+    }
+
+    /// `rows` by descending script score. Ties keep their order, and so do
+    /// all rows if the script fails for any of them.
+    pub fn rank<T>(&self, rows: Vec<(T, RankingFeatures)>) -> Result<Vec<T>> {
+        let mut scored = rows
+            .into_iter()
+            .map(|(row, features)| Ok((self.score(&features)?, row)))
+            .collect::<Result<Vec<_>>>()?;
+        scored.sort_by(|left, right| right.0.total_cmp(&left.0));
+        Ok(scored.into_iter().map(|(_, row)| row).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RankingFeatures, RankingScript};
+
+    fn features(tier: i64, age_days: f64, tag_matches: i64) -> RankingFeatures {
+        RankingFeatures {
+            tier,
+            score: 1.5,
+            position: 0,
+            age_days,
+            tag_matches,
+            pinned: false,
+        }
+    }
+
+    #[test]
+    fn rank_orders_rows_by_script_score() {
+        let script = RankingScript::compile(
+            "let fresh = if age_days < 7.0 { 10 } else { 0 };\nfresh + tag_matches * 5 - tier",
+        )
+        .unwrap();
+        let ranked = script
+            .rank(vec![
+                ("old", features(0, 30.0, 0)),
+                ("tagged", features(1, 30.0, 2)),
+                ("fresh", features(2, 1.0, 0)),
+                ("also old", features(0, 40.0, 0)),
+            ])
+            .unwrap();
+        assert_eq!(ranked, vec!["tagged", "fresh", "old", "also old"]);
+    }
+
+    #[test]
+    fn bad_scripts_are_reported() {
+        assert!(
+            RankingScript::compile("score +")
+                .err()
+                .unwrap()
+                .to_string()
+                .starts_with("invalid ranking script")
+        );
+
+        let text = RankingScript::compile("\"high\"").unwrap();
+        assert!(text.score(&features(0, 0.0, 0)).is_err());
+
+        let endless = RankingScript::compile("loop { }").unwrap();
+        assert!(endless.rank(vec![((), features(0, 0.0, 0))]).is_err());
+    }
+}
@@ -0,0 +1,556 @@
+//! The `read <url>` command: saves the article of a web page as a note tagged
+//! `#readlater`.
+//!
+//! The article is the page's largest `article` element, else its `main`
+//! element or body, without navigation, headers, footers, forms and blocks
+//! whose class or id marks them as comments, sharing or promotion. Pages that
+//! can't be reached are queued and fetched again later.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::Read;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::html_note::{NoteWriter, Token, attribute, decode_entities, download_image, tokenize};
+use crate::logging::log_error;
+use crate::models::{ImageLimits, ImportedNote, NoteImage};
+use crate::tags;
+
+pub const READ_LATER_TAG: &str = "readlater";
+/// Failed fetches kept for retrying; more are refused.
+pub const MAX_QUEUED_ARTICLES: usize = 100;
+const PAGE_FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+const MAX_PAGE_BYTES: usize = 5 * 1024 * 1024;
+/// Longer articles are cut at a line break before this many bytes.
+const MAX_ARTICLE_BYTES: usize = 200_000;
+/// An `article` element with less text is taken for a teaser card.
+const MIN_ARTICLE_TEXT_LEN: usize = 500;
+/// Left out of the article with everything inside them.
+const SKIPPED_ELEMENTS: [&str; 11] = [
+    "nav", "header", "footer", "aside", "form", "button", "noscript", "svg", "iframe", "dialog",
+    "template",
+];
+/// Containers dropped when their class or id contains one of `BOILERPLATE_HINTS`.
+const BOILERPLATE_CONTAINERS: [&str; 6] = ["div", "section", "ul", "ol", "figure", "table"];
+const BOILERPLATE_HINTS: [&str; 14] = [
+    "comment",
+    "share",
+    "social",
+    "sidebar",
+    "related",
+    "promo",
+    "advert",
+    "newsletter",
+    "subscribe",
+    "cookie",
+    "breadcrumb",
+    "popup",
+    "modal",
+    "banner",
+];
+const VOID_ELEMENTS: [&str; 8] = ["img", "br", "hr", "meta", "link", "input", "source", "wbr"];
+
+/// A page's title and article as note text.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Article {
+    pub title: String,
+    pub note: String,
+}
+
+/// A page that could not be reached, waiting to be fetched again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedArticle {
+    pub url: String,
+    pub queued_at_unix_seconds: u64,
+    pub attempts: u32,
+    pub last_error: String,
+}
+
+/// Why a page could not be saved; only unreachable pages are queued.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchError {
+    /// Offline, timed out or a temporary server error.
+    Unreachable(String),
+    /// Fetching again won't help, e.g. a 404 or a PDF.
+    Rejected(String),
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Unreachable(reason) | FetchError::Rejected(reason) => f.write_str(reason),
+        }
+    }
+}
+
+/// An article ready to be added as a note, plus the images left out because
+/// they were unreachable or over the limits.
+#[derive(Debug, Clone)]
+pub struct SavedArticle {
+    pub note: ImportedNote,
+    pub skipped_images: usize,
+}
+
+/// `input` as an http(s) URL; a missing scheme means https. `None` for text
+/// that isn't a web address.
+pub fn normalize_url(input: &str) -> Option<String> {
+    let input = input.trim();
+    if input.is_empty() || input.contains(char::is_whitespace) {
+        return None;
+    }
+    let url = if input.contains("://") {
+        input.to_string()
+    } else {
+        format!("https://{input}")
+    };
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host = host.rsplit('@').next().unwrap_or(host);
+    let host = host.split(':').next().unwrap_or(host);
+    let valid = host == "localhost"
+        || (host.contains('.') && !host.starts_with('.') && !host.ends_with('.'));
+    valid.then_some(url)
+}
+
+/// Fetches `url` and converts its article, downloading images within `limits`.
+pub fn fetch_article(url: &str, limits: ImageLimits) -> Result<SavedArticle, FetchError> {
+    let html = fetch_page(url)?;
+    let mut images = Vec::new();
+    let mut skipped_images = 0;
+    let article = parse_article(&html, url, |src| {
+        if images.len() >= limits.max_images_per_note {
+            skipped_images += 1;
+            return None;
+        }
+        match download_image(src, limits.max_image_bytes) {
+            Ok(bytes) => {
+                let key = image_key(url, images.len());
+                images.push(NoteImage {
+                    image_key: key.clone(),
+                    bytes,
+                });
+                Some(key)
+            }
+            Err(err) => {
+                log_error(format_args!("skipped article image {src}"), err);
+                skipped_images += 1;
+                None
+            }
+        }
+    });
+
+    let body = truncate_article(&article.note);
+    let note = tags::add_tag(&format!("Source: {url}\n\n{body}"), READ_LATER_TAG);
+    let title = if article.title.is_empty() {
+        url.to_string()
+    } else {
+        article.title
+    };
+    Ok(SavedArticle {
+        note: ImportedNote {
+            title,
+            note,
+            images,
+        },
+        skipped_images,
+    })
+}
+
+/// Fetches `url` and returns a Markdown link to it named after the page
+/// title, or `url` alone when the page has no title.
+pub fn fetch_link_markdown(url: &str) -> Result<String, FetchError> {
+    let html = fetch_page(url)?;
+    Ok(markdown_link(&page_title(&tokenize(&html)), url))
+}
+
+/// `[title](url)`, with brackets in the title escaped; `url` alone for an
+/// empty title.
+pub fn markdown_link(title: &str, url: &str) -> String {
+    let title = title.trim();
+    if title.is_empty() {
+        return url.to_string();
+    }
+    let escaped = title.replace('[', "\\[").replace(']', "\\]");
+    format!("[{escaped}]({url})")
+}
+
+fn fetch_page(url: &str) -> Result<String, FetchError> {
+    let response = match ureq::get(url)
+        .timeout(PAGE_FETCH_TIMEOUT)
+        .set("Accept", "text/html,application/xhtml+xml")
+        .call()
+    {
+        Ok(response) => response,
+        Err(ureq::Error::Status(code, _)) if code == 429 || code >= 500 => {
+            return Err(FetchError::Unreachable(format!(
+                "{url} returned HTTP {code}"
+            )));
+        }
+        Err(ureq::Error::Status(code, _)) => {
+            return Err(FetchError::Rejected(format!("{url} returned HTTP {code}")));
+        }
+        Err(ureq::Error::Transport(err)) => {
+            return Err(FetchError::Unreachable(format!(
+                "{url} is unreachable: {err}"
+            )));
+        }
+    };
+
+    let content_type = response.content_type().to_ascii_lowercase();
+    if !content_type.contains("html") {
+        return Err(FetchError::Rejected(format!(
+            "{url} is not a web page ({content_type})"
+        )));
+    }
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_PAGE_BYTES as u64 + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|err| FetchError::Unreachable(format!("failed to download {url}: {err}")))?;
+    if bytes.len() > MAX_PAGE_BYTES {
+        return Err(FetchError::Rejected(format!(
+            "{url} is larger than {} MB",
+            MAX_PAGE_BYTES / (1024 * 1024)
+        )));
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn image_key(url: &str, index: usize) -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let mut hasher = DefaultHasher::new();
+    (url, index, timestamp).hash(&mut hasher);
+    format!("img-{timestamp}-{:08x}", hasher.finish() as u32)
+}
+
+/// Converts the article of a page fetched from `page_url`. `image_key` gets
+/// each image's absolute URL, stores it and returns its key; images it
+/// declines are left out.
+pub fn parse_article(
+    html: &str,
+    page_url: &str,
+    mut image_key: impl FnMut(&str) -> Option<String>,
+) -> Article {
+    let tokens = tokenize(html);
+    let title = page_title(&tokens);
+    let (start, end) = article_range(&tokens);
+
+    let mut writer = NoteWriter::default();
+    let mut index = start;
+    while index < end {
+        match &tokens[index] {
+            Token::Open { name, attrs, .. } => {
+                if is_boilerplate(name, attrs) {
+                    index = closing_index(&tokens, index).min(end) + 1;
+                    continue;
+                }
+                match name.as_str() {
+                    // Lazy-loaded images keep the real source in a data attribute.
+                    "img" => {
+                        let src = ["data-src", "data-original", "data-lazy-src", "src"]
+                            .iter()
+                            .filter_map(|name| attribute(attrs, name))
+                            .find(|src| !src.is_empty() && !src.starts_with("data:"))
+                            .and_then(|src| resolve_url(page_url, &src));
+                        if let Some(src) = src {
+                            writer.open("img", &quoted_attribute("src", &src), &mut image_key);
+                        }
+                    }
+                    "a" => {
+                        let href = attribute(attrs, "href")
+                            .and_then(|href| resolve_url(page_url, &href))
+                            .map(|href| quoted_attribute("href", &href))
+                            .unwrap_or_default();
+                        writer.open("a", &href, &mut image_key);
+                    }
+                    _ => writer.open(name, attrs, &mut image_key),
+                }
+            }
+            Token::Close(name) => writer.close(name),
+            Token::Text(text) => writer.push_text(&decode_entities(text)),
+        }
+        index += 1;
+    }
+
+    Article {
+        title,
+        note: writer.finish(),
+    }
+}
+
+/// `og:title` when the page has one, else its `title`.
+fn page_title(tokens: &[Token]) -> String {
+    let mut title = None;
+    let mut in_title = false;
+    for token in tokens {
+        match token {
+            Token::Open { name, attrs, .. } if name == "meta" => {
+                if attribute(attrs, "property").as_deref() == Some("og:title")
+                    && let Some(content) = attribute(attrs, "content")
+                {
+                    return content.split_whitespace().collect::<Vec<_>>().join(" ");
+                }
+            }
+            Token::Open { name, .. } if name == "title" && title.is_none() => {
+                in_title = true;
+                title = Some(String::new());
+            }
+            Token::Close(name) if name == "title" => in_title = false,
+            Token::Close(name) if name == "head" => break,
+            Token::Text(text) if in_title => {
+                if let Some(title) = title.as_mut() {
+                    title.push_str(&decode_entities(text));
+                }
+            }
+            _ => {}
+        }
+    }
+    title
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Tokens inside the element holding the article.
+fn article_range(tokens: &[Token]) -> (usize, usize) {
+    let longest = |matches: &dyn Fn(&str, &str) -> bool| {
+        let mut best: Option<((usize, usize), usize)> = None;
+        let mut index = 0;
+        while index < tokens.len() {
+            let Token::Open { name, attrs, .. } = &tokens[index] else {
+                index += 1;
+                continue;
+            };
+            if !matches(name, attrs) {
+                index += 1;
+                continue;
+            }
+            let close = closing_index(tokens, index);
+            let range = (index + 1, close);
+            let len = text_len(&tokens[range.0..range.1]);
+            if best.is_none_or(|(_, best_len)| len > best_len) {
+                best = Some((range, len));
+            }
+            index = close + 1;
+        }
+        best
+    };
+
+    if let Some((range, len)) = longest(&|name, _| name == "article")
+        && len >= MIN_ARTICLE_TEXT_LEN
+    {
+        return range;
+    }
+    let main = |name: &str, attrs: &str| {
+        name == "main" || attribute(attrs, "role").as_deref() == Some("main")
+    };
+    for matches in [&main as &dyn Fn(&str, &str) -> bool, &|name, _| {
+        name == "body"
+    }] {
+        if let Some((range, _)) = longest(matches) {
+            return range;
+        }
+    }
+    (0, tokens.len())
+}
+
+/// Index of the tag closing the element opened at `start`, or of the last
+/// token when it is never closed. Void and self-closing elements close
+/// themselves.
+fn closing_index(tokens: &[Token], start: usize) -> usize {
+    let Token::Open {
+        name, self_closing, ..
+    } = &tokens[start]
+    else {
+        return start;
+    };
+    if *self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+        return start;
+    }
+    let mut depth = 0usize;
+    for (index, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::Open {
+                name: open,
+                self_closing: false,
+                ..
+            } if open == name => depth += 1,
+            Token::Close(close) if close == name => {
+                depth -= 1;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.len().saturating_sub(1).max(start)
+}
+
+fn text_len(tokens: &[Token]) -> usize {
+    tokens
+        .iter()
+        .map(|token| match token {
+            Token::Text(text) => text.trim().len(),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn is_boilerplate(name: &str, attrs: &str) -> bool {
+    if SKIPPED_ELEMENTS.contains(&name)
+        || attribute(attrs, "aria-hidden").as_deref() == Some("true")
+    {
+        return true;
+    }
+    BOILERPLATE_CONTAINERS.contains(&name)
+        && ["class", "id"]
+            .iter()
+            .filter_map(|attr| attribute(attrs, attr))
+            .any(|value| {
+                let value = value.to_lowercase();
+                BOILERPLATE_HINTS.iter().any(|hint| value.contains(hint))
+            })
+}
+
+fn quoted_attribute(name: &str, value: &str) -> String {
+    format!(
+        "{name}=\"{}\"",
+        value.replace('&', "&amp;").replace('"', "&quot;")
+    )
+}
+
+/// `href` made absolute against the page at `base`; `None` for fragments
+/// and non-web schemes.
+pub fn resolve_url(base: &str, href: &str) -> Option<String> {
+    let href = href.trim();
+    if href.starts_with("https://") || href.starts_with("http://") {
+        return Some(href.to_string());
+    }
+    if href.is_empty()
+        || href.starts_with('#')
+        || href
+            .split(['/', '?', '#'])
+            .next()
+            .is_some_and(|head| head.contains(':'))
+    {
+        return None;
+    }
+    let (scheme, rest) = base.split_once("://")?;
+    if let Some(network_path) = href.strip_prefix("//") {
+        return Some(format!("{scheme}://{network_path}"));
+    }
+    let host_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let origin = format!("{scheme}://{}", &rest[..host_end]);
+    if href.starts_with('/') {
+        return Some(format!("{origin}{href}"));
+    }
+    let path = rest[host_end..].split(['?', '#']).next().unwrap_or("");
+    let dir = path.rfind('/').map_or("/", |end| &path[..=end]);
+    Some(format!("{origin}{dir}{href}"))
+}
+
+fn truncate_article(note: &str) -> String {
+    if note.len() <= MAX_ARTICLE_BYTES {
+        return note.to_string();
+    }
+    let mut end = MAX_ARTICLE_BYTES;
+    while !note.is_char_boundary(end) {
+        end -= 1;
+    }
+    let end = note[..end].rfind('\n').unwrap_or(end);
+    format!("{}\n\n… (article truncated)", note[..end].trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_link_escapes_brackets_and_falls_back_to_the_url() {
+        assert_eq!(
+            markdown_link(" Release [beta] notes ", "https://example.com/a"),
+            "[Release \\[beta\\] notes](https://example.com/a)"
+        );
+        assert_eq!(
+            markdown_link("  ", "https://example.com"),
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    fn parse_article_keeps_the_article_and_drops_page_chrome() {
+        let paragraph = "Lorem ipsum dolor sit amet. ".repeat(20);
+        let html = format!(
+            r#"<html><head><title>Fallback</title>
+<meta property="og:title" content="The  Story"></head>
+<body><nav><a href="/">Home</a></nav>
+<article class="teaser">Short teaser</article>
+<article><header><h1>The Story</h1></header>
+<h2>Intro</h2><p>{paragraph}</p>
+<img data-src="/img/a.png" src="data:image/gif;base64,R0lGOD">
+<p>See <a href="more.html">more</a>.</p>
+<div class="share-buttons">Share this</div>
+<footer>Footer</footer></article>
+<aside>Related</aside></body></html>"#
+        );
+
+        let mut sources = Vec::new();
+        let article = parse_article(&html, "https://example.com/posts/story?id=1", |src| {
+            sources.push(src.to_string());
+            Some("img-1".to_string())
+        });
+
+        assert_eq!(article.title, "The Story");
+        assert_eq!(sources, vec!["https://example.com/img/a.png"]);
+        assert_eq!(
+            article.note,
+            format!(
+                "[[fs=20]][[b]]Intro[[/b]][[/fs]]\n\n{}\n![image](alfred://image/img-1?w=360)\nSee more (https://example.com/posts/more.html).",
+                paragraph.trim_end()
+            )
+        );
+    }
+
+    #[test]
+    fn urls_are_normalized_and_resolved_against_the_page() {
+        assert_eq!(
+            normalize_url(" example.com/a ").as_deref(),
+            Some("https://example.com/a")
+        );
+        assert_eq!(
+            normalize_url("http://localhost:8080").as_deref(),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(normalize_url("not a url"), None);
+        assert_eq!(normalize_url("ftp://example.com"), None);
+        assert_eq!(normalize_url("notes"), None);
+
+        let base = "https://example.com/blog/post.html?x=1";
+        assert_eq!(
+            resolve_url(base, "img.png").as_deref(),
+            Some("https://example.com/blog/img.png")
+        );
+        assert_eq!(
+            resolve_url(base, "/img.png").as_deref(),
+            Some("https://example.com/img.png")
+        );
+        assert_eq!(
+            resolve_url(base, "//cdn.example.com/a.png").as_deref(),
+            Some("https://cdn.example.com/a.png")
+        );
+        assert_eq!(
+            resolve_url("https://example.com", "a").as_deref(),
+            Some("https://example.com/a")
+        );
+        assert_eq!(resolve_url(base, "#top"), None);
+        assert_eq!(resolve_url(base, "mailto:a@example.com"), None);
+    }
+}
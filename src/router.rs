@@ -0,0 +1,413 @@
+use crate::commands::{AppCommand, SystemCommand};
+use crate::models::{ResultKind, RunningTimer, SearchResult};
+use crate::read_later;
+use crate::time_tracking;
+
+/// Query prefixes that send the rest of the query to a specific provider
+/// instead of the regular note search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryPrefix {
+    Commands,
+    Tag,
+    ReadLater,
+    Track,
+    Help,
+}
+
+impl QueryPrefix {
+    pub const ALL: [QueryPrefix; 5] = [
+        QueryPrefix::Commands,
+        QueryPrefix::Tag,
+        QueryPrefix::ReadLater,
+        QueryPrefix::Track,
+        QueryPrefix::Help,
+    ];
+
+    /// Typed text that selects the provider, including the separating space.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            QueryPrefix::Commands => "> ",
+            QueryPrefix::Tag => "t ",
+            QueryPrefix::ReadLater => "read ",
+            QueryPrefix::Track => "track ",
+            QueryPrefix::Help => "? ",
+        }
+    }
+
+    /// Whether the provider reads notes, so it is unavailable without the store.
+    fn needs_store(self) -> bool {
+        matches!(
+            self,
+            QueryPrefix::Tag | QueryPrefix::ReadLater | QueryPrefix::Track
+        )
+    }
+
+    /// Symbol prefixes also work without the space; a bare "t" stays a search.
+    fn matches_bare(self) -> bool {
+        !matches!(
+            self,
+            QueryPrefix::Tag | QueryPrefix::ReadLater | QueryPrefix::Track
+        )
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            QueryPrefix::Commands => "Commands",
+            QueryPrefix::Tag => "Tag search",
+            QueryPrefix::ReadLater => "Read later",
+            QueryPrefix::Track => "Time tracking",
+            QueryPrefix::Help => "Help",
+        }
+    }
+
+    fn subtitle(self) -> &'static str {
+        match self {
+            QueryPrefix::Commands => "\"> name\" lists system and app commands",
+            QueryPrefix::Tag => "\"t tag\" lists items tagged #tag",
+            QueryPrefix::ReadLater => {
+                "\"read <url>\" saves the page's article as a #readlater note"
+            }
+            QueryPrefix::Track => {
+                "\"track <note>\" starts a timer on a note; \"track stop\" stops it"
+            }
+            QueryPrefix::Help => "\"? \" shows these prefixes",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            QueryPrefix::Commands => "chevron.right",
+            QueryPrefix::Tag => "number",
+            QueryPrefix::ReadLater => "book",
+            QueryPrefix::Track => "timer",
+            QueryPrefix::Help => "questionmark.circle",
+        }
+    }
+
+    /// Numbered after all commands so result ids stay unique.
+    fn result_id(self) -> i64 {
+        let position = Self::ALL
+            .iter()
+            .position(|prefix| *prefix == self)
+            .unwrap_or(0);
+        -((SystemCommand::ALL.len() + AppCommand::ALL.len() + position) as i64) - 1
+    }
+
+    fn to_search_result(self) -> SearchResult {
+        SearchResult {
+            id: self.result_id(),
+            title: format!("{}  {}", self.prefix().trim_end(), self.title()),
+            subtitle: self.subtitle().to_string(),
+            snippet: None,
+            snippet_source: None,
+            kind: ResultKind::QueryPrefix,
+            icon: Some(self.icon().to_string()),
+            command_key: Some(self.prefix().to_string()),
+            pinned: false,
+            thumbnail_key: None,
+            title_match_ranges: Vec::new(),
+            checklist_progress: None,
+        }
+    }
+}
+
+/// `command_key` of a `Timer` result starting a timer on its note.
+pub const TIMER_START_KEY: &str = "start";
+/// `command_key` of the `Timer` result stopping the running timer.
+pub const TIMER_STOP_KEY: &str = "stop";
+
+/// The provider a launcher query is dispatched to, with its prefix removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryRoute<'a> {
+    Items(&'a str),
+    Commands(&'a str),
+    Tag(&'a str),
+    /// A normalized URL, or `None` while none has been typed.
+    ReadLater(Option<String>),
+    Track(TrackQuery<'a>),
+    Help,
+}
+
+/// What a `track` query asks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackQuery<'a> {
+    /// Nothing typed yet.
+    Status,
+    /// A note search for the note to start a timer on.
+    Start(&'a str),
+    Stop,
+}
+
+pub fn route_query(query: &str) -> QueryRoute<'_> {
+    let query = query.trim_start();
+    for prefix in QueryPrefix::ALL {
+        let rest = query.strip_prefix(prefix.prefix()).or_else(|| {
+            (prefix.matches_bare() && query == prefix.prefix().trim_end()).then_some("")
+        });
+        if let Some(rest) = rest {
+            let rest = rest.trim();
+            return match prefix {
+                QueryPrefix::Commands => QueryRoute::Commands(rest),
+                QueryPrefix::Tag => QueryRoute::Tag(rest),
+                // "read the docs" is still a search.
+                QueryPrefix::ReadLater if rest.is_empty() => QueryRoute::ReadLater(None),
+                QueryPrefix::ReadLater => match read_later::normalize_url(rest) {
+                    Some(url) => QueryRoute::ReadLater(Some(url)),
+                    None => QueryRoute::Items(query),
+                },
+                QueryPrefix::Track => QueryRoute::Track(track_query(rest)),
+                QueryPrefix::Help => QueryRoute::Help,
+            };
+        }
+    }
+    QueryRoute::Items(query)
+}
+
+/// The result saving `url` as a read-later note, or the prefix's hint while
+/// no URL has been typed. `queued` counts pages waiting to be fetched again.
+pub fn read_later_results(url: Option<String>, queued: usize) -> Vec<SearchResult> {
+    let Some(url) = url else {
+        return vec![QueryPrefix::ReadLater.to_search_result()];
+    };
+    let mut subtitle = format!(
+        "Save the article as a note tagged #{}",
+        read_later::READ_LATER_TAG
+    );
+    if queued > 0 {
+        subtitle.push_str(&format!(" · {queued} queued until online"));
+    }
+    vec![SearchResult {
+        // Numbered after the prefixes so result ids stay unique.
+        id: -((SystemCommand::ALL.len() + AppCommand::ALL.len() + QueryPrefix::ALL.len()) as i64)
+            - 1,
+        title: format!("Read later: {url}"),
+        subtitle,
+        snippet: None,
+        snippet_source: None,
+        kind: ResultKind::ReadLater,
+        icon: Some(QueryPrefix::ReadLater.icon().to_string()),
+        command_key: Some(url),
+        pinned: false,
+        thumbnail_key: None,
+        title_match_ranges: Vec::new(),
+        checklist_progress: None,
+    }]
+}
+
+fn track_query(rest: &str) -> TrackQuery<'_> {
+    match rest {
+        "" => TrackQuery::Status,
+        "stop" => TrackQuery::Stop,
+        "start" => TrackQuery::Start(""),
+        _ => TrackQuery::Start(rest.strip_prefix("start ").unwrap_or(rest).trim_start()),
+    }
+}
+
+/// The row stopping the running timer, then a row starting a timer on each
+/// of `notes` with the time already tracked on it, or the prefix's hint when
+/// there is neither. `now` is in Unix seconds.
+pub fn track_results(
+    running: Option<RunningTimer>,
+    notes: Vec<(SearchResult, u64)>,
+    now: u64,
+) -> Vec<SearchResult> {
+    let mut results = Vec::new();
+    let running_id = running.as_ref().map(|timer| timer.item_id);
+    if let Some(timer) = running {
+        results.push(SearchResult {
+            // Numbered after the read-later result so result ids stay unique.
+            id: -((SystemCommand::ALL.len() + AppCommand::ALL.len() + QueryPrefix::ALL.len())
+                as i64)
+                - 2,
+            title: format!("Stop timer: {}", timer.title),
+            subtitle: format!(
+                "Running for {}",
+                time_tracking::format_duration(now.saturating_sub(timer.started_at))
+            ),
+            snippet: None,
+            snippet_source: None,
+            kind: ResultKind::Timer,
+            icon: Some("stop.circle".to_string()),
+            command_key: Some(TIMER_STOP_KEY.to_string()),
+            pinned: false,
+            thumbnail_key: None,
+            title_match_ranges: Vec::new(),
+            checklist_progress: None,
+        });
+    }
+    for (note, tracked) in notes {
+        if Some(note.id) == running_id {
+            continue;
+        }
+        let subtitle = if tracked > 0 {
+            format!(
+                "Start a timer · {} tracked",
+                time_tracking::format_duration(tracked)
+            )
+        } else {
+            "Start a timer".to_string()
+        };
+        results.push(SearchResult {
+            id: note.id,
+            title: format!("Track: {}", note.title),
+            subtitle,
+            snippet: None,
+            snippet_source: None,
+            kind: ResultKind::Timer,
+            icon: Some(QueryPrefix::Track.icon().to_string()),
+            command_key: Some(TIMER_START_KEY.to_string()),
+            pinned: false,
+            thumbnail_key: None,
+            title_match_ranges: Vec::new(),
+            checklist_progress: None,
+        });
+    }
+    if results.is_empty() {
+        results.push(QueryPrefix::Track.to_search_result());
+    }
+    results
+}
+
+pub fn help_results() -> Vec<SearchResult> {
+    QueryPrefix::ALL
+        .into_iter()
+        .map(QueryPrefix::to_search_result)
+        .collect()
+}
+
+/// The prefixes that still work when the note store is turned off.
+pub fn help_results_without_store() -> Vec<SearchResult> {
+    QueryPrefix::ALL
+        .into_iter()
+        .filter(|prefix| !prefix.needs_store())
+        .map(QueryPrefix::to_search_result)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        QueryRoute, TIMER_START_KEY, TIMER_STOP_KEY, TrackQuery, help_results_without_store,
+        route_query, track_results,
+    };
+    use crate::models::{ResultKind, RunningTimer, SearchResult};
+
+    #[test]
+    fn route_query_strips_known_prefixes() {
+        assert_eq!(route_query("> sle"), QueryRoute::Commands("sle"));
+        assert_eq!(route_query(">"), QueryRoute::Commands(""));
+        assert_eq!(route_query("t  work "), QueryRoute::Tag("work"));
+        assert_eq!(route_query("?"), QueryRoute::Help);
+        assert_eq!(route_query("? anything"), QueryRoute::Help);
+        assert_eq!(route_query("read "), QueryRoute::ReadLater(None));
+        assert_eq!(
+            route_query("read example.com/post"),
+            QueryRoute::ReadLater(Some("https://example.com/post".into()))
+        );
+    }
+
+    #[test]
+    fn route_query_parses_track_commands() {
+        assert_eq!(route_query("track "), QueryRoute::Track(TrackQuery::Status));
+        assert_eq!(
+            route_query("track stop"),
+            QueryRoute::Track(TrackQuery::Stop)
+        );
+        assert_eq!(
+            route_query("track start"),
+            QueryRoute::Track(TrackQuery::Start(""))
+        );
+        assert_eq!(
+            route_query("track start  client a"),
+            QueryRoute::Track(TrackQuery::Start("client a"))
+        );
+        assert_eq!(
+            route_query("track client a"),
+            QueryRoute::Track(TrackQuery::Start("client a"))
+        );
+        assert_eq!(route_query("track"), QueryRoute::Items("track"));
+    }
+
+    #[test]
+    fn track_results_put_the_running_timer_first() {
+        let note = |id: i64, title: &str| SearchResult {
+            id,
+            title: title.to_string(),
+            subtitle: String::new(),
+            snippet: None,
+            snippet_source: None,
+            kind: ResultKind::Item,
+            icon: None,
+            command_key: None,
+            pinned: false,
+            thumbnail_key: None,
+            title_match_ranges: Vec::new(),
+            checklist_progress: None,
+        };
+        let running = RunningTimer {
+            item_id: 1,
+            title: "Client A".to_string(),
+            started_at: 1_000,
+        };
+        let results = track_results(
+            Some(running),
+            vec![(note(1, "Client A"), 600), (note(2, "Client B"), 3_900)],
+            1_000 + 25 * 60,
+        );
+        let rows: Vec<_> = results
+            .iter()
+            .map(|row| {
+                (
+                    row.title.as_str(),
+                    row.subtitle.as_str(),
+                    row.command_key.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    "Stop timer: Client A",
+                    "Running for 0:25",
+                    Some(TIMER_STOP_KEY)
+                ),
+                (
+                    "Track: Client B",
+                    "Start a timer · 1:05 tracked",
+                    Some(TIMER_START_KEY)
+                ),
+            ]
+        );
+        assert!(results[0].id < 0);
+        assert_eq!(results[1].id, 2);
+
+        let hint = track_results(None, Vec::new(), 0);
+        assert_eq!(hint[0].kind, ResultKind::QueryPrefix);
+    }
+
+    #[test]
+    fn help_without_store_lists_only_note_free_prefixes() {
+        let prefixes: Vec<_> = help_results_without_store()
+            .into_iter()
+            .filter_map(|result| result.command_key)
+            .collect();
+        assert_eq!(prefixes, vec!["> ".to_string(), "? ".to_string()]);
+    }
+
+    #[test]
+    fn route_query_keeps_plain_searches() {
+        assert_eq!(route_query("t"), QueryRoute::Items("t"));
+        assert_eq!(route_query("tea"), QueryRoute::Items("tea"));
+        assert_eq!(route_query("a > b"), QueryRoute::Items("a > b"));
+        assert_eq!(route_query("read"), QueryRoute::Items("read"));
+        assert_eq!(
+            route_query("tracking numbers"),
+            QueryRoute::Items("tracking numbers")
+        );
+        assert_eq!(
+            route_query("read the docs"),
+            QueryRoute::Items("read the docs")
+        );
+    }
+}
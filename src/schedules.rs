@@ -0,0 +1,288 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Datelike, Days, NaiveDate, TimeZone, Weekday};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+
+use crate::logging::log_error;
+
+static SCHEDULER: OnceCell<()> = OnceCell::new();
+const SCHEDULER_TICK: Duration = Duration::from_secs(30);
+/// Longest power-saving sleep, bounding how late a schedule can run when the
+/// machine slept through its occurrence.
+const MAX_POWER_SAVING_SLEEP: Duration = Duration::from_secs(15 * 60);
+
+static POWER_SAVING: AtomicBool = AtomicBool::new(false);
+static WAKEUPS: AtomicU64 = AtomicU64::new(0);
+static POWER_SAVING_WAKEUPS: AtomicU64 = AtomicU64::new(0);
+/// Set (and signalled) to end the current scheduler sleep early.
+static WAKE_REQUESTED: Mutex<bool> = Mutex::new(false);
+static WAKE_SIGNAL: Condvar = Condvar::new();
+/// A weekly rule recurs within 7 days; one extra day covers DST gaps.
+const OCCURRENCE_SEARCH_DAYS: u64 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Recurrence {
+    Daily,
+    /// `weekday` counts from Monday = 0.
+    Weekly {
+        weekday: u32,
+    },
+}
+
+impl Recurrence {
+    fn includes(self, date: NaiveDate) -> bool {
+        match self {
+            Recurrence::Daily => true,
+            Recurrence::Weekly { weekday } => date.weekday().num_days_from_monday() == weekday,
+        }
+    }
+}
+
+/// A template that produces a new dated note every time its recurrence comes due.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Schedule {
+    pub id: String,
+    pub name: String,
+    /// Title of the created note; `{date}` and `{weekday}` are substituted.
+    pub title_template: String,
+    #[serde(default)]
+    pub note_template: String,
+    pub recurrence: Recurrence,
+    pub hour: u32,
+    pub minute: u32,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Unix time of the last created instance (or of creation, so that saving a
+    /// schedule never back-fills an occurrence that already passed).
+    #[serde(default)]
+    pub last_run_unix_seconds: i64,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Schedule {
+    fn occurrence_on<Tz: TimeZone>(&self, date: NaiveDate, tz: &Tz) -> Option<DateTime<Tz>> {
+        if !self.recurrence.includes(date) {
+            return None;
+        }
+        let naive = date.and_hms_opt(self.hour, self.minute, 0)?;
+        tz.from_local_datetime(&naive).earliest()
+    }
+
+    /// Most recent occurrence at or before `now`.
+    pub fn latest_occurrence<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let today = now.date_naive();
+        (0..OCCURRENCE_SEARCH_DAYS)
+            .filter_map(|offset| today.checked_sub_days(Days::new(offset)))
+            .filter_map(|date| self.occurrence_on(date, &now.timezone()))
+            .find(|occurrence| occurrence <= now)
+    }
+
+    /// First occurrence strictly after `now`.
+    pub fn next_occurrence<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let today = now.date_naive();
+        (0..OCCURRENCE_SEARCH_DAYS)
+            .filter_map(|offset| today.checked_add_days(Days::new(offset)))
+            .filter_map(|date| self.occurrence_on(date, &now.timezone()))
+            .find(|occurrence| occurrence > now)
+    }
+
+    /// Returns the occurrence to create an instance for, if one passed since the last run.
+    /// Missed occurrences collapse into the latest one instead of being replayed.
+    pub fn due_occurrence<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        if !self.enabled {
+            return None;
+        }
+        self.latest_occurrence(now)
+            .filter(|occurrence| occurrence.timestamp() > self.last_run_unix_seconds)
+    }
+}
+
+pub fn render_template(template: &str, date: NaiveDate) -> String {
+    template
+        .replace("{date}", &date.format("%Y-%m-%d").to_string())
+        .replace("{weekday}", weekday_name(date.weekday()))
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+/// Scheduler thread wakeups since launch, in total and while power saving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchedulerWakeups {
+    pub total: u64,
+    pub power_saving: u64,
+    pub power_saving_active: bool,
+}
+
+pub fn scheduler_wakeups() -> SchedulerWakeups {
+    SchedulerWakeups {
+        total: WAKEUPS.load(Ordering::Relaxed),
+        power_saving: POWER_SAVING_WAKEUPS.load(Ordering::Relaxed),
+        power_saving_active: POWER_SAVING.load(Ordering::Relaxed),
+    }
+}
+
+/// While power saving, the scheduler sleeps until the next occurrence instead
+/// of polling every 30 seconds. Leaving power saving runs a tick right away.
+pub fn set_power_saving(enabled: bool) {
+    if POWER_SAVING.swap(enabled, Ordering::Relaxed) && !enabled {
+        wake_scheduler();
+    }
+}
+
+/// Ends the current sleep so changed schedules are picked up immediately.
+pub fn wake_scheduler() {
+    let mut requested = WAKE_REQUESTED.lock().unwrap_or_else(|err| err.into_inner());
+    *requested = true;
+    WAKE_SIGNAL.notify_all();
+}
+
+fn wait_for_wake(timeout: Duration) {
+    let requested = WAKE_REQUESTED.lock().unwrap_or_else(|err| err.into_inner());
+    let (mut requested, _) = WAKE_SIGNAL
+        .wait_timeout_while(requested, timeout, |requested| !*requested)
+        .unwrap_or_else(|err| err.into_inner());
+    *requested = false;
+}
+
+/// How long to sleep before the next tick, given the next occurrence of any
+/// schedule as unix seconds.
+fn scheduler_sleep(power_saving: bool, next_due: Option<i64>, now: i64) -> Duration {
+    if !power_saving {
+        return SCHEDULER_TICK;
+    }
+    match next_due {
+        Some(next_due) => {
+            let seconds = u64::try_from(next_due.saturating_sub(now)).unwrap_or(0);
+            Duration::from_secs(seconds.max(1)).min(MAX_POWER_SAVING_SLEEP)
+        }
+        None => MAX_POWER_SAVING_SLEEP,
+    }
+}
+
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Starts the scheduler thread once. `tick` runs due schedules and returns the
+/// next occurrence (unix seconds); it is called every 30 seconds, or only when
+/// something is due while power saving.
+pub fn spawn_scheduler(tick: fn() -> Option<i64>) {
+    SCHEDULER.get_or_init(|| {
+        let spawned = std::thread::Builder::new()
+            .name("note-scheduler".to_string())
+            .spawn(move || {
+                let mut next_due = None;
+                loop {
+                    let power_saving = POWER_SAVING.load(Ordering::Relaxed);
+                    wait_for_wake(scheduler_sleep(power_saving, next_due, unix_now()));
+                    WAKEUPS.fetch_add(1, Ordering::Relaxed);
+                    if POWER_SAVING.load(Ordering::Relaxed) {
+                        POWER_SAVING_WAKEUPS.fetch_add(1, Ordering::Relaxed);
+                    }
+                    next_due = tick();
+                }
+            });
+        if let Err(err) = spawned {
+            log_error("failed to spawn note scheduler thread", err);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MAX_POWER_SAVING_SLEEP, Recurrence, SCHEDULER_TICK, Schedule, render_template,
+        scheduler_sleep,
+    };
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use std::time::Duration;
+
+    fn weekly_review() -> Schedule {
+        Schedule {
+            id: "s".into(),
+            name: "Weekly review".into(),
+            title_template: "Weekly review {date}".into(),
+            note_template: String::new(),
+            recurrence: Recurrence::Weekly { weekday: 0 },
+            hour: 9,
+            minute: 0,
+            enabled: true,
+            last_run_unix_seconds: 0,
+        }
+    }
+
+    #[test]
+    fn weekly_schedule_finds_latest_and_next_monday() {
+        let tz = FixedOffset::east_opt(3 * 3600).unwrap();
+        // Wednesday 2026-10-14 12:00
+        let now = tz.with_ymd_and_hms(2026, 10, 14, 12, 0, 0).unwrap();
+        let schedule = weekly_review();
+
+        let latest = schedule.latest_occurrence(&now).unwrap();
+        assert_eq!(latest, tz.with_ymd_and_hms(2026, 10, 12, 9, 0, 0).unwrap());
+        let next = schedule.next_occurrence(&now).unwrap();
+        assert_eq!(next, tz.with_ymd_and_hms(2026, 10, 19, 9, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn due_occurrence_respects_last_run() {
+        let tz = FixedOffset::east_opt(0).unwrap();
+        let mut schedule = Schedule {
+            recurrence: Recurrence::Daily,
+            ..weekly_review()
+        };
+        let before_nine = tz.with_ymd_and_hms(2026, 10, 14, 8, 59, 0).unwrap();
+        let after_nine = tz.with_ymd_and_hms(2026, 10, 14, 9, 0, 30).unwrap();
+
+        schedule.last_run_unix_seconds = before_nine.timestamp();
+        assert!(schedule.due_occurrence(&before_nine).is_none());
+        assert!(schedule.due_occurrence(&after_nine).is_some());
+
+        schedule.last_run_unix_seconds = after_nine.timestamp();
+        assert!(schedule.due_occurrence(&after_nine).is_none());
+    }
+
+    #[test]
+    fn scheduler_sleep_waits_for_the_next_occurrence_while_power_saving() {
+        assert_eq!(scheduler_sleep(false, Some(10_000), 0), SCHEDULER_TICK);
+        assert_eq!(
+            scheduler_sleep(true, Some(120), 0),
+            Duration::from_secs(120)
+        );
+        assert_eq!(scheduler_sleep(true, Some(-5), 0), Duration::from_secs(1));
+        assert_eq!(
+            scheduler_sleep(true, Some(1_000_000), 0),
+            MAX_POWER_SAVING_SLEEP
+        );
+        assert_eq!(scheduler_sleep(true, None, 0), MAX_POWER_SAVING_SLEEP);
+    }
+
+    #[test]
+    fn render_template_substitutes_date_and_weekday() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        assert_eq!(
+            render_template("Review {weekday} {date}", date),
+            "Review Monday 2026-10-12"
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use std::path::Path;
+
+const MIB: u64 = 1024 * 1024;
+const GIB: u64 = 1024 * MIB;
+/// Below this much free space writes are likely to start failing.
+const CRITICAL_FREE_BYTES: u64 = 256 * MIB;
+const LOW_FREE_BYTES: u64 = 2 * GIB;
+/// Sync clients keep their own caches and the account quota is not visible
+/// locally, so cloud-synced folders warn earlier.
+const LOW_FREE_BYTES_CLOUD: u64 = 5 * GIB;
+/// Share of the configured budget at which the mirror size starts warning.
+const BUDGET_WARNING_PERCENT: u64 = 90;
+
+/// Sync services recognized from the storage root path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloudProvider {
+    ICloudDrive,
+    Dropbox,
+    GoogleDrive,
+    OneDrive,
+    /// Another File Provider folder under `~/Library/CloudStorage`.
+    OtherCloudStorage,
+}
+
+impl CloudProvider {
+    pub fn name(self) -> &'static str {
+        match self {
+            CloudProvider::ICloudDrive => "iCloud Drive",
+            CloudProvider::Dropbox => "Dropbox",
+            CloudProvider::GoogleDrive => "Google Drive",
+            CloudProvider::OneDrive => "OneDrive",
+            CloudProvider::OtherCloudStorage => "cloud storage",
+        }
+    }
+}
+
+pub fn detect_cloud_provider(path: &Path) -> Option<CloudProvider> {
+    let path = path.to_string_lossy().to_lowercase();
+    if path.contains("/library/mobile documents/") {
+        return Some(CloudProvider::ICloudDrive);
+    }
+    if path.contains("/dropbox") {
+        return Some(CloudProvider::Dropbox);
+    }
+    if path.contains("/google drive") || path.contains("/googledrive") {
+        return Some(CloudProvider::GoogleDrive);
+    }
+    if path.contains("/onedrive") {
+        return Some(CloudProvider::OneDrive);
+    }
+    if path.contains("/library/cloudstorage/") {
+        return Some(CloudProvider::OtherCloudStorage);
+    }
+    None
+}
+
+/// Space figures for the JSON storage root at one point in time.
+#[derive(Debug, Clone, Default)]
+pub struct StorageSnapshot {
+    pub cloud_provider: Option<CloudProvider>,
+    /// `None` when the volume could not be queried.
+    pub available_bytes: Option<u64>,
+    pub total_bytes: Option<u64>,
+    /// Bytes currently used by the mirror folder, deleted archives included.
+    pub mirror_bytes: u64,
+    /// User-configured ceiling for `mirror_bytes`; `None` for no budget.
+    pub budget_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AlertLevel {
+    Warning,
+    Critical,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageAlert {
+    pub level: AlertLevel,
+    pub message: String,
+}
+
+/// A rule that inspects a snapshot and may raise an alert. New rules are added
+/// to `BUDGET_CHECKS`.
+type BudgetCheck = fn(&StorageSnapshot) -> Option<StorageAlert>;
+
+const BUDGET_CHECKS: &[BudgetCheck] = &[check_free_space, check_mirror_budget];
+
+/// Alerts raised by every check, most severe first.
+pub fn evaluate(snapshot: &StorageSnapshot) -> Vec<StorageAlert> {
+    let mut alerts: Vec<StorageAlert> = BUDGET_CHECKS
+        .iter()
+        .filter_map(|check| check(snapshot))
+        .collect();
+    alerts.sort_by_key(|alert| std::cmp::Reverse(alert.level));
+    alerts
+}
+
+fn check_free_space(snapshot: &StorageSnapshot) -> Option<StorageAlert> {
+    let available = snapshot.available_bytes?;
+    let location = match snapshot.cloud_provider {
+        Some(provider) => format!("the {} folder's disk", provider.name()),
+        None => "the storage folder's disk".to_string(),
+    };
+    if available < CRITICAL_FREE_BYTES {
+        return Some(StorageAlert {
+            level: AlertLevel::Critical,
+            message: format!(
+                "Only {} free on {location}; saving notes may fail.",
+                format_bytes(available)
+            ),
+        });
+    }
+
+    let low_threshold = if snapshot.cloud_provider.is_some() {
+        LOW_FREE_BYTES_CLOUD
+    } else {
+        LOW_FREE_BYTES
+    };
+    (available < low_threshold).then(|| StorageAlert {
+        level: AlertLevel::Warning,
+        message: format!("{} free on {location}.", format_bytes(available)),
+    })
+}
+
+fn check_mirror_budget(snapshot: &StorageSnapshot) -> Option<StorageAlert> {
+    let budget = snapshot.budget_bytes.filter(|budget| *budget > 0)?;
+    let used = snapshot.mirror_bytes;
+    if used > budget {
+        return Some(StorageAlert {
+            level: AlertLevel::Critical,
+            message: format!(
+                "Notes use {} of the {} storage budget.",
+                format_bytes(used),
+                format_bytes(budget)
+            ),
+        });
+    }
+    (used.saturating_mul(100) >= budget.saturating_mul(BUDGET_WARNING_PERCENT)).then(|| {
+        StorageAlert {
+            level: AlertLevel::Warning,
+            message: format!(
+                "Notes use {} of the {} storage budget.",
+                format_bytes(used),
+                format_bytes(budget)
+            ),
+        }
+    })
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes >= GIB {
+        format!("{:.1} GB", bytes as f64 / GIB as f64)
+    } else if bytes >= MIB {
+        format!("{:.1} MB", bytes as f64 / MIB as f64)
+    } else {
+        format!("{} KB", bytes.div_ceil(1024))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AlertLevel, CloudProvider, GIB, MIB, StorageSnapshot, detect_cloud_provider, evaluate,
+    };
+    use std::path::Path;
+
+    #[test]
+    fn detect_cloud_provider_recognizes_sync_folders() {
+        let icloud = Path::new("/Users/a/Library/Mobile Documents/com~apple~CloudDocs/Notes");
+        assert_eq!(
+            detect_cloud_provider(icloud),
+            Some(CloudProvider::ICloudDrive)
+        );
+        let drive = Path::new("/Users/a/Library/CloudStorage/GoogleDrive-a@b.c/My Drive");
+        assert_eq!(
+            detect_cloud_provider(drive),
+            Some(CloudProvider::GoogleDrive)
+        );
+        assert_eq!(
+            detect_cloud_provider(Path::new("/Users/a/Documents/AlfredAlternativeData")),
+            None
+        );
+    }
+
+    #[test]
+    fn evaluate_orders_alerts_by_severity() {
+        let snapshot = StorageSnapshot {
+            cloud_provider: Some(CloudProvider::Dropbox),
+            available_bytes: Some(3 * GIB),
+            total_bytes: Some(100 * GIB),
+            mirror_bytes: 120 * MIB,
+            budget_bytes: Some(100 * MIB),
+        };
+        let levels: Vec<_> = evaluate(&snapshot)
+            .into_iter()
+            .map(|alert| alert.level)
+            .collect();
+        assert_eq!(levels, vec![AlertLevel::Critical, AlertLevel::Warning]);
+
+        let local = StorageSnapshot {
+            cloud_provider: None,
+            ..snapshot
+        };
+        assert_eq!(evaluate(&local).len(), 1);
+    }
+}
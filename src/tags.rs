@@ -0,0 +1,150 @@
+use std::ops::Range;
+
+/// Extracts `#tag` tokens from free text, lowercased and deduplicated in order.
+///
+/// A tag starts after a `#` that begins a word and runs over alphanumerics,
+/// `-`, `_`, and `/`. Markdown headings (`# Title`) are not tags.
+pub fn extract_hashtags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for (_, tag) in hashtag_spans(text) {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// Byte range (including the `#`) and lowercased name of every tag in `text`.
+pub fn hashtag_spans(text: &str) -> Vec<(Range<usize>, String)> {
+    let mut spans = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, ch)) = chars.next() {
+        let starts_word = previous.is_none_or(|prev| prev.is_whitespace() || prev == '(');
+        previous = Some(ch);
+        if ch != '#' || !starts_word {
+            continue;
+        }
+
+        let start = index + ch.len_utf8();
+        let mut end = start;
+        while let Some(&(next_index, next)) = chars.peek() {
+            if next.is_alphanumeric() || matches!(next, '-' | '_' | '/') {
+                end = next_index + next.len_utf8();
+                previous = Some(next);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let name = text[start..end].trim_end_matches(['-', '/']);
+        if name.chars().any(char::is_alphabetic) {
+            spans.push((index..start + name.len(), name.to_lowercase()));
+        }
+    }
+
+    spans
+}
+
+/// Rewrites `text` so that exactly one tag from `group` remains, set to `tag`.
+///
+/// The first group tag found is replaced in place and any others are removed;
+/// when none is present `#tag` is appended on its own line.
+pub fn replace_tag_in_group(text: &str, group: &[&str], tag: &str) -> String {
+    let matches: Vec<Range<usize>> = hashtag_spans(text)
+        .into_iter()
+        .filter(|(_, name)| group.contains(&name.as_str()))
+        .map(|(range, _)| range)
+        .collect();
+
+    if matches.is_empty() {
+        return append_tag(text, tag);
+    }
+
+    let mut output = String::with_capacity(text.len() + tag.len());
+    let mut cursor = 0usize;
+    for (position, range) in matches.into_iter().enumerate() {
+        if position == 0 {
+            output.push_str(&text[cursor..range.start]);
+            output.push('#');
+            output.push_str(tag);
+        } else {
+            // Drop the duplicate together with the space that separated it.
+            let before = &text[cursor..range.start];
+            output.push_str(before.strip_suffix(' ').unwrap_or(before));
+        }
+        cursor = range.end;
+    }
+    output.push_str(&text[cursor..]);
+    output
+}
+
+/// Appends `#tag` on its own line unless the text already carries the tag.
+pub fn add_tag(text: &str, tag: &str) -> String {
+    if hashtag_spans(text).iter().any(|(_, name)| name == tag) {
+        return text.to_string();
+    }
+    append_tag(text, tag)
+}
+
+fn append_tag(text: &str, tag: &str) -> String {
+    match text {
+        "" => format!("#{tag}"),
+        _ if text.ends_with('\n') => format!("{text}#{tag}"),
+        _ => format!("{text}\n#{tag}"),
+    }
+}
+
+/// Whether `tag` (already normalized) would be recognized as a hashtag in a note.
+pub fn is_valid_tag(tag: &str) -> bool {
+    extract_hashtags(&format!("#{tag}")) == [tag]
+}
+
+/// Normalizes user-entered tag names (`#Work`, ` work `) to the stored form.
+pub fn normalize_tag(raw: &str) -> String {
+    raw.trim().trim_start_matches('#').trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_tag, extract_hashtags, is_valid_tag, normalize_tag, replace_tag_in_group};
+
+    #[test]
+    fn extract_hashtags_finds_word_initial_tags() {
+        let tags = extract_hashtags("Call Bob #todo about #Work/Q3, not a#b or #123");
+        assert_eq!(tags, vec!["todo".to_string(), "work/q3".to_string()]);
+    }
+
+    #[test]
+    fn extract_hashtags_ignores_markdown_headings() {
+        assert!(extract_hashtags("# Heading\n## Sub").is_empty());
+    }
+
+    #[test]
+    fn replace_tag_in_group_rewrites_first_and_drops_others() {
+        let group = ["todo", "doing", "done"];
+        assert_eq!(
+            replace_tag_in_group("Ship it #Todo #work #done", &group, "doing"),
+            "Ship it #doing #work"
+        );
+        assert_eq!(
+            replace_tag_in_group("Ship it", &group, "done"),
+            "Ship it\n#done"
+        );
+    }
+
+    #[test]
+    fn add_tag_appends_only_missing_tags() {
+        assert_eq!(add_tag("Plan #Work", "work"), "Plan #Work");
+        assert_eq!(add_tag("Plan", "work"), "Plan\n#work");
+        assert!(is_valid_tag("work/q3"));
+        assert!(!is_valid_tag("two words"));
+    }
+
+    #[test]
+    fn normalize_tag_strips_hash_and_case() {
+        assert_eq!(normalize_tag(" #Todo "), "todo");
+    }
+}
@@ -0,0 +1,148 @@
+//! Editor commands that rewrite the selected part of a note. Style and image
+//! tokens are left as they are: case changes and URL encoding only touch the
+//! text between them, and line commands move tokens along with their lines.
+
+use std::collections::HashSet;
+
+use crate::html_note::{percent_decode, percent_encode};
+use crate::vault::{parse_inline_image, style_token_len};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextTransform {
+    Uppercase,
+    Lowercase,
+    TitleCase,
+    SortLines,
+    DeduplicateLines,
+    JoinLines,
+    UrlEncode,
+    UrlDecode,
+}
+
+/// `text` rewritten by `transform`.
+pub fn transform_text(text: &str, transform: TextTransform) -> String {
+    match transform {
+        TextTransform::Uppercase => map_text_segments(text, |segment| segment.to_uppercase()),
+        TextTransform::Lowercase => map_text_segments(text, |segment| segment.to_lowercase()),
+        TextTransform::TitleCase => {
+            let mut at_word_start = true;
+            map_text_segments(text, |segment| {
+                let mut output = String::with_capacity(segment.len());
+                for character in segment.chars() {
+                    if character.is_alphanumeric() {
+                        if at_word_start {
+                            output.extend(character.to_uppercase());
+                        } else {
+                            output.extend(character.to_lowercase());
+                        }
+                        at_word_start = false;
+                    } else {
+                        output.push(character);
+                        at_word_start = character.is_whitespace() || character == '-';
+                    }
+                }
+                output
+            })
+        }
+        TextTransform::SortLines => map_lines(text, |mut lines| {
+            lines.sort_by_cached_key(|line| line.to_lowercase());
+            lines.join("\n")
+        }),
+        TextTransform::DeduplicateLines => map_lines(text, |lines| {
+            let mut seen = HashSet::new();
+            lines
+                .into_iter()
+                .filter(|line| line.trim().is_empty() || seen.insert(*line))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }),
+        TextTransform::JoinLines => map_lines(text, |lines| {
+            lines
+                .iter()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        }),
+        TextTransform::UrlEncode => map_text_segments(text, percent_encode),
+        TextTransform::UrlDecode => map_text_segments(text, percent_decode),
+    }
+}
+
+/// Applies `transform` to each run of text between style and image tokens.
+fn map_text_segments(text: &str, mut transform: impl FnMut(&str) -> String) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut segment_start = 0;
+    let mut index = 0;
+    while index < text.len() {
+        let rest = &text[index..];
+        let token_len =
+            style_token_len(rest).or_else(|| parse_inline_image(rest).map(|(_, _, len)| len));
+        match token_len {
+            Some(len) => {
+                output.push_str(&transform(&text[segment_start..index]));
+                output.push_str(&rest[..len]);
+                index += len;
+                segment_start = index;
+            }
+            None => index += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    output.push_str(&transform(&text[segment_start..]));
+    output
+}
+
+/// Applies `transform` to the lines of `text`; a trailing line break stays.
+fn map_lines(text: &str, transform: impl FnOnce(Vec<&str>) -> String) -> String {
+    let (body, trailing) = match text.strip_suffix('\n') {
+        Some(body) => (body, "\n"),
+        None => (text, ""),
+    };
+    format!("{}{trailing}", transform(body.split('\n').collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_changes_leave_tokens_alone() {
+        let text = "[[b]]hello[[/b]] wORLD ![image](alfred://image/img-1-ab?w=320) new-york";
+        assert_eq!(
+            transform_text(text, TextTransform::Uppercase),
+            "[[b]]HELLO[[/b]] WORLD ![image](alfred://image/img-1-ab?w=320) NEW-YORK"
+        );
+        assert_eq!(
+            transform_text(text, TextTransform::TitleCase),
+            "[[b]]Hello[[/b]] World ![image](alfred://image/img-1-ab?w=320) New-York"
+        );
+        assert_eq!(
+            transform_text("[[fs=18]]Big[[/fs]]", TextTransform::Lowercase),
+            "[[fs=18]]big[[/fs]]"
+        );
+    }
+
+    #[test]
+    fn line_commands_keep_the_trailing_line_break() {
+        assert_eq!(
+            transform_text("pear\nApple\nbanana\n", TextTransform::SortLines),
+            "Apple\nbanana\npear\n"
+        );
+        assert_eq!(
+            transform_text("a\nb\na\n\n\nb", TextTransform::DeduplicateLines),
+            "a\nb\n\n"
+        );
+        assert_eq!(
+            transform_text("  one\n\ttwo \n\nthree\n", TextTransform::JoinLines),
+            "one two three\n"
+        );
+    }
+
+    #[test]
+    fn url_encoding_round_trips() {
+        let text = "a b&c=ü/d";
+        let encoded = transform_text(text, TextTransform::UrlEncode);
+        assert_eq!(encoded, "a%20b%26c%3D%C3%BC%2Fd");
+        assert_eq!(transform_text(&encoded, TextTransform::UrlDecode), text);
+    }
+}
@@ -0,0 +1,213 @@
+//! Time tracked on notes. One timer runs at a time; stopping it adds a time
+//! entry to its note, and a note's entries add up to its tracked time.
+//!
+//! Weekly reports run Monday to Sunday in local time. An entry that runs past
+//! midnight counts toward both days.
+
+use chrono::{Datelike, Duration, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeEntry {
+    /// Unix seconds.
+    pub started_at: u64,
+    pub ended_at: u64,
+}
+
+impl TimeEntry {
+    pub fn seconds(&self) -> u64 {
+        self.ended_at.saturating_sub(self.started_at)
+    }
+}
+
+/// One note's tracked time in a week.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeekRow {
+    pub item_id: i64,
+    pub title: String,
+    /// Monday first.
+    pub day_seconds: [u64; 7],
+}
+
+impl WeekRow {
+    pub fn total_seconds(&self) -> u64 {
+        self.day_seconds.iter().sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeekReport {
+    /// The Monday the week starts on.
+    pub week_start: NaiveDate,
+    /// Notes with time in the week, most tracked first.
+    pub rows: Vec<WeekRow>,
+}
+
+impl WeekReport {
+    pub fn days(&self) -> [NaiveDate; 7] {
+        std::array::from_fn(|offset| self.week_start + Duration::days(offset as i64))
+    }
+
+    pub fn day_totals(&self) -> [u64; 7] {
+        std::array::from_fn(|day| self.rows.iter().map(|row| row.day_seconds[day]).sum())
+    }
+}
+
+/// The Monday of `date`'s week.
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Unix seconds of local midnight starting `date`; on days whose midnight
+/// is skipped by a clock change, the first hour that exists.
+pub fn day_start<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> u64 {
+    (0..24)
+        .filter_map(|hour| date.and_hms_opt(hour, 0, 0))
+        .find_map(|time| tz.from_local_datetime(&time).earliest())
+        .map(|time| time.timestamp().max(0) as u64)
+        .unwrap_or(0)
+}
+
+/// The seconds of `entry` on each day of the week starting `week_start`.
+pub fn seconds_per_day<Tz: TimeZone>(entry: TimeEntry, week_start: NaiveDate, tz: &Tz) -> [u64; 7] {
+    let bounds: [u64; 8] =
+        std::array::from_fn(|offset| day_start(week_start + Duration::days(offset as i64), tz));
+    std::array::from_fn(|day| {
+        let start = entry.started_at.max(bounds[day]);
+        let end = entry.ended_at.min(bounds[day + 1]);
+        end.saturating_sub(start)
+    })
+}
+
+/// Tracked time per note in the week starting `week_start`, from each note's
+/// id, title and entries.
+pub fn weekly_report<Tz: TimeZone>(
+    items: impl IntoIterator<Item = (i64, String, Vec<TimeEntry>)>,
+    week_start: NaiveDate,
+    tz: &Tz,
+) -> WeekReport {
+    let mut rows: Vec<WeekRow> = items
+        .into_iter()
+        .filter_map(|(item_id, title, entries)| {
+            let mut day_seconds = [0u64; 7];
+            for entry in entries {
+                for (total, seconds) in day_seconds
+                    .iter_mut()
+                    .zip(seconds_per_day(entry, week_start, tz))
+                {
+                    *total += seconds;
+                }
+            }
+            let row = WeekRow {
+                item_id,
+                title,
+                day_seconds,
+            };
+            (row.total_seconds() > 0).then_some(row)
+        })
+        .collect();
+    rows.sort_by(|left, right| {
+        right
+            .total_seconds()
+            .cmp(&left.total_seconds())
+            .then_with(|| left.title.cmp(&right.title))
+    });
+    WeekReport { week_start, rows }
+}
+
+/// Hours and minutes, e.g. "1:05".
+pub fn format_duration(seconds: u64) -> String {
+    let minutes = seconds / 60;
+    format!("{}:{:02}", minutes / 60, minutes % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+    use super::{TimeEntry, format_duration, seconds_per_day, week_start, weekly_report};
+
+    fn at(tz: &FixedOffset, day: u32, hour: u32, minute: u32) -> u64 {
+        tz.with_ymd_and_hms(2026, 10, day, hour, minute, 0)
+            .unwrap()
+            .timestamp() as u64
+    }
+
+    #[test]
+    fn entries_are_split_at_local_midnight() {
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+        // Thursday 15 October 2026.
+        let monday = week_start(NaiveDate::from_ymd_opt(2026, 10, 15).unwrap());
+        assert_eq!(monday, NaiveDate::from_ymd_opt(2026, 10, 12).unwrap());
+
+        let late = TimeEntry {
+            started_at: at(&tz, 13, 23, 30),
+            ended_at: at(&tz, 14, 0, 45),
+        };
+        assert_eq!(
+            seconds_per_day(late, monday, &tz),
+            [0, 30 * 60, 45 * 60, 0, 0, 0, 0]
+        );
+
+        // Time before the week is left out.
+        let sunday_before = TimeEntry {
+            started_at: at(&tz, 11, 23, 0),
+            ended_at: at(&tz, 12, 1, 0),
+        };
+        assert_eq!(seconds_per_day(sunday_before, monday, &tz)[0], 3600);
+    }
+
+    #[test]
+    fn weekly_report_sums_notes_and_days() {
+        let tz = FixedOffset::east_opt(0).unwrap();
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        let report = weekly_report(
+            [
+                (
+                    1,
+                    "Client A".to_string(),
+                    vec![
+                        TimeEntry {
+                            started_at: at(&tz, 12, 9, 0),
+                            ended_at: at(&tz, 12, 10, 30),
+                        },
+                        TimeEntry {
+                            started_at: at(&tz, 16, 14, 0),
+                            ended_at: at(&tz, 16, 14, 20),
+                        },
+                    ],
+                ),
+                (
+                    2,
+                    "Client B".to_string(),
+                    vec![TimeEntry {
+                        started_at: at(&tz, 12, 11, 0),
+                        ended_at: at(&tz, 12, 14, 0),
+                    }],
+                ),
+                (
+                    3,
+                    "Last week".to_string(),
+                    vec![TimeEntry {
+                        started_at: at(&tz, 5, 9, 0),
+                        ended_at: at(&tz, 5, 10, 0),
+                    }],
+                ),
+            ],
+            monday,
+            &tz,
+        );
+        let titles: Vec<&str> = report.rows.iter().map(|row| row.title.as_str()).collect();
+        assert_eq!(titles, vec!["Client B", "Client A"]);
+        assert_eq!(report.rows[1].total_seconds(), 110 * 60);
+        assert_eq!(report.day_totals()[0], (90 + 180) * 60);
+        assert_eq!(report.day_totals()[4], 20 * 60);
+        assert_eq!(
+            report.days()[6],
+            NaiveDate::from_ymd_opt(2026, 10, 18).unwrap()
+        );
+
+        assert_eq!(format_duration(0), "0:00");
+        assert_eq!(format_duration(65 * 60 + 59), "1:05");
+    }
+}
@@ -0,0 +1,524 @@
+//! Issue search in Jira and Linear. `jira <query>` (`lin` for Linear) lists
+//! matching issues and opens the picked one; without a query it lists the
+//! user's open issues. `jira capture <query>` lists the same issues, and
+//! picking one saves it as a note: its key and title, a link back to the
+//! issue and its description, tagged #jira or #linear.
+
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::html_note::percent_encode;
+use crate::providers::{
+    CapturedNote, ProviderAction, ProviderOutcome, ProviderResult, ResultProvider,
+    read_json_response,
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const ISSUES_PER_SEARCH: usize = 20;
+const CAPTURE_KEYWORD: &str = "capture";
+const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
+const LINEAR_ISSUE_FIELDS: &str = "identifier title url state { name }";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrackerKind {
+    Jira,
+    Linear,
+}
+
+impl TrackerKind {
+    pub const ALL: [TrackerKind; 2] = [TrackerKind::Jira, TrackerKind::Linear];
+
+    pub fn provider_id(self) -> &'static str {
+        match self {
+            TrackerKind::Jira => "jira",
+            TrackerKind::Linear => "linear",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            TrackerKind::Jira => "Jira",
+            TrackerKind::Linear => "Linear",
+        }
+    }
+
+    fn prefix(self) -> &'static str {
+        match self {
+            TrackerKind::Jira => "jira ",
+            TrackerKind::Linear => "lin ",
+        }
+    }
+
+    /// Tag of notes captured from the tracker.
+    fn tag(self) -> &'static str {
+        self.provider_id()
+    }
+}
+
+/// A tracker workspace from settings. Jira needs the site (e.g.
+/// "team.atlassian.net") and the account's email next to the API token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackerAccount {
+    pub kind: TrackerKind,
+    pub site: Option<String>,
+    pub email: Option<String>,
+    /// Never written to settings; the app keeps it in the Keychain.
+    #[serde(skip)]
+    pub token: String,
+}
+
+impl TrackerAccount {
+    /// The account with its site reduced to a bare host, or an error when
+    /// something needed is missing.
+    pub fn normalized(mut self) -> Result<Self> {
+        let title = self.kind.title();
+        self.token = self.token.trim().to_string();
+        if self.token.is_empty() {
+            return Err(anyhow!("{title} token must not be empty"));
+        }
+        if self.token.chars().any(char::is_control) {
+            return Err(anyhow!("{title} token must not contain control characters"));
+        }
+        self.email = self
+            .email
+            .map(|email| email.trim().to_string())
+            .filter(|email| !email.is_empty());
+        self.site = self
+            .site
+            .map(|site| {
+                let site = site.trim();
+                let site = site
+                    .strip_prefix("https://")
+                    .or_else(|| site.strip_prefix("http://"))
+                    .unwrap_or(site);
+                site.trim_end_matches('/').to_ascii_lowercase()
+            })
+            .filter(|site| !site.is_empty());
+        match self.kind {
+            TrackerKind::Jira => {
+                let Some(site) = &self.site else {
+                    return Err(anyhow!("{title} site must not be empty"));
+                };
+                if !site
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '.' | '-' | ':'))
+                {
+                    return Err(anyhow!("{title} site must not contain {site:?}"));
+                }
+                if self.email.is_none() {
+                    return Err(anyhow!("{title} email must not be empty"));
+                }
+            }
+            TrackerKind::Linear => {
+                self.site = None;
+                self.email = None;
+            }
+        }
+        Ok(self)
+    }
+
+    fn home_url(&self) -> String {
+        match (self.kind, self.site.as_deref()) {
+            (TrackerKind::Jira, Some(site)) => format!("https://{site}"),
+            _ => "https://linear.app".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackerIssue {
+    /// "ENG-42" and the like.
+    pub key: String,
+    pub title: String,
+    pub url: String,
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TrackerQuery<'a> {
+    Search(&'a str),
+    Capture(&'a str),
+}
+
+fn parse_query(query: &str) -> TrackerQuery<'_> {
+    let query = query.trim();
+    match query.strip_prefix(CAPTURE_KEYWORD) {
+        Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace) => {
+            TrackerQuery::Capture(rest.trim())
+        }
+        _ => TrackerQuery::Search(query),
+    }
+}
+
+/// Whether `query` is an issue key such as "ENG-42".
+fn is_issue_key(query: &str) -> bool {
+    let Some((project, number)) = query.split_once('-') else {
+        return false;
+    };
+    !project.is_empty()
+        && project.chars().all(|ch| ch.is_ascii_alphanumeric())
+        && project.starts_with(|ch: char| ch.is_ascii_alphabetic())
+        && !number.is_empty()
+        && number.chars().all(|ch| ch.is_ascii_digit())
+}
+
+/// JQL for a launcher query: the user's open issues without one.
+fn jira_jql(query: &str) -> String {
+    if query.is_empty() {
+        return "assignee = currentUser() AND resolution = Unresolved ORDER BY updated DESC"
+            .to_string();
+    }
+    let escaped = query.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("text ~ \"{escaped}\" ORDER BY updated DESC")
+}
+
+fn parse_jira_issue(site: &str, issue: &Value) -> Option<TrackerIssue> {
+    let key = issue.get("key")?.as_str()?.to_string();
+    let fields = issue.get("fields")?;
+    Some(TrackerIssue {
+        url: format!("https://{site}/browse/{key}"),
+        title: fields.get("summary")?.as_str()?.to_string(),
+        status: fields
+            .pointer("/status/name")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        key,
+    })
+}
+
+fn parse_linear_issue(issue: &Value) -> Option<TrackerIssue> {
+    Some(TrackerIssue {
+        key: issue.get("identifier")?.as_str()?.to_string(),
+        title: issue.get("title")?.as_str()?.to_string(),
+        url: issue.get("url")?.as_str()?.to_string(),
+        status: issue
+            .pointer("/state/name")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+    })
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (index, byte)| {
+            bits | (*byte as u32) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(bits >> (18 - 6 * index)) as usize & 63] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Lists a tracker's issues in the launcher and captures them as notes.
+pub struct TrackerProvider {
+    account: TrackerAccount,
+}
+
+impl TrackerProvider {
+    pub fn new(account: TrackerAccount) -> Self {
+        Self { account }
+    }
+
+    fn site(&self) -> &str {
+        self.account.site.as_deref().unwrap_or_default()
+    }
+
+    fn search(&self, query: &str) -> Result<Vec<TrackerIssue>> {
+        match self.account.kind {
+            TrackerKind::Jira => {
+                // A key is looked up directly; text search doesn't match keys.
+                if is_issue_key(query)
+                    && let Ok(issue) = self.jira_get(&format!(
+                        "/rest/api/2/issue/{}?fields=summary,status",
+                        percent_encode(&query.to_ascii_uppercase())
+                    ))
+                {
+                    return Ok(parse_jira_issue(self.site(), &issue).into_iter().collect());
+                }
+                let body = self.jira_get(&format!(
+                    "/rest/api/3/search/jql?fields=summary,status&maxResults={ISSUES_PER_SEARCH}&jql={}",
+                    percent_encode(&jira_jql(query))
+                ))?;
+                Ok(body
+                    .get("issues")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|issue| parse_jira_issue(self.site(), issue))
+                    .collect())
+            }
+            TrackerKind::Linear => {
+                let (request, nodes) = if query.is_empty() {
+                    (
+                        json!({"query": format!(
+                            "query {{ viewer {{ assignedIssues(first: {ISSUES_PER_SEARCH}, orderBy: updatedAt, filter: {{ state: {{ type: {{ nin: [\"completed\", \"canceled\"] }} }} }}) {{ nodes {{ {LINEAR_ISSUE_FIELDS} }} }} }} }}"
+                        )}),
+                        "/data/viewer/assignedIssues/nodes",
+                    )
+                } else {
+                    (
+                        json!({
+                            "query": format!(
+                                "query($term: String!) {{ searchIssues(term: $term, first: {ISSUES_PER_SEARCH}) {{ nodes {{ {LINEAR_ISSUE_FIELDS} }} }} }}"
+                            ),
+                            "variables": {"term": query},
+                        }),
+                        "/data/searchIssues/nodes",
+                    )
+                };
+                let body = self.linear_post(&request)?;
+                Ok(body
+                    .pointer(nodes)
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(parse_linear_issue)
+                    .collect())
+            }
+        }
+    }
+
+    /// The issue `key` as a note, with its description fetched fresh.
+    fn capture(&self, key: &str) -> Result<CapturedNote> {
+        let (issue, description) = match self.account.kind {
+            TrackerKind::Jira => {
+                let body = self.jira_get(&format!(
+                    "/rest/api/2/issue/{}?fields=summary,status,description",
+                    percent_encode(key)
+                ))?;
+                let description = body
+                    .pointer("/fields/description")
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+                (parse_jira_issue(self.site(), &body), description)
+            }
+            TrackerKind::Linear => {
+                let body = self.linear_post(&json!({
+                    "query": format!(
+                        "query($id: String!) {{ issue(id: $id) {{ {LINEAR_ISSUE_FIELDS} description }} }}"
+                    ),
+                    "variables": {"id": key},
+                }))?;
+                let issue = body.pointer("/data/issue").cloned().unwrap_or_default();
+                let description = issue
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+                (parse_linear_issue(&issue), description)
+            }
+        };
+        let issue = issue.ok_or_else(|| anyhow!("issue {key} not found"))?;
+        Ok(CapturedNote {
+            title: format!("{} {}", issue.key, issue.title),
+            body: description.unwrap_or_default(),
+            url: issue.url,
+            tags: vec![self.account.kind.tag().to_string()],
+        })
+    }
+
+    fn jira_get(&self, path: &str) -> Result<Value> {
+        let credentials = format!(
+            "{}:{}",
+            self.account.email.as_deref().unwrap_or_default(),
+            self.account.token
+        );
+        let response = ureq::get(&format!("https://{}{path}", self.site()))
+            .timeout(REQUEST_TIMEOUT)
+            .set("Accept", "application/json")
+            .set(
+                "Authorization",
+                &format!("Basic {}", base64_encode(credentials.as_bytes())),
+            )
+            .call();
+        read_json_response(response, self.account.kind.title())
+    }
+
+    fn linear_post(&self, request: &Value) -> Result<Value> {
+        let response = ureq::post(LINEAR_API_URL)
+            .timeout(REQUEST_TIMEOUT)
+            .set("Content-Type", "application/json")
+            .set("Authorization", &self.account.token)
+            .send_string(&request.to_string());
+        let body = read_json_response(response, self.account.kind.title())?;
+        if let Some(message) = body.pointer("/errors/0/message").and_then(Value::as_str) {
+            return Err(anyhow!("Linear returned an error: {message}"));
+        }
+        Ok(body)
+    }
+}
+
+impl ResultProvider for TrackerProvider {
+    fn id(&self) -> &str {
+        self.account.kind.provider_id()
+    }
+
+    fn title(&self) -> &str {
+        self.account.kind.title()
+    }
+
+    fn prefix(&self) -> Option<&str> {
+        Some(self.account.kind.prefix())
+    }
+
+    fn icon(&self) -> &str {
+        "checklist"
+    }
+
+    fn results(&self, query: &str, limit: usize) -> Result<Vec<ProviderResult>> {
+        let (query, capturing) = match parse_query(query) {
+            TrackerQuery::Search(query) => (query, false),
+            TrackerQuery::Capture(query) => (query, true),
+        };
+        let issues = match self.search(query) {
+            Ok(issues) => issues,
+            Err(err) => {
+                return Ok(vec![ProviderResult {
+                    title: format!("{} can't be reached", self.account.kind.title()),
+                    subtitle: format!("{err:#}"),
+                    icon: Some("wifi.exclamationmark".to_string()),
+                    action: ProviderAction::OpenUrl(self.account.home_url()),
+                }]);
+            }
+        };
+        let tag = self.account.kind.tag();
+        Ok(issues
+            .into_iter()
+            .take(limit)
+            .map(|issue| {
+                let status = issue.status.as_deref().unwrap_or("Issue");
+                if capturing {
+                    ProviderResult {
+                        title: format!("Capture {} {}", issue.key, issue.title),
+                        subtitle: format!("{status} · save as a note tagged #{tag}"),
+                        icon: Some("square.and.arrow.down".to_string()),
+                        action: ProviderAction::Run(issue.key),
+                    }
+                } else {
+                    ProviderResult {
+                        title: format!("{} {}", issue.key, issue.title),
+                        subtitle: format!(
+                            "{status} · \"{}{CAPTURE_KEYWORD}\" saves it as a note",
+                            self.account.kind.prefix()
+                        ),
+                        icon: None,
+                        action: ProviderAction::OpenUrl(issue.url),
+                    }
+                }
+            })
+            .collect())
+    }
+
+    fn perform(&self, key: &str) -> Result<ProviderOutcome> {
+        Ok(ProviderOutcome::CaptureNote(self.capture(key)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{
+        TrackerAccount, TrackerIssue, TrackerKind, TrackerQuery, base64_encode, is_issue_key,
+        jira_jql, parse_jira_issue, parse_linear_issue, parse_query,
+    };
+
+    #[test]
+    fn queries_search_or_capture_issues() {
+        assert_eq!(
+            parse_query(" login bug "),
+            TrackerQuery::Search("login bug")
+        );
+        assert_eq!(parse_query("captured"), TrackerQuery::Search("captured"));
+        assert_eq!(parse_query("capture"), TrackerQuery::Capture(""));
+        assert_eq!(
+            parse_query("capture  ENG-42"),
+            TrackerQuery::Capture("ENG-42")
+        );
+
+        assert!(is_issue_key("ENG-42"));
+        assert!(is_issue_key("web2-7"));
+        assert!(!is_issue_key("-42"));
+        assert!(!is_issue_key("2FA-1"));
+        assert!(!is_issue_key("log-in"));
+
+        assert_eq!(
+            jira_jql(r#"say "hi"\"#),
+            r#"text ~ "say \"hi\"\\" ORDER BY updated DESC"#
+        );
+        assert!(jira_jql("").starts_with("assignee = currentUser()"));
+    }
+
+    #[test]
+    fn issues_of_both_trackers_are_parsed() {
+        let jira = json!({"key": "OPS-3", "fields": {"summary": "Rotate keys", "status": {"name": "To Do"}}});
+        assert_eq!(
+            parse_jira_issue("team.atlassian.net", &jira),
+            Some(TrackerIssue {
+                key: "OPS-3".into(),
+                title: "Rotate keys".into(),
+                url: "https://team.atlassian.net/browse/OPS-3".into(),
+                status: Some("To Do".into()),
+            })
+        );
+        let linear = json!({"identifier": "ENG-42", "title": "Crash", "url": "https://linear.app/t/issue/ENG-42"});
+        let parsed = parse_linear_issue(&linear).unwrap();
+        assert_eq!(parsed.key, "ENG-42");
+        assert_eq!(parsed.status, None);
+        assert_eq!(parse_linear_issue(&json!({"title": "no id"})), None);
+    }
+
+    #[test]
+    fn accounts_are_normalized() {
+        let jira = TrackerAccount {
+            kind: TrackerKind::Jira,
+            site: Some("https://Team.atlassian.net/".into()),
+            email: Some(" me@example.com ".into()),
+            token: " token ".into(),
+        }
+        .normalized()
+        .unwrap();
+        assert_eq!(jira.site.as_deref(), Some("team.atlassian.net"));
+        assert_eq!(jira.email.as_deref(), Some("me@example.com"));
+        assert_eq!(jira.token, "token");
+
+        let without_email = TrackerAccount {
+            email: None,
+            ..jira.clone()
+        };
+        assert!(without_email.normalized().is_err());
+        let bad_site = TrackerAccount {
+            site: Some("team.atlassian.net/x?y".into()),
+            ..jira
+        };
+        assert!(bad_site.normalized().is_err());
+
+        let linear = TrackerAccount {
+            kind: TrackerKind::Linear,
+            site: Some("ignored".into()),
+            email: None,
+            token: "lin_api_x".into(),
+        }
+        .normalized()
+        .unwrap();
+        assert_eq!(linear.site, None);
+    }
+
+    #[test]
+    fn base64_pads_the_last_group() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"me@x.io:t0k"), "bWVAeC5pbzp0MGs=");
+    }
+}
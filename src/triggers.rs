@@ -0,0 +1,364 @@
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result, anyhow, ensure};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+
+use crate::logging::log_error;
+
+static DISPATCHER: OnceCell<Sender<TriggerJob>> = OnceCell::new();
+const MAX_ATTEMPTS: u32 = 3;
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(2);
+const SCRIPT_TIMEOUT: Duration = Duration::from_secs(30);
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+const LOG_MESSAGE_MAX_CHARS: usize = 400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerEvent {
+    Created,
+    Saved,
+    Deleted,
+}
+
+impl TriggerEvent {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TriggerEvent::Created => "created",
+            TriggerEvent::Saved => "saved",
+            TriggerEvent::Deleted => "deleted",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "target", rename_all = "snake_case")]
+pub enum TriggerAction {
+    /// Shell command run through `sh -c`; the event payload is written to stdin.
+    RunScript(String),
+    /// URL receiving the event payload as a JSON `POST` body.
+    PostUrl(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trigger {
+    pub id: String,
+    pub name: String,
+    pub event: TriggerEvent,
+    /// Only fire for items carrying this `#tag`; `None` fires for every item.
+    #[serde(default)]
+    pub tag: Option<String>,
+    pub action: TriggerAction,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Trigger {
+    pub fn matches(&self, event: TriggerEvent, item_tags: &[String]) -> bool {
+        if !self.enabled || self.event != event {
+            return false;
+        }
+
+        match self.tag.as_deref() {
+            None => true,
+            Some(tag) => item_tags.iter().any(|item_tag| item_tag == tag),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemEventPayload {
+    pub event: TriggerEvent,
+    pub item_id: i64,
+    pub title: String,
+    pub note: String,
+    pub tags: Vec<String>,
+    pub fired_at_unix_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerLogEntry {
+    pub at_unix_seconds: u64,
+    pub event: TriggerEvent,
+    pub item_id: i64,
+    pub attempt: u32,
+    pub success: bool,
+    pub message: String,
+}
+
+struct TriggerJob {
+    trigger: Trigger,
+    payload: ItemEventPayload,
+    log_dir: PathBuf,
+    attempt: u32,
+}
+
+/// Queues every trigger matching the payload on the background dispatcher.
+pub fn dispatch(triggers: &[Trigger], payload: &ItemEventPayload, log_dir: &Path) {
+    for trigger in triggers
+        .iter()
+        .filter(|trigger| trigger.matches(payload.event, &payload.tags))
+    {
+        let job = TriggerJob {
+            trigger: trigger.clone(),
+            payload: payload.clone(),
+            log_dir: log_dir.to_path_buf(),
+            attempt: 1,
+        };
+        if let Err(err) = dispatcher().send(job) {
+            log_error(
+                format_args!("failed to queue trigger '{}'", trigger.name),
+                err,
+            );
+        }
+    }
+}
+
+fn dispatcher() -> &'static Sender<TriggerJob> {
+    DISPATCHER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<TriggerJob>();
+        let spawned = std::thread::Builder::new()
+            .name("trigger-dispatcher".to_string())
+            .spawn(move || {
+                // Failed jobs wait here for their retry so new events aren't held up.
+                let mut retries: Vec<(Instant, TriggerJob)> = Vec::new();
+                loop {
+                    let received = match retries.iter().map(|(due, _)| *due).min() {
+                        Some(due) => {
+                            receiver.recv_timeout(due.saturating_duration_since(Instant::now()))
+                        }
+                        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                    };
+                    match received {
+                        Ok(job) => retries.extend(run_attempt(job)),
+                        Err(RecvTimeoutError::Timeout) => {}
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                    let now = Instant::now();
+                    let (due, waiting): (Vec<_>, Vec<_>) =
+                        retries.into_iter().partition(|(due, _)| *due <= now);
+                    retries = waiting;
+                    for (_, job) in due {
+                        retries.extend(run_attempt(job));
+                    }
+                }
+            });
+        // Without the thread the receiver is gone, so every send fails and is reported.
+        if let Err(err) = spawned {
+            log_error("failed to spawn trigger dispatcher thread", err);
+        }
+        sender
+    })
+}
+
+/// Runs one attempt of the job and logs it; a failed job comes back with the
+/// time of its next attempt until it runs out of attempts.
+fn run_attempt(job: TriggerJob) -> Option<(Instant, TriggerJob)> {
+    let outcome = execute_action(&job.trigger.action, &job.payload);
+    let entry = TriggerLogEntry {
+        at_unix_seconds: unix_timestamp(),
+        event: job.payload.event,
+        item_id: job.payload.item_id,
+        attempt: job.attempt,
+        success: outcome.is_ok(),
+        message: match &outcome {
+            Ok(message) => truncate_message(message),
+            Err(err) => truncate_message(&format!("{err:#}")),
+        },
+    };
+    if let Err(err) = append_log_entry(&job.log_dir, &job.trigger.id, &entry) {
+        log_error("failed to write trigger log", err);
+    }
+
+    if outcome.is_ok() || job.attempt >= MAX_ATTEMPTS {
+        return None;
+    }
+    let due = Instant::now() + RETRY_BASE_DELAY * 2u32.pow(job.attempt - 1);
+    Some((
+        due,
+        TriggerJob {
+            attempt: job.attempt + 1,
+            ..job
+        },
+    ))
+}
+
+fn execute_action(action: &TriggerAction, payload: &ItemEventPayload) -> Result<String> {
+    let body = serde_json::to_string(payload).context("failed to serialize trigger payload")?;
+    match action {
+        TriggerAction::RunScript(command) => run_script(command, payload, &body),
+        TriggerAction::PostUrl(url) => post_json(url, &body),
+    }
+}
+
+fn run_script(command: &str, payload: &ItemEventPayload, body: &str) -> Result<String> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("ALFRED_EVENT", payload.event.as_str())
+        .env("ALFRED_ITEM_ID", payload.item_id.to_string())
+        .env("ALFRED_ITEM_TITLE", &payload.title)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("failed to start script: {command}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // Written on its own thread so a script that never reads stdin still
+        // times out; one that closes the pipe early is not a failure.
+        let body = body.to_string();
+        let writer = std::thread::Builder::new()
+            .name("trigger-script-stdin".to_string())
+            .spawn(move || {
+                let _ = stdin.write_all(body.as_bytes());
+            });
+        if let Err(err) = writer {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(anyhow!("failed to write script input: {err}"));
+        }
+    }
+
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait().context("failed to poll script")? {
+            ensure!(status.success(), "script exited with {status}");
+            return Ok(format!("script exited with {status}"));
+        }
+        if started.elapsed() >= SCRIPT_TIMEOUT {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(anyhow!(
+                "script timed out after {}s",
+                SCRIPT_TIMEOUT.as_secs()
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn post_json(url: &str, body: &str) -> Result<String> {
+    let response = ureq::post(url)
+        .timeout(HTTP_TIMEOUT)
+        .set("Content-Type", "application/json")
+        .send_string(body)
+        .map_err(|err| anyhow!("POST {url} failed: {err}"))?;
+    Ok(format!("HTTP {}", response.status()))
+}
+
+fn log_path(log_dir: &Path, trigger_id: &str) -> PathBuf {
+    let safe_id: String = trigger_id
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    log_dir.join(format!("{safe_id}.jsonl"))
+}
+
+fn append_log_entry(log_dir: &Path, trigger_id: &str, entry: &TriggerLogEntry) -> Result<()> {
+    std::fs::create_dir_all(log_dir)
+        .with_context(|| format!("failed to create trigger log dir {}", log_dir.display()))?;
+    let path = log_path(log_dir, trigger_id);
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("failed to open trigger log {}", path.display()))?;
+    let line = serde_json::to_string(entry).context("failed to serialize trigger log entry")?;
+    writeln!(file, "{line}")
+        .with_context(|| format!("failed to append trigger log {}", path.display()))
+}
+
+/// Returns the newest `limit` log entries of a trigger, newest first.
+pub fn read_log(log_dir: &Path, trigger_id: &str, limit: usize) -> Result<Vec<TriggerLogEntry>> {
+    let path = log_path(log_dir, trigger_id);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let file = std::fs::File::open(&path)
+        .with_context(|| format!("failed to open trigger log {}", path.display()))?;
+    let mut entries: Vec<TriggerLogEntry> = BufReader::new(file)
+        .lines()
+        .map_while(std::io::Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect();
+    entries.reverse();
+    entries.truncate(limit);
+    Ok(entries)
+}
+
+pub fn remove_log(log_dir: &Path, trigger_id: &str) -> Result<()> {
+    let path = log_path(log_dir, trigger_id);
+    if path.exists() {
+        std::fs::remove_file(&path)
+            .with_context(|| format!("failed removing trigger log {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn truncate_message(message: &str) -> String {
+    message.chars().take(LOG_MESSAGE_MAX_CHARS).collect()
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Trigger, TriggerAction, TriggerEvent};
+
+    fn trigger(event: TriggerEvent, tag: Option<&str>) -> Trigger {
+        Trigger {
+            id: "t1".to_string(),
+            name: "test".to_string(),
+            event,
+            tag: tag.map(str::to_string),
+            action: TriggerAction::PostUrl("http://localhost".to_string()),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn trigger_matches_event_and_optional_tag() {
+        let tags = vec!["todo".to_string()];
+        assert!(trigger(TriggerEvent::Saved, Some("todo")).matches(TriggerEvent::Saved, &tags));
+        assert!(trigger(TriggerEvent::Saved, None).matches(TriggerEvent::Saved, &[]));
+        assert!(!trigger(TriggerEvent::Saved, Some("work")).matches(TriggerEvent::Saved, &tags));
+        assert!(!trigger(TriggerEvent::Created, None).matches(TriggerEvent::Deleted, &tags));
+    }
+
+    #[test]
+    fn disabled_trigger_never_matches() {
+        let mut disabled = trigger(TriggerEvent::Created, None);
+        disabled.enabled = false;
+        assert!(!disabled.matches(TriggerEvent::Created, &[]));
+    }
+
+    #[test]
+    fn trigger_action_round_trips_through_json() {
+        let original = trigger(TriggerEvent::Deleted, Some("todo"));
+        let json = serde_json::to_string(&original).unwrap();
+        let parsed: Trigger = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.action, original.action);
+        assert_eq!(parsed.tag.as_deref(), Some("todo"));
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{Days, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+
+/// Once the log grows past this, events older than `RETAINED_DAYS` are dropped.
+const COMPACT_THRESHOLD_BYTES: u64 = 1_000_000;
+const RETAINED_DAYS: u64 = 90;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageEventKind {
+    /// A launcher query the user acted on.
+    Search,
+    /// An item opened in the editor.
+    Open,
+}
+
+/// One line of the usage log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageEvent {
+    pub at_unix_seconds: u64,
+    pub kind: UsageEventKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<i64>,
+}
+
+/// Searches per local day, oldest first, and how often each item was opened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageSummary {
+    pub searches_per_day: Vec<(NaiveDate, u32)>,
+    /// Most opened first; ties go to the lower id.
+    pub opens_by_item: Vec<(i64, u32)>,
+}
+
+pub fn append_event(path: &Path, event: &UsageEvent) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create usage log dir {}", dir.display()))?;
+    }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open usage log {}", path.display()))?;
+    let line = serde_json::to_string(event).context("failed to serialize usage event")?;
+    writeln!(file, "{line}")
+        .with_context(|| format!("failed to append usage log {}", path.display()))?;
+    drop(file);
+
+    if std::fs::metadata(path).map(|meta| meta.len()).unwrap_or(0) > COMPACT_THRESHOLD_BYTES {
+        compact(path, event.at_unix_seconds)?;
+    }
+    Ok(())
+}
+
+/// Oldest first; a missing log has no events and unreadable lines are skipped.
+pub fn read_events(path: &Path) -> Result<Vec<UsageEvent>> {
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(err)
+                .with_context(|| format!("failed to open usage log {}", path.display()));
+        }
+    };
+    Ok(BufReader::new(file)
+        .lines()
+        .map_while(std::io::Result::ok)
+        .filter_map(|line| serde_json::from_str(&line).ok())
+        .collect())
+}
+
+fn compact(path: &Path, now: u64) -> Result<()> {
+    let cutoff = now.saturating_sub(RETAINED_DAYS * 24 * 60 * 60);
+    let mut payload = String::new();
+    for event in read_events(path)? {
+        if event.at_unix_seconds >= cutoff {
+            payload.push_str(
+                &serde_json::to_string(&event).context("failed to serialize usage event")?,
+            );
+            payload.push('\n');
+        }
+    }
+    let tmp_path = path.with_extension("jsonl.tmp");
+    std::fs::write(&tmp_path, payload)
+        .with_context(|| format!("failed to write usage log {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, path)
+        .with_context(|| format!("failed to replace usage log {}", path.display()))
+}
+
+/// Summarizes `events` over the `days` local days ending on `today`; every day
+/// is listed, including those without searches.
+pub fn summarize<Tz: TimeZone>(
+    events: &[UsageEvent],
+    tz: &Tz,
+    today: NaiveDate,
+    days: u32,
+) -> UsageSummary {
+    let first_day = today
+        .checked_sub_days(Days::new(u64::from(days.saturating_sub(1))))
+        .unwrap_or(today);
+    let mut searches: BTreeMap<NaiveDate, u32> = first_day
+        .iter_days()
+        .take_while(|date| *date <= today)
+        .map(|date| (date, 0))
+        .collect();
+    let mut opens: HashMap<i64, u32> = HashMap::new();
+
+    for event in events {
+        match event.kind {
+            UsageEventKind::Search => {
+                let Some(date) = i64::try_from(event.at_unix_seconds)
+                    .ok()
+                    .and_then(|seconds| tz.timestamp_opt(seconds, 0).single())
+                    .map(|local| local.date_naive())
+                else {
+                    continue;
+                };
+                if let Some(count) = searches.get_mut(&date) {
+                    *count += 1;
+                }
+            }
+            UsageEventKind::Open => {
+                if let Some(item_id) = event.item_id {
+                    *opens.entry(item_id).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    let mut opens_by_item: Vec<(i64, u32)> = opens.into_iter().collect();
+    opens_by_item.sort_by_key(|&(item_id, count)| (std::cmp::Reverse(count), item_id));
+    UsageSummary {
+        searches_per_day: searches.into_iter().collect(),
+        opens_by_item,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UsageEvent, UsageEventKind, summarize};
+    use chrono::{NaiveDate, Utc};
+
+    fn event(at_unix_seconds: u64, kind: UsageEventKind, item_id: Option<i64>) -> UsageEvent {
+        UsageEvent {
+            at_unix_seconds,
+            kind,
+            item_id,
+        }
+    }
+
+    #[test]
+    fn summarize_counts_searches_per_day_and_ranks_opened_items() {
+        const DAY: u64 = 24 * 60 * 60;
+        // 2024-01-03 00:00 UTC.
+        let jan_3 = 1_704_240_000;
+        let events = vec![
+            event(jan_3 - 5 * DAY, UsageEventKind::Search, None),
+            event(jan_3 - DAY, UsageEventKind::Search, None),
+            event(jan_3 + 60, UsageEventKind::Search, None),
+            event(jan_3 + 120, UsageEventKind::Search, None),
+            event(jan_3, UsageEventKind::Open, Some(7)),
+            event(jan_3, UsageEventKind::Open, Some(3)),
+            event(jan_3, UsageEventKind::Open, Some(7)),
+            event(jan_3, UsageEventKind::Open, Some(5)),
+        ];
+        let today = NaiveDate::from_ymd_opt(2024, 1, 3).unwrap();
+
+        let summary = summarize(&events, &Utc, today, 3);
+        assert_eq!(
+            summary.searches_per_day,
+            vec![
+                (NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), 0),
+                (NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(), 1),
+                (today, 2),
+            ]
+        );
+        assert_eq!(summary.opens_by_item, vec![(7, 2), (3, 1), (5, 1)]);
+    }
+}
@@ -0,0 +1,456 @@
+//! Conversion between notes and the Markdown files of an Obsidian-style vault.
+//!
+//! A vault note keeps the item id in its front matter so the file can be
+//! matched to its item after either side renames it. Bold text maps to `**`,
+//! inline images to `![[attachments/file|width]]` embeds, and `[[wiki links]]`
+//! are kept as they are in both directions. Org files in the vault go
+//! through `crate::org` instead.
+
+/// Front matter key holding the item id.
+pub const VAULT_ID_KEY: &str = "alfred-id";
+/// Folder inside the vault for embedded images.
+pub const ATTACHMENTS_DIR_NAME: &str = "attachments";
+/// Prefix of attachment files written by the app, so other files in the
+/// folder are never pruned.
+pub const ATTACHMENT_FILE_PREFIX: &str = "alfred-";
+
+pub const INLINE_IMAGE_URL_PREFIX: &str = "alfred://image/";
+/// Alt text of image markers that have none of their own.
+pub const DEFAULT_IMAGE_ALT: &str = "image";
+pub const BOLD_TOKEN: &str = "[[b]]";
+pub const BOLD_END_TOKEN: &str = "[[/b]]";
+const FRONT_MATTER_FENCE: &str = "---";
+
+/// The kind of note file in the vault. New notes are Markdown; a note added
+/// as an org file keeps that format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaultFormat {
+    Markdown,
+    Org,
+}
+
+impl VaultFormat {
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "md" => Some(Self::Markdown),
+            "org" => Some(Self::Org),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Org => "org",
+        }
+    }
+}
+
+/// A vault file split into the item id from its front matter and its body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaultNote {
+    pub id: Option<i64>,
+    pub body: String,
+}
+
+/// The vault file for item `id`: front matter with the id, then the note as
+/// Markdown. `file_for_key` names the attachment of an image key.
+pub fn render_vault_note(
+    id: i64,
+    note: &str,
+    file_for_key: impl Fn(&str) -> Option<String>,
+) -> String {
+    let body = note_to_markdown(note, file_for_key);
+    format!("{FRONT_MATTER_FENCE}\n{VAULT_ID_KEY}: {id}\n{FRONT_MATTER_FENCE}\n{body}")
+}
+
+/// Splits off the front matter of a vault file; files without one have no id.
+pub fn parse_vault_note(markdown: &str) -> VaultNote {
+    let markdown = markdown.strip_prefix('\u{feff}').unwrap_or(markdown);
+    let Some(rest) = markdown.strip_prefix(FRONT_MATTER_FENCE).and_then(|rest| {
+        rest.strip_prefix('\n')
+            .or_else(|| rest.strip_prefix("\r\n"))
+    }) else {
+        return VaultNote {
+            id: None,
+            body: markdown.to_string(),
+        };
+    };
+
+    let mut id = None;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        offset += line.len();
+        let line = line.trim_end();
+        if line == FRONT_MATTER_FENCE {
+            return VaultNote {
+                id,
+                body: rest[offset..].to_string(),
+            };
+        }
+        if let Some(value) = line
+            .strip_prefix(VAULT_ID_KEY)
+            .and_then(|value| value.strip_prefix(':'))
+        {
+            id = value.trim().parse().ok();
+        }
+    }
+
+    // An unclosed fence is not front matter.
+    VaultNote {
+        id: None,
+        body: markdown.to_string(),
+    }
+}
+
+/// The note as vault Markdown; font size tokens have no Markdown form and are
+/// dropped.
+pub fn note_to_markdown(note: &str, file_for_key: impl Fn(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(note.len());
+    let mut cursor = 0;
+
+    while let Some(start_rel) = note[cursor..].find(['!', '[']) {
+        let start = cursor + start_rel;
+        output.push_str(&note[cursor..start]);
+        let rest = &note[start..];
+
+        if let Some((key, width, len)) = parse_inline_image(rest) {
+            match file_for_key(key) {
+                Some(file) => {
+                    output.push_str(&format!("![[{ATTACHMENTS_DIR_NAME}/{file}"));
+                    if let Some(width) = width {
+                        output.push_str(&format!("|{width}"));
+                    }
+                    output.push_str("]]");
+                }
+                None => output.push_str(&rest[..len]),
+            }
+            cursor = start + len;
+        } else if let Some(token_len) = style_token_len(rest) {
+            let token = &rest[..token_len];
+            if token == BOLD_TOKEN || token == BOLD_END_TOKEN {
+                output.push_str("**");
+            }
+            cursor = start + token_len;
+        } else {
+            output.push_str(&rest[..1]);
+            cursor = start + 1;
+        }
+    }
+
+    output.push_str(&note[cursor..]);
+    output
+}
+
+/// The note for vault Markdown. `key_for_file` gives the image key of an
+/// embedded file (as written in the embed); embeds it can't resolve and wiki
+/// links stay as text.
+pub fn markdown_to_note(
+    markdown: &str,
+    mut key_for_file: impl FnMut(&str) -> Option<String>,
+) -> String {
+    let mut output = String::with_capacity(markdown.len());
+    let mut cursor = 0;
+
+    while let Some(start_rel) = markdown[cursor..].find("![[") {
+        let start = cursor + start_rel;
+        output.push_str(&bold_to_tokens(&markdown[cursor..start]));
+        let target_start = start + 3;
+        let Some(end_rel) = markdown[target_start..].find("]]") else {
+            break;
+        };
+        let end = target_start + end_rel;
+        let (file, width) = match markdown[target_start..end].split_once('|') {
+            Some((file, width)) => (file, width.trim().parse::<u32>().ok()),
+            None => (&markdown[target_start..end], None),
+        };
+
+        match key_for_file(file.trim()) {
+            Some(key) => {
+                output.push_str(&format!("![image]({INLINE_IMAGE_URL_PREFIX}{key}"));
+                if let Some(width) = width {
+                    output.push_str(&format!("?w={width}"));
+                }
+                output.push(')');
+            }
+            None => output.push_str(&markdown[start..end + 2]),
+        }
+        cursor = end + 2;
+    }
+
+    output.push_str(&bold_to_tokens(&markdown[cursor..]));
+    output
+}
+
+/// Extension for an attachment, from the image's leading bytes; `None` when
+/// they aren't a known image format.
+pub fn image_extension(bytes: &[u8]) -> Option<&'static str> {
+    let extension = match bytes {
+        [0x89, b'P', b'N', b'G', ..] => "png",
+        [0xff, 0xd8, 0xff, ..] => "jpg",
+        [b'G', b'I', b'F', b'8', ..] => "gif",
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'E',
+            b'B',
+            b'P',
+            ..,
+        ] => "webp",
+        [
+            _,
+            _,
+            _,
+            _,
+            b'f',
+            b't',
+            b'y',
+            b'p',
+            b'h',
+            b'e',
+            b'i',
+            b'c',
+            ..,
+        ] => "heic",
+        _ => return None,
+    };
+    Some(extension)
+}
+
+/// Pairs of `**` become bold tokens; an unpaired one stays as text.
+fn bold_to_tokens(text: &str) -> String {
+    let parts: Vec<&str> = text.split("**").collect();
+    if parts.len() < 3 {
+        return text.to_string();
+    }
+    let paired = if parts.len() % 2 == 1 {
+        parts.len()
+    } else {
+        parts.len() - 1
+    };
+    let mut output = String::with_capacity(text.len());
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            output.push_str(match index {
+                index if index >= paired => "**",
+                index if index % 2 == 1 => BOLD_TOKEN,
+                _ => BOLD_END_TOKEN,
+            });
+        }
+        output.push_str(part);
+    }
+    output
+}
+
+/// Key, width and length of an `![..](alfred://image/key?w=..)` token at the
+/// start of `text`.
+pub fn parse_inline_image(text: &str) -> Option<(&str, Option<u32>, usize)> {
+    let rest = text.strip_prefix("![")?;
+    let alt_end = rest.find("](")?;
+    if rest[..alt_end].contains('\n') {
+        return None;
+    }
+    let url_start = 2 + alt_end + 2;
+    let url_len = text[url_start..].find(')')?;
+    let url = text[url_start..url_start + url_len].strip_prefix(INLINE_IMAGE_URL_PREFIX)?;
+    let (key, query) = url.split_once('?').unwrap_or((url, ""));
+    let width = query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("w="))
+        .and_then(|width| width.parse::<f64>().ok())
+        .map(|width| width.round() as u32);
+    Some((key, width, url_start + url_len + 1))
+}
+
+/// An inline image token in a note; `start..end` is its byte range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineImageMarker {
+    pub key: String,
+    pub width: Option<u32>,
+    pub alt: Option<String>,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The inline image tokens of `note` in order.
+pub fn inline_image_markers(note: &str) -> Vec<InlineImageMarker> {
+    let mut markers = Vec::new();
+    let mut cursor = 0;
+    while let Some(start_rel) = note[cursor..].find("![") {
+        let start = cursor + start_rel;
+        match parse_inline_image(&note[start..]) {
+            Some((key, width, len)) => {
+                markers.push(InlineImageMarker {
+                    key: key.to_string(),
+                    width,
+                    alt: inline_image_alt(&note[start..start + len]).map(str::to_string),
+                    start,
+                    end: start + len,
+                });
+                cursor = start + len;
+            }
+            None => cursor = start + 2,
+        }
+    }
+    markers
+}
+
+/// The alt text of an inline image token, or `None` when it has only the
+/// default `image`.
+pub fn inline_image_alt(token: &str) -> Option<&str> {
+    let alt = token.strip_prefix("![")?.split_once("](")?.0.trim();
+    (!alt.is_empty() && alt != DEFAULT_IMAGE_ALT).then_some(alt)
+}
+
+/// `note` with image `index` moved to just before line `target_line`
+/// (0-based; past the last line appends). An image alone on its line moves
+/// with the line; one inside text is cut out and gets a line of its own.
+/// `None` when the note has no image `index`.
+pub fn move_inline_image_to_line(note: &str, index: usize, target_line: usize) -> Option<String> {
+    let marker = inline_image_markers(note).into_iter().nth(index)?;
+    let line_start = note[..marker.start].rfind('\n').map_or(0, |pos| pos + 1);
+    let line_end = note[marker.end..]
+        .find('\n')
+        .map_or(note.len(), |pos| marker.end + pos);
+    let line_index = note[..line_start].matches('\n').count();
+
+    let mut lines: Vec<String> = note.split('\n').map(str::to_string).collect();
+    let target = target_line.min(lines.len());
+    let marker_text = &note[marker.start..marker.end];
+    let alone = note[line_start..marker.start].trim().is_empty()
+        && note[marker.end..line_end].trim().is_empty();
+
+    if alone {
+        if target == line_index || target == line_index + 1 {
+            return Some(note.to_string());
+        }
+        let line = lines.remove(line_index);
+        let target = if target > line_index {
+            target - 1
+        } else {
+            target
+        };
+        lines.insert(target, line);
+    } else {
+        let before = note[line_start..marker.start].trim_end_matches([' ', '\t']);
+        let after = note[marker.end..line_end].trim_start_matches([' ', '\t']);
+        let separator = if before.is_empty() || after.is_empty() {
+            ""
+        } else {
+            " "
+        };
+        lines[line_index] = format!("{before}{separator}{after}");
+        lines.insert(target, marker_text.to_string());
+    }
+    Some(lines.join("\n"))
+}
+
+/// Length of a `[[b]]`, `[[/b]]`, `[[fs=..]]` or `[[/fs]]` token at the start of `text`.
+pub fn style_token_len(text: &str) -> Option<usize> {
+    let end = text.strip_prefix("[[")?.find("]]")? + 4;
+    let token = &text[..end];
+    let is_style = matches!(token, BOLD_TOKEN | BOLD_END_TOKEN | "[[/fs]]")
+        || token
+            .strip_prefix("[[fs=")
+            .and_then(|rest| rest.strip_suffix("]]"))
+            .is_some_and(|size| size.parse::<f32>().is_ok());
+    is_style.then_some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notes_round_trip_through_vault_markdown() {
+        let note = "[[b]]Plan[[/b]] for [[Project X]]\n![image](alfred://image/img-1-ab?w=320)\n[[fs=18]]big[[/fs]] text";
+        let markdown = note_to_markdown(note, |key| Some(format!("alfred-{key}.png")));
+        assert_eq!(
+            markdown,
+            "**Plan** for [[Project X]]\n![[attachments/alfred-img-1-ab.png|320]]\nbig text"
+        );
+
+        let restored = markdown_to_note(&markdown, |file| {
+            (file == "attachments/alfred-img-1-ab.png").then(|| "img-1-ab".to_string())
+        });
+        assert_eq!(
+            restored,
+            "[[b]]Plan[[/b]] for [[Project X]]\n![image](alfred://image/img-1-ab?w=320)\nbig text"
+        );
+    }
+
+    #[test]
+    fn markdown_to_note_keeps_unresolved_embeds_and_unpaired_bold() {
+        assert_eq!(
+            markdown_to_note("![[missing.png]] a ** b", |_| None),
+            "![[missing.png]] a ** b"
+        );
+        assert_eq!(
+            markdown_to_note("**a** and **b** **", |_| None),
+            "[[b]]a[[/b]] and [[b]]b[[/b]] **"
+        );
+    }
+
+    #[test]
+    fn parse_vault_note_reads_the_id_from_front_matter() {
+        let rendered = render_vault_note(42, "body", |_| None);
+        assert_eq!(
+            parse_vault_note(&rendered),
+            VaultNote {
+                id: Some(42),
+                body: "body".into(),
+            }
+        );
+        assert_eq!(parse_vault_note("just text").id, None);
+        assert_eq!(parse_vault_note("---\nunclosed").body, "---\nunclosed");
+    }
+
+    #[test]
+    fn image_extension_only_knows_images() {
+        assert_eq!(image_extension(b"\x89PNG\r\n"), Some("png"));
+        assert_eq!(image_extension(b"\xff\xd8\xff\xe0"), Some("jpg"));
+        assert_eq!(image_extension(b"RIFF\0\0\0\0WEBPVP8 "), Some("webp"));
+        assert_eq!(image_extension(b"root:x:0:0:root"), None);
+    }
+
+    #[test]
+    fn inline_images_move_to_other_lines() {
+        let a = "![image](alfred://image/img-a?w=200)";
+        let b = "![image](alfred://image/img-b)";
+        let note = format!("one\n{a}\ntwo {b} three\nfour");
+
+        let markers = inline_image_markers(&note);
+        assert_eq!(markers.len(), 2);
+        assert_eq!(
+            (markers[0].key.as_str(), markers[0].width),
+            ("img-a", Some(200))
+        );
+        assert_eq!(markers[0].alt, None);
+        assert_eq!(&note[markers[1].start..markers[1].end], b);
+
+        assert_eq!(
+            move_inline_image_to_line(&note, 0, 4).unwrap(),
+            format!("one\ntwo {b} three\nfour\n{a}")
+        );
+        assert_eq!(
+            move_inline_image_to_line(&note, 0, 0).unwrap(),
+            format!("{a}\none\ntwo {b} three\nfour")
+        );
+        assert_eq!(move_inline_image_to_line(&note, 0, 2).unwrap(), note);
+        assert_eq!(
+            move_inline_image_to_line(&note, 1, 1).unwrap(),
+            format!("one\n{b}\n{a}\ntwo three\nfour")
+        );
+        assert_eq!(move_inline_image_to_line(&note, 2, 0), None);
+
+        let described = inline_image_markers("![Q3 revenue chart](alfred://image/img-c)");
+        assert_eq!(described[0].alt.as_deref(), Some("Q3 revenue chart"));
+    }
+}
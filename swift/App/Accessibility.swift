@@ -0,0 +1,53 @@
+import AppKit
+
+/// Spoken announcements for changes VoiceOver can't see on its own, like the
+/// launcher selection moving while focus stays in the search field.
+enum AccessibilityAnnouncer {
+    static func announce(_ message: String, priority: NSAccessibilityPriorityLevel = .medium) {
+        guard NSWorkspace.shared.isVoiceOverEnabled, !message.isEmpty else {
+            return
+        }
+        let element: Any = NSApp.keyWindow ?? NSApp as Any
+        NSAccessibility.post(
+            element: element,
+            notification: .announcementRequested,
+            userInfo: [
+                .announcement: message,
+                .priority: priority.rawValue,
+            ]
+        )
+    }
+
+    /// "3 results" for a result list of that size.
+    static func resultCountDescription(_ count: Int) -> String {
+        switch count {
+        case 0:
+            return "No results"
+        case 1:
+            return "1 result"
+        default:
+            return "\(count) results"
+        }
+    }
+}
+
+extension SearchResultRecord {
+    /// The row as one spoken phrase: title, subtitle or snippet without its
+    /// highlight markers, checklist progress and pinned state.
+    var accessibilityDescription: String {
+        var parts = [title]
+        if let snippet, !snippet.isEmpty {
+            let plain = snippet.replacingOccurrences(of: "**", with: "")
+            parts.append(LauncherViewModel.imageKey(fromSnippetSource: snippetSource) != nil ? "Text in image: \(plain)" : plain)
+        } else if kind != .item, !subtitle.isEmpty {
+            parts.append(subtitle)
+        }
+        if let checklistProgress {
+            parts.append("\(checklistProgress.done) of \(checklistProgress.total) done")
+        }
+        if pinned {
+            parts.append("Pinned")
+        }
+        return parts.joined(separator: ", ")
+    }
+}
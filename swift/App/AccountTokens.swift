@@ -0,0 +1,104 @@
+import Foundation
+import Security
+
+struct AccountTokenError: LocalizedError {
+    let status: OSStatus
+
+    var errorDescription: String? {
+        let message = SecCopyErrorMessageString(status, nil) as String? ?? "error \(status)"
+        return "The Keychain did not accept the account token (\(message))."
+    }
+}
+
+/// Keeps the API tokens of forge and tracker accounts in the Keychain. The
+/// backend stores the accounts without them, so it gets the tokens on every
+/// save and once after launch.
+enum AccountTokens {
+    private static let keychainService = "AlfredAlternative.AccountTokens"
+
+    static func forgeAccounts(_ accounts: [ForgeAccountRecord]) -> [ForgeAccountRecord] {
+        accounts.map { account in
+            var account = account
+            account.token = read(account: forgeAccount(account.kind)) ?? ""
+            return account
+        }
+    }
+
+    static func trackerAccounts(_ accounts: [TrackerAccountRecord]) -> [TrackerAccountRecord] {
+        accounts.map { account in
+            var account = account
+            account.token = read(account: trackerAccount(account.kind)) ?? ""
+            return account
+        }
+    }
+
+    /// Stores the tokens of `accounts` and forgets those of forges not listed.
+    static func storeForgeTokens(_ accounts: [ForgeAccountRecord]) throws {
+        for kind in [ForgeKindRecord.gitHub, .gitLab] {
+            let token = accounts.first(where: { $0.kind == kind })?.token
+            try store(token, account: forgeAccount(kind))
+        }
+    }
+
+    /// Stores the tokens of `accounts` and forgets those of trackers not listed.
+    static func storeTrackerTokens(_ accounts: [TrackerAccountRecord]) throws {
+        for kind in [TrackerKindRecord.jira, .linear] {
+            let token = accounts.first(where: { $0.kind == kind })?.token
+            try store(token, account: trackerAccount(kind))
+        }
+    }
+
+    private static func forgeAccount(_ kind: ForgeKindRecord) -> String {
+        switch kind {
+        case .gitHub:
+            return "forge.github"
+        case .gitLab:
+            return "forge.gitlab"
+        }
+    }
+
+    private static func trackerAccount(_ kind: TrackerKindRecord) -> String {
+        switch kind {
+        case .jira:
+            return "tracker.jira"
+        case .linear:
+            return "tracker.linear"
+        }
+    }
+
+    private static func baseQuery(account: String) -> [String: Any] {
+        [
+            kSecClass as String: kSecClassGenericPassword,
+            kSecAttrService as String: keychainService,
+            kSecAttrAccount as String: account,
+            kSecUseDataProtectionKeychain as String: true,
+        ]
+    }
+
+    private static func read(account: String) -> String? {
+        var query = baseQuery(account: account)
+        query[kSecReturnData as String] = true
+        query[kSecMatchLimit as String] = kSecMatchLimitOne
+        var result: AnyObject?
+        guard SecItemCopyMatching(query as CFDictionary, &result) == errSecSuccess,
+              let data = result as? Data else {
+            return nil
+        }
+        return String(data: data, encoding: .utf8)
+    }
+
+    /// Replaces the token; `nil` removes it.
+    private static func store(_ token: String?, account: String) throws {
+        SecItemDelete(baseQuery(account: account) as CFDictionary)
+        guard let token else {
+            return
+        }
+        var query = baseQuery(account: account)
+        query[kSecAttrAccessible as String] = kSecAttrAccessibleAfterFirstUnlockThisDeviceOnly
+        query[kSecValueData as String] = Data(token.utf8)
+        let status = SecItemAdd(query as CFDictionary, nil)
+        guard status == errSecSuccess else {
+            throw AccountTokenError(status: status)
+        }
+    }
+}
@@ -5,6 +5,7 @@ final class AlfredAlternativeAppDelegate: NSObject, NSApplicationDelegate {
     weak var viewModel: LauncherViewModel?
 
     func applicationDidFinishLaunching(_ notification: Notification) {
+        StoreWarmUp.shared.start()
         HotKeyManager.shared.setHandler { [weak self] in
             DispatchQueue.main.async {
                 self?.viewModel?.toggleLauncherVisibilityFromHotKey()
@@ -13,6 +14,7 @@ final class AlfredAlternativeAppDelegate: NSObject, NSApplicationDelegate {
         if !HotKeyManager.shared.register() {
             NSLog("Failed to register global hotkey.")
         }
+        _ = PowerSavingMonitor.shared
     }
 
     func applicationShouldHandleReopen(_ sender: NSApplication, hasVisibleWindows flag: Bool) -> Bool {
@@ -24,6 +26,10 @@ final class AlfredAlternativeAppDelegate: NSObject, NSApplicationDelegate {
             viewModel?.revealEditorIfNeeded()
             return true
         }
+        if viewModel?.isViewWindowPresented == true {
+            viewModel?.revealViewWindowIfNeeded()
+            return true
+        }
         viewModel?.revealLauncherIfNeeded()
         return true
     }
@@ -37,6 +43,10 @@ final class AlfredAlternativeAppDelegate: NSObject, NSApplicationDelegate {
             viewModel?.revealEditorIfNeeded()
             return
         }
+        if viewModel?.isViewWindowPresented == true {
+            viewModel?.revealViewWindowIfNeeded()
+            return
+        }
 
         if NSApp.windows.contains(where: { $0.title == "Settings" && $0.isVisible }) {
             return
@@ -49,6 +59,7 @@ final class AlfredAlternativeAppDelegate: NSObject, NSApplicationDelegate {
     }
 
     func applicationWillTerminate(_ notification: Notification) {
+        viewModel?.persistSession(exitedCleanly: true)
         HotKeyManager.shared.unregister()
     }
 }
@@ -73,6 +84,13 @@ struct AlfredAlternativeApp: App {
                     appDelegate.viewModel = viewModel
                     updateChecker.checkOncePerSession()
                 }
+                .onOpenURL { url in
+                    Task {
+                        if await viewModel.openDeepLink(url) {
+                            openWindow.openEditor(for: viewModel)
+                        }
+                    }
+                }
         }
         .commands {
             CommandGroup(replacing: .appSettings) {
@@ -86,8 +104,8 @@ struct AlfredAlternativeApp: App {
         .windowStyle(.hiddenTitleBar)
         .defaultSize(width: 1040, height: 220)
 
-        Window("Editor", id: "editor") {
-            EditorWindowView()
+        WindowGroup("Editor", id: "editor", for: EditorWindowTarget.self) { $target in
+            EditorWindowView(target: target)
                 .environmentObject(viewModel)
                 .environmentObject(updateChecker)
                 .environmentObject(autoUpdater)
@@ -106,5 +124,59 @@ struct AlfredAlternativeApp: App {
         .defaultSize(width: 800, height: 550)
         .defaultPosition(.center)
         .commandsRemoved()
+
+        Window("Browse Notes", id: "browse") {
+            BrowseView()
+                .environmentObject(viewModel)
+                .environmentObject(themeManager)
+        }
+        .defaultSize(width: 1000, height: 680)
+        .defaultPosition(.center)
+        .commandsRemoved()
+
+        Window("Board", id: "board") {
+            BoardView()
+                .environmentObject(viewModel)
+                .environmentObject(themeManager)
+        }
+        .defaultSize(width: 960, height: 600)
+        .defaultPosition(.center)
+        .commandsRemoved()
+
+        Window("Calendar", id: "calendar") {
+            CalendarView()
+                .environmentObject(viewModel)
+                .environmentObject(themeManager)
+        }
+        .defaultSize(width: 860, height: 640)
+        .defaultPosition(.center)
+        .commandsRemoved()
+
+        Window("Stats", id: "stats") {
+            StatsView()
+                .environmentObject(viewModel)
+                .environmentObject(themeManager)
+        }
+        .defaultSize(width: 720, height: 600)
+        .defaultPosition(.center)
+        .commandsRemoved()
+
+        Window("Timesheet", id: "time_report") {
+            TimeReportView()
+                .environmentObject(viewModel)
+                .environmentObject(themeManager)
+        }
+        .defaultSize(width: 820, height: 480)
+        .defaultPosition(.center)
+        .commandsRemoved()
+
+        Window("Gallery", id: "gallery") {
+            GalleryView()
+                .environmentObject(viewModel)
+                .environmentObject(themeManager)
+        }
+        .defaultSize(width: 900, height: 640)
+        .defaultPosition(.center)
+        .commandsRemoved()
     }
 }
@@ -0,0 +1,139 @@
+import AppKit
+import LocalAuthentication
+import Security
+
+enum AppLockError: LocalizedError {
+    case locked
+    case randomBytes
+    case accessControl
+    case keychain(OSStatus)
+
+    var errorDescription: String? {
+        switch self {
+        case .locked:
+            return "Secure notes are locked."
+        case .randomBytes:
+            return "A lock key could not be generated."
+        case .accessControl:
+            return "Touch ID protection is not available for the lock key."
+        case .keychain(let status):
+            let message = SecCopyErrorMessageString(status, nil) as String? ?? "error \(status)"
+            return "The Keychain did not accept the lock key (\(message))."
+        }
+    }
+}
+
+/// Keeps the key that unlocks secure notes in the Keychain behind Touch ID
+/// (or the login password) and hands it to the backend when a secure note is
+/// opened. Secure notes lock again whenever the app goes to the background.
+@MainActor
+final class AppLockController: ObservableObject {
+    static let shared = AppLockController()
+    private static let keychainService = "AlfredAlternative.AppLock"
+    private static let keychainAccount = "secure-notes-key"
+    private static let keyLength = 32
+
+    @Published private(set) var isEnabled: Bool
+
+    private init() {
+        isEnabled = (try? RustBridgeClient.hasAppLockKey()) ?? false
+        NotificationCenter.default.addObserver(
+            forName: NSApplication.didResignActiveNotification,
+            object: nil,
+            queue: .main
+        ) { _ in
+            RustBridgeClient.lockSecureItems()
+        }
+    }
+
+    /// Makes a new key, stores it behind Touch ID and enrolls it.
+    func enable() throws {
+        var key = Data(count: Self.keyLength)
+        let status = key.withUnsafeMutableBytes { buffer in
+            SecRandomCopyBytes(kSecRandomDefault, Self.keyLength, buffer.baseAddress!)
+        }
+        guard status == errSecSuccess else {
+            throw AppLockError.randomBytes
+        }
+        try Self.storeKey(key)
+        do {
+            try RustBridgeClient.enrollLockKey(key)
+        } catch {
+            Self.deleteKey()
+            throw error
+        }
+        isEnabled = true
+    }
+
+    /// Turns the lock off; secure notes must be unlocked first.
+    func disable() async throws {
+        if (try? RustBridgeClient.isSecureItemsLocked()) == true {
+            guard await unlock(reason: "turn off the lock for secure notes") else {
+                return
+            }
+        }
+        try RustBridgeClient.removeAppLockKey()
+        Self.deleteKey()
+        isEnabled = false
+    }
+
+    /// Asks for Touch ID and unlocks secure notes. False when the prompt is
+    /// cancelled or the key is no longer in the Keychain.
+    func unlock(reason: String) async -> Bool {
+        let key = await Task.detached {
+            Self.readKey(reason: reason)
+        }.value
+        guard let key, (try? RustBridgeClient.unlock(key: key)) != nil else {
+            return false
+        }
+        AccessibilityAnnouncer.announce("Secure notes unlocked")
+        return true
+    }
+
+    private nonisolated static func baseQuery() -> [String: Any] {
+        [
+            kSecClass as String: kSecClassGenericPassword,
+            kSecAttrService as String: keychainService,
+            kSecAttrAccount as String: keychainAccount,
+            kSecUseDataProtectionKeychain as String: true,
+        ]
+    }
+
+    private static func storeKey(_ key: Data) throws {
+        guard let access = SecAccessControlCreateWithFlags(
+            nil,
+            kSecAttrAccessibleWhenPasscodeSetThisDeviceOnly,
+            .userPresence,
+            nil
+        ) else {
+            throw AppLockError.accessControl
+        }
+        deleteKey()
+        var query = baseQuery()
+        query[kSecAttrAccessControl as String] = access
+        query[kSecValueData as String] = key
+        let status = SecItemAdd(query as CFDictionary, nil)
+        guard status == errSecSuccess else {
+            throw AppLockError.keychain(status)
+        }
+    }
+
+    /// Blocks while the Touch ID prompt is shown, so call it off the main actor.
+    private nonisolated static func readKey(reason: String) -> Data? {
+        let context = LAContext()
+        context.localizedReason = reason
+        var query = baseQuery()
+        query[kSecReturnData as String] = true
+        query[kSecMatchLimit as String] = kSecMatchLimitOne
+        query[kSecUseAuthenticationContext as String] = context
+        var result: AnyObject?
+        guard SecItemCopyMatching(query as CFDictionary, &result) == errSecSuccess else {
+            return nil
+        }
+        return result as? Data
+    }
+
+    private static func deleteKey() {
+        SecItemDelete(baseQuery() as CFDictionary)
+    }
+}
@@ -0,0 +1,244 @@
+import SwiftUI
+
+private let triggerLogLimit: UInt32 = 30
+
+struct AutomationSettingsView: View {
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var triggers: [TriggerRecord] = []
+    @State private var draft: TriggerRecord = AutomationSettingsView.emptyDraft
+    @State private var selectedTriggerId: String?
+    @State private var logEntries: [TriggerLogEntryRecord] = []
+    @State private var errorMessage: String?
+
+    private static let emptyDraft = TriggerRecord(
+        id: "",
+        name: "",
+        event: .saved,
+        tag: nil,
+        actionKind: .postUrl,
+        target: "",
+        enabled: true
+    )
+
+    var body: some View {
+        ScrollView {
+            VStack(alignment: .leading, spacing: 16) {
+                Text("Triggers")
+                    .font(.system(size: 14, weight: .medium))
+
+                Text("Run a script or POST to a URL when items are created, saved, or deleted. Failed runs are retried up to 3 times.")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+
+                if triggers.isEmpty {
+                    Text("No triggers yet.")
+                        .font(.system(size: 12))
+                        .foregroundStyle(.secondary)
+                } else {
+                    ForEach(triggers, id: \.id) { trigger in
+                        triggerRow(trigger)
+                    }
+                }
+
+                Divider()
+
+                editorSection
+
+                if let errorMessage {
+                    Text(errorMessage)
+                        .font(.system(size: 12))
+                        .foregroundStyle(themeManager.colors.errorColor)
+                }
+
+                if selectedTriggerId != nil {
+                    Divider()
+                    logSection
+                }
+
+                Spacer(minLength: 20)
+            }
+            .padding(.bottom, 10)
+        }
+        .onAppear(perform: reload)
+    }
+
+    private func triggerRow(_ trigger: TriggerRecord) -> some View {
+        HStack(spacing: 8) {
+            Image(systemName: trigger.actionKind == .postUrl ? "network" : "terminal")
+                .frame(width: 20)
+                .foregroundStyle(.secondary)
+            VStack(alignment: .leading, spacing: 2) {
+                Text(trigger.name)
+                    .font(.system(size: 13, weight: .medium))
+                Text(triggerSummary(trigger))
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+                    .lineLimit(1)
+            }
+            Spacer()
+            Button("Edit") {
+                draft = trigger
+                selectedTriggerId = trigger.id
+                loadLog(triggerId: trigger.id)
+            }
+            .font(.system(size: 12))
+            Button("Delete") {
+                delete(trigger)
+            }
+            .font(.system(size: 12))
+            .foregroundStyle(themeManager.colors.errorColor)
+        }
+        .padding(.horizontal, 10)
+        .padding(.vertical, 8)
+        .background(Color(nsColor: .controlBackgroundColor))
+        .clipShape(RoundedRectangle(cornerRadius: 8, style: .continuous))
+        .opacity(trigger.enabled ? 1 : 0.55)
+    }
+
+    private var editorSection: some View {
+        VStack(alignment: .leading, spacing: 10) {
+            Text(draft.id.isEmpty ? "New Trigger" : "Edit Trigger")
+                .font(.system(size: 14, weight: .medium))
+
+            TextField("Name", text: $draft.name)
+                .textFieldStyle(.roundedBorder)
+
+            Picker("When", selection: $draft.event) {
+                Text("Item created").tag(TriggerEventKind.created)
+                Text("Item saved").tag(TriggerEventKind.saved)
+                Text("Item deleted").tag(TriggerEventKind.deleted)
+            }
+
+            TextField("Only items tagged (e.g. #todo, optional)", text: Binding(
+                get: { draft.tag ?? "" },
+                set: { draft.tag = $0.isEmpty ? nil : $0 }
+            ))
+            .textFieldStyle(.roundedBorder)
+
+            Picker("Action", selection: $draft.actionKind) {
+                Text("POST to URL").tag(TriggerActionKind.postUrl)
+                Text("Run script").tag(TriggerActionKind.runScript)
+            }
+
+            TextField(draft.actionKind == .postUrl ? "https://example.com/hook" : "/path/to/script.sh", text: $draft.target)
+                .font(.system(size: 13, design: .monospaced))
+                .textFieldStyle(.roundedBorder)
+
+            Toggle("Enabled", isOn: $draft.enabled)
+
+            HStack {
+                if !draft.id.isEmpty {
+                    Button("New") {
+                        draft = Self.emptyDraft
+                        selectedTriggerId = nil
+                        logEntries = []
+                    }
+                }
+                Spacer()
+                Button("Save Trigger") {
+                    save()
+                }
+            }
+        }
+    }
+
+    private var logSection: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            HStack {
+                Text("Recent Runs")
+                    .font(.system(size: 14, weight: .medium))
+                Spacer()
+                Button("Refresh") {
+                    if let selectedTriggerId {
+                        loadLog(triggerId: selectedTriggerId)
+                    }
+                }
+                .font(.system(size: 12))
+            }
+
+            if logEntries.isEmpty {
+                Text("This trigger has not run yet.")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+            } else {
+                ForEach(Array(logEntries.enumerated()), id: \.offset) { _, entry in
+                    HStack(alignment: .top, spacing: 8) {
+                        Image(systemName: entry.success ? "checkmark.circle.fill" : "xmark.octagon.fill")
+                            .foregroundStyle(entry.success ? themeManager.colors.successColor : themeManager.colors.errorColor)
+                        VStack(alignment: .leading, spacing: 2) {
+                            Text("\(Self.logDateFormatter.string(from: Date(timeIntervalSince1970: TimeInterval(entry.atUnixSeconds)))) • item \(entry.itemId) • attempt \(entry.attempt)")
+                                .font(.system(size: 11))
+                                .foregroundStyle(.secondary)
+                            Text(entry.message)
+                                .font(.system(size: 12, design: .monospaced))
+                                .lineLimit(3)
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    private static let logDateFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.dateStyle = .short
+        formatter.timeStyle = .medium
+        return formatter
+    }()
+
+    private func triggerSummary(_ trigger: TriggerRecord) -> String {
+        let event: String
+        switch trigger.event {
+        case .created: event = "On create"
+        case .saved: event = "On save"
+        case .deleted: event = "On delete"
+        }
+        let tag = trigger.tag.map { " #\($0)" } ?? ""
+        return "\(event)\(tag) → \(trigger.target)"
+    }
+
+    private func reload() {
+        do {
+            triggers = try RustBridgeClient.listTriggers()
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func save() {
+        do {
+            let savedId = try RustBridgeClient.saveTrigger(draft)
+            reload()
+            if let saved = triggers.first(where: { $0.id == savedId }) {
+                draft = saved
+            }
+            selectedTriggerId = savedId
+            loadLog(triggerId: savedId)
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func delete(_ trigger: TriggerRecord) {
+        do {
+            try RustBridgeClient.deleteTrigger(id: trigger.id)
+            if selectedTriggerId == trigger.id {
+                draft = Self.emptyDraft
+                selectedTriggerId = nil
+                logEntries = []
+            }
+            reload()
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func loadLog(triggerId: String) {
+        do {
+            logEntries = try RustBridgeClient.triggerLog(id: triggerId, limit: triggerLogLimit)
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
@@ -0,0 +1,163 @@
+import SwiftUI
+
+private let boardColumnMinWidth: CGFloat = 240
+
+struct BoardView: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @Environment(\.openWindow) private var openWindow
+    @State private var statuses: [String] = RustBridgeClient.boardStatuses()
+    @State private var cards: [BoardCardRecord] = []
+    @State private var targetedStatus: String?
+    @State private var errorMessage: String?
+    @State private var window: NSWindow?
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 12) {
+            HStack {
+                Text("Board")
+                    .font(.system(size: 20, weight: .semibold))
+                Spacer()
+                Button {
+                    reload()
+                } label: {
+                    Image(systemName: "arrow.clockwise")
+                }
+                .buttonStyle(.borderless)
+                .help("Refresh")
+            }
+
+            if let errorMessage {
+                Text(errorMessage)
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+
+            HStack(alignment: .top, spacing: 12) {
+                ForEach(statuses, id: \.self) { status in
+                    column(for: status)
+                }
+            }
+        }
+        .padding(16)
+        .frame(minWidth: boardColumnMinWidth * 3 + 56, minHeight: 360, alignment: .topLeading)
+        .background(themeManager.colors.editorBackground)
+        .background(
+            WindowAccessor { resolved in
+                guard window !== resolved else { return }
+                window = resolved
+                viewModel.viewWindowDidOpen(resolved)
+            }
+        )
+        .onAppear(perform: reload)
+        .onDisappear {
+            viewModel.viewWindowDidClose(window)
+        }
+        .onChange(of: viewModel.viewWindowRefreshID) { _, _ in
+            reload()
+        }
+    }
+
+    private func column(for status: String) -> some View {
+        let columnCards = cards.filter { $0.status == status }
+        let isTargeted = targetedStatus == status
+
+        return VStack(alignment: .leading, spacing: 8) {
+            HStack {
+                Text("#\(status)")
+                    .font(.system(size: 13, weight: .semibold))
+                Spacer()
+                Text("\(columnCards.count)")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+            }
+            .padding(.horizontal, 4)
+
+            ScrollView {
+                LazyVStack(alignment: .leading, spacing: 8) {
+                    ForEach(columnCards, id: \.id) { card in
+                        cardView(card)
+                    }
+                }
+                .padding(.bottom, 8)
+            }
+        }
+        .padding(10)
+        .frame(minWidth: boardColumnMinWidth, maxWidth: .infinity, maxHeight: .infinity, alignment: .top)
+        .background(
+            RoundedRectangle(cornerRadius: 10, style: .continuous)
+                .fill(isTargeted ? themeManager.colors.accentColor.opacity(0.12) : Color(nsColor: .controlBackgroundColor))
+        )
+        .overlay(
+            RoundedRectangle(cornerRadius: 10, style: .continuous)
+                .stroke(isTargeted ? themeManager.colors.accentColor : Color.clear, lineWidth: 1.5)
+        )
+        .dropDestination(for: String.self) { payloads, _ in
+            guard let payload = payloads.first, let itemId = Int64(payload) else {
+                return false
+            }
+            move(itemId: itemId, to: status)
+            return true
+        } isTargeted: { targeted in
+            targetedStatus = targeted ? status : (targetedStatus == status ? nil : targetedStatus)
+        }
+    }
+
+    private func cardView(_ card: BoardCardRecord) -> some View {
+        Button {
+            openCard(card)
+        } label: {
+            VStack(alignment: .leading, spacing: 4) {
+                Text(card.title)
+                    .font(.system(size: 13, weight: .medium))
+                    .foregroundStyle(themeManager.colors.itemTitleText)
+                    .lineLimit(2)
+                if !card.preview.isEmpty {
+                    Text(card.preview)
+                        .font(.system(size: 11))
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                        .lineLimit(3)
+                }
+            }
+            .padding(10)
+            .frame(maxWidth: .infinity, alignment: .leading)
+            .background(themeManager.colors.itemBackground)
+            .clipShape(RoundedRectangle(cornerRadius: 8, style: .continuous))
+            .shadow(color: .black.opacity(0.08), radius: 2, y: 1)
+        }
+        .buttonStyle(.plain)
+        .draggable(String(card.id))
+    }
+
+    private func reload() {
+        do {
+            cards = try RustBridgeClient.listBoardCards()
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func move(itemId: Int64, to status: String) {
+        guard let index = cards.firstIndex(where: { $0.id == itemId }), cards[index].status != status else {
+            return
+        }
+
+        do {
+            try RustBridgeClient.moveBoardCard(itemId: itemId, status: status)
+            reload()
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func openCard(_ card: BoardCardRecord) {
+        Task {
+            let opened = await viewModel.open(itemId: card.id)
+            if opened {
+                viewModel.beginEditorPresentation()
+                openWindow.openEditor(for: viewModel)
+            }
+        }
+    }
+}
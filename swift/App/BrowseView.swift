@@ -0,0 +1,278 @@
+import SwiftUI
+
+private let browseListWidth: CGFloat = 300
+/// Delay after the last keystroke in the filter field before the list reloads.
+private let browseFilterDelayNanoseconds: UInt64 = 150_000_000
+/// Delay before the selected note is loaded, so arrowing through the list
+/// doesn't fetch every item passed on the way.
+private let browsePreviewDelayNanoseconds: UInt64 = 100_000_000
+
+private extension BrowseSortOrder {
+    static let allOrders: [BrowseSortOrder] = [.relevance, .updated, .created, .title]
+
+    var title: String {
+        switch self {
+        case .relevance:
+            return "Best Match"
+        case .updated:
+            return "Last Edited"
+        case .created:
+            return "Created"
+        case .title:
+            return "Title"
+        }
+    }
+}
+
+/// Every note in a list on the left with a read-only rendered preview of the
+/// selected one on the right, for browsing like a notes app. The filter field
+/// takes the launcher's search syntax, including `tag:` and `has:` filters.
+/// Up/Down move through the list, Return opens the note in the editor.
+struct BrowseView: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @Environment(\.openWindow) private var openWindow
+    @State private var filter = ""
+    @State private var sort: BrowseSortOrder = .updated
+    @State private var items: [SearchResultRecord] = []
+    @State private var selectedId: Int64?
+    @State private var selectedItem: EditableItemRecord?
+    @State private var errorMessage: String?
+    @State private var window: NSWindow?
+    @FocusState private var isFilterFocused: Bool
+
+    var body: some View {
+        HStack(spacing: 0) {
+            sidebar
+                .frame(width: browseListWidth)
+            Divider()
+            previewPane
+        }
+        .frame(minWidth: 760, minHeight: 480)
+        .background(themeManager.colors.editorBackground)
+        .background(
+            WindowAccessor { resolved in
+                guard window !== resolved else { return }
+                window = resolved
+                viewModel.viewWindowDidOpen(resolved)
+            }
+        )
+        .onAppear {
+            isFilterFocused = true
+        }
+        .onDisappear {
+            viewModel.viewWindowDidClose(window)
+        }
+        .onChange(of: viewModel.viewWindowRefreshID) { _, _ in
+            Task { await reload(after: 0) }
+        }
+        .task(id: "\(filter)\u{0}\(sort.title)") {
+            await reload(after: items.isEmpty ? 0 : browseFilterDelayNanoseconds)
+        }
+        .task(id: selectedId) {
+            await loadSelectedItem()
+        }
+    }
+
+    private var sidebar: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            TextField("Filter notes (text, tag:work, has:image…)", text: $filter)
+                .textFieldStyle(.roundedBorder)
+                .focused($isFilterFocused)
+                .onSubmit(openSelected)
+                .onKeyPress(.upArrow) { moveSelection(by: -1) }
+                .onKeyPress(.downArrow) { moveSelection(by: 1) }
+                .onKeyPress(.escape) {
+                    if filter.isEmpty {
+                        window?.performClose(nil)
+                    } else {
+                        filter = ""
+                    }
+                    return .handled
+                }
+                .accessibilityLabel("Filter notes")
+
+            HStack {
+                Picker("Sort", selection: $sort) {
+                    ForEach(BrowseSortOrder.allOrders, id: \.self) { order in
+                        Text(order.title).tag(order)
+                    }
+                }
+                .labelsHidden()
+                .fixedSize()
+                Spacer()
+                Text(items.count == 1 ? "1 note" : "\(items.count) notes")
+                    .font(.system(size: 11))
+                    .monospacedDigit()
+                    .foregroundStyle(.secondary)
+            }
+
+            if let errorMessage {
+                Text(errorMessage)
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+
+            ScrollViewReader { proxy in
+                List(items, id: \.id, selection: $selectedId) { item in
+                    row(item)
+                        .tag(item.id)
+                        .onTapGesture(count: 2) {
+                            selectedId = item.id
+                            openSelected()
+                        }
+                        .simultaneousGesture(TapGesture().onEnded { selectedId = item.id })
+                }
+                .listStyle(.sidebar)
+                .scrollContentBackground(.hidden)
+                .onChange(of: selectedId) { _, id in
+                    if let id {
+                        proxy.scrollTo(id)
+                    }
+                }
+            }
+        }
+        .padding(12)
+    }
+
+    private func row(_ item: SearchResultRecord) -> some View {
+        HStack(alignment: .firstTextBaseline, spacing: 6) {
+            VStack(alignment: .leading, spacing: 2) {
+                Text(item.title.isEmpty ? "Untitled" : item.title)
+                    .font(.system(size: 13, weight: .medium))
+                    .foregroundStyle(themeManager.colors.itemTitleText)
+                    .lineLimit(1)
+                if let snippet = item.snippet ?? (item.subtitle.isEmpty ? nil : item.subtitle) {
+                    Text(snippet.replacingOccurrences(of: "**", with: ""))
+                        .font(.system(size: 11))
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                        .lineLimit(1)
+                }
+            }
+            Spacer(minLength: 4)
+            if let progress = item.checklistProgress {
+                Text("\(progress.done)/\(progress.total)")
+                    .font(.system(size: 11))
+                    .monospacedDigit()
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+            }
+            if item.pinned {
+                Image(systemName: "pin.fill")
+                    .font(.system(size: 10))
+                    .rotationEffect(.degrees(45))
+                    .foregroundStyle(themeManager.colors.accentColor)
+                    .accessibilityHidden(true)
+            }
+        }
+        .padding(.vertical, 2)
+        .contentShape(Rectangle())
+        .accessibilityElement(children: .ignore)
+        .accessibilityLabel(item.accessibilityDescription)
+    }
+
+    @ViewBuilder
+    private var previewPane: some View {
+        VStack(alignment: .leading, spacing: 10) {
+            if let item = selectedItem, item.id == selectedId {
+                HStack {
+                    Text(item.title)
+                        .font(.system(size: 20, weight: .semibold))
+                        .foregroundStyle(themeManager.colors.itemTitleText)
+                        .lineLimit(2)
+                        .textSelection(.enabled)
+                    Spacer()
+                    Button("Open in Editor", action: openSelected)
+                        .help("Open the note in the editor (Return)")
+                }
+                MarkdownPreviewView(
+                    text: item.note,
+                    imagesByKey: Dictionary(uniqueKeysWithValues: item.images.map { ($0.imageKey, $0.bytes) }),
+                    fontSize: themeManager.scaledEditorFontSize,
+                    defaultImageWidth: 360
+                )
+                .padding(10)
+                .frame(maxWidth: .infinity, maxHeight: .infinity)
+                .background(themeManager.colors.editorTextBackground)
+                .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+            } else {
+                Text(items.isEmpty ? "No notes match the filter." : "Select a note to preview it.")
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    .frame(maxWidth: .infinity, maxHeight: .infinity)
+            }
+        }
+        .padding(16)
+        .frame(maxWidth: .infinity, maxHeight: .infinity, alignment: .topLeading)
+    }
+
+    private func moveSelection(by offset: Int) -> KeyPress.Result {
+        guard !items.isEmpty else {
+            return .ignored
+        }
+        let current = items.firstIndex { $0.id == selectedId } ?? -1
+        let index = min(max(current + offset, 0), items.count - 1)
+        selectedId = items[index].id
+        return .handled
+    }
+
+    private func openSelected() {
+        guard let itemId = selectedId else {
+            return
+        }
+        Task {
+            let opened = await viewModel.open(itemId: itemId)
+            if opened {
+                viewModel.beginEditorPresentation()
+                openWindow.openEditor(for: viewModel)
+            }
+        }
+    }
+
+    private func reload(after delayNanoseconds: UInt64) async {
+        if delayNanoseconds > 0 {
+            try? await Task.sleep(nanoseconds: delayNanoseconds)
+        }
+        guard !Task.isCancelled else {
+            return
+        }
+        let query = filter
+        let sort = sort
+        do {
+            let loaded = try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.browse(query: query, sort: sort)
+            }.value
+            guard !Task.isCancelled else {
+                return
+            }
+            items = loaded
+            errorMessage = nil
+            if !loaded.contains(where: { $0.id == selectedId }) {
+                selectedId = loaded.first?.id
+            }
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func loadSelectedItem() async {
+        guard let itemId = selectedId else {
+            selectedItem = nil
+            return
+        }
+        try? await Task.sleep(nanoseconds: browsePreviewDelayNanoseconds)
+        guard !Task.isCancelled else {
+            return
+        }
+        do {
+            let loaded = try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.fetch(itemId: itemId)
+            }.value
+            guard !Task.isCancelled else {
+                return
+            }
+            selectedItem = loaded
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
@@ -0,0 +1,233 @@
+import SwiftUI
+
+private let calendarMaxTitlesPerDay = 3
+
+struct CalendarView: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @Environment(\.openWindow) private var openWindow
+    @State private var monthStart: Date = CalendarView.startOfMonth(for: Date())
+    @State private var entriesByDay: [UInt32: [CalendarEntryRecord]] = [:]
+    @State private var errorMessage: String?
+    @State private var window: NSWindow?
+
+    private static let calendar = Calendar.current
+
+    private static let monthTitleFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.setLocalizedDateFormatFromTemplate("MMMM yyyy")
+        return formatter
+    }()
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 12) {
+            header
+
+            if let errorMessage {
+                Text(errorMessage)
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+
+            weekdayHeader
+
+            LazyVGrid(columns: Array(repeating: GridItem(.flexible(), spacing: 6), count: 7), spacing: 6) {
+                ForEach(Array(gridDays.enumerated()), id: \.offset) { _, day in
+                    if let day {
+                        dayCell(day)
+                    } else {
+                        Color.clear
+                            .frame(minHeight: 84)
+                    }
+                }
+            }
+
+            Spacer(minLength: 0)
+        }
+        .padding(16)
+        .frame(minWidth: 760, minHeight: 560, alignment: .topLeading)
+        .background(themeManager.colors.editorBackground)
+        .background(
+            WindowAccessor { resolved in
+                guard window !== resolved else { return }
+                window = resolved
+                viewModel.viewWindowDidOpen(resolved)
+            }
+        )
+        .onAppear(perform: reload)
+        .onDisappear {
+            viewModel.viewWindowDidClose(window)
+        }
+        .onChange(of: monthStart) { _, _ in
+            reload()
+        }
+        .onChange(of: viewModel.viewWindowRefreshID) { _, _ in
+            reload()
+        }
+    }
+
+    private var header: some View {
+        HStack(spacing: 8) {
+            Text(Self.monthTitleFormatter.string(from: monthStart))
+                .font(.system(size: 20, weight: .semibold))
+            Spacer()
+            Button {
+                shiftMonth(by: -1)
+            } label: {
+                Image(systemName: "chevron.left")
+            }
+            .buttonStyle(.borderless)
+            Button("Today") {
+                monthStart = Self.startOfMonth(for: Date())
+                openJournal(for: Date())
+            }
+            .help("Open today's journal entry")
+            Button {
+                shiftMonth(by: 1)
+            } label: {
+                Image(systemName: "chevron.right")
+            }
+            .buttonStyle(.borderless)
+        }
+    }
+
+    private var weekdayHeader: some View {
+        let symbols = Self.calendar.shortWeekdaySymbols
+        let first = Self.calendar.firstWeekday - 1
+        let ordered = Array(symbols[first...] + symbols[..<first])
+        return HStack(spacing: 6) {
+            ForEach(ordered, id: \.self) { symbol in
+                Text(symbol)
+                    .font(.system(size: 11, weight: .medium))
+                    .foregroundStyle(.secondary)
+                    .frame(maxWidth: .infinity)
+            }
+        }
+    }
+
+    /// Day numbers of the visible month, padded with `nil` so the first day
+    /// lands in its weekday column.
+    private var gridDays: [Int?] {
+        let calendar = Self.calendar
+        guard let range = calendar.range(of: .day, in: .month, for: monthStart) else {
+            return []
+        }
+        let weekday = calendar.component(.weekday, from: monthStart)
+        let leading = (weekday - calendar.firstWeekday + 7) % 7
+        return Array(repeating: nil, count: leading) + range.map { Optional($0) }
+    }
+
+    private func dayCell(_ day: Int) -> some View {
+        let entries = entriesByDay[UInt32(day)] ?? []
+        let date = dateFor(day: day)
+        let isToday = date.map { Self.calendar.isDateInToday($0) } ?? false
+        let hasJournal = entries.contains { $0.isJournal }
+
+        return VStack(alignment: .leading, spacing: 3) {
+            HStack(spacing: 4) {
+                Text("\(day)")
+                    .font(.system(size: 12, weight: isToday ? .bold : .medium))
+                    .foregroundStyle(isToday ? themeManager.colors.accentColor : themeManager.colors.itemTitleText)
+                if hasJournal {
+                    Image(systemName: "book.closed.fill")
+                        .font(.system(size: 9))
+                        .foregroundStyle(themeManager.colors.accentColor)
+                }
+                Spacer()
+            }
+
+            ForEach(entries.prefix(calendarMaxTitlesPerDay), id: \.itemId) { entry in
+                Button {
+                    openItem(id: entry.itemId)
+                } label: {
+                    Text(entry.title)
+                        .font(.system(size: 10))
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                        .lineLimit(1)
+                        .frame(maxWidth: .infinity, alignment: .leading)
+                }
+                .buttonStyle(.plain)
+            }
+
+            if entries.count > calendarMaxTitlesPerDay {
+                Text("+\(entries.count - calendarMaxTitlesPerDay) more")
+                    .font(.system(size: 10))
+                    .foregroundStyle(.secondary)
+            }
+
+            Spacer(minLength: 0)
+        }
+        .padding(6)
+        .frame(maxWidth: .infinity, minHeight: 84, alignment: .topLeading)
+        .background(
+            RoundedRectangle(cornerRadius: 8, style: .continuous)
+                .fill(Color(nsColor: .controlBackgroundColor))
+        )
+        .overlay(
+            RoundedRectangle(cornerRadius: 8, style: .continuous)
+                .stroke(isToday ? themeManager.colors.accentColor : Color.clear, lineWidth: 1.5)
+        )
+        .contentShape(Rectangle())
+        .onTapGesture {
+            if let date {
+                openJournal(for: date)
+            }
+        }
+        .help("Open or create the journal entry for this day")
+    }
+
+    private static func startOfMonth(for date: Date) -> Date {
+        let components = calendar.dateComponents([.year, .month], from: date)
+        return calendar.date(from: components) ?? date
+    }
+
+    private func dateFor(day: Int) -> Date? {
+        Self.calendar.date(byAdding: .day, value: day - 1, to: monthStart)
+    }
+
+    private func shiftMonth(by months: Int) {
+        if let shifted = Self.calendar.date(byAdding: .month, value: months, to: monthStart) {
+            monthStart = shifted
+        }
+    }
+
+    private func reload() {
+        let components = Self.calendar.dateComponents([.year, .month], from: monthStart)
+        guard let year = components.year, let month = components.month else {
+            return
+        }
+
+        do {
+            let entries = try RustBridgeClient.calendarMonth(year: Int32(year), month: UInt32(month))
+            entriesByDay = Dictionary(grouping: entries, by: \.day)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func openJournal(for date: Date) {
+        let components = Self.calendar.dateComponents([.year, .month, .day], from: date)
+        guard let year = components.year, let month = components.month, let day = components.day else {
+            return
+        }
+
+        do {
+            let itemId = try RustBridgeClient.journalEntry(year: Int32(year), month: UInt32(month), day: UInt32(day))
+            reload()
+            openItem(id: itemId)
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func openItem(id: Int64) {
+        Task {
+            let opened = await viewModel.open(itemId: id)
+            if opened {
+                viewModel.beginEditorPresentation()
+                openWindow.openEditor(for: viewModel)
+            }
+        }
+    }
+}
@@ -1,16 +1,20 @@
 import AppKit
 import SwiftUI
+import UniformTypeIdentifiers
 
-private let launcherWindowWidth: CGFloat = 1040
+/// Space kept between the launcher and the sides of a display narrower than
+/// the configured width.
+private let launcherScreenMargin: CGFloat = 24
 private let launcherEmptyHeight: CGFloat = 96
 private let launcherResultRowHeight: CGFloat = 60
 private let launcherMaxVisibleRows: CGFloat = 5
-private let launcherShellCornerRadius: CGFloat = 24
-private let launcherSearchFieldCornerRadius: CGFloat = 12
 private let launcherShellPadding: CGFloat = 14
-private let launcherResultsCornerRadius: CGFloat = launcherShellCornerRadius - launcherShellPadding
+private let launcherPreviewResultsWidth: CGFloat = 540
+/// Narrower launchers leave too little room for the preview pane and hide it.
+private let launcherPreviewMinimumWidth: CGFloat = 860
 private let keyHandlingModifierMask: NSEvent.ModifierFlags = [.shift, .control, .option, .command]
 private let actionMenuRowHeight: CGFloat = 44
+private let markdownTypeIdentifier = "net.daringfireball.markdown"
 private let editorDocumentFontSizesFileName = "editor-document-font-sizes.json"
 private let editorDocumentMinFontSize: CGFloat = 11
 private let editorDocumentMaxFontSize: CGFloat = 40
@@ -26,18 +30,42 @@ private struct LauncherShellHeightPreferenceKey: PreferenceKey {
 
 private enum ItemAction: Int, CaseIterable {
     case openEditor
+    case togglePin
     case rename
     case showJsonInFinder
     case copyTitle
+    case copyNote
+    case copyNoteWithImage
+    case showGallery
+    case addTag
+    case exportItems
+    case clearSelection
     case openSettings
     case delete
 
+    func label(for target: SearchResultRecord?, markedCount: Int = 0) -> String {
+        if self == .togglePin, target?.pinned == true {
+            return "Unpin"
+        }
+        if self == .delete, markedCount > 0 {
+            return "Delete \(markedCount) Items"
+        }
+        return label
+    }
+
     var label: String {
         switch self {
         case .openEditor: return "Open in Editor"
+        case .togglePin: return "Pin to Top"
         case .rename: return "Rename"
         case .showJsonInFinder: return "Show JSON in Finder"
         case .copyTitle: return "Copy Title"
+        case .copyNote: return "Copy Note"
+        case .copyNoteWithImage: return "Copy Note with First Image"
+        case .showGallery: return "Show Image Gallery"
+        case .addTag: return "Add Tag…"
+        case .exportItems: return "Export as JSON…"
+        case .clearSelection: return "Clear Selection"
         case .openSettings: return "Settings"
         case .delete: return "Delete"
         }
@@ -46,9 +74,16 @@ private enum ItemAction: Int, CaseIterable {
     var systemImage: String {
         switch self {
         case .openEditor: return "doc.text"
+        case .togglePin: return "pin"
         case .rename: return "pencil"
         case .showJsonInFinder: return "folder"
         case .copyTitle: return "doc.on.doc"
+        case .copyNote: return "doc.plaintext"
+        case .copyNoteWithImage: return "photo.on.rectangle"
+        case .showGallery: return "square.grid.3x3"
+        case .addTag: return "number"
+        case .exportItems: return "square.and.arrow.up"
+        case .clearSelection: return "xmark.circle"
         case .openSettings: return "gear"
         case .delete: return "trash"
         }
@@ -70,14 +105,37 @@ private enum ItemAction: Int, CaseIterable {
             return true
         }
     }
+
+    /// Actions offered while several results are marked.
+    var supportsMarkedItems: Bool {
+        switch self {
+        case .addTag, .exportItems, .clearSelection, .delete:
+            return true
+        default:
+            return false
+        }
+    }
+
+    var requiresMarkedItems: Bool {
+        supportsMarkedItems && self != .delete
+    }
 }
 
 struct ContentView: View {
     @EnvironmentObject private var viewModel: LauncherViewModel
     @EnvironmentObject private var themeManager: ThemeManager
+    @ObservedObject private var storeWarmUp = StoreWarmUp.shared
     @Environment(\.openWindow) private var openWindow
     @FocusState private var searchFieldFocused: Bool
     @State private var selectedIndex = 0
+    /// Results marked with Cmd+click or Shift+arrows for bulk actions.
+    @State private var markedItemIDs: Set<Int64> = []
+    @State private var markAnchorIndex: Int?
+    @State private var isEnteringBulkTag = false
+    @State private var bulkTagInput = ""
+    /// Result being renamed in the search field (Cmd+R or the Rename action).
+    @State private var renamingItem: SearchResultRecord?
+    @State private var renameInput = ""
     @State private var measuredShellHeight: CGFloat = launcherEmptyHeight
     @State private var resultsScrollProxy: ScrollViewProxy?
     @State private var isActionMenuVisible = false
@@ -86,16 +144,40 @@ struct ContentView: View {
     @State private var actionMenuFilter = ""
     @State private var firstVisibleRow: Int = 0
     @State private var isScrolling = false
+    /// Pending VoiceOver announcement of the result count, replaced on each keystroke.
+    @State private var resultsAnnouncementTask: Task<Void, Never>?
 
     private var filteredActions: [ItemAction] {
         let baseActions = ItemAction.allCases.filter { action in
-            actionMenuTarget != nil ? action.requiresItemTarget || action == .openSettings : !action.requiresItemTarget
+            if isBulkActionMenu {
+                return action.supportsMarkedItems
+            }
+            if action.requiresMarkedItems {
+                return false
+            }
+            if action == .copyNoteWithImage || action == .showGallery, actionMenuTarget?.thumbnailKey == nil {
+                return false
+            }
+            return actionMenuTarget != nil ? action.requiresItemTarget || action == .openSettings : !action.requiresItemTarget
         }
         let filter = actionMenuFilter.trimmingCharacters(in: .whitespacesAndNewlines).lowercased()
         guard !filter.isEmpty else {
             return baseActions
         }
-        return baseActions.filter { $0.label.lowercased().contains(filter) }
+        return baseActions.filter { actionLabel($0).lowercased().contains(filter) }
+    }
+
+    private var isBulkActionMenu: Bool {
+        isActionMenuVisible && actionMenuTarget == nil && !markedItemIDs.isEmpty
+    }
+
+    /// Marked ids in result order, so bulk actions see items as listed.
+    private var markedItemIDsInResultOrder: [Int64] {
+        viewModel.results.map(\.id).filter { markedItemIDs.contains($0) }
+    }
+
+    private func actionLabel(_ action: ItemAction) -> String {
+        action.label(for: actionMenuTarget, markedCount: isBulkActionMenu ? markedItemIDs.count : 0)
     }
 
     private var resultsViewportHeight: CGFloat {
@@ -103,6 +185,15 @@ struct ContentView: View {
         launcherResultRowHeight * launcherMaxVisibleRows + (launcherMaxVisibleRows * 2) + 4
     }
 
+    /// The width from Settings, capped so the launcher fits the main display.
+    private var launcherWindowWidth: CGFloat {
+        let configured = CGFloat(themeManager.launcherWidth)
+        guard let screenWidth = NSScreen.main?.visibleFrame.width else {
+            return configured
+        }
+        return max(min(configured, screenWidth - launcherScreenMargin * 2), CGFloat(ThemeManager.launcherWidthRange.lowerBound))
+    }
+
     var body: some View {
         launcherShell(width: launcherWindowWidth)
             .background(
@@ -125,7 +216,9 @@ struct ContentView: View {
         )
         .background(
             KeyEventMonitor(onKeyDown: { event in
-                return handleLauncherKeyEvent(event)
+                return FrameTimingMonitor.measure(.messages) {
+                    handleLauncherKeyEvent(event)
+                }
             }, onCmdTap: {
                 handleCmdTap()
             })
@@ -143,6 +236,7 @@ struct ContentView: View {
                 if selectedIndex != 0 {
                     selectedIndex = 0
                 }
+                clearMarkedItems()
             }
         }
         .onChange(of: actionMenuFilter) { _, _ in
@@ -154,10 +248,18 @@ struct ContentView: View {
             } else if selectedIndex >= viewModel.results.count {
                 selectedIndex = max(0, viewModel.results.count - 1)
             }
+            let visibleIDs = Set(viewModel.results.map(\.id))
+            if !markedItemIDs.isSubset(of: visibleIDs) {
+                markedItemIDs.formIntersection(visibleIDs)
+            }
             // Reset firstVisibleRow when results change
             firstVisibleRow = max(0, selectedIndex - Int(launcherMaxVisibleRows) + 1)
+            announceResults()
         }
         .onChange(of: selectedIndex) { oldValue, newValue in
+            if oldValue != newValue {
+                announceSelection()
+            }
             guard oldValue != newValue, let proxy = resultsScrollProxy else {
                 return
             }
@@ -173,9 +275,21 @@ struct ContentView: View {
     
     private var searchFieldBinding: Binding<String> {
         Binding(
-            get: { isActionMenuVisible ? actionMenuFilter : viewModel.query },
+            get: {
+                if isEnteringBulkTag {
+                    return bulkTagInput
+                }
+                if renamingItem != nil {
+                    return renameInput
+                }
+                return isActionMenuVisible ? actionMenuFilter : viewModel.query
+            },
             set: { newValue in
-                if isActionMenuVisible {
+                if isEnteringBulkTag {
+                    bulkTagInput = newValue
+                } else if renamingItem != nil {
+                    renameInput = newValue
+                } else if isActionMenuVisible {
                     actionMenuFilter = newValue
                 } else {
                     viewModel.query = newValue
@@ -185,30 +299,74 @@ struct ContentView: View {
     }
     
     private var searchFieldPlaceholder: String {
-        isActionMenuVisible ? "Filter actions..." : "Type to search..."
+        if isEnteringBulkTag {
+            return "Tag to add to \(markedItemIDs.count) items..."
+        }
+        if let renamingItem {
+            return "New title for \(renamingItem.title)..."
+        }
+        return isActionMenuVisible ? "Filter actions..." : "Type to search..."
     }
     
     private func searchFieldView() -> some View {
         let colors = themeManager.colors
+        let metrics = themeManager.metrics
         return HStack {
             TextField(searchFieldPlaceholder, text: searchFieldBinding)
                 .textFieldStyle(.plain)
-                .font(.system(size: themeManager.searchFieldFontSize, weight: .regular))
+                .font(metrics.font(size: themeManager.scaledSearchFieldFontSize))
                 .foregroundStyle(colors.itemTitleText)
                 .focused($searchFieldFocused)
                 .onSubmit(handleSearchSubmit)
+                .accessibilityLabel(searchFieldPlaceholder)
+                .accessibilityHint(viewModel.shouldShowResultsForCurrentQuery
+                    ? "\(AccessibilityAnnouncer.resultCountDescription(viewModel.results.count)). Use the arrow keys to choose one and Return to open it."
+                    : "Type to search notes and commands.")
+                .background(alignment: .leading) {
+                    if let completion = queryCompletionSuffix {
+                        // The typed query is drawn invisibly so the completion starts where it ends.
+                        (Text(viewModel.query).foregroundColor(.clear) + Text(completion).foregroundColor(colors.itemSubtitleText))
+                            .font(metrics.font(size: themeManager.scaledSearchFieldFontSize))
+                            .lineLimit(1)
+                            .allowsHitTesting(false)
+                            .accessibilityHidden(true)
+                    }
+                }
         }
         .padding(.horizontal, 14)
         .padding(.vertical, 10)
         .background(colors.searchFieldBackground)
         .overlay(
-            RoundedRectangle(cornerRadius: launcherSearchFieldCornerRadius, style: .continuous)
+            RoundedRectangle(cornerRadius: metrics.searchFieldCornerRadius, style: .continuous)
                 .stroke(colors.searchFieldBorder, lineWidth: 1)
         )
-        .clipShape(RoundedRectangle(cornerRadius: launcherSearchFieldCornerRadius, style: .continuous))
+        .clipShape(RoundedRectangle(cornerRadius: metrics.searchFieldCornerRadius, style: .continuous))
+        .themeShadow(metrics.searchFieldShadow)
     }
     
+    /// The rest of the top result's title when it starts with the query, shown
+    /// as ghost text in the search field and accepted with Tab.
+    private var queryCompletionSuffix: String? {
+        guard !isActionMenuVisible, !isEnteringBulkTag, renamingItem == nil,
+              !viewModel.query.isEmpty,
+              let title = viewModel.results.first?.title,
+              let match = title.range(of: viewModel.query, options: [.caseInsensitive, .anchored]),
+              match.upperBound < title.endIndex
+        else {
+            return nil
+        }
+        return String(title[match.upperBound...])
+    }
+
     private func handleSearchSubmit() {
+        if isEnteringBulkTag {
+            submitBulkTag()
+            return
+        }
+        if renamingItem != nil {
+            submitRename()
+            return
+        }
         if isActionMenuVisible {
             let actions = filteredActions
             if actions.indices.contains(actionMenuSelectedIndex) {
@@ -225,38 +383,144 @@ struct ContentView: View {
     private func resultsContentView(showResults: Bool) -> some View {
         if isActionMenuVisible {
             actionMenuView(for: actionMenuTarget)
+        } else if showResults, themeManager.launcherPreviewEnabled, launcherWindowWidth >= launcherPreviewMinimumWidth {
+            HStack(spacing: 10) {
+                resultsListView
+                    .frame(width: launcherPreviewResultsWidth)
+                ResultPreviewPane(
+                    itemId: previewItemID,
+                    searchQuery: viewModel.query,
+                    onToggleChecklistEntry: { itemId, index in
+                        await viewModel.toggleChecklistEntry(itemId: itemId, index: index)
+                    }
+                )
+            }
         } else if showResults {
-            ResultsListView(
-                results: viewModel.results,
-                selectedIndex: $selectedIndex,
-                resultsScrollProxy: $resultsScrollProxy,
-                onActivate: { idx in
-                    activateResult(at: idx)
-                },
-                onScrollProxySet: { proxy in
-                    resultsScrollProxy = proxy
-                },
-                onScrollSelection: { proxy, animated in
-                    scrollSelectionIntoView(using: proxy, animated: animated)
-                }
-            )
+            resultsListView
+        }
+    }
+
+    /// The selected result's item id, if it is a note that can be previewed.
+    private var previewItemID: Int64? {
+        guard viewModel.results.indices.contains(selectedIndex) else {
+            return nil
         }
+        let result = viewModel.results[selectedIndex]
+        return result.kind == .item ? result.id : nil
+    }
+
+    private var resultsListView: some View {
+        ResultsListView(
+            results: viewModel.results,
+            selectedIndex: $selectedIndex,
+            markedItemIDs: markedItemIDs,
+            resultsScrollProxy: $resultsScrollProxy,
+            onActivate: { idx in
+                activateResult(at: idx)
+            },
+            onMark: { idx, extendRange in
+                if extendRange {
+                    extendMarkedRange(to: idx)
+                } else {
+                    toggleMarked(at: idx)
+                }
+            },
+            onScrollProxySet: { proxy in
+                resultsScrollProxy = proxy
+            },
+            onScrollSelection: { proxy, animated in
+                scrollSelectionIntoView(using: proxy, animated: animated)
+            }
+        )
     }
 
     private func launcherShell(width: CGFloat) -> some View {
         let colors = themeManager.colors
+        let shellCornerRadius = themeManager.metrics.launcherCornerRadius
+        let resultsCornerRadius = max(0, shellCornerRadius - launcherShellPadding)
         let showResults = viewModel.shouldShowResultsForCurrentQuery
         let hasContent = showResults || isActionMenuVisible
         
         return VStack(alignment: .leading, spacing: 0) {
             searchFieldView()
             
-            if let errorMessage = viewModel.errorMessage {
-                Text("Error: \(errorMessage)")
-                    .foregroundStyle(colors.errorColor)
-                    .font(.system(size: 13))
+            if !viewModel.errorToasts.isEmpty {
+                ErrorToastStack()
+                    .padding(.top, 6)
+            }
+
+            if viewModel.isSavingReadLater {
+                Label("Saving article…", systemImage: "book")
+                    .foregroundStyle(colors.placeholderText)
+                    .font(.system(size: 12))
+                    .padding(.top, 6)
+            }
+
+            if !storeWarmUp.isReady {
+                Label("Indexing…", systemImage: "hourglass")
+                    .foregroundStyle(colors.placeholderText)
+                    .font(.system(size: 12))
+                    .padding(.top, 6)
+            }
+
+            if let storageAlert = viewModel.storageAlert {
+                Label("\(storageAlert.message) See Settings › Storage.", systemImage: "externaldrive.badge.exclamationmark")
+                    .foregroundStyle(storageAlert.level == .critical ? colors.errorColor : Color.orange)
+                    .font(.system(size: 12))
+                    .lineLimit(1)
                     .padding(.top, 6)
             }
+
+            if let session = viewModel.restorableSession {
+                HStack(spacing: 8) {
+                    Label(sessionRestoreMessage(for: session), systemImage: "arrow.counterclockwise")
+                        .foregroundStyle(session.exitedCleanly ? colors.itemSubtitleText : Color.orange)
+                        .lineLimit(1)
+                    Spacer()
+                    Button("Restore") {
+                        Task {
+                            if await viewModel.restoreSession() {
+                                openWindow.openEditor(for: viewModel)
+                            }
+                        }
+                    }
+                    .buttonStyle(.link)
+                    Button {
+                        viewModel.dismissSessionRestore()
+                    } label: {
+                        Image(systemName: "xmark")
+                    }
+                    .buttonStyle(.plain)
+                    .foregroundStyle(colors.itemSubtitleText)
+                    .accessibilityLabel("Dismiss")
+                }
+                .font(.system(size: 12))
+                .padding(.top, 6)
+            }
+
+            if let deletion = viewModel.undoableDeletion {
+                HStack(spacing: 8) {
+                    Label("Deleted “\(deletion.title)”", systemImage: "trash")
+                        .foregroundStyle(colors.itemSubtitleText)
+                        .lineLimit(1)
+                    Spacer()
+                    Button("Undo (⌘Z)") {
+                        Task {
+                            await viewModel.undoDeletion()
+                        }
+                    }
+                    .buttonStyle(.link)
+                    Button {
+                        viewModel.dismissUndoableDeletion()
+                    } label: {
+                        Image(systemName: "xmark")
+                    }
+                    .buttonStyle(.plain)
+                    .foregroundStyle(colors.itemSubtitleText)
+                }
+                .font(.system(size: 12))
+                .padding(.top, 6)
+            }
             
             resultsContentView(showResults: showResults)
                 .padding(.top, hasContent ? 8 : 0)
@@ -267,17 +531,36 @@ struct ContentView: View {
                     alignment: .top
                 )
                 .clipped()
-                .clipShape(RoundedRectangle(cornerRadius: launcherResultsCornerRadius, style: .continuous))
+                .clipShape(RoundedRectangle(cornerRadius: resultsCornerRadius, style: .continuous))
         }
         .padding(launcherShellPadding)
         .frame(width: width)
-        .background(colors.launcherBackground)
+        .background {
+            ZStack {
+                if themeManager.launcherBlurEnabled {
+                    VisualEffectBackground()
+                }
+                colors.launcherBackground.opacity(themeManager.launcherOpacity)
+            }
+        }
         .overlay(WindowDragHandle(inset: launcherShellPadding))
         .overlay(
-            RoundedRectangle(cornerRadius: launcherShellCornerRadius, style: .continuous)
+            RoundedRectangle(cornerRadius: shellCornerRadius, style: .continuous)
                 .stroke(colors.launcherBorder, lineWidth: 1)
         )
-        .clipShape(RoundedRectangle(cornerRadius: launcherShellCornerRadius, style: .continuous))
+        .overlay(alignment: .bottomTrailing) {
+            FrameTimingOverlay()
+                .padding(launcherShellPadding)
+        }
+        .clipShape(RoundedRectangle(cornerRadius: shellCornerRadius, style: .continuous))
+    }
+
+    private func sessionRestoreMessage(for session: PersistedSession) -> String {
+        let prefix = session.exitedCleanly ? "Last session" : "The app quit unexpectedly"
+        if let title = session.editorItemTitle {
+            return "\(prefix) — reopen “\(title)”?"
+        }
+        return "\(prefix) — search “\(session.launcherQuery)” again?"
     }
 
     private func activateCurrentSelection() {
@@ -285,11 +568,23 @@ struct ContentView: View {
     }
 
     private func activateResult(at index: Int) {
+        if viewModel.results.indices.contains(index),
+           viewModel.results[index].kind == .appCommand,
+           let commandKey = viewModel.results[index].commandKey {
+            if commandKey == "export_csv" {
+                exportItemsCSV()
+                return
+            }
+            // Other app command keys double as the id of the window they open.
+            openWindow(id: commandKey)
+            return
+        }
+
         Task {
             let openedEditor = await viewModel.activate(selectedIndex: index)
             if openedEditor {
                 viewModel.beginEditorPresentation()
-                openWindow(id: "editor")
+                openWindow.openEditor(for: viewModel)
             }
         }
     }
@@ -304,6 +599,40 @@ struct ContentView: View {
         return "\(index)_\(item.id)"
     }
     
+    /// Tells VoiceOver how many results the query found and which one is
+    /// selected, once typing pauses.
+    private func announceResults() {
+        resultsAnnouncementTask?.cancel()
+        guard searchFieldFocused, viewModel.shouldShowResultsForCurrentQuery else {
+            return
+        }
+        resultsAnnouncementTask = Task {
+            try? await Task.sleep(for: .milliseconds(400))
+            guard !Task.isCancelled else {
+                return
+            }
+            var message = AccessibilityAnnouncer.resultCountDescription(viewModel.results.count)
+            if viewModel.results.indices.contains(selectedIndex) {
+                message += ". \(viewModel.results[selectedIndex].accessibilityDescription)"
+            }
+            AccessibilityAnnouncer.announce(message)
+        }
+    }
+
+    /// Reads out the result the arrow keys moved to, since focus stays in the search field.
+    private func announceSelection() {
+        guard !isActionMenuVisible, viewModel.results.indices.contains(selectedIndex) else {
+            return
+        }
+        resultsAnnouncementTask?.cancel()
+        let result = viewModel.results[selectedIndex]
+        var message = "\(result.accessibilityDescription), \(selectedIndex + 1) of \(viewModel.results.count)"
+        if markedItemIDs.contains(result.id) {
+            message += ", marked"
+        }
+        AccessibilityAnnouncer.announce(message, priority: .high)
+    }
+
     private func handleSelectionChange(oldValue: Int, newValue: Int, proxy: ScrollViewProxy) {
         guard !isScrolling else { return }
         
@@ -351,8 +680,28 @@ struct ContentView: View {
             dismissActionMenu()
             return
         }
+        if isEnteringBulkTag {
+            cancelBulkTagEntry()
+            return
+        }
+        if renamingItem != nil {
+            cancelRename()
+            return
+        }
+
+        if !markedItemIDs.isEmpty {
+            actionMenuTarget = nil
+            isActionMenuVisible = true
+            actionMenuSelectedIndex = 0
+            actionMenuFilter = ""
+            searchFieldFocused = true
+            return
+        }
 
         if viewModel.results.indices.contains(selectedIndex) {
+            guard viewModel.results[selectedIndex].kind == .item else {
+                return
+            }
             actionMenuTarget = viewModel.results[selectedIndex]
             isActionMenuVisible = true
             actionMenuSelectedIndex = 0
@@ -387,12 +736,12 @@ struct ContentView: View {
                 Image(systemName: "ellipsis.circle.fill")
                     .font(.system(size: 14, weight: .medium))
                     .foregroundStyle(themeManager.colors.itemSubtitleText)
-                Text(target?.title ?? "Actions")
+                Text(target?.title ?? (isBulkActionMenu ? "\(markedItemIDs.count) items selected" : "Actions"))
                     .font(.system(size: 14, weight: .semibold))
                     .foregroundStyle(themeManager.colors.actionMenuHeaderText)
                     .lineLimit(1)
                 Spacer()
-                Text("⌘ to go back")
+                Text("⌘, ⌘K or Tab to go back")
                     .font(.system(size: 11))
                     .foregroundStyle(themeManager.colors.placeholderText)
             }
@@ -431,7 +780,7 @@ struct ContentView: View {
                                     .font(.system(size: 15))
                                     .frame(width: 22)
                                     .foregroundStyle(actionIconColor(action: action, isSelected: isSelected))
-                                Text(action.label)
+                                Text(actionLabel(action))
                                     .font(.system(size: 16, weight: .medium))
                                     .foregroundStyle(actionTextColor(action: action, isSelected: isSelected))
                                 Spacer()
@@ -451,8 +800,14 @@ struct ContentView: View {
     }
 
     private func executeAction(_ action: ItemAction, on target: SearchResultRecord?) {
+        let isBulk = isBulkActionMenu
         dismissActionMenu()
 
+        if isBulk {
+            executeBulkAction(action)
+            return
+        }
+
         switch action {
         case .openEditor:
             guard let target else { return }
@@ -460,25 +815,33 @@ struct ContentView: View {
                 let opened = await viewModel.open(itemId: target.id)
                 if opened {
                     viewModel.beginEditorPresentation()
-                    openWindow(id: "editor")
+                    openWindow.openEditor(for: viewModel)
                 }
             }
+        case .togglePin:
+            guard let target else { return }
+            viewModel.setItemPinned(itemId: target.id, pinned: !target.pinned)
         case .rename:
             guard let target else { return }
-            Task {
-                let opened = await viewModel.open(itemId: target.id)
-                if opened {
-                    viewModel.requestEditorTitleFocus()
-                    viewModel.beginEditorPresentation()
-                    openWindow(id: "editor")
-                }
-            }
+            beginRename(target)
         case .showJsonInFinder:
             guard let target else { return }
             viewModel.revealItemJsonInFinder(itemId: target.id)
         case .copyTitle:
             guard let target else { return }
             viewModel.copyItemTitle(target.title)
+        case .copyNote:
+            guard let target else { return }
+            viewModel.copyItemNote(itemId: target.id)
+        case .copyNoteWithImage:
+            guard let target else { return }
+            viewModel.copyItemNote(itemId: target.id, imageKey: target.thumbnailKey)
+        case .showGallery:
+            guard let target else { return }
+            viewModel.prepareGallery(itemId: target.id, title: target.title)
+            openWindow(id: "gallery")
+        case .addTag, .exportItems, .clearSelection:
+            return
         case .openSettings:
             viewModel.prepareSettings()
             openWindow(id: "settings")
@@ -490,6 +853,144 @@ struct ContentView: View {
         }
     }
     
+    private func executeBulkAction(_ action: ItemAction) {
+        let itemIds = markedItemIDsInResultOrder
+        guard !itemIds.isEmpty else {
+            return
+        }
+
+        switch action {
+        case .addTag:
+            bulkTagInput = ""
+            isEnteringBulkTag = true
+            searchFieldFocused = true
+        case .exportItems:
+            guard let url = chooseExportURL(itemCount: itemIds.count) else {
+                return
+            }
+            Task {
+                await viewModel.performBulkAction(.export(url), itemIds: itemIds)
+            }
+        case .clearSelection:
+            clearMarkedItems()
+        case .delete:
+            Task {
+                if await viewModel.performBulkAction(.delete, itemIds: itemIds) {
+                    clearMarkedItems()
+                }
+            }
+        default:
+            return
+        }
+    }
+
+    private func submitBulkTag() {
+        let tag = bulkTagInput.trimmingCharacters(in: .whitespacesAndNewlines)
+        let itemIds = markedItemIDsInResultOrder
+        cancelBulkTagEntry()
+        guard !tag.isEmpty, !itemIds.isEmpty else {
+            return
+        }
+        Task {
+            await viewModel.performBulkAction(.addTag(tag), itemIds: itemIds)
+        }
+    }
+
+    private func cancelBulkTagEntry() {
+        isEnteringBulkTag = false
+        bulkTagInput = ""
+        searchFieldFocused = true
+    }
+
+    private func searchFieldHasSelection() -> Bool {
+        guard let editor = NSApp.keyWindow?.firstResponder as? NSTextView else {
+            return false
+        }
+        return editor.selectedRange().length > 0
+    }
+
+    private func beginRename(_ item: SearchResultRecord) {
+        renamingItem = item
+        renameInput = item.title
+        searchFieldFocused = true
+    }
+
+    private func submitRename() {
+        guard let item = renamingItem else {
+            return
+        }
+        let title = renameInput
+        cancelRename()
+        Task {
+            await viewModel.renameItem(itemId: item.id, to: title)
+        }
+    }
+
+    private func cancelRename() {
+        renamingItem = nil
+        renameInput = ""
+        searchFieldFocused = true
+    }
+
+    private func exportItemsCSV() {
+        let panel = NSSavePanel()
+        panel.title = "Export Notes as CSV"
+        panel.nameFieldStringValue = "alfred-items.csv"
+        panel.allowedContentTypes = [.commaSeparatedText]
+        panel.canCreateDirectories = true
+        guard panel.runModal() == .OK, let url = panel.url else {
+            return
+        }
+        Task {
+            await viewModel.exportItemsCSV(to: url)
+        }
+    }
+
+    private func chooseExportURL(itemCount: Int) -> URL? {
+        let panel = NSSavePanel()
+        panel.title = "Export \(itemCount) Items"
+        panel.nameFieldStringValue = "alfred-items.json"
+        panel.allowedContentTypes = [.json]
+        panel.canCreateDirectories = true
+        return panel.runModal() == .OK ? panel.url : nil
+    }
+
+    // MARK: - Multi-Selection
+
+    private func toggleMarked(at index: Int) {
+        guard viewModel.results.indices.contains(index), viewModel.results[index].kind == .item else {
+            return
+        }
+        let id = viewModel.results[index].id
+        if markedItemIDs.contains(id) {
+            markedItemIDs.remove(id)
+        } else {
+            markedItemIDs.insert(id)
+        }
+        markAnchorIndex = index
+        selectedIndex = index
+    }
+
+    /// Marks every item between the anchor (or the current row) and `index`.
+    private func extendMarkedRange(to index: Int) {
+        guard viewModel.results.indices.contains(index) else {
+            return
+        }
+        let anchor = min(markAnchorIndex ?? selectedIndex, viewModel.results.count - 1)
+        markAnchorIndex = anchor
+        for result in viewModel.results[min(anchor, index)...max(anchor, index)] where result.kind == .item {
+            markedItemIDs.insert(result.id)
+        }
+        selectedIndex = index
+    }
+
+    private func clearMarkedItems() {
+        if !markedItemIDs.isEmpty {
+            markedItemIDs.removeAll()
+        }
+        markAnchorIndex = nil
+    }
+
     // MARK: - Action Menu Styling
     
     private func actionTextColor(action: ItemAction, isSelected: Bool) -> Color {
@@ -516,6 +1017,26 @@ struct ContentView: View {
         selectedIndex = normalizedIndex == 0 ? count - 1 : normalizedIndex - 1
     }
 
+    /// Moves the selection to the first result of the next (or previous) group,
+    /// wrapping around at either end.
+    private func moveResultSelectionToSection(forward: Bool) {
+        let results = viewModel.results
+        guard !results.isEmpty else {
+            return
+        }
+
+        let sectionStarts = results.indices.filter { $0 == 0 || results[$0 - 1].section != results[$0].section }
+        let current = min(max(selectedIndex, 0), results.count - 1)
+        if forward {
+            selectedIndex = sectionStarts.first(where: { $0 > current }) ?? sectionStarts[0]
+        } else {
+            let currentStart = sectionStarts.last(where: { $0 <= current }) ?? 0
+            selectedIndex = current > currentStart
+                ? currentStart
+                : sectionStarts.last(where: { $0 < currentStart }) ?? sectionStarts[sectionStarts.count - 1]
+        }
+    }
+
     private func moveResultSelectionDownCyclic() {
         let count = viewModel.results.count
         guard count > 0 else {
@@ -529,6 +1050,20 @@ struct ContentView: View {
     private func handleLauncherKeyEvent(_ event: NSEvent) -> Bool {
         let modifiers = event.modifierFlags.intersection(keyHandlingModifierMask)
 
+        // Tab completes the query to the top result's title when it offers one;
+        // with the action panel open, Tab still closes it below
+        if !isActionMenuVisible, modifiers.isEmpty, event.keyCode == 48,
+           let completion = queryCompletionSuffix {
+            viewModel.query += completion
+            return true
+        }
+
+        // Tab or Cmd+K toggles the action panel for the selected result
+        if (modifiers.isEmpty && event.keyCode == 48) || (modifiers == .command && event.keyCode == 40) {
+            handleCmdTap()
+            return true
+        }
+
         // Handle action menu key events
         if isActionMenuVisible {
             if !modifiers.isEmpty {
@@ -565,26 +1100,112 @@ struct ContentView: View {
             }
         }
 
-        // Handle Shift+Enter - always create new item
-        if modifiers == .shift && (event.keyCode == 36 || event.keyCode == 76) {
-            Task {
-                let created = await viewModel.createItemFromQuery()
-                if created {
-                    viewModel.beginEditorPresentation()
-                    openWindow(id: "editor")
-                }
+        if isEnteringBulkTag {
+            if modifiers.isEmpty && event.keyCode == 53 {
+                cancelBulkTagEntry()
+                return true
             }
-            return true
+            return false
         }
-        
-        if !modifiers.isEmpty {
+
+        if renamingItem != nil {
+            if modifiers.isEmpty && event.keyCode == 53 {
+                cancelRename()
+                return true
+            }
             return false
         }
 
-        switch event.keyCode {
-        case 126: // up
-            moveResultSelectionUpCyclic()
-            searchFieldFocused = true
+        // Cmd+C copies the selected result's note unless text in the search field is selected;
+        // Shift+Cmd+C also copies its first image
+        if (modifiers == .command || modifiers == [.command, .shift]) && event.keyCode == 8
+            && viewModel.shouldShowResultsForCurrentQuery && !searchFieldHasSelection() {
+            guard viewModel.results.indices.contains(selectedIndex), viewModel.results[selectedIndex].kind == .item else {
+                return false
+            }
+            let item = viewModel.results[selectedIndex]
+            viewModel.copyItemNote(itemId: item.id, imageKey: modifiers.contains(.shift) ? item.thumbnailKey : nil)
+            return true
+        }
+
+        // Cmd+R renames the selected result in place
+        if modifiers == .command && event.keyCode == 15 && viewModel.shouldShowResultsForCurrentQuery {
+            if viewModel.results.indices.contains(selectedIndex), viewModel.results[selectedIndex].kind == .item {
+                beginRename(viewModel.results[selectedIndex])
+            }
+            return true
+        }
+
+        // Option+Up/Down jumps between result groups
+        if modifiers == .option && (event.keyCode == 126 || event.keyCode == 125) {
+            moveResultSelectionToSection(forward: event.keyCode == 125)
+            searchFieldFocused = true
+            return true
+        }
+
+        // Shift+Up/Down extends the marked range for bulk actions
+        if modifiers == .shift && (event.keyCode == 126 || event.keyCode == 125) {
+            let count = viewModel.results.count
+            guard count > 0 else {
+                return true
+            }
+            let current = min(max(selectedIndex, 0), count - 1)
+            let target = event.keyCode == 126 ? max(current - 1, 0) : min(current + 1, count - 1)
+            extendMarkedRange(to: target)
+            searchFieldFocused = true
+            return true
+        }
+
+        // Cmd+Backspace deletes the selected result; Cmd+Z restores it while the toast shows
+        if modifiers == .command && event.keyCode == 51 && viewModel.shouldShowResultsForCurrentQuery && !viewModel.results.isEmpty {
+            let index = min(max(selectedIndex, 0), viewModel.results.count - 1)
+            let itemId = viewModel.results[index].id
+            Task {
+                await viewModel.deleteItem(itemId: itemId)
+            }
+            return true
+        }
+
+        if modifiers == .command && event.keyCode == 6 && viewModel.undoableDeletion != nil {
+            Task {
+                await viewModel.undoDeletion()
+            }
+            return true
+        }
+
+        // Handle Shift+Enter - always create new item
+        if modifiers == .shift && (event.keyCode == 36 || event.keyCode == 76) {
+            Task {
+                let created = await viewModel.createItemFromQuery()
+                if created {
+                    viewModel.beginEditorPresentation()
+                    openWindow.openEditor(for: viewModel)
+                }
+            }
+            return true
+        }
+        
+        // Ctrl+P / Ctrl+N step through earlier queries
+        if modifiers == .control && (event.keyCode == 35 || event.keyCode == 45) {
+            viewModel.recallQueryFromHistory(older: event.keyCode == 35)
+            searchFieldFocused = true
+            return true
+        }
+
+        if !modifiers.isEmpty {
+            return false
+        }
+
+        switch event.keyCode {
+        case 126: // up
+            // In an empty field, Up recalls the last query instead
+            if viewModel.query.trimmingCharacters(in: .whitespaces).isEmpty,
+               viewModel.recallQueryFromHistory(older: true) {
+                searchFieldFocused = true
+                return true
+            }
+            moveResultSelectionUpCyclic()
+            searchFieldFocused = true
             return true
         case 125: // down
             moveResultSelectionDownCyclic()
@@ -597,6 +1218,10 @@ struct ContentView: View {
             }
             return true
         case 53: // escape
+            if !markedItemIDs.isEmpty {
+                clearMarkedItems()
+                return true
+            }
             viewModel.dismissLauncher()
             return true
         default:
@@ -605,13 +1230,31 @@ struct ContentView: View {
     }
 }
 
+extension OpenWindowAction {
+    /// Opens the editor window of the note being edited, or brings it forward
+    /// when the note is already open.
+    @MainActor
+    func openEditor(for viewModel: LauncherViewModel) {
+        if let target = viewModel.activeEditorTarget {
+            self(id: "editor", value: target)
+        }
+    }
+}
+
 struct EditorWindowView: View {
     @EnvironmentObject private var viewModel: LauncherViewModel
     @EnvironmentObject private var themeManager: ThemeManager
+    /// What this window shows; nil for a window opened without a note.
+    let target: EditorWindowTarget?
+    @State private var window: NSWindow?
 
     var body: some View {
         Group {
-            if viewModel.selectedItem == nil && viewModel.deletedPreviewItem == nil {
+            if let target, target == viewModel.activeEditorTarget {
+                EditorSheet(viewModel: viewModel)
+            } else if case .item(let itemId)? = target, let session = viewModel.backgroundEditors[itemId] {
+                BackgroundEditorView(session: session)
+            } else {
                 VStack(spacing: 10) {
                     Text("No item selected")
                         .font(.system(size: 17, weight: .semibold))
@@ -620,23 +1263,68 @@ struct EditorWindowView: View {
                         .foregroundStyle(.secondary)
                 }
                 .frame(maxWidth: .infinity, maxHeight: .infinity)
-            } else {
-                EditorSheet(viewModel: viewModel)
             }
         }
         .background(themeManager.colors.editorBackground)
         .background(
             WindowAccessor { window in
-                viewModel.registerEditorWindow(window)
+                if self.window !== window {
+                    self.window = window
+                }
+                if let target {
+                    viewModel.registerEditorWindow(window, for: target)
+                }
             }
         )
+        .onReceive(NotificationCenter.default.publisher(for: NSWindow.didBecomeKeyNotification)) { notification in
+            guard let target, let window, notification.object as? NSWindow === window else {
+                return
+            }
+            Task {
+                await viewModel.activateEditor(target, in: window)
+            }
+        }
         .onDisappear {
-            viewModel.editorDidClose()
+            viewModel.editorDidClose(target)
+        }
+    }
+}
+
+/// A note open in an editor window that isn't key, shown as it was left;
+/// clicking the window makes it the note being edited.
+private struct BackgroundEditorView: View {
+    let session: EditorSession
+    @EnvironmentObject private var themeManager: ThemeManager
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 12) {
+            Text(session.item.title)
+                .font(.system(size: 20, weight: .semibold))
+                .foregroundStyle(themeManager.colors.itemTitleText)
+                .lineLimit(1)
+
+            InlineImageTextEditor(
+                text: .constant(session.text),
+                imagesByKey: Dictionary(uniqueKeysWithValues: session.item.images.map { ($0.imageKey, $0.bytes) }),
+                isEditable: false,
+                highlightSearchMatches: false,
+                dividerColor: themeManager.editorDividerColor,
+                dividerTopMargin: themeManager.editorDividerTopMargin,
+                dividerBottomMargin: themeManager.editorDividerBottomMargin,
+                fontSize: themeManager.scaledEditorFontSize,
+                scrollRestoreOffset: session.scrollOffset,
+                scrollRestoreRequestID: 1
+            )
+            .padding(10)
+            .background(themeManager.colors.editorTextBackground)
+            .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
         }
+        .padding(16)
+        .frame(maxWidth: .infinity, maxHeight: .infinity, alignment: .topLeading)
     }
 }
 
-private struct WindowAccessor: NSViewRepresentable {
+struct WindowAccessor: NSViewRepresentable {
     let onResolve: (NSWindow) -> Void
 
     func makeNSView(context: Context) -> NSView {
@@ -660,6 +1348,47 @@ private struct WindowAccessor: NSViewRepresentable {
     }
 }
 
+/// Offers a dragged result as plain text and, for Finder and other file drop
+/// targets, as a Markdown file exported when the drop happens.
+private func resultDragItemProvider(for item: SearchResultRecord) -> NSItemProvider {
+    let itemId = item.id
+    let provider = NSItemProvider()
+    provider.suggestedName = item.title
+
+    provider.registerFileRepresentation(
+        forTypeIdentifier: markdownTypeIdentifier,
+        fileOptions: [],
+        visibility: .all
+    ) { completion in
+        do {
+            let export = try RustBridgeClient.markdown(itemId: itemId)
+            let folder = FileManager.default.temporaryDirectory
+                .appendingPathComponent(UUID().uuidString, isDirectory: true)
+            try FileManager.default.createDirectory(at: folder, withIntermediateDirectories: true)
+            let fileURL = folder.appendingPathComponent(export.fileName, isDirectory: false)
+            try export.markdown.write(to: fileURL, atomically: true, encoding: .utf8)
+            completion(fileURL, false, nil)
+        } catch {
+            completion(nil, false, error)
+        }
+        return nil
+    }
+
+    provider.registerDataRepresentation(
+        forTypeIdentifier: UTType.utf8PlainText.identifier,
+        visibility: .all
+    ) { completion in
+        do {
+            completion(Data(try RustBridgeClient.plainText(itemId: itemId).utf8), nil)
+        } catch {
+            completion(nil, error)
+        }
+        return nil
+    }
+
+    return provider
+}
+
 private struct WindowDragHandle: NSViewRepresentable {
     let inset: CGFloat
 
@@ -709,6 +1438,34 @@ private struct WindowDragHandle: NSViewRepresentable {
     }
 }
 
+private struct ResultThumbnail: View {
+    let itemId: Int64
+    let imageKey: String
+    @State private var image: NSImage?
+
+    init(itemId: Int64, imageKey: String) {
+        self.itemId = itemId
+        self.imageKey = imageKey
+        _image = State(initialValue: ResultThumbnailCache.shared.cachedThumbnail(itemId: itemId, imageKey: imageKey))
+    }
+
+    var body: some View {
+        Group {
+            if let image {
+                Image(nsImage: image)
+                    .resizable()
+                    .aspectRatio(contentMode: .fill)
+            } else {
+                Color.secondary.opacity(0.15)
+            }
+        }
+        .frame(width: 28, height: 28)
+        .clipShape(RoundedRectangle(cornerRadius: 5, style: .continuous))
+        .task(id: "\(itemId)/\(imageKey)") {
+            image = await ResultThumbnailCache.shared.thumbnail(itemId: itemId, imageKey: imageKey)
+        }
+    }
+}
 
 private struct ResultRow: View, Equatable {
     private struct SnippetSegment {
@@ -718,62 +1475,132 @@ private struct ResultRow: View, Equatable {
 
     let item: SearchResultRecord
     let isSelected: Bool
+    let isMarked: Bool
     let onActivate: () -> Void
     @EnvironmentObject var themeManager: ThemeManager
 
     static func == (lhs: ResultRow, rhs: ResultRow) -> Bool {
         lhs.item.id == rhs.item.id &&
             lhs.item.title == rhs.item.title &&
+            lhs.item.titleMatchRanges == rhs.item.titleMatchRanges &&
             lhs.item.snippet == rhs.item.snippet &&
-            lhs.isSelected == rhs.isSelected
+            lhs.item.snippetSource == rhs.item.snippetSource &&
+            lhs.item.icon == rhs.item.icon &&
+            lhs.item.pinned == rhs.item.pinned &&
+            lhs.item.thumbnailKey == rhs.item.thumbnailKey &&
+            lhs.item.checklistProgress == rhs.item.checklistProgress &&
+            lhs.isSelected == rhs.isSelected &&
+            lhs.isMarked == rhs.isMarked
     }
 
     var body: some View {
         Button(action: onActivate) {
-            VStack(alignment: .leading, spacing: 2) {
-                Text(item.title)
-                    .font(.system(size: themeManager.itemTitleFontSize, weight: .semibold))
-                    .foregroundStyle(isSelected ? themeManager.colors.selectedItemTitleText : themeManager.colors.itemTitleText)
+            HStack(spacing: 10) {
+                if isMarked {
+                    Image(systemName: "checkmark.circle.fill")
+                        .font(.system(size: themeManager.scaledItemTitleFontSize - 2))
+                        .foregroundStyle(isSelected ? themeManager.colors.selectedItemTitleText : themeManager.colors.accentColor)
+                }
+
+                if let thumbnailKey = item.thumbnailKey {
+                    ResultThumbnail(itemId: item.id, imageKey: thumbnailKey)
+                } else if let icon = item.icon ?? (item.kind == .item ? "doc.text" : nil) {
+                    Image(systemName: icon)
+                        .font(.system(size: themeManager.scaledItemTitleFontSize - 2, weight: .medium))
+                        .frame(width: 28)
+                        .foregroundStyle(isSelected ? themeManager.colors.selectedItemSubtitleText : themeManager.colors.itemSubtitleText)
+                }
 
-                if let snippetSegments = visibleSnippetSegments {
-                    highlightedSnippetText(from: snippetSegments, isSelected: isSelected)
-                        .lineLimit(2)
+                VStack(alignment: .leading, spacing: 2) {
+                    highlightedTitleText(isSelected: isSelected)
+                        .font(themeManager.metrics.font(size: themeManager.scaledItemTitleFontSize, weight: .semibold))
+                        .foregroundStyle(isSelected ? themeManager.colors.selectedItemTitleText : themeManager.colors.itemTitleText)
+
+                    if let snippetFragments = visibleSnippetFragments {
+                        if snippetFragments.count == 1 {
+                            highlightedSnippetText(from: snippetFragments[0], isSelected: isSelected)
+                                .lineLimit(2)
+                        } else {
+                            // Fragments far apart in the note get a line each, so neither is cut off.
+                            ForEach(snippetFragments.indices, id: \.self) { index in
+                                let leader = index > 0 ? [SnippetSegment(text: "… ", isHighlighted: false)] : []
+                                highlightedSnippetText(from: leader + snippetFragments[index], isSelected: isSelected)
+                                    .lineLimit(1)
+                            }
+                        }
+                    } else if item.kind != .item, !item.subtitle.isEmpty {
+                        Text(item.subtitle)
+                            .font(themeManager.metrics.font(size: themeManager.scaledItemSubtitleFontSize))
+                            .foregroundStyle(isSelected ? themeManager.colors.selectedItemSubtitleText : themeManager.colors.itemSubtitleText)
+                            .lineLimit(1)
+                    }
+                }
+
+                if let progress = item.checklistProgress {
+                    Spacer(minLength: 8)
+                    Text("\(progress.done)/\(progress.total) done")
+                        .font(themeManager.metrics.font(size: themeManager.scaledItemSubtitleFontSize))
+                        .monospacedDigit()
+                        .foregroundStyle(isSelected ? themeManager.colors.selectedItemSubtitleText : themeManager.colors.itemSubtitleText)
+                }
+
+                if item.pinned {
+                    Spacer(minLength: 8)
+                    Image(systemName: "pin.fill")
+                        .font(.system(size: themeManager.scaledItemSubtitleFontSize))
+                        .rotationEffect(.degrees(45))
+                        .foregroundStyle(isSelected ? themeManager.colors.selectedItemSubtitleText : themeManager.colors.accentColor)
+                        .help("Pinned")
                 }
             }
             .frame(maxWidth: .infinity, alignment: .leading)
             .padding(.horizontal, 10)
             .padding(.vertical, 7)
-            .background(isSelected ? themeManager.colors.selectedItemBackground : themeManager.colors.itemBackground)
+            .background(
+                RoundedRectangle(cornerRadius: themeManager.metrics.resultRowCornerRadius, style: .continuous)
+                    .fill(isSelected ? themeManager.colors.selectedItemBackground : themeManager.colors.itemBackground)
+            )
         }
         .buttonStyle(.plain)
+        .accessibilityElement(children: .ignore)
+        .accessibilityLabel(isMarked ? "\(item.accessibilityDescription), marked" : item.accessibilityDescription)
+        .accessibilityAddTraits(isSelected ? [.isButton, .isSelected] : .isButton)
+        .accessibilityAction(named: "Open", onActivate)
     }
 
-    private var visibleSnippetSegments: [SnippetSegment]? {
+    /// The snippet's fragments, split at the backend's " … " separator.
+    private var visibleSnippetFragments: [[SnippetSegment]]? {
         guard let snippet = item.snippet else {
             return nil
         }
 
-        let trimmed = snippet.trimmingCharacters(in: .whitespacesAndNewlines)
-        guard !trimmed.isEmpty else {
-            return nil
-        }
+        var fragments = snippet.components(separatedBy: " … ").compactMap { fragment -> [SnippetSegment]? in
+            let trimmed = fragment.trimmingCharacters(in: .whitespacesAndNewlines)
+            guard !trimmed.isEmpty else {
+                return nil
+            }
 
-        let segments = parseSnippetSegments(trimmed)
-        let hasVisibleText = segments.contains { segment in
-            !segment.text.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty
+            let segments = parseSnippetSegments(trimmed)
+            let hasVisibleText = segments.contains { segment in
+                !segment.text.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty
+            }
+            return hasVisibleText ? segments : nil
         }
-        guard hasVisibleText else {
+        guard !fragments.isEmpty else {
             return nil
         }
-
-        return segments
+        // Text recognized in an image is labeled so the match isn't looked for in the note.
+        if LauncherViewModel.imageKey(fromSnippetSource: item.snippetSource) != nil {
+            fragments[0].insert(SnippetSegment(text: imageSnippetSourcePrefix + " ", isHighlighted: false), at: 0)
+        }
+        return fragments
     }
 
     private func highlightedSnippetText(from segments: [SnippetSegment], isSelected: Bool) -> Text {
         var attributed = AttributedString()
         for segment in segments {
             var part = AttributedString(segment.text)
-            part.font = .system(size: themeManager.itemSubtitleFontSize, weight: .regular)
+            part.font = themeManager.metrics.font(size: themeManager.scaledItemSubtitleFontSize)
             
             // Use different colors based on selection state
             if segment.isHighlighted {
@@ -795,6 +1622,55 @@ private struct ResultRow: View, Equatable {
         return Text(attributed)
     }
 
+    /// The title with the query matches found by the backend set on the highlight background.
+    private func highlightedTitleText(isSelected: Bool) -> Text {
+        let segments = titleSegments
+        guard segments.contains(where: \.isHighlighted) else {
+            return Text(item.title)
+        }
+
+        var attributed = AttributedString()
+        for segment in segments {
+            var part = AttributedString(segment.text)
+            if segment.isHighlighted {
+                part.backgroundColor = themeManager.colors.highlightBackground
+            }
+            attributed.append(part)
+        }
+        return Text(attributed)
+    }
+
+    /// Splits the title at the match ranges, which are UTF-16 offsets.
+    private var titleSegments: [SnippetSegment] {
+        let title = item.title
+        let utf16Count = title.utf16.count
+        var segments: [SnippetSegment] = []
+        var cursor = title.startIndex
+
+        for range in item.titleMatchRanges {
+            let startOffset = Int(range.start)
+            let endOffset = startOffset + Int(range.length)
+            guard endOffset <= utf16Count else {
+                break
+            }
+            let start = String.Index(utf16Offset: startOffset, in: title)
+            let end = String.Index(utf16Offset: endOffset, in: title)
+            guard cursor <= start, start < end else {
+                continue
+            }
+            if cursor < start {
+                segments.append(SnippetSegment(text: String(title[cursor..<start]), isHighlighted: false))
+            }
+            segments.append(SnippetSegment(text: String(title[start..<end]), isHighlighted: true))
+            cursor = end
+        }
+
+        if cursor < title.endIndex {
+            segments.append(SnippetSegment(text: String(title[cursor...]), isHighlighted: false))
+        }
+        return segments
+    }
+
     private func parseSnippetSegments(_ snippet: String) -> [SnippetSegment] {
         var segments: [SnippetSegment] = []
         var buffer = String()
@@ -831,8 +1707,11 @@ private struct ResultRow: View, Equatable {
 private struct ResultsListView: View {
     let results: [SearchResultRecord]
     @Binding var selectedIndex: Int
+    let markedItemIDs: Set<Int64>
     @Binding var resultsScrollProxy: ScrollViewProxy?
     let onActivate: (Int) -> Void
+    /// Called for Cmd+click (`false`) and Shift+click (`true`, extend range).
+    let onMark: (Int, Bool) -> Void
     let onScrollProxySet: (ScrollViewProxy) -> Void
     let onScrollSelection: (ScrollViewProxy, Bool) -> Void
     @EnvironmentObject var themeManager: ThemeManager
@@ -859,9 +1738,14 @@ private struct ResultsListView: View {
                 ResultsListItems(
                     results: results,
                     selectedIndex: $selectedIndex,
-                    onActivate: onActivate
+                    markedItemIDs: markedItemIDs,
+                    onActivate: onActivate,
+                    onMark: onMark
                 )
             }
+            .accessibilityElement(children: .contain)
+            .accessibilityLabel("Results")
+            .accessibilityValue(AccessibilityAnnouncer.resultCountDescription(results.count))
             .onAppear {
                 onScrollProxySet(proxy)
             }
@@ -869,19 +1753,53 @@ private struct ResultsListView: View {
     }
 }
 
+private extension SearchResultSection {
+    var title: String {
+        switch self {
+        case .answers:
+            return "Answers"
+        case .commands:
+            return "Commands"
+        case .notes:
+            return "Notes"
+        case .providers:
+            return "Other Sources"
+        }
+    }
+}
+
 private struct ResultsListItems: View {
     let results: [SearchResultRecord]
     @Binding var selectedIndex: Int
+    let markedItemIDs: Set<Int64>
     let onActivate: (Int) -> Void
+    let onMark: (Int, Bool) -> Void
     @EnvironmentObject var themeManager: ThemeManager
     
     var body: some View {
+        let showsSections = Set(results.map(\.section)).count > 1
         VStack(spacing: 0) {
             ForEach(Array(results.enumerated()), id: \.element.id) { idx, item in
+                if showsSections && (idx == 0 || results[idx - 1].section != item.section) {
+                    sectionHeader(item.section)
+                        .id("section_\(idx)")
+                }
                 resultsItem(at: idx, item: item)
             }
         }
     }
+
+    private func sectionHeader(_ section: SearchResultSection) -> some View {
+        Text(section.title.uppercased())
+            .font(.system(size: 10, weight: .semibold))
+            .foregroundStyle(themeManager.colors.placeholderText)
+            .frame(maxWidth: .infinity, alignment: .leading)
+            .padding(.horizontal, 8)
+            .padding(.top, 6)
+            .padding(.bottom, 2)
+            .accessibilityLabel(section.title)
+            .accessibilityAddTraits(.isHeader)
+    }
     
     private func resultsItem(at idx: Int, item: SearchResultRecord) -> some View {
         let isSelected = idx == selectedIndex
@@ -889,18 +1807,28 @@ private struct ResultsListItems: View {
             ResultRow(
                 item: item,
                 isSelected: isSelected,
+                isMarked: markedItemIDs.contains(item.id),
                 onActivate: {
+                    let modifiers = NSEvent.modifierFlags
+                    if modifiers.contains(.command) || modifiers.contains(.shift) {
+                        onMark(idx, modifiers.contains(.shift))
+                        return
+                    }
                     selectedIndex = idx
                     onActivate(idx)
                 }
             )
             .environmentObject(themeManager)
+            .onDrag {
+                item.kind == .item ? resultDragItemProvider(for: item) : NSItemProvider()
+            }
             // Stable ID combining item ID with index to prevent re-rendering issues
             .id("\(idx)_\(item.id)")
             
             if idx + 1 < results.count {
                 Divider()
                     .id("divider_\(idx)")
+                    .accessibilityHidden(true)
             }
         }
     }
@@ -987,6 +1915,8 @@ struct SettingsWindowView: View {
     @EnvironmentObject var autoUpdater: AutoUpdater
     @EnvironmentObject var themeManager: ThemeManager
     @StateObject private var hotKeyManager = HotKeyManager.shared
+    @StateObject private var frameTiming = FrameTimingMonitor.shared
+    @StateObject private var powerSaving = PowerSavingMonitor.shared
     @FocusState private var pathFieldFocused: Bool
     @State private var selectedTab: SettingsTab = .general
     @State private var isRecordingHotKey: Bool = false
@@ -999,6 +1929,9 @@ struct SettingsWindowView: View {
         case appearance = "Appearance"
         case editor = "Editor"
         case hotkeys = "Hotkeys"
+        case schedules = "Schedules"
+        case automation = "Automation"
+        case storage = "Storage"
         
         var id: String { rawValue }
         
@@ -1008,6 +1941,17 @@ struct SettingsWindowView: View {
             case .appearance: return "paintbrush"
             case .editor: return "doc.text"
             case .hotkeys: return "keyboard"
+            case .schedules: return "calendar.badge.clock"
+            case .automation: return "bolt"
+            case .storage: return "internaldrive"
+            }
+        }
+
+        /// Tabs hidden in launcher-only mode, where there are no notes to act on.
+        var needsNoteStore: Bool {
+            switch self {
+            case .schedules, .automation, .storage: return true
+            case .general, .appearance, .editor, .hotkeys: return false
             }
         }
     }
@@ -1018,7 +1962,7 @@ struct SettingsWindowView: View {
         VStack(alignment: .leading, spacing: 0) {
             // Tab picker
             HStack(spacing: 0) {
-                ForEach(SettingsTab.allCases) { tab in
+                ForEach(SettingsTab.allCases.filter { !viewModel.isLauncherOnly || !$0.needsNoteStore }) { tab in
                     TabButton(
                         tab: tab,
                         isSelected: selectedTab == tab,
@@ -1043,6 +1987,12 @@ struct SettingsWindowView: View {
                     editorTab
                 case .hotkeys:
                     hotkeysTab
+                case .schedules:
+                    ScheduleSettingsView()
+                case .automation:
+                    AutomationSettingsView()
+                case .storage:
+                    StorageSettingsView()
                 }
             }
             .padding(18)
@@ -1103,47 +2053,128 @@ struct SettingsWindowView: View {
         }
     }
     
+    private var launcherOnlySection: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            Toggle("Launcher only (no note storage)", isOn: Binding(
+                get: { viewModel.settingsLauncherOnly },
+                set: { viewModel.setLauncherOnly($0) }
+            ))
+            .font(.system(size: 13))
+
+            Text(launcherOnlyDescription)
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+        }
+    }
+
+    private var launcherOnlyDescription: String {
+        let base = "Turns off notes and their search index so the launcher only runs commands. Stored notes stay on disk and come back when this is turned off."
+        guard viewModel.settingsLauncherOnly != viewModel.isLauncherOnly else {
+            return base
+        }
+        return base + " Takes effect after restarting the app."
+    }
+
     private var generalTab: some View {
         VStack(alignment: .leading, spacing: 16) {
-            VStack(alignment: .leading, spacing: 8) {
-                Text("JSON Storage Folder")
-                    .font(.system(size: 12, weight: .medium))
-                    .foregroundStyle(.secondary)
+            launcherOnlySection
+
+            Divider()
+
+            if !viewModel.isLauncherOnly {
+                VStack(alignment: .leading, spacing: 8) {
+                    Text("JSON Storage Folder")
+                        .font(.system(size: 12, weight: .medium))
+                        .foregroundStyle(.secondary)
                 
-                TextField("Folder path", text: $viewModel.settingsStorageDirectoryPath)
-                    .font(.system(size: 13, design: .monospaced))
-                    .textFieldStyle(.roundedBorder)
-                    .focused($pathFieldFocused)
-            }
-            
-            if let settingsErrorMessage = viewModel.settingsErrorMessage {
-                Text(settingsErrorMessage)
-                    .font(.system(size: 12))
-                    .foregroundStyle(themeManager.colors.errorColor)
-            }
+                    TextField("Folder path", text: $viewModel.settingsStorageDirectoryPath)
+                        .font(.system(size: 13, design: .monospaced))
+                        .textFieldStyle(.roundedBorder)
+                        .focused($pathFieldFocused)
+                }
+
+                VStack(alignment: .leading, spacing: 8) {
+                    Text("Don't Mirror Items Tagged")
+                        .font(.system(size: 12, weight: .medium))
+                        .foregroundStyle(.secondary)
+
+                    TextField("#private, #work", text: $viewModel.settingsMirrorExcludedTags)
+                        .font(.system(size: 13))
+                        .textFieldStyle(.roundedBorder)
+
+                    Text("Items with these tags, and items marked secure in the editor, stay in the local index and are removed from the JSON folder.")
+                        .font(.system(size: 11))
+                        .foregroundStyle(.secondary)
+                }
+
+                AppLockSettingsSection()
+
+                VStack(alignment: .leading, spacing: 8) {
+                    Toggle("Write as an Obsidian vault", isOn: $viewModel.settingsObsidianVaultMode)
+                        .font(.system(size: 13))
+
+                    Text("Keeps one Markdown note per item with images in an attachments folder instead of JSON files. Notes edited or added in the vault, including .org files, are imported when the launcher opens.")
+                        .font(.system(size: 11))
+                        .foregroundStyle(.secondary)
+                }
             
-            if let settingsSuccessMessage = viewModel.settingsSuccessMessage {
-                Text(settingsSuccessMessage)
-                    .font(.system(size: 12))
-                    .foregroundStyle(themeManager.colors.successColor)
-            }
+                if let settingsErrorMessage = viewModel.settingsErrorMessage {
+                    Text(settingsErrorMessage)
+                        .font(.system(size: 12))
+                        .foregroundStyle(themeManager.colors.errorColor)
+                }
             
-            HStack {
-                Button("Browse...") {
-                    chooseStorageFolder()
+                if let settingsSuccessMessage = viewModel.settingsSuccessMessage {
+                    Text(settingsSuccessMessage)
+                        .font(.system(size: 12))
+                        .foregroundStyle(themeManager.colors.successColor)
                 }
+            
+                HStack {
+                    Button("Browse...") {
+                        chooseStorageFolder()
+                    }
                 
-                Button("Open in Finder") {
-                    openStorageFolder()
-                }
+                    Button("Open in Finder") {
+                        openStorageFolder()
+                    }
                 
-                Spacer()
+                    Spacer()
                 
-                Button("Save") {
-                    _ = viewModel.saveSettingsStorageDirectoryPath()
+                    Button("Save") {
+                        _ = viewModel.saveSettingsStorageDirectoryPath()
+                    }
+                    .keyboardShortcut("s", modifiers: .command)
+                }
+
+                Divider()
+
+                VStack(alignment: .leading, spacing: 8) {
+                    Text("Import")
+                        .font(.system(size: 12, weight: .medium))
+                        .foregroundStyle(.secondary)
+
+                    HStack {
+                        Button("Import Notion Export…") {
+                            chooseNotionExport()
+                        }
+                        .disabled(viewModel.isImportingNotionExport)
+
+                        if viewModel.isImportingNotionExport {
+                            ProgressView()
+                                .controlSize(.small)
+                        }
+                    }
+
+                    Text("Choose the ZIP from Notion's “Export › HTML” or its unpacked folder. Every page becomes a note; database rows are tagged with the database name.")
+                        .font(.system(size: 11))
+                        .foregroundStyle(.secondary)
                 }
-                .keyboardShortcut("s", modifiers: .command)
+
+                Divider()
             }
+
+            RecentErrorsLog()
             
             Divider()
             
@@ -1303,16 +2334,76 @@ struct SettingsWindowView: View {
                     }
                 }
             }
-            
-            // Font Sizes Section
-            VStack(alignment: .leading, spacing: 12) {
-                Text("Font Sizes")
-                    .font(.system(size: 14, weight: .medium))
-                
-                FontSizeRow(
-                    label: "Search Field",
-                    value: $themeManager.searchFieldFontSize,
-                    range: 20...40,
+
+            SearchDebounceSettingsSection()
+
+            RankingScriptSettingsSection()
+
+            ForgeAccountsSettingsSection()
+
+            TrackerAccountsSettingsSection()
+
+            VStack(alignment: .leading, spacing: 8) {
+                Text("Diagnostics")
+                    .font(.system(size: 12, weight: .medium))
+                    .foregroundStyle(.secondary)
+
+                Toggle("Show frame timings in the launcher", isOn: $frameTiming.isEnabled)
+                    .font(.system(size: 12))
+
+                HStack(spacing: 8) {
+                    Text("Log frames slower than")
+                    TextField(
+                        "\(Int(FrameTimingMonitor.defaultSlowFrameMilliseconds))",
+                        value: $frameTiming.slowFrameMilliseconds,
+                        format: .number
+                    )
+                    .textFieldStyle(.roundedBorder)
+                    .frame(width: 60)
+                    Text("ms")
+                        .foregroundStyle(.secondary)
+                }
+                .font(.system(size: 12))
+                .disabled(!frameTiming.isEnabled)
+
+                Text("Slow frames are written to the system log (Console.app, \"FrameTiming\") with the time spent on key events, applying search results, inline images and layout/drawing.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+
+                Toggle("Save power while hidden", isOn: $powerSaving.isEnabled)
+                    .font(.system(size: 12))
+
+                Text("Background workers sleep until they have work instead of checking every 30 seconds. Scheduled notes can be created up to 15 minutes late after the Mac wakes from sleep.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+
+                HStack(spacing: 8) {
+                    if let stats = powerSaving.backendStats {
+                        Text("Idle wakeups: \(powerSaving.hiddenMainThreadWakeups) main thread while hidden, \(stats.schedulerWakeups) scheduler (\(stats.powerSavingSchedulerWakeups) power saving)")
+                    } else {
+                        Text("Idle wakeups: \(powerSaving.hiddenMainThreadWakeups) main thread while hidden")
+                    }
+                    Spacer()
+                    Button("Refresh") {
+                        powerSaving.refreshStats()
+                    }
+                }
+                .font(.system(size: 12))
+                .foregroundStyle(.secondary)
+                .onAppear {
+                    powerSaving.refreshStats()
+                }
+            }
+            
+            // Font Sizes Section
+            VStack(alignment: .leading, spacing: 12) {
+                Text("Font Sizes")
+                    .font(.system(size: 14, weight: .medium))
+                
+                FontSizeRow(
+                    label: "Search Field",
+                    value: $themeManager.searchFieldFontSize,
+                    range: 20...40,
                     defaultValue: 30
                 )
                 
@@ -1336,6 +2427,27 @@ struct SettingsWindowView: View {
                     range: 12...24,
                     defaultValue: 15
                 )
+
+                HStack {
+                    Text("Scale")
+                        .font(.system(size: 13))
+
+                    Spacer()
+
+                    Text("\(Int((themeManager.uiScale * 100).rounded()))%")
+                        .font(.system(size: 12, design: .monospaced))
+                        .foregroundStyle(.secondary)
+                        .frame(width: 45, alignment: .trailing)
+
+                    Stepper("", value: $themeManager.uiScale, in: ThemeManager.uiScaleRange, step: 0.1)
+                        .labelsHidden()
+                        .frame(width: 80)
+                }
+                .padding(.horizontal, 12)
+                .padding(.vertical, 8)
+                .background(Color(nsColor: .controlBackgroundColor))
+                .clipShape(RoundedRectangle(cornerRadius: 8, style: .continuous))
+                .help("Scales all of the sizes above")
                 
                 HStack {
                     Spacer()
@@ -1380,7 +2492,7 @@ struct SettingsWindowView: View {
 
             dismissWindow(id: "settings")
             viewModel.beginEditorPresentation()
-            openWindow(id: "editor")
+            openWindow.openEditor(for: viewModel)
         }
     }
 
@@ -1397,7 +2509,7 @@ struct SettingsWindowView: View {
 
             dismissWindow(id: "settings")
             viewModel.beginEditorPresentation()
-            openWindow(id: "editor")
+            openWindow.openEditor(for: viewModel)
         }
     }
 
@@ -1410,6 +2522,8 @@ struct SettingsWindowView: View {
                 Text("Choose a base theme. You can customize any theme's colors below.")
                     .font(.system(size: 12))
                     .foregroundStyle(.secondary)
+
+                systemAppearanceSection
                 
                 LazyVGrid(columns: [
                     GridItem(.adaptive(minimum: 160, maximum: 200), spacing: 16)
@@ -1436,6 +2550,65 @@ struct SettingsWindowView: View {
                     }
                 }
                 
+                Divider()
+                    .padding(.vertical, 8)
+
+                themeFilesSection
+
+                Divider()
+                    .padding(.vertical, 8)
+
+                VStack(alignment: .leading, spacing: 8) {
+                    Text("Launcher")
+                        .font(.system(size: 14, weight: .medium))
+
+                    Toggle("Show a preview of the selected note next to the results", isOn: $themeManager.launcherPreviewEnabled)
+                        .font(.system(size: 12))
+
+                    HStack(spacing: 12) {
+                        Text("Background opacity")
+                            .font(.system(size: 12))
+                        Slider(value: $themeManager.launcherOpacity, in: ThemeManager.launcherOpacityRange)
+                            .frame(width: 140)
+                        Text("\(Int((themeManager.launcherOpacity * 100).rounded()))%")
+                            .font(.system(size: 12, design: .monospaced))
+                    }
+
+                    Toggle("Blur what is behind the launcher", isOn: $themeManager.launcherBlurEnabled)
+                        .font(.system(size: 12))
+
+                    HStack(spacing: 12) {
+                        Text("Width")
+                            .font(.system(size: 12))
+                        Slider(value: $themeManager.launcherWidth, in: ThemeManager.launcherWidthRange, step: 20)
+                            .frame(width: 140)
+                        Text("\(Int(themeManager.launcherWidth)) pt")
+                            .font(.system(size: 12, design: .monospaced))
+                    }
+
+                    HStack(spacing: 12) {
+                        Text("Distance from top")
+                            .font(.system(size: 12))
+                        Slider(value: $themeManager.launcherTopOffsetPercent, in: ThemeManager.launcherTopOffsetRange, step: 1)
+                            .frame(width: 140)
+                        Text("\(Int(themeManager.launcherTopOffsetPercent))%")
+                            .font(.system(size: 12, design: .monospaced))
+                    }
+
+                    Text("The launcher is centered horizontally on the display it opens on. Below \(Int(launcherPreviewMinimumWidth)) pt the note preview is hidden.")
+                        .font(.system(size: 11))
+                        .foregroundStyle(.secondary)
+                }
+                .onChange(of: themeManager.launcherWidth) { _, _ in
+                    // The window takes its new width on the next layout pass.
+                    DispatchQueue.main.async {
+                        viewModel.recenterLauncher()
+                    }
+                }
+                .onChange(of: themeManager.launcherTopOffsetPercent) { _, _ in
+                    viewModel.recenterLauncher()
+                }
+
                 Divider()
                     .padding(.vertical, 8)
                 
@@ -1447,6 +2620,133 @@ struct SettingsWindowView: View {
         }
     }
 
+    private var systemAppearanceSection: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            Toggle("Match system appearance", isOn: $themeManager.followSystemAppearance)
+                .font(.system(size: 12))
+            Toggle("Switch by time of day", isOn: $themeManager.followTimeOfDay)
+                .font(.system(size: 12))
+
+            if themeManager.followTimeOfDay {
+                HStack(spacing: 16) {
+                    DatePicker("Light from", selection: timeOfDayBinding(\.lightThemeStartMinute), displayedComponents: .hourAndMinute)
+                        .frame(width: 220)
+                    DatePicker("Dark from", selection: timeOfDayBinding(\.darkThemeStartMinute), displayedComponents: .hourAndMinute)
+                        .frame(width: 220)
+                }
+                .font(.system(size: 12))
+            }
+
+            if themeManager.followSystemAppearance || themeManager.followTimeOfDay {
+                HStack(spacing: 16) {
+                    Picker("Light", selection: $themeManager.lightThemeId) {
+                        ForEach(themeManager.selectableThemes) { theme in
+                            Text(theme.name).tag(theme.id)
+                        }
+                    }
+                    .frame(width: 220)
+
+                    Picker("Dark", selection: $themeManager.darkThemeId) {
+                        ForEach(themeManager.selectableThemes) { theme in
+                            Text(theme.name).tag(theme.id)
+                        }
+                    }
+                    .frame(width: 220)
+                }
+                .font(.system(size: 12))
+
+                Text("Picking a theme below turns this off.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+            }
+        }
+    }
+
+    /// A time picker's date for a schedule minute of the theme manager; only
+    /// its hour and minute are used.
+    private func timeOfDayBinding(_ keyPath: ReferenceWritableKeyPath<ThemeManager, Int>) -> Binding<Date> {
+        Binding(
+            get: {
+                let minute = themeManager[keyPath: keyPath]
+                return Calendar.current.date(
+                    bySettingHour: minute / 60,
+                    minute: minute % 60,
+                    second: 0,
+                    of: Date()
+                ) ?? Date()
+            },
+            set: { date in
+                let parts = Calendar.current.dateComponents([.hour, .minute], from: date)
+                themeManager[keyPath: keyPath] = (parts.hour ?? 0) * 60 + (parts.minute ?? 0)
+            }
+        )
+    }
+
+    private var themeFilesSection: some View {
+        VStack(alignment: .leading, spacing: 12) {
+            Text("Theme Files")
+                .font(.system(size: 14, weight: .medium))
+
+            Text("JSON themes in the themes folder can set colors, corner radii, a font family and shadows. Changes to the selected file apply immediately.")
+                .font(.system(size: 12))
+                .foregroundStyle(.secondary)
+
+            if !themeManager.userThemes.isEmpty {
+                LazyVGrid(columns: [
+                    GridItem(.adaptive(minimum: 160, maximum: 200), spacing: 16)
+                ], spacing: 16) {
+                    ForEach(themeManager.userThemes) { theme in
+                        ThemeCard(
+                            theme: theme,
+                            isSelected: themeManager.currentTheme.id == theme.id
+                        ) {
+                            themeManager.setTheme(theme)
+                        }
+                    }
+                }
+            }
+
+            ForEach(themeManager.userThemeErrors, id: \.self) { message in
+                Text(message)
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+
+            HStack {
+                Button("Open Themes Folder") {
+                    try? FileManager.default.createDirectory(at: themeManager.themesDirectoryURL, withIntermediateDirectories: true)
+                    NSWorkspace.shared.open(themeManager.themesDirectoryURL)
+                }
+
+                Button("Save Current Theme as File") {
+                    if let fileURL = try? themeManager.exportCurrentThemeFile() {
+                        NSWorkspace.shared.activateFileViewerSelecting([fileURL])
+                    }
+                }
+
+                Button("Reload") {
+                    themeManager.reloadUserThemes()
+                }
+            }
+
+            Text("`preview.css` in the same folder styles the rendered Markdown preview: `body` (font-family, font-size, max-width), `h1`–`h3` (font-size) and `code` (font-family, color, background).")
+                .font(.system(size: 12))
+                .foregroundStyle(.secondary)
+
+            ForEach(themeManager.previewStyle?.warnings ?? [], id: \.self) { warning in
+                Text("preview.css: \(warning)")
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+
+            Button("Edit Preview Style") {
+                if let fileURL = try? themeManager.previewStyleFile() {
+                    NSWorkspace.shared.open(fileURL)
+                }
+            }
+        }
+    }
+
     private var editorTab: some View {
         ScrollView {
             VStack(alignment: .leading, spacing: 16) {
@@ -1486,6 +2786,63 @@ struct SettingsWindowView: View {
                     }
                 }
 
+                Divider()
+                    .padding(.vertical, 8)
+
+                ImageLimitsSettingsSection()
+
+                Divider()
+                    .padding(.vertical, 8)
+
+                EditorMacrosSettingsSection()
+
+                VStack(alignment: .leading, spacing: 8) {
+                    Toggle("Name pasted links after their page title", isOn: $viewModel.preferences.fetchesPastedLinkTitles)
+                        .font(.system(size: 12))
+                    Text("A web address pasted on its own becomes a `[Title](url)` link once the page is fetched. Web addresses in notes open on ⌘-click either way.")
+                        .font(.system(size: 12))
+                        .foregroundStyle(.secondary)
+
+                    Toggle("Use Vim keys in the editor", isOn: $viewModel.preferences.usesVimKeys)
+                        .font(.system(size: 12))
+                    Text("Notes open in Normal mode: i, a or o start typing and Esc goes back. Motions, dd, yy, p and Visual mode work as in Vim; Esc in Normal mode closes the note.")
+                        .font(.system(size: 12))
+                        .foregroundStyle(.secondary)
+
+                    HStack(spacing: 8) {
+                        Text("Autosave after")
+                            .frame(width: 140, alignment: .leading)
+                        TextField(
+                            "\(LauncherPreferences.defaults.autosaveDelayMilliseconds)",
+                            value: $viewModel.preferences.autosaveDelayMilliseconds,
+                            format: .number
+                        )
+                        .textFieldStyle(.roundedBorder)
+                        .frame(width: 70)
+                        Text("ms without typing (\(LauncherPreferences.autosaveDelayRange.lowerBound)–\(LauncherPreferences.autosaveDelayRange.upperBound))")
+                            .foregroundStyle(.secondary)
+                    }
+                    .font(.system(size: 12))
+
+                    HStack(spacing: 8) {
+                        Text("Image memory")
+                            .frame(width: 140, alignment: .leading)
+                        Picker("", selection: $themeManager.imageCacheBudgetMegabytes) {
+                            ForEach(InlineImageCache.budgetMegabyteChoices, id: \.self) { megabytes in
+                                Text(megabytes >= 1024 ? "\(megabytes / 1024) GB" : "\(megabytes) MB")
+                                    .tag(megabytes)
+                            }
+                        }
+                        .labelsHidden()
+                        .frame(width: 120)
+                    }
+                    .font(.system(size: 12))
+
+                    Text("Decoded images are shared by all open notes; the least recently shown are dropped once this is exceeded.")
+                        .font(.system(size: 12))
+                        .foregroundStyle(.secondary)
+                }
+
                 Spacer(minLength: 20)
             }
             .padding(.bottom, 10)
@@ -1804,6 +3161,24 @@ struct SettingsWindowView: View {
             viewModel.settingsSuccessMessage = nil
         }
     }
+
+    private func chooseNotionExport() {
+        let panel = NSOpenPanel()
+        panel.title = "Import Notion Export"
+        panel.message = "Choose a Notion HTML export ZIP or the folder it unpacks to."
+        panel.canChooseDirectories = true
+        panel.canChooseFiles = true
+        panel.allowedContentTypes = [.zip, .folder]
+        panel.allowsMultipleSelection = false
+
+        guard panel.runModal() == .OK, let exportURL = panel.url else {
+            return
+        }
+
+        Task {
+            await viewModel.importNotionExport(from: exportURL)
+        }
+    }
 }
 
 // MARK: - Settings Key Event Monitor
@@ -1872,58 +3247,578 @@ private struct SettingsKeyEventMonitor: NSViewRepresentable {
     }
 }
 
-// Predefined themes computed once
-private let predefinedThemes: [AppTheme] = AppTheme.allThemes.filter { !$0.isCustom }
+/// Editor for the Rhai script that reorders launcher results.
+private struct RankingScriptSettingsSection: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var isExpanded = false
+    @State private var source = ""
+    @State private var savedSource = ""
+    @State private var status: String?
+    @State private var statusIsError = false
 
-private struct ThemeCard: View {
-    let theme: AppTheme
-    let isSelected: Bool
-    let action: () -> Void
-    @EnvironmentObject var themeManager: ThemeManager
-    
-    // Only observe custom colors for the custom theme card to reduce updates
-    private var displayColors: ThemeColors {
-        theme.isCustom ? themeManager.customColors : theme.colors
-    }
-    
-    // Use a stable ID for the card to prevent unnecessary re-renders
-    // Custom theme card needs to update when custom colors change
-    private var cardId: String {
-        if theme.isCustom {
-            // Use a simple string that changes when theme changes
-            return "custom-\(themeManager.currentTheme.isCustom)"
-        }
-        return theme.id
-    }
-    
     var body: some View {
-        Button(action: action) {
-            VStack(spacing: 0) {
-                // Preview area - use id to control when view updates
-                themePreview
+        DisclosureGroup("Ranking Script", isExpanded: $isExpanded) {
+            VStack(alignment: .leading, spacing: 8) {
+                Text("A Rhai expression that scores each result; higher scores come first and ties keep the built-in order. It can use tier (0 full-text, 1 substring, 2 fuzzy), score, position, age_days, tag_matches and pinned, e.g. \"score - tier * 2 + tag_matches * 3 - age_days / 30.0\". Leave it empty for the built-in order.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+                    .fixedSize(horizontal: false, vertical: true)
+
+                TextEditor(text: $source)
+                    .font(.system(size: 12, design: .monospaced))
                     .frame(height: 90)
-                
-                // Name label - only use accent color for selection state, not custom colors
-                Text(theme.name)
-                    .font(.system(size: 12, weight: isSelected ? .semibold : .medium))
-                    .foregroundStyle(selectionForegroundColor)
-                    .frame(maxWidth: .infinity)
-                    .padding(.vertical, 8)
-                    .background(selectionBackgroundColor)
+                    .overlay(RoundedRectangle(cornerRadius: 4).stroke(Color.secondary.opacity(0.3)))
+
+                HStack {
+                    if let status {
+                        Text(status)
+                            .font(.system(size: 11))
+                            .foregroundStyle(statusIsError ? themeManager.colors.errorColor : Color.secondary)
+                            .lineLimit(3)
+                    }
+                    Spacer()
+                    Button("Revert") {
+                        source = savedSource
+                        status = nil
+                    }
+                    .disabled(source == savedSource)
+                    Button("Save") {
+                        save()
+                    }
+                    .disabled(source == savedSource)
+                }
+                .controlSize(.small)
             }
+            .padding(.top, 6)
+        }
+        .font(.system(size: 12))
+        .onAppear {
+            savedSource = viewModel.loadRankingScript()
+            source = savedSource
         }
-        .buttonStyle(.plain)
-        .overlay(
-            RoundedRectangle(cornerRadius: 12, style: .continuous)
-                .stroke(selectionStrokeColor, lineWidth: 2)
-        )
-        .clipShape(RoundedRectangle(cornerRadius: 12, style: .continuous))
-        .id(cardId)
     }
-    
-    // Separate computed properties to minimize re-evaluation
-    private var selectionForegroundColor: Color {
-        isSelected ? themeManager.colors.accentColor : Color.primary
+
+    private func save() {
+        if let error = viewModel.saveRankingScript(source) {
+            status = error
+            statusIsError = true
+            return
+        }
+        savedSource = source.trimmingCharacters(in: .whitespacesAndNewlines)
+        source = savedSource
+        status = savedSource.isEmpty ? "Results use the built-in order." : "Saved; new searches use this order."
+        statusIsError = false
+    }
+}
+
+private struct ForgeAccountsSettingsSection: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var isExpanded = false
+    @State private var githubToken = ""
+    @State private var githubHost = ""
+    @State private var gitlabToken = ""
+    @State private var gitlabHost = ""
+    @State private var saved: [ForgeAccountRecord] = []
+    @State private var status: String?
+    @State private var statusIsError = false
+
+    var body: some View {
+        DisclosureGroup("GitHub & GitLab", isExpanded: $isExpanded) {
+            VStack(alignment: .leading, spacing: 8) {
+                Text("With a personal access token, \"gh name\" opens your recent and starred GitHub repositories and \"gh issues name query\" searches their issues; \"gl\" does the same for GitLab. Results are cached, so they still show up offline. Leave the host empty for github.com or gitlab.com.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+                    .fixedSize(horizontal: false, vertical: true)
+
+                accountFields(title: "GitHub", token: $githubToken, host: $githubHost, hostPlaceholder: "github.com")
+                accountFields(title: "GitLab", token: $gitlabToken, host: $gitlabHost, hostPlaceholder: "gitlab.com")
+
+                HStack {
+                    if let status {
+                        Text(status)
+                            .font(.system(size: 11))
+                            .foregroundStyle(statusIsError ? themeManager.colors.errorColor : Color.secondary)
+                            .lineLimit(3)
+                    }
+                    Spacer()
+                    Button("Save") {
+                        save()
+                    }
+                    .disabled(edited == saved)
+                }
+                .controlSize(.small)
+            }
+            .padding(.top, 6)
+        }
+        .font(.system(size: 12))
+        .onAppear {
+            load(viewModel.loadForgeAccounts())
+        }
+    }
+
+    private func accountFields(title: String, token: Binding<String>, host: Binding<String>, hostPlaceholder: String) -> some View {
+        HStack(spacing: 6) {
+            Text(title)
+                .frame(width: 50, alignment: .leading)
+            SecureField("Token", text: token)
+                .textFieldStyle(.roundedBorder)
+            TextField(hostPlaceholder, text: host)
+                .textFieldStyle(.roundedBorder)
+                .frame(width: 150)
+        }
+    }
+
+    /// The accounts as typed; a forge without a token is left out.
+    private var edited: [ForgeAccountRecord] {
+        [
+            (ForgeKindRecord.gitHub, githubToken, githubHost),
+            (ForgeKindRecord.gitLab, gitlabToken, gitlabHost),
+        ].compactMap { kind, token, host in
+            let token = token.trimmingCharacters(in: .whitespacesAndNewlines)
+            let host = host.trimmingCharacters(in: .whitespacesAndNewlines)
+            return token.isEmpty ? nil : ForgeAccountRecord(kind: kind, host: host.isEmpty ? nil : host, token: token)
+        }
+    }
+
+    private func load(_ accounts: [ForgeAccountRecord]) {
+        saved = accounts
+        let github = accounts.first { $0.kind == .gitHub }
+        let gitlab = accounts.first { $0.kind == .gitLab }
+        githubToken = github?.token ?? ""
+        githubHost = github?.host ?? ""
+        gitlabToken = gitlab?.token ?? ""
+        gitlabHost = gitlab?.host ?? ""
+    }
+
+    private func save() {
+        if let error = viewModel.saveForgeAccounts(edited) {
+            status = error
+            statusIsError = true
+            return
+        }
+        load(viewModel.loadForgeAccounts())
+        status = saved.isEmpty ? "No accounts; \"gh\" and \"gl\" are plain searches again." : "Saved."
+        statusIsError = false
+    }
+}
+
+private struct TrackerAccountsSettingsSection: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var isExpanded = false
+    @State private var jiraSite = ""
+    @State private var jiraEmail = ""
+    @State private var jiraToken = ""
+    @State private var linearToken = ""
+    @State private var saved: [TrackerAccountRecord] = []
+    @State private var status: String?
+    @State private var statusIsError = false
+
+    var body: some View {
+        DisclosureGroup("Jira & Linear", isExpanded: $isExpanded) {
+            VStack(alignment: .leading, spacing: 8) {
+                Text("\"jira query\" searches Jira issues and \"lin query\" searches Linear; without a query they list your open issues. \"jira capture query\" saves the picked issue as a note tagged #jira, with a link back to it.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+                    .fixedSize(horizontal: false, vertical: true)
+
+                HStack(spacing: 6) {
+                    Text("Jira")
+                        .frame(width: 50, alignment: .leading)
+                    TextField("team.atlassian.net", text: $jiraSite)
+                        .textFieldStyle(.roundedBorder)
+                    TextField("Email", text: $jiraEmail)
+                        .textFieldStyle(.roundedBorder)
+                    SecureField("API token", text: $jiraToken)
+                        .textFieldStyle(.roundedBorder)
+                }
+                HStack(spacing: 6) {
+                    Text("Linear")
+                        .frame(width: 50, alignment: .leading)
+                    SecureField("API key", text: $linearToken)
+                        .textFieldStyle(.roundedBorder)
+                }
+
+                HStack {
+                    if let status {
+                        Text(status)
+                            .font(.system(size: 11))
+                            .foregroundStyle(statusIsError ? themeManager.colors.errorColor : Color.secondary)
+                            .lineLimit(3)
+                    }
+                    Spacer()
+                    Button("Save") {
+                        save()
+                    }
+                    .disabled(edited == saved)
+                }
+                .controlSize(.small)
+            }
+            .padding(.top, 6)
+        }
+        .font(.system(size: 12))
+        .onAppear {
+            load(viewModel.loadTrackerAccounts())
+        }
+    }
+
+    /// The accounts as typed; a tracker without a token is left out.
+    private var edited: [TrackerAccountRecord] {
+        var accounts: [TrackerAccountRecord] = []
+        let jiraToken = jiraToken.trimmingCharacters(in: .whitespacesAndNewlines)
+        if !jiraToken.isEmpty {
+            let site = jiraSite.trimmingCharacters(in: .whitespacesAndNewlines)
+            let email = jiraEmail.trimmingCharacters(in: .whitespacesAndNewlines)
+            accounts.append(TrackerAccountRecord(kind: .jira, site: site.isEmpty ? nil : site, email: email.isEmpty ? nil : email, token: jiraToken))
+        }
+        let linearToken = linearToken.trimmingCharacters(in: .whitespacesAndNewlines)
+        if !linearToken.isEmpty {
+            accounts.append(TrackerAccountRecord(kind: .linear, site: nil, email: nil, token: linearToken))
+        }
+        return accounts
+    }
+
+    private func load(_ accounts: [TrackerAccountRecord]) {
+        saved = accounts
+        let jira = accounts.first { $0.kind == .jira }
+        jiraSite = jira?.site ?? ""
+        jiraEmail = jira?.email ?? ""
+        jiraToken = jira?.token ?? ""
+        linearToken = accounts.first { $0.kind == .linear }?.token ?? ""
+    }
+
+    private func save() {
+        if let error = viewModel.saveTrackerAccounts(edited) {
+            status = error
+            statusIsError = true
+            return
+        }
+        load(viewModel.loadTrackerAccounts())
+        status = saved.isEmpty ? "No accounts; \"jira\" and \"lin\" are plain searches again." : "Saved."
+        statusIsError = false
+    }
+}
+
+private struct SearchDebounceSettingsSection: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+
+    private static let presets: [(String, SearchDebounceSettings)] = [
+        ("Instant", .instant),
+        ("Balanced", .balanced),
+        ("Large Store", .largeStore),
+    ]
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            Text("Search")
+                .font(.system(size: 12, weight: .medium))
+                .foregroundStyle(.secondary)
+
+            Picker("", selection: presetBinding) {
+                ForEach(Self.presets, id: \.0) { preset in
+                    Text(preset.0).tag(preset.0)
+                }
+                Text("Custom").tag("Custom")
+            }
+            .pickerStyle(.segmented)
+            .labelsHidden()
+            .frame(width: 360)
+
+            HStack(spacing: 8) {
+                Text("1–2 characters")
+                    .frame(width: 110, alignment: .leading)
+                TextField("150", value: millisecondsBinding(\.shortQueryMilliseconds), format: .number)
+                    .textFieldStyle(.roundedBorder)
+                    .frame(width: 60)
+                Text("ms")
+                    .foregroundStyle(.secondary)
+                Text("Longer queries")
+                    .frame(width: 100, alignment: .trailing)
+                TextField("40", value: millisecondsBinding(\.longQueryMilliseconds), format: .number)
+                    .textFieldStyle(.roundedBorder)
+                    .frame(width: 60)
+                Text("ms")
+                    .foregroundStyle(.secondary)
+            }
+            .font(.system(size: 12))
+
+            Text("How long the launcher waits after a keystroke before searching. Instant searches on every keystroke; raise the delay if typing stutters with a very large store.")
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+
+            Stepper(value: $viewModel.preferences.searchResultLimit, in: LauncherPreferences.searchResultLimitRange) {
+                Text("Show up to \(viewModel.preferences.searchResultLimit) results")
+            }
+            .font(.system(size: 12))
+
+            Toggle("Match notes containing any word", isOn: Binding(
+                get: { viewModel.searchMatchesAnyTerm },
+                set: { viewModel.setSearchMatchesAnyTerm($0) }
+            ))
+            .font(.system(size: 12))
+
+            Text("By default every word must match. Separate alternatives with | to match either, e.g. \"rust swift | zig\"; notes matching more words rank first.")
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+        }
+        .onAppear {
+            viewModel.loadSearchMatchesAnyTerm()
+        }
+    }
+
+    private var presetBinding: Binding<String> {
+        Binding(
+            get: {
+                Self.presets.first(where: { $0.1 == viewModel.searchDebounce })?.0 ?? "Custom"
+            },
+            set: { name in
+                if let preset = Self.presets.first(where: { $0.0 == name }) {
+                    viewModel.searchDebounce = preset.1
+                }
+            }
+        )
+    }
+
+    private func millisecondsBinding(_ keyPath: WritableKeyPath<SearchDebounceSettings, Int>) -> Binding<Int> {
+        Binding(
+            get: { viewModel.searchDebounce[keyPath: keyPath] },
+            set: { value in
+                viewModel.searchDebounce[keyPath: keyPath] = min(max(value, 0), SearchDebounceSettings.maxMilliseconds)
+            }
+        )
+    }
+}
+
+private struct EditorMacrosSettingsSection: View {
+    @ObservedObject private var macroStore = EditorMacroStore.shared
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 12) {
+            Text("Macros")
+                .font(.system(size: 14, weight: .medium))
+
+            Text("In the editor, ⌃⌥R starts recording keystrokes and ⌃⌥R again stops; save the recording to ⌃⌥1–⌃⌥9 to replay it in any note.")
+                .font(.system(size: 12))
+                .foregroundStyle(.secondary)
+
+            if macroStore.macros.isEmpty {
+                Text("No macros recorded.")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+            }
+
+            ForEach(macroStore.macros) { macro in
+                HStack(spacing: 8) {
+                    Text(macro.shortcutLabel)
+                        .font(.system(size: 12, design: .monospaced))
+                        .frame(width: 40, alignment: .leading)
+                    Text(macro.name)
+                        .font(.system(size: 12))
+                    Text(macro.steps.count == 1 ? "1 key" : "\(macro.steps.count) keys")
+                        .font(.system(size: 11))
+                        .foregroundStyle(.secondary)
+                    Spacer()
+                    Button {
+                        macroStore.delete(id: macro.id)
+                    } label: {
+                        Image(systemName: "trash")
+                    }
+                    .buttonStyle(.borderless)
+                    .help("Delete macro")
+                    .accessibilityLabel("Delete \(macro.name)")
+                }
+            }
+        }
+    }
+}
+
+/// Turns Touch ID protection of secure notes on and off.
+private struct AppLockSettingsSection: View {
+    @ObservedObject private var appLock = AppLockController.shared
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var errorMessage: String?
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            Toggle("Lock secure notes with Touch ID", isOn: Binding(
+                get: { appLock.isEnabled },
+                set: { enabled in
+                    Task { await setEnabled(enabled) }
+                }
+            ))
+            .font(.system(size: 13))
+
+            Text("Opening a note marked secure asks for Touch ID or your login password. Secure notes lock again when AlfredAlt goes to the background, and their text and images are left out of search results while locked.")
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+
+            if let errorMessage {
+                Text(errorMessage)
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+        }
+    }
+
+    private func setEnabled(_ enabled: Bool) async {
+        do {
+            if enabled {
+                try appLock.enable()
+            } else {
+                try await appLock.disable()
+            }
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
+
+private struct ImageLimitsSettingsSection: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var countInput: String = ""
+    @State private var megabytesInput: String = ""
+    @State private var statusMessage: String?
+    @State private var isError = false
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 12) {
+            Text("Images")
+                .font(.system(size: 14, weight: .medium))
+
+            Text("Limits apply to every note and are checked when it is saved. Leave a field empty to use the default (24 images, 12 MB each).")
+                .font(.system(size: 12))
+                .foregroundStyle(.secondary)
+
+            HStack(spacing: 8) {
+                Text("Images per note")
+                    .frame(width: 140, alignment: .leading)
+                TextField("24", text: $countInput)
+                    .textFieldStyle(.roundedBorder)
+                    .frame(width: 80)
+                Text("1–500")
+                    .foregroundStyle(.secondary)
+            }
+            .font(.system(size: 12))
+
+            HStack(spacing: 8) {
+                Text("Maximum image size")
+                    .frame(width: 140, alignment: .leading)
+                TextField("12", text: $megabytesInput)
+                    .textFieldStyle(.roundedBorder)
+                    .frame(width: 80)
+                Text("MB, 0.25–100")
+                    .foregroundStyle(.secondary)
+            }
+            .font(.system(size: 12))
+
+            HStack {
+                if let statusMessage {
+                    Text(statusMessage)
+                        .font(.system(size: 12))
+                        .foregroundStyle(isError ? themeManager.colors.errorColor : themeManager.colors.successColor)
+                }
+                Spacer()
+                Button("Apply Image Limits", action: apply)
+                    .font(.system(size: 12))
+            }
+        }
+        .onAppear(perform: syncInputs)
+        .onChange(of: viewModel.imageLimits) { _, _ in
+            syncInputs()
+        }
+    }
+
+    private func syncInputs() {
+        let limits = viewModel.imageLimits
+        countInput = String(limits.maxImagesPerNote)
+        megabytesInput = String(format: "%g", Double(limits.maxImageBytes) / 1_000_000)
+    }
+
+    private func apply() {
+        let count = countInput.trimmingCharacters(in: .whitespacesAndNewlines)
+        let megabytes = megabytesInput.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard count.isEmpty || Int(count) != nil else {
+            showStatus("Images per note must be a whole number.", isError: true)
+            return
+        }
+        guard megabytes.isEmpty || Double(megabytes) != nil else {
+            showStatus("Maximum image size must be a number of megabytes.", isError: true)
+            return
+        }
+
+        if viewModel.saveImageLimits(
+            maxImagesPerNote: count.isEmpty ? nil : Int(count),
+            maxImageMegabytes: megabytes.isEmpty ? nil : Double(megabytes)
+        ) {
+            syncInputs()
+            showStatus("Saved.", isError: false)
+        } else {
+            showStatus(viewModel.settingsErrorMessage ?? "Could not save image limits.", isError: true)
+        }
+    }
+
+    private func showStatus(_ message: String, isError: Bool) {
+        statusMessage = message
+        self.isError = isError
+    }
+}
+
+// Predefined themes computed once
+private let predefinedThemes: [AppTheme] = AppTheme.allThemes.filter { !$0.isCustom }
+
+private struct ThemeCard: View {
+    let theme: AppTheme
+    let isSelected: Bool
+    let action: () -> Void
+    @EnvironmentObject var themeManager: ThemeManager
+    
+    // Only observe custom colors for the custom theme card to reduce updates
+    private var displayColors: ThemeColors {
+        theme.isCustom ? themeManager.customColors : theme.colors
+    }
+    
+    // Use a stable ID for the card to prevent unnecessary re-renders
+    // Custom theme card needs to update when custom colors change
+    private var cardId: String {
+        if theme.isCustom {
+            // Use a simple string that changes when theme changes
+            return "custom-\(themeManager.currentTheme.isCustom)"
+        }
+        return theme.id
+    }
+    
+    var body: some View {
+        Button(action: action) {
+            VStack(spacing: 0) {
+                // Preview area - use id to control when view updates
+                themePreview
+                    .frame(height: 90)
+                
+                // Name label - only use accent color for selection state, not custom colors
+                Text(theme.name)
+                    .font(.system(size: 12, weight: isSelected ? .semibold : .medium))
+                    .foregroundStyle(selectionForegroundColor)
+                    .frame(maxWidth: .infinity)
+                    .padding(.vertical, 8)
+                    .background(selectionBackgroundColor)
+            }
+        }
+        .buttonStyle(.plain)
+        .overlay(
+            RoundedRectangle(cornerRadius: 12, style: .continuous)
+                .stroke(selectionStrokeColor, lineWidth: 2)
+        )
+        .clipShape(RoundedRectangle(cornerRadius: 12, style: .continuous))
+        .id(cardId)
+    }
+    
+    // Separate computed properties to minimize re-evaluation
+    private var selectionForegroundColor: Color {
+        isSelected ? themeManager.colors.accentColor : Color.primary
     }
     
     private var selectionBackgroundColor: Color {
@@ -2005,52 +3900,203 @@ private struct EditorSheet: View {
     @State private var isClosingEditor = false
     @State private var documentFontSize: CGFloat = 15
     @State private var draftTitle: String = ""
+    /// The note `draftTitle` belongs to; another window's note may be edited
+    /// by the time a pending title is committed.
+    @State private var draftTitleItemId: Int64?
     @State private var titleSaveTask: Task<Void, Never>?
+    @State private var imageImportSummary: String?
+    @State private var showsImagesPanel = false
+    @State private var showsFindBar = false
+    @State private var showsReplace = false
+    @State private var findQuery = ""
+    @State private var findReplacement = ""
+    @State private var findStatus = EditorFindStatus()
+    @State private var findCommand: EditorFindCommand?
+    @State private var findCommandID: UInt64 = 0
+    @FocusState private var findFieldFocus: EditorFindField?
+    @State private var editingImage: EditingImage?
+    @ObservedObject private var macroStore = EditorMacroStore.shared
+    @State private var macroName = ""
+    @State private var macroSlot = EditorMacro.slots.lowerBound
+    @State private var showsCommandPalette = false
+    @State private var textCommand: EditorTextCommand?
+    @State private var textCommandID: UInt64 = 0
+    /// The Vim mode while Vim keys are on.
+    @State private var vimMode: EditorVimMode?
+    @State private var showsSplitPane = false
+    @State private var showsOutline = false
+    @State private var outlineLineOffset: Int?
+    @State private var outlineRequestID: UInt64 = 0
+    @State private var splitItemId: Int64?
+
+    /// The note image open in the image edit sheet.
+    private struct EditingImage: Identifiable {
+        let key: String
+        var id: String { key }
+    }
 
     private var isDeletedPreviewMode: Bool {
         viewModel.deletedPreviewItem != nil
     }
 
+    /// Focus mode is kept off while previewing a deleted note.
+    private var isFocusModeActive: Bool {
+        themeManager.editorFocusModeEnabled && !isDeletedPreviewMode
+    }
+
     var body: some View {
         VStack(alignment: .leading, spacing: 12) {
-            TextField("Title", text: $draftTitle)
-                .textFieldStyle(.plain)
-                .font(.system(size: 20, weight: .semibold))
-                .foregroundStyle(themeManager.colors.itemTitleText)
-                .focused($titleFieldFocused)
-                .disabled(isDeletedPreviewMode)
-                .onSubmit {
-                    guard !isDeletedPreviewMode else {
-                        return
+            HStack(spacing: 8) {
+                TextField("Title", text: $draftTitle)
+                    .textFieldStyle(.plain)
+                    .font(.system(size: 20, weight: .semibold))
+                    .foregroundStyle(themeManager.colors.itemTitleText)
+                    .focused($titleFieldFocused)
+                    .disabled(isDeletedPreviewMode)
+                    .accessibilityLabel("Note title")
+                    .onSubmit {
+                        guard !isDeletedPreviewMode else {
+                            return
+                        }
+                        scheduleTitleSave(immediate: true)
                     }
-                    scheduleTitleSave(immediate: true)
+                if !isDeletedPreviewMode, let item = viewModel.selectedItem {
+                    titleMenu(for: item)
                 }
+            }
 
-            InlineImageTextEditor(
-                text: $viewModel.editorText,
-                imagesByKey: isDeletedPreviewMode
-                    ? [:]
-                    : Dictionary(uniqueKeysWithValues: (viewModel.selectedItem?.images ?? []).map { ($0.imageKey, $0.bytes) }),
-                isEditable: !isDeletedPreviewMode,
-                searchQuery: viewModel.query,
-                highlightSearchMatches: themeManager.editorSearchHighlightsEnabled,
-                dividerColor: themeManager.editorDividerColor,
-                dividerTopMargin: themeManager.editorDividerTopMargin,
-                dividerBottomMargin: themeManager.editorDividerBottomMargin,
-                defaultImageWidth: 360,
-                fontSize: documentFontSize,
-                onIncreaseDocumentFontSize: {
-                    adjustDocumentFontSize(by: editorDocumentFontSizeStep)
-                },
-                onDecreaseDocumentFontSize: {
-                    adjustDocumentFontSize(by: -editorDocumentFontSizeStep)
+            if isFocusModeActive {
+                HStack {
+                    Spacer()
+                    Button("Exit Focus Mode") {
+                        themeManager.toggleEditorFocusModeEnabled()
+                    }
+                    .buttonStyle(.link)
+                    .help("Show the editor's toolbars again (⌥⌘F)")
                 }
-            ) { cursorIndex in
-                editorCursorCharIndex = cursorIndex
+                .font(.system(size: 11))
+            } else if !isDeletedPreviewMode, let item = viewModel.selectedItem {
+                mirrorStatusRow(for: item)
+                NoteMetadataSection(viewModel: viewModel, item: item)
+            }
+
+            if let imageImportSummary {
+                HStack(alignment: .top, spacing: 6) {
+                    Image(systemName: "photo.on.rectangle")
+                        .foregroundStyle(themeManager.colors.accentColor)
+                        .accessibilityHidden(true)
+                    Text(imageImportSummary)
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                        .textSelection(.enabled)
+                    Spacer()
+                    Button("Dismiss") {
+                        self.imageImportSummary = nil
+                    }
+                    .buttonStyle(.link)
+                }
+                .font(.system(size: 11))
+            }
+
+            if viewModel.isProcessingPastedImage {
+                HStack(spacing: 6) {
+                    ProgressView()
+                        .controlSize(.small)
+                    Text("Processing pasted image…")
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    Spacer()
+                    Button("Cancel", action: viewModel.cancelImagePaste)
+                        .buttonStyle(.link)
+                        .help("Stop processing the image without inserting it (Esc)")
+                }
+                .font(.system(size: 11))
+            }
+
+            if macroStore.isRecording {
+                HStack(spacing: 6) {
+                    Image(systemName: "record.circle")
+                        .foregroundStyle(themeManager.colors.errorColor)
+                        .accessibilityHidden(true)
+                    Text("Recording macro… press ⌃⌥R to stop.")
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    Spacer()
+                }
+                .font(.system(size: 11))
+            } else if let steps = macroStore.pendingSteps {
+                macroSaveRow(keyCount: steps.count)
+            }
+
+            if let vimMode, vimMode != .normal {
+                Text("-- \(vimMode.label) --")
+                    .font(.system(size: 11, design: .monospaced))
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    .accessibilityLabel("Vim \(vimMode.label.lowercased()) mode")
+            }
+
+            HStack(alignment: .top, spacing: 12) {
+                if showsOutline, !isDeletedPreviewMode {
+                    EditorOutlineSidebar(text: viewModel.editorText) { offset in
+                        outlineLineOffset = offset
+                        outlineRequestID &+= 1
+                    }
+                    .frame(width: 200)
+                    .themeShadow(themeManager.metrics.editorShadow)
+                }
+                VStack(spacing: 8) {
+                    if showsFindBar {
+                        EditorFindBar(
+                            query: $findQuery,
+                            replacement: $findReplacement,
+                            showsReplace: $showsReplace,
+                            status: findStatus,
+                            canReplace: !isDeletedPreviewMode,
+                            focus: $findFieldFocus,
+                            onCommand: sendFindCommand,
+                            onClose: closeFindBar
+                        )
+                    }
+                    noteTextEditor
+                }
+                if showsSplitPane, !isDeletedPreviewMode {
+                    EditorSplitPane(
+                        editedItemId: viewModel.selectedItem?.id,
+                        itemId: $splitItemId,
+                        fontSize: documentFontSize,
+                        onOpen: openSplitNote,
+                        onClose: { showsSplitPane = false }
+                    )
+                }
+                if themeManager.editorMarkdownPreviewEnabled {
+                    MarkdownPreviewView(
+                        text: viewModel.editorText,
+                        imagesByKey: editorImagesByKey,
+                        fontSize: documentFontSize,
+                        defaultImageWidth: 360,
+                        onOpenNoteLink: { url in
+                            Task {
+                                if await viewModel.openNoteLink(url) {
+                                    openWindow.openEditor(for: viewModel)
+                                }
+                            }
+                        }
+                    )
+                    .padding(10)
+                    .frame(maxWidth: .infinity, maxHeight: .infinity)
+                    .background(themeManager.colors.editorTextBackground)
+                    .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+                    .themeShadow(themeManager.metrics.editorShadow)
+                }
+                if showsImagesPanel, !isDeletedPreviewMode {
+                    NoteImagesPanel(viewModel: viewModel)
+                        .frame(width: 240)
+                        .frame(maxHeight: .infinity)
+                        .themeShadow(themeManager.metrics.editorShadow)
+                }
+            }
+
+            if !isDeletedPreviewMode, !isFocusModeActive, let item = viewModel.selectedItem {
+                NoteStatsPanel(itemId: item.id, revision: item.revision)
+                VersionHistoryPanel(itemId: item.id, revision: item.revision)
             }
-            .padding(10)
-            .background(themeManager.colors.editorTextBackground)
-            .clipShape(RoundedRectangle(cornerRadius: 10, style: .continuous))
 
             if let preview = viewModel.deletedPreviewItem {
                 HStack {
@@ -2068,8 +4114,12 @@ private struct EditorSheet: View {
                             guard let restoredItemId = await viewModel.restoreDeletedItem(archiveKey: preview.archiveKey) else {
                                 return
                             }
-                            _ = await viewModel.open(itemId: restoredItemId)
+                            guard await viewModel.open(itemId: restoredItemId) else {
+                                return
+                            }
                             viewModel.requestEditorTitleFocus()
+                            openWindow.openEditor(for: viewModel)
+                            dismissWindow(id: "editor", value: EditorWindowTarget.deletedPreview)
                         }
                     }
                     .keyboardShortcut(.defaultAction)
@@ -2079,6 +4129,35 @@ private struct EditorSheet: View {
         .padding(16)
         .frame(minWidth: 760, minHeight: 500)
         .background(themeManager.colors.editorBackground)
+        .overlay(alignment: .top) {
+            if showsCommandPalette {
+                EditorCommandPalette(commands: paletteCommands) { command in
+                    showsCommandPalette = false
+                    command.perform()
+                } onClose: {
+                    showsCommandPalette = false
+                }
+                .padding(.top, 56)
+            }
+        }
+        .overlay(alignment: .bottomTrailing) {
+            ErrorToastStack()
+                .frame(maxWidth: 360)
+                .padding(16)
+        }
+        .sheet(item: $editingImage) { target in
+            if let data = editorImagesByKey[target.key] {
+                ImageEditView(data: data) { edited in
+                    editingImage = nil
+                    Task {
+                        await viewModel.replaceEditorImage(key: target.key, with: edited)
+                    }
+                } onCancel: {
+                    editingImage = nil
+                }
+                .environmentObject(themeManager)
+            }
+        }
         .background(
             KeyEventMonitor { event in
                 handleEditorKeyEvent(event)
@@ -2107,6 +4186,9 @@ private struct EditorSheet: View {
             refreshDocumentFontSize()
             refreshDraftTitle()
         }
+        .onChange(of: themeManager.scaledEditorFontSize) { _, _ in
+            refreshDocumentFontSize()
+        }
         .onChange(of: viewModel.deletedPreviewItem?.archiveKey) { _, _ in
             refreshDocumentFontSize()
             refreshDraftTitle()
@@ -2159,9 +4241,57 @@ private struct EditorSheet: View {
         }
     }
 
+    private func macroSaveRow(keyCount: Int) -> some View {
+        HStack(spacing: 6) {
+            Text(keyCount == 1 ? "Macro recorded (1 key)." : "Macro recorded (\(keyCount) keys).")
+                .foregroundStyle(themeManager.colors.itemSubtitleText)
+            TextField("Name", text: $macroName)
+                .textFieldStyle(.roundedBorder)
+                .frame(width: 160)
+                .onSubmit(saveRecordedMacro)
+            Picker("Shortcut", selection: $macroSlot) {
+                ForEach(EditorMacro.slots, id: \.self) { slot in
+                    if let existing = macroStore.macro(forSlot: slot) {
+                        Text("⌃⌥\(slot) (replaces \(existing.name))").tag(slot)
+                    } else {
+                        Text("⌃⌥\(slot)").tag(slot)
+                    }
+                }
+            }
+            .labelsHidden()
+            .frame(width: 200)
+            Spacer()
+            Button("Save", action: saveRecordedMacro)
+            Button("Discard") {
+                macroStore.discardPending()
+            }
+            .buttonStyle(.link)
+        }
+        .font(.system(size: 11))
+        .controlSize(.small)
+        .onAppear {
+            macroSlot = EditorMacro.slots.first { macroStore.macro(forSlot: $0) == nil } ?? EditorMacro.slots.lowerBound
+        }
+    }
+
+    private func saveRecordedMacro() {
+        macroStore.savePending(slot: macroSlot, name: macroName)
+        macroName = ""
+    }
+
     private func handleEditorKeyEvent(_ event: NSEvent) -> Bool {
         let modifiers = event.modifierFlags.intersection(keyHandlingModifierMask)
 
+        if modifiers == [.command, .shift], event.charactersIgnoringModifiers?.lowercased() == "p" {
+            showsCommandPalette.toggle()
+            return true
+        }
+
+        // Keys belong to the palette's search field while it is open.
+        if showsCommandPalette {
+            return false
+        }
+
         if !isDeletedPreviewMode,
            modifiers == [.command],
            event.charactersIgnoringModifiers?.lowercased() == "v",
@@ -2170,6 +4300,75 @@ private struct EditorSheet: View {
             return true
         }
 
+        if modifiers == [.command], event.charactersIgnoringModifiers?.lowercased() == "f" {
+            openFindBar(replacing: false)
+            return true
+        }
+
+        if !isDeletedPreviewMode, modifiers == [.command], event.charactersIgnoringModifiers?.lowercased() == "r" {
+            openFindBar(replacing: true)
+            return true
+        }
+
+        if showsFindBar, !findQuery.isEmpty, event.charactersIgnoringModifiers?.lowercased() == "g" {
+            if modifiers == [.command] {
+                sendFindCommand(.next)
+                return true
+            }
+            if modifiers == [.command, .shift] {
+                sendFindCommand(.previous)
+                return true
+            }
+        }
+
+        // The editor keeps its own undo history so edits the view model makes,
+        // like pasting or deleting images, undo together with typing.
+        if !isDeletedPreviewMode, !titleFieldFocused, findFieldFocus == nil, event.charactersIgnoringModifiers?.lowercased() == "z" {
+            if modifiers == [.command] {
+                viewModel.undoEditorChange()
+                return true
+            }
+            if modifiers == [.command, .shift] {
+                viewModel.redoEditorChange()
+                return true
+            }
+        }
+
+        if modifiers == [.command, .option], event.charactersIgnoringModifiers?.lowercased() == "p" {
+            themeManager.toggleEditorMarkdownPreviewEnabled()
+            return true
+        }
+
+        if !isDeletedPreviewMode, modifiers == [.command, .option], event.charactersIgnoringModifiers?.lowercased() == "f" {
+            themeManager.toggleEditorFocusModeEnabled()
+            return true
+        }
+
+        if !isDeletedPreviewMode, modifiers == [.command, .option], event.charactersIgnoringModifiers?.lowercased() == "s" {
+            showsSplitPane.toggle()
+            return true
+        }
+
+        if !isDeletedPreviewMode, modifiers == [.command, .option], event.charactersIgnoringModifiers?.lowercased() == "o" {
+            showsOutline.toggle()
+            return true
+        }
+
+        if modifiers.isEmpty, event.keyCode == 53, showsFindBar {
+            closeFindBar()
+            return true
+        }
+
+        if modifiers.isEmpty, event.keyCode == 53, viewModel.isProcessingPastedImage {
+            viewModel.cancelImagePaste()
+            return true
+        }
+
+        // Esc leaves Vim's Insert or Visual mode before it closes the editor.
+        if modifiers.isEmpty, event.keyCode == 53, vimMode == .insert || vimMode == .visual, !titleFieldFocused {
+            return false
+        }
+
         if modifiers.isEmpty, event.keyCode == 53 {
             closeEditorWindow()
             return true
@@ -2178,6 +4377,25 @@ private struct EditorSheet: View {
         return false
     }
 
+    private func openFindBar(replacing: Bool) {
+        showsFindBar = true
+        if replacing {
+            showsReplace = true
+        }
+        findFieldFocus = replacing && !findQuery.isEmpty ? .replace : .find
+    }
+
+    private func closeFindBar() {
+        showsFindBar = false
+        findFieldFocus = nil
+        findStatus = EditorFindStatus()
+    }
+
+    private func sendFindCommand(_ command: EditorFindCommand) {
+        findCommand = command
+        findCommandID &+= 1
+    }
+
     private func adjustDocumentFontSize(by delta: CGFloat) {
         let next = min(max(documentFontSize + delta, editorDocumentMinFontSize), editorDocumentMaxFontSize)
         if abs(next - documentFontSize) > 0.01 {
@@ -2196,24 +4414,393 @@ private struct EditorSheet: View {
 
             if isDeletedPreviewMode {
                 viewModel.clearDeletedItemPreview()
-                dismissWindow(id: "editor")
+                dismissWindow(id: "editor", value: EditorWindowTarget.deletedPreview)
                 viewModel.prepareSettings()
                 openWindow(id: "settings")
                 return
             }
 
             await commitTitleIfNeeded()
-            dismissWindow(id: "editor")
+            // Saved before the window goes, since another editor window may
+            // take over the editor once it closes.
             _ = await viewModel.flushAutosave()
+            if let target = viewModel.activeEditorTarget {
+                dismissWindow(id: "editor", value: target)
+            }
         }
     }
 
     private func refreshDraftTitle() {
         if let preview = viewModel.deletedPreviewItem {
             draftTitle = preview.title
+            draftTitleItemId = nil
             return
         }
         draftTitle = viewModel.selectedItem?.title ?? ""
+        draftTitleItemId = viewModel.selectedItem?.id
+    }
+
+    private var editorImagesByKey: [String: Data] {
+        isDeletedPreviewMode
+            ? [:]
+            : Dictionary(uniqueKeysWithValues: (viewModel.selectedItem?.images ?? []).map { ($0.imageKey, $0.bytes) })
+    }
+
+    private var noteTextEditor: some View {
+        InlineImageTextEditor(
+            text: $viewModel.editorText,
+            imagesByKey: editorImagesByKey,
+            isEditable: !isDeletedPreviewMode,
+            searchQuery: viewModel.query,
+            highlightSearchMatches: themeManager.editorSearchHighlightsEnabled,
+            dividerColor: themeManager.editorDividerColor,
+            dividerTopMargin: themeManager.editorDividerTopMargin,
+            dividerBottomMargin: themeManager.editorDividerBottomMargin,
+            defaultImageWidth: 360,
+            fontSize: documentFontSize,
+            onIncreaseDocumentFontSize: {
+                adjustDocumentFontSize(by: editorDocumentFontSizeStep)
+            },
+            onDecreaseDocumentFontSize: {
+                adjustDocumentFontSize(by: -editorDocumentFontSizeStep)
+            },
+            onSelectionChange: { cursorIndex in
+                editorCursorCharIndex = cursorIndex
+            },
+            revealImageKey: viewModel.editorRevealImageKey,
+            revealRequestID: viewModel.editorRevealRequestID,
+            revealLineOffset: outlineLineOffset,
+            revealLineRequestID: outlineRequestID,
+            scrollRestoreOffset: viewModel.editorScrollRestoreOffset,
+            scrollRestoreRequestID: viewModel.editorScrollRestoreRequestID,
+            onScrollOffsetChange: { offset in
+                viewModel.editorDidScroll(to: offset)
+            },
+            showsProgressAtCursor: viewModel.isProcessingPastedImage,
+            findQuery: showsFindBar ? findQuery : "",
+            findCommand: findCommand,
+            findCommandID: findCommandID,
+            onFindStatusChange: { status in
+                findStatus = status
+            },
+            onEditImage: { key in
+                editingImage = EditingImage(key: key)
+            },
+            onWillTransformText: {
+                viewModel.startEditorUndoStep()
+            },
+            onOpenNoteLink: { url in
+                Task {
+                    if await viewModel.openNoteLink(url) {
+                        openWindow.openEditor(for: viewModel)
+                    }
+                }
+            },
+            onSearchTag: { tag in
+                viewModel.searchTag(tag)
+                closeEditorWindow()
+            },
+            textCommand: textCommand,
+            textCommandID: textCommandID,
+            enrichesPastedLinks: viewModel.preferences.fetchesPastedLinkTitles,
+            vimKeysEnabled: viewModel.preferences.usesVimKeys,
+            onVimModeChange: { mode in
+                vimMode = mode
+            },
+            onVimUndo: { redo in
+                if redo {
+                    viewModel.redoEditorChange()
+                } else {
+                    viewModel.undoEditorChange()
+                }
+            },
+            focusModeEnabled: isFocusModeActive
+        )
+        .padding(10)
+        .background(themeManager.colors.editorTextBackground)
+        .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+        .themeShadow(themeManager.metrics.editorShadow)
+    }
+
+    private func titleMenu(for item: EditableItemRecord) -> some View {
+        Menu {
+            Button("Copy alfredalt:// Link") {
+                viewModel.copyItemDeepLink(itemId: item.id)
+            }
+            Button("Copy as Markdown") {
+                copyMarkdown(itemId: item.id)
+            }
+            Divider()
+            Button("Reveal JSON File") {
+                viewModel.revealItemJsonInFinder(itemId: item.id)
+            }
+            .disabled(item.mirrorExclusion != nil)
+        } label: {
+            Image(systemName: "ellipsis.circle")
+                .foregroundStyle(themeManager.colors.itemSubtitleText)
+        }
+        .menuStyle(.borderlessButton)
+        .menuIndicator(.hidden)
+        .fixedSize()
+        .help("Note link, Markdown and file")
+        .accessibilityLabel("Note actions")
+    }
+
+    private func copyMarkdown(itemId: Int64) {
+        Task {
+            // Export what's on screen, not the last autosave.
+            await commitTitleIfNeeded()
+            await viewModel.flushAutosave()
+            viewModel.copyItemMarkdown(itemId: itemId)
+        }
+    }
+
+    private func openSplitNote(_ itemId: Int64) {
+        Task {
+            if await viewModel.open(itemId: itemId) {
+                openWindow.openEditor(for: viewModel)
+            }
+        }
+    }
+
+    private func sendTextCommand(_ command: EditorTextCommand) {
+        textCommand = command
+        textCommandID &+= 1
+    }
+
+    /// The editor's actions for the command palette; ones that don't apply to
+    /// the open note right now are left out.
+    private var paletteCommands: [EditorPaletteCommand] {
+        var commands = [
+            EditorPaletteCommand(id: "find", category: "Edit", title: "Find", shortcut: "⌘F") {
+                openFindBar(replacing: false)
+            },
+            EditorPaletteCommand(
+                id: "preview",
+                category: "View",
+                title: themeManager.editorMarkdownPreviewEnabled ? "Hide Preview" : "Show Preview",
+                shortcut: "⌥⌘P"
+            ) {
+                themeManager.toggleEditorMarkdownPreviewEnabled()
+            },
+        ]
+        guard !isDeletedPreviewMode, let item = viewModel.selectedItem else {
+            return commands
+        }
+
+        commands += [
+            EditorPaletteCommand(id: "replace", category: "Edit", title: "Find and Replace", shortcut: "⌘R") {
+                openFindBar(replacing: true)
+            },
+            EditorPaletteCommand(
+                id: "focus-mode",
+                category: "View",
+                title: themeManager.editorFocusModeEnabled ? "Exit Focus Mode" : "Focus Mode",
+                shortcut: "⌥⌘F"
+            ) {
+                themeManager.toggleEditorFocusModeEnabled()
+            },
+            EditorPaletteCommand(
+                id: "split",
+                category: "View",
+                title: showsSplitPane ? "Hide Split View" : "Show Split View",
+                shortcut: "⌥⌘S"
+            ) {
+                showsSplitPane.toggle()
+            },
+            EditorPaletteCommand(
+                id: "outline",
+                category: "View",
+                title: showsOutline ? "Hide Outline" : "Show Outline",
+                shortcut: "⌥⌘O"
+            ) {
+                showsOutline.toggle()
+            },
+            EditorPaletteCommand(
+                id: "search-highlights",
+                category: "View",
+                title: themeManager.editorSearchHighlightsEnabled ? "Hide Search Highlights" : "Show Search Highlights",
+                shortcut: "⌥⌥"
+            ) {
+                themeManager.toggleEditorSearchHighlightsEnabled()
+            },
+            EditorPaletteCommand(id: "undo", category: "History", title: "Undo", shortcut: "⌘Z") {
+                viewModel.undoEditorChange()
+            },
+            EditorPaletteCommand(id: "redo", category: "History", title: "Redo", shortcut: "⇧⌘Z") {
+                viewModel.redoEditorChange()
+            },
+            EditorPaletteCommand(id: "bold", category: "Format", title: "Bold", shortcut: "⌘B") {
+                sendTextCommand(.toggleBold)
+            },
+            EditorPaletteCommand(id: "italic", category: "Format", title: "Italic", shortcut: "⌘I") {
+                sendTextCommand(.toggleItalic)
+            },
+            EditorPaletteCommand(id: "larger", category: "Format", title: "Larger Text", shortcut: "⌘+") {
+                sendTextCommand(.increaseFontSize)
+            },
+            EditorPaletteCommand(id: "smaller", category: "Format", title: "Smaller Text", shortcut: "⌘−") {
+                sendTextCommand(.decreaseFontSize)
+            },
+            EditorPaletteCommand(id: "reset-style", category: "Format", title: "Reset Text Style", shortcut: "⌘0") {
+                sendTextCommand(.resetStyle)
+            },
+            EditorPaletteCommand(id: "answer-block", category: "Format", title: "Wrap in Answer Block", shortcut: "⇧⌘A") {
+                sendTextCommand(.wrapInAnswerBlock)
+            },
+            EditorPaletteCommand(id: "table-row", category: "Table", title: "Insert Table Row") {
+                sendTextCommand(.insertTableRow)
+            },
+            EditorPaletteCommand(id: "table-column", category: "Table", title: "Insert Table Column") {
+                sendTextCommand(.insertTableColumn)
+            },
+        ]
+        commands += TextTransformKind.menuOrder.map { transform in
+            EditorPaletteCommand(id: "transform-\(transform.menuTitle)", category: "Transform", title: transform.menuTitle) {
+                sendTextCommand(.transform(transform))
+            }
+        }
+
+        if viewModel.hasImageInClipboard() {
+            commands.append(EditorPaletteCommand(id: "paste-image", category: "Images", title: "Paste Image", shortcut: "⌘V") {
+                Task { await viewModel.pasteImageFromClipboard(at: editorCursorCharIndex) }
+            })
+        }
+        commands += [
+            EditorPaletteCommand(id: "import-images", category: "Images", title: "Import Images from Folder…") {
+                importImagesFromFolder()
+            },
+            EditorPaletteCommand(id: "images-panel", category: "Images", title: showsImagesPanel ? "Hide Images" : "Manage Images") {
+                showsImagesPanel.toggle()
+            },
+        ]
+        if !item.images.isEmpty {
+            commands.append(EditorPaletteCommand(id: "gallery", category: "Images", title: "Show Gallery") {
+                viewModel.prepareGallery(itemId: item.id, title: item.title)
+                openWindow(id: "gallery")
+            })
+        }
+
+        commands += [
+            EditorPaletteCommand(id: "copy-link", category: "Export", title: "Copy alfredalt:// Link") {
+                viewModel.copyItemDeepLink(itemId: item.id)
+            },
+            EditorPaletteCommand(id: "copy-markdown", category: "Export", title: "Copy as Markdown") {
+                copyMarkdown(itemId: item.id)
+            },
+        ]
+        if item.mirrorExclusion == nil {
+            commands.append(EditorPaletteCommand(id: "reveal-json", category: "Export", title: "Reveal JSON File") {
+                viewModel.revealItemJsonInFinder(itemId: item.id)
+            })
+        }
+        commands.append(EditorPaletteCommand(id: "secure", category: "Note", title: item.secure ? "Unmark Secure" : "Mark Secure") {
+            viewModel.setSelectedItemSecure(!item.secure)
+        })
+
+        commands.append(EditorPaletteCommand(
+            id: "record-macro",
+            category: "Macros",
+            title: macroStore.isRecording ? "Stop Recording Macro" : "Record Macro",
+            shortcut: "⌃⌥R"
+        ) {
+            macroStore.toggleRecording()
+        })
+        if !macroStore.isRecording {
+            commands += macroStore.macros.map { macro in
+                EditorPaletteCommand(id: "macro-\(macro.id)", category: "Macros", title: "Run \(macro.name)", shortcut: macro.shortcutLabel) {
+                    sendTextCommand(.replayMacro(macro))
+                }
+            }
+        }
+        return commands
+    }
+
+    private func mirrorStatusRow(for item: EditableItemRecord) -> some View {
+        HStack(spacing: 6) {
+            if let reason = item.mirrorExclusion {
+                Image(systemName: "lock.fill")
+                    .foregroundStyle(themeManager.colors.accentColor)
+                    .accessibilityHidden(true)
+                Text("Not mirrored to the JSON folder (\(reason))")
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+            } else {
+                Image(systemName: "arrow.triangle.2.circlepath")
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    .accessibilityHidden(true)
+                Text("Mirrored to the JSON folder")
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+            }
+            Spacer()
+            Text(imageLimitHint(for: item))
+                .foregroundStyle(themeManager.colors.itemSubtitleText)
+            Button(showsImagesPanel ? "Hide Images" : "Manage Images") {
+                showsImagesPanel.toggle()
+            }
+            .buttonStyle(.link)
+            .help("List the note's images to reorder, delete or export several at once")
+            if !item.images.isEmpty {
+                Button("Show Gallery") {
+                    viewModel.prepareGallery(itemId: item.id, title: item.title)
+                    openWindow(id: "gallery")
+                }
+                .buttonStyle(.link)
+            }
+            Button(themeManager.editorMarkdownPreviewEnabled ? "Hide Preview" : "Show Preview") {
+                themeManager.toggleEditorMarkdownPreviewEnabled()
+            }
+            .buttonStyle(.link)
+            .help("Toggle the rendered Markdown preview (⌥⌘P)")
+            Button(showsSplitPane ? "Hide Split View" : "Split View") {
+                showsSplitPane.toggle()
+            }
+            .buttonStyle(.link)
+            .help("Show another note next to this one (⌥⌘S)")
+            Button(showsOutline ? "Hide Outline" : "Outline") {
+                showsOutline.toggle()
+            }
+            .buttonStyle(.link)
+            .help("List the note's headings to jump between them (⌥⌘O)")
+            Button("Focus Mode") {
+                themeManager.toggleEditorFocusModeEnabled()
+            }
+            .buttonStyle(.link)
+            .help("Dim all but the current paragraph, keep the caret line centered and hide these toolbars (⌥⌘F)")
+            Button("Import Images from Folder…", action: importImagesFromFolder)
+                .buttonStyle(.link)
+            Button(item.secure ? "Unmark Secure" : "Mark Secure") {
+                viewModel.setSelectedItemSecure(!item.secure)
+            }
+            .buttonStyle(.link)
+            NoteTimerButton(itemId: item.id)
+        }
+        .font(.system(size: 11))
+    }
+
+    private func imageLimitHint(for item: EditableItemRecord) -> String {
+        let limits = viewModel.imageLimits
+        let maxSize = ByteCountFormatter.string(
+            fromByteCount: Int64(clamping: limits.maxImageBytes),
+            countStyle: .file
+        )
+        return "\(item.images.count)/\(limits.maxImagesPerNote) images, up to \(maxSize) each"
+    }
+
+    private func importImagesFromFolder() {
+        let panel = NSOpenPanel()
+        panel.title = "Import Images from Folder"
+        panel.message = "Every image in the folder is appended to the end of the note."
+        panel.canChooseDirectories = true
+        panel.canChooseFiles = false
+        panel.allowsMultipleSelection = false
+
+        guard panel.runModal() == .OK, let folderURL = panel.url else {
+            return
+        }
+
+        Task {
+            imageImportSummary = await viewModel.importImagesFromFolder(folderURL)
+        }
     }
 
     private func scheduleTitleSave(immediate: Bool) {
@@ -2240,7 +4827,11 @@ private struct EditorSheet: View {
             return
         }
 
-        guard let currentTitle = viewModel.selectedItem?.title else {
+        guard let itemId = draftTitleItemId,
+              let currentTitle = viewModel.selectedItem?.id == itemId
+                ? viewModel.selectedItem?.title
+                : viewModel.backgroundEditors[itemId]?.item.title
+        else {
             return
         }
 
@@ -2260,16 +4851,18 @@ private struct EditorSheet: View {
         }
 
         let previous = currentTitle
-        let success = await viewModel.renameCurrentItem(to: trimmed)
+        let success = await viewModel.renameItem(itemId: itemId, to: trimmed)
         if !success {
             draftTitle = previous
             return
         }
-        draftTitle = viewModel.selectedItem?.title ?? trimmed
+        if viewModel.selectedItem?.id == itemId {
+            draftTitle = viewModel.selectedItem?.title ?? trimmed
+        }
     }
 
     private func refreshDocumentFontSize() {
-        let base = themeManager.editorFontSize
+        let base = themeManager.scaledEditorFontSize
         guard !isDeletedPreviewMode else {
             documentFontSize = base
             return
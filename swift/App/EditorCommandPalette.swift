@@ -0,0 +1,137 @@
+import SwiftUI
+
+/// One action offered by the editor's command palette.
+struct EditorPaletteCommand: Identifiable {
+    let id: String
+    let category: String
+    let title: String
+    var shortcut: String?
+    let perform: () -> Void
+
+    /// True when every word of `query` appears in the title or category.
+    func matches(_ query: String) -> Bool {
+        let haystack = "\(category) \(title)".lowercased()
+        return query.lowercased().split(whereSeparator: \.isWhitespace).allSatisfy { haystack.contains($0) }
+    }
+}
+
+/// Searchable list of the editor's actions, opened with ⇧⌘P. Up and Down move
+/// through the matches, Return runs the selected one and Esc closes.
+struct EditorCommandPalette: View {
+    let commands: [EditorPaletteCommand]
+    let onRun: (EditorPaletteCommand) -> Void
+    let onClose: () -> Void
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var query = ""
+    @State private var selectedId: String?
+    @FocusState private var isQueryFocused: Bool
+
+    private var matches: [EditorPaletteCommand] {
+        commands.filter { $0.matches(query) }
+    }
+
+    var body: some View {
+        VStack(spacing: 0) {
+            TextField("Search editor commands", text: $query)
+                .textFieldStyle(.plain)
+                .font(.system(size: 15))
+                .padding(10)
+                .focused($isQueryFocused)
+                .onSubmit(runSelected)
+                .onKeyPress(.upArrow) { moveSelection(by: -1) }
+                .onKeyPress(.downArrow) { moveSelection(by: 1) }
+                .onKeyPress(.escape) {
+                    onClose()
+                    return .handled
+                }
+                .accessibilityLabel("Search editor commands")
+
+            Divider()
+
+            if matches.isEmpty {
+                Text("No matching commands")
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    .frame(maxWidth: .infinity)
+                    .padding(12)
+            } else {
+                ScrollViewReader { proxy in
+                    ScrollView {
+                        LazyVStack(spacing: 0) {
+                            ForEach(matches) { command in
+                                row(command)
+                                    .id(command.id)
+                            }
+                        }
+                        .padding(4)
+                    }
+                    .onChange(of: selectedId) { _, id in
+                        if let id {
+                            proxy.scrollTo(id)
+                        }
+                    }
+                }
+                .frame(maxHeight: 320)
+            }
+        }
+        .frame(width: 480)
+        .fixedSize(horizontal: false, vertical: true)
+        .background(themeManager.colors.editorTextBackground)
+        .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+        .themeShadow(themeManager.metrics.editorShadow)
+        .onAppear {
+            isQueryFocused = true
+            selectedId = matches.first?.id
+        }
+        .onChange(of: query) { _, _ in
+            selectedId = matches.first?.id
+        }
+        .accessibilityElement(children: .contain)
+        .accessibilityLabel("Editor commands")
+    }
+
+    private func row(_ command: EditorPaletteCommand) -> some View {
+        let isSelected = command.id == selectedId
+        return HStack(spacing: 8) {
+            Text(command.category)
+                .foregroundStyle(isSelected ? themeManager.colors.selectedItemSubtitleText : themeManager.colors.itemSubtitleText)
+                .frame(width: 80, alignment: .leading)
+            Text(command.title)
+                .foregroundStyle(isSelected ? themeManager.colors.selectedItemTitleText : themeManager.colors.itemTitleText)
+            Spacer()
+            if let shortcut = command.shortcut {
+                Text(shortcut)
+                    .foregroundStyle(isSelected ? themeManager.colors.selectedItemSubtitleText : themeManager.colors.itemSubtitleText)
+            }
+        }
+        .font(.system(size: 13))
+        .padding(.horizontal, 8)
+        .padding(.vertical, 5)
+        .background(isSelected ? themeManager.colors.selectedItemBackground : Color.clear)
+        .clipShape(RoundedRectangle(cornerRadius: 5, style: .continuous))
+        .contentShape(Rectangle())
+        .onTapGesture {
+            onRun(command)
+        }
+        .accessibilityElement(children: .combine)
+        .accessibilityAddTraits(isSelected ? [.isButton, .isSelected] : .isButton)
+    }
+
+    private func moveSelection(by offset: Int) -> KeyPress.Result {
+        let matches = matches
+        guard !matches.isEmpty else {
+            return .ignored
+        }
+        let current = matches.firstIndex { $0.id == selectedId } ?? -1
+        let index = min(max(current + offset, 0), matches.count - 1)
+        selectedId = matches[index].id
+        return .handled
+    }
+
+    private func runSelected() {
+        guard let command = matches.first(where: { $0.id == selectedId }) ?? matches.first else {
+            return
+        }
+        onRun(command)
+    }
+}
@@ -0,0 +1,113 @@
+import SwiftUI
+
+enum EditorFindField: Hashable {
+    case find
+    case replace
+}
+
+/// Find and replace row above the editor text. Return in the find field moves
+/// to the next match; matches are counted and replaced in the open note only.
+struct EditorFindBar: View {
+    @Binding var query: String
+    @Binding var replacement: String
+    @Binding var showsReplace: Bool
+    let status: EditorFindStatus
+    let canReplace: Bool
+    var focus: FocusState<EditorFindField?>.Binding
+    let onCommand: (EditorFindCommand) -> Void
+    let onClose: () -> Void
+    @EnvironmentObject private var themeManager: ThemeManager
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 6) {
+            HStack(spacing: 6) {
+                Button {
+                    showsReplace.toggle()
+                } label: {
+                    Image(systemName: showsReplace ? "chevron.down" : "chevron.right")
+                        .frame(width: 12)
+                }
+                .buttonStyle(.borderless)
+                .disabled(!canReplace)
+                .help(showsReplace ? "Hide Replace" : "Show Replace (⌘R)")
+                .accessibilityLabel(showsReplace ? "Hide replace" : "Show replace")
+
+                TextField("Find in note", text: $query)
+                    .textFieldStyle(.roundedBorder)
+                    .focused(focus, equals: .find)
+                    .onSubmit { onCommand(.next) }
+                    .accessibilityLabel("Find in note")
+
+                Text(statusText)
+                    .font(.system(size: 11))
+                    .monospacedDigit()
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    .frame(minWidth: 70, alignment: .trailing)
+
+                Button {
+                    onCommand(.previous)
+                } label: {
+                    Image(systemName: "chevron.up")
+                }
+                .help("Previous Match (⇧⌘G)")
+                .accessibilityLabel("Previous match")
+                .disabled(status.total == 0)
+
+                Button {
+                    onCommand(.next)
+                } label: {
+                    Image(systemName: "chevron.down")
+                }
+                .help("Next Match (⌘G)")
+                .accessibilityLabel("Next match")
+                .disabled(status.total == 0)
+
+                Button("Done", action: onClose)
+                    .help("Close the find bar (Esc)")
+            }
+
+            if showsReplace, canReplace {
+                HStack(spacing: 6) {
+                    Color.clear
+                        .frame(width: 12, height: 1)
+                    TextField("Replace with", text: $replacement)
+                        .textFieldStyle(.roundedBorder)
+                        .focused(focus, equals: .replace)
+                        .onSubmit { onCommand(.replace(replacement)) }
+                        .accessibilityLabel("Replace with")
+                    Button("Replace") {
+                        onCommand(.replace(replacement))
+                    }
+                    .disabled(status.total == 0)
+                    Button("Replace All") {
+                        onCommand(.replaceAll(replacement))
+                    }
+                    .disabled(status.total == 0)
+                }
+            }
+        }
+        .controlSize(.small)
+        .padding(8)
+        .background(themeManager.colors.editorTextBackground)
+        .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+    }
+
+    private var statusText: String {
+        if let replacedCount = status.replacedCount {
+            return replacedCount == 1 ? "1 replaced" : "\(replacedCount) replaced"
+        }
+        guard !query.isEmpty else {
+            return ""
+        }
+        switch (status.current, status.total) {
+        case (_, 0):
+            return "No matches"
+        case (let current?, let total):
+            return "\(current) of \(total)"
+        case (nil, 1):
+            return "1 match"
+        case (nil, let total):
+            return "\(total) matches"
+        }
+    }
+}
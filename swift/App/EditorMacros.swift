@@ -0,0 +1,156 @@
+import AppKit
+
+private let editorMacrosFileName = "editor-macros.json"
+
+/// One key press captured while recording, enough to rebuild the event.
+struct RecordedKeyEvent: Codable, Equatable {
+    var keyCode: UInt16
+    var characters: String
+    var charactersIgnoringModifiers: String
+    var modifierFlags: UInt
+
+    init(event: NSEvent) {
+        keyCode = event.keyCode
+        characters = event.characters ?? ""
+        charactersIgnoringModifiers = event.charactersIgnoringModifiers ?? ""
+        modifierFlags = event.modifierFlags.intersection(.deviceIndependentFlagsMask).rawValue
+    }
+
+    var modifiers: NSEvent.ModifierFlags {
+        NSEvent.ModifierFlags(rawValue: modifierFlags)
+    }
+
+    func makeEvent(windowNumber: Int) -> NSEvent? {
+        NSEvent.keyEvent(
+            with: .keyDown,
+            location: .zero,
+            modifierFlags: modifiers,
+            timestamp: ProcessInfo.processInfo.systemUptime,
+            windowNumber: windowNumber,
+            context: nil,
+            characters: characters,
+            charactersIgnoringModifiers: charactersIgnoringModifiers,
+            isARepeat: false,
+            keyCode: keyCode
+        )
+    }
+}
+
+/// A recorded sequence of editor key presses, replayed with ⌃⌥ and its slot digit.
+struct EditorMacro: Codable, Equatable, Identifiable {
+    static let slots = 1...9
+
+    var id: UUID
+    var name: String
+    var slot: Int
+    var steps: [RecordedKeyEvent]
+
+    var shortcutLabel: String {
+        "⌃⌥\(slot)"
+    }
+}
+
+private struct PersistedEditorMacros: Codable {
+    let macros: [EditorMacro]
+}
+
+/// Records key presses in the note editor and keeps the saved macros. ⌃⌥R
+/// starts and stops recording; the recording waits in `pendingSteps` until it
+/// is saved to a slot or discarded.
+@MainActor
+final class EditorMacroStore: ObservableObject {
+    static let shared = EditorMacroStore()
+    static let recordKeyCode: UInt16 = 15 // R
+    static let controlModifiers: NSEvent.ModifierFlags = [.control, .option]
+
+    @Published private(set) var macros: [EditorMacro]
+    @Published private(set) var isRecording = false
+    @Published private(set) var pendingSteps: [RecordedKeyEvent]?
+    /// Set while a macro is being replayed, so its keys are not recorded again.
+    private(set) var isReplaying = false
+    private var recordedSteps: [RecordedKeyEvent] = []
+
+    private init() {
+        macros = SettingsStore.shared.loadJSON(PersistedEditorMacros.self, fileName: editorMacrosFileName)?.macros ?? []
+    }
+
+    /// The slot of a replay shortcut (⌃⌥1–⌃⌥9), if `event` is one.
+    static func replaySlot(for event: NSEvent) -> Int? {
+        guard event.modifierFlags.intersection([.command, .shift, .control, .option]) == controlModifiers,
+              let character = event.charactersIgnoringModifiers?.first,
+              let digit = character.wholeNumberValue,
+              EditorMacro.slots.contains(digit)
+        else {
+            return nil
+        }
+        return digit
+    }
+
+    static func isRecordShortcut(_ event: NSEvent) -> Bool {
+        event.modifierFlags.intersection([.command, .shift, .control, .option]) == controlModifiers
+            && event.keyCode == recordKeyCode
+    }
+
+    func macro(forSlot slot: Int) -> EditorMacro? {
+        macros.first { $0.slot == slot }
+    }
+
+    func toggleRecording() {
+        if isRecording {
+            isRecording = false
+            pendingSteps = recordedSteps.isEmpty ? nil : recordedSteps
+            recordedSteps = []
+            AccessibilityAnnouncer.announce("Macro recording stopped")
+        } else {
+            pendingSteps = nil
+            recordedSteps = []
+            isRecording = true
+            AccessibilityAnnouncer.announce("Recording macro")
+        }
+    }
+
+    func record(_ event: NSEvent) {
+        guard isRecording, !isReplaying else {
+            return
+        }
+        recordedSteps.append(RecordedKeyEvent(event: event))
+    }
+
+    /// Saves the pending recording to `slot`, replacing the macro already there.
+    func savePending(slot: Int, name: String) {
+        guard let pendingSteps, EditorMacro.slots.contains(slot) else {
+            return
+        }
+        let trimmed = name.trimmingCharacters(in: .whitespacesAndNewlines)
+        macros.removeAll { $0.slot == slot }
+        macros.append(EditorMacro(
+            id: UUID(),
+            name: trimmed.isEmpty ? "Macro \(slot)" : trimmed,
+            slot: slot,
+            steps: pendingSteps
+        ))
+        macros.sort { $0.slot < $1.slot }
+        self.pendingSteps = nil
+        persist()
+    }
+
+    func discardPending() {
+        pendingSteps = nil
+    }
+
+    func delete(id: UUID) {
+        macros.removeAll { $0.id == id }
+        persist()
+    }
+
+    /// Runs `body` with recording suspended, for replaying a macro.
+    func replaying(_ body: () -> Void) {
+        isReplaying = true
+        defer { isReplaying = false }
+        body()
+    }
+
+    private func persist() {
+        _ = SettingsStore.shared.saveJSON(PersistedEditorMacros(macros: macros), fileName: editorMacrosFileName)
+    }
+}
@@ -0,0 +1,77 @@
+import SwiftUI
+
+/// Delay after the last keystroke before the outline is rebuilt.
+private let outlineRefreshDelayNanoseconds: UInt64 = 300_000_000
+
+/// The note's headings as a clickable list; picking one scrolls the editor to
+/// its line. The backend finds the headings, so `#` lines and lines set in a
+/// heading-sized font show up here just like in the preview.
+struct EditorOutlineSidebar: View {
+    let text: String
+    /// Called with the character offset in `text` of the picked heading's line.
+    let onSelect: (Int) -> Void
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var entries: [OutlineEntryRecord] = []
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 6) {
+            Text("Outline")
+                .font(.system(size: 11, weight: .semibold))
+                .foregroundStyle(themeManager.colors.itemSubtitleText)
+
+            if entries.isEmpty {
+                Text("No headings yet. Start a line with # or ## to add one.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+                Spacer()
+            } else {
+                ScrollView {
+                    LazyVStack(alignment: .leading, spacing: 2) {
+                        ForEach(entries.indices, id: \.self) { index in
+                            entryRow(entries[index])
+                        }
+                    }
+                }
+            }
+        }
+        .padding(10)
+        .frame(maxHeight: .infinity, alignment: .topLeading)
+        .background(themeManager.colors.editorTextBackground)
+        .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+        .task(id: text) {
+            if !entries.isEmpty {
+                try? await Task.sleep(nanoseconds: outlineRefreshDelayNanoseconds)
+            }
+            guard !Task.isCancelled else {
+                return
+            }
+            entries = RustBridgeClient.outline(note: text)
+        }
+        .accessibilityElement(children: .contain)
+        .accessibilityLabel("Outline")
+    }
+
+    private func entryRow(_ entry: OutlineEntryRecord) -> some View {
+        Button {
+            onSelect(lineOffset(entry.line))
+        } label: {
+            Text(entry.title)
+                .font(.system(size: 12, weight: entry.level == 1 ? .semibold : .regular))
+                .foregroundStyle(themeManager.colors.itemTitleText)
+                .lineLimit(1)
+                .truncationMode(.tail)
+                .padding(.leading, CGFloat(entry.level - 1) * 12)
+                .frame(maxWidth: .infinity, alignment: .leading)
+                .contentShape(Rectangle())
+        }
+        .buttonStyle(.plain)
+        .help(entry.title)
+        .accessibilityAddTraits(.isHeader)
+    }
+
+    private func lineOffset(_ line: UInt32) -> Int {
+        text.split(separator: "\n", omittingEmptySubsequences: false)
+            .prefix(Int(line))
+            .reduce(0) { $0 + $1.count + 1 }
+    }
+}
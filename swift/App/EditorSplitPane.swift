@@ -0,0 +1,128 @@
+import SwiftUI
+
+private let splitPaneCandidateLimit: UInt32 = 20
+
+/// The right half of the editor's split view: another note, as text or as its
+/// rendered preview, scrolling on its own. It is read-only so copying between
+/// the two notes never edits the wrong one; Open moves it to its own window.
+struct EditorSplitPane: View {
+    /// The note in the main pane, which can't be picked here.
+    let editedItemId: Int64?
+    @Binding var itemId: Int64?
+    let fontSize: CGFloat
+    let onOpen: (Int64) -> Void
+    let onClose: () -> Void
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var item: EditableItemRecord?
+    @State private var candidates: [SearchResultRecord] = []
+    @State private var showsPreview = false
+    @State private var errorMessage: String?
+
+    var body: some View {
+        VStack(spacing: 8) {
+            HStack(spacing: 6) {
+                Picker("Note", selection: $itemId) {
+                    Text("Choose a Note").tag(Int64?.none)
+                    ForEach(candidates, id: \.id) { candidate in
+                        Text(candidate.title).tag(Int64?.some(candidate.id))
+                    }
+                }
+                .labelsHidden()
+                Picker("Show", selection: $showsPreview) {
+                    Text("Text").tag(false)
+                    Text("Preview").tag(true)
+                }
+                .pickerStyle(.segmented)
+                .labelsHidden()
+                .frame(width: 120)
+                Button("Open") {
+                    if let itemId {
+                        onOpen(itemId)
+                    }
+                }
+                .disabled(item == nil)
+                .help("Open this note in its own editor window")
+                Button(action: onClose) {
+                    Image(systemName: "xmark")
+                }
+                .buttonStyle(.borderless)
+                .help("Close the split view (⌥⌘S)")
+                .accessibilityLabel("Close Split View")
+            }
+            .font(.system(size: 11))
+            .controlSize(.small)
+
+            content
+                .padding(10)
+                .frame(maxWidth: .infinity, maxHeight: .infinity)
+                .background(themeManager.colors.editorTextBackground)
+                .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+                .themeShadow(themeManager.metrics.editorShadow)
+        }
+        .onAppear {
+            reloadCandidates()
+            reloadItem()
+        }
+        .onChange(of: itemId) { _, _ in
+            reloadItem()
+        }
+        .onChange(of: editedItemId) { _, _ in
+            reloadCandidates()
+        }
+    }
+
+    @ViewBuilder
+    private var content: some View {
+        if let item {
+            let imagesByKey = Dictionary(item.images.map { ($0.imageKey, $0.bytes) }) { first, _ in first }
+            if showsPreview {
+                MarkdownPreviewView(
+                    text: item.note,
+                    imagesByKey: imagesByKey,
+                    fontSize: fontSize,
+                    defaultImageWidth: 360
+                )
+            } else {
+                InlineImageTextEditor(
+                    text: .constant(item.note),
+                    imagesByKey: imagesByKey,
+                    isEditable: false,
+                    highlightSearchMatches: false,
+                    dividerColor: themeManager.editorDividerColor,
+                    dividerTopMargin: themeManager.editorDividerTopMargin,
+                    dividerBottomMargin: themeManager.editorDividerBottomMargin,
+                    fontSize: fontSize
+                )
+            }
+        } else {
+            Text(errorMessage ?? "Choose a note to show it next to this one.")
+                .font(.system(size: 12))
+                .foregroundStyle(errorMessage == nil ? themeManager.colors.itemSubtitleText : themeManager.colors.errorColor)
+                .frame(maxWidth: .infinity, maxHeight: .infinity)
+        }
+    }
+
+    private func reloadCandidates() {
+        do {
+            candidates = try RustBridgeClient.recentItems(limit: splitPaneCandidateLimit)
+                .filter { $0.id != editedItemId }
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func reloadItem() {
+        guard let itemId else {
+            item = nil
+            errorMessage = nil
+            return
+        }
+        do {
+            item = try RustBridgeClient.fetch(itemId: itemId)
+            errorMessage = nil
+        } catch {
+            item = nil
+            errorMessage = error.localizedDescription
+        }
+    }
+}
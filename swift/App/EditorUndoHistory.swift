@@ -0,0 +1,58 @@
+import Foundation
+
+/// A note's text and images at one point of its undo history. Images are part
+/// of the step so undoing a paste or a deleted image brings back exactly the
+/// images its markers refer to, even after a save has pruned them.
+struct EditorUndoSnapshot {
+    var note: String
+    var images: [NoteImageRecord]
+}
+
+/// Undo and redo stacks for one note's editor text. Changes less than
+/// `coalescingInterval` apart (typing) form one step; the view model starts a
+/// new step for its own edits like pasting or deleting images. Histories live
+/// in the view model, so they outlast closing and reopening the editor.
+struct EditorUndoHistory {
+    static let coalescingInterval: TimeInterval = 1
+    static let maxSteps = 200
+
+    private(set) var undoStack: [EditorUndoSnapshot] = []
+    private(set) var redoStack: [EditorUndoSnapshot] = []
+    private var lastChangeAt: Date?
+
+    /// Records that the note changed away from `previous`.
+    mutating func recordChange(from previous: EditorUndoSnapshot, at date: Date, startsNewStep: Bool) {
+        redoStack.removeAll()
+        defer { lastChangeAt = date }
+        if !startsNewStep,
+           let lastChangeAt,
+           date.timeIntervalSince(lastChangeAt) < Self.coalescingInterval,
+           !undoStack.isEmpty {
+            return
+        }
+        undoStack.append(previous)
+        if undoStack.count > Self.maxSteps {
+            undoStack.removeFirst(undoStack.count - Self.maxSteps)
+        }
+    }
+
+    /// The snapshot to return to, with `current` kept for redo.
+    mutating func undo(from current: EditorUndoSnapshot) -> EditorUndoSnapshot? {
+        guard let previous = undoStack.popLast() else {
+            return nil
+        }
+        redoStack.append(current)
+        lastChangeAt = nil
+        return previous
+    }
+
+    /// The snapshot undone last, with `current` kept for undo.
+    mutating func redo(from current: EditorUndoSnapshot) -> EditorUndoSnapshot? {
+        guard let next = redoStack.popLast() else {
+            return nil
+        }
+        undoStack.append(current)
+        lastChangeAt = nil
+        return next
+    }
+}
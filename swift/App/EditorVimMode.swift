@@ -0,0 +1,405 @@
+import AppKit
+
+enum EditorVimMode {
+    case normal
+    case insert
+    case visual
+
+    var label: String {
+        switch self {
+        case .normal: return "NORMAL"
+        case .insert: return "INSERT"
+        case .visual: return "VISUAL"
+        }
+    }
+}
+
+/// Vim-style modal editing on top of the note editor's text view. Normal mode
+/// moves with h j k l, w b e, 0 ^ $ and gg G (all but the last two take a
+/// count), deletes with x and dd, yanks with yy and puts with p and P; i a I A
+/// o O start Insert mode, v starts Visual mode (d, x or y act on the
+/// selection) and Esc goes back to Normal. u and ⌃R undo and redo through
+/// `onUndo`; other keys held with ⌘ or ⌃ are left to the editor.
+@MainActor
+final class EditorVimController {
+    private(set) var mode: EditorVimMode = .normal {
+        didSet {
+            if mode != oldValue {
+                onModeChange?(mode)
+            }
+        }
+    }
+    var onModeChange: ((EditorVimMode) -> Void)?
+    /// Undoes, or redoes when passed true, in the editor's own history.
+    var onUndo: ((_ redo: Bool) -> Void)?
+    /// First key of a two-key command: `d`, `y` or `g`.
+    private var pendingKey: Character?
+    private var count = 0
+    private var visualAnchor = 0
+    private var visualHead = 0
+    /// Last deleted or yanked text; whole lines are put on lines of their own.
+    private var register: (text: NSAttributedString, isLinewise: Bool)?
+
+    /// Handles a key press in `textView`; false leaves it to the text view.
+    func handle(_ event: NSEvent, in textView: NSTextView) -> Bool {
+        if event.keyCode == 53 { // Esc
+            if mode == .visual {
+                textView.setSelectedRange(NSRange(location: visualHead, length: 0))
+            }
+            mode = .normal
+            pendingKey = nil
+            count = 0
+            return true
+        }
+        if mode == .normal,
+           event.modifierFlags.intersection([.command, .control, .option]) == [.control],
+           event.charactersIgnoringModifiers?.lowercased() == "r" {
+            onUndo?(true)
+            return true
+        }
+        guard mode != .insert,
+              event.modifierFlags.intersection([.command, .control]).isEmpty,
+              let key = event.characters?.first,
+              !isFunctionKey(key)
+        else {
+            return false
+        }
+
+        if pendingKey == nil, let digit = key.wholeNumberValue, digit > 0 || count > 0 {
+            count = min(count * 10 + digit, 9_999)
+            return true
+        }
+        let repeatCount = max(count, 1)
+        count = 0
+
+        if let pending = pendingKey {
+            pendingKey = nil
+            switch (pending, key) {
+            case ("g", "g"):
+                moveHead(to: 0, in: textView)
+            case ("d", "d") where mode == .normal:
+                takeLines(count: repeatCount, deleting: true, in: textView)
+            case ("y", "y") where mode == .normal:
+                takeLines(count: repeatCount, deleting: false, in: textView)
+            default:
+                break
+            }
+            return true
+        }
+
+        if let target = motionTarget(for: key, count: repeatCount, in: textView) {
+            moveHead(to: target, in: textView)
+        } else if mode == .visual {
+            handleVisualCommand(key, in: textView)
+        } else {
+            handleNormalCommand(key, count: repeatCount, in: textView)
+        }
+        // Keys without a command do nothing rather than type outside Insert mode.
+        return true
+    }
+
+    // MARK: Commands
+
+    private func handleNormalCommand(_ key: Character, count: Int, in textView: NSTextView) {
+        let text = textView.string as NSString
+        let position = textView.selectedRange().location
+        switch key {
+        case "g", "d", "y":
+            pendingKey = key
+        case "i":
+            mode = .insert
+        case "a":
+            textView.setSelectedRange(NSRange(location: min(position + 1, lineEnd(of: position, in: text)), length: 0))
+            mode = .insert
+        case "I":
+            textView.setSelectedRange(NSRange(location: firstNonBlank(ofLineAt: position, in: text), length: 0))
+            mode = .insert
+        case "A":
+            textView.setSelectedRange(NSRange(location: lineEnd(of: position, in: text), length: 0))
+            mode = .insert
+        case "o":
+            let end = lineEnd(of: position, in: text)
+            replace(NSRange(location: end, length: 0), with: plain("\n", in: textView), in: textView)
+            textView.setSelectedRange(NSRange(location: end + 1, length: 0))
+            mode = .insert
+        case "O":
+            let start = text.lineRange(for: NSRange(location: position, length: 0)).location
+            replace(NSRange(location: start, length: 0), with: plain("\n", in: textView), in: textView)
+            textView.setSelectedRange(NSRange(location: start, length: 0))
+            mode = .insert
+        case "v":
+            visualAnchor = position
+            mode = .visual
+            moveHead(to: position, in: textView)
+        case "x":
+            let range = NSRange(location: position, length: min(count, lineEnd(of: position, in: text) - position))
+            guard range.length > 0 else {
+                return
+            }
+            register = (textView.attributedString().attributedSubstring(from: range), false)
+            replace(range, with: NSAttributedString(), in: textView)
+            textView.setSelectedRange(NSRange(location: position, length: 0))
+        case "p", "P":
+            put(after: key == "p", count: count, in: textView)
+        case "u":
+            onUndo?(false)
+        default:
+            break
+        }
+    }
+
+    private func handleVisualCommand(_ key: Character, in textView: NSTextView) {
+        let selection = textView.selectedRange()
+        switch key {
+        case "d", "x", "y":
+            guard selection.length > 0 else {
+                mode = .normal
+                return
+            }
+            register = (textView.attributedString().attributedSubstring(from: selection), false)
+            if key != "y" {
+                replace(selection, with: NSAttributedString(), in: textView)
+            }
+            mode = .normal
+            textView.setSelectedRange(NSRange(location: selection.location, length: 0))
+        case "v":
+            mode = .normal
+            textView.setSelectedRange(NSRange(location: visualHead, length: 0))
+        default:
+            break
+        }
+    }
+
+    /// `dd` and `yy`: the `count` lines from the cursor's line go to the register.
+    private func takeLines(count: Int, deleting: Bool, in textView: NSTextView) {
+        let text = textView.string as NSString
+        let position = textView.selectedRange().location
+        var range = text.lineRange(for: NSRange(location: position, length: 0))
+        for _ in 1..<count where NSMaxRange(range) < text.length {
+            range = NSUnionRange(range, text.lineRange(for: NSRange(location: NSMaxRange(range), length: 0)))
+        }
+        let lines = NSMutableAttributedString(attributedString: textView.attributedString().attributedSubstring(from: range))
+        if !lines.string.hasSuffix("\n") {
+            lines.append(plain("\n", in: textView))
+        }
+        register = (lines, true)
+        guard deleting else {
+            return
+        }
+        // The last line has no line break of its own; take the one before it.
+        if NSMaxRange(range) == text.length, range.location > 0, !text.substring(with: range).hasSuffix("\n") {
+            range = NSRange(location: range.location - 1, length: range.length + 1)
+        }
+        replace(range, with: NSAttributedString(), in: textView)
+        let remaining = textView.string as NSString
+        let lineStart = remaining.lineRange(for: NSRange(location: min(range.location, remaining.length), length: 0)).location
+        textView.setSelectedRange(NSRange(location: firstNonBlank(ofLineAt: lineStart, in: remaining), length: 0))
+    }
+
+    private func put(after: Bool, count: Int, in textView: NSTextView) {
+        guard let register else {
+            return
+        }
+        let text = textView.string as NSString
+        let position = textView.selectedRange().location
+        let repeated = NSMutableAttributedString()
+        for _ in 0..<count {
+            repeated.append(register.text)
+        }
+
+        if register.isLinewise {
+            let line = text.lineRange(for: NSRange(location: position, length: 0))
+            var location = after ? NSMaxRange(line) : line.location
+            if after, location == text.length, text.length > 0, !text.substring(with: line).hasSuffix("\n") {
+                // Putting below a last line without a line break: break it first.
+                repeated.insert(plain("\n", in: textView), at: 0)
+                repeated.deleteCharacters(in: NSRange(location: repeated.length - 1, length: 1))
+                location = text.length
+                replace(NSRange(location: location, length: 0), with: repeated, in: textView)
+                textView.setSelectedRange(NSRange(location: location + 1, length: 0))
+                return
+            }
+            replace(NSRange(location: location, length: 0), with: repeated, in: textView)
+            textView.setSelectedRange(NSRange(location: location, length: 0))
+        } else {
+            let location = after ? min(position + 1, lineEnd(of: position, in: text)) : position
+            replace(NSRange(location: location, length: 0), with: repeated, in: textView)
+            textView.setSelectedRange(NSRange(location: location + repeated.length - 1, length: 0))
+        }
+    }
+
+    /// An undoable edit that goes through the text view's delegate like typing.
+    private func replace(_ range: NSRange, with replacement: NSAttributedString, in textView: NSTextView) {
+        guard let storage = textView.textStorage,
+              textView.shouldChangeText(in: range, replacementString: replacement.string)
+        else {
+            return
+        }
+        storage.replaceCharacters(in: range, with: replacement)
+        textView.didChangeText()
+    }
+
+    private func plain(_ string: String, in textView: NSTextView) -> NSAttributedString {
+        NSAttributedString(string: string, attributes: textView.typingAttributes)
+    }
+
+    // MARK: Motions
+
+    /// Where a motion key moves the cursor, or nil when `key` is no motion.
+    private func motionTarget(for key: Character, count: Int, in textView: NSTextView) -> Int? {
+        let text = textView.string as NSString
+        var position = mode == .visual ? visualHead : textView.selectedRange().location
+        switch key {
+        case "h":
+            position = max(position - count, text.lineRange(for: NSRange(location: position, length: 0)).location)
+        case "l":
+            position = min(position + count, lineEnd(of: position, in: text))
+        case "j", "k":
+            for _ in 0..<count {
+                position = verticalTarget(from: position, down: key == "j", in: text)
+            }
+        case "w":
+            for _ in 0..<count {
+                position = nextWordStart(after: position, in: text)
+            }
+        case "b":
+            for _ in 0..<count {
+                position = previousWordStart(before: position, in: text)
+            }
+        case "e":
+            for _ in 0..<count {
+                position = nextWordEnd(after: position, in: text)
+            }
+        case "0":
+            position = text.lineRange(for: NSRange(location: position, length: 0)).location
+        case "^":
+            position = firstNonBlank(ofLineAt: position, in: text)
+        case "$":
+            position = lineEnd(of: position, in: text)
+        case "G":
+            position = text.lineRange(for: NSRange(location: text.length, length: 0)).location
+        default:
+            return nil
+        }
+        return position
+    }
+
+    private func moveHead(to position: Int, in textView: NSTextView) {
+        if mode == .visual {
+            visualHead = position
+            let length = (textView.string as NSString).length
+            let start = min(visualAnchor, visualHead)
+            let end = min(max(visualAnchor, visualHead) + 1, length)
+            textView.setSelectedRange(NSRange(location: start, length: max(end - start, 0)))
+        } else {
+            textView.setSelectedRange(NSRange(location: position, length: 0))
+        }
+        textView.scrollRangeToVisible(NSRange(location: position, length: 0))
+    }
+
+    private func lineEnd(of position: Int, in text: NSString) -> Int {
+        var contentsEnd = 0
+        text.getLineStart(nil, end: nil, contentsEnd: &contentsEnd, for: NSRange(location: position, length: 0))
+        return contentsEnd
+    }
+
+    private func firstNonBlank(ofLineAt position: Int, in text: NSString) -> Int {
+        var index = text.lineRange(for: NSRange(location: position, length: 0)).location
+        let end = lineEnd(of: position, in: text)
+        while index < end, characterClass(text.character(at: index)) == .blank {
+            index += 1
+        }
+        return index
+    }
+
+    /// The same column on the line above or below, or that line's end when it
+    /// is shorter.
+    private func verticalTarget(from position: Int, down: Bool, in text: NSString) -> Int {
+        let line = text.lineRange(for: NSRange(location: position, length: 0))
+        let column = position - line.location
+        let targetStart: Int
+        if down {
+            let endsWithBreak = line.length > 0 && isNewline(text.character(at: NSMaxRange(line) - 1))
+            guard endsWithBreak else {
+                return position
+            }
+            targetStart = NSMaxRange(line)
+        } else {
+            guard line.location > 0 else {
+                return position
+            }
+            targetStart = text.lineRange(for: NSRange(location: line.location - 1, length: 0)).location
+        }
+        return min(targetStart + column, lineEnd(of: targetStart, in: text))
+    }
+
+    private func nextWordStart(after position: Int, in text: NSString) -> Int {
+        var index = position
+        guard index < text.length else {
+            return index
+        }
+        let startClass = characterClass(text.character(at: index))
+        while index < text.length, startClass != .blank, characterClass(text.character(at: index)) == startClass {
+            index += 1
+        }
+        while index < text.length, characterClass(text.character(at: index)) == .blank {
+            index += 1
+        }
+        return index
+    }
+
+    private func previousWordStart(before position: Int, in text: NSString) -> Int {
+        var index = position
+        while index > 0, characterClass(text.character(at: index - 1)) == .blank {
+            index -= 1
+        }
+        guard index > 0 else {
+            return 0
+        }
+        let wordClass = characterClass(text.character(at: index - 1))
+        while index > 0, characterClass(text.character(at: index - 1)) == wordClass {
+            index -= 1
+        }
+        return index
+    }
+
+    private func nextWordEnd(after position: Int, in text: NSString) -> Int {
+        var index = position + 1
+        while index < text.length, characterClass(text.character(at: index)) == .blank {
+            index += 1
+        }
+        guard index < text.length else {
+            return max(text.length - 1, position)
+        }
+        let wordClass = characterClass(text.character(at: index))
+        while index + 1 < text.length, characterClass(text.character(at: index + 1)) == wordClass {
+            index += 1
+        }
+        return index
+    }
+
+    private enum CharacterClass {
+        case blank
+        case word
+        case punctuation
+    }
+
+    private func characterClass(_ character: unichar) -> CharacterClass {
+        if character == 0x20 || character == 0x09 || isNewline(character) {
+            return .blank
+        }
+        guard let scalar = Unicode.Scalar(character) else {
+            return .word
+        }
+        return CharacterSet.alphanumerics.contains(scalar) || scalar == "_" ? .word : .punctuation
+    }
+
+    private func isNewline(_ character: unichar) -> Bool {
+        character == 0x0A || character == 0x0D
+    }
+
+    /// Arrow, page and other function keys arrive as private-use characters.
+    private func isFunctionKey(_ key: Character) -> Bool {
+        key.unicodeScalars.first.map { (0xF700...0xF8FF).contains($0.value) } ?? false
+    }
+}
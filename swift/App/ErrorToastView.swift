@@ -0,0 +1,103 @@
+import AppKit
+import SwiftUI
+
+/// The view model's error toasts, oldest on top. Each one dismisses itself
+/// after a few seconds or when clicked.
+struct ErrorToastStack: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 4) {
+            ForEach(viewModel.errorToasts) { toast in
+                HStack(alignment: .top, spacing: 6) {
+                    Image(systemName: "exclamationmark.triangle.fill")
+                        .accessibilityHidden(true)
+                    Text(toast.message)
+                        .frame(maxWidth: .infinity, alignment: .leading)
+                        .lineLimit(3)
+                    Button {
+                        viewModel.dismissErrorToast(id: toast.id)
+                    } label: {
+                        Image(systemName: "xmark")
+                    }
+                    .buttonStyle(.borderless)
+                    .help("Dismiss")
+                    .accessibilityLabel("Dismiss error")
+                }
+                .font(.system(size: 12))
+                .foregroundStyle(themeManager.colors.errorColor)
+                .padding(.horizontal, 8)
+                .padding(.vertical, 6)
+                .background(themeManager.colors.errorColor.opacity(0.12))
+                .clipShape(RoundedRectangle(cornerRadius: 6, style: .continuous))
+                .transition(.opacity.combined(with: .move(edge: .top)))
+            }
+        }
+        .animation(.easeOut(duration: 0.15), value: viewModel.errorToasts)
+    }
+}
+
+/// Settings list of the errors shown as toasts since launch, newest first.
+struct RecentErrorsLog: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+
+    private static let timeFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.dateStyle = .none
+        formatter.timeStyle = .medium
+        return formatter
+    }()
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            HStack {
+                Text("Recent Errors")
+                    .font(.system(size: 12, weight: .medium))
+                    .foregroundStyle(.secondary)
+                Spacer()
+                Button("Copy") {
+                    copyLog()
+                }
+                .disabled(viewModel.recentErrors.isEmpty)
+                Button("Clear") {
+                    viewModel.clearRecentErrors()
+                }
+                .disabled(viewModel.recentErrors.isEmpty)
+            }
+
+            if viewModel.recentErrors.isEmpty {
+                Text("No errors since the app started.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+            } else {
+                ScrollView {
+                    VStack(alignment: .leading, spacing: 4) {
+                        ForEach(viewModel.recentErrors) { error in
+                            HStack(alignment: .top, spacing: 8) {
+                                Text(Self.timeFormatter.string(from: error.date))
+                                    .monospacedDigit()
+                                    .foregroundStyle(.secondary)
+                                Text(error.message)
+                                    .foregroundStyle(themeManager.colors.errorColor)
+                                    .textSelection(.enabled)
+                            }
+                            .font(.system(size: 11))
+                            .frame(maxWidth: .infinity, alignment: .leading)
+                        }
+                    }
+                }
+                .frame(maxHeight: 120)
+            }
+        }
+    }
+
+    private func copyLog() {
+        let log = viewModel.recentErrors
+            .map { "\(Self.timeFormatter.string(from: $0.date))  \($0.message)" }
+            .joined(separator: "\n")
+        NSPasteboard.general.clearContents()
+        NSPasteboard.general.setString(log, forType: .string)
+    }
+}
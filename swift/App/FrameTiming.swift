@@ -0,0 +1,191 @@
+import AppKit
+import SwiftUI
+
+/// Work measured inside a main-thread frame.
+enum FramePhase: String, CaseIterable {
+    case messages
+    case searchApply
+    case inlineImages
+    /// Whatever the frame spent outside the measured phases, mostly SwiftUI
+    /// layout and drawing.
+    case paint
+
+    var label: String {
+        switch self {
+        case .messages: return "events"
+        case .searchApply: return "search"
+        case .inlineImages: return "images"
+        case .paint: return "paint"
+        }
+    }
+}
+
+/// Timing of one pass of the main run loop, from waking up to going back to sleep.
+struct FrameTimingSample: Equatable {
+    var totalMilliseconds: Double
+    var phaseMilliseconds: [FramePhase: Double]
+
+    var summary: String {
+        let phases = FramePhase.allCases.compactMap { phase -> String? in
+            guard let value = phaseMilliseconds[phase], value >= 0.05 else {
+                return nil
+            }
+            return "\(phase.label) \(String(format: "%.1f", value))"
+        }
+        let total = String(format: "%.1f ms", totalMilliseconds)
+        return phases.isEmpty ? total : "\(total) (\(phases.joined(separator: ", ")))"
+    }
+}
+
+/// Optional per-frame timing for diagnosing stutter: measures each main run loop
+/// pass, breaks it down by phase, shows the figures in a launcher overlay and logs
+/// frames slower than the threshold. Used from the main thread only.
+final class FrameTimingMonitor: ObservableObject {
+    static let shared = FrameTimingMonitor()
+
+    static let defaultSlowFrameMilliseconds = 50.0
+    private static let settingsFileName = "frame-timing-settings.json"
+    /// The overlay is refreshed at most this often so it doesn't cause the frames it measures.
+    private static let publishInterval: TimeInterval = 0.5
+
+    private struct PersistedSettings: Codable {
+        let enabled: Bool
+        let slowFrameMilliseconds: Double
+    }
+
+    @Published var isEnabled: Bool {
+        didSet {
+            guard isEnabled != oldValue else {
+                return
+            }
+            updateObserver()
+            persist()
+        }
+    }
+    @Published var slowFrameMilliseconds: Double {
+        didSet {
+            guard slowFrameMilliseconds >= 1 else {
+                slowFrameMilliseconds = 1
+                return
+            }
+            persist()
+        }
+    }
+    /// The last frame and the slowest frame since the previous overlay refresh.
+    @Published private(set) var lastFrame: FrameTimingSample?
+    @Published private(set) var slowestRecentFrame: FrameTimingSample?
+    @Published private(set) var slowFrameCount = 0
+
+    private var observer: CFRunLoopObserver?
+    private var frameStart: CFAbsoluteTime?
+    private var phaseTotals: [FramePhase: Double] = [:]
+    private var pendingLast: FrameTimingSample?
+    private var pendingSlowest: FrameTimingSample?
+    private var pendingSlowCount = 0
+    private var lastPublish: CFAbsoluteTime = 0
+
+    private init() {
+        let persisted = SettingsStore.shared.loadJSON(PersistedSettings.self, fileName: Self.settingsFileName)
+        isEnabled = persisted?.enabled ?? false
+        slowFrameMilliseconds = persisted?.slowFrameMilliseconds ?? Self.defaultSlowFrameMilliseconds
+        updateObserver()
+    }
+
+    /// Runs `body`, adding its duration to `phase` of the current frame while timing is on.
+    /// Work off the main thread is not part of a frame and runs unmeasured.
+    static func measure<T>(_ phase: FramePhase, _ body: () throws -> T) rethrows -> T {
+        guard Thread.isMainThread, shared.observer != nil else {
+            return try body()
+        }
+        let start = CFAbsoluteTimeGetCurrent()
+        defer {
+            shared.phaseTotals[phase, default: 0] += (CFAbsoluteTimeGetCurrent() - start) * 1000
+        }
+        return try body()
+    }
+
+    private func updateObserver() {
+        if isEnabled, observer == nil {
+            let activities = CFRunLoopActivity.afterWaiting.rawValue | CFRunLoopActivity.beforeWaiting.rawValue
+            let observer = CFRunLoopObserverCreateWithHandler(nil, activities, true, 0) { _, activity in
+                FrameTimingMonitor.shared.runLoopDidChange(activity)
+            }
+            CFRunLoopAddObserver(CFRunLoopGetMain(), observer, .commonModes)
+            self.observer = observer
+        } else if !isEnabled, let observer {
+            CFRunLoopRemoveObserver(CFRunLoopGetMain(), observer, .commonModes)
+            self.observer = nil
+            frameStart = nil
+            pendingLast = nil
+            pendingSlowest = nil
+            pendingSlowCount = 0
+            lastFrame = nil
+            slowestRecentFrame = nil
+            slowFrameCount = 0
+        }
+    }
+
+    private func runLoopDidChange(_ activity: CFRunLoopActivity) {
+        if activity == .afterWaiting {
+            frameStart = CFAbsoluteTimeGetCurrent()
+            phaseTotals.removeAll(keepingCapacity: true)
+            return
+        }
+
+        guard let frameStart else {
+            return
+        }
+        self.frameStart = nil
+        let now = CFAbsoluteTimeGetCurrent()
+        let total = (now - frameStart) * 1000
+        var phases = phaseTotals
+        phases[.paint] = max(0, total - phaseTotals.values.reduce(0, +))
+        let sample = FrameTimingSample(totalMilliseconds: total, phaseMilliseconds: phases)
+
+        pendingLast = sample
+        if total > pendingSlowest?.totalMilliseconds ?? -1 {
+            pendingSlowest = sample
+        }
+        if total >= slowFrameMilliseconds {
+            pendingSlowCount += 1
+            NSLog("FrameTiming: slow frame \(sample.summary)")
+        }
+
+        if now - lastPublish >= Self.publishInterval {
+            lastPublish = now
+            lastFrame = pendingLast
+            slowestRecentFrame = pendingSlowest
+            slowFrameCount = pendingSlowCount
+            pendingSlowest = nil
+        }
+    }
+
+    private func persist() {
+        let settings = PersistedSettings(enabled: isEnabled, slowFrameMilliseconds: slowFrameMilliseconds)
+        _ = SettingsStore.shared.saveJSON(settings, fileName: Self.settingsFileName)
+    }
+}
+
+/// Debug overlay with the latest frame timings, observed on its own so the
+/// launcher doesn't re-render when the figures change.
+struct FrameTimingOverlay: View {
+    @ObservedObject private var monitor = FrameTimingMonitor.shared
+
+    var body: some View {
+        if monitor.isEnabled, let lastFrame = monitor.lastFrame {
+            VStack(alignment: .trailing, spacing: 2) {
+                Text("last \(lastFrame.summary)")
+                if let slowest = monitor.slowestRecentFrame {
+                    Text("max \(slowest.summary)")
+                }
+                Text("\(monitor.slowFrameCount) slow (≥ \(Int(monitor.slowFrameMilliseconds)) ms)")
+            }
+            .font(.system(size: 10, design: .monospaced))
+            .foregroundStyle(.white)
+            .padding(6)
+            .background(Color.black.opacity(0.6))
+            .clipShape(RoundedRectangle(cornerRadius: 6, style: .continuous))
+            .allowsHitTesting(false)
+        }
+    }
+}
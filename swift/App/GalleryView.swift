@@ -0,0 +1,269 @@
+import SwiftUI
+
+private let galleryCellSize: CGFloat = 160
+
+/// Every image of one note as a thumbnail grid, for notes used as screenshot
+/// collections. Click or Space zooms into an image, the arrow keys step
+/// through them and Escape goes back to the grid.
+struct GalleryView: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var imageKeys: [String] = []
+    @State private var selectedIndex = 0
+    @State private var zoomedImage: NSImage?
+    @State private var showsActualSize = false
+    @State private var errorMessage: String?
+    @State private var window: NSWindow?
+    @FocusState private var isFocused: Bool
+
+    private var isZoomed: Bool {
+        zoomedImage != nil
+    }
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 12) {
+            HStack {
+                Text(viewModel.galleryTarget?.title ?? "Gallery")
+                    .font(.system(size: 20, weight: .semibold))
+                    .lineLimit(1)
+                Spacer()
+                Text(imageKeys.count == 1 ? "1 image" : "\(imageKeys.count) images")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+                Button {
+                    reload()
+                } label: {
+                    Image(systemName: "arrow.clockwise")
+                }
+                .buttonStyle(.borderless)
+                .help("Refresh")
+            }
+
+            if let errorMessage {
+                Text(errorMessage)
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+
+            if isZoomed {
+                zoomedView
+            } else if imageKeys.isEmpty {
+                Text("This note has no images.")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+                Spacer(minLength: 0)
+            } else {
+                thumbnailGrid
+            }
+        }
+        .padding(16)
+        .frame(minWidth: 560, minHeight: 420, alignment: .topLeading)
+        .background(themeManager.colors.editorBackground)
+        .background(
+            WindowAccessor { resolved in
+                guard window !== resolved else { return }
+                window = resolved
+                viewModel.viewWindowDidOpen(resolved)
+            }
+        )
+        .focusable()
+        .focused($isFocused)
+        .focusEffectDisabled()
+        .onKeyPress(.leftArrow) { moveSelection(by: -1) }
+        .onKeyPress(.rightArrow) { moveSelection(by: 1) }
+        .onKeyPress(.upArrow) { moveSelection(by: isZoomed ? -1 : -columnCount) }
+        .onKeyPress(.downArrow) { moveSelection(by: isZoomed ? 1 : columnCount) }
+        .onKeyPress(.space) {
+            toggleZoom()
+            return .handled
+        }
+        .onKeyPress(.return) {
+            toggleZoom()
+            return .handled
+        }
+        .onKeyPress(.escape) {
+            if isZoomed {
+                zoomedImage = nil
+            } else {
+                window?.performClose(nil)
+            }
+            return .handled
+        }
+        .onAppear {
+            reload()
+            isFocused = true
+        }
+        .onDisappear {
+            zoomedImage = nil
+            viewModel.viewWindowDidClose(window)
+        }
+        .onChange(of: viewModel.galleryTarget) { _, _ in
+            selectedIndex = 0
+            zoomedImage = nil
+            reload()
+        }
+        .onChange(of: viewModel.viewWindowRefreshID) { _, _ in
+            reload()
+        }
+    }
+
+    /// Columns the grid fits into the window, so Up/Down move by a row.
+    private var columnCount: Int {
+        let width = (window?.contentView?.bounds.width ?? 0) - 32
+        return max(1, Int((width + 8) / (galleryCellSize + 8)))
+    }
+
+    private var thumbnailGrid: some View {
+        ScrollViewReader { proxy in
+            ScrollView {
+                LazyVGrid(columns: [GridItem(.adaptive(minimum: galleryCellSize, maximum: galleryCellSize), spacing: 8)], spacing: 8) {
+                    ForEach(Array(imageKeys.enumerated()), id: \.element) { index, imageKey in
+                        GalleryThumbnail(
+                            itemId: viewModel.galleryTarget?.itemId ?? 0,
+                            imageKey: imageKey,
+                            isSelected: index == selectedIndex,
+                            accentColor: themeManager.colors.accentColor
+                        )
+                        .id(index)
+                        .accessibilityElement(children: .ignore)
+                        .accessibilityLabel("Image \(index + 1) of \(imageKeys.count)")
+                        .accessibilityAddTraits(index == selectedIndex ? [.isImage, .isSelected] : .isImage)
+                        .accessibilityAction(named: "Zoom") {
+                            selectedIndex = index
+                            zoom()
+                        }
+                        .onTapGesture(count: 2) {
+                            selectedIndex = index
+                            zoom()
+                        }
+                        .onTapGesture {
+                            selectedIndex = index
+                            isFocused = true
+                        }
+                    }
+                }
+                .padding(2)
+            }
+            .onChange(of: selectedIndex) { _, index in
+                proxy.scrollTo(index)
+            }
+        }
+    }
+
+    private var zoomedView: some View {
+        VStack(spacing: 8) {
+            if let zoomedImage {
+                if showsActualSize {
+                    ScrollView([.horizontal, .vertical]) {
+                        Image(nsImage: zoomedImage)
+                    }
+                    .onTapGesture { showsActualSize = false }
+                } else {
+                    Image(nsImage: zoomedImage)
+                        .resizable()
+                        .scaledToFit()
+                        .frame(maxWidth: .infinity, maxHeight: .infinity)
+                        .onTapGesture { showsActualSize = true }
+                }
+            }
+            HStack {
+                Text("\(selectedIndex + 1) of \(imageKeys.count)")
+                    .monospacedDigit()
+                Spacer()
+                Text(showsActualSize ? "Click to fit" : "Click for actual size")
+                Text("·")
+                Text("Esc to go back")
+            }
+            .font(.system(size: 11))
+            .foregroundStyle(.secondary)
+        }
+    }
+
+    private func moveSelection(by offset: Int) -> KeyPress.Result {
+        guard !imageKeys.isEmpty else {
+            return .ignored
+        }
+        let index = min(max(selectedIndex + offset, 0), imageKeys.count - 1)
+        guard index != selectedIndex else {
+            return .handled
+        }
+        selectedIndex = index
+        if isZoomed {
+            zoom()
+        }
+        AccessibilityAnnouncer.announce("Image \(index + 1) of \(imageKeys.count)")
+        return .handled
+    }
+
+    private func toggleZoom() {
+        if isZoomed {
+            zoomedImage = nil
+        } else {
+            zoom()
+        }
+    }
+
+    private func zoom() {
+        guard let itemId = viewModel.galleryTarget?.itemId, imageKeys.indices.contains(selectedIndex) else {
+            return
+        }
+        do {
+            let data = try RustBridgeClient.itemImage(itemId: itemId, imageKey: imageKeys[selectedIndex])
+            zoomedImage = NSImage(data: data)
+            showsActualSize = false
+            errorMessage = zoomedImage == nil ? "The image could not be decoded." : nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func reload() {
+        guard let itemId = viewModel.galleryTarget?.itemId else {
+            imageKeys = []
+            return
+        }
+        do {
+            imageKeys = try RustBridgeClient.itemImageKeys(itemId: itemId)
+            selectedIndex = min(selectedIndex, max(imageKeys.count - 1, 0))
+            errorMessage = nil
+        } catch {
+            imageKeys = []
+            errorMessage = error.localizedDescription
+        }
+    }
+}
+
+private struct GalleryThumbnail: View {
+    let itemId: Int64
+    let imageKey: String
+    let isSelected: Bool
+    let accentColor: Color
+    @State private var image: NSImage?
+
+    var body: some View {
+        ZStack {
+            Color(nsColor: .controlBackgroundColor)
+            if let image {
+                Image(nsImage: image)
+                    .resizable()
+                    .scaledToFill()
+            } else {
+                ProgressView()
+                    .controlSize(.small)
+            }
+        }
+        .frame(width: galleryCellSize, height: galleryCellSize)
+        .clipShape(RoundedRectangle(cornerRadius: 8, style: .continuous))
+        .overlay(
+            RoundedRectangle(cornerRadius: 8, style: .continuous)
+                .stroke(isSelected ? accentColor : Color.clear, lineWidth: 3)
+        )
+        .contentShape(Rectangle())
+        .task(id: imageKey) {
+            image = ResultThumbnailCache.gallery.cachedThumbnail(itemId: itemId, imageKey: imageKey)
+            if image == nil {
+                image = await ResultThumbnailCache.gallery.thumbnail(itemId: itemId, imageKey: imageKey)
+            }
+        }
+    }
+}
@@ -0,0 +1,335 @@
+import AppKit
+import ImageIO
+import SwiftUI
+
+/// A mark drawn over an image, in pixels from the image's top-left corner.
+enum ImageAnnotation: Equatable {
+    case arrow(from: CGPoint, to: CGPoint)
+    case rectangle(CGRect)
+}
+
+enum ImageEditTool: String, CaseIterable, Identifiable {
+    case arrow
+    case rectangle
+    case crop
+
+    var id: String { rawValue }
+
+    var name: String {
+        switch self {
+        case .arrow: return "Arrow"
+        case .rectangle: return "Rectangle"
+        case .crop: return "Crop"
+        }
+    }
+
+    var systemImage: String {
+        switch self {
+        case .arrow: return "arrow.up.right"
+        case .rectangle: return "rectangle"
+        case .crop: return "crop"
+        }
+    }
+}
+
+/// Pixel operations behind the image editor. Rectangles and points use a
+/// top-left origin, like `CGImage.cropping(to:)`.
+enum ImageEditing {
+    static let annotationColor = CGColor(red: 1, green: 0.23, blue: 0.19, alpha: 1)
+
+    /// Stroke width that stays visible when a large screenshot is scaled down.
+    static func lineWidth(for image: CGImage) -> CGFloat {
+        max(3, CGFloat(min(image.width, image.height)) / 150)
+    }
+
+    static func rotatedClockwise(_ image: CGImage) -> CGImage? {
+        guard let context = makeContext(width: image.height, height: image.width) else {
+            return nil
+        }
+        // Drawing happens with the y axis up, so a clockwise turn is -90°.
+        context.translateBy(x: 0, y: CGFloat(image.width))
+        context.rotate(by: -.pi / 2)
+        context.draw(image, in: CGRect(x: 0, y: 0, width: image.width, height: image.height))
+        return context.makeImage()
+    }
+
+    static func cropped(_ image: CGImage, to rect: CGRect) -> CGImage? {
+        let bounds = CGRect(x: 0, y: 0, width: image.width, height: image.height)
+        let clipped = rect.standardized.integral.intersection(bounds)
+        guard clipped.width >= 1, clipped.height >= 1 else {
+            return nil
+        }
+        return image.cropping(to: clipped)
+    }
+
+    static func drawing(_ annotations: [ImageAnnotation], on image: CGImage) -> CGImage? {
+        guard !annotations.isEmpty else {
+            return image
+        }
+        guard let context = makeContext(width: image.width, height: image.height) else {
+            return nil
+        }
+        let height = CGFloat(image.height)
+        context.draw(image, in: CGRect(x: 0, y: 0, width: image.width, height: image.height))
+        context.translateBy(x: 0, y: height)
+        context.scaleBy(x: 1, y: -1)
+        context.setStrokeColor(annotationColor)
+        context.setLineWidth(lineWidth(for: image))
+        context.setLineCap(.round)
+        context.setLineJoin(.round)
+        for annotation in annotations {
+            context.addPath(path(for: annotation, lineWidth: lineWidth(for: image)))
+            context.strokePath()
+        }
+        return context.makeImage()
+    }
+
+    /// The outline of an annotation; arrows get a two-stroke head at `to`.
+    static func path(for annotation: ImageAnnotation, lineWidth: CGFloat) -> CGPath {
+        let path = CGMutablePath()
+        switch annotation {
+        case .rectangle(let rect):
+            path.addRect(rect.standardized)
+        case .arrow(let from, let to):
+            path.move(to: from)
+            path.addLine(to: to)
+            let angle = atan2(to.y - from.y, to.x - from.x)
+            let headLength = lineWidth * 5
+            for side in [-1.0, 1.0] {
+                let headAngle = angle + .pi - side * .pi / 7
+                path.move(to: to)
+                path.addLine(to: CGPoint(
+                    x: to.x + cos(headAngle) * headLength,
+                    y: to.y + sin(headAngle) * headLength
+                ))
+            }
+        }
+        return path
+    }
+
+    static func pngData(_ image: CGImage) -> Data? {
+        NSBitmapImageRep(cgImage: image).representation(using: .png, properties: [:])
+    }
+
+    private static func makeContext(width: Int, height: Int) -> CGContext? {
+        CGContext(
+            data: nil,
+            width: width,
+            height: height,
+            bitsPerComponent: 8,
+            bytesPerRow: 0,
+            space: CGColorSpace(name: CGColorSpace.sRGB) ?? CGColorSpaceCreateDeviceRGB(),
+            bitmapInfo: CGImageAlphaInfo.premultipliedLast.rawValue
+        )
+    }
+}
+
+/// Crop, rotate and annotate one note image. Marks stay editable until the
+/// image is rotated or cropped, which draws them into the pixels.
+struct ImageEditView: View {
+    let onSave: (Data) -> Void
+    let onCancel: () -> Void
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var image: CGImage?
+    @State private var annotations: [ImageAnnotation] = []
+    @State private var tool: ImageEditTool = .arrow
+    @State private var dragStart: CGPoint?
+    @State private var dragCurrent: CGPoint?
+    @State private var cropRect: CGRect?
+    @State private var isEdited = false
+
+    init(data: Data, onSave: @escaping (Data) -> Void, onCancel: @escaping () -> Void) {
+        self.onSave = onSave
+        self.onCancel = onCancel
+        let source = CGImageSourceCreateWithData(data as CFData, nil)
+        _image = State(initialValue: source.flatMap { CGImageSourceCreateImageAtIndex($0, 0, nil) })
+    }
+
+    var body: some View {
+        VStack(spacing: 12) {
+            HStack(spacing: 8) {
+                Picker("Tool", selection: $tool) {
+                    ForEach(ImageEditTool.allCases) { tool in
+                        Label(tool.name, systemImage: tool.systemImage).tag(tool)
+                    }
+                }
+                .pickerStyle(.segmented)
+                .labelsHidden()
+                .frame(width: 300)
+
+                Button {
+                    rotate()
+                } label: {
+                    Label("Rotate", systemImage: "rotate.right")
+                }
+                .help("Rotate 90° clockwise")
+
+                if tool == .crop {
+                    Button("Apply Crop", action: applyCrop)
+                        .disabled(cropRect == nil)
+                }
+
+                Button {
+                    annotations.removeLast()
+                } label: {
+                    Label("Remove Last Mark", systemImage: "arrow.uturn.backward")
+                }
+                .disabled(annotations.isEmpty)
+
+                Spacer()
+            }
+            .controlSize(.small)
+
+            if let image {
+                canvas(for: image)
+            } else {
+                Text("The image could not be read.")
+                    .foregroundStyle(themeManager.colors.errorColor)
+                    .frame(maxWidth: .infinity, maxHeight: .infinity)
+            }
+
+            HStack {
+                Text(tool == .crop ? "Drag to select the part to keep." : "Drag to draw.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(themeManager.colors.itemSubtitleText)
+                Spacer()
+                Button("Cancel", role: .cancel, action: onCancel)
+                    .keyboardShortcut(.cancelAction)
+                Button("Save", action: save)
+                    .keyboardShortcut(.defaultAction)
+                    .disabled(image == nil || (!isEdited && annotations.isEmpty))
+            }
+        }
+        .padding(16)
+        .frame(minWidth: 640, idealWidth: 820, minHeight: 480, idealHeight: 620)
+    }
+
+    private func canvas(for image: CGImage) -> some View {
+        GeometryReader { proxy in
+            let imageSize = CGSize(width: image.width, height: image.height)
+            let scale = min(proxy.size.width / imageSize.width, proxy.size.height / imageSize.height, 1)
+            let fitted = CGSize(width: imageSize.width * scale, height: imageSize.height * scale)
+
+            ZStack(alignment: .topLeading) {
+                Image(decorative: image, scale: 1)
+                    .resizable()
+                    .frame(width: fitted.width, height: fitted.height)
+
+                Canvas { context, _ in
+                    context.scaleBy(x: scale, y: scale)
+                    let lineWidth = ImageEditing.lineWidth(for: image)
+                    let style = StrokeStyle(lineWidth: lineWidth, lineCap: .round, lineJoin: .round)
+                    for annotation in annotations + [pendingAnnotation].compactMap({ $0 }) {
+                        context.stroke(
+                            Path(ImageEditing.path(for: annotation, lineWidth: lineWidth)),
+                            with: .color(Color(cgColor: ImageEditing.annotationColor)),
+                            style: style
+                        )
+                    }
+                    if let selection = pendingCropRect ?? cropRect {
+                        let outside = Path(CGRect(origin: .zero, size: imageSize))
+                            .subtracting(Path(selection))
+                        context.fill(outside, with: .color(.black.opacity(0.45)))
+                        context.stroke(Path(selection), with: .color(.white), lineWidth: 1 / scale)
+                    }
+                }
+                .frame(width: fitted.width, height: fitted.height)
+                .allowsHitTesting(false)
+            }
+            .frame(width: fitted.width, height: fitted.height)
+            .contentShape(Rectangle())
+            .gesture(
+                DragGesture(minimumDistance: 2)
+                    .onChanged { value in
+                        dragStart = clamped(value.startLocation, scale: scale, in: imageSize)
+                        dragCurrent = clamped(value.location, scale: scale, in: imageSize)
+                    }
+                    .onEnded { _ in
+                        finishDrag()
+                    }
+            )
+            .frame(width: proxy.size.width, height: proxy.size.height)
+        }
+        .accessibilityElement()
+        .accessibilityLabel("Image being edited")
+    }
+
+    private var pendingAnnotation: ImageAnnotation? {
+        guard let dragStart, let dragCurrent else {
+            return nil
+        }
+        switch tool {
+        case .arrow:
+            return .arrow(from: dragStart, to: dragCurrent)
+        case .rectangle:
+            return .rectangle(CGRect(origin: dragStart, size: .zero).union(CGRect(origin: dragCurrent, size: .zero)))
+        case .crop:
+            return nil
+        }
+    }
+
+    private var pendingCropRect: CGRect? {
+        guard tool == .crop, let dragStart, let dragCurrent else {
+            return nil
+        }
+        return CGRect(origin: dragStart, size: .zero).union(CGRect(origin: dragCurrent, size: .zero))
+    }
+
+    /// A view location in image pixels, kept inside the image.
+    private func clamped(_ location: CGPoint, scale: CGFloat, in size: CGSize) -> CGPoint {
+        CGPoint(
+            x: min(max(location.x / scale, 0), size.width),
+            y: min(max(location.y / scale, 0), size.height)
+        )
+    }
+
+    private func finishDrag() {
+        defer {
+            dragStart = nil
+            dragCurrent = nil
+        }
+        if let selection = pendingCropRect {
+            cropRect = selection.width >= 2 && selection.height >= 2 ? selection : nil
+        } else if let annotation = pendingAnnotation {
+            annotations.append(annotation)
+        }
+    }
+
+    /// Draws the marks into the pixels so rotating or cropping carries them along.
+    private func flattenedImage() -> CGImage? {
+        guard let image else {
+            return nil
+        }
+        return ImageEditing.drawing(annotations, on: image)
+    }
+
+    private func rotate() {
+        guard let flattened = flattenedImage(), let rotated = ImageEditing.rotatedClockwise(flattened) else {
+            return
+        }
+        image = rotated
+        annotations = []
+        cropRect = nil
+        isEdited = true
+    }
+
+    private func applyCrop() {
+        guard let cropRect,
+              let flattened = flattenedImage(),
+              let cropped = ImageEditing.cropped(flattened, to: cropRect)
+        else {
+            return
+        }
+        image = cropped
+        annotations = []
+        self.cropRect = nil
+        isEdited = true
+    }
+
+    private func save() {
+        guard let flattened = flattenedImage(), let data = ImageEditing.pngData(flattened) else {
+            return
+        }
+        onSave(data)
+    }
+}
@@ -0,0 +1,222 @@
+import Foundation
+import ImageIO
+import UniformTypeIdentifiers
+
+/// A re-encoded image must be at most this share of the original to be kept.
+private let minimumSavingsRatio = 0.9
+private let pngSignature = Data([0x89, 0x50, 0x4E, 0x47])
+
+/// Lossy formats stored images can be re-encoded to.
+enum ImageCodec: String, CaseIterable, Identifiable, Codable, Sendable {
+    case heic
+    case jpeg
+
+    var id: String { rawValue }
+
+    var name: String {
+        switch self {
+        case .heic: return "HEIC"
+        case .jpeg: return "JPEG"
+        }
+    }
+
+    fileprivate var typeIdentifier: CFString {
+        switch self {
+        case .heic: return UTType.heic.identifier as CFString
+        case .jpeg: return UTType.jpeg.identifier as CFString
+        }
+    }
+
+    /// JPEG has no alpha channel, so transparent images are left as PNG.
+    fileprivate var supportsAlpha: Bool {
+        self == .heic
+    }
+}
+
+/// Format new images are stored in. Pasted and imported images are PNG
+/// until then; with a codec they are re-encoded when that makes them smaller.
+struct ImageStorageSettings: Codable, Equatable, Sendable {
+    /// `nil` stores PNG.
+    var codec: ImageCodec?
+    var quality: Double
+
+    static let png = ImageStorageSettings(codec: nil, quality: 0.8)
+    static let qualityRange: ClosedRange<Double> = 0.5...0.95
+}
+
+/// The bytes to store for a new PNG image: re-encoded with the configured codec
+/// when that is clearly smaller and keeps its transparency, otherwise the PNG.
+func imageBytesForStorage(_ png: Data, settings: ImageStorageSettings) -> Data {
+    guard let codec = settings.codec,
+          let encoded = ImageRecompressor.reencode(png, codec: codec, quality: settings.quality),
+          Double(encoded.count) <= Double(png.count) * minimumSavingsRatio
+    else {
+        return png
+    }
+    return encoded
+}
+
+/// Re-encodes stored PNG images in the background, keeping a result only when it
+/// is clearly smaller than the original.
+@MainActor
+final class ImageRecompressor: ObservableObject {
+    static let shared = ImageRecompressor()
+
+    /// Re-encoded images are written back in batches of this size.
+    private static let batchSize = 8
+
+    @Published private(set) var isRunning = false
+    @Published private(set) var processedCount = 0
+    @Published private(set) var totalCount = 0
+    @Published private(set) var replacedCount = 0
+    @Published private(set) var savedBytes: Int64 = 0
+    @Published private(set) var statusMessage: String?
+
+    private var task: Task<Void, Never>?
+
+    private init() {}
+
+    /// Starts re-encoding every stored PNG; images of `skippedItemId` (the note open
+    /// in the editor) are left alone so its next save doesn't restore the originals.
+    func start(codec: ImageCodec, quality: Double, skippedItemId: Int64?) {
+        guard !isRunning else {
+            return
+        }
+
+        isRunning = true
+        processedCount = 0
+        totalCount = 0
+        replacedCount = 0
+        savedBytes = 0
+        statusMessage = nil
+
+        task = Task { [weak self] in
+            await self?.run(codec: codec, quality: quality, skippedItemId: skippedItemId)
+        }
+    }
+
+    func cancel() {
+        task?.cancel()
+    }
+
+    private func run(codec: ImageCodec, quality: Double, skippedItemId: Int64?) async {
+        defer {
+            isRunning = false
+            task = nil
+        }
+
+        let images: [StoredImageRecord]
+        do {
+            images = try await Task.detached(priority: .utility) {
+                try RustBridgeClient.storedImages()
+            }.value
+        } catch {
+            statusMessage = error.localizedDescription
+            return
+        }
+
+        totalCount = images.count
+        var pending: [ImageReplacementRecord] = []
+        var pendingSavings: Int64 = 0
+
+        for image in images {
+            if Task.isCancelled {
+                break
+            }
+
+            if image.itemId != skippedItemId,
+               let bytes = await Task.detached(priority: .utility, operation: {
+                   Self.reencodedBytes(for: image, codec: codec, quality: quality)
+               }).value {
+                pending.append(ImageReplacementRecord(
+                    itemId: image.itemId,
+                    imageKey: image.imageKey,
+                    fingerprint: image.fingerprint,
+                    bytes: bytes
+                ))
+                pendingSavings += Int64(image.bytes) - Int64(bytes.count)
+            }
+            processedCount += 1
+
+            if pending.count >= Self.batchSize {
+                guard await flush(&pending, savings: &pendingSavings) else {
+                    return
+                }
+            }
+        }
+
+        guard await flush(&pending, savings: &pendingSavings) else {
+            return
+        }
+
+        let saved = ByteCountFormatter.string(fromByteCount: savedBytes, countStyle: .file)
+        let outcome = Task.isCancelled ? "Cancelled" : "Done"
+        statusMessage = "\(outcome): re-encoded \(replacedCount) image\(replacedCount == 1 ? "" : "s"), saving \(saved)."
+    }
+
+    /// Writes pending replacements; returns false (with a status message) on failure.
+    private func flush(_ pending: inout [ImageReplacementRecord], savings: inout Int64) async -> Bool {
+        guard !pending.isEmpty else {
+            return true
+        }
+
+        let batch = pending
+        pending.removeAll()
+        do {
+            let replaced = try await Task.detached(priority: .utility) {
+                try RustBridgeClient.replaceImages(batch)
+            }.value
+            replacedCount += Int(replaced)
+            // Images edited since they were listed are skipped; scale the estimate.
+            savedBytes += savings * Int64(replaced) / Int64(batch.count)
+            savings = 0
+            return true
+        } catch {
+            statusMessage = error.localizedDescription
+            return false
+        }
+    }
+
+    nonisolated private static func reencodedBytes(
+        for image: StoredImageRecord,
+        codec: ImageCodec,
+        quality: Double
+    ) -> Data? {
+        guard let original = try? RustBridgeClient.itemImage(itemId: image.itemId, imageKey: image.imageKey),
+              original.starts(with: pngSignature),
+              let encoded = reencode(original, codec: codec, quality: quality),
+              Double(encoded.count) <= Double(original.count) * minimumSavingsRatio
+        else {
+            return nil
+        }
+        return encoded
+    }
+
+    nonisolated fileprivate static func reencode(_ data: Data, codec: ImageCodec, quality: Double) -> Data? {
+        guard let source = CGImageSourceCreateWithData(data as CFData, nil),
+              let image = CGImageSourceCreateImageAtIndex(source, 0, nil)
+        else {
+            return nil
+        }
+
+        switch image.alphaInfo {
+        case .none, .noneSkipFirst, .noneSkipLast:
+            break
+        default:
+            if !codec.supportsAlpha {
+                return nil
+            }
+        }
+
+        let output = NSMutableData()
+        guard let destination = CGImageDestinationCreateWithData(output, codec.typeIdentifier, 1, nil) else {
+            return nil
+        }
+        let options = [kCGImageDestinationLossyCompressionQuality: quality] as CFDictionary
+        CGImageDestinationAddImage(destination, image, options)
+        guard CGImageDestinationFinalize(destination) else {
+            return nil
+        }
+        return output as Data
+    }
+}
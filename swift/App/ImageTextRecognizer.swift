@@ -0,0 +1,100 @@
+import Foundation
+import ImageIO
+import Vision
+
+/// Recognizes text in stored note images in the background and hands it to the
+/// backend, which searches it like note text. Each image is scanned once; images
+/// edited since they were listed are skipped and picked up by the next scan.
+@MainActor
+final class ImageTextRecognizer {
+    static let shared = ImageTextRecognizer()
+
+    /// Images are listed, recognized and saved in batches of this size.
+    private static let batchSize: UInt32 = 8
+
+    private var isRunning = false
+    private var needsAnotherScan = false
+
+    private init() {}
+
+    /// Scans images without recognized text; a request made while a scan runs
+    /// starts another pass once it finishes.
+    func scheduleScan() {
+        guard !isRunning else {
+            needsAnotherScan = true
+            return
+        }
+
+        isRunning = true
+        Task { [weak self] in
+            await self?.run()
+        }
+    }
+
+    private func run() async {
+        repeat {
+            needsAnotherScan = false
+            await scanPendingImages()
+        } while needsAnotherScan
+        isRunning = false
+    }
+
+    private func scanPendingImages() async {
+        let limit = Self.batchSize
+        while true {
+            let texts: [ImageTextRecord]
+            do {
+                texts = try await Task.detached(priority: .utility) {
+                    try RustBridgeClient.imagesWithoutText(limit: limit).map { image in
+                        let data = try? RustBridgeClient.itemImage(itemId: image.itemId, imageKey: image.imageKey)
+                        return ImageTextRecord(
+                            itemId: image.itemId,
+                            imageKey: image.imageKey,
+                            fingerprint: image.fingerprint,
+                            text: data.map(Self.recognizeText(in:)) ?? ""
+                        )
+                    }
+                }.value
+            } catch {
+                NSLog("Image text recognition failed: \(error.localizedDescription)")
+                return
+            }
+
+            guard !texts.isEmpty else {
+                return
+            }
+
+            let saved = try? await Task.detached(priority: .utility) {
+                try RustBridgeClient.saveRecognizedText(texts)
+            }.value
+            // Nothing applied means every image changed meanwhile; stop rather
+            // than rescanning the same images.
+            guard let saved, saved > 0 else {
+                return
+            }
+        }
+    }
+
+    /// Recognized lines of text, or an empty string for images without text or
+    /// that can't be decoded, so they aren't scanned again.
+    nonisolated private static func recognizeText(in data: Data) -> String {
+        guard let source = CGImageSourceCreateWithData(data as CFData, nil),
+              let image = CGImageSourceCreateImageAtIndex(source, 0, nil)
+        else {
+            return ""
+        }
+
+        let request = VNRecognizeTextRequest()
+        request.recognitionLevel = .accurate
+        request.usesLanguageCorrection = true
+        do {
+            try VNImageRequestHandler(cgImage: image, options: [:]).perform([request])
+        } catch {
+            return ""
+        }
+
+        return (request.results ?? [])
+            .compactMap { $0.topCandidates(1).first?.string }
+            .joined(separator: "\n")
+    }
+}
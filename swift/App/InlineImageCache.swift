@@ -0,0 +1,120 @@
+import AppKit
+
+/// Decoded inline images shared by every editor and preview, kept within a memory
+/// budget by evicting the least recently rendered images first.
+///
+/// Each note view used to decode its images on every rebuild; with dozens of
+/// screenshots open across windows that held several full-size bitmaps per image.
+final class InlineImageCache {
+    static let shared = InlineImageCache()
+
+    static let defaultBudgetMegabytes = 256
+    static let budgetMegabyteChoices = [64, 128, 256, 512, 1024]
+
+    private struct Entry {
+        let image: NSImage
+        let cost: Int
+        var lastUse: UInt64
+    }
+
+    private let lock = NSLock()
+    private var entries: [String: Entry] = [:]
+    private var totalCost = 0
+    private var useCounter: UInt64 = 0
+    private var budgetBytes = InlineImageCache.defaultBudgetMegabytes * 1_048_576
+
+    private init() {}
+
+    /// Changes the budget, evicting images right away when it shrinks.
+    func setBudget(megabytes: Int) {
+        lock.lock()
+        defer { lock.unlock() }
+        budgetBytes = max(1, megabytes) * 1_048_576
+        evictIfNeeded()
+    }
+
+    /// The decoded image for `data`, decoding it on a miss.
+    ///
+    /// `key` is the note's image key; the entry is also keyed by a fingerprint of
+    /// the bytes so images sharing a key across notes, or re-encoded in place,
+    /// never alias.
+    func image(forKey key: String, data: Data) -> NSImage? {
+        let cacheKey = "\(key)#\(Self.fingerprint(of: data))"
+
+        lock.lock()
+        useCounter += 1
+        if var entry = entries[cacheKey] {
+            entry.lastUse = useCounter
+            entries[cacheKey] = entry
+            lock.unlock()
+            return entry.image
+        }
+        lock.unlock()
+
+        guard let image = NSImage(data: data) else {
+            return nil
+        }
+
+        let cost = Self.decodedCost(of: image, fallback: data.count)
+        lock.lock()
+        defer { lock.unlock() }
+        // An image larger than the whole budget is returned but never retained.
+        guard cost <= budgetBytes else {
+            return image
+        }
+        if let existing = entries.removeValue(forKey: cacheKey) {
+            totalCost -= existing.cost
+        }
+        entries[cacheKey] = Entry(image: image, cost: cost, lastUse: useCounter)
+        totalCost += cost
+        evictIfNeeded()
+        return image
+    }
+
+    /// Bytes currently held by decoded images.
+    var currentCost: Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return totalCost
+    }
+
+    func removeAll() {
+        lock.lock()
+        defer { lock.unlock() }
+        entries.removeAll()
+        totalCost = 0
+    }
+
+    private func evictIfNeeded() {
+        guard totalCost > budgetBytes else {
+            return
+        }
+
+        let oldestFirst = entries.sorted { $0.value.lastUse < $1.value.lastUse }
+        for (cacheKey, entry) in oldestFirst {
+            guard totalCost > budgetBytes else {
+                break
+            }
+            entries.removeValue(forKey: cacheKey)
+            totalCost -= entry.cost
+        }
+    }
+
+    /// Size of the decoded RGBA bitmap, which is what the budget limits.
+    private static func decodedCost(of image: NSImage, fallback: Int) -> Int {
+        let pixels = image.representations
+            .map { $0.pixelsWide * $0.pixelsHigh }
+            .max() ?? 0
+        return pixels > 0 ? pixels * 4 : fallback
+    }
+
+    /// Hashes the length plus the leading and trailing bytes, which is enough to
+    /// tell encoded images apart without reading megabytes on every render.
+    private static func fingerprint(of data: Data) -> Int {
+        var hasher = Hasher()
+        hasher.combine(data.count)
+        data.prefix(4096).withUnsafeBytes { hasher.combine(bytes: $0) }
+        data.suffix(1024).withUnsafeBytes { hasher.combine(bytes: $0) }
+        return hasher.finalize()
+    }
+}
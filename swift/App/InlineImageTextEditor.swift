@@ -1,11 +1,20 @@
 import AppKit
 import SwiftUI
 
-private let imageRefPattern = #"!\[image\]\(alfred://image/([^\)\?]+)(?:\?w=(\d+))?\)"#
+private let imageRefPattern = #"!\[([^\]\n]*)\]\(alfred://image/([^\)\?]+)(?:\?w=(\d+))?\)"#
 private let imageKeyAttribute = NSAttributedString.Key("InlineImageKey")
 private let imageWidthAttribute = NSAttributedString.Key("InlineImageWidth")
+private let imageAltAttribute = NSAttributedString.Key("InlineImageAlt")
+/// Alt text of image markers that have none of their own.
+private let defaultImageAlt = "image"
 private let dividerMarkerAttribute = NSAttributedString.Key("EditorDividerMarker")
 private let dividerLinePattern = #"(?m)^[ \t]*---[ \t]*$"#
+private let checklistMarkerAttribute = NSAttributedString.Key("EditorChecklistMarker")
+private let checklistLinePattern = #"(?m)^[ \t]*[-*] \[[ xX]\](?=[ \t]|$)"#
+/// Target URL of a detected link, set on the whole link text.
+private let editorLinkAttribute = NSAttributedString.Key("EditorLink")
+/// Name of a `#tag`, set on the tag text including its `#`.
+private let editorHashtagAttribute = NSAttributedString.Key("EditorHashtag")
 private let styleTokenPattern = #"\[\[b\]\]|\[\[/b\]\]|\[\[fs=(\d+(?:\.\d+)?)\]\]|\[\[/fs\]\]"#
 private let boldStyleOpenToken = "[[b]]"
 private let boldStyleCloseToken = "[[/b]]"
@@ -14,6 +23,10 @@ private let fontSizeStyleCloseToken = "[[/fs]]"
 private let editorDefaultFontSize: CGFloat = 15
 private let editorTextColor = NSColor.labelColor
 private let resizeHandleSize: CGFloat = 24
+/// Side of the corner handles drawn on the selected image.
+private let selectedImageHandleSize: CGFloat = 8
+/// Width change after which a drag rewrites the image and its `?w=` width.
+private let liveResizeStep: CGFloat = 4
 private let minImageWidth: CGFloat = 140
 private let maxImageWidth: CGFloat = 1200
 private let inlineStyleMinFontSize: CGFloat = 11
@@ -21,6 +34,9 @@ private let inlineStyleMaxFontSize: CGFloat = 40
 private let minimapWidth: CGFloat = 80
 private let minimapPadding: CGFloat = 4
 private let dividerLineThickness: CGFloat = 1
+private let editorTextInsetHeight: CGFloat = 6
+/// Opacity of the text outside the caret's paragraph in focus mode.
+private let focusModeDimmedAlpha: CGFloat = 0.3
 
 // MARK: - Minimap Colors (adaptive for light/dark mode)
 private var minimapBackgroundColor: NSColor {
@@ -39,12 +55,73 @@ private var minimapViewportBorderColor: NSColor {
     NSColor.separatorColor
 }
 
+/// Note titles offered while a `[[` note link is typed.
+private let noteLinkSuggestionLimit = 12
+
+/// An action of the editor's find bar, applied once per change of `findCommandID`.
+enum EditorFindCommand: Equatable {
+    case next
+    case previous
+    /// Replaces the selected match and moves to the next one.
+    case replace(String)
+    case replaceAll(String)
+}
+
+extension TextTransformKind {
+    /// The commands of the editor's Transform menu, in menu order.
+    static let menuOrder: [TextTransformKind] = [
+        .uppercase, .lowercase, .titleCase,
+        .sortLines, .deduplicateLines, .joinLines,
+        .urlEncode, .urlDecode,
+    ]
+
+    var menuTitle: String {
+        switch self {
+        case .uppercase: return "Uppercase"
+        case .lowercase: return "Lowercase"
+        case .titleCase: return "Title Case"
+        case .sortLines: return "Sort Lines"
+        case .deduplicateLines: return "Remove Duplicate Lines"
+        case .joinLines: return "Join Lines"
+        case .urlEncode: return "URL-Encode"
+        case .urlDecode: return "URL-Decode"
+        }
+    }
+}
+
+/// A command sent to the editor text from outside it, like the command
+/// palette; applied once per change of `textCommandID`.
+enum EditorTextCommand: Equatable {
+    case toggleBold
+    case toggleItalic
+    case insertTableRow
+    case insertTableColumn
+    case increaseFontSize
+    case decreaseFontSize
+    case resetStyle
+    case wrapInAnswerBlock
+    case transform(TextTransformKind)
+    case replayMacro(EditorMacro)
+}
+
+/// Matches of the find bar's query in the note, and which one is selected.
+struct EditorFindStatus: Equatable {
+    var total = 0
+    /// 1-based index of the selected match.
+    var current: Int?
+    /// Matches replaced by the last Replace All.
+    var replacedCount: Int?
+}
+
 private protocol EditorCommandDelegate: AnyObject {
     func increaseDocumentFontSize()
     func decreaseDocumentFontSize()
     func documentFontSize() -> CGFloat
     func currentSearchQuery() -> String
     func areSearchHighlightsEnabled() -> Bool
+    func transformSelection(_ transform: TextTransformKind)
+    func openLink(_ url: URL)
+    func searchTag(_ tag: String)
 }
 
 private func editorFont(for fontSize: CGFloat) -> NSFont {
@@ -77,16 +154,66 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
     ]
 }
 
+/// The lines around `location` up to the nearest blank lines, or just the
+/// blank line `location` is on.
+private func focusedParagraphRange(in text: NSString, at location: Int) -> NSRange {
+    let isBlank = { (range: NSRange) in
+        text.substring(with: range).trimmingCharacters(in: .whitespacesAndNewlines).isEmpty
+    }
+    var paragraph = text.lineRange(for: NSRange(location: min(location, text.length), length: 0))
+    guard !isBlank(paragraph) else {
+        return paragraph
+    }
+    while paragraph.location > 0 {
+        let previous = text.lineRange(for: NSRange(location: paragraph.location - 1, length: 0))
+        guard !isBlank(previous) else {
+            break
+        }
+        paragraph = NSUnionRange(previous, paragraph)
+    }
+    while NSMaxRange(paragraph) < text.length {
+        let next = text.lineRange(for: NSRange(location: NSMaxRange(paragraph), length: 0))
+        guard !isBlank(next) else {
+            break
+        }
+        paragraph = NSUnionRange(paragraph, next)
+    }
+    return paragraph
+}
+
     // MARK: - Resize drag state
 
+    private enum ImageCorner: CaseIterable {
+        case topLeft
+        case topRight
+        case bottomLeft
+        case bottomRight
+
+        /// Which way moving the mouse grows the image from this corner.
+        var horizontalSign: CGFloat {
+            self == .topLeft || self == .bottomLeft ? -1 : 1
+        }
+
+        var verticalSign: CGFloat {
+            self == .topLeft || self == .topRight ? -1 : 1
+        }
+
+        func point(in rect: NSRect) -> NSPoint {
+            NSPoint(x: horizontalSign < 0 ? rect.minX : rect.maxX, y: verticalSign < 0 ? rect.minY : rect.maxY)
+        }
+    }
+
     private struct ImageResizeDragState {
         let attachmentCharIndex: Int
         let imageKey: String
+        let corner: ImageCorner
         let originalWidth: CGFloat
         let originalAspectRatio: CGFloat
         let originalImageData: Data
         let mouseDownPoint: NSPoint
         var currentWidth: CGFloat
+        /// The width last written to the note while dragging.
+        var appliedWidth: CGFloat
         let initialRectInView: NSRect
     }
 
@@ -102,6 +229,16 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
     private final class ResizableImageTextView: NSTextView {
         weak var resizeDelegate: ImageResizeDelegate?
         weak var commandDelegate: EditorCommandDelegate?
+        var enrichesPastedLinks = false
+        /// Set while Vim keys are turned on in Settings.
+        var vim: EditorVimController? {
+            didSet {
+                needsDisplay = true
+            }
+        }
+        private var isInsertingNoteLinkCompletion = false
+        /// What was typed after `[[` when the shown completions are note titles.
+        private var noteLinkCompletionQuery: String?
 
         private var dragState: ImageResizeDragState?
         private var moveDragState: ImageMoveDragState?
@@ -142,20 +279,217 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
             pasteAsPlainText(sender)
         }
 
+        // MARK: - Note Link Completion
+
+        /// The title typed after an unclosed `[[` before the caret.
+        private var noteLinkQueryRange: NSRange? {
+            let selection = selectedRange()
+            guard isEditable, selection.length == 0 else {
+                return nil
+            }
+            let text = string as NSString
+            let lineStart = text.lineRange(for: NSRange(location: selection.location, length: 0)).location
+            let beforeCaret = text.substring(with: NSRange(location: lineStart, length: selection.location - lineStart))
+            guard let query = RustBridgeClient.noteLinkQuery(beforeCaret: beforeCaret) else {
+                return nil
+            }
+            let length = (query as NSString).length
+            return NSRange(location: selection.location - length, length: length)
+        }
+
+        override func didChangeText() {
+            super.didChangeText()
+            guard !isInsertingNoteLinkCompletion, !hasMarkedText(), noteLinkQueryRange != nil else {
+                return
+            }
+            // Suggest note titles while one is typed; outside of this edit so
+            // the completion list doesn't open in the middle of it.
+            DispatchQueue.main.async { [weak self] in
+                guard let self, self.noteLinkQueryRange != nil else {
+                    return
+                }
+                self.complete(nil)
+            }
+        }
+
+        override var rangeForUserCompletion: NSRange {
+            noteLinkQueryRange ?? super.rangeForUserCompletion
+        }
+
+        override func completions(forPartialWordRange charRange: NSRange, indexOfSelectedItem index: UnsafeMutablePointer<Int>) -> [String]? {
+            guard let queryRange = noteLinkQueryRange, queryRange == charRange else {
+                noteLinkCompletionQuery = nil
+                return super.completions(forPartialWordRange: charRange, indexOfSelectedItem: index)
+            }
+            let query = (string as NSString).substring(with: charRange)
+            let titles = ((try? RustBridgeClient.noteLinkSuggestions(query: query, limit: noteLinkSuggestionLimit)) ?? [])
+                .map(\.title)
+                .filter { title in !title.contains(where: { "[]\n".contains($0) }) }
+            noteLinkCompletionQuery = query
+            // Nothing is preselected, so typing on doesn't take a suggestion.
+            index.pointee = -1
+            return titles
+        }
+
+        override func insertCompletion(_ word: String, forPartialWordRange charRange: NSRange, movement: Int, isFinal flag: Bool) {
+            isInsertingNoteLinkCompletion = true
+            defer { isInsertingNoteLinkCompletion = false }
+            super.insertCompletion(word, forPartialWordRange: charRange, movement: movement, isFinal: flag)
+            guard flag, let query = noteLinkCompletionQuery else {
+                return
+            }
+            noteLinkCompletionQuery = nil
+            guard movement != NSTextMovement.cancel.rawValue, word != query else {
+                return
+            }
+            // Close the link after the chosen title unless it already is.
+            let caret = selectedRange().location
+            let text = string as NSString
+            if caret + 2 <= text.length, text.substring(with: NSRange(location: caret, length: 2)) == "]]" {
+                setSelectedRange(NSRange(location: caret + 2, length: 0))
+            } else {
+                insertText("]]", replacementRange: NSRange(location: caret, length: 0))
+            }
+        }
+
         override func keyDown(with event: NSEvent) {
+            if handleMacroShortcut(event) {
+                return
+            }
+            // Command keys were recorded when offered as key equivalents.
+            if !event.modifierFlags.contains(.command) {
+                EditorMacroStore.shared.record(event)
+            }
+            if isEditable, let vim, vim.handle(event, in: self) {
+                return
+            }
             if handleEditorShortcut(event) {
                 return
             }
             super.keyDown(with: event)
         }
 
+        // MARK: - Vim Block Cursor
+
+        private var showsBlockCursor: Bool {
+            vim.map { $0.mode != .insert } ?? false
+        }
+
+        private var blockCursorWidth: CGFloat {
+            editorSpaceAdvance(for: (typingAttributes[.font] as? NSFont) ?? editorFont(for: editorDefaultFontSize))
+        }
+
+        override func drawInsertionPoint(in rect: NSRect, color: NSColor, turnedOn flag: Bool) {
+            guard showsBlockCursor else {
+                super.drawInsertionPoint(in: rect, color: color, turnedOn: flag)
+                return
+            }
+            var block = rect
+            block.size.width = blockCursorWidth
+            if flag {
+                color.withAlphaComponent(0.5).setFill()
+                block.fill(using: .sourceOver)
+            } else {
+                setNeedsDisplay(block, avoidAdditionalLayout: false)
+            }
+        }
+
+        override func setNeedsDisplay(_ invalidRect: NSRect, avoidAdditionalLayout flag: Bool) {
+            var rect = invalidRect
+            if showsBlockCursor {
+                // Also clear the part of the block past the thin caret's rect.
+                rect.size.width += blockCursorWidth
+            }
+            super.setNeedsDisplay(rect, avoidAdditionalLayout: flag)
+        }
+
         override func performKeyEquivalent(with event: NSEvent) -> Bool {
+            guard window?.firstResponder === self else {
+                return super.performKeyEquivalent(with: event)
+            }
+            if handleMacroShortcut(event) {
+                return true
+            }
+            if event.modifierFlags.contains(.command) {
+                EditorMacroStore.shared.record(event)
+            }
             if handleEditorShortcut(event) {
                 return true
             }
             return super.performKeyEquivalent(with: event)
         }
 
+        // MARK: - Macros
+
+        /// ⌃⌥R starts or stops recording; ⌃⌥1–9 replays the macro in that slot.
+        private func handleMacroShortcut(_ event: NSEvent) -> Bool {
+            let macros = EditorMacroStore.shared
+            if EditorMacroStore.isRecordShortcut(event) {
+                guard isEditable else {
+                    return false
+                }
+                macros.toggleRecording()
+                return true
+            }
+            guard let slot = EditorMacroStore.replaySlot(for: event) else {
+                return false
+            }
+            guard isEditable, !macros.isRecording, let macro = macros.macro(forSlot: slot) else {
+                NSSound.beep()
+                return true
+            }
+            replay(macro)
+            return true
+        }
+
+        /// Sends the recorded keys through the same paths typing takes: command
+        /// keys as key equivalents (falling back to the menu), the rest to `keyDown`.
+        private func replay(_ macro: EditorMacro) {
+            let windowNumber = window?.windowNumber ?? 0
+            EditorMacroStore.shared.replaying {
+                for step in macro.steps {
+                    guard let event = step.makeEvent(windowNumber: windowNumber) else {
+                        continue
+                    }
+                    if step.modifiers.contains(.command) {
+                        if performKeyEquivalent(with: event) || NSApp.mainMenu?.performKeyEquivalent(with: event) == true {
+                            continue
+                        }
+                    }
+                    keyDown(with: event)
+                }
+            }
+        }
+
+        /// Runs a command the editor also offers through shortcuts and menus.
+        func perform(_ command: EditorTextCommand) {
+            guard isEditable else {
+                return
+            }
+            switch command {
+            case .toggleBold:
+                _ = toggleBoldForSelection()
+            case .toggleItalic:
+                _ = wrapSelection(inMarkdownMarker: "_", toggle: true)
+            case .insertTableRow:
+                _ = editMarkdownTable(.insertRow)
+            case .insertTableColumn:
+                _ = editMarkdownTable(.insertColumn)
+            case .increaseFontSize:
+                _ = adjustFontSize(delta: 1)
+            case .decreaseFontSize:
+                _ = adjustFontSize(delta: -1)
+            case .resetStyle:
+                _ = resetTextStyleForSelection()
+            case .wrapInAnswerBlock:
+                _ = wrapSelectionInAnswerBlock()
+            case .transform(let transform):
+                commandDelegate?.transformSelection(transform)
+            case .replayMacro(let macro):
+                replay(macro)
+            }
+        }
+
         @objc func toggleBoldface(_ sender: Any?) {
             _ = toggleBoldForSelection()
         }
@@ -189,10 +523,20 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
                 return toggleBoldForSelection()
             }
 
+            if modifiers == [.command],
+               event.charactersIgnoringModifiers?.lowercased() == "i" {
+                return wrapSelection(inMarkdownMarker: "_", toggle: true)
+            }
+
             if modifiers == [.command], isResetStyleShortcut(event) {
                 return resetTextStyleForSelection()
             }
 
+            if modifiers == [.command, .shift],
+               event.charactersIgnoringModifiers?.lowercased() == "a" {
+                return wrapSelectionInAnswerBlock()
+            }
+
             if modifiers == [.command] || modifiers == [.command, .shift] {
                 if isIncreaseFontShortcut(event) {
                     return adjustFontSize(delta: 1)
@@ -259,6 +603,43 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
             }
             
             insertText(text, replacementRange: range)
+            if enrichesPastedLinks, isPastedWebAddress(text) {
+                enrichPastedLink(text, at: range.location)
+            }
+        }
+
+        private func isPastedWebAddress(_ text: String) -> Bool {
+            guard !text.contains(where: \.isWhitespace),
+                  let url = URL(string: text),
+                  url.scheme == "http" || url.scheme == "https"
+            else {
+                return false
+            }
+            return url.host?.isEmpty == false
+        }
+
+        /// Replaces a web address pasted at `location` with a link named after
+        /// its page title once the page is fetched. The address stays as pasted
+        /// when the fetch fails or it was edited in the meantime.
+        private func enrichPastedLink(_ url: String, at location: Int) {
+            Task { [weak self] in
+                let markdown = try? await Task.detached(priority: .utility) {
+                    try RustBridgeClient.linkMarkdown(url: url)
+                }.value
+                guard let self, let markdown, markdown != url else {
+                    return
+                }
+                let range = NSRange(location: location, length: (url as NSString).length)
+                let current = string as NSString
+                guard NSMaxRange(range) <= current.length, current.substring(with: range) == url else {
+                    return
+                }
+                let selection = selectedRange()
+                insertText(markdown, replacementRange: range)
+                let shift = (markdown as NSString).length - range.length
+                let location = selection.location >= NSMaxRange(range) ? selection.location + shift : selection.location
+                setSelectedRange(NSRange(location: location, length: selection.length))
+            }
         }
 
         private func isIncreaseFontShortcut(_ event: NSEvent) -> Bool {
@@ -294,6 +675,149 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
             return chars == "0"
         }
 
+        // MARK: - Markdown typing
+
+        override func insertNewline(_ sender: Any?) {
+            if isEditable, continueMarkdownList() {
+                return
+            }
+            super.insertNewline(sender)
+        }
+
+        override func insertText(_ string: Any, replacementRange: NSRange) {
+            // Typing *, _ or ` over a selection wraps it instead of replacing it.
+            if isEditable,
+               replacementRange.location == NSNotFound,
+               NSApp.currentEvent?.type == .keyDown,
+               selectedRange().length > 0,
+               let typed = string as? String,
+               ["*", "_", "`"].contains(typed),
+               wrapSelection(inMarkdownMarker: typed, toggle: false) {
+                return
+            }
+            super.insertText(string, replacementRange: replacementRange)
+        }
+
+        override func insertTab(_ sender: Any?) {
+            if isEditable, editMarkdownTable(.nextCell) {
+                return
+            }
+            super.insertTab(sender)
+        }
+
+        override func insertBacktab(_ sender: Any?) {
+            if isEditable, editMarkdownTable(.previousCell) {
+                return
+            }
+            super.insertBacktab(sender)
+        }
+
+        /// The note text of `range`, with style and image tokens.
+        private func plainText(in range: NSRange) -> String {
+            makePlainText(
+                from: attributedString().attributedSubstring(from: range),
+                baseFontSize: commandDelegate?.documentFontSize() ?? editorDefaultFontSize,
+                closeOpenStylesAtEnd: false
+            )
+        }
+
+        /// Starts the next item when Return is pressed in a Markdown list item
+        /// or checkbox, or clears the marker of an empty one to end the list.
+        /// False when the caret's line isn't a list item.
+        private func continueMarkdownList() -> Bool {
+            let selection = selectedRange()
+            guard selection.length == 0 else {
+                return false
+            }
+            let text = string as NSString
+            let lineStart = text.lineRange(for: NSRange(location: selection.location, length: 0)).location
+            let beforeCaret = NSRange(location: lineStart, length: selection.location - lineStart)
+            guard let continuation = RustBridgeClient.listContinuation(lineBeforeCaret: plainText(in: beforeCaret)) else {
+                return false
+            }
+            if continuation.endsList {
+                insertText("", replacementRange: beforeCaret)
+            } else {
+                insertText("\n" + continuation.prefix, replacementRange: selection)
+            }
+            return true
+        }
+
+        /// Runs a table command on the Markdown table at the caret and selects
+        /// the cell it lands in. False when the caret isn't in a table, or the
+        /// table has images or styled text, which its plain lines would drop.
+        private func editMarkdownTable(_ command: TableCommandKind) -> Bool {
+            let text = string as NSString
+            let caret = selectedRange().location
+            let tables = RustBridgeClient.tableLayouts(in: string).compactMap { table -> NSRange? in
+                guard let first = table.rows.first?.first, let last = table.rows.last?.first else {
+                    return nil
+                }
+                let start = text.lineRange(for: NSRange(location: Int(first.start), length: 0)).location
+                var end = 0
+                text.getLineStart(nil, end: nil, contentsEnd: &end, for: NSRange(location: Int(last.start), length: 0))
+                return NSRange(location: start, length: end - start)
+            }
+            guard let block = tables.first(where: { $0.location <= caret && caret <= NSMaxRange($0) }),
+                  plainText(in: block) == text.substring(with: block) else {
+                return false
+            }
+            let lines = text.substring(with: block).components(separatedBy: "\n")
+            let caretLineStart = text.lineRange(for: NSRange(location: caret, length: 0)).location
+            let row = text.substring(with: NSRange(location: block.location, length: caretLineStart - block.location))
+                .components(separatedBy: "\n").count - 1
+            guard let edit = RustBridgeClient.tableEdit(
+                lines: lines,
+                row: UInt32(row),
+                caret: UInt32(caret - caretLineStart),
+                command: command
+            ) else {
+                return false
+            }
+            let newText = edit.lines.joined(separator: "\n")
+            if newText != text.substring(with: block) {
+                insertText(newText, replacementRange: block)
+            }
+            let rowStart = edit.lines.prefix(Int(edit.row)).reduce(block.location) { $0 + $1.utf16.count + 1 }
+            setSelectedRange(NSRange(location: rowStart + Int(edit.selection.start), length: Int(edit.selection.length)))
+            scrollRangeToVisible(selectedRange())
+            return true
+        }
+
+        /// Puts a Markdown marker around the selection and keeps the text inside
+        /// it selected; with `toggle`, a selection already wrapped in the marker
+        /// is unwrapped.
+        private func wrapSelection(inMarkdownMarker marker: String, toggle: Bool) -> Bool {
+            let selection = selectedRange()
+            guard let wrap = try? RustBridgeClient.selectionWrap(plainText(in: selection), marker: marker, toggle: toggle) else {
+                return false
+            }
+            if wrap.unwrapLength > 0 {
+                let length = Int(wrap.unwrapLength)
+                insertText("", replacementRange: NSRange(location: NSMaxRange(selection) - length, length: length))
+                insertText("", replacementRange: NSRange(location: selection.location, length: length))
+                setSelectedRange(NSRange(location: selection.location, length: selection.length - 2 * length))
+            } else {
+                insertText(wrap.close, replacementRange: NSRange(location: NSMaxRange(selection), length: 0))
+                insertText(wrap.open, replacementRange: NSRange(location: selection.location, length: 0))
+                setSelectedRange(NSRange(location: selection.location + wrap.open.utf16.count, length: selection.length))
+            }
+            return true
+        }
+
+        /// Puts `::: answer` and `:::` fence lines around the selected lines, so
+        /// the launcher shows them as an answer; keywords go after `answer`.
+        private func wrapSelectionInAnswerBlock() -> Bool {
+            let text = string as NSString
+            let lines = text.paragraphRange(for: selectedRange())
+            let endsWithNewline = lines.length > 0 && text.character(at: lines.upperBound - 1) == 0x0A
+            let closing = endsWithNewline ? ":::\n" : "\n:::"
+            insertText(closing, replacementRange: NSRange(location: lines.upperBound, length: 0))
+            insertText("::: answer \n", replacementRange: NSRange(location: lines.location, length: 0))
+            setSelectedRange(NSRange(location: lines.location + "::: answer ".utf16.count, length: 0))
+            return true
+        }
+
         private func toggleBoldForSelection() -> Bool {
             let range = selectedRange()
             guard let storage = textStorage else {
@@ -582,6 +1106,47 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
             case none
             case resizeHandle
             case imageBody
+            case link
+        }
+
+        // MARK: Hashtag Chips
+
+        override func drawBackground(in rect: NSRect) {
+            super.drawBackground(in: rect)
+            drawHashtagChips(in: rect)
+        }
+
+        /// Draws a rounded, tinted chip behind every visible `#tag`.
+        private func drawHashtagChips(in rect: NSRect) {
+            guard let layoutManager = layoutManager,
+                  let textContainer = textContainer,
+                  let storage = textStorage,
+                  storage.length > 0
+            else {
+                return
+            }
+
+            let origin = textContainerOrigin
+            let visibleGlyphs = layoutManager.glyphRange(
+                forBoundingRect: rect.offsetBy(dx: -origin.x, dy: -origin.y),
+                in: textContainer
+            )
+            let visibleChars = layoutManager.characterRange(forGlyphRange: visibleGlyphs, actualGlyphRange: nil)
+            storage.enumerateAttribute(editorHashtagAttribute, in: visibleChars) { value, range, _ in
+                guard let tag = value as? String else {
+                    return
+                }
+                hashtagChipColor(for: tag).withAlphaComponent(0.2).setFill()
+                let glyphRange = layoutManager.glyphRange(forCharacterRange: range, actualCharacterRange: nil)
+                layoutManager.enumerateEnclosingRects(
+                    forGlyphRange: glyphRange,
+                    withinSelectedGlyphRange: NSRange(location: NSNotFound, length: 0),
+                    in: textContainer
+                ) { lineRect, _ in
+                    let chipRect = lineRect.offsetBy(dx: origin.x, dy: origin.y).insetBy(dx: -2, dy: 0)
+                    NSBezierPath(roundedRect: chipRect, xRadius: 4, yRadius: 4).fill()
+                }
+            }
         }
 
         // MARK: Drawing Override for Resize Overlay
@@ -624,9 +1189,55 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
                 let handlePath = NSBezierPath(rect: handleRect)
                 overlayColor.setFill()
                 handlePath.fill()
+            } else if let charIndex = selectedImageCharIndex, let rect = imageRectInView(charIndex: charIndex) {
+                for corner in ImageCorner.allCases {
+                    let center = corner.point(in: rect)
+                    let handleRect = NSRect(
+                        x: center.x - selectedImageHandleSize / 2,
+                        y: center.y - selectedImageHandleSize / 2,
+                        width: selectedImageHandleSize,
+                        height: selectedImageHandleSize
+                    )
+                    let handlePath = NSBezierPath(roundedRect: handleRect, xRadius: 2, yRadius: 2)
+                    NSColor.controlAccentColor.setFill()
+                    handlePath.fill()
+                    NSColor.white.setStroke()
+                    handlePath.lineWidth = 1
+                    handlePath.stroke()
+                }
             }
         }
 
+        override func setSelectedRanges(_ ranges: [NSValue], affinity: NSSelectionAffinity, stillSelecting: Bool) {
+            let hadSelectedImage = selectedImageCharIndex != nil
+            super.setSelectedRanges(ranges, affinity: affinity, stillSelecting: stillSelecting)
+            if hadSelectedImage || selectedImageCharIndex != nil {
+                needsDisplay = true
+            }
+        }
+
+        /// The image attachment that is the whole selection, which gets corner handles.
+        private var selectedImageCharIndex: Int? {
+            let selection = selectedRange()
+            guard selection.length == 1,
+                  let storage = textStorage,
+                  selection.location < storage.length,
+                  storage.attribute(imageKeyAttribute, at: selection.location, effectiveRange: nil) is String
+            else {
+                return nil
+            }
+            return selection.location
+        }
+
+        private func imageRectInView(charIndex: Int) -> NSRect? {
+            guard let layoutManager, let textContainer else {
+                return nil
+            }
+            let glyphRange = layoutManager.glyphRange(forCharacterRange: NSRange(location: charIndex, length: 1), actualCharacterRange: nil)
+            let attachmentRect = layoutManager.boundingRect(forGlyphRange: glyphRange, in: textContainer)
+            return attachmentRect.offsetBy(dx: textContainerOrigin.x, dy: textContainerOrigin.y)
+        }
+
         // MARK: Hit-testing helpers
 
         /// Checks if the point is on any image attachment and returns its char index and key.
@@ -666,9 +1277,99 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
             return (charIndex, key)
         }
 
+        /// Checks if the point is on a checklist checkbox and returns its char index.
+        /// Target of the link drawn under `point`, if any.
+        private func linkAt(point: NSPoint) -> URL? {
+            textAttribute(editorLinkAttribute, at: point) as? URL
+        }
+
+        /// Name of the `#tag` drawn under `point`, if any.
+        private func hashtagAt(point: NSPoint) -> String? {
+            textAttribute(editorHashtagAttribute, at: point) as? String
+        }
+
+        /// Value of `key` on the character drawn under `point`; points beside
+        /// the text, past the end of a line, hit nothing.
+        private func textAttribute(_ key: NSAttributedString.Key, at point: NSPoint) -> Any? {
+            guard let layoutManager = layoutManager,
+                  let textContainer = textContainer,
+                  let storage = textStorage
+            else {
+                return nil
+            }
+
+            let containerPoint = NSPoint(x: point.x - textContainerOrigin.x, y: point.y - textContainerOrigin.y)
+            var fraction: CGFloat = 0
+            let glyphIndex = layoutManager.glyphIndex(
+                for: containerPoint,
+                in: textContainer,
+                fractionOfDistanceThroughGlyph: &fraction
+            )
+            let glyphRect = layoutManager.boundingRect(
+                forGlyphRange: NSRange(location: glyphIndex, length: 1),
+                in: textContainer
+            )
+            guard glyphRect.contains(containerPoint) else {
+                return nil
+            }
+
+            let charIndex = layoutManager.characterIndexForGlyph(at: glyphIndex)
+            guard charIndex < storage.length else {
+                return nil
+            }
+            return storage.attribute(key, at: charIndex, effectiveRange: nil)
+        }
+
+        private func checklistMarkerAt(point: NSPoint) -> Int? {
+            guard isEditable,
+                  let layoutManager = layoutManager,
+                  let textContainer = textContainer,
+                  let storage = textStorage
+            else {
+                return nil
+            }
+
+            let charIndex = layoutManager.characterIndex(
+                for: point,
+                in: textContainer,
+                fractionOfDistanceBetweenInsertionPoints: nil
+            )
+
+            guard charIndex < storage.length,
+                  storage.attribute(checklistMarkerAttribute, at: charIndex, effectiveRange: nil) is ChecklistMarker
+            else {
+                return nil
+            }
+
+            let glyphRange = layoutManager.glyphRange(forCharacterRange: NSRange(location: charIndex, length: 1), actualCharacterRange: nil)
+            let rect = layoutManager.boundingRect(forGlyphRange: glyphRange, in: textContainer)
+            let rectInView = rect.offsetBy(dx: textContainerOrigin.x, dy: textContainerOrigin.y)
+
+            return rectInView.contains(point) ? charIndex : nil
+        }
+
+        /// Swaps the checkbox at `charIndex` for its toggled state as a regular
+        /// edit, so it is undoable and the note is saved like typed text.
+        private func toggleChecklistMarker(at charIndex: Int) {
+            guard let storage = textStorage,
+                  var marker = storage.attribute(checklistMarkerAttribute, at: charIndex, effectiveRange: nil) as? ChecklistMarker
+            else {
+                return
+            }
+            marker.done.toggle()
+
+            let range = NSRange(location: charIndex, length: 1)
+            guard shouldChangeText(in: range, replacementString: nil) else {
+                return
+            }
+            storage.replaceCharacters(in: range, with: makeChecklistAttachment(marker))
+            didChangeText()
+        }
+
         /// Returns the character index and attachment rect for an image attachment at the given point, if
-        /// the point falls within the resize handle zone (bottom-right corner of the image).
-        private func imageResizeHitTest(at point: NSPoint) -> (charIndex: Int, imageKey: String, width: Int, rect: NSRect)? {
+        /// the point falls within a resize handle zone: the bottom-right corner of any image, or any
+        /// corner of the selected one.
+        private func imageResizeHitTest(at point: NSPoint) -> (charIndex: Int, imageKey: String, width: Int, rect: NSRect, corner: ImageCorner)? {
             guard let layoutManager = layoutManager,
                   let textContainer = textContainer,
                   let storage = textStorage
@@ -703,28 +1404,28 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
                 width = Int(rect.width.rounded())
             }
 
-            let glyphRange = layoutManager.glyphRange(forCharacterRange: NSRange(location: charIndex, length: 1), actualCharacterRange: nil)
-            let attachmentRect = layoutManager.boundingRect(forGlyphRange: glyphRange, in: textContainer)
-            let rectInView = attachmentRect.offsetBy(dx: textContainerOrigin.x, dy: textContainerOrigin.y)
-
-            // Check if point is inside the attachment rect
-            guard rectInView.contains(point) else {
+            guard let rectInView = imageRectInView(charIndex: charIndex),
+                  rectInView.contains(point)
+            else {
                 return nil
             }
 
-            // Check if point is within the bottom-right corner resize handle
-            let handleZone = NSRect(
-                x: rectInView.maxX - resizeHandleSize,
-                y: rectInView.maxY - resizeHandleSize,
-                width: resizeHandleSize,
-                height: resizeHandleSize
-            )
-
-            guard handleZone.contains(point) else {
+            let corners: [ImageCorner] = charIndex == selectedImageCharIndex ? ImageCorner.allCases : [.bottomRight]
+            let corner = corners.first { corner in
+                let cornerPoint = corner.point(in: rectInView)
+                let handleZone = NSRect(
+                    x: corner.horizontalSign < 0 ? cornerPoint.x : cornerPoint.x - resizeHandleSize,
+                    y: corner.verticalSign < 0 ? cornerPoint.y : cornerPoint.y - resizeHandleSize,
+                    width: resizeHandleSize,
+                    height: resizeHandleSize
+                )
+                return handleZone.contains(point)
+            }
+            guard let corner else {
                 return nil
             }
 
-            return (charIndex, key, width, rectInView)
+            return (charIndex, key, width, rectInView, corner)
         }
 
         // MARK: Mouse events
@@ -744,6 +1445,13 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
                     cursorState = .imageBody
                     NSCursor.openHand.push()
                 }
+            } else if event.modifierFlags.contains(.command),
+                      linkAt(point: point) != nil || hashtagAt(point: point) != nil {
+                if cursorState != .link {
+                    if cursorState != .none { NSCursor.pop() }
+                    cursorState = .link
+                    NSCursor.pointingHand.push()
+                }
             } else {
                 if cursorState != .none {
                     NSCursor.pop()
@@ -757,6 +1465,16 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
         override func mouseDown(with event: NSEvent) {
             let point = convert(event.locationInWindow, from: nil)
 
+            // ⌘-click on a link opens it instead of placing the caret
+            if event.modifierFlags.contains(.command), let url = linkAt(point: point) {
+                commandDelegate?.openLink(url)
+                return
+            }
+            if event.modifierFlags.contains(.command), let tag = hashtagAt(point: point) {
+                commandDelegate?.searchTag(tag)
+                return
+            }
+
             // Priority 1: Resize handle click
             if let hit = imageResizeHitTest(at: point),
                let imageData = resizeDelegate?.originalImageData(forKey: hit.imageKey) {
@@ -768,11 +1486,13 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
                 dragState = ImageResizeDragState(
                     attachmentCharIndex: hit.charIndex,
                     imageKey: hit.imageKey,
+                    corner: hit.corner,
                     originalWidth: originalWidth,
                     originalAspectRatio: ratio,
                     originalImageData: imageData,
                     mouseDownPoint: point,
                     currentWidth: originalWidth,
+                    appliedWidth: originalWidth,
                     initialRectInView: hit.rect
                 )
                 
@@ -783,7 +1503,13 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
                 return
             }
 
-            // Priority 2: Image body click → start potential move
+            // Priority 2: Checkbox click → toggle the task
+            if let charIndex = checklistMarkerAt(point: point) {
+                toggleChecklistMarker(at: charIndex)
+                return
+            }
+
+            // Priority 3: Image body click → start potential move
             if let hit = imageAttachmentAt(point: point) {
                 moveDragState = ImageMoveDragState(
                     attachmentCharIndex: hit.charIndex,
@@ -803,12 +1529,20 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
             // Resize drag
             if var state = dragState {
                 let point = convert(event.locationInWindow, from: nil)
-                let deltaX = point.x - state.mouseDownPoint.x
-                let deltaY = point.y - state.mouseDownPoint.y
+                let deltaX = (point.x - state.mouseDownPoint.x) * state.corner.horizontalSign
+                let deltaY = (point.y - state.mouseDownPoint.y) * state.corner.verticalSign
                 let diagonalDelta = (deltaX + deltaY) / 2.0
                 
                 let newWidth = min(max(state.originalWidth + diagonalDelta, minImageWidth), maxImageWidth)
                 state.currentWidth = newWidth
+
+                // Resize the image and its `?w=` width as the mouse moves, in steps
+                // so a slow drag doesn't re-render it on every event.
+                if abs(newWidth - state.appliedWidth) >= liveResizeStep {
+                    updateImageAttachment(state: state, newWidth: newWidth)
+                    state.appliedWidth = newWidth
+                    resizeDelegate?.imageDidResize()
+                }
                 dragState = state
                 
                 // Invalidate display to update overlay
@@ -827,6 +1561,14 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
                         NSCursor.closedHand.push()
                     }
 
+                    // An image on a line of its own moves with the line: show the
+                    // insertion point at the start of the line it would land on.
+                    if imageSitsOnOwnLine(at: moveDragState!.attachmentCharIndex),
+                       let drop = lineDropTarget(at: point) {
+                        setSelectedRange(NSRange(location: drop.location, length: 0))
+                        return
+                    }
+
                     // Show insertion point at current mouse position
                     guard let layoutManager = layoutManager, let textContainer = textContainer else { return }
                     let adjustedPoint = NSPoint(x: point.x - textContainerOrigin.x, y: point.y - textContainerOrigin.y)
@@ -847,8 +1589,10 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
         override func mouseUp(with event: NSEvent) {
             // Resize complete
             if let state = dragState {
-                // Apply final resize
-                updateImageAttachment(state: state, newWidth: state.currentWidth)
+                // Apply what the last drag step left out
+                if state.currentWidth != state.appliedWidth {
+                    updateImageAttachment(state: state, newWidth: state.currentWidth)
+                }
                 
                 dragState = nil
                 NSCursor.pop() // Pop the closedHand cursor pushed in mouseDown
@@ -865,6 +1609,12 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
                     NSCursor.pop()
 
                     let point = convert(event.locationInWindow, from: nil)
+                    if imageSitsOnOwnLine(at: moveState.attachmentCharIndex),
+                       let drop = lineDropTarget(at: point) {
+                        resizeDelegate?.moveImage(index: imageIndex(at: moveState.attachmentCharIndex), toLine: drop.line)
+                        return
+                    }
+
                     guard let layoutManager = layoutManager, let textContainer = textContainer else { return }
                     let adjustedPoint = NSPoint(x: point.x - textContainerOrigin.x, y: point.y - textContainerOrigin.y)
                     let dropIndex = layoutManager.characterIndex(
@@ -912,6 +1662,108 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
         addTrackingArea(area)
     }
 
+    // MARK: Image context menu
+
+    override func menu(for event: NSEvent) -> NSMenu? {
+        let menu = super.menu(for: event) ?? NSMenu()
+        if isEditable, selectedRange().length > 0 {
+            menu.insertItem(transformMenuItem(), at: 0)
+            menu.insertItem(.separator(), at: 1)
+        }
+        let point = convert(event.locationInWindow, from: nil)
+        guard isEditable, let hit = imageAttachmentAt(point: point) else {
+            return menu
+        }
+        let editItem = NSMenuItem(title: "Edit Image…", action: #selector(editImage(_:)), keyEquivalent: "")
+        editItem.target = self
+        editItem.representedObject = hit.charIndex
+        let item = NSMenuItem(title: "Edit Alt Text…", action: #selector(editImageAltText(_:)), keyEquivalent: "")
+        item.target = self
+        item.representedObject = hit.charIndex
+        menu.insertItem(editItem, at: 0)
+        menu.insertItem(item, at: 1)
+        menu.insertItem(.separator(), at: 2)
+        return menu
+    }
+
+    private func transformMenuItem() -> NSMenuItem {
+        let submenu = NSMenu(title: "Transform")
+        for (index, transform) in TextTransformKind.menuOrder.enumerated() {
+            if index == 3 || index == 6 {
+                submenu.addItem(.separator())
+            }
+            let item = NSMenuItem(title: transform.menuTitle, action: #selector(transformSelectedText(_:)), keyEquivalent: "")
+            item.target = self
+            item.representedObject = index
+            submenu.addItem(item)
+        }
+        let item = NSMenuItem(title: "Transform", action: nil, keyEquivalent: "")
+        item.submenu = submenu
+        return item
+    }
+
+    @objc private func transformSelectedText(_ sender: NSMenuItem) {
+        guard let index = sender.representedObject as? Int,
+              TextTransformKind.menuOrder.indices.contains(index)
+        else {
+            return
+        }
+        commandDelegate?.transformSelection(TextTransformKind.menuOrder[index])
+    }
+
+    /// Opens the crop, rotate and annotate sheet for the image.
+    @objc private func editImage(_ sender: NSMenuItem) {
+        guard let charIndex = sender.representedObject as? Int,
+              let storage = textStorage,
+              charIndex < storage.length,
+              let key = storage.attribute(imageKeyAttribute, at: charIndex, effectiveRange: nil) as? String
+        else {
+            return
+        }
+        resizeDelegate?.editImage(forKey: key)
+    }
+
+    /// Asks for a description of the image, read by VoiceOver and searchable.
+    @objc private func editImageAltText(_ sender: NSMenuItem) {
+        guard let charIndex = sender.representedObject as? Int,
+              let storage = textStorage,
+              charIndex < storage.length,
+              storage.attribute(imageKeyAttribute, at: charIndex, effectiveRange: nil) is String
+        else {
+            return
+        }
+
+        let field = NSTextField(frame: NSRect(x: 0, y: 0, width: 280, height: 24))
+        field.stringValue = storage.attribute(imageAltAttribute, at: charIndex, effectiveRange: nil) as? String ?? ""
+        field.placeholderString = "Describe the image"
+
+        let alert = NSAlert()
+        alert.messageText = "Alt Text"
+        alert.informativeText = "Read aloud by VoiceOver in place of the image, and found when searching notes."
+        alert.accessoryView = field
+        alert.addButton(withTitle: "Save")
+        alert.addButton(withTitle: "Cancel")
+        alert.window.initialFirstResponder = field
+        guard alert.runModal() == .alertFirstButtonReturn else {
+            return
+        }
+
+        // Markers can't hold brackets or line breaks in their alt text.
+        let alt = field.stringValue
+            .components(separatedBy: CharacterSet(charactersIn: "[]").union(.newlines))
+            .joined(separator: " ")
+            .trimmingCharacters(in: .whitespaces)
+        let range = NSRange(location: charIndex, length: 1)
+        if alt.isEmpty {
+            storage.removeAttribute(imageAltAttribute, range: range)
+        } else {
+            storage.addAttribute(imageAltAttribute, value: alt, range: range)
+        }
+        (storage.attribute(.attachment, at: charIndex, effectiveRange: nil) as? NSTextAttachment)?
+            .image?.accessibilityDescription = alt.isEmpty ? "Image" : alt
+        resizeDelegate?.imageDidResize()
+    }
+
     // MARK: Prevent default attachment drag-and-drop
 
     override func draggingSession(_ session: NSDraggingSession, sourceOperationMaskFor context: NSDraggingContext) -> NSDragOperation {
@@ -933,7 +1785,7 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
             return
         }
 
-        guard let originalImage = NSImage(data: state.originalImageData) else {
+        guard let originalImage = InlineImageCache.shared.image(forKey: state.imageKey, data: state.originalImageData) else {
             return
         }
 
@@ -986,7 +1838,57 @@ private func editorBaseAttributes(fontSize: CGFloat) -> [NSAttributedString.Key:
         let cursorPos = min(destIndex + 1, storage.length)
         setSelectedRange(NSRange(location: cursorPos, length: 0))
     }
-    
+    
+    /// Whether the image at `charIndex` is the only thing on its line.
+    private func imageSitsOnOwnLine(at charIndex: Int) -> Bool {
+        let string = self.string as NSString
+        guard charIndex < string.length else {
+            return false
+        }
+        let lineRange = string.paragraphRange(for: NSRange(location: charIndex, length: 0))
+        let before = string.substring(with: NSRange(location: lineRange.location, length: charIndex - lineRange.location))
+        let after = string.substring(with: NSRange(location: charIndex + 1, length: NSMaxRange(lineRange) - charIndex - 1))
+        return before.trimmingCharacters(in: .whitespaces).isEmpty
+            && after.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty
+    }
+
+    /// The note line a dragged image line would be inserted before, and where
+    /// that line starts: the line under the point, or the next one when the
+    /// point is in its lower half.
+    private func lineDropTarget(at point: NSPoint) -> (line: Int, location: Int)? {
+        guard let layoutManager = layoutManager, let textContainer = textContainer else {
+            return nil
+        }
+        let string = self.string as NSString
+        let adjustedPoint = NSPoint(x: point.x - textContainerOrigin.x, y: point.y - textContainerOrigin.y)
+        let glyphIndex = layoutManager.glyphIndex(for: adjustedPoint, in: textContainer)
+        let charIndex = min(layoutManager.characterIndexForGlyph(at: glyphIndex), string.length)
+        let lineRange = string.paragraphRange(for: NSRange(location: charIndex, length: 0))
+        let fragment = layoutManager.lineFragmentRect(forGlyphAt: glyphIndex, effectiveRange: nil)
+
+        var location = lineRange.location
+        if adjustedPoint.y > fragment.midY {
+            location = NSMaxRange(lineRange)
+        }
+        let line = string.substring(to: location).filter { $0 == "\n" }.count
+        let endsWithoutNewline = location == string.length && !string.hasSuffix("\n") && location > lineRange.location
+        return (endsWithoutNewline ? line + 1 : line, location)
+    }
+
+    /// How many images come before the one at `charIndex`.
+    private func imageIndex(at charIndex: Int) -> Int {
+        guard let storage = textStorage else {
+            return 0
+        }
+        var count = 0
+        storage.enumerateAttribute(imageKeyAttribute, in: NSRange(location: 0, length: min(charIndex, storage.length))) { value, range, _ in
+            if value is String {
+                count += range.length
+            }
+        }
+        return count
+    }
+
     // MARK: - Search Navigation
     
     private enum SearchDirection {
@@ -1491,6 +2393,8 @@ fileprivate final class EditorMinimapView: NSView {
 private protocol ImageResizeDelegate: AnyObject {
     func originalImageData(forKey key: String) -> Data?
     func imageDidResize()
+    func moveImage(index: Int, toLine line: Int)
+    func editImage(forKey key: String)
 }
 
 struct InlineImageTextEditor: NSViewRepresentable {
@@ -1507,6 +2411,51 @@ struct InlineImageTextEditor: NSViewRepresentable {
     var onIncreaseDocumentFontSize: (() -> Void)?
     var onDecreaseDocumentFontSize: (() -> Void)?
     var onSelectionChange: ((Int?) -> Void)?
+    /// Image to scroll to, applied once per change of `revealRequestID`.
+    var revealImageKey: String?
+    var revealRequestID: UInt64 = 0
+    /// Character offset in `text` of a line to scroll to the top, applied once
+    /// per change of `revealLineRequestID`.
+    var revealLineOffset: Int?
+    var revealLineRequestID: UInt64 = 0
+    /// Vertical scroll offset to return to, applied once per change of
+    /// `scrollRestoreRequestID`.
+    var scrollRestoreOffset: CGFloat?
+    var scrollRestoreRequestID: UInt64 = 0
+    var onScrollOffsetChange: ((CGFloat) -> Void)?
+    /// Shows a spinner at the insertion point while something is being
+    /// prepared for it, like a pasted image.
+    var showsProgressAtCursor: Bool = false
+    /// Phrase from the find bar; while set, its matches are highlighted instead
+    /// of the launcher query's terms.
+    var findQuery: String = ""
+    var findCommand: EditorFindCommand?
+    var findCommandID: UInt64 = 0
+    var onFindStatusChange: ((EditorFindStatus) -> Void)?
+    /// Called with an image's key when "Edit Image…" is chosen on it.
+    var onEditImage: ((String) -> Void)?
+    /// Called before a Transform command rewrites the selection, so the
+    /// change becomes an undo step of its own.
+    var onWillTransformText: (() -> Void)?
+    /// Called with the `alfredalt://note/<title>` link of a clicked `[[title]]`
+    /// note link.
+    var onOpenNoteLink: ((URL) -> Void)?
+    /// Called with the name of a `#tag` ⌘-clicked in the text.
+    var onSearchTag: ((String) -> Void)?
+    var textCommand: EditorTextCommand?
+    var textCommandID: UInt64 = 0
+    /// Fetches the title of a web address pasted alone and turns it into a
+    /// Markdown link.
+    var enrichesPastedLinks: Bool = false
+    /// Turns on Vim-style modal editing; see `EditorVimController`.
+    var vimKeysEnabled: Bool = false
+    /// Called with the Vim mode as it changes, or nil when Vim keys are off.
+    var onVimModeChange: ((EditorVimMode?) -> Void)?
+    /// Called for Vim's u (false) and ⌃R (true).
+    var onVimUndo: ((_ redo: Bool) -> Void)?
+    /// Dims every paragraph but the caret's and keeps the caret line
+    /// vertically centered (typewriter scrolling).
+    var focusModeEnabled: Bool = false
 
     func makeCoordinator() -> Coordinator {
         Coordinator(parent: self)
@@ -1528,8 +2477,10 @@ struct InlineImageTextEditor: NSViewRepresentable {
         textView.delegate = context.coordinator
         textView.resizeDelegate = context.coordinator
         textView.commandDelegate = context.coordinator
+        textView.enrichesPastedLinks = enrichesPastedLinks
         textView.isEditable = isEditable
         textView.isSelectable = true
+        textView.setAccessibilityLabel("Note")
         // Note: isRichText is left as default (true) to allow formatting.
         // Paste normalization (see paste(_:) overrides) handles stripping unwanted HTML.
         textView.isVerticallyResizable = true
@@ -1540,11 +2491,13 @@ struct InlineImageTextEditor: NSViewRepresentable {
         textView.backgroundColor = .clear
         textView.drawsBackground = false
         applyEditorTypingAppearance(to: textView, fontSize: fontSize)
-        textView.textContainerInset = NSSize(width: 0, height: 6)
+        textView.textContainerInset = NSSize(width: 0, height: editorTextInsetHeight)
         textView.textContainer?.lineFragmentPadding = 0
         textView.textContainer?.widthTracksTextView = true
         textView.textContainer?.containerSize = NSSize(width: 0, height: CGFloat.greatestFiniteMagnitude)
-        textView.allowsUndo = true
+        // Undo is handled by the view model's per-note history; the text view's
+        // own stack breaks whenever the text is re-rendered.
+        textView.allowsUndo = false
         textView.isAutomaticTextCompletionEnabled = false
         textView.isAutomaticDashSubstitutionEnabled = false
         textView.isAutomaticQuoteSubstitutionEnabled = false
@@ -1590,6 +2543,12 @@ struct InlineImageTextEditor: NSViewRepresentable {
         )
         
         context.coordinator.renderIfNeeded(force: true)
+        context.coordinator.updateVimKeys()
+        context.coordinator.revealImageIfRequested()
+        context.coordinator.revealLineIfRequested()
+        context.coordinator.restoreScrollIfRequested()
+        context.coordinator.updateCursorProgress()
+        context.coordinator.updateFocusMode()
 
         return container
     }
@@ -1597,7 +2556,16 @@ struct InlineImageTextEditor: NSViewRepresentable {
     func updateNSView(_ nsView: NSView, context: Context) {
         context.coordinator.parent = self
         context.coordinator.textView?.isEditable = isEditable
+        (context.coordinator.textView as? ResizableImageTextView)?.enrichesPastedLinks = enrichesPastedLinks
+        context.coordinator.updateVimKeys()
         context.coordinator.renderIfNeeded(force: false)
+        context.coordinator.revealImageIfRequested()
+        context.coordinator.revealLineIfRequested()
+        context.coordinator.restoreScrollIfRequested()
+        context.coordinator.updateCursorProgress()
+        context.coordinator.updateFind()
+        context.coordinator.performTextCommandIfRequested()
+        context.coordinator.updateFocusMode()
         
         // Update minimap search query
         context.coordinator.minimapView?.searchQuery = searchQuery
@@ -1612,6 +2580,7 @@ struct InlineImageTextEditor: NSViewRepresentable {
             name: NSView.boundsDidChangeNotification,
             object: observedContentView
         )
+        coordinator.cursorProgressIndicator?.removeFromSuperview()
         coordinator.textView = nil
         coordinator.scrollView = nil
         coordinator.minimapView = nil
@@ -1631,15 +2600,101 @@ struct InlineImageTextEditor: NSViewRepresentable {
         private var lastRenderedHighlightState = true
         private var lastRenderedDividerStyleSignature: Int = 0
         private var lastRenderedContainerWidth: CGFloat = 0
+        private var lastRevealRequestID: UInt64 = 0
+        private var lastRevealLineRequestID: UInt64 = 0
+        private var lastScrollRestoreRequestID: UInt64 = 0
+        private var isFocusModeApplied = false
 
         init(parent: InlineImageTextEditor) {
             self.parent = parent
         }
+
+        /// Puts the caret on the requested image and scrolls to it. Until the image
+        /// has been rendered the request stays pending.
+        func revealImageIfRequested() {
+            guard parent.revealRequestID != lastRevealRequestID,
+                  let key = parent.revealImageKey,
+                  let textView,
+                  let storage = textView.textStorage
+            else {
+                return
+            }
+
+            var imageRange: NSRange?
+            storage.enumerateAttribute(imageKeyAttribute, in: NSRange(location: 0, length: storage.length)) { value, range, stop in
+                if value as? String == key {
+                    imageRange = range
+                    stop.pointee = true
+                }
+            }
+            guard let imageRange else {
+                return
+            }
+
+            lastRevealRequestID = parent.revealRequestID
+            textView.setSelectedRange(NSRange(location: imageRange.location, length: 0))
+            // Layout of a freshly loaded note finishes on the next pass.
+            DispatchQueue.main.async {
+                textView.scrollRangeToVisible(imageRange)
+            }
+        }
+        
+        /// Puts the caret at the start of the requested line and scrolls it to
+        /// the top of the editor.
+        func revealLineIfRequested() {
+            guard parent.revealLineRequestID != lastRevealLineRequestID,
+                  let offset = parent.revealLineOffset,
+                  let textView,
+                  let scrollView,
+                  let layoutManager = textView.layoutManager,
+                  let textContainer = textView.textContainer
+            else {
+                return
+            }
+
+            lastRevealLineRequestID = parent.revealLineRequestID
+            let location = attributedLocation(fromPlainOffset: offset, in: textView)
+            textView.setSelectedRange(NSRange(location: location, length: 0))
+            textView.window?.makeFirstResponder(textView)
+            let glyphRange = layoutManager.glyphRange(
+                forCharacterRange: NSRange(location: location, length: 0),
+                actualCharacterRange: nil
+            )
+            let lineRect = layoutManager.boundingRect(forGlyphRange: glyphRange, in: textContainer)
+            let clipView = scrollView.contentView
+            let maxOffset = max(0, textView.frame.height - clipView.bounds.height)
+            let targetOffset = lineRect.minY + textView.textContainerOrigin.y - 8
+            clipView.scroll(to: NSPoint(x: clipView.bounds.origin.x, y: min(max(0, targetOffset), maxOffset)))
+            scrollView.reflectScrolledClipView(clipView)
+        }
+
+        /// Scrolls back to a saved offset, clamped to the note's current height.
+        func restoreScrollIfRequested() {
+            guard parent.scrollRestoreRequestID != lastScrollRestoreRequestID,
+                  let offset = parent.scrollRestoreOffset,
+                  let textView,
+                  let scrollView
+            else {
+                return
+            }
+
+            lastScrollRestoreRequestID = parent.scrollRestoreRequestID
+            // Layout of a freshly loaded note finishes on the next pass.
+            DispatchQueue.main.async {
+                let clipView = scrollView.contentView
+                let maxOffset = max(0, textView.frame.height - clipView.bounds.height)
+                clipView.scroll(to: NSPoint(x: clipView.bounds.origin.x, y: min(max(0, offset), maxOffset)))
+                scrollView.reflectScrolledClipView(clipView)
+            }
+        }
         
         // MARK: Scroll Handling
         
         @objc func textViewDidScroll(_ notification: Notification) {
             minimapView?.needsDisplay = true
+            if let offset = scrollView?.contentView.bounds.origin.y {
+                parent.onScrollOffsetChange?(offset)
+            }
         }
 
         // MARK: ImageResizeDelegate
@@ -1661,7 +2716,7 @@ struct InlineImageTextEditor: NSViewRepresentable {
         }
         
         func currentSearchQuery() -> String {
-            return parent.searchQuery
+            return parent.findQuery.isEmpty ? parent.searchQuery : parent.findQuery
         }
 
         func areSearchHighlightsEnabled() -> Bool {
@@ -1674,11 +2729,7 @@ struct InlineImageTextEditor: NSViewRepresentable {
             isApplyingProgrammaticUpdate = true
             normalizeVisibleTextAttributes(in: textView, fontSize: parent.fontSize)
             applyEditorTypingAppearance(to: textView, fontSize: parent.fontSize)
-            applySearchHighlightsTemporarily(
-                in: textView,
-                query: parent.searchQuery,
-                enabled: parent.highlightSearchMatches
-            )
+            applyHighlights(in: textView)
             isApplyingProgrammaticUpdate = false
 
             let plain = makePlainText(from: textView.attributedString(), baseFontSize: parent.fontSize, closeOpenStylesAtEnd: true)
@@ -1689,6 +2740,283 @@ struct InlineImageTextEditor: NSViewRepresentable {
             minimapView?.invalidateCache()
         }
 
+        // MARK: Find and replace
+
+        private var lastFindQuery = ""
+        private var lastFindCommandID: UInt64 = 0
+        private var lastTextCommandID: UInt64 = 0
+
+        func updateFind() {
+            guard let textView else {
+                return
+            }
+            if parent.findQuery != lastFindQuery {
+                lastFindQuery = parent.findQuery
+                applyHighlights(in: textView)
+                if !parent.findQuery.isEmpty {
+                    // Find as you type: the first match from the cursor on.
+                    let start = textView.selectedRange().location
+                    let matches = findMatches(in: textView)
+                    if let match = matches.first(where: { $0.location >= start }) ?? matches.first {
+                        selectFindMatch(match, in: textView)
+                    }
+                }
+                publishFindStatus()
+            }
+            if parent.findCommandID != lastFindCommandID {
+                lastFindCommandID = parent.findCommandID
+                if let command = parent.findCommand, !parent.findQuery.isEmpty {
+                    performFind(command, in: textView)
+                }
+            }
+        }
+
+        func performTextCommandIfRequested() {
+            guard parent.textCommandID != lastTextCommandID else {
+                return
+            }
+            lastTextCommandID = parent.textCommandID
+            guard let command = parent.textCommand, let textView = textView as? ResizableImageTextView else {
+                return
+            }
+            // The palette that sent the command had the focus; the selection
+            // it applies to is still the text view's.
+            textView.window?.makeFirstResponder(textView)
+            textView.perform(command)
+        }
+
+        private func performFind(_ command: EditorFindCommand, in textView: NSTextView) {
+            var replacedCount: Int?
+            switch command {
+            case .next:
+                selectAdjacentFindMatch(forward: true, in: textView)
+            case .previous:
+                selectAdjacentFindMatch(forward: false, in: textView)
+            case .replace(let replacement):
+                let selection = textView.selectedRange()
+                if textView.isEditable,
+                   findMatches(in: textView).contains(selection),
+                   textView.shouldChangeText(in: selection, replacementString: replacement) {
+                    textView.textStorage?.replaceCharacters(in: selection, with: replacement)
+                    textView.didChangeText()
+                    textView.setSelectedRange(NSRange(location: selection.location + (replacement as NSString).length, length: 0))
+                }
+                selectAdjacentFindMatch(forward: true, in: textView)
+            case .replaceAll(let replacement):
+                let matches = findMatches(in: textView)
+                guard textView.isEditable, !matches.isEmpty, let storage = textView.textStorage else {
+                    replacedCount = 0
+                    break
+                }
+                let fullRange = NSRange(location: 0, length: storage.length)
+                guard textView.shouldChangeText(in: fullRange, replacementString: nil) else {
+                    break
+                }
+                // Replacing keeps the attributes of each match's first character,
+                // so bold and sized text stay styled.
+                storage.beginEditing()
+                for match in matches.reversed() {
+                    storage.replaceCharacters(in: match, with: replacement)
+                }
+                storage.endEditing()
+                textView.didChangeText()
+                replacedCount = matches.count
+            }
+            publishFindStatus(replacedCount: replacedCount)
+        }
+
+        /// Selects the match after (or before) the selection, wrapping around.
+        private func selectAdjacentFindMatch(forward: Bool, in textView: NSTextView) {
+            let matches = findMatches(in: textView)
+            let selection = textView.selectedRange()
+            let match: NSRange?
+            if forward {
+                let start = selection.length > 0 ? NSMaxRange(selection) : selection.location
+                match = matches.first { $0.location >= start } ?? matches.first
+            } else {
+                match = matches.last { NSMaxRange($0) <= selection.location } ?? matches.last
+            }
+            if let match {
+                selectFindMatch(match, in: textView)
+            } else {
+                NSSound.beep()
+            }
+        }
+
+        private func selectFindMatch(_ match: NSRange, in textView: NSTextView) {
+            textView.setSelectedRange(match)
+            textView.scrollRangeToVisible(match)
+            textView.showFindIndicator(for: match)
+        }
+
+        private func findMatches(in textView: NSTextView) -> [NSRange] {
+            editorFindMatches(in: textView.string as NSString, query: parent.findQuery)
+        }
+
+        private func publishFindStatus(replacedCount: Int? = nil) {
+            guard let textView else {
+                return
+            }
+            let matches = findMatches(in: textView)
+            let status = EditorFindStatus(
+                total: matches.count,
+                current: matches.firstIndex(of: textView.selectedRange()).map { $0 + 1 },
+                replacedCount: replacedCount
+            )
+            // Called while SwiftUI updates the view; report on the next turn.
+            DispatchQueue.main.async { [weak self] in
+                self?.parent.onFindStatusChange?(status)
+            }
+        }
+
+        private func applyHighlights(in textView: NSTextView) {
+            if parent.findQuery.isEmpty {
+                applySearchHighlightsTemporarily(
+                    in: textView,
+                    query: parent.searchQuery,
+                    enabled: parent.highlightSearchMatches
+                )
+            } else {
+                applyFindHighlightsTemporarily(in: textView, ranges: findMatches(in: textView))
+            }
+        }
+
+        var cursorProgressIndicator: NSProgressIndicator?
+
+        /// Adds or removes the Vim controller as the setting changes.
+        func updateVimKeys() {
+            guard let textView = textView as? ResizableImageTextView,
+                  parent.vimKeysEnabled != (textView.vim != nil)
+            else {
+                return
+            }
+            if parent.vimKeysEnabled {
+                let vim = EditorVimController()
+                vim.onModeChange = { [weak self, weak textView] mode in
+                    textView?.updateInsertionPointStateAndRestartTimer(true)
+                    textView?.needsDisplay = true
+                    self?.parent.onVimModeChange?(mode)
+                }
+                vim.onUndo = { [weak self] redo in
+                    self?.parent.onVimUndo?(redo)
+                }
+                textView.vim = vim
+            } else {
+                textView.vim = nil
+            }
+            let mode = textView.vim?.mode
+            // Reported after this view update, since it changes SwiftUI state.
+            DispatchQueue.main.async { [weak self] in
+                self?.parent.onVimModeChange?(mode)
+            }
+        }
+
+        func updateCursorProgress() {
+            guard parent.showsProgressAtCursor, let textView else {
+                cursorProgressIndicator?.stopAnimation(nil)
+                cursorProgressIndicator?.removeFromSuperview()
+                cursorProgressIndicator = nil
+                return
+            }
+
+            let indicator = cursorProgressIndicator ?? {
+                let indicator = NSProgressIndicator()
+                indicator.style = .spinning
+                indicator.controlSize = .small
+                indicator.isIndeterminate = true
+                indicator.setAccessibilityLabel("Processing image")
+                textView.addSubview(indicator)
+                indicator.startAnimation(nil)
+                cursorProgressIndicator = indicator
+                return indicator
+            }()
+
+            let side: CGFloat = 16
+            let caret = insertionPointRect(in: textView)
+            indicator.frame = NSRect(x: caret.minX + 2, y: caret.midY - side / 2, width: side, height: side)
+        }
+
+        /// The line fragment rect at the insertion point, in text view coordinates.
+        private func insertionPointRect(in textView: NSTextView) -> NSRect {
+            guard let layoutManager = textView.layoutManager, let textContainer = textView.textContainer else {
+                return .zero
+            }
+            let length = (textView.string as NSString).length
+            let location = textView.selectedRange().location
+            let rect: NSRect
+            if location >= length, !layoutManager.extraLineFragmentRect.isEmpty {
+                rect = layoutManager.extraLineFragmentRect
+            } else if length > 0 {
+                let glyphRange = layoutManager.glyphRange(
+                    forCharacterRange: NSRange(location: min(location, length - 1), length: 1),
+                    actualCharacterRange: nil
+                )
+                let glyphRect = layoutManager.boundingRect(forGlyphRange: glyphRange, in: textContainer)
+                rect = location >= length
+                    ? NSRect(x: glyphRect.maxX, y: glyphRect.minY, width: 0, height: glyphRect.height)
+                    : NSRect(x: glyphRect.minX, y: glyphRect.minY, width: 0, height: glyphRect.height)
+            } else {
+                rect = NSRect(x: 0, y: 0, width: 0, height: textView.font?.boundingRectForFont.height ?? editorDefaultFontSize)
+            }
+            return rect.offsetBy(dx: textView.textContainerOrigin.x, dy: textView.textContainerOrigin.y)
+        }
+
+        func moveImage(index: Int, toLine line: Int) {
+            guard let textView else { return }
+
+            let plain = makePlainText(from: textView.attributedString(), baseFontSize: parent.fontSize, closeOpenStylesAtEnd: true)
+            guard let moved = try? RustBridgeClient.moveImage(note: plain, imageIndex: index, toLine: line),
+                  moved != plain
+            else {
+                return
+            }
+            parent.text = moved
+            renderIfNeeded(force: true)
+        }
+
+        func editImage(forKey key: String) {
+            parent.onEditImage?(key)
+        }
+
+        func searchTag(_ tag: String) {
+            parent.onSearchTag?(tag)
+        }
+
+        /// Note links go to the app; web links open in the default browser.
+        func openLink(_ url: URL) {
+            if RustBridgeClient.noteTitle(forDeepLink: url.absoluteString) != nil {
+                parent.onOpenNoteLink?(url)
+            } else {
+                NSWorkspace.shared.open(url)
+            }
+        }
+
+        /// Rewrites the selected part of the note string and keeps the result
+        /// selected. Style and image tokens in the selection stay intact.
+        func transformSelection(_ transform: TextTransformKind) {
+            guard let textView else { return }
+
+            let selection = textView.selectedRange()
+            let plain = makePlainText(from: textView.attributedString(), baseFontSize: parent.fontSize, closeOpenStylesAtEnd: true)
+            let start = plainOffset(fromAttributedLocation: selection.location, in: textView)
+            let end = plainOffset(fromAttributedLocation: NSMaxRange(selection), in: textView)
+            guard start < end, end <= plain.count else {
+                return
+            }
+            let range = plain.index(plain.startIndex, offsetBy: start)..<plain.index(plain.startIndex, offsetBy: end)
+            let transformed = RustBridgeClient.transformedText(String(plain[range]), with: transform)
+            guard transformed != plain[range] else {
+                return
+            }
+
+            parent.onWillTransformText?()
+            parent.text = plain.replacingCharacters(in: range, with: transformed)
+            renderIfNeeded(force: true)
+            let newStart = attributedLocation(fromPlainOffset: start, in: textView)
+            let newEnd = attributedLocation(fromPlainOffset: start + transformed.count, in: textView)
+            textView.setSelectedRange(NSRange(location: newStart, length: max(0, newEnd - newStart)))
+        }
+
         func renderIfNeeded(force: Bool) {
             guard let textView else {
                 return
@@ -1735,12 +3063,11 @@ struct InlineImageTextEditor: NSViewRepresentable {
             )
             textView.textStorage?.setAttributedString(attributed)
             normalizeVisibleTextAttributes(in: textView, fontSize: parent.fontSize)
+            applyLinkAttributes(in: textView)
+            applyHashtagAttributes(in: textView)
+            applyTableLayout(in: textView)
             applyEditorTypingAppearance(to: textView, fontSize: parent.fontSize)
-            applySearchHighlightsTemporarily(
-                in: textView,
-                query: parent.searchQuery,
-                enabled: parent.highlightSearchMatches
-            )
+            applyHighlights(in: textView)
 
             let newCursorAttributedLocation = attributedLocation(fromPlainOffset: oldPlainCursor, in: textView)
             let safeLocation = max(0, min(newCursorAttributedLocation, textView.string.utf16.count))
@@ -1770,26 +3097,25 @@ struct InlineImageTextEditor: NSViewRepresentable {
 
             isApplyingProgrammaticUpdate = true
             normalizeVisibleTextAttributes(in: textView, fontSize: parent.fontSize)
+            applyLinkAttributes(in: textView)
+            applyHashtagAttributes(in: textView)
+            applyTableLayout(in: textView)
             applyEditorTypingAppearance(to: textView, fontSize: parent.fontSize)
-            applySearchHighlightsTemporarily(
-                in: textView,
-                query: parent.searchQuery,
-                enabled: parent.highlightSearchMatches
-            )
+            applyHighlights(in: textView)
             isApplyingProgrammaticUpdate = false
 
             // Safely extract plain text
             let plain = makePlainText(from: textView.attributedString(), baseFontSize: parent.fontSize, closeOpenStylesAtEnd: true)
-            let hasPendingDividerToken = textViewHasPendingDividerToken(textView)
+            let hasPendingLineToken = textViewHasPendingDividerToken(textView) || textViewHasPendingChecklistToken(textView)
 
             if plain != parent.text {
                 parent.text = plain
-                if !hasPendingDividerToken {
+                if !hasPendingLineToken {
                     lastRenderedText = plain
                 }
             }
 
-            if hasPendingDividerToken {
+            if hasPendingLineToken {
                 renderIfNeeded(force: true)
             }
             
@@ -1797,10 +3123,97 @@ struct InlineImageTextEditor: NSViewRepresentable {
             minimapView?.invalidateCache()
 
             publishSelectionIfNeeded()
+            if !parent.findQuery.isEmpty {
+                publishFindStatus()
+            }
         }
 
         func textViewDidChangeSelection(_ notification: Notification) {
             publishSelectionIfNeeded()
+            guard parent.focusModeEnabled, let textView else {
+                return
+            }
+            applyFocusDimming(in: textView)
+            // After the text view's own scroll to the caret.
+            DispatchQueue.main.async { [weak self] in
+                self?.centerCaretLine()
+            }
+        }
+
+        /// Applies focus mode after it is turned on or off, the text is
+        /// re-rendered or the editor is resized.
+        func updateFocusMode() {
+            guard let textView, let scrollView else {
+                return
+            }
+            // Half a screen of room above the first line and below the last
+            // so that they can be centered too.
+            let insetHeight = parent.focusModeEnabled
+                ? max(editorTextInsetHeight, scrollView.contentView.bounds.height / 2)
+                : editorTextInsetHeight
+            if textView.textContainerInset.height != insetHeight {
+                textView.textContainerInset = NSSize(width: 0, height: insetHeight)
+            }
+            if parent.focusModeEnabled || isFocusModeApplied {
+                applyFocusDimming(in: textView)
+            }
+            if parent.focusModeEnabled != isFocusModeApplied {
+                isFocusModeApplied = parent.focusModeEnabled
+                if isFocusModeApplied {
+                    centerCaretLine()
+                } else {
+                    textView.scrollRangeToVisible(textView.selectedRange())
+                }
+            }
+        }
+
+        /// Dims the text outside the caret's paragraph with temporary
+        /// attributes, which leave the note's own colors untouched.
+        private func applyFocusDimming(in textView: NSTextView) {
+            guard let layoutManager = textView.layoutManager, let storage = textView.textStorage else {
+                return
+            }
+            let text = textView.string as NSString
+            let fullRange = NSRange(location: 0, length: text.length)
+            layoutManager.removeTemporaryAttribute(.foregroundColor, forCharacterRange: fullRange)
+            // Links are colored with the same temporary attribute; see applyLinkAttributes.
+            storage.enumerateAttribute(editorLinkAttribute, in: fullRange) { value, range, _ in
+                if value != nil {
+                    layoutManager.addTemporaryAttribute(.foregroundColor, value: NSColor.linkColor, forCharacterRange: range)
+                }
+            }
+            guard parent.focusModeEnabled, text.length > 0 else {
+                return
+            }
+
+            let focused = focusedParagraphRange(in: text, at: textView.selectedRange().location)
+            let dimmed = editorTextColor.withAlphaComponent(focusModeDimmedAlpha)
+            layoutManager.addTemporaryAttribute(
+                .foregroundColor,
+                value: dimmed,
+                forCharacterRange: NSRange(location: 0, length: focused.location)
+            )
+            layoutManager.addTemporaryAttribute(
+                .foregroundColor,
+                value: dimmed,
+                forCharacterRange: NSRange(location: NSMaxRange(focused), length: text.length - NSMaxRange(focused))
+            )
+        }
+
+        /// Scrolls so that the caret line sits in the middle of the editor.
+        private func centerCaretLine() {
+            guard parent.focusModeEnabled, let textView, let scrollView else {
+                return
+            }
+            let clipView = scrollView.contentView
+            let caret = insertionPointRect(in: textView)
+            let maxY = max(0, textView.frame.height - clipView.bounds.height)
+            let y = min(max(0, caret.midY - clipView.bounds.height / 2), maxY)
+            guard abs(clipView.bounds.origin.y - y) > 0.5 else {
+                return
+            }
+            clipView.scroll(to: NSPoint(x: clipView.bounds.origin.x, y: y))
+            scrollView.reflectScrolledClipView(clipView)
         }
 
         private func publishSelectionIfNeeded() {
@@ -1866,6 +3279,18 @@ struct InlineImageTextEditor: NSViewRepresentable {
             return regex.firstMatch(in: visible, range: range) != nil
         }
 
+        private func textViewHasPendingChecklistToken(_ textView: NSTextView) -> Bool {
+            let visible = textView.string
+            guard visible.contains("]") else {
+                return false
+            }
+            guard let regex = try? NSRegularExpression(pattern: checklistLinePattern) else {
+                return false
+            }
+            let range = NSRange(location: 0, length: (visible as NSString).length)
+            return regex.firstMatch(in: visible, range: range) != nil
+        }
+
         private func dividerStyleSignature(color: Color, topMargin: CGFloat, bottomMargin: CGFloat) -> Int {
             let nsColor = NSColor(color).usingColorSpace(.deviceRGB) ?? NSColor(color)
             var red: CGFloat = 0
@@ -1899,7 +3324,7 @@ private func makeAttributedText(
 ) -> NSAttributedString {
     let output = NSMutableAttributedString()
 
-    let combinedPattern = "\(imageRefPattern)|\(styleTokenPattern)|\(dividerLinePattern)"
+    let combinedPattern = "\(imageRefPattern)|\(styleTokenPattern)|\(dividerLinePattern)|\(checklistLinePattern)"
     guard let regex = try? NSRegularExpression(pattern: combinedPattern) else {
         output.append(NSAttributedString(string: plainText, attributes: editorBaseAttributes(fontSize: fontSize)))
         return output
@@ -1930,22 +3355,30 @@ private func makeAttributedText(
             output.append(NSAttributedString(string: String(prefix), attributes: activeAttributes()))
         }
 
-        if let keyRange = Range(match.range(at: 1), in: plainText) {
+        if let keyRange = Range(match.range(at: 2), in: plainText) {
             let key = String(plainText[keyRange])
             let width = extractedWidth(match: match, from: plainText) ?? Double(defaultImageWidth)
-
-            if let data = imagesByKey[key], let image = NSImage(data: data) {
-                let resized = resizedImage(image, targetWidth: CGFloat(width))
-                let framed = imageWithBorder(resized)
+            let alt = Range(match.range(at: 1), in: plainText)
+                .map { plainText[$0].trimmingCharacters(in: .whitespaces) }
+                .flatMap { $0.isEmpty || $0 == defaultImageAlt ? nil : $0 }
+
+            if let data = imagesByKey[key],
+               let framed = FrameTimingMonitor.measure(.inlineImages, {
+                   InlineImageCache.shared.image(forKey: key, data: data)
+                       .map { imageWithBorder(resizedImage($0, targetWidth: CGFloat(width))) }
+               }) {
+                framed.accessibilityDescription = alt ?? "Image"
                 let attachment = NSTextAttachment()
                 attachment.image = framed
                 attachment.bounds = NSRect(origin: .zero, size: framed.size)
 
                 let attachmentString = NSMutableAttributedString(attachment: attachment)
-                attachmentString.addAttributes([
+                var attributes: [NSAttributedString.Key: Any] = [
                     imageKeyAttribute: key,
                     imageWidthAttribute: Int(width.rounded()),
-                ], range: NSRange(location: 0, length: attachmentString.length))
+                ]
+                attributes[imageAltAttribute] = alt
+                attachmentString.addAttributes(attributes, range: NSRange(location: 0, length: attachmentString.length))
                 output.append(attachmentString)
             } else {
                 output.append(NSAttributedString(string: String(plainText[matchRange]), attributes: activeAttributes()))
@@ -1961,6 +3394,13 @@ private func makeAttributedText(
                         contentWidth: contentWidth
                     )
                 )
+            } else if let checkbox = parseChecklistToken(token) {
+                output.append(NSAttributedString(string: checkbox.indent, attributes: activeAttributes()))
+                output.append(
+                    makeChecklistAttachment(
+                        ChecklistMarker(bullet: checkbox.bullet, done: checkbox.done, fontSize: fontSizeStack.last ?? fontSize)
+                    )
+                )
             } else if token == boldStyleOpenToken {
                 boldDepth += 1
             } else if token == boldStyleCloseToken {
@@ -2023,6 +3463,58 @@ private func makeDividerAttachment(
     return output
 }
 
+/// A checklist line's `- [ ]` / `- [x]` prefix, rendered as one checkbox attachment.
+private struct ChecklistMarker {
+    var bullet: Character
+    var done: Bool
+    var fontSize: CGFloat
+
+    var plainText: String {
+        "\(bullet) [\(done ? "x" : " ")]"
+    }
+}
+
+/// Splits a `checklistLinePattern` match into its indent, bullet and state.
+private func parseChecklistToken(_ token: String) -> (indent: String, bullet: Character, done: Bool)? {
+    let indent = String(token.prefix { $0 == " " || $0 == "\t" })
+    let marker = token.dropFirst(indent.count)
+    guard let bullet = marker.first, bullet == "-" || bullet == "*", marker.hasSuffix("]"), marker.count == 5 else {
+        return nil
+    }
+    let state = marker[marker.index(marker.startIndex, offsetBy: 3)]
+    return (indent, bullet, state == "x" || state == "X")
+}
+
+private func makeChecklistAttachment(_ marker: ChecklistMarker) -> NSAttributedString {
+    let side = (marker.fontSize * 1.1).rounded()
+    let symbolName = marker.done ? "checkmark.square.fill" : "square"
+    let color = marker.done ? NSColor.controlAccentColor : NSColor.secondaryLabelColor
+    let configuration = NSImage.SymbolConfiguration(pointSize: marker.fontSize, weight: .regular)
+        .applying(NSImage.SymbolConfiguration(paletteColors: [color]))
+    let symbol = NSImage(systemSymbolName: symbolName, accessibilityDescription: marker.done ? "Done" : "Not done")?
+        .withSymbolConfiguration(configuration)
+
+    let image = NSImage(size: NSSize(width: side, height: side), flipped: false) { rect in
+        guard let symbol else {
+            return false
+        }
+        let size = symbol.size
+        let origin = NSPoint(x: rect.midX - size.width / 2, y: rect.midY - size.height / 2)
+        symbol.draw(in: NSRect(origin: origin, size: size))
+        return true
+    }
+
+    let attachment = NSTextAttachment()
+    attachment.image = image
+    // Centre the box on the lowercase letters of the line.
+    let font = styledEditorFont(size: marker.fontSize, bold: false)
+    attachment.bounds = NSRect(x: 0, y: (font.xHeight - side) / 2, width: side, height: side)
+
+    let output = NSMutableAttributedString(attachment: attachment)
+    output.addAttribute(checklistMarkerAttribute, value: marker, range: NSRange(location: 0, length: output.length))
+    return output
+}
+
 private func applySearchHighlightsTemporarily(in textView: NSTextView, query: String, enabled: Bool) {
     guard let layoutManager = textView.layoutManager else {
         return
@@ -2062,6 +3554,165 @@ private func applySearchHighlightsTemporarily(in textView: NSTextView, query: St
     }
 }
 
+/// Case-insensitive matches of the whole `query` phrase in `string`.
+private func editorFindMatches(in string: NSString, query: String) -> [NSRange] {
+    guard !query.isEmpty else {
+        return []
+    }
+    var matches: [NSRange] = []
+    var searchRange = NSRange(location: 0, length: string.length)
+    while searchRange.length > 0 {
+        let found = string.range(of: query, options: .caseInsensitive, range: searchRange)
+        guard found.location != NSNotFound, found.length > 0 else {
+            break
+        }
+        matches.append(found)
+        let next = NSMaxRange(found)
+        searchRange = NSRange(location: next, length: string.length - next)
+    }
+    return matches
+}
+
+private func applyFindHighlightsTemporarily(in textView: NSTextView, ranges: [NSRange]) {
+    guard let layoutManager = textView.layoutManager else {
+        return
+    }
+    let fullRange = NSRange(location: 0, length: textView.string.utf16.count)
+    layoutManager.removeTemporaryAttribute(.backgroundColor, forCharacterRange: fullRange)
+    for range in ranges where NSMaxRange(range) <= fullRange.length {
+        layoutManager.addTemporaryAttribute(
+            .backgroundColor,
+            value: NSColor.systemOrange.withAlphaComponent(0.35),
+            forCharacterRange: range
+        )
+    }
+}
+
+private let editorLinkDetector = try? NSDataDetector(types: NSTextCheckingResult.CheckingType.link.rawValue)
+private let noteLinkRegex = try? NSRegularExpression(pattern: #"\[\[([^\[\]\n]+)\]\]"#)
+/// `[text](http…)` links; image markers (`![…](…)`) are left out.
+private let markdownLinkRegex = try? NSRegularExpression(pattern: #"(?<!!)\[([^\[\]\n]+)\]\((https?://[^\s()]+)\)"#)
+
+/// Finds markdown links, bare web addresses and `[[title]]` note links,
+/// underlines them and records their target for ⌘-click. Links are found
+/// again on every change, so an edited address never keeps its old target.
+private func applyLinkAttributes(in textView: NSTextView) {
+    guard let storage = textView.textStorage, let layoutManager = textView.layoutManager else {
+        return
+    }
+    let fullRange = NSRange(location: 0, length: storage.length)
+    let text = storage.string as NSString
+    var links: [(range: NSRange, url: URL)] = []
+
+    func isCovered(_ range: NSRange) -> Bool {
+        links.contains { NSIntersectionRange($0.range, range).length > 0 }
+    }
+
+    for match in markdownLinkRegex?.matches(in: storage.string, range: fullRange) ?? [] {
+        if let url = URL(string: text.substring(with: match.range(at: 2))) {
+            links.append((match.range, url))
+        }
+    }
+    for match in editorLinkDetector?.matches(in: storage.string, range: fullRange) ?? [] {
+        guard let url = match.url, url.scheme == "http" || url.scheme == "https", !isCovered(match.range) else {
+            continue
+        }
+        links.append((match.range, url))
+    }
+    for match in noteLinkRegex?.matches(in: storage.string, range: fullRange) ?? [] {
+        let title = text.substring(with: match.range(at: 1)).trimmingCharacters(in: .whitespaces)
+        guard !title.isEmpty, !isCovered(match.range), let url = RustBridgeClient.noteLink(title: title) else {
+            continue
+        }
+        links.append((match.range, url))
+    }
+
+    storage.beginEditing()
+    storage.removeAttribute(editorLinkAttribute, range: fullRange)
+    for link in links {
+        storage.addAttribute(editorLinkAttribute, value: link.url, range: link.range)
+    }
+    storage.endEditing()
+
+    layoutManager.removeTemporaryAttribute(.underlineStyle, forCharacterRange: fullRange)
+    layoutManager.removeTemporaryAttribute(.foregroundColor, forCharacterRange: fullRange)
+    for link in links {
+        layoutManager.addTemporaryAttributes(
+            [.underlineStyle: NSUnderlineStyle.single.rawValue, .foregroundColor: NSColor.linkColor],
+            forCharacterRange: link.range
+        )
+    }
+}
+
+/// Marks every `#tag` so it is drawn as a chip and can be ⌘-clicked.
+private func applyHashtagAttributes(in textView: NSTextView) {
+    guard let storage = textView.textStorage else {
+        return
+    }
+    let fullRange = NSRange(location: 0, length: storage.length)
+    storage.beginEditing()
+    storage.removeAttribute(editorHashtagAttribute, range: fullRange)
+    for hashtag in RustBridgeClient.hashtags(inNote: storage.string) {
+        let range = NSRange(location: Int(hashtag.range.start), length: Int(hashtag.range.length))
+        guard NSMaxRange(range) <= fullRange.length else {
+            continue
+        }
+        storage.addAttribute(editorHashtagAttribute, value: hashtag.tag, range: range)
+    }
+    storage.endEditing()
+}
+
+/// Lines up the columns of Markdown tables: the last character of each cell
+/// is kerned so every cell is as wide as the widest in its column. The kern
+/// is a storage attribute only; the note text keeps its own spacing.
+private func applyTableLayout(in textView: NSTextView) {
+    guard let storage = textView.textStorage else {
+        return
+    }
+    let fullRange = NSRange(location: 0, length: storage.length)
+    storage.beginEditing()
+    storage.removeAttribute(.kern, range: fullRange)
+    for table in RustBridgeClient.tableLayouts(in: storage.string) {
+        let rows = table.rows.map { cells in
+            cells
+                .map { NSRange(location: Int($0.start), length: Int($0.length)) }
+                .filter { $0.location > 0 && NSMaxRange($0) <= fullRange.length }
+        }
+        var columnWidths: [CGFloat] = []
+        let cellWidths = rows.map { cells in
+            cells.enumerated().map { column, cell in
+                let width = storage.attributedSubstring(from: cell).size().width
+                if column < columnWidths.count {
+                    columnWidths[column] = max(columnWidths[column], width)
+                } else {
+                    columnWidths.append(width)
+                }
+                return width
+            }
+        }
+        for (cells, widths) in zip(rows, cellWidths) {
+            for (column, cell) in cells.enumerated() where widths[column] < columnWidths[column] {
+                // An empty cell pads its opening pipe.
+                let padded = NSRange(location: NSMaxRange(cell) - 1, length: 1)
+                storage.addAttribute(.kern, value: columnWidths[column] - widths[column], range: padded)
+            }
+        }
+    }
+    storage.endEditing()
+}
+
+private let hashtagChipColors: [NSColor] = [
+    .systemBlue, .systemGreen, .systemOrange, .systemPink,
+    .systemPurple, .systemTeal, .systemIndigo, .systemBrown,
+]
+
+/// Chip colour of a tag; the same tag gets the same colour in every note
+/// and every run.
+private func hashtagChipColor(for tag: String) -> NSColor {
+    let seed = tag.unicodeScalars.reduce(0) { ($0 &* 31 &+ Int($1.value)) & 0xFFFF }
+    return hashtagChipColors[seed % hashtagChipColors.count]
+}
+
 private func applyEditorTypingAppearance(to textView: NSTextView, fontSize: CGFloat) {
     textView.insertionPointColor = editorTextColor
     let font = editorFont(for: fontSize)
@@ -2073,6 +3724,9 @@ private func applyEditorTypingAppearance(to textView: NSTextView, fontSize: CGFl
     paragraphStyle.firstLineHeadIndent = 0
     attributes[.paragraphStyle] = paragraphStyle
     attributes.removeValue(forKey: .backgroundColor)
+    attributes.removeValue(forKey: editorLinkAttribute)
+    attributes.removeValue(forKey: editorHashtagAttribute)
+    attributes.removeValue(forKey: .kern)
     textView.typingAttributes = attributes
 }
 
@@ -2182,10 +3836,11 @@ private func makePlainText(
         // Check if this is an image attachment
         if let key = attrs[imageKeyAttribute] as? String {
             let width = attrs[imageWidthAttribute] as? Int
+            let alt = attrs[imageAltAttribute] as? String ?? defaultImageAlt
             if let width {
-                output += "![image](alfred://image/\(key)?w=\(width))"
+                output += "![\(alt)](alfred://image/\(key)?w=\(width))"
             } else {
-                output += "![image](alfred://image/\(key))"
+                output += "![\(alt)](alfred://image/\(key))"
             }
             return
         }
@@ -2193,6 +3848,10 @@ private func makePlainText(
             output += "---"
             return
         }
+        if let checklist = attrs[checklistMarkerAttribute] as? ChecklistMarker {
+            output += String(repeating: checklist.plainText, count: range.length)
+            return
+        }
 
         let runFont = attrs[.font] as? NSFont
         let runBold = runFont.map(fontIsBold) ?? false
@@ -2298,7 +3957,7 @@ private func styledEditorFont(size: CGFloat, bold: Bool) -> NSFont {
 }
 
 private func extractedWidth(match: NSTextCheckingResult, from text: String) -> Double? {
-    let widthRange = match.range(at: 2)
+    let widthRange = match.range(at: 3)
     guard widthRange.location != NSNotFound,
           let swiftRange = Range(widthRange, in: text)
     else {
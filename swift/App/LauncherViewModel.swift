@@ -1,33 +1,203 @@
 import AppKit
 import Foundation
 
-private let maxNoteImageCount = 24
+/// Backend defaults, used until the configured limits have been loaded.
+private let defaultImageLimits = ImageLimitsRecord(maxImagesPerNote: 24, maxImageBytes: 12_000_000)
 private let noteImageURLPrefix = "alfred://image/"
 private let inlineImageDefaultWidth: Double = 360
 private let inlineImageMinWidth: Double = 140
 private let inlineImageMaxWidth: Double = 1200
 private let inlineImageResizeStep: Double = 80
-private let autosaveDebounceNanoseconds: UInt64 = 1_200_000_000
 private let editorDefaultFontSize: CGFloat = 15
 private let editorMinFontSize: CGFloat = 11
 private let editorMaxFontSize: CGFloat = 40
 private let editorFontSizeStep: CGFloat = 1
-private let defaultSearchLimit: UInt32 = 8
 private let listAllSearchLimit: UInt32 = 50
 private let deletedItemsLimit: UInt32 = 50
+/// Storage checks walk the notes folder, so they run at most this often.
+private let storageCheckInterval: TimeInterval = 5 * 60
+/// Queued read-later pages are fetched again at most this often.
+private let readLaterRetryInterval: TimeInterval = 5 * 60
+private let searchDebounceSettingsFileName = "search-settings.json"
+private let launcherPreferencesFileName = "launcher-preferences.json"
+private let queryHistoryFileName = "query-history.json"
+private let sessionFileName = "session.json"
+private let imageStorageSettingsFileName = "image-storage.json"
+/// Snippet source of text recognized in an image, followed by the image key.
+let imageSnippetSourcePrefix = "image:"
+private let queryHistoryLimit = 50
+/// How long the launcher offers to undo a deletion.
+private let undoableDeletionSeconds: UInt64 = 8
+
+/// What an editor window shows: a note, or the deleted item previewed from
+/// Settings. Each note gets a window of its own.
+enum EditorWindowTarget: Hashable, Codable {
+    case item(Int64)
+    case deletedPreview
+}
+
+/// A note open in an editor window that isn't the key one. Its edits are
+/// saved before it is set aside, and it is fetched again once its window
+/// becomes key.
+struct EditorSession {
+    var item: EditableItemRecord
+    var text: String
+    var scrollOffset: CGFloat
+}
+
+/// A deleted item that can still be restored from its archive.
+struct UndoableDeletion: Equatable {
+    let archiveKey: String
+    let title: String
+}
+
+/// What was open in the last run: the launcher query and the editor's note
+/// with its scroll position. Offered for restore at the next start.
+struct PersistedSession: Codable, Equatable {
+    var launcherQuery: String
+    var editorItemId: Int64?
+    var editorItemTitle: String?
+    var editorScrollOffset: Double
+    /// Cleared while the app runs and set on a normal quit, so a session that
+    /// is still `false` at launch ended in a crash.
+    var exitedCleanly: Bool
+
+    static let empty = PersistedSession(
+        launcherQuery: "",
+        editorItemId: nil,
+        editorItemTitle: nil,
+        editorScrollOffset: 0,
+        exitedCleanly: true
+    )
+
+    var hasContentToRestore: Bool {
+        editorItemId != nil || !launcherQuery.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty
+    }
+}
+
+/// How long an error toast stays up, and how many stack at once.
+private let errorToastSeconds: UInt64 = 5
+private let maxVisibleErrorToasts = 3
+/// Errors kept for the log in Settings › General.
+private let maxRecentErrors = 50
+
+/// A failure shown briefly as a toast and kept in the recent error log.
+struct ErrorToast: Identifiable, Equatable {
+    let id: UInt64
+    let message: String
+    let date: Date
+}
+
+/// The item the gallery window shows the images of.
+struct GalleryTarget: Equatable {
+    let itemId: Int64
+    let title: String
+}
+
+/// How long the launcher waits after a keystroke before searching. One- and
+/// two-character queries match much of the store, so they can wait longer.
+struct SearchDebounceSettings: Codable, Equatable {
+    var shortQueryMilliseconds: Int
+    var longQueryMilliseconds: Int
+
+    static let instant = SearchDebounceSettings(shortQueryMilliseconds: 0, longQueryMilliseconds: 0)
+    static let balanced = SearchDebounceSettings(shortQueryMilliseconds: 150, longQueryMilliseconds: 40)
+    static let largeStore = SearchDebounceSettings(shortQueryMilliseconds: 400, longQueryMilliseconds: 150)
+
+    static let maxMilliseconds = 2000
+    /// Queries up to this many characters use the short-query delay.
+    static let shortQueryMaxLength = 2
+
+    func delayNanoseconds(forQuery query: String) -> UInt64 {
+        let trimmedCount = query.trimmingCharacters(in: .whitespaces).count
+        let isShort = trimmedCount > 0 && trimmedCount <= Self.shortQueryMaxLength
+        let milliseconds = isShort ? shortQueryMilliseconds : longQueryMilliseconds
+        return UInt64(min(max(milliseconds, 0), Self.maxMilliseconds)) * 1_000_000
+    }
+}
+
+/// Launcher and editor tunables that used to be constants. Values are clamped
+/// when used, so a hand-edited file can't break search or saving.
+struct LauncherPreferences: Codable, Equatable {
+    var searchResultLimit: Int
+    var autosaveDelayMilliseconds: Int
+    /// Turns a web address pasted alone into a `[Title](url)` link.
+    var fetchesPastedLinkTitles: Bool
+    /// Modal Vim-style editing in the note editor.
+    var usesVimKeys: Bool
+
+    static let defaults = LauncherPreferences(
+        searchResultLimit: 8,
+        autosaveDelayMilliseconds: 1200,
+        fetchesPastedLinkTitles: false,
+        usesVimKeys: false
+    )
+    static let searchResultLimitRange = 1...Int(listAllSearchLimit)
+    static let autosaveDelayRange = 200...10_000
+
+    var clampedSearchResultLimit: UInt32 {
+        UInt32(min(max(searchResultLimit, Self.searchResultLimitRange.lowerBound), Self.searchResultLimitRange.upperBound))
+    }
+
+    var autosaveDelayNanoseconds: UInt64 {
+        UInt64(min(max(autosaveDelayMilliseconds, Self.autosaveDelayRange.lowerBound), Self.autosaveDelayRange.upperBound)) * 1_000_000
+    }
+}
+
+extension LauncherPreferences {
+    /// Settings added later default when missing, so older files still load.
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        searchResultLimit = try container.decode(Int.self, forKey: .searchResultLimit)
+        autosaveDelayMilliseconds = try container.decode(Int.self, forKey: .autosaveDelayMilliseconds)
+        fetchesPastedLinkTitles = try container.decodeIfPresent(Bool.self, forKey: .fetchesPastedLinkTitles)
+            ?? Self.defaults.fetchesPastedLinkTitles
+        usesVimKeys = try container.decodeIfPresent(Bool.self, forKey: .usesVimKeys)
+            ?? Self.defaults.usesVimKeys
+    }
+}
+
+private func loadLauncherPreferences() -> LauncherPreferences {
+    SettingsStore.shared.loadJSON(LauncherPreferences.self, fileName: launcherPreferencesFileName) ?? .defaults
+}
+
+private struct PersistedQueryHistory: Codable {
+    let queries: [String]
+}
+
+private func loadQueryHistory() -> [String] {
+    let persisted = SettingsStore.shared.loadJSON(PersistedQueryHistory.self, fileName: queryHistoryFileName)
+    return Array((persisted?.queries ?? []).prefix(queryHistoryLimit))
+}
+
+private func loadImageStorageSettings() -> ImageStorageSettings {
+    SettingsStore.shared.loadJSON(ImageStorageSettings.self, fileName: imageStorageSettingsFileName) ?? .png
+}
+
+private func loadSearchDebounceSettings() -> SearchDebounceSettings {
+    SettingsStore.shared.loadJSON(SearchDebounceSettings.self, fileName: searchDebounceSettingsFileName) ?? .balanced
+}
+
+/// Actions applied to every marked launcher result at once.
+enum BulkItemAction {
+    case delete
+    case addTag(String)
+    case export(URL)
+}
 
 @MainActor
 final class LauncherViewModel: ObservableObject {
     @Published var query: String = "" {
         didSet {
+            if !isRecallingQueryHistory {
+                queryHistoryIndex = nil
+            }
             guard let searchQuery = effectiveSearchQuery(from: query) else {
                 queuedSearchQuery = nil
-                if !results.isEmpty {
-                    results = []
-                }
                 if errorMessage != nil {
                     errorMessage = nil
                 }
+                loadRecentItems()
                 return
             }
             triggerSearch(for: searchQuery)
@@ -39,53 +209,395 @@ final class LauncherViewModel: ObservableObject {
     @Published var editorText: String = "" {
         didSet {
             editorStateRevision &+= 1
+            recordEditorUndoStep(previousText: oldValue)
         }
     }
     @Published private(set) var editorFontSize: CGFloat = editorDefaultFontSize
-    @Published var errorMessage: String?
+    /// The latest failure. Setting a new message posts it as a toast, so
+    /// errors from background work stack instead of replacing each other;
+    /// clearing it only records that the failing operation recovered.
+    var errorMessage: String? {
+        didSet {
+            guard let errorMessage, errorMessage != oldValue else {
+                return
+            }
+            postErrorToast(errorMessage)
+        }
+    }
+    @Published private(set) var errorToasts: [ErrorToast] = []
+    /// Newest first.
+    @Published private(set) var recentErrors: [ErrorToast] = []
+    private var nextErrorToastID: UInt64 = 0
     @Published private(set) var isEditorPresented: Bool = false
     @Published private(set) var isSettingsPresented: Bool = false
+    @Published private(set) var isViewWindowPresented: Bool = false
+    @Published private(set) var viewWindowRefreshID: UInt64 = 0
+    /// Item whose images the gallery window shows.
+    @Published private(set) var galleryTarget: GalleryTarget?
     @Published private(set) var launcherFocusRequestID: UInt64 = 0
     @Published private(set) var editorTitleFocusRequestID: UInt64 = 0
+    /// Image the editor scrolls to after opening a result found through its text.
+    @Published private(set) var editorRevealImageKey: String?
+    @Published private(set) var editorRevealRequestID: UInt64 = 0
     @Published var settingsStorageDirectoryPath: String = ""
+    /// Comma-separated tags whose items are kept out of the JSON storage folder.
+    @Published var settingsMirrorExcludedTags: String = ""
+    /// Whether the storage folder is written as an Obsidian vault instead of JSON files.
+    @Published var settingsObsidianVaultMode: Bool = false
     @Published var settingsErrorMessage: String?
+    @Published private(set) var isImportingNotionExport: Bool = false
+    /// Whether a `read <url>` page is being fetched.
+    @Published private(set) var isSavingReadLater: Bool = false
+    /// Whether a pasted image is being converted before its marker is inserted.
+    @Published private(set) var isProcessingPastedImage: Bool = false
     @Published var settingsSuccessMessage: String?
     @Published private(set) var deletedItems: [DeletedItemRecord] = []
     @Published private(set) var isLoadingDeletedItems: Bool = false
     @Published private(set) var deletedPreviewItem: DeletedItemPreviewRecord?
+    /// Image count and size limits the backend enforces when a note is saved.
+    @Published private(set) var imageLimits: ImageLimitsRecord = defaultImageLimits
+    /// Most severe storage budget alert, shown in the launcher until resolved.
+    @Published private(set) var storageAlert: StorageAlertRecord?
+    /// Delay between typing and searching, persisted with the other settings files.
+    @Published var searchDebounce: SearchDebounceSettings = loadSearchDebounceSettings() {
+        didSet {
+            guard searchDebounce != oldValue else {
+                return
+            }
+            _ = SettingsStore.shared.saveJSON(searchDebounce, fileName: searchDebounceSettingsFileName)
+        }
+    }
+    /// Result limit and autosave delay; changes apply to the next search or edit.
+    @Published var preferences: LauncherPreferences = loadLauncherPreferences() {
+        didSet {
+            guard preferences != oldValue else {
+                return
+            }
+            _ = SettingsStore.shared.saveJSON(preferences, fileName: launcherPreferencesFileName)
+            if preferences.searchResultLimit != oldValue.searchResultLimit {
+                refreshSearchForCurrentQuery()
+            }
+        }
+    }
+    /// Whether this run has the note store turned off, leaving a command
+    /// launcher; note features are hidden and skip their backend calls.
+    let isLauncherOnly = RustBridgeClient.noteStoreDisabled()
+    /// The launcher-only setting for the next launch, shown in Settings.
+    @Published private(set) var settingsLauncherOnly = RustBridgeClient.noteStoreDisabled()
+    /// Codec new images are stored with; stored images keep their format.
+    @Published var imageStorage: ImageStorageSettings = loadImageStorageSettings() {
+        didSet {
+            guard imageStorage != oldValue else {
+                return
+            }
+            _ = SettingsStore.shared.saveJSON(imageStorage, fileName: imageStorageSettingsFileName)
+        }
+    }
+    /// Whether queries match items with any of their terms instead of all; the
+    /// backend stores it. Terms separated by `|` always match either way.
+    @Published private(set) var searchMatchesAnyTerm = false
+    /// The item just deleted from the launcher, offered for undo for a few seconds.
+    @Published private(set) var undoableDeletion: UndoableDeletion?
+    /// The previous run's session, offered in the launcher until restored,
+    /// dismissed, or replaced by opening another note.
+    @Published private(set) var restorableSession: PersistedSession?
+    /// Scroll position the editor moves to once per change of the request ID.
+    @Published private(set) var editorScrollRestoreOffset: CGFloat?
+    @Published private(set) var editorScrollRestoreRequestID: UInt64 = 0
+    /// Notes open in editor windows other than the one being edited, by id.
+    @Published private(set) var backgroundEditors: [Int64: EditorSession] = [:]
 
     private var queuedSearchQuery: String?
+    private var lastStorageCheck: Date?
+    private var lastReadLaterRetry: Date?
+    private var undoableDeletionTask: Task<Void, Never>?
+    /// Recent launcher queries, newest first.
+    private var queryHistory: [String] = loadQueryHistory()
+    /// Position in `queryHistory` of the recalled query, while it is unedited.
+    private var queryHistoryIndex: Int?
+    private var isRecallingQueryHistory = false
     private var isSearchWorkerRunning = false
     private var autosaveTask: Task<Void, Never>?
+    private var imagePasteTask: Task<Data?, Never>?
+    /// Per-note undo histories for the session, kept across editor reopenings.
+    private var editorUndoHistories: [Int64: EditorUndoHistory] = [:]
+    /// Set while the editor text is loaded or restored rather than edited.
+    private var suppressesEditorUndoRecording = false
+    /// Makes the next text change its own undo step instead of extending typing.
+    private var editorUndoStartsNewStep = false
     private var editorStateRevision: UInt64 = 0
+    private var editorScrollOffset: CGFloat = 0
+    private var inFlightSaveCounts: [Int64: Int] = [:]
     private var consumedEditorTitleFocusRequestID: UInt64 = 0
     private weak var launcherWindow: NSWindow?
     private weak var editorWindow: NSWindow?
     private weak var settingsWindow: NSWindow?
+    private let viewWindows = NSHashTable<NSWindow>.weakObjects()
 
     var shouldShowResultsForCurrentQuery: Bool {
-        effectiveSearchQuery(from: query) != nil
+        // An empty query lists recent items, when there are any.
+        effectiveSearchQuery(from: query) != nil || !results.isEmpty
     }
 
     func initialLoad() async {
-        if let searchQuery = effectiveSearchQuery(from: query) {
-            triggerSearch(for: searchQuery)
+        guard !isLauncherOnly else {
+            refreshSearchForCurrentQuery()
+            return
+        }
+        loadSessionForRestore()
+        refreshSearchForCurrentQuery()
+        refreshStorageAlert()
+        refreshImageLimits()
+        importVaultChanges()
+        retryReadLaterQueueIfDue()
+    }
+
+    /// Picks up notes edited or added in the Obsidian vault; a no-op unless the
+    /// storage folder is a vault.
+    func importVaultChanges() {
+        Task { [weak self] in
+            let changed = try? await Task.detached(priority: .utility) {
+                try RustBridgeClient.importVaultChanges()
+            }.value
+            guard let self, let changed, changed > 0 else {
+                return
+            }
+            self.refreshSearchForCurrentQuery()
         }
     }
 
+    func refreshImageLimits() {
+        if let limits = try? RustBridgeClient.imageLimits() {
+            imageLimits = limits
+        }
+    }
+
+    /// Saves new image limits; `nil` restores a default. Returns whether it succeeded.
+    @discardableResult
+    func saveImageLimits(maxImagesPerNote: Int?, maxImageMegabytes: Double?) -> Bool {
+        do {
+            imageLimits = try RustBridgeClient.updateImageLimits(
+                maxImagesPerNote: maxImagesPerNote.map { UInt32(clamping: $0) },
+                maxImageBytes: maxImageMegabytes.map { UInt64(max(0, $0) * 1_000_000) }
+            )
+            settingsErrorMessage = nil
+            return true
+        } catch {
+            settingsErrorMessage = error.localizedDescription
+            return false
+        }
+    }
+
+    func refreshStorageAlert() {
+        lastStorageCheck = Date()
+        Task { [weak self] in
+            let report = try? await Task.detached(priority: .utility) {
+                try RustBridgeClient.storageReport()
+            }.value
+            guard let self, let report else {
+                return
+            }
+            let alert = report.alerts.first
+            if self.storageAlert != alert {
+                self.storageAlert = alert
+            }
+        }
+    }
+
+    private func refreshStorageAlertIfStale() {
+        if let lastStorageCheck, Date().timeIntervalSince(lastStorageCheck) < storageCheckInterval {
+            return
+        }
+        refreshStorageAlert()
+    }
+
     func activate(selectedIndex: Int) async -> Bool {
+        recordQueryInHistory()
         if results.indices.contains(selectedIndex) {
-            return await open(itemId: results[selectedIndex].id)
+            let result = results[selectedIndex]
+            if result.kind == .systemCommand, let commandKey = result.commandKey {
+                await runSystemCommand(key: commandKey)
+                return false
+            }
+            if result.kind == .queryPrefix, let prefix = result.commandKey {
+                query = prefix
+                return false
+            }
+            if result.kind == .answer, let answer = result.commandKey {
+                copyItemTitle(answer)
+                dismissLauncher()
+                return false
+            }
+            if result.kind == .readLater, let url = result.commandKey {
+                return await saveReadLaterArticle(url: url)
+            }
+            if result.kind == .provider, let commandKey = result.commandKey {
+                return await performProviderAction(commandKey: commandKey)
+            }
+            if result.kind == .timer {
+                await toggleTimer(itemId: result.commandKey == "stop" ? nil : result.id)
+                return false
+            }
+            return await open(itemId: result.id, revealingImageKey: Self.imageKey(fromSnippetSource: result.snippetSource))
         }
         return await createItemFromQuery()
     }
 
+    func runSystemCommand(key: String) async {
+        dismissLauncher()
+        do {
+            try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.runCommand(key: key)
+            }.value
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    /// Runs a picked result of a registered provider, then opens the URL,
+    /// copies the text or opens the captured note it hands back.
+    func performProviderAction(commandKey: String) async -> Bool {
+        do {
+            let outcome = try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.performProviderAction(commandKey: commandKey)
+            }.value
+            switch outcome.kind {
+            case .openUrl:
+                if let value = outcome.value, let url = URL(string: value) {
+                    NSWorkspace.shared.open(url)
+                }
+            case .copyText:
+                if let value = outcome.value {
+                    copyItemTitle(value)
+                }
+            case .openItem:
+                if let itemId = outcome.itemId {
+                    errorMessage = nil
+                    query = ""
+                    refreshSearchForCurrentQuery()
+                    return await open(itemId: itemId)
+                }
+            case .done:
+                break
+            }
+            errorMessage = nil
+            dismissLauncher()
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+        return false
+    }
+
+    /// Starts a timer on `itemId`, or stops the running timer when nil.
+    func toggleTimer(itemId: Int64?) async {
+        do {
+            try await Task.detached(priority: .userInitiated) {
+                if let itemId {
+                    try RustBridgeClient.startTimer(itemId: itemId)
+                } else {
+                    try RustBridgeClient.stopTimer()
+                }
+            }.value
+            errorMessage = nil
+            query = ""
+            dismissLauncher()
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    /// Saves the article at `url` as a note and opens it; an unreachable page
+    /// is queued and fetched again later.
+    func saveReadLaterArticle(url: String) async -> Bool {
+        guard !isSavingReadLater else {
+            return false
+        }
+        isSavingReadLater = true
+        defer { isSavingReadLater = false }
+
+        do {
+            let report = try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.saveReadLater(url: url)
+            }.value
+            guard let itemId = report.itemId else {
+                errorMessage = "Queued \(url) to save when it can be reached: \(report.message ?? "unreachable")"
+                query = ""
+                return false
+            }
+            query = ""
+            refreshSearchForCurrentQuery()
+            return await open(itemId: itemId)
+        } catch {
+            errorMessage = error.localizedDescription
+            return false
+        }
+    }
+
+    /// Fetches the queued read-later pages again, at most every few minutes.
+    func retryReadLaterQueueIfDue() {
+        if let lastReadLaterRetry, Date().timeIntervalSince(lastReadLaterRetry) < readLaterRetryInterval {
+            return
+        }
+        lastReadLaterRetry = Date()
+        Task { [weak self] in
+            let saved = try? await Task.detached(priority: .utility) {
+                try RustBridgeClient.retryReadLater()
+            }.value
+            guard let self, let saved, saved > 0 else {
+                return
+            }
+            self.refreshSearchForCurrentQuery()
+        }
+    }
+
     func registerLauncherWindow(_ window: NSWindow) {
         launcherWindow = window
     }
 
-    func registerEditorWindow(_ window: NSWindow) {
+    /// The editor window of the note being edited, or of the deleted item previewed.
+    var activeEditorTarget: EditorWindowTarget? {
+        if deletedPreviewItem != nil {
+            return .deletedPreview
+        }
+        return selectedItem.map { .item($0.id) }
+    }
+
+    func registerEditorWindow(_ window: NSWindow, for target: EditorWindowTarget) {
+        if target == activeEditorTarget {
+            editorWindow = window
+        }
+    }
+
+    /// Edits the note of an editor window once it becomes key, setting aside
+    /// the note edited until then.
+    func activateEditor(_ target: EditorWindowTarget, in window: NSWindow) async {
+        if target == activeEditorTarget {
+            editorWindow = window
+            return
+        }
+        guard case .item(let itemId) = target, await open(itemId: itemId) else {
+            return
+        }
         editorWindow = window
+        if !isEditorPresented {
+            isEditorPresented = true
+            persistSession()
+        }
+    }
+
+    /// Sets the note being edited aside so its window keeps showing it while
+    /// another note is edited; false when its pending edits couldn't be saved.
+    private func stashActiveEditor() async -> Bool {
+        guard isEditorPresented, deletedPreviewItem == nil, selectedItem != nil else {
+            return true
+        }
+        guard await flushAutosave(), let item = selectedItem else {
+            return false
+        }
+        backgroundEditors[item.id] = EditorSession(item: item, text: editorText, scrollOffset: editorScrollOffset)
+        editorWindow = nil
+        return true
     }
 
     func registerSettingsWindow(_ window: NSWindow) {
@@ -106,20 +618,68 @@ final class LauncherViewModel: ObservableObject {
 
     func beginEditorPresentation() {
         isEditorPresented = true
+        persistSession()
         launcherWindow?.orderOut(nil)
 
         NSApp.activate(ignoringOtherApps: true)
         editorWindow?.makeKeyAndOrderFront(nil)
     }
 
+    func postErrorToast(_ message: String) {
+        nextErrorToastID &+= 1
+        let toast = ErrorToast(id: nextErrorToastID, message: message, date: Date())
+        errorToasts.append(toast)
+        if errorToasts.count > maxVisibleErrorToasts {
+            errorToasts.removeFirst(errorToasts.count - maxVisibleErrorToasts)
+        }
+        recentErrors.insert(toast, at: 0)
+        if recentErrors.count > maxRecentErrors {
+            recentErrors.removeLast(recentErrors.count - maxRecentErrors)
+        }
+        // Toasts vanish before VoiceOver would reach them.
+        AccessibilityAnnouncer.announce("Error: \(message)", priority: .high)
+
+        Task { [weak self] in
+            try? await Task.sleep(nanoseconds: errorToastSeconds * 1_000_000_000)
+            self?.dismissErrorToast(id: toast.id)
+        }
+    }
+
+    func dismissErrorToast(id: UInt64) {
+        errorToasts.removeAll { $0.id == id }
+    }
+
+    func clearRecentErrors() {
+        recentErrors = []
+    }
+
+    func prepareGallery(itemId: Int64, title: String) {
+        galleryTarget = GalleryTarget(itemId: itemId, title: title)
+    }
+
     func prepareSettings() {
         settingsErrorMessage = nil
         settingsSuccessMessage = nil
-        loadSettingsStorageDirectoryPath()
+        settingsLauncherOnly = (try? RustBridgeClient.launcherOnlyAtNextLaunch()) ?? isLauncherOnly
         reloadSettingsFromDisk()
+        guard !isLauncherOnly else {
+            return
+        }
+        loadSettingsStorageDirectoryPath()
         refreshDeletedItems()
     }
 
+    /// Saves the launcher-only setting; it applies from the next launch.
+    func setLauncherOnly(_ enabled: Bool) {
+        do {
+            try RustBridgeClient.updateLauncherOnly(enabled)
+            settingsLauncherOnly = enabled
+            settingsErrorMessage = nil
+        } catch {
+            settingsErrorMessage = error.localizedDescription
+        }
+    }
+
     func settingsDidOpen() {
         isSettingsPresented = true
         launcherWindow?.orderOut(nil)
@@ -132,6 +692,43 @@ final class LauncherViewModel: ObservableObject {
         settingsWindow?.orderFrontRegardless()
     }
 
+    /// Called by app views opened from launcher commands (e.g. the board) so the
+    /// launcher stays out of the way while they are on screen.
+    func viewWindowDidOpen(_ window: NSWindow) {
+        viewWindows.add(window)
+        isViewWindowPresented = true
+        launcherWindow?.orderOut(nil)
+
+        NSApp.activate(ignoringOtherApps: true)
+        window.makeKeyAndOrderFront(nil)
+    }
+
+    func viewWindowDidClose(_ window: NSWindow?) {
+        if let window {
+            viewWindows.remove(window)
+        }
+        guard isViewWindowPresented, viewWindows.allObjects.allSatisfy({ !$0.isVisible }) else {
+            return
+        }
+
+        isViewWindowPresented = false
+        revealLauncherIfNeeded()
+    }
+
+    func revealViewWindowIfNeeded() {
+        guard isViewWindowPresented, let window = viewWindows.allObjects.first(where: { $0.isVisible || $0.isMiniaturized }) else {
+            return
+        }
+
+        NSApp.unhide(nil)
+        NSApp.activate(ignoringOtherApps: true)
+        if window.isMiniaturized {
+            window.deminiaturize(nil)
+        }
+        window.makeKeyAndOrderFront(nil)
+        window.orderFrontRegardless()
+    }
+
     func settingsDidClose() {
         guard isSettingsPresented else {
             return
@@ -142,24 +739,101 @@ final class LauncherViewModel: ObservableObject {
     }
 
     func loadSettingsStorageDirectoryPath() {
+        guard !isLauncherOnly else {
+            return
+        }
         do {
             settingsStorageDirectoryPath = try RustBridgeClient.loadJsonStorageDirectoryPath()
+            settingsMirrorExcludedTags = try RustBridgeClient.mirrorExcludedTags()
+                .map { "#\($0)" }
+                .joined(separator: ", ")
+            settingsObsidianVaultMode = try RustBridgeClient.obsidianVaultMode()
+            imageLimits = try RustBridgeClient.imageLimits()
             settingsErrorMessage = nil
         } catch {
             settingsErrorMessage = error.localizedDescription
         }
     }
 
+    func loadSearchMatchesAnyTerm() {
+        if let matchAny = try? RustBridgeClient.searchMatchesAnyTerm() {
+            searchMatchesAnyTerm = matchAny
+        }
+    }
+
+    func loadRankingScript() -> String {
+        (try? RustBridgeClient.rankingScript()) ?? ""
+    }
+
+    /// Returns why the script was rejected, if it was.
+    func saveRankingScript(_ source: String) -> String? {
+        do {
+            try RustBridgeClient.updateRankingScript(source)
+            refreshSearchForCurrentQuery()
+            return nil
+        } catch {
+            return error.localizedDescription
+        }
+    }
+
+    func loadForgeAccounts() -> [ForgeAccountRecord] {
+        (try? RustBridgeClient.forgeAccounts()) ?? []
+    }
+
+    /// Returns why the accounts were rejected, if they were.
+    func saveForgeAccounts(_ accounts: [ForgeAccountRecord]) -> String? {
+        do {
+            try RustBridgeClient.updateForgeAccounts(accounts)
+            refreshSearchForCurrentQuery()
+            return nil
+        } catch {
+            return error.localizedDescription
+        }
+    }
+
+    func loadTrackerAccounts() -> [TrackerAccountRecord] {
+        (try? RustBridgeClient.trackerAccounts()) ?? []
+    }
+
+    /// Returns why the accounts were rejected, if they were.
+    func saveTrackerAccounts(_ accounts: [TrackerAccountRecord]) -> String? {
+        do {
+            try RustBridgeClient.updateTrackerAccounts(accounts)
+            refreshSearchForCurrentQuery()
+            return nil
+        } catch {
+            return error.localizedDescription
+        }
+    }
+
+    func setSearchMatchesAnyTerm(_ matchAny: Bool) {
+        do {
+            try RustBridgeClient.updateSearchMatchesAnyTerm(matchAny)
+            searchMatchesAnyTerm = matchAny
+            settingsErrorMessage = nil
+            refreshSearchForCurrentQuery()
+        } catch {
+            settingsErrorMessage = error.localizedDescription
+        }
+    }
+
     func reloadSettingsFromDisk() {
         ThemeManager.shared.reloadFromDisk()
         HotKeyManager.shared.reloadFromDisk()
+        searchDebounce = loadSearchDebounceSettings()
+        preferences = loadLauncherPreferences()
     }
 
     @discardableResult
     func saveSettingsStorageDirectoryPath() -> Bool {
         do {
             try RustBridgeClient.saveJsonStorageDirectoryPath(settingsStorageDirectoryPath)
-            settingsStorageDirectoryPath = try RustBridgeClient.loadJsonStorageDirectoryPath()
+            let excludedTags = settingsMirrorExcludedTags
+                .split(whereSeparator: { $0 == "," || $0.isWhitespace })
+                .map(String.init)
+            try RustBridgeClient.saveExcludedTags(excludedTags)
+            try RustBridgeClient.updateObsidianVaultMode(settingsObsidianVaultMode)
+            loadSettingsStorageDirectoryPath()
             settingsErrorMessage = nil
             settingsSuccessMessage = "Saved."
             refreshDeletedItems()
@@ -171,6 +845,27 @@ final class LauncherViewModel: ObservableObject {
         }
     }
 
+    func importNotionExport(from url: URL) async {
+        isImportingNotionExport = true
+        defer { isImportingNotionExport = false }
+
+        do {
+            let report = try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.importNotion(path: url.path)
+            }.value
+            var message = "Imported \(report.importedCount) notes from Notion."
+            if report.skippedImageCount > 0 {
+                message += " \(report.skippedImageCount) images were skipped."
+            }
+            settingsErrorMessage = nil
+            settingsSuccessMessage = message
+            refreshSearchForCurrentQuery()
+        } catch {
+            settingsErrorMessage = error.localizedDescription
+            settingsSuccessMessage = nil
+        }
+    }
+
     func refreshDeletedItems() {
         guard !isLoadingDeletedItems else {
             return
@@ -249,14 +944,16 @@ final class LauncherViewModel: ObservableObject {
     }
 
     func openDeletedItemPreview(archiveKey: String) async -> Bool {
-        guard let preview = await fetchDeletedItemPreview(archiveKey: archiveKey) else {
+        guard let preview = await fetchDeletedItemPreview(archiveKey: archiveKey),
+              await stashActiveEditor()
+        else {
             return false
         }
         autosaveTask?.cancel()
         autosaveTask = nil
         deletedPreviewItem = preview
         selectedItem = nil
-        editorText = preview.note
+        loadEditorText(preview.note)
         errorMessage = nil
         return true
     }
@@ -282,11 +979,62 @@ final class LauncherViewModel: ObservableObject {
         }
 
         launcherWindow.orderOut(nil)
-        if !isEditorPresented && !isSettingsPresented {
+        recordQueryInHistory()
+        persistSession()
+        if !isEditorPresented && !isSettingsPresented && !isViewWindowPresented {
             NSApp.hide(nil)
         }
     }
 
+    /// Replaces the query with an older (or newer) one from the history; returns
+    /// false when there is nothing further to recall.
+    @discardableResult
+    func recallQueryFromHistory(older: Bool) -> Bool {
+        let next: Int?
+        switch (queryHistoryIndex, older) {
+        case (nil, true):
+            next = queryHistory.isEmpty ? nil : 0
+        case (nil, false):
+            return false
+        case (let index?, true):
+            guard index + 1 < queryHistory.count else {
+                return false
+            }
+            next = index + 1
+        case (let index?, false):
+            next = index > 0 ? index - 1 : nil
+        }
+
+        guard next != nil || queryHistoryIndex != nil else {
+            return false
+        }
+        isRecallingQueryHistory = true
+        defer { isRecallingQueryHistory = false }
+        queryHistoryIndex = next
+        query = next.map { queryHistory[$0] } ?? ""
+        return true
+    }
+
+    private func recordQueryInHistory() {
+        let trimmed = query.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard !trimmed.isEmpty else {
+            return
+        }
+        Task.detached(priority: .utility) {
+            RustBridgeClient.countSearch()
+        }
+        guard queryHistory.first != trimmed else {
+            return
+        }
+        queryHistory.removeAll { $0 == trimmed }
+        queryHistory.insert(trimmed, at: 0)
+        if queryHistory.count > queryHistoryLimit {
+            queryHistory.removeLast(queryHistory.count - queryHistoryLimit)
+        }
+        queryHistoryIndex = nil
+        _ = SettingsStore.shared.saveJSON(PersistedQueryHistory(queries: queryHistory), fileName: queryHistoryFileName)
+    }
+
     func revealSettingsIfNeeded() {
         guard isSettingsPresented, let settingsWindow else {
             return
@@ -316,10 +1064,14 @@ final class LauncherViewModel: ObservableObject {
     }
 
     func revealLauncherIfNeeded() {
-        guard !isEditorPresented, !isSettingsPresented, let launcherWindow else {
+        guard !isEditorPresented, !isSettingsPresented, !isViewWindowPresented, let launcherWindow else {
             return
         }
 
+        if !launcherWindow.isVisible || NSApp.isHidden {
+            moveLauncherToActiveScreen(launcherWindow)
+        }
+
         NSApp.unhide(nil)
         NSApp.activate(ignoringOtherApps: true)
         if launcherWindow.isMiniaturized {
@@ -328,6 +1080,53 @@ final class LauncherViewModel: ObservableObject {
         launcherWindow.makeKeyAndOrderFront(nil)
         launcherWindow.orderFrontRegardless()
         launcherFocusRequestID &+= 1
+        guard !isLauncherOnly else {
+            return
+        }
+        refreshRecentItemsIfIdle()
+        refreshStorageAlertIfStale()
+        importVaultChanges()
+        retryReadLaterQueueIfDue()
+    }
+
+    /// Puts the launcher back at its configured position after the width or top
+    /// offset changed in Settings.
+    func recenterLauncher() {
+        guard let launcherWindow else {
+            return
+        }
+        moveLauncherToActiveScreen(launcherWindow, keepingDraggedPosition: false)
+    }
+
+    /// Centers the launcher on the display under the mouse pointer when it was last
+    /// shown on another display; on the same display a dragged position is kept.
+    private func moveLauncherToActiveScreen(_ window: NSWindow, keepingDraggedPosition: Bool = true) {
+        let mouseLocation = NSEvent.mouseLocation
+        guard let activeScreen = NSScreen.screens.first(where: { NSMouseInRect(mouseLocation, $0.frame, false) })
+            ?? NSScreen.main
+        else {
+            return
+        }
+
+        if keepingDraggedPosition, let currentScreen = window.screen, currentScreen == activeScreen {
+            return
+        }
+
+        let visible = activeScreen.visibleFrame
+        let size = window.frame.size
+        let topOffset = CGFloat(ThemeManager.shared.launcherTopOffsetPercent) / 100
+        let topEdge = max(visible.maxY - visible.height * topOffset, visible.minY)
+        let origin = NSPoint(
+            x: (visible.midX - size.width / 2).rounded(),
+            y: max(visible.minY, topEdge - size.height).rounded()
+        )
+        window.setFrameOrigin(origin)
+    }
+
+    private func refreshRecentItemsIfIdle() {
+        if effectiveSearchQuery(from: query) == nil {
+            loadRecentItems()
+        }
     }
 
     func toggleLauncherVisibilityFromHotKey() {
@@ -341,6 +1140,11 @@ final class LauncherViewModel: ObservableObject {
             return
         }
 
+        if isViewWindowPresented {
+            revealViewWindowIfNeeded()
+            return
+        }
+
         guard let launcherWindow else {
             return
         }
@@ -353,39 +1157,102 @@ final class LauncherViewModel: ObservableObject {
         }
     }
 
-    func editorDidClose() {
+    func editorDidClose(_ target: EditorWindowTarget?) {
+        switch target {
+        case .item(let itemId)? where target != activeEditorTarget:
+            backgroundEditors[itemId] = nil
+            return
+        case .deletedPreview? where selectedItem != nil:
+            // The previewed item was restored and opened in a window of its own.
+            return
+        default:
+            break
+        }
         guard isEditorPresented else {
             return
         }
 
         isEditorPresented = false
         deletedPreviewItem = nil
+        persistSession()
+        // Another editor window takes over once it becomes key.
+        if !backgroundEditors.isEmpty {
+            return
+        }
+        if isViewWindowPresented {
+            viewWindowRefreshID &+= 1
+            revealViewWindowIfNeeded()
+            return
+        }
         NSApp.activate(ignoringOtherApps: true)
         launcherWindow?.makeKeyAndOrderFront(nil)
         launcherWindow?.orderFrontRegardless()
         launcherFocusRequestID &+= 1
+        refreshRecentItemsIfIdle()
     }
 
-    func open(itemId: Int64) async -> Bool {
+    func open(itemId: Int64, revealingImageKey imageKey: String? = nil) async -> Bool {
+        let stashedItemId = selectedItem?.id != itemId ? selectedItem?.id : nil
+        if stashedItemId != nil, await !stashActiveEditor() {
+            return false
+        }
         autosaveTask?.cancel()
         autosaveTask = nil
 
         do {
-            let item = try RustBridgeClient.fetch(itemId: itemId)
+            let item = try await fetchUnlockingIfNeeded(itemId: itemId)
+            try? RustBridgeClient.markOpened(itemId: itemId)
             deletedPreviewItem = nil
             selectedItem = item
-            editorText = item.note
+            loadEditorText(item.note)
+            if let background = backgroundEditors.removeValue(forKey: itemId) {
+                editorScrollOffset = background.scrollOffset
+                editorScrollRestoreOffset = background.scrollOffset
+                editorScrollRestoreRequestID &+= 1
+            } else {
+                editorScrollOffset = 0
+                editorScrollRestoreOffset = nil
+            }
+            restorableSession = nil
+            editorRevealImageKey = imageKey
+            if imageKey != nil {
+                editorRevealRequestID &+= 1
+            }
             errorMessage = nil
             return true
         } catch {
+            if let stashedItemId {
+                backgroundEditors[stashedItemId] = nil
+            }
             errorMessage = error.localizedDescription
             return false
         }
     }
 
+    /// Fetches an item, asking for Touch ID first when it is a locked secure note.
+    private func fetchUnlockingIfNeeded(itemId: Int64) async throws -> EditableItemRecord {
+        do {
+            return try RustBridgeClient.fetch(itemId: itemId)
+        } catch BackendError.Locked {
+            guard await AppLockController.shared.unlock(reason: "open a secure note") else {
+                throw AppLockError.locked
+            }
+            return try RustBridgeClient.fetch(itemId: itemId)
+        }
+    }
+
+    /// The image key of a snippet taken from text recognized in an image.
+    static func imageKey(fromSnippetSource source: String?) -> String? {
+        guard let source, source.hasPrefix(imageSnippetSourcePrefix) else {
+            return nil
+        }
+        let key = String(source.dropFirst(imageSnippetSourcePrefix.count))
+        return key.isEmpty ? nil : key
+    }
+
     func createItemFromQuery() async -> Bool {
         let title = query.trimmingCharacters(in: .whitespacesAndNewlines)
-        guard !title.isEmpty else {
+        guard !title.isEmpty, !isLauncherOnly else {
             return false
         }
 
@@ -400,28 +1267,68 @@ final class LauncherViewModel: ObservableObject {
     }
 
     @discardableResult
-    func saveCurrentItem() async -> Bool {
+    /// Saves the open note, and its subtitle and keywords when `metadata` is given.
+    func saveCurrentItem(metadata: ItemMetadataRecord? = nil) async -> Bool {
         guard var item = selectedItem else {
             return true
         }
 
         let saveRevision = editorStateRevision
         let localTitleAtSaveStart = item.title
+        let baseNote = item.note
         item.note = editorText
+        if let metadata {
+            item.subtitle = metadata.subtitle
+            item.keywords = metadata.keywords
+        }
         let referenced = referencedImageKeys(in: editorText)
         item.images.removeAll { !referenced.contains($0.imageKey) }
         selectedItem = item
         let itemId = item.id
         let note = editorText
         let images = item.images
+        // Overlapping saves of our own would trip the conflict check, so only the first carries a token.
+        let expectedRevision: UInt64? = inFlightSaveCounts[itemId, default: 0] == 0 ? item.revision : nil
+        inFlightSaveCounts[itemId, default: 0] += 1
+        defer {
+            inFlightSaveCounts[itemId, default: 1] -= 1
+            if inFlightSaveCounts[itemId] == 0 {
+                inFlightSaveCounts.removeValue(forKey: itemId)
+            }
+        }
 
         do {
-            let refreshed: EditableItemRecord = try await withCheckedThrowingContinuation { continuation in
+            let (refreshed, merge): (EditableItemRecord, MergedNoteRecord?) = try await withCheckedThrowingContinuation { continuation in
                 DispatchQueue.global(qos: .userInitiated).async {
                     do {
-                        try RustBridgeClient.save(itemId: itemId, note: note, images: images)
+                        var merge: MergedNoteRecord?
+                        do {
+                            try RustBridgeClient.save(
+                                itemId: itemId,
+                                note: note,
+                                images: images,
+                                expectedRevision: expectedRevision,
+                                metadata: metadata
+                            )
+                        } catch BackendError.Conflict {
+                            // Saved elsewhere since this editor loaded the note: apply both edits to the latest version.
+                            let latest = try RustBridgeClient.fetch(itemId: itemId)
+                            let merged = RustBridgeClient.mergeEdits(base: baseNote, ours: note, theirs: latest.note)
+                            let ownKeys = Set(images.map(\.imageKey))
+                            let theirImages = latest.images.filter {
+                                !ownKeys.contains($0.imageKey) && merged.text.contains("alfred://image/\($0.imageKey)")
+                            }
+                            try RustBridgeClient.save(
+                                itemId: itemId,
+                                note: merged.text,
+                                images: images + theirImages,
+                                expectedRevision: latest.revision,
+                                metadata: metadata
+                            )
+                            merge = merged
+                        }
                         let refreshed = try RustBridgeClient.fetch(itemId: itemId)
-                        continuation.resume(returning: refreshed)
+                        continuation.resume(returning: (refreshed, merge))
                     } catch {
                         continuation.resume(throwing: error)
                     }
@@ -441,51 +1348,72 @@ final class LauncherViewModel: ObservableObject {
                     current.title = refreshed.title
                 }
                 current.images = refreshed.images
+                current.subtitle = refreshed.subtitle
+                current.keywords = refreshed.keywords
+                current.revision = refreshed.revision
+                current.mirrorExclusion = refreshed.mirrorExclusion
                 if saveRevision == editorStateRevision {
                     current.note = refreshed.note
                     selectedItem = current
-                    editorText = refreshed.note
+                    loadEditorText(refreshed.note)
+                } else if merge != nil {
+                    // Typed on while the merge ran; fold those keystrokes into the merged note too.
+                    current.note = refreshed.note
+                    selectedItem = current
+                    loadEditorText(RustBridgeClient.mergeEdits(base: note, ours: editorText, theirs: refreshed.note).text)
                 } else {
                     current.note = editorText
                     selectedItem = current
                 }
             }
 
+            if let merge, merge.conflictCount > 0 {
+                errorMessage = merge.conflictCount == 1
+                    ? "This note was also changed elsewhere. One overlapping edit is marked with <<<<<<< and >>>>>>>."
+                    : "This note was also changed elsewhere. \(merge.conflictCount) overlapping edits are marked with <<<<<<< and >>>>>>>."
+                refreshSearchForCurrentQuery()
+                return true
+            }
             errorMessage = nil
             refreshSearchForCurrentQuery()
+            if !images.isEmpty {
+                ImageTextRecognizer.shared.scheduleScan()
+            }
             return true
+        } catch BackendError.Conflict {
+            errorMessage = "This note keeps changing elsewhere. Reopen it to load the latest version."
+            return false
         } catch {
             errorMessage = error.localizedDescription
             return false
         }
     }
 
+    /// Renames an item, updating the open note and the launcher results right away
+    /// and rolling both back if the backend rejects the title.
     @discardableResult
-    func renameCurrentItem(to title: String) async -> Bool {
-        guard var current = selectedItem else {
-            return false
-        }
-
+    func renameItem(itemId: Int64, to title: String) async -> Bool {
         let trimmed = title.trimmingCharacters(in: .whitespacesAndNewlines)
         guard !trimmed.isEmpty else {
             errorMessage = "title must not be empty"
             return false
         }
 
-        guard trimmed != current.title else {
+        let previousTitle = (selectedItem?.id == itemId ? selectedItem?.title : nil)
+            ?? backgroundEditors[itemId]?.item.title
+            ?? results.first(where: { $0.id == itemId })?.title
+        guard trimmed != previousTitle else {
             errorMessage = nil
             return true
         }
 
-        let previousTitle = current.title
-        current.title = trimmed
-        selectedItem = current
+        applyTitle(trimmed, toItem: itemId)
 
         do {
             try await withCheckedThrowingContinuation { (continuation: CheckedContinuation<Void, Error>) in
                 DispatchQueue.global(qos: .userInitiated).async {
                     do {
-                        try RustBridgeClient.rename(itemId: current.id, title: trimmed)
+                        try RustBridgeClient.rename(itemId: itemId, title: trimmed)
                         continuation.resume()
                     } catch {
                         continuation.resume(throwing: error)
@@ -496,19 +1424,33 @@ final class LauncherViewModel: ObservableObject {
             refreshSearchForCurrentQuery()
             return true
         } catch {
-            if var latest = selectedItem, latest.id == current.id, latest.title == trimmed {
-                latest.title = previousTitle
-                selectedItem = latest
+            if let previousTitle {
+                applyTitle(previousTitle, toItem: itemId, onlyIfTitle: trimmed)
             }
             errorMessage = error.localizedDescription
             return false
         }
     }
 
+    private func applyTitle(_ title: String, toItem itemId: Int64, onlyIfTitle expected: String? = nil) {
+        if var current = selectedItem, current.id == itemId, expected == nil || current.title == expected {
+            current.title = title
+            selectedItem = current
+        }
+        if let background = backgroundEditors[itemId], expected == nil || background.item.title == expected {
+            backgroundEditors[itemId]?.item.title = title
+        }
+        if let index = results.firstIndex(where: { $0.id == itemId && $0.kind == .item }),
+           expected == nil || results[index].title == expected {
+            results[index].title = title
+        }
+    }
+
     func scheduleAutosave() {
         autosaveTask?.cancel()
+        let delay = preferences.autosaveDelayNanoseconds
         autosaveTask = Task { [weak self] in
-            try? await Task.sleep(nanoseconds: autosaveDebounceNanoseconds)
+            try? await Task.sleep(nanoseconds: delay)
             guard let self, !Task.isCancelled else {
                 return
             }
@@ -523,6 +1465,75 @@ final class LauncherViewModel: ObservableObject {
         return await saveCurrentItem()
     }
 
+    /// Saves a new subtitle and keywords together with the open note's text.
+    @discardableResult
+    func saveItemMetadata(subtitle: String, keywords: String) async -> Bool {
+        autosaveTask?.cancel()
+        autosaveTask = nil
+        return await saveCurrentItem(metadata: ItemMetadataRecord(subtitle: subtitle, keywords: keywords))
+    }
+
+    /// Returns the open note's text and images to before the last change.
+    func undoEditorChange() {
+        applyEditorUndo { history, current in history.undo(from: current) }
+    }
+
+    func redoEditorChange() {
+        applyEditorUndo { history, current in history.redo(from: current) }
+    }
+
+    private func applyEditorUndo(_ step: (inout EditorUndoHistory, EditorUndoSnapshot) -> EditorUndoSnapshot?) {
+        guard deletedPreviewItem == nil, var item = selectedItem, var history = editorUndoHistories[item.id] else {
+            return
+        }
+        let current = EditorUndoSnapshot(note: editorText, images: item.images)
+        guard let target = step(&history, current) else {
+            NSSound.beep()
+            return
+        }
+        editorUndoHistories[item.id] = history
+
+        var images = item.images
+        for image in target.images where !images.contains(where: { $0.imageKey == image.imageKey }) {
+            images.append(image)
+        }
+        item.images = images
+        item.note = target.note
+        selectedItem = item
+        loadEditorText(target.note)
+    }
+
+    /// Sets the editor text without recording an undo step, for loading a note
+    /// or restoring one of its snapshots.
+    private func loadEditorText(_ text: String) {
+        suppressesEditorUndoRecording = true
+        editorText = text
+        suppressesEditorUndoRecording = false
+    }
+
+    /// Makes the next change of the editor text an undo step of its own, for
+    /// editor commands that rewrite text in one go.
+    func startEditorUndoStep() {
+        editorUndoStartsNewStep = true
+    }
+
+    private func recordEditorUndoStep(previousText: String) {
+        let startsNewStep = editorUndoStartsNewStep
+        editorUndoStartsNewStep = false
+        guard !suppressesEditorUndoRecording,
+              deletedPreviewItem == nil,
+              let item = selectedItem,
+              previousText != editorText
+        else {
+            return
+        }
+        editorUndoHistories[item.id, default: EditorUndoHistory()].recordChange(
+            from: EditorUndoSnapshot(note: previousText, images: item.images),
+            at: Date(),
+            startsNewStep: startsNewStep
+        )
+    }
+
     func increaseEditorFontSize() {
         setEditorFontSize(editorFontSize + editorFontSizeStep)
     }
@@ -531,41 +1542,118 @@ final class LauncherViewModel: ObservableObject {
         setEditorFontSize(editorFontSize - editorFontSizeStep)
     }
 
-    private func setEditorFontSize(_ value: CGFloat) {
-        let clamped = min(max(value, editorMinFontSize), editorMaxFontSize)
-        guard abs(clamped - editorFontSize) > 0.01 else {
+    private func setEditorFontSize(_ value: CGFloat) {
+        let clamped = min(max(value, editorMinFontSize), editorMaxFontSize)
+        guard abs(clamped - editorFontSize) > 0.01 else {
+            return
+        }
+        editorFontSize = clamped
+    }
+
+    func hasImageInClipboard() -> Bool {
+        let pasteboard = NSPasteboard.general
+        if pasteboard.data(forType: .png) != nil || pasteboard.data(forType: .tiff) != nil {
+            return true
+        }
+        return !(pasteboard.readObjects(forClasses: [NSImage.self], options: nil) ?? []).isEmpty
+    }
+
+    func pasteImageFromClipboard(at cursorCharIndex: Int?) async {
+        guard var item = selectedItem else {
+            return
+        }
+
+        if item.images.count >= Int(imageLimits.maxImagesPerNote) {
+            errorMessage = "Too many note images (max \(imageLimits.maxImagesPerNote))"
+            return
+        }
+
+        guard let source = clipboardImageSource() else {
+            errorMessage = "Clipboard does not contain an image"
+            return
+        }
+
+        // Decoding and re-encoding a large image takes a while; do it off the
+        // main thread and insert the marker only once it's done.
+        imagePasteTask?.cancel()
+        let itemId = item.id
+        let storage = imageStorage
+        let task = Task.detached(priority: .userInitiated) {
+            source.pngData().map { imageBytesForStorage($0, settings: storage) }
+        }
+        imagePasteTask = task
+        isProcessingPastedImage = true
+        let converted = await task.value
+        guard imagePasteTask == task else {
+            return
+        }
+        imagePasteTask = nil
+        isProcessingPastedImage = false
+
+        guard selectedItem?.id == itemId, let current = selectedItem else {
+            return
+        }
+        item = current
+        guard let imageBytes = converted else {
+            errorMessage = "Clipboard image could not be read"
+            return
+        }
+
+        if imageBytes.count > Int(clamping: imageLimits.maxImageBytes) {
+            errorMessage = "Image exceeds \(imageLimits.maxImageBytes / 1024) KB storage limit"
+            return
+        }
+
+        let key = nextImageKey(existing: Set(item.images.map(\.imageKey)))
+        item.images.append(NoteImageRecord(imageKey: key, bytes: imageBytes))
+
+        editorUndoStartsNewStep = true
+        editorText = insertMarkdownImageRef(into: editorText, key: key, cursorCharIndex: cursorCharIndex)
+        item.note = editorText
+        selectedItem = item
+        errorMessage = nil
+
+        await saveCurrentItem()
+    }
+
+    /// Puts edited pixels in place of a note image. The result gets a new key so
+    /// undoing the edit brings back the original image with its marker.
+    func replaceEditorImage(key: String, with png: Data) async {
+        guard var item = selectedItem, item.images.contains(where: { $0.imageKey == key }) else {
             return
         }
-        editorFontSize = clamped
-    }
 
-    func hasImageInClipboard() -> Bool {
-        let pasteboard = NSPasteboard.general
-        if pasteboard.data(forType: .png) != nil || pasteboard.data(forType: .tiff) != nil {
-            return true
+        let storage = imageStorage
+        let imageBytes = await Task.detached(priority: .userInitiated) {
+            imageBytesForStorage(png, settings: storage)
+        }.value
+        guard selectedItem?.id == item.id, let current = selectedItem else {
+            return
         }
-        return !(pasteboard.readObjects(forClasses: [NSImage.self], options: nil) ?? []).isEmpty
-    }
-
-    func pasteImageFromClipboard(at cursorCharIndex: Int?) async {
-        guard var item = selectedItem else {
+        item = current
+        if imageBytes.count > Int(clamping: imageLimits.maxImageBytes) {
+            errorMessage = "Edited image exceeds \(imageLimits.maxImageBytes / 1024) KB storage limit"
             return
         }
 
-        if item.images.count >= maxNoteImageCount {
-            errorMessage = "Too many note images (max \(maxNoteImageCount))"
+        let newKey = nextImageKey(existing: Set(item.images.map(\.imageKey)))
+        let pattern = NSRegularExpression.escapedPattern(for: "(\(noteImageURLPrefix)\(key)") + #"(?=[?)])"#
+        guard let regex = try? NSRegularExpression(pattern: pattern) else {
             return
         }
-
-        guard let imageBytes = clipboardImageBytes() else {
-            errorMessage = "Clipboard does not contain an image"
+        let range = NSRange(editorText.startIndex..., in: editorText)
+        let updated = regex.stringByReplacingMatches(
+            in: editorText,
+            range: range,
+            withTemplate: NSRegularExpression.escapedTemplate(for: "(\(noteImageURLPrefix)\(newKey)")
+        )
+        guard updated != editorText else {
             return
         }
 
-        let key = nextImageKey(existing: Set(item.images.map(\.imageKey)))
-        item.images.append(NoteImageRecord(imageKey: key, bytes: imageBytes))
-
-        editorText = insertMarkdownImageRef(into: editorText, key: key, cursorCharIndex: cursorCharIndex)
+        item.images.append(NoteImageRecord(imageKey: newKey, bytes: imageBytes))
+        editorUndoStartsNewStep = true
+        editorText = updated
         item.note = editorText
         selectedItem = item
         errorMessage = nil
@@ -573,18 +1661,96 @@ final class LauncherViewModel: ObservableObject {
         await saveCurrentItem()
     }
 
+    /// Stops converting a pasted image; nothing is inserted.
+    func cancelImagePaste() {
+        imagePasteTask?.cancel()
+        imagePasteTask = nil
+        isProcessingPastedImage = false
+    }
+
+    /// Appends every image in `folder` to the end of the note as PNG and returns a
+    /// summary naming the files that were left out.
+    func importImagesFromFolder(_ folder: URL) async -> String? {
+        guard var item = selectedItem else {
+            return nil
+        }
+
+        let existingCount = item.images.count
+        let plan: ImageImportPlanRecord
+        do {
+            plan = try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.imageImportPlan(folder: folder.path, existingImageCount: existingCount)
+            }.value
+        } catch {
+            errorMessage = error.localizedDescription
+            return nil
+        }
+
+        let paths = plan.paths
+        let maxBytes = Int(clamping: imageLimits.maxImageBytes)
+        let storage = imageStorage
+        let normalized = await Task.detached(priority: .userInitiated) {
+            paths.map { path -> (String, Result<Data, ImageImportFailure>) in
+                (path, normalizedImageData(atPath: path, maxBytes: maxBytes, storage: storage))
+            }
+        }.value
+
+        var skipped = plan.skipped.map { "\($0.fileName): \($0.reason)" }
+        var keys = Set(item.images.map(\.imageKey))
+        var note = editorText
+        var imported = 0
+        for (path, result) in normalized {
+            let fileName = (path as NSString).lastPathComponent
+            switch result {
+            case .success(let bytes):
+                let key = nextImageKey(existing: keys)
+                keys.insert(key)
+                item.images.append(NoteImageRecord(imageKey: key, bytes: bytes))
+                note = appendMarkdownImageRef(to: note, key: key)
+                imported += 1
+            case .failure(let failure):
+                skipped.append("\(fileName): \(failure.reason)")
+            }
+        }
+
+        if imported > 0 {
+            editorUndoStartsNewStep = true
+            editorText = note
+            item.note = note
+            selectedItem = item
+            errorMessage = nil
+            await saveCurrentItem()
+        }
+
+        var summary = "Imported \(imported) image\(imported == 1 ? "" : "s")."
+        if !skipped.isEmpty {
+            summary += " Skipped \(skipped.count):\n" + skipped.joined(separator: "\n")
+        }
+        return summary
+    }
+
     func removeImage(imageKey: String) async {
+        await removeImages(imageKeys: [imageKey])
+    }
+
+    /// Deletes several images of the open note along with their markers.
+    func removeImages(imageKeys: Set<String>) async {
         guard var item = selectedItem else {
             return
         }
 
         let before = item.images.count
-        item.images.removeAll { $0.imageKey == imageKey }
+        item.images.removeAll { imageKeys.contains($0.imageKey) }
         guard item.images.count != before else {
             return
         }
 
-        editorText = removeMarkdownImageRef(from: editorText, key: imageKey)
+        var note = editorText
+        for key in imageKeys {
+            note = removeMarkdownImageRef(from: note, key: key)
+        }
+        editorUndoStartsNewStep = true
+        editorText = note
         item.note = editorText
         selectedItem = item
         errorMessage = nil
@@ -592,6 +1758,70 @@ final class LauncherViewModel: ObservableObject {
         await saveCurrentItem()
     }
 
+    /// Keys of the images the open note shows, in the order they appear.
+    var editorImageKeysInOrder: [String] {
+        var seen = Set<String>()
+        return markdownImageRefs(in: editorText).map(\.key).filter { seen.insert($0).inserted }
+    }
+
+    /// Moves the images' markers so they appear in `order` (image keys), keeping
+    /// the text around them. Markers repeated later in the note stay put.
+    func reorderImages(_ order: [String]) async {
+        guard selectedItem != nil else {
+            return
+        }
+        var seen = Set<String>()
+        let slots = markdownImageRefs(in: editorText).filter { seen.insert($0.key).inserted }
+        guard slots.count == order.count, slots.map(\.key) != order else {
+            return
+        }
+        let markerByKey = Dictionary(uniqueKeysWithValues: slots.map { ($0.key, String(editorText[$0.range])) })
+
+        var note = editorText
+        for (slot, key) in zip(slots, order).reversed() {
+            guard let marker = markerByKey[key] else {
+                return
+            }
+            note.replaceSubrange(slot.range, with: marker)
+        }
+        editorUndoStartsNewStep = true
+        editorText = note
+        selectedItem?.note = note
+        errorMessage = nil
+
+        await saveCurrentItem()
+    }
+
+    /// Writes images of the open note into `folder`, named after the note and
+    /// numbered in note order; returns a summary of what was written.
+    func exportImages(imageKeys: [String], to folder: URL) -> String {
+        guard let item = selectedItem else {
+            return "No note is open."
+        }
+        let bytesByKey = Dictionary(uniqueKeysWithValues: item.images.map { ($0.imageKey, $0.bytes) })
+        let baseName = sanitizedFileName(item.title.isEmpty ? "image" : item.title)
+        var written = 0
+        var failures: [String] = []
+        for (index, key) in imageKeys.enumerated() {
+            guard let bytes = bytesByKey[key] else {
+                continue
+            }
+            let url = uniqueFileURL(in: folder, name: "\(baseName)-\(index + 1)", extension: imageFileExtension(for: bytes))
+            do {
+                try bytes.write(to: url, options: .withoutOverwriting)
+                written += 1
+            } catch {
+                failures.append("\(url.lastPathComponent): \(error.localizedDescription)")
+            }
+        }
+
+        var summary = "Exported \(written) image\(written == 1 ? "" : "s") to \(folder.lastPathComponent)."
+        if !failures.isEmpty {
+            summary += " Failed \(failures.count):\n" + failures.joined(separator: "\n")
+        }
+        return summary
+    }
+
     func imageDisplayWidth(for imageKey: String) -> Double {
         markdownImageWidth(in: editorText, key: imageKey) ?? inlineImageDefaultWidth
     }
@@ -633,10 +1863,15 @@ final class LauncherViewModel: ObservableObject {
     }
 
     func deleteItem(itemId: Int64) async {
+        let title = results.first(where: { $0.id == itemId })?.title
+            ?? (selectedItem?.id == itemId ? selectedItem?.title : nil)
+            ?? "Item"
         do {
-            try await Task.detached(priority: .userInitiated) {
+            let archiveKey = try await Task.detached(priority: .userInitiated) {
                 try RustBridgeClient.delete(itemId: itemId)
             }.value
+            editorUndoHistories[itemId] = nil
+            backgroundEditors[itemId] = nil
 
             if selectedItem?.id == itemId {
                 selectedItem = nil
@@ -646,6 +1881,138 @@ final class LauncherViewModel: ObservableObject {
 
             refreshSearchForCurrentQuery()
             errorMessage = nil
+            offerUndo(for: UndoableDeletion(archiveKey: archiveKey, title: title))
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    /// Checks or unchecks a checklist entry without opening the editor; returns
+    /// whether the note changed.
+    func toggleChecklistEntry(itemId: Int64, index: Int) async -> Bool {
+        do {
+            try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.toggleChecklist(itemId: itemId, index: UInt32(index))
+            }.value
+            refreshSearchForCurrentQuery()
+            errorMessage = nil
+            return true
+        } catch {
+            errorMessage = error.localizedDescription
+            return false
+        }
+    }
+
+    /// Restores the item named by the undo toast.
+    func undoDeletion() async {
+        guard let deletion = undoableDeletion else {
+            return
+        }
+        dismissUndoableDeletion()
+
+        do {
+            _ = try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.restoreDeleted(archiveKey: deletion.archiveKey)
+            }.value
+            refreshSearchForCurrentQuery()
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    func dismissUndoableDeletion() {
+        undoableDeletionTask?.cancel()
+        undoableDeletionTask = nil
+        undoableDeletion = nil
+    }
+
+    private func offerUndo(for deletion: UndoableDeletion) {
+        undoableDeletionTask?.cancel()
+        undoableDeletion = deletion
+        undoableDeletionTask = Task { [weak self] in
+            try? await Task.sleep(nanoseconds: undoableDeletionSeconds * 1_000_000_000)
+            guard !Task.isCancelled, self?.undoableDeletion == deletion else {
+                return
+            }
+            self?.undoableDeletion = nil
+        }
+    }
+
+    /// Runs a bulk action over the marked items; returns whether it succeeded.
+    @discardableResult
+    func performBulkAction(_ action: BulkItemAction, itemIds: [Int64]) async -> Bool {
+        do {
+            switch action {
+            case .delete:
+                try await Task.detached(priority: .userInitiated) {
+                    try RustBridgeClient.delete(itemIds: itemIds)
+                }.value
+                for itemId in itemIds {
+                    editorUndoHistories[itemId] = nil
+                    backgroundEditors[itemId] = nil
+                }
+                if let selectedId = selectedItem?.id, itemIds.contains(selectedId) {
+                    selectedItem = nil
+                    editorText = ""
+                    isEditorPresented = false
+                }
+            case .addTag(let tag):
+                _ = try await Task.detached(priority: .userInitiated) {
+                    try RustBridgeClient.tag(itemIds: itemIds, tag: tag)
+                }.value
+            case .export(let url):
+                let json = try await Task.detached(priority: .userInitiated) {
+                    try RustBridgeClient.exportJSON(itemIds: itemIds)
+                }.value
+                try json.write(to: url, atomically: true, encoding: .utf8)
+            }
+
+            refreshSearchForCurrentQuery()
+            errorMessage = nil
+            return true
+        } catch {
+            errorMessage = error.localizedDescription
+            return false
+        }
+    }
+
+    /// Writes the CSV inventory of every item to `url`.
+    func exportItemsCSV(to url: URL) async {
+        do {
+            let csv = try await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.exportCSV()
+            }.value
+            try csv.write(to: url, atomically: true, encoding: .utf8)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    func setSelectedItemSecure(_ secure: Bool) {
+        guard let itemId = selectedItem?.id else {
+            return
+        }
+        do {
+            try RustBridgeClient.setSecure(itemId: itemId, secure: secure)
+            let refreshed = try RustBridgeClient.fetch(itemId: itemId)
+            if var current = selectedItem, current.id == itemId {
+                current.secure = refreshed.secure
+                current.mirrorExclusion = refreshed.mirrorExclusion
+                selectedItem = current
+            }
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    func setItemPinned(itemId: Int64, pinned: Bool) {
+        do {
+            try RustBridgeClient.setPinned(itemId: itemId, pinned: pinned)
+            refreshSearchForCurrentQuery()
+            errorMessage = nil
         } catch {
             errorMessage = error.localizedDescription
         }
@@ -661,12 +2028,152 @@ final class LauncherViewModel: ObservableObject {
         }
     }
 
+    /// Copies the `alfredalt://` link that opens the item.
+    func copyItemDeepLink(itemId: Int64) {
+        do {
+            let link = try RustBridgeClient.deepLink(itemId: itemId)
+            let pasteboard = NSPasteboard.general
+            pasteboard.clearContents()
+            pasteboard.setString(link, forType: .string)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    /// Copies the item as a Markdown document with its title as the heading.
+    func copyItemMarkdown(itemId: Int64) {
+        do {
+            let export = try RustBridgeClient.markdown(itemId: itemId)
+            let pasteboard = NSPasteboard.general
+            pasteboard.clearContents()
+            pasteboard.setString(export.markdown, forType: .string)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    /// Opens the note an `alfredalt://item/<id>` or `alfredalt://note/<title>`
+    /// link points to; the caller shows the editor window when this returns true.
+    func openDeepLink(_ url: URL) async -> Bool {
+        let link = url.absoluteString
+        let itemId: Int64
+        if let title = RustBridgeClient.noteTitle(forDeepLink: link) {
+            guard let id = (try? RustBridgeClient.itemId(forNoteTitle: title)) ?? nil else {
+                errorMessage = "No note is titled “\(title)”"
+                return false
+            }
+            itemId = id
+        } else if let id = RustBridgeClient.itemId(forDeepLink: link) {
+            itemId = id
+        } else {
+            errorMessage = "Not a note link: \(link)"
+            return false
+        }
+        guard await open(itemId: itemId) else {
+            return false
+        }
+        beginEditorPresentation()
+        return true
+    }
+
+    /// Follows a `[[title]]` note link clicked in the editor or its preview,
+    /// saving the open note first; true when the linked note's window should
+    /// be shown.
+    @discardableResult
+    func openNoteLink(_ url: URL) async -> Bool {
+        await saveCurrentItem()
+        return await openDeepLink(url)
+    }
+
+    /// Puts a tag search for `tag` in the launcher, for a `#tag` ⌘-clicked in
+    /// the editor.
+    func searchTag(_ tag: String) {
+        query = RustBridgeClient.tagSearch(for: tag)
+    }
+
+    // MARK: Session restore
+
+    /// Reads the previous run's session for the restore offer, then marks the
+    /// new run as not yet exited cleanly.
+    private func loadSessionForRestore() {
+        var session = SettingsStore.shared.loadJSON(PersistedSession.self, fileName: sessionFileName) ?? .empty
+        if session.hasContentToRestore {
+            restorableSession = session
+        }
+        session.exitedCleanly = false
+        _ = SettingsStore.shared.saveJSON(session, fileName: sessionFileName)
+    }
+
+    /// Writes what is open now. While a restore is still offered, the previous
+    /// session stays on disk so ignoring the offer does not lose it.
+    func persistSession(exitedCleanly: Bool = false) {
+        var session = restorableSession ?? PersistedSession(
+            launcherQuery: query,
+            editorItemId: isEditorPresented ? selectedItem?.id : nil,
+            editorItemTitle: isEditorPresented ? selectedItem?.title : nil,
+            editorScrollOffset: isEditorPresented ? Double(editorScrollOffset) : 0,
+            exitedCleanly: false
+        )
+        session.exitedCleanly = exitedCleanly
+        _ = SettingsStore.shared.saveJSON(session, fileName: sessionFileName)
+    }
+
+    func editorDidScroll(to offset: CGFloat) {
+        editorScrollOffset = offset
+    }
+
+    /// Puts back the offered session's query and note; true when the editor
+    /// window should be shown.
+    func restoreSession() async -> Bool {
+        guard let session = restorableSession else {
+            return false
+        }
+        restorableSession = nil
+        query = session.launcherQuery
+        guard let itemId = session.editorItemId else {
+            persistSession()
+            return false
+        }
+        guard await open(itemId: itemId) else {
+            persistSession()
+            return false
+        }
+        editorScrollRestoreOffset = CGFloat(session.editorScrollOffset)
+        editorScrollRestoreRequestID &+= 1
+        beginEditorPresentation()
+        return true
+    }
+
+    func dismissSessionRestore() {
+        restorableSession = nil
+        persistSession()
+    }
+
     func copyItemTitle(_ title: String) {
         let pasteboard = NSPasteboard.general
         pasteboard.clearContents()
         pasteboard.setString(title, forType: .string)
     }
 
+    /// Copies the note as plain text; with `imageKey`, that image is added to the
+    /// same pasteboard item so apps that take images paste it instead.
+    func copyItemNote(itemId: Int64, imageKey: String? = nil) {
+        do {
+            let text = try RustBridgeClient.plainText(itemId: itemId)
+            let imageData = try imageKey.map { try RustBridgeClient.itemImage(itemId: itemId, imageKey: $0) }
+            let pasteboard = NSPasteboard.general
+            pasteboard.clearContents()
+            pasteboard.setString(text, forType: .string)
+            if let tiff = imageData.flatMap({ NSImage(data: $0) })?.tiffRepresentation {
+                pasteboard.setData(tiff, forType: .tiff)
+            }
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
     private func deletedItemsFolderURL() throws -> URL {
         let root = try RustBridgeClient.loadJsonStorageDirectoryPath()
         return URL(fileURLWithPath: root, isDirectory: true)
@@ -679,11 +2186,38 @@ final class LauncherViewModel: ObservableObject {
 
     private func refreshSearchForCurrentQuery() {
         guard let searchQuery = effectiveSearchQuery(from: query) else {
+            loadRecentItems()
             return
         }
         triggerSearch(for: searchQuery)
     }
 
+    private func loadRecentItems() {
+        guard !isLauncherOnly else {
+            // Without notes there is nothing recent; an empty query lists nothing.
+            if !results.isEmpty {
+                results = []
+            }
+            return
+        }
+        let limit = preferences.clampedSearchResultLimit
+        Task { [weak self] in
+            let fetched = try? await Task.detached(priority: .userInitiated) {
+                try RustBridgeClient.recentItems(limit: limit)
+            }.value
+
+            guard let self, self.effectiveSearchQuery(from: self.query) == nil else {
+                return
+            }
+            let recent = fetched ?? []
+            FrameTimingMonitor.measure(.searchApply) {
+                if self.results != recent {
+                    self.results = recent
+                }
+            }
+        }
+    }
+
     private func triggerSearch(for searchQuery: String) {
         queuedSearchQuery = searchQuery
         guard !isSearchWorkerRunning else {
@@ -702,23 +2236,30 @@ final class LauncherViewModel: ObservableObject {
     private func processQueuedSearches() async {
         while let currentQuery = queuedSearchQuery {
             queuedSearchQuery = nil
-            await Task.yield()
+            let delay = searchDebounce.delayNanoseconds(forQuery: currentQuery)
+            if delay > 0 {
+                try? await Task.sleep(nanoseconds: delay)
+            } else {
+                await Task.yield()
+            }
             if queuedSearchQuery != nil {
                 continue
             }
 
             do {
+                let limit = currentQuery.isEmpty ? listAllSearchLimit : preferences.clampedSearchResultLimit
                 let fetched = try await Task.detached(priority: .userInitiated) {
-                    let limit = currentQuery.isEmpty ? listAllSearchLimit : defaultSearchLimit
-                    return try RustBridgeClient.search(query: currentQuery, limit: limit)
+                    try RustBridgeClient.search(query: currentQuery, limit: limit)
                 }.value
 
                 guard effectiveSearchQuery(from: query) == .some(currentQuery) else {
                     continue
                 }
 
-                if results != fetched {
-                    results = fetched
+                FrameTimingMonitor.measure(.searchApply) {
+                    if results != fetched {
+                        results = fetched
+                    }
                 }
                 if errorMessage != nil {
                     errorMessage = nil
@@ -742,25 +2283,29 @@ final class LauncherViewModel: ObservableObject {
         if trimmedQuery.isEmpty {
             return rawQuery == "  " ? "" : nil
         }
+        // The backend routes prefixes such as "t " to other providers, so the
+        // space after a one-letter prefix has to survive.
+        if let first = trimmedQuery.first,
+           trimmedQuery.count == 1,
+           rawQuery.drop(while: \.isWhitespace).dropFirst().first == " " {
+            return "\(first) "
+        }
         return trimmedQuery
     }
 
-    private func clipboardImageBytes() -> Data? {
+    private func clipboardImageSource() -> ClipboardImageSource? {
         let pasteboard = NSPasteboard.general
 
         if let png = pasteboard.data(forType: .png), !png.isEmpty {
-            return png
+            return .png(png)
         }
 
-        if let tiff = pasteboard.data(forType: .tiff),
-           let image = NSImage(data: tiff),
-           let png = image.pngData() {
-            return png
+        if let tiff = pasteboard.data(forType: .tiff), !tiff.isEmpty {
+            return .tiff(tiff)
         }
 
-        if let image = (pasteboard.readObjects(forClasses: [NSImage.self], options: nil) as? [NSImage])?.first,
-           let png = image.pngData() {
-            return png
+        if let image = (pasteboard.readObjects(forClasses: [NSImage.self], options: nil) as? [NSImage])?.first {
+            return .image(image)
         }
 
         return nil
@@ -818,7 +2363,7 @@ final class LauncherViewModel: ObservableObject {
         guard let match = regex.firstMatch(in: note, options: [], range: noteRange) else {
             return nil
         }
-        let capture = match.range(at: 1)
+        let capture = match.range(at: 2)
         guard capture.location != NSNotFound,
               let captureRange = Range(capture, in: note),
               let value = Double(note[captureRange])
@@ -834,7 +2379,8 @@ final class LauncherViewModel: ObservableObject {
         }
 
         let noteRange = NSRange(note.startIndex..<note.endIndex, in: note)
-        let replacement = "![image](\(noteImageURLPrefix)\(key)?w=\(width))"
+        // `$1` keeps the marker's alt text.
+        let replacement = "![$1](\(noteImageURLPrefix)\(NSRegularExpression.escapedTemplate(for: key))?w=\(width))"
         let updated = regex.stringByReplacingMatches(
             in: note,
             options: [],
@@ -846,12 +2392,28 @@ final class LauncherViewModel: ObservableObject {
 
     private func imageRefRegex(for key: String) -> NSRegularExpression? {
         let escaped = NSRegularExpression.escapedPattern(for: key)
-        let pattern = "!\\[image\\]\\(\(noteImageURLPrefix)\(escaped)(?:\\?w=(\\d+))?\\)"
+        let pattern = "!\\[([^\\]\\n]*)\\]\\(\(noteImageURLPrefix)\(escaped)(?:\\?w=(\\d+))?\\)"
         return try? NSRegularExpression(pattern: pattern)
     }
 
+    /// Every image marker in `note` with its key, in order.
+    private func markdownImageRefs(in note: String) -> [(key: String, range: Range<String.Index>)] {
+        guard let regex = try? NSRegularExpression(pattern: #"!\[[^\]\n]*\]\(alfred://image/([^\)\?]+)(?:\?w=\d+)?\)"#) else {
+            return []
+        }
+        let range = NSRange(note.startIndex..<note.endIndex, in: note)
+        return regex.matches(in: note, range: range).compactMap { match in
+            guard let markerRange = Range(match.range, in: note),
+                  let keyRange = Range(match.range(at: 1), in: note)
+            else {
+                return nil
+            }
+            return (String(note[keyRange]), markerRange)
+        }
+    }
+
     private func referencedImageKeys(in note: String) -> Set<String> {
-        guard let regex = try? NSRegularExpression(pattern: #"!\[image\]\(alfred://image/([^\)\?]+)(?:\?w=\d+)?\)"#) else {
+        guard let regex = try? NSRegularExpression(pattern: #"!\[[^\]\n]*\]\(alfred://image/([^\)\?]+)(?:\?w=\d+)?\)"#) else {
             return []
         }
 
@@ -873,6 +2435,90 @@ final class LauncherViewModel: ObservableObject {
     }
 }
 
+private struct ImageImportFailure: Error {
+    let reason: String
+}
+
+/// Decodes an image file and re-encodes it in the storage format pasted images use.
+private func normalizedImageData(
+    atPath path: String,
+    maxBytes: Int,
+    storage: ImageStorageSettings
+) -> Result<Data, ImageImportFailure> {
+    guard let image = NSImage(contentsOfFile: path), let png = image.pngData() else {
+        return .failure(ImageImportFailure(reason: "could not be read as an image"))
+    }
+    let stored = imageBytesForStorage(png, settings: storage)
+    guard stored.count <= maxBytes else {
+        let format = stored == png ? "PNG" : (storage.codec?.name ?? "PNG")
+        return .failure(ImageImportFailure(reason: "exceeds \(maxBytes / 1024) KB storage limit as \(format)"))
+    }
+    return .success(stored)
+}
+
+/// File extension for image bytes, from their leading signature.
+private func imageFileExtension(for bytes: Data) -> String {
+    let header = [UInt8](bytes.prefix(12))
+    if header.starts(with: [0x89, 0x50, 0x4E, 0x47]) {
+        return "png"
+    }
+    if header.starts(with: [0xFF, 0xD8, 0xFF]) {
+        return "jpg"
+    }
+    if header.starts(with: Array("GIF8".utf8)) {
+        return "gif"
+    }
+    if header.count == 12, header[0..<4].elementsEqual("RIFF".utf8), header[8..<12].elementsEqual("WEBP".utf8) {
+        return "webp"
+    }
+    if header.count == 12, header[4..<12].elementsEqual("ftypheic".utf8) {
+        return "heic"
+    }
+    return "png"
+}
+
+/// `name` without characters that are awkward in file names.
+private func sanitizedFileName(_ name: String) -> String {
+    let invalid = CharacterSet(charactersIn: "/\\:?%*|\"<>").union(.newlines).union(.controlCharacters)
+    let cleaned = name.components(separatedBy: invalid).joined(separator: "-")
+        .trimmingCharacters(in: .whitespaces)
+    return cleaned.isEmpty ? "image" : String(cleaned.prefix(80))
+}
+
+/// `folder/name.ext`, or `name 2.ext` and so on when that file exists.
+private func uniqueFileURL(in folder: URL, name: String, extension ext: String) -> URL {
+    var url = folder.appendingPathComponent(name).appendingPathExtension(ext)
+    var counter = 2
+    while FileManager.default.fileExists(atPath: url.path) {
+        url = folder.appendingPathComponent("\(name) \(counter)").appendingPathExtension(ext)
+        counter += 1
+    }
+    return url
+}
+
+/// Image data read from the pasteboard on the main thread, converted to PNG
+/// off it.
+private enum ClipboardImageSource: @unchecked Sendable {
+    case png(Data)
+    case tiff(Data)
+    case image(NSImage)
+
+    /// The image as PNG, or `nil` when it can't be decoded or the paste was cancelled.
+    func pngData() -> Data? {
+        switch self {
+        case .png(let data):
+            return data
+        case .tiff(let data):
+            guard let bitmap = NSBitmapImageRep(data: data), !Task.isCancelled else {
+                return nil
+            }
+            return bitmap.representation(using: .png, properties: [:])
+        case .image(let image):
+            return image.pngData()
+        }
+    }
+}
+
 private extension NSImage {
     func pngData() -> Data? {
         guard let tiffData = tiffRepresentation,
@@ -0,0 +1,204 @@
+import SwiftUI
+
+/// Delay after the last keystroke before the preview is rebuilt.
+private let markdownPreviewDelayNanoseconds: UInt64 = 150_000_000
+
+/// Read-only rendering of a note's Markdown: headings, lists, checklists,
+/// quotes, code blocks and images, with bold, italic, links and inline code
+/// styled. The backend splits the note into blocks; images come from the
+/// shared inline image cache the editor fills. `[[title]]` note links arrive
+/// as `alfredalt://note/<title>` links. `preview.css` in the themes folder
+/// can override fonts, heading sizes, code colours and the content width.
+struct MarkdownPreviewView: View {
+    let text: String
+    let imagesByKey: [String: Data]
+    let fontSize: CGFloat
+    let defaultImageWidth: CGFloat
+    /// Opens clicked note links; without it they go through the system like
+    /// any `alfredalt://` link.
+    var onOpenNoteLink: ((URL) -> Void)?
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var blocks: [PreviewBlockRecord] = []
+
+    var body: some View {
+        ScrollView {
+            VStack(alignment: .leading, spacing: 8) {
+                ForEach(blocks.indices, id: \.self) { index in
+                    blockView(blocks[index])
+                }
+            }
+            .frame(maxWidth: style?.maxWidth.map { CGFloat($0) } ?? .infinity, alignment: .leading)
+            .frame(maxWidth: .infinity, alignment: .leading)
+            .textSelection(.enabled)
+            .padding(.vertical, 6)
+        }
+        .task(id: text) {
+            if !blocks.isEmpty {
+                try? await Task.sleep(nanoseconds: markdownPreviewDelayNanoseconds)
+            }
+            guard !Task.isCancelled else {
+                return
+            }
+            blocks = RustBridgeClient.previewBlocks(note: text)
+        }
+        .environment(\.openURL, OpenURLAction { url in
+            guard let onOpenNoteLink, RustBridgeClient.noteTitle(forDeepLink: url.absoluteString) != nil else {
+                return .systemAction
+            }
+            onOpenNoteLink(url)
+            return .handled
+        })
+        .accessibilityElement(children: .contain)
+        .accessibilityLabel("Rendered preview")
+    }
+
+    private var style: PreviewStyleRecord? {
+        themeManager.previewStyle
+    }
+
+    private var bodyFontSize: CGFloat {
+        style?.fontSize.map { CGFloat($0) } ?? fontSize
+    }
+
+    private func bodyFont(size: CGFloat, weight: Font.Weight = .regular) -> Font {
+        guard let family = style?.fontFamily else {
+            return .system(size: size, weight: weight)
+        }
+        return .custom(family, size: size).weight(weight)
+    }
+
+    private var codeFont: Font {
+        let size = bodyFontSize - 1
+        guard let family = style?.codeFontFamily else {
+            return .system(size: size, design: .monospaced)
+        }
+        return .custom(family, size: size)
+    }
+
+    @ViewBuilder
+    private func blockView(_ block: PreviewBlockRecord) -> some View {
+        let colors = themeManager.colors
+        let fontSize = bodyFontSize
+        switch block.kind {
+        case .paragraph:
+            Text(inlineMarkdown(block.text))
+                .font(bodyFont(size: fontSize))
+                .foregroundStyle(colors.itemTitleText)
+        case .heading:
+            Text(inlineMarkdown(block.text))
+                .font(bodyFont(size: headingFontSize(level: block.level), weight: .bold))
+                .foregroundStyle(colors.itemTitleText)
+                .padding(.top, block.level == 1 ? 6 : 2)
+                .accessibilityAddTraits(.isHeader)
+        case .listItem:
+            HStack(alignment: .firstTextBaseline, spacing: 6) {
+                Text(block.marker)
+                    .monospacedDigit()
+                    .foregroundStyle(colors.itemSubtitleText)
+                Text(inlineMarkdown(block.text))
+                    .foregroundStyle(colors.itemTitleText)
+            }
+            .font(bodyFont(size: fontSize))
+            .padding(.leading, CGFloat(block.level) * 18)
+        case .checklistItem:
+            HStack(alignment: .firstTextBaseline, spacing: 6) {
+                Image(systemName: block.done ? "checkmark.square.fill" : "square")
+                    .foregroundStyle(block.done ? colors.accentColor : colors.itemSubtitleText)
+                    .accessibilityLabel(block.done ? "Done" : "Not done")
+                Text(inlineMarkdown(block.text))
+                    .strikethrough(block.done)
+                    .foregroundStyle(block.done ? colors.itemSubtitleText : colors.itemTitleText)
+            }
+            .font(bodyFont(size: fontSize))
+            .padding(.leading, CGFloat(block.level) * 18)
+        case .quote:
+            HStack(spacing: 8) {
+                RoundedRectangle(cornerRadius: 1.5)
+                    .fill(colors.accentColor.opacity(0.6))
+                    .frame(width: 3)
+                Text(inlineMarkdown(block.text))
+                    .italic()
+                    .foregroundStyle(colors.itemSubtitleText)
+            }
+            .font(bodyFont(size: fontSize))
+            .fixedSize(horizontal: false, vertical: true)
+        case .code:
+            Text(block.text)
+                .font(codeFont)
+                .foregroundStyle(style?.codeColor.map(Color.init(previewRGBA:)) ?? colors.itemTitleText)
+                .frame(maxWidth: .infinity, alignment: .leading)
+                .padding(8)
+                .background(style?.codeBackground.map(Color.init(previewRGBA:)) ?? colors.itemSubtitleText.opacity(0.1))
+                .clipShape(RoundedRectangle(cornerRadius: 6, style: .continuous))
+        case .divider:
+            Rectangle()
+                .fill(themeManager.editorDividerColor)
+                .frame(height: 1)
+                .padding(.vertical, 4)
+                .accessibilityHidden(true)
+        case .image:
+            imageView(key: block.imageKey, width: block.imageWidth, alt: block.text)
+        case .footnote:
+            HStack(alignment: .firstTextBaseline, spacing: 6) {
+                Text(block.marker)
+                    .monospacedDigit()
+                Text(inlineMarkdown(block.text))
+            }
+            .font(bodyFont(size: fontSize - 2))
+            .foregroundStyle(colors.itemSubtitleText)
+            .accessibilityElement(children: .combine)
+            .accessibilityLabel("Footnote \(block.marker) \(block.text)")
+        }
+    }
+
+    @ViewBuilder
+    private func imageView(key: String?, width: UInt32?, alt: String) -> some View {
+        if let key, let data = imagesByKey[key], let image = InlineImageCache.shared.image(forKey: key, data: data) {
+            Image(nsImage: image)
+                .resizable()
+                .scaledToFit()
+                .frame(maxWidth: width.map { CGFloat($0) } ?? defaultImageWidth, alignment: .leading)
+                .clipShape(RoundedRectangle(cornerRadius: 4, style: .continuous))
+                .accessibilityLabel(alt.isEmpty ? "Image" : alt)
+                .help(alt)
+        } else {
+            Label("Missing image", systemImage: "photo")
+                .font(.system(size: 11))
+                .foregroundStyle(themeManager.colors.itemSubtitleText)
+        }
+    }
+
+    private func headingFontSize(level: UInt32) -> CGFloat {
+        let index = Int(min(max(level, 1), 3)) - 1
+        if let headingSizes = style?.headingSizes, index < headingSizes.count, let size = headingSizes[index] {
+            return CGFloat(size)
+        }
+        let fontSize = bodyFontSize
+        switch level {
+        case 1:
+            return fontSize + 9
+        case 2:
+            return fontSize + 5
+        default:
+            return fontSize + 2
+        }
+    }
+
+    /// Bold, italic, links and inline code; text that isn't valid Markdown is shown as typed.
+    private func inlineMarkdown(_ text: String) -> AttributedString {
+        let options = AttributedString.MarkdownParsingOptions(interpretedSyntax: .inlineOnlyPreservingWhitespace)
+        return (try? AttributedString(markdown: text, options: options)) ?? AttributedString(text)
+    }
+}
+
+private extension Color {
+    /// A 0xRRGGBBAA colour from the preview style.
+    init(previewRGBA rgba: UInt32) {
+        self.init(
+            red: Double((rgba >> 24) & 0xFF) / 255,
+            green: Double((rgba >> 16) & 0xFF) / 255,
+            blue: Double((rgba >> 8) & 0xFF) / 255,
+            opacity: Double(rgba & 0xFF) / 255
+        )
+    }
+}
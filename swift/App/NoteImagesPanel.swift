@@ -0,0 +1,197 @@
+import AppKit
+import SwiftUI
+
+private let noteImagesThumbnailSize: CGFloat = 56
+
+/// Editor side panel listing the open note's images in note order. Several can
+/// be selected to delete or export them at once; dragging a row, or Move Up and
+/// Move Down, reorders the images' markers in the note.
+struct NoteImagesPanel: View {
+    @ObservedObject var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var selection = Set<String>()
+    @State private var summary: String?
+    @State private var isConfirmingDelete = false
+
+    private var imageKeys: [String] {
+        viewModel.editorImageKeysInOrder
+    }
+
+    private var bytesByKey: [String: Data] {
+        Dictionary(uniqueKeysWithValues: (viewModel.selectedItem?.images ?? []).map { ($0.imageKey, $0.bytes) })
+    }
+
+    var body: some View {
+        let keys = imageKeys
+        let bytesByKey = bytesByKey
+        VStack(alignment: .leading, spacing: 8) {
+            HStack {
+                Text("Images")
+                    .font(.system(size: 13, weight: .semibold))
+                Spacer()
+                Text(selection.isEmpty ? "\(keys.count)" : "\(selection.count) of \(keys.count)")
+                    .font(.system(size: 11))
+                    .monospacedDigit()
+                    .foregroundStyle(.secondary)
+            }
+
+            if keys.isEmpty {
+                Text("This note has no images.")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+                    .frame(maxWidth: .infinity, maxHeight: .infinity)
+            } else {
+                List(selection: $selection) {
+                    ForEach(Array(keys.enumerated()), id: \.element) { index, key in
+                        row(index: index, key: key, bytes: bytesByKey[key])
+                            .tag(key)
+                    }
+                    .onMove { source, destination in
+                        var order = keys
+                        order.move(fromOffsets: source, toOffset: destination)
+                        Task { await viewModel.reorderImages(order) }
+                    }
+                }
+                .listStyle(.plain)
+                .scrollContentBackground(.hidden)
+                .onDeleteCommand {
+                    if !selection.isEmpty {
+                        isConfirmingDelete = true
+                    }
+                }
+            }
+
+            HStack(spacing: 6) {
+                Button {
+                    move(by: -1)
+                } label: {
+                    Image(systemName: "arrow.up")
+                }
+                .help("Move Up")
+                .accessibilityLabel("Move up")
+                Button {
+                    move(by: 1)
+                } label: {
+                    Image(systemName: "arrow.down")
+                }
+                .help("Move Down")
+                .accessibilityLabel("Move down")
+                Spacer()
+                Button("Export…", action: exportSelection)
+                    .help("Save the selected images, or all of them, to a folder")
+                    .disabled(keys.isEmpty)
+                Button("Delete", role: .destructive) {
+                    isConfirmingDelete = true
+                }
+                .disabled(selection.isEmpty)
+            }
+            .controlSize(.small)
+            .disabled(viewModel.deletedPreviewItem != nil)
+
+            if let summary {
+                HStack(alignment: .top) {
+                    Text(summary)
+                        .font(.system(size: 11))
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                        .textSelection(.enabled)
+                    Spacer()
+                    Button {
+                        self.summary = nil
+                    } label: {
+                        Image(systemName: "xmark")
+                    }
+                    .buttonStyle(.borderless)
+                    .accessibilityLabel("Dismiss")
+                }
+            }
+        }
+        .padding(10)
+        .background(themeManager.colors.editorTextBackground)
+        .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+        .confirmationDialog(
+            selection.count == 1 ? "Delete 1 image?" : "Delete \(selection.count) images?",
+            isPresented: $isConfirmingDelete
+        ) {
+            Button("Delete", role: .destructive) {
+                let keys = selection
+                selection = []
+                Task { await viewModel.removeImages(imageKeys: keys) }
+            }
+        } message: {
+            Text("The images and their places in the note are removed. Earlier versions keep them.")
+        }
+        .onChange(of: keys) { _, keys in
+            selection.formIntersection(keys)
+        }
+    }
+
+    private func row(index: Int, key: String, bytes: Data?) -> some View {
+        HStack(spacing: 8) {
+            Group {
+                if let bytes, let image = InlineImageCache.shared.image(forKey: key, data: bytes) {
+                    Image(nsImage: image)
+                        .resizable()
+                        .scaledToFill()
+                } else {
+                    Color.secondary.opacity(0.15)
+                }
+            }
+            .frame(width: noteImagesThumbnailSize, height: noteImagesThumbnailSize)
+            .clipShape(RoundedRectangle(cornerRadius: 5, style: .continuous))
+
+            VStack(alignment: .leading, spacing: 2) {
+                Text("Image \(index + 1)")
+                    .font(.system(size: 12, weight: .medium))
+                if let bytes {
+                    Text(ByteCountFormatter.string(fromByteCount: Int64(bytes.count), countStyle: .file))
+                        .font(.system(size: 11))
+                        .foregroundStyle(.secondary)
+                }
+            }
+        }
+        .padding(.vertical, 2)
+        .accessibilityElement(children: .combine)
+    }
+
+    /// Moves the selected images one place up or down, keeping their order.
+    private func move(by offset: Int) {
+        var order = imageKeys
+        let selectedIndices = order.indices.filter { selection.contains(order[$0]) }
+        guard let first = selectedIndices.first, let last = selectedIndices.last else {
+            return
+        }
+        if offset < 0 {
+            guard first > 0 else {
+                return
+            }
+            for index in selectedIndices {
+                order.swapAt(index, index - 1)
+            }
+        } else {
+            guard last < order.count - 1 else {
+                return
+            }
+            for index in selectedIndices.reversed() {
+                order.swapAt(index, index + 1)
+            }
+        }
+        Task { await viewModel.reorderImages(order) }
+    }
+
+    private func exportSelection() {
+        let keys = imageKeys.filter { selection.isEmpty || selection.contains($0) }
+        let panel = NSOpenPanel()
+        panel.title = "Export Images"
+        panel.message = keys.count == 1 ? "Choose a folder for the image." : "Choose a folder for the \(keys.count) images."
+        panel.prompt = "Export"
+        panel.canChooseDirectories = true
+        panel.canChooseFiles = false
+        panel.canCreateDirectories = true
+        panel.allowsMultipleSelection = false
+
+        guard panel.runModal() == .OK, let folderURL = panel.url else {
+            return
+        }
+        summary = viewModel.exportImages(imageKeys: keys, to: folderURL)
+    }
+}
@@ -0,0 +1,94 @@
+import SwiftUI
+
+/// Collapsible editor section for the open note's launcher subtitle and the
+/// extra keywords it is found by. Changes are saved with the note on Return
+/// or when a field loses focus.
+struct NoteMetadataSection: View {
+    @ObservedObject var viewModel: LauncherViewModel
+    let item: EditableItemRecord
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var isExpanded = false
+    @State private var subtitle = ""
+    @State private var keywords = ""
+    @FocusState private var focusedField: Field?
+
+    private enum Field {
+        case subtitle
+        case keywords
+    }
+
+    var body: some View {
+        DisclosureGroup(isExpanded: $isExpanded) {
+            Grid(alignment: .leading, horizontalSpacing: 8, verticalSpacing: 6) {
+                GridRow {
+                    Text("Subtitle")
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    TextField("Shown under the title in the launcher", text: $subtitle)
+                        .textFieldStyle(.roundedBorder)
+                        .focused($focusedField, equals: .subtitle)
+                        .onSubmit(commit)
+                }
+                GridRow {
+                    Text("Keywords")
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                    TextField("Extra words this note is found by", text: $keywords)
+                        .textFieldStyle(.roundedBorder)
+                        .focused($focusedField, equals: .keywords)
+                        .onSubmit(commit)
+                }
+            }
+            .font(.system(size: 12))
+            .padding(.top, 6)
+        } label: {
+            HStack(spacing: 6) {
+                Text("Subtitle & Keywords")
+                    .font(.system(size: 12, weight: .medium))
+                if !item.subtitle.isEmpty {
+                    Text(item.subtitle)
+                        .font(.system(size: 12))
+                        .foregroundStyle(themeManager.colors.itemSubtitleText)
+                        .lineLimit(1)
+                }
+            }
+        }
+        .onAppear(perform: reset)
+        .onChange(of: item.id) { _, _ in
+            reset()
+        }
+        .onChange(of: item.subtitle) { _, _ in
+            if focusedField == nil {
+                reset()
+            }
+        }
+        .onChange(of: item.keywords) { _, _ in
+            if focusedField == nil {
+                reset()
+            }
+        }
+        .onChange(of: focusedField) { oldValue, newValue in
+            if oldValue != nil, newValue != oldValue {
+                commit()
+            }
+        }
+    }
+
+    private func reset() {
+        subtitle = item.subtitle
+        keywords = item.keywords
+    }
+
+    private func commit() {
+        let subtitle = subtitle.trimmingCharacters(in: .whitespacesAndNewlines)
+        let keywords = keywords.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard subtitle != item.subtitle || keywords != item.keywords else {
+            return
+        }
+        let itemId = item.id
+        Task {
+            guard viewModel.selectedItem?.id == itemId else {
+                return
+            }
+            await viewModel.saveItemMetadata(subtitle: subtitle, keywords: keywords)
+        }
+    }
+}
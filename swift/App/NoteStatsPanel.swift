@@ -0,0 +1,206 @@
+import Charts
+import SwiftUI
+
+private let heatmapWeeks = 20
+private let heatmapCellSize: CGFloat = 11
+private let heatmapCellSpacing: CGFloat = 3
+private let staleNoteDays = 90
+
+/// Collapsible editor panel with word counts, a save-activity heatmap and a
+/// note-length chart, all derived from the item's version history.
+struct NoteStatsPanel: View {
+    let itemId: Int64
+    /// Changes whenever the note is saved so the panel can refresh.
+    let revision: UInt64
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var isExpanded = false
+    @State private var stats: ItemStatsRecord?
+    @State private var errorMessage: String?
+
+    private static let dayKeyFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.calendar = Calendar(identifier: .gregorian)
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.dateFormat = "yyyy-MM-dd"
+        return formatter
+    }()
+
+    private static let dateFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.dateStyle = .medium
+        formatter.timeStyle = .none
+        return formatter
+    }()
+
+    var body: some View {
+        DisclosureGroup(isExpanded: $isExpanded) {
+            content
+                .padding(.top, 8)
+        } label: {
+            HStack(spacing: 6) {
+                Text("Statistics")
+                    .font(.system(size: 12, weight: .medium))
+                if let stats {
+                    Text("\(stats.wordCount) words")
+                        .font(.system(size: 12))
+                        .foregroundStyle(.secondary)
+                }
+                if let staleDays {
+                    Text("• not edited in \(staleDays) days")
+                        .font(.system(size: 12))
+                        .foregroundStyle(themeManager.colors.errorColor)
+                }
+            }
+        }
+        .onAppear(perform: reload)
+        .onChange(of: itemId) { _, _ in
+            reload()
+        }
+        .onChange(of: revision) { _, _ in
+            if isExpanded {
+                reload()
+            }
+        }
+        .onChange(of: isExpanded) { _, expanded in
+            if expanded {
+                reload()
+            }
+        }
+    }
+
+    @ViewBuilder
+    private var content: some View {
+        if let errorMessage {
+            Text(errorMessage)
+                .font(.system(size: 12))
+                .foregroundStyle(themeManager.colors.errorColor)
+        } else if let stats {
+            VStack(alignment: .leading, spacing: 12) {
+                HStack(spacing: 18) {
+                    statLabel("Words", "\(stats.wordCount)")
+                    statLabel("Characters", "\(stats.charCount)")
+                    statLabel("Versions", "\(stats.versionCount)")
+                    statLabel("Created", formattedDate(stats.createdAtUnixSeconds))
+                    statLabel("Last edited", formattedDate(stats.updatedAtUnixSeconds))
+                }
+
+                HStack(alignment: .top, spacing: 24) {
+                    VStack(alignment: .leading, spacing: 4) {
+                        Text("Edits per day")
+                            .font(.system(size: 11))
+                            .foregroundStyle(.secondary)
+                        heatmap(stats)
+                    }
+
+                    VStack(alignment: .leading, spacing: 4) {
+                        Text("Note length")
+                            .font(.system(size: 11))
+                            .foregroundStyle(.secondary)
+                        growthChart(stats)
+                    }
+                }
+            }
+        } else {
+            ProgressView()
+                .controlSize(.small)
+        }
+    }
+
+    private func statLabel(_ title: String, _ value: String) -> some View {
+        VStack(alignment: .leading, spacing: 2) {
+            Text(title)
+                .font(.system(size: 10))
+                .foregroundStyle(.secondary)
+            Text(value)
+                .font(.system(size: 13, weight: .medium))
+                .foregroundStyle(themeManager.colors.itemTitleText)
+        }
+    }
+
+    private func heatmap(_ stats: ItemStatsRecord) -> some View {
+        let savesByDay = Dictionary(uniqueKeysWithValues: stats.days.map { ($0.date, $0.saves) })
+        let maxSaves = max(savesByDay.values.max() ?? 0, 1)
+        let calendar = Calendar.current
+        let today = calendar.startOfDay(for: Date())
+        let weekdayOffset = (calendar.component(.weekday, from: today) - calendar.firstWeekday + 7) % 7
+        let firstDay = calendar.date(byAdding: .day, value: -((heatmapWeeks - 1) * 7 + weekdayOffset), to: today) ?? today
+
+        return HStack(alignment: .top, spacing: heatmapCellSpacing) {
+            ForEach(0..<heatmapWeeks, id: \.self) { week in
+                VStack(spacing: heatmapCellSpacing) {
+                    ForEach(0..<7, id: \.self) { weekday in
+                        let date = calendar.date(byAdding: .day, value: week * 7 + weekday, to: firstDay) ?? firstDay
+                        let key = Self.dayKeyFormatter.string(from: date)
+                        let saves = savesByDay[key] ?? 0
+                        RoundedRectangle(cornerRadius: 2, style: .continuous)
+                            .fill(heatmapColor(saves: saves, maxSaves: maxSaves, isFuture: date > today))
+                            .frame(width: heatmapCellSize, height: heatmapCellSize)
+                            .help("\(Self.dateFormatter.string(from: date)): \(saves) saves")
+                    }
+                }
+            }
+        }
+    }
+
+    private func heatmapColor(saves: UInt32, maxSaves: UInt32, isFuture: Bool) -> Color {
+        if isFuture {
+            return Color.clear
+        }
+        guard saves > 0 else {
+            return Color(nsColor: .quaternaryLabelColor)
+        }
+        let intensity = 0.3 + 0.7 * Double(saves) / Double(maxSaves)
+        return themeManager.colors.accentColor.opacity(intensity)
+    }
+
+    @ViewBuilder
+    private func growthChart(_ stats: ItemStatsRecord) -> some View {
+        let points = stats.days.compactMap { day -> (Date, UInt64)? in
+            guard let date = Self.dayKeyFormatter.date(from: day.date) else {
+                return nil
+            }
+            return (date, day.length)
+        }
+
+        if points.count < 2 {
+            Text("Not enough history yet.")
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+                .frame(width: 260, height: 7 * (heatmapCellSize + heatmapCellSpacing), alignment: .topLeading)
+        } else {
+            Chart(points, id: \.0) { point in
+                LineMark(
+                    x: .value("Day", point.0),
+                    y: .value("Characters", point.1)
+                )
+                .foregroundStyle(themeManager.colors.accentColor)
+            }
+            .chartXAxis(.hidden)
+            .frame(width: 260, height: 7 * (heatmapCellSize + heatmapCellSpacing))
+        }
+    }
+
+    private var staleDays: Int? {
+        guard let updated = stats?.updatedAtUnixSeconds, updated > 0 else {
+            return nil
+        }
+        let days = Int(Date().timeIntervalSince1970 - TimeInterval(updated)) / 86_400
+        return days >= staleNoteDays ? days : nil
+    }
+
+    private func formattedDate(_ unixSeconds: Int64) -> String {
+        guard unixSeconds > 0 else {
+            return "—"
+        }
+        return Self.dateFormatter.string(from: Date(timeIntervalSince1970: TimeInterval(unixSeconds)))
+    }
+
+    private func reload() {
+        do {
+            stats = try RustBridgeClient.itemStats(itemId: itemId)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
@@ -0,0 +1,67 @@
+import SwiftUI
+
+/// Editor control that starts or stops the time-tracking timer on a note and
+/// shows the time tracked on it, counting up while its timer runs.
+struct NoteTimerButton: View {
+    let itemId: Int64
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var runningSince: Date?
+    /// Tracked seconds from finished timer runs.
+    @State private var trackedSeconds: UInt64 = 0
+    @State private var errorMessage: String?
+
+    var body: some View {
+        HStack(spacing: 4) {
+            TimelineView(.periodic(from: .now, by: 1)) { context in
+                Text(TimeReportView.duration(totalSeconds(at: context.date)))
+                    .monospacedDigit()
+                    .foregroundStyle(runningSince == nil ? themeManager.colors.itemSubtitleText : themeManager.colors.accentColor)
+            }
+            Button(runningSince == nil ? "Start Timer" : "Stop Timer", action: toggle)
+                .buttonStyle(.link)
+                .help(errorMessage ?? "Track time spent on this note; see it in the Timesheet")
+        }
+        .onAppear(perform: reload)
+        .onChange(of: itemId) { _, _ in
+            reload()
+        }
+    }
+
+    private func totalSeconds(at date: Date) -> UInt64 {
+        guard let runningSince else {
+            return trackedSeconds
+        }
+        return trackedSeconds + UInt64(max(0, date.timeIntervalSince(runningSince)))
+    }
+
+    private func toggle() {
+        do {
+            if runningSince == nil {
+                try RustBridgeClient.startTimer(itemId: itemId)
+            } else {
+                try RustBridgeClient.stopTimer()
+            }
+            reload()
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func reload() {
+        do {
+            let timer = try RustBridgeClient.runningTimer()
+            let total = try RustBridgeClient.trackedSeconds(itemId: itemId)
+            if let timer, timer.itemId == itemId {
+                let started = Date(timeIntervalSince1970: TimeInterval(timer.startedAtUnixSeconds))
+                runningSince = started
+                trackedSeconds = total - min(total, UInt64(max(0, Date().timeIntervalSince(started))))
+            } else {
+                runningSince = nil
+                trackedSeconds = total
+            }
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
@@ -0,0 +1,77 @@
+import AppKit
+
+/// Tracks whether the app is hidden, switches the backend into power saving while
+/// it is (when enabled), and counts idle wakeups so the hidden state can be audited.
+/// Used from the main thread only.
+final class PowerSavingMonitor: ObservableObject {
+    static let shared = PowerSavingMonitor()
+
+    private static let settingsFileName = "power-saving-settings.json"
+
+    private struct PersistedSettings: Codable {
+        let enabled: Bool
+    }
+
+    @Published var isEnabled: Bool {
+        didSet {
+            guard isEnabled != oldValue else {
+                return
+            }
+            _ = SettingsStore.shared.saveJSON(PersistedSettings(enabled: isEnabled), fileName: Self.settingsFileName)
+            applyPowerSaving()
+        }
+    }
+    @Published private(set) var isAppHidden = false
+    /// Main run loop wakeups counted while the app was hidden.
+    @Published private(set) var hiddenMainThreadWakeups: UInt64 = 0
+    @Published private(set) var backendStats: IdleStatsRecord?
+
+    private var pendingWakeups: UInt64 = 0
+    private var wakeupObserver: CFRunLoopObserver?
+    private var notificationObservers: [NSObjectProtocol] = []
+
+    private init() {
+        isEnabled = SettingsStore.shared.loadJSON(PersistedSettings.self, fileName: Self.settingsFileName)?.enabled ?? false
+
+        let center = NotificationCenter.default
+        notificationObservers = [
+            center.addObserver(forName: NSApplication.didHideNotification, object: nil, queue: .main) { [weak self] _ in
+                self?.setAppHidden(true)
+            },
+            center.addObserver(forName: NSApplication.didUnhideNotification, object: nil, queue: .main) { [weak self] _ in
+                self?.setAppHidden(false)
+            },
+        ]
+    }
+
+    /// Re-reads the backend counters and publishes the wakeups counted while hidden.
+    func refreshStats() {
+        hiddenMainThreadWakeups = pendingWakeups
+        backendStats = RustBridgeClient.idleStats()
+    }
+
+    private func setAppHidden(_ hidden: Bool) {
+        guard hidden != isAppHidden else {
+            return
+        }
+        isAppHidden = hidden
+
+        if hidden {
+            // Counting must not publish, or the observer would cause the wakeups it counts.
+            let observer = CFRunLoopObserverCreateWithHandler(nil, CFRunLoopActivity.afterWaiting.rawValue, true, 0) { [weak self] _, _ in
+                self?.pendingWakeups += 1
+            }
+            CFRunLoopAddObserver(CFRunLoopGetMain(), observer, .commonModes)
+            wakeupObserver = observer
+        } else if let wakeupObserver {
+            CFRunLoopRemoveObserver(CFRunLoopGetMain(), wakeupObserver, .commonModes)
+            self.wakeupObserver = nil
+            hiddenMainThreadWakeups = pendingWakeups
+        }
+        applyPowerSaving()
+    }
+
+    private func applyPowerSaving() {
+        RustBridgeClient.updatePowerSaving(isEnabled && isAppHidden)
+    }
+}
@@ -0,0 +1,122 @@
+import SwiftUI
+
+/// Delay before the selected note is loaded, so arrowing through results
+/// doesn't fetch every item passed on the way.
+private let previewLoadDelayNanoseconds: UInt64 = 120_000_000
+
+/// Read-only rendering of the selected launcher result's note and images. Its
+/// checklist entries can be checked off without opening the editor.
+struct ResultPreviewPane: View {
+    let itemId: Int64?
+    let searchQuery: String
+    /// Checks or unchecks checklist entry `index` of an item; returns whether it changed.
+    let onToggleChecklistEntry: (Int64, Int) async -> Bool
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var item: EditableItemRecord?
+    @State private var checklist: [ChecklistEntryRecord] = []
+    @State private var loadError: String?
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            if let item, item.id == itemId {
+                Text(item.title)
+                    .font(themeManager.metrics.font(size: themeManager.itemTitleFontSize - 4, weight: .semibold))
+                    .foregroundStyle(themeManager.colors.itemTitleText)
+                    .lineLimit(1)
+
+                if !checklist.isEmpty {
+                    checklistView(itemId: item.id)
+                }
+
+                InlineImageTextEditor(
+                    text: .constant(item.note),
+                    imagesByKey: Dictionary(uniqueKeysWithValues: item.images.map { ($0.imageKey, $0.bytes) }),
+                    isEditable: false,
+                    searchQuery: searchQuery,
+                    highlightSearchMatches: themeManager.editorSearchHighlightsEnabled,
+                    dividerColor: themeManager.editorDividerColor,
+                    dividerTopMargin: themeManager.editorDividerTopMargin,
+                    dividerBottomMargin: themeManager.editorDividerBottomMargin,
+                    defaultImageWidth: 220,
+                    fontSize: 13
+                )
+            } else if let loadError {
+                placeholder(loadError)
+            } else if itemId == nil {
+                placeholder("No preview")
+            } else {
+                ProgressView()
+                    .controlSize(.small)
+                    .frame(maxWidth: .infinity, maxHeight: .infinity)
+            }
+        }
+        .padding(10)
+        .frame(maxWidth: .infinity, maxHeight: .infinity, alignment: .topLeading)
+        .background(themeManager.colors.editorTextBackground)
+        .clipShape(RoundedRectangle(cornerRadius: themeManager.metrics.editorCornerRadius, style: .continuous))
+        .task(id: itemId) {
+            await load(after: previewLoadDelayNanoseconds)
+        }
+    }
+
+    private func checklistView(itemId: Int64) -> some View {
+        VStack(alignment: .leading, spacing: 4) {
+            ForEach(checklist.indices, id: \.self) { index in
+                Button {
+                    Task {
+                        if await onToggleChecklistEntry(itemId, index) {
+                            await load(after: 0)
+                        }
+                    }
+                } label: {
+                    HStack(alignment: .firstTextBaseline, spacing: 6) {
+                        Image(systemName: checklist[index].done ? "checkmark.square.fill" : "square")
+                            .foregroundStyle(checklist[index].done ? themeManager.colors.accentColor : themeManager.colors.itemSubtitleText)
+                        Text(checklist[index].text)
+                            .strikethrough(checklist[index].done)
+                            .foregroundStyle(themeManager.colors.itemTitleText)
+                            .lineLimit(1)
+                    }
+                    .font(.system(size: 12))
+                    .contentShape(Rectangle())
+                }
+                .buttonStyle(.plain)
+            }
+        }
+    }
+
+    private func placeholder(_ message: String) -> some View {
+        Text(message)
+            .font(.system(size: 12))
+            .foregroundStyle(themeManager.colors.itemSubtitleText)
+            .frame(maxWidth: .infinity, maxHeight: .infinity)
+    }
+
+    private func load(after delayNanoseconds: UInt64) async {
+        loadError = nil
+        guard let itemId else {
+            item = nil
+            checklist = []
+            return
+        }
+        if delayNanoseconds > 0 {
+            try? await Task.sleep(nanoseconds: delayNanoseconds)
+        }
+        guard !Task.isCancelled else {
+            return
+        }
+
+        do {
+            let (loaded, entries) = try await Task.detached(priority: .userInitiated) {
+                (try RustBridgeClient.fetch(itemId: itemId), try RustBridgeClient.checklistEntries(itemId: itemId))
+            }.value
+            guard !Task.isCancelled else {
+                return
+            }
+            item = loaded
+            checklist = entries
+        } catch {
+            loadError = error.localizedDescription
+        }
+    }
+}
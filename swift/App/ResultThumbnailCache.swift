@@ -0,0 +1,65 @@
+import AppKit
+
+/// Downscaled square thumbnails keyed by item and image key. Image keys are
+/// never reused for different bytes, so entries don't need invalidation.
+final class ResultThumbnailCache {
+    /// Result-row thumbnails.
+    static let shared = ResultThumbnailCache(pixelSize: 64, countLimit: 300)
+    /// Gallery grid cells.
+    static let gallery = ResultThumbnailCache(pixelSize: 200, countLimit: 200)
+
+    private let cache = NSCache<NSString, NSImage>()
+    private let pixelSize: CGFloat
+
+    private init(pixelSize: CGFloat, countLimit: Int) {
+        self.pixelSize = pixelSize
+        cache.countLimit = countLimit
+    }
+
+    func cachedThumbnail(itemId: Int64, imageKey: String) -> NSImage? {
+        cache.object(forKey: cacheKey(itemId: itemId, imageKey: imageKey))
+    }
+
+    func thumbnail(itemId: Int64, imageKey: String) async -> NSImage? {
+        let key = cacheKey(itemId: itemId, imageKey: imageKey)
+        if let cached = cache.object(forKey: key) {
+            return cached
+        }
+
+        let pixelSize = pixelSize
+        let image = await Task.detached(priority: .utility) { () -> NSImage? in
+            guard let data = try? RustBridgeClient.itemImage(itemId: itemId, imageKey: imageKey),
+                  let original = NSImage(data: data) else {
+                return nil
+            }
+            return Self.downscaled(original, to: pixelSize)
+        }.value
+
+        if let image {
+            cache.setObject(image, forKey: key)
+        }
+        return image
+    }
+
+    private func cacheKey(itemId: Int64, imageKey: String) -> NSString {
+        "\(itemId)/\(imageKey)" as NSString
+    }
+
+    private static func downscaled(_ image: NSImage, to side: CGFloat) -> NSImage {
+        let size = image.size
+        guard size.width > 0, size.height > 0 else {
+            return image
+        }
+
+        // Aspect-fill a square so rows line up regardless of image shape.
+        let scale = max(side / size.width, side / size.height)
+        let drawSize = NSSize(width: size.width * scale, height: size.height * scale)
+        let origin = NSPoint(x: (side - drawSize.width) / 2, y: (side - drawSize.height) / 2)
+        let thumbnail = NSImage(size: NSSize(width: side, height: side))
+        thumbnail.lockFocus()
+        NSGraphicsContext.current?.imageInterpolation = .high
+        image.draw(in: NSRect(origin: origin, size: drawSize), from: .zero, operation: .copy, fraction: 1)
+        thumbnail.unlockFocus()
+        return thumbnail
+    }
+}
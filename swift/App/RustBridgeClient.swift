@@ -5,10 +5,37 @@ enum RustBridgeClient {
         backendVersion()
     }
 
+    static func warmUp() throws {
+        setActivityOrigin(origin: .gui)
+        try warmUpStore()
+        try connectForgeAccounts(accounts: try forgeAccounts().filter { !$0.token.isEmpty })
+        try connectTrackerAccounts(accounts: try trackerAccounts().filter { !$0.token.isEmpty })
+    }
+
+    static func storeIsReady() -> Bool {
+        isStoreReady()
+    }
+
+    static func noteStoreDisabled() -> Bool {
+        isLauncherOnly()
+    }
+
+    static func launcherOnlyAtNextLaunch() throws -> Bool {
+        try loadLauncherOnly()
+    }
+
+    static func updateLauncherOnly(_ enabled: Bool) throws {
+        try saveLauncherOnly(enabled: enabled)
+    }
+
     static func search(query: String, limit: UInt32 = 8) throws -> [SearchResultRecord] {
         try searchItems(query: query, limit: limit)
     }
 
+    static func runCommand(key: String) throws {
+        try runSystemCommand(commandKey: key)
+    }
+
     static func create(title: String) throws -> Int64 {
         try createItem(title: title)
     }
@@ -17,14 +44,169 @@ enum RustBridgeClient {
         try getItem(itemId: itemId)
     }
 
-    static func save(itemId: Int64, note: String, images: [NoteImageRecord]) throws {
-        try saveItem(itemId: itemId, note: note, images: images)
+    static func browse(query: String, sort: BrowseSortOrder) throws -> [SearchResultRecord] {
+        try browseItems(query: query, sort: sort)
+    }
+
+    static func recentItems(limit: UInt32 = 8) throws -> [SearchResultRecord] {
+        try listRecentItems(limit: limit)
+    }
+
+    static func itemStats(itemId: Int64) throws -> ItemStatsRecord {
+        try getItemStats(itemId: itemId)
+    }
+
+    static func itemVersions(itemId: Int64) throws -> [NoteVersionRecord] {
+        try listItemVersions(itemId: itemId)
+    }
+
+    static func versionText(itemId: Int64, index: UInt32) throws -> String {
+        try getItemVersionText(itemId: itemId, index: index)
+    }
+
+    static func diffVersions(itemId: Int64, oldIndex: UInt32, newIndex: UInt32) throws -> [DiffSegmentRecord] {
+        try diffItemVersions(itemId: itemId, oldIndex: oldIndex, newIndex: newIndex)
+    }
+
+    static func setSecure(itemId: Int64, secure: Bool) throws {
+        try setItemSecure(itemId: itemId, secure: secure)
+    }
+
+    static func isSecureItemsLocked() throws -> Bool {
+        try isLocked()
+    }
+
+    static func hasAppLockKey() throws -> Bool {
+        try hasLockKey()
+    }
+
+    static func unlock(key: Data) throws {
+        try unlockWithKey(key: key)
+    }
+
+    static func lockSecureItems() {
+        lock()
+    }
+
+    static func enrollLockKey(_ key: Data) throws {
+        try setLockKey(key: key)
+    }
+
+    static func removeAppLockKey() throws {
+        try removeLockKey()
+    }
+
+    static func searchMatchesAnyTerm() throws -> Bool {
+        try loadSearchMatchAny()
+    }
+
+    static func updateSearchMatchesAnyTerm(_ matchAny: Bool) throws {
+        try saveSearchMatchAny(matchAny: matchAny)
+    }
+
+    static func rankingScript() throws -> String {
+        try loadRankingScript()
+    }
+
+    static func updateRankingScript(_ source: String) throws {
+        try saveRankingScript(source: source)
+    }
+
+    /// The saved accounts with their tokens from the Keychain.
+    static func forgeAccounts() throws -> [ForgeAccountRecord] {
+        AccountTokens.forgeAccounts(try loadForgeAccounts())
+    }
+
+    static func updateForgeAccounts(_ accounts: [ForgeAccountRecord]) throws {
+        try saveForgeAccounts(accounts: accounts)
+        try AccountTokens.storeForgeTokens(accounts)
+    }
+
+    /// The saved workspaces with their tokens from the Keychain.
+    static func trackerAccounts() throws -> [TrackerAccountRecord] {
+        AccountTokens.trackerAccounts(try loadTrackerAccounts())
+    }
+
+    static func updateTrackerAccounts(_ accounts: [TrackerAccountRecord]) throws {
+        try saveTrackerAccounts(accounts: accounts)
+        try AccountTokens.storeTrackerTokens(accounts)
+    }
+
+    static func mirrorExcludedTags() throws -> [String] {
+        try loadMirrorExcludedTags()
+    }
+
+    static func saveExcludedTags(_ tags: [String]) throws {
+        try saveMirrorExcludedTags(tags: tags)
+    }
+
+    static func obsidianVaultMode() throws -> Bool {
+        try loadObsidianVaultMode()
+    }
+
+    static func updateObsidianVaultMode(_ enabled: Bool) throws {
+        try saveObsidianVaultMode(enabled: enabled)
+    }
+
+    static func importVaultChanges() throws -> UInt32 {
+        try importObsidianVaultChanges()
+    }
+
+    static func importNotion(path: String) throws -> NotionImportRecord {
+        try importNotionExport(path: path)
+    }
+
+    static func saveReadLater(url: String) throws -> ReadLaterRecord {
+        try saveReadLaterArticle(url: url)
+    }
+
+    static func performProviderAction(commandKey: String) throws -> ProviderOutcomeRecord {
+        try alfred_alt.performProviderAction(commandKey: commandKey)
+    }
+
+    static func linkMarkdown(url: String) throws -> String {
+        try fetchLinkMarkdown(url: url)
+    }
+
+    static func retryReadLater() throws -> UInt32 {
+        try retryReadLaterQueue()
+    }
+
+    static func storageReport() throws -> StorageReportRecord {
+        try getStorageReport(largestLimit: nil)
+    }
+
+    static func saveBudget(bytes: UInt64?) throws {
+        try saveStorageBudget(budgetBytes: bytes)
+    }
+
+    static func markOpened(itemId: Int64) throws {
+        try markItemOpened(itemId: itemId)
+    }
+
+    @discardableResult
+    static func save(
+        itemId: Int64,
+        note: String,
+        images: [NoteImageRecord],
+        expectedRevision: UInt64? = nil,
+        metadata: ItemMetadataRecord? = nil
+    ) throws -> UInt64 {
+        try saveItem(itemId: itemId, note: note, images: images, expectedRevision: expectedRevision, metadata: metadata)
+    }
+
+    static func mergeEdits(base: String, ours: String, theirs: String) -> MergedNoteRecord {
+        mergeNoteEdits(base: base, ours: ours, theirs: theirs)
     }
 
     static func rename(itemId: Int64, title: String) throws {
         try renameItem(itemId: itemId, title: title)
     }
 
+    static func setPinned(itemId: Int64, pinned: Bool) throws {
+        try setItemPinned(itemId: itemId, pinned: pinned)
+    }
+
     static func loadJsonStorageDirectoryPath() throws -> String {
         try loadJsonStoragePath()
     }
@@ -33,10 +215,28 @@ enum RustBridgeClient {
         try saveJsonStoragePath(path: path)
     }
 
-    static func delete(itemId: Int64) throws {
+    /// Returns the archive key that restores the item.
+    @discardableResult
+    static func delete(itemId: Int64) throws -> String {
         try deleteItem(itemId: itemId)
     }
 
+    static func delete(itemIds: [Int64]) throws {
+        try deleteItems(itemIds: itemIds)
+    }
+
+    static func tag(itemIds: [Int64], tag: String) throws -> UInt32 {
+        try tagItems(itemIds: itemIds, tag: tag)
+    }
+
+    static func exportJSON(itemIds: [Int64]) throws -> String {
+        try exportItemsJson(itemIds: itemIds)
+    }
+
+    static func exportCSV() throws -> String {
+        try exportItemsCsv()
+    }
+
     static func listDeleted(limit: UInt32 = 50) throws -> [DeletedItemRecord] {
         try listDeletedItems(limit: limit)
     }
@@ -56,4 +256,219 @@ enum RustBridgeClient {
     static func getJsonPath(itemId: Int64) throws -> String {
         try getItemJsonPath(itemId: itemId)
     }
+
+    static func plainText(itemId: Int64) throws -> String {
+        try getItemPlainText(itemId: itemId)
+    }
+
+    static func updatePowerSaving(_ enabled: Bool) {
+        setPowerSaving(enabled: enabled)
+    }
+
+    static func idleStats() -> IdleStatsRecord {
+        getIdleStats()
+    }
+
+    static func deepLink(itemId: Int64) throws -> String {
+        try getItemDeepLink(itemId: itemId)
+    }
+
+    static func itemId(forDeepLink link: String) -> Int64? {
+        itemIdForDeepLink(link: link)
+    }
+
+    static func noteLink(title: String) -> URL? {
+        URL(string: noteTitleDeepLink(title: title))
+    }
+
+    static func noteTitle(forDeepLink link: String) -> String? {
+        noteTitleForDeepLink(link: link)
+    }
+
+    static func itemId(forNoteTitle title: String) throws -> Int64? {
+        try itemIdForNoteTitle(title: title)
+    }
+
+    static func noteLinkQuery(beforeCaret text: String) -> String? {
+        pendingNoteLinkQuery(textBeforeCaret: text)
+    }
+
+    static func noteLinkSuggestions(query: String, limit: Int) throws -> [NoteTitleRecord] {
+        try suggestNoteLinkTitles(query: query, limit: UInt32(limit))
+    }
+
+    static func markdown(itemId: Int64) throws -> ItemMarkdownRecord {
+        try getItemMarkdown(itemId: itemId)
+    }
+
+    static func itemImage(itemId: Int64, imageKey: String) throws -> Data {
+        try getItemImage(itemId: itemId, imageKey: imageKey)
+    }
+
+    static func outline(note: String) -> [OutlineEntryRecord] {
+        noteOutline(note: note)
+    }
+
+    static func previewBlocks(note: String) -> [PreviewBlockRecord] {
+        notePreviewBlocks(note: note)
+    }
+
+    static func moveImage(note: String, imageIndex: Int, toLine line: Int) throws -> String {
+        try moveNoteImageToLine(note: note, imageIndex: UInt32(imageIndex), targetLine: UInt32(line))
+    }
+
+    static func hashtags(inNote text: String) -> [HashtagRecord] {
+        noteHashtags(text: text)
+    }
+
+    static func tagSearch(for tag: String) -> String {
+        tagSearchQuery(tag: tag)
+    }
+
+    static func transformedText(_ text: String, with transform: TextTransformKind) -> String {
+        transformNoteText(text: text, transform: transform)
+    }
+
+    static func listContinuation(lineBeforeCaret line: String) -> ListContinuationRecord? {
+        continueMarkdownList(lineBeforeCaret: line)
+    }
+
+    static func selectionWrap(_ selected: String, marker: String, toggle: Bool) throws -> SelectionWrapRecord {
+        try wrapMarkdownSelection(selected: selected, marker: marker, toggle: toggle)
+    }
+
+    static func previewStyle(source: String) -> PreviewStyleRecord {
+        parsePreviewStyle(source: source)
+    }
+
+    static func tableLayouts(in text: String) -> [TableLayoutRecord] {
+        noteTableLayouts(text: text)
+    }
+
+    static func tableEdit(lines: [String], row: UInt32, caret: UInt32, command: TableCommandKind) -> TableEditRecord? {
+        editMarkdownTable(lines: lines, row: row, caret: caret, command: command)
+    }
+
+    static func itemImageKeys(itemId: Int64) throws -> [String] {
+        try listItemImageKeys(itemId: itemId)
+    }
+
+    static func imageLimits() throws -> ImageLimitsRecord {
+        try loadImageLimits()
+    }
+
+    static func updateImageLimits(maxImagesPerNote: UInt32?, maxImageBytes: UInt64?) throws -> ImageLimitsRecord {
+        try saveImageLimits(maxImagesPerNote: maxImagesPerNote, maxImageBytes: maxImageBytes)
+    }
+
+    static func storedImages() throws -> [StoredImageRecord] {
+        try listStoredImages()
+    }
+
+    static func replaceImages(_ replacements: [ImageReplacementRecord]) throws -> UInt32 {
+        try replaceStoredImages(replacements: replacements)
+    }
+
+    static func imagesWithoutText(limit: UInt32) throws -> [StoredImageRecord] {
+        try listImagesWithoutText(limit: limit)
+    }
+
+    static func saveRecognizedText(_ texts: [ImageTextRecord]) throws -> UInt32 {
+        try saveImageTexts(texts: texts)
+    }
+
+    static func imageImportPlan(folder: String, existingImageCount: Int) throws -> ImageImportPlanRecord {
+        try planImageFolderImport(folder: folder, existingImageCount: UInt32(clamping: existingImageCount))
+    }
+
+    static func listTriggers() throws -> [TriggerRecord] {
+        try alfred_alt.listTriggers()
+    }
+
+    static func saveTrigger(_ trigger: TriggerRecord) throws -> String {
+        try alfred_alt.saveTrigger(trigger: trigger)
+    }
+
+    static func deleteTrigger(id: String) throws {
+        try alfred_alt.deleteTrigger(triggerId: id)
+    }
+
+    static func boardStatuses() -> [String] {
+        alfred_alt.boardStatuses()
+    }
+
+    static func listBoardCards() throws -> [BoardCardRecord] {
+        try alfred_alt.listBoardCards()
+    }
+
+    @discardableResult
+    static func moveBoardCard(itemId: Int64, status: String) throws -> UInt64 {
+        try alfred_alt.moveBoardCard(itemId: itemId, status: status)
+    }
+
+    static func checklistEntries(itemId: Int64) throws -> [ChecklistEntryRecord] {
+        try listChecklistEntries(itemId: itemId)
+    }
+
+    @discardableResult
+    static func toggleChecklist(itemId: Int64, index: UInt32) throws -> UInt64 {
+        try toggleChecklistEntry(itemId: itemId, index: index)
+    }
+
+    static func usageStats(days: UInt32, topLimit: UInt32) throws -> UsageStatsRecord {
+        try getUsageStats(days: days, topLimit: topLimit)
+    }
+
+    static func countSearch() {
+        recordSearch()
+    }
+
+    static func calendarMonth(year: Int32, month: UInt32) throws -> [CalendarEntryRecord] {
+        try listCalendarMonth(year: year, month: month)
+    }
+
+    static func journalEntry(year: Int32, month: UInt32, day: UInt32) throws -> Int64 {
+        try openJournalEntry(year: year, month: month, day: day)
+    }
+
+    static func startTimer(itemId: Int64) throws {
+        try alfred_alt.startTimer(itemId: itemId)
+    }
+
+    @discardableResult
+    static func stopTimer() throws -> UInt64 {
+        try alfred_alt.stopTimer()
+    }
+
+    static func runningTimer() throws -> RunningTimerRecord? {
+        try alfred_alt.runningTimer()
+    }
+
+    static func trackedSeconds(itemId: Int64) throws -> UInt64 {
+        try alfred_alt.trackedSeconds(itemId: itemId)
+    }
+
+    static func weeklyTimeReport(year: Int32, month: UInt32, day: UInt32) throws -> TimeReportRecord {
+        try alfred_alt.weeklyTimeReport(year: year, month: month, day: day)
+    }
+
+    static func exportTimeEntriesCSV(year: Int32, month: UInt32, day: UInt32) throws -> String {
+        try exportTimeEntriesCsv(year: year, month: month, day: day)
+    }
+
+    static func listSchedules() throws -> [ScheduleRecord] {
+        try alfred_alt.listSchedules()
+    }
+
+    static func saveSchedule(_ schedule: ScheduleRecord) throws -> String {
+        try alfred_alt.saveSchedule(schedule: schedule)
+    }
+
+    static func deleteSchedule(id: String) throws {
+        try alfred_alt.deleteSchedule(scheduleId: id)
+    }
+
+    static func triggerLog(id: String, limit: UInt32 = 50) throws -> [TriggerLogEntryRecord] {
+        try listTriggerLog(triggerId: id, limit: limit)
+    }
 }
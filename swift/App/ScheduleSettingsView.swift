@@ -0,0 +1,218 @@
+import SwiftUI
+
+struct ScheduleSettingsView: View {
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var schedules: [ScheduleRecord] = []
+    @State private var draft: ScheduleRecord = ScheduleSettingsView.emptyDraft
+    @State private var errorMessage: String?
+
+    private static let emptyDraft = ScheduleRecord(
+        id: "",
+        name: "",
+        titleTemplate: "",
+        noteTemplate: "",
+        recurrence: .weekly,
+        weekday: 0,
+        hour: 9,
+        minute: 0,
+        enabled: true,
+        nextRunUnixSeconds: nil
+    )
+
+    private static let weekdayNames = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"]
+
+    var body: some View {
+        ScrollView {
+            VStack(alignment: .leading, spacing: 16) {
+                Text("Recurring Notes")
+                    .font(.system(size: 14, weight: .medium))
+
+                Text("Create a dated note from a template on a daily or weekly schedule. Use {date} and {weekday} in the title or body.")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+
+                if schedules.isEmpty {
+                    Text("No schedules yet.")
+                        .font(.system(size: 12))
+                        .foregroundStyle(.secondary)
+                } else {
+                    ForEach(schedules, id: \.id) { schedule in
+                        scheduleRow(schedule)
+                    }
+                }
+
+                Divider()
+
+                editorSection
+
+                if let errorMessage {
+                    Text(errorMessage)
+                        .font(.system(size: 12))
+                        .foregroundStyle(themeManager.colors.errorColor)
+                }
+
+                Spacer(minLength: 20)
+            }
+            .padding(.bottom, 10)
+        }
+        .onAppear(perform: reload)
+    }
+
+    private func scheduleRow(_ schedule: ScheduleRecord) -> some View {
+        HStack(spacing: 8) {
+            Image(systemName: "calendar.badge.clock")
+                .frame(width: 20)
+                .foregroundStyle(.secondary)
+            VStack(alignment: .leading, spacing: 2) {
+                Text(schedule.name)
+                    .font(.system(size: 13, weight: .medium))
+                Text(scheduleSummary(schedule))
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+                    .lineLimit(1)
+            }
+            Spacer()
+            Button("Edit") {
+                draft = schedule
+            }
+            .font(.system(size: 12))
+            Button("Delete") {
+                delete(schedule)
+            }
+            .font(.system(size: 12))
+            .foregroundStyle(themeManager.colors.errorColor)
+        }
+        .padding(.horizontal, 10)
+        .padding(.vertical, 8)
+        .background(Color(nsColor: .controlBackgroundColor))
+        .clipShape(RoundedRectangle(cornerRadius: 8, style: .continuous))
+        .opacity(schedule.enabled ? 1 : 0.55)
+    }
+
+    private var editorSection: some View {
+        VStack(alignment: .leading, spacing: 10) {
+            Text(draft.id.isEmpty ? "New Schedule" : "Edit Schedule")
+                .font(.system(size: 14, weight: .medium))
+
+            TextField("Name", text: $draft.name)
+                .textFieldStyle(.roundedBorder)
+
+            TextField("Note title (e.g. Weekly review {date})", text: $draft.titleTemplate)
+                .textFieldStyle(.roundedBorder)
+
+            Picker("Repeat", selection: $draft.recurrence) {
+                Text("Daily").tag(RecurrenceKind.daily)
+                Text("Weekly").tag(RecurrenceKind.weekly)
+            }
+
+            if draft.recurrence == .weekly {
+                Picker("On", selection: $draft.weekday) {
+                    ForEach(Array(Self.weekdayNames.enumerated()), id: \.offset) { index, name in
+                        Text(name).tag(UInt32(index))
+                    }
+                }
+            }
+
+            HStack(spacing: 6) {
+                Text("At")
+                Picker("", selection: $draft.hour) {
+                    ForEach(0..<24, id: \.self) { hour in
+                        Text(String(format: "%02d", hour)).tag(UInt32(hour))
+                    }
+                }
+                .labelsHidden()
+                .frame(width: 70)
+                Text(":")
+                Picker("", selection: $draft.minute) {
+                    ForEach(Array(stride(from: 0, to: 60, by: 5)), id: \.self) { minute in
+                        Text(String(format: "%02d", minute)).tag(UInt32(minute))
+                    }
+                }
+                .labelsHidden()
+                .frame(width: 70)
+            }
+
+            Text("Note template")
+                .font(.system(size: 12))
+                .foregroundStyle(.secondary)
+            TextEditor(text: $draft.noteTemplate)
+                .font(.system(size: 13))
+                .frame(minHeight: 90)
+                .overlay(
+                    RoundedRectangle(cornerRadius: 6)
+                        .stroke(Color(nsColor: .separatorColor), lineWidth: 1)
+                )
+
+            Toggle("Enabled", isOn: $draft.enabled)
+
+            HStack {
+                if !draft.id.isEmpty {
+                    Button("New") {
+                        draft = Self.emptyDraft
+                    }
+                }
+                Spacer()
+                Button("Save Schedule") {
+                    save()
+                }
+            }
+        }
+    }
+
+    private static let nextRunFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.dateStyle = .medium
+        formatter.timeStyle = .short
+        return formatter
+    }()
+
+    private func scheduleSummary(_ schedule: ScheduleRecord) -> String {
+        let time = String(format: "%02d:%02d", schedule.hour, schedule.minute)
+        let cadence: String
+        switch schedule.recurrence {
+        case .daily:
+            cadence = "Daily at \(time)"
+        case .weekly:
+            let day = Self.weekdayNames.indices.contains(Int(schedule.weekday)) ? Self.weekdayNames[Int(schedule.weekday)] : "?"
+            cadence = "Every \(day) at \(time)"
+        }
+        guard let next = schedule.nextRunUnixSeconds else {
+            return cadence
+        }
+        let nextDate = Date(timeIntervalSince1970: TimeInterval(next))
+        return "\(cadence) • next \(Self.nextRunFormatter.string(from: nextDate))"
+    }
+
+    private func reload() {
+        do {
+            schedules = try RustBridgeClient.listSchedules()
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func save() {
+        do {
+            let savedId = try RustBridgeClient.saveSchedule(draft)
+            reload()
+            if let saved = schedules.first(where: { $0.id == savedId }) {
+                draft = saved
+            }
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func delete(_ schedule: ScheduleRecord) {
+        do {
+            try RustBridgeClient.deleteSchedule(id: schedule.id)
+            if draft.id == schedule.id {
+                draft = Self.emptyDraft
+            }
+            reload()
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
@@ -0,0 +1,170 @@
+import Charts
+import SwiftUI
+
+private let statsDays: UInt32 = 30
+private let statsMostOpenedLimit: UInt32 = 10
+
+/// Library-wide usage: totals, searches per day over the last month and the
+/// most opened notes, from the backend's usage log.
+struct StatsView: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @Environment(\.openWindow) private var openWindow
+    @State private var stats: UsageStatsRecord?
+    @State private var errorMessage: String?
+    @State private var window: NSWindow?
+
+    private static let dayKeyFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.calendar = Calendar(identifier: .gregorian)
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.dateFormat = "yyyy-MM-dd"
+        return formatter
+    }()
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 16) {
+            HStack {
+                Text("Stats")
+                    .font(.system(size: 20, weight: .semibold))
+                Spacer()
+                Button {
+                    reload()
+                } label: {
+                    Image(systemName: "arrow.clockwise")
+                }
+                .buttonStyle(.borderless)
+                .help("Refresh")
+            }
+
+            if let errorMessage {
+                Text(errorMessage)
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+
+            if let stats {
+                HStack(spacing: 12) {
+                    totalTile("Notes", value: "\(stats.itemCount)")
+                    totalTile("Images", value: "\(stats.imageCount)")
+                    totalTile(
+                        "Image storage",
+                        value: ByteCountFormatter.string(fromByteCount: Int64(clamping: stats.imageBytes), countStyle: .file)
+                    )
+                    totalTile("Searches (\(statsDays) days)", value: "\(stats.searchesPerDay.reduce(0) { $0 + $1.searches })")
+                }
+
+                searchesChart(stats)
+
+                mostOpenedList(stats)
+            }
+
+            Spacer(minLength: 0)
+        }
+        .padding(16)
+        .frame(minWidth: 620, minHeight: 520, alignment: .topLeading)
+        .background(themeManager.colors.editorBackground)
+        .background(
+            WindowAccessor { resolved in
+                guard window !== resolved else { return }
+                window = resolved
+                viewModel.viewWindowDidOpen(resolved)
+            }
+        )
+        .onAppear(perform: reload)
+        .onDisappear {
+            viewModel.viewWindowDidClose(window)
+        }
+        .onChange(of: viewModel.viewWindowRefreshID) { _, _ in
+            reload()
+        }
+    }
+
+    private func totalTile(_ label: String, value: String) -> some View {
+        VStack(alignment: .leading, spacing: 4) {
+            Text(value)
+                .font(.system(size: 20, weight: .semibold))
+                .monospacedDigit()
+            Text(label)
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+        }
+        .padding(10)
+        .frame(maxWidth: .infinity, alignment: .leading)
+        .background(Color(nsColor: .controlBackgroundColor))
+        .clipShape(RoundedRectangle(cornerRadius: 8, style: .continuous))
+    }
+
+    private func searchesChart(_ stats: UsageStatsRecord) -> some View {
+        let points = stats.searchesPerDay.compactMap { day -> (Date, UInt32)? in
+            guard let date = Self.dayKeyFormatter.date(from: day.date) else {
+                return nil
+            }
+            return (date, day.searches)
+        }
+
+        return VStack(alignment: .leading, spacing: 8) {
+            Text("Searches per day")
+                .font(.system(size: 13, weight: .semibold))
+            Chart(points, id: \.0) { point in
+                BarMark(
+                    x: .value("Day", point.0, unit: .day),
+                    y: .value("Searches", point.1)
+                )
+                .foregroundStyle(themeManager.colors.accentColor)
+            }
+            .frame(height: 160)
+        }
+    }
+
+    private func mostOpenedList(_ stats: UsageStatsRecord) -> some View {
+        VStack(alignment: .leading, spacing: 6) {
+            Text("Most opened")
+                .font(.system(size: 13, weight: .semibold))
+
+            if stats.mostOpened.isEmpty {
+                Text("No notes opened yet.")
+                    .font(.system(size: 12))
+                    .foregroundStyle(.secondary)
+            } else {
+                ForEach(stats.mostOpened, id: \.id) { item in
+                    Button {
+                        open(itemId: item.id)
+                    } label: {
+                        HStack {
+                            Text(item.title)
+                                .foregroundStyle(themeManager.colors.itemTitleText)
+                                .lineLimit(1)
+                            Spacer()
+                            Text("\(item.opens)×")
+                                .monospacedDigit()
+                                .foregroundStyle(.secondary)
+                        }
+                        .font(.system(size: 12))
+                        .contentShape(Rectangle())
+                    }
+                    .buttonStyle(.plain)
+                }
+            }
+        }
+    }
+
+    private func open(itemId: Int64) {
+        Task {
+            let opened = await viewModel.open(itemId: itemId)
+            if opened {
+                viewModel.beginEditorPresentation()
+                openWindow.openEditor(for: viewModel)
+            }
+        }
+    }
+
+    private func reload() {
+        do {
+            stats = try RustBridgeClient.usageStats(days: statsDays, topLimit: statsMostOpenedLimit)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
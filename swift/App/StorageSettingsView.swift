@@ -0,0 +1,282 @@
+import SwiftUI
+
+struct StorageSettingsView: View {
+    @EnvironmentObject private var themeManager: ThemeManager
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @ObservedObject private var recompressor = ImageRecompressor.shared
+    @State private var recompressionCodec: ImageCodec = .heic
+    @State private var recompressionQuality: Double = 0.8
+    @State private var report: StorageReportRecord?
+    @State private var budgetMegabytes: String = ""
+    @State private var isLoading = false
+    @State private var errorMessage: String?
+
+    private static let byteFormatter: ByteCountFormatter = {
+        let formatter = ByteCountFormatter()
+        formatter.countStyle = .file
+        return formatter
+    }()
+
+    var body: some View {
+        ScrollView {
+            VStack(alignment: .leading, spacing: 16) {
+                Text("Storage")
+                    .font(.system(size: 14, weight: .medium))
+
+                if let report {
+                    summarySection(report)
+                    alertsSection(report)
+                    Divider()
+                    budgetSection
+                    Divider()
+                    imageFormatSection
+                    Divider()
+                    recompressionSection
+                    Divider()
+                    largestItemsSection(report)
+                } else if isLoading {
+                    ProgressView()
+                        .controlSize(.small)
+                }
+
+                if let errorMessage {
+                    Text(errorMessage)
+                        .font(.system(size: 12))
+                        .foregroundStyle(themeManager.colors.errorColor)
+                }
+
+                Spacer(minLength: 20)
+            }
+            .padding(.bottom, 10)
+        }
+        .onAppear(perform: reload)
+    }
+
+    private func summarySection(_ report: StorageReportRecord) -> some View {
+        VStack(alignment: .leading, spacing: 6) {
+            Text(report.root)
+                .font(.system(size: 12, design: .monospaced))
+                .foregroundStyle(.secondary)
+                .lineLimit(1)
+                .truncationMode(.middle)
+
+            HStack(spacing: 18) {
+                summaryValue("Location", report.cloudProvider ?? "Local disk")
+                summaryValue("Notes folder", formatted(report.mirrorBytes))
+                summaryValue("Free", report.availableBytes.map(formatted) ?? "Unknown")
+                summaryValue("Disk size", report.totalBytes.map(formatted) ?? "Unknown")
+            }
+
+            if report.cloudProvider != nil {
+                Text("The folder is cloud-synced. Free space is measured on this Mac; your account quota may run out first.")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+            }
+        }
+    }
+
+    private func summaryValue(_ title: String, _ value: String) -> some View {
+        VStack(alignment: .leading, spacing: 2) {
+            Text(title)
+                .font(.system(size: 10))
+                .foregroundStyle(.secondary)
+            Text(value)
+                .font(.system(size: 13, weight: .medium))
+        }
+    }
+
+    @ViewBuilder
+    private func alertsSection(_ report: StorageReportRecord) -> some View {
+        ForEach(Array(report.alerts.enumerated()), id: \.offset) { _, alert in
+            Label(alert.message, systemImage: alert.level == .critical ? "exclamationmark.octagon.fill" : "exclamationmark.triangle.fill")
+                .font(.system(size: 12))
+                .foregroundStyle(alert.level == .critical ? themeManager.colors.errorColor : Color.orange)
+        }
+    }
+
+    private var budgetSection: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            Text("Storage Budget")
+                .font(.system(size: 12, weight: .medium))
+                .foregroundStyle(.secondary)
+
+            HStack {
+                TextField("No budget", text: $budgetMegabytes)
+                    .textFieldStyle(.roundedBorder)
+                    .frame(width: 120)
+                Text("MB")
+                    .font(.system(size: 12))
+                Spacer()
+                Button("Save", action: saveBudget)
+            }
+
+            Text("Warn when the notes folder grows past 90% of this size, e.g. to stay within a cloud quota.")
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+        }
+    }
+
+    private var imageFormatSection: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            Text("New Images")
+                .font(.system(size: 12, weight: .medium))
+                .foregroundStyle(.secondary)
+
+            HStack(spacing: 12) {
+                Picker("Store as", selection: $viewModel.imageStorage.codec) {
+                    Text("PNG").tag(ImageCodec?.none)
+                    ForEach(ImageCodec.allCases) { codec in
+                        Text(codec.name).tag(ImageCodec?.some(codec))
+                    }
+                }
+                .frame(width: 160)
+
+                if viewModel.imageStorage.codec != nil {
+                    Text("Quality")
+                        .font(.system(size: 12))
+                    Slider(value: $viewModel.imageStorage.quality, in: ImageStorageSettings.qualityRange)
+                        .frame(width: 140)
+                    Text("\(Int((viewModel.imageStorage.quality * 100).rounded()))%")
+                        .font(.system(size: 12, design: .monospaced))
+                }
+            }
+
+            Text("Pasted and imported images are saved in this format when it is at least 10% smaller than PNG. Transparent images stay PNG when saving as JPEG. WebP can be read but not written on macOS.")
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+        }
+    }
+
+    private var recompressionSection: some View {
+        VStack(alignment: .leading, spacing: 8) {
+            Text("Re-compress Images")
+                .font(.system(size: 12, weight: .medium))
+                .foregroundStyle(.secondary)
+
+            HStack(spacing: 12) {
+                Picker("Format", selection: $recompressionCodec) {
+                    ForEach(ImageCodec.allCases) { codec in
+                        Text(codec.name).tag(codec)
+                    }
+                }
+                .frame(width: 160)
+
+                Text("Quality")
+                    .font(.system(size: 12))
+                Slider(value: $recompressionQuality, in: 0.5...0.95)
+                    .frame(width: 140)
+                Text("\(Int((recompressionQuality * 100).rounded()))%")
+                    .font(.system(size: 12, design: .monospaced))
+
+                Spacer()
+
+                if recompressor.isRunning {
+                    Button("Cancel") {
+                        recompressor.cancel()
+                    }
+                } else {
+                    Button("Start") {
+                        let openItemId = viewModel.isEditorPresented ? viewModel.selectedItem?.id : nil
+                        recompressor.start(
+                            codec: recompressionCodec,
+                            quality: recompressionQuality,
+                            skippedItemId: openItemId
+                        )
+                    }
+                }
+            }
+
+            if recompressor.isRunning {
+                ProgressView(
+                    value: Double(recompressor.processedCount),
+                    total: Double(max(recompressor.totalCount, 1))
+                )
+                Text("\(recompressor.processedCount) of \(recompressor.totalCount) images checked")
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+            } else if let statusMessage = recompressor.statusMessage {
+                Text(statusMessage)
+                    .font(.system(size: 11))
+                    .foregroundStyle(.secondary)
+            }
+
+            Text("Stored PNGs are re-encoded in the background and kept only when at least 10% smaller. Transparent images stay PNG when saving as JPEG.")
+                .font(.system(size: 11))
+                .foregroundStyle(.secondary)
+        }
+        .onChange(of: recompressor.isRunning) { _, isRunning in
+            if !isRunning {
+                reload()
+            }
+        }
+    }
+
+    private func largestItemsSection(_ report: StorageReportRecord) -> some View {
+        VStack(alignment: .leading, spacing: 6) {
+            Text("Largest Items")
+                .font(.system(size: 12, weight: .medium))
+                .foregroundStyle(.secondary)
+
+            ForEach(report.largestItems, id: \.itemId) { item in
+                HStack(spacing: 8) {
+                    Text(item.title.isEmpty ? "Untitled" : item.title)
+                        .font(.system(size: 13))
+                        .lineLimit(1)
+                    if !item.mirrored {
+                        Image(systemName: "lock.fill")
+                            .font(.system(size: 10))
+                            .foregroundStyle(.secondary)
+                            .help("Not mirrored to the JSON folder")
+                    }
+                    Spacer()
+                    Text(formatted(item.bytes))
+                        .font(.system(size: 12, design: .monospaced))
+                        .foregroundStyle(.secondary)
+                }
+            }
+        }
+    }
+
+    private func formatted(_ bytes: UInt64) -> String {
+        Self.byteFormatter.string(fromByteCount: Int64(clamping: bytes))
+    }
+
+    private func reload() {
+        isLoading = true
+        Task {
+            do {
+                let loaded = try await Task.detached(priority: .userInitiated) {
+                    try RustBridgeClient.storageReport()
+                }.value
+                report = loaded
+                budgetMegabytes = loaded.budgetBytes.map { String($0 / 1_048_576) } ?? ""
+                errorMessage = nil
+            } catch {
+                errorMessage = error.localizedDescription
+            }
+            isLoading = false
+        }
+    }
+
+    private func saveBudget() {
+        let trimmed = budgetMegabytes.trimmingCharacters(in: .whitespacesAndNewlines)
+        let budgetBytes: UInt64?
+        if trimmed.isEmpty {
+            budgetBytes = nil
+        } else if let megabytes = UInt64(trimmed) {
+            budgetBytes = megabytes * 1_048_576
+        } else {
+            errorMessage = "Enter the budget as a whole number of megabytes."
+            return
+        }
+
+        do {
+            try RustBridgeClient.saveBudget(bytes: budgetBytes)
+            errorMessage = nil
+            reload()
+            viewModel.refreshStorageAlert()
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
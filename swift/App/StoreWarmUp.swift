@@ -0,0 +1,35 @@
+import Foundation
+
+/// Opens the store and index on a background thread at launch, so the first
+/// search after the hotkey doesn't wait for them. Searches made before warm-up
+/// finishes still work; they wait for the store while the launcher says so.
+@MainActor
+final class StoreWarmUp: ObservableObject {
+    static let shared = StoreWarmUp()
+
+    @Published private(set) var isReady = RustBridgeClient.storeIsReady()
+
+    private var task: Task<Void, Never>?
+
+    private init() {}
+
+    func start() {
+        guard !isReady, task == nil else {
+            return
+        }
+
+        task = Task { [weak self] in
+            do {
+                try await Task.detached(priority: .userInitiated) {
+                    try RustBridgeClient.warmUp()
+                }.value
+            } catch {
+                // The first search reports the same failure where the user sees it.
+                NSLog("Store warm-up failed: \(error.localizedDescription)")
+            }
+            self?.isReady = true
+            self?.task = nil
+            ImageTextRecognizer.shared.scheduleScan()
+        }
+    }
+}
@@ -0,0 +1,229 @@
+import AppKit
+import SwiftUI
+
+// MARK: - Theme File Format
+
+/// A user theme stored as JSON in the `themes` folder next to the settings files.
+///
+/// Every section is optional: colors missing from the file come from `base` (a
+/// built-in theme id, "default" when omitted) and metrics fall back to the
+/// standard launcher layout.
+struct ThemeFile: Codable {
+    struct CornerRadii: Codable {
+        var launcher: Double?
+        var searchField: Double?
+        var resultRow: Double?
+        var editor: Double?
+    }
+
+    struct Fonts: Codable {
+        var family: String?
+    }
+
+    struct Shadow: Codable {
+        var color: String?
+        var radius: Double?
+        var x: Double?
+        var y: Double?
+    }
+
+    struct Shadows: Codable {
+        var searchField: Shadow?
+        var editor: Shadow?
+    }
+
+    var name: String?
+    var base: String?
+    var colors: [String: String]?
+    var cornerRadii: CornerRadii?
+    var fonts: Fonts?
+    var shadows: Shadows?
+}
+
+enum ThemeFileError: LocalizedError {
+    case unreadable(String, String)
+    case invalidColor(String, String, String)
+    case unknownColorKey(String, String)
+    case unknownBase(String, String)
+
+    var errorDescription: String? {
+        switch self {
+        case .unreadable(let file, let reason):
+            return "\(file): \(reason)"
+        case .invalidColor(let file, let key, let value):
+            return "\(file): '\(value)' for \(key) is not a #RRGGBB or #RRGGBBAA color"
+        case .unknownColorKey(let file, let key):
+            return "\(file): unknown color '\(key)'"
+        case .unknownBase(let file, let base):
+            return "\(file): unknown base theme '\(base)'"
+        }
+    }
+}
+
+/// Theme file keys and the colors they set.
+private let themeColorKeys: [(String, WritableKeyPath<ThemeColors, Color>)] = [
+    ("launcherBackground", \.launcherBackground),
+    ("searchFieldBackground", \.searchFieldBackground),
+    ("searchFieldBorder", \.searchFieldBorder),
+    ("launcherBorder", \.launcherBorder),
+    ("resultsBackground", \.resultsBackground),
+    ("placeholderText", \.placeholderText),
+    ("itemBackground", \.itemBackground),
+    ("itemTitleText", \.itemTitleText),
+    ("itemSubtitleText", \.itemSubtitleText),
+    ("selectedItemBackground", \.selectedItemBackground),
+    ("selectedItemTitleText", \.selectedItemTitleText),
+    ("selectedItemSubtitleText", \.selectedItemSubtitleText),
+    ("highlightBackground", \.highlightBackground),
+    ("actionMenuHeaderBackground", \.actionMenuHeaderBackground),
+    ("actionMenuHeaderText", \.actionMenuHeaderText),
+    ("destructiveAction", \.destructiveAction),
+    ("editorBackground", \.editorBackground),
+    ("editorTextBackground", \.editorTextBackground),
+    ("accentColor", \.accentColor),
+    ("successColor", \.successColor),
+    ("errorColor", \.errorColor)
+]
+
+extension ThemeFile {
+    static let fileExtension = "json"
+
+    /// Reads a theme file into an `AppTheme` whose id is derived from the file name.
+    static func loadTheme(from url: URL, builtInThemes: [AppTheme]) throws -> AppTheme {
+        let fileName = url.lastPathComponent
+        let file: ThemeFile
+        do {
+            file = try JSONDecoder().decode(ThemeFile.self, from: Data(contentsOf: url))
+        } catch let error as DecodingError {
+            throw ThemeFileError.unreadable(fileName, "invalid theme JSON (\(error.localizedDescription))")
+        } catch {
+            throw ThemeFileError.unreadable(fileName, error.localizedDescription)
+        }
+
+        let baseId = file.base ?? AppTheme.default.id
+        guard let base = builtInThemes.first(where: { $0.id == baseId && !$0.isCustom }) else {
+            throw ThemeFileError.unknownBase(fileName, baseId)
+        }
+
+        var colors = base.colors
+        for (key, value) in file.colors ?? [:] {
+            guard let keyPath = themeColorKeys.first(where: { $0.0 == key })?.1 else {
+                throw ThemeFileError.unknownColorKey(fileName, key)
+            }
+            guard let color = Color(themeHex: value) else {
+                throw ThemeFileError.invalidColor(fileName, key, value)
+            }
+            colors[keyPath: keyPath] = color
+        }
+
+        var metrics = ThemeMetrics.standard
+        if let radii = file.cornerRadii {
+            metrics.launcherCornerRadius = radii.launcher.map { CGFloat(max(0, $0)) } ?? metrics.launcherCornerRadius
+            metrics.searchFieldCornerRadius = radii.searchField.map { CGFloat(max(0, $0)) } ?? metrics.searchFieldCornerRadius
+            metrics.resultRowCornerRadius = radii.resultRow.map { CGFloat(max(0, $0)) } ?? metrics.resultRowCornerRadius
+            metrics.editorCornerRadius = radii.editor.map { CGFloat(max(0, $0)) } ?? metrics.editorCornerRadius
+        }
+        if let family = file.fonts?.family?.trimmingCharacters(in: .whitespacesAndNewlines), !family.isEmpty {
+            metrics.fontFamily = family
+        }
+        metrics.searchFieldShadow = try shadow(file.shadows?.searchField, key: "shadows.searchField", fileName: fileName)
+        metrics.editorShadow = try shadow(file.shadows?.editor, key: "shadows.editor", fileName: fileName)
+
+        let stem = url.deletingPathExtension().lastPathComponent
+        return AppTheme(
+            id: "file:\(fileName)",
+            name: file.name ?? stem,
+            colors: colors,
+            isCustom: false,
+            metrics: metrics,
+            sourceURL: url
+        )
+    }
+
+    /// A complete theme file for `colors` and `metrics`, as a starting point for editing.
+    static func template(name: String, colors: ThemeColors, metrics: ThemeMetrics) -> ThemeFile {
+        var hexColors: [String: String] = [:]
+        for (key, keyPath) in themeColorKeys {
+            hexColors[key] = colors[keyPath: keyPath].themeHex
+        }
+        return ThemeFile(
+            name: name,
+            base: AppTheme.default.id,
+            colors: hexColors,
+            cornerRadii: CornerRadii(
+                launcher: Double(metrics.launcherCornerRadius),
+                searchField: Double(metrics.searchFieldCornerRadius),
+                resultRow: Double(metrics.resultRowCornerRadius),
+                editor: Double(metrics.editorCornerRadius)
+            ),
+            fonts: Fonts(family: metrics.fontFamily),
+            shadows: Shadows(
+                searchField: Shadow(metrics.searchFieldShadow),
+                editor: Shadow(metrics.editorShadow)
+            )
+        )
+    }
+
+    private static func shadow(_ value: Shadow?, key: String, fileName: String) throws -> ThemeShadow {
+        guard let value else {
+            return .none
+        }
+        let colorHex = value.color ?? "#00000040"
+        guard let color = Color(themeHex: colorHex) else {
+            throw ThemeFileError.invalidColor(fileName, key, colorHex)
+        }
+        return ThemeShadow(
+            color: color,
+            radius: CGFloat(max(0, value.radius ?? 0)),
+            x: CGFloat(value.x ?? 0),
+            y: CGFloat(value.y ?? 0)
+        )
+    }
+}
+
+private extension ThemeFile.Shadow {
+    init(_ shadow: ThemeShadow) {
+        self.init(
+            color: shadow.color.themeHex,
+            radius: Double(shadow.radius),
+            x: Double(shadow.x),
+            y: Double(shadow.y)
+        )
+    }
+}
+
+// MARK: - Hex Colors
+
+private extension Color {
+    init?(themeHex: String) {
+        var hex = themeHex.trimmingCharacters(in: .whitespacesAndNewlines)
+        if hex.hasPrefix("#") {
+            hex.removeFirst()
+        }
+        guard hex.count == 6 || hex.count == 8, let value = UInt64(hex, radix: 16) else {
+            return nil
+        }
+
+        let rgba = hex.count == 6 ? (value << 8) | 0xFF : value
+        self.init(
+            red: Double((rgba >> 24) & 0xFF) / 255,
+            green: Double((rgba >> 16) & 0xFF) / 255,
+            blue: Double((rgba >> 8) & 0xFF) / 255,
+            opacity: Double(rgba & 0xFF) / 255
+        )
+    }
+
+    var themeHex: String {
+        let color = NSColor(self).usingColorSpace(.sRGB) ?? .black
+        let components = [color.redComponent, color.greenComponent, color.blueComponent, color.alphaComponent]
+        return "#" + components
+            .map { String(format: "%02X", Int(($0 * 255).rounded()).clamped(0, 255)) }
+            .joined()
+    }
+}
+
+private extension Int {
+    func clamped(_ lower: Int, _ upper: Int) -> Int {
+        Swift.min(Swift.max(self, lower), upper)
+    }
+}
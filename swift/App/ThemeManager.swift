@@ -9,6 +9,9 @@ struct AppTheme: Identifiable, Hashable, Equatable {
     let name: String
     let colors: ThemeColors
     let isCustom: Bool
+    var metrics: ThemeMetrics = .standard
+    /// Theme file this theme was loaded from; `nil` for built-in themes.
+    var sourceURL: URL? = nil
     
     static func == (lhs: AppTheme, rhs: AppTheme) -> Bool {
         lhs.id == rhs.id
@@ -58,6 +61,52 @@ struct ThemeColors: Equatable {
     var errorColor: Color
 }
 
+// MARK: - Theme Metrics
+
+struct ThemeShadow: Equatable {
+    var color: Color
+    var radius: CGFloat
+    var x: CGFloat
+    var y: CGFloat
+
+    static let none = ThemeShadow(color: .clear, radius: 0, x: 0, y: 0)
+}
+
+/// Shapes, fonts and shadows a theme applies on top of its colors.
+struct ThemeMetrics: Equatable {
+    var launcherCornerRadius: CGFloat
+    var searchFieldCornerRadius: CGFloat
+    var resultRowCornerRadius: CGFloat
+    var editorCornerRadius: CGFloat
+    /// Font family for launcher text; `nil` uses the system font.
+    var fontFamily: String?
+    var searchFieldShadow: ThemeShadow
+    var editorShadow: ThemeShadow
+
+    static let standard = ThemeMetrics(
+        launcherCornerRadius: 24,
+        searchFieldCornerRadius: 12,
+        resultRowCornerRadius: 0,
+        editorCornerRadius: 10,
+        fontFamily: nil,
+        searchFieldShadow: .none,
+        editorShadow: .none
+    )
+
+    func font(size: CGFloat, weight: Font.Weight = .regular) -> Font {
+        guard let fontFamily else {
+            return .system(size: size, weight: weight)
+        }
+        return .custom(fontFamily, size: size).weight(weight)
+    }
+}
+
+extension View {
+    func themeShadow(_ shadow: ThemeShadow) -> some View {
+        self.shadow(color: shadow.color, radius: shadow.radius, x: shadow.x, y: shadow.y)
+    }
+}
+
 // MARK: - Color Codable Support
 
 private struct ColorComponents: Codable {
@@ -712,6 +761,25 @@ final class ThemeManager: ObservableObject {
     static let shared = ThemeManager()
     static let themeChangedNotification = Notification.Name("ThemeManager.themeChanged")
     private static let settingsFileName = "theme-settings.json"
+    private static let themesDirectoryName = "themes"
+    private static let previewStyleFileName = "preview.css"
+    private static let previewStyleTemplate = """
+    /* Styles for the rendered Markdown preview. Saved changes apply right away. */
+    body {
+        /* font-family: "Iowan Old Style"; */
+        /* font-size: 16px; */
+        /* max-width: 720px; */
+    }
+    h1 { /* font-size: 30px; */ }
+    h2 { /* font-size: 22px; */ }
+    h3 { /* font-size: 18px; */ }
+    code {
+        /* font-family: Menlo; */
+        /* color: #c7254e; */
+        /* background: #f9f2f4; */
+    }
+
+    """
     private static let legacyCustomColorsKey = "customThemeColors"
     private static let legacyFontSizesKey = "customFontSizes"
     private static let legacySelectedThemeKey = "selectedThemeId"
@@ -724,10 +792,40 @@ final class ThemeManager: ObservableObject {
         let editorDividerColor: ColorComponents?
         let editorDividerTopMargin: Double?
         let editorDividerBottomMargin: Double?
+        let followSystemAppearance: Bool?
+        let lightThemeId: String?
+        let darkThemeId: String?
+        let followTimeOfDay: Bool?
+        let lightThemeStartMinute: Int?
+        let darkThemeStartMinute: Int?
+        let launcherPreviewEnabled: Bool?
+        let imageCacheBudgetMegabytes: Int?
+        let uiScale: Double?
+        let launcherOpacity: Double?
+        let launcherBlurEnabled: Bool?
+        let editorMarkdownPreviewEnabled: Bool?
+        let editorFocusModeEnabled: Bool?
+        let launcherWidth: Double?
+        let launcherTopOffsetPercent: Double?
     }
+
+    static let uiScaleRange: ClosedRange<CGFloat> = 0.8...1.6
+    static let launcherOpacityRange: ClosedRange<Double> = 0.3...1
+    static let launcherWidthRange: ClosedRange<Double> = 560...1400
+    static let defaultLauncherWidth: Double = 1040
+    static let launcherTopOffsetRange: ClosedRange<Double> = 0...60
+    static let defaultLauncherTopOffsetPercent: Double = 25
+    static let defaultLightThemeStartMinute = 7 * 60
+    static let defaultDarkThemeStartMinute = 19 * 60
     
     @Published var currentTheme: AppTheme
     @Published var customColors: ThemeColors
+    /// Themes loaded from files in the themes folder, sorted by name.
+    @Published private(set) var userThemes: [AppTheme] = []
+    /// Files in the themes folder that failed to load.
+    @Published private(set) var userThemeErrors: [String] = []
+    /// Overrides from `preview.css` in the themes folder; nil without the file.
+    @Published private(set) var previewStyle: PreviewStyleRecord?
     
     // Font sizes
     @Published var searchFieldFontSize: CGFloat {
@@ -758,6 +856,15 @@ final class ThemeManager: ObservableObject {
             }
         }
     }
+    /// Multiplies every font size above, for displays or eyes the sizes alone
+    /// don't suit.
+    @Published var uiScale: CGFloat {
+        didSet {
+            if !isApplyingPersistedState {
+                saveFontSizes()
+            }
+        }
+    }
     @Published var editorSearchHighlightsEnabled: Bool {
         didSet {
             if !isApplyingPersistedState {
@@ -765,6 +872,73 @@ final class ThemeManager: ObservableObject {
             }
         }
     }
+    /// Shows the selected note next to the launcher results.
+    @Published var launcherPreviewEnabled: Bool {
+        didSet {
+            if !isApplyingPersistedState {
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// Shows the note rendered as Markdown next to the editor's text.
+    @Published var editorMarkdownPreviewEnabled: Bool {
+        didSet {
+            if !isApplyingPersistedState {
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// Dims the editor around the caret's paragraph, keeps the caret line
+    /// centered and hides the editor's toolbars.
+    @Published var editorFocusModeEnabled: Bool {
+        didSet {
+            if !isApplyingPersistedState {
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// Opacity of the launcher background color; below 1 the desktop shows through.
+    @Published var launcherOpacity: Double {
+        didSet {
+            if !isApplyingPersistedState {
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// Blurs what is behind the launcher with the system vibrancy material.
+    @Published var launcherBlurEnabled: Bool {
+        didSet {
+            if !isApplyingPersistedState {
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// Width of the launcher window in points; a narrower display caps it further.
+    @Published var launcherWidth: Double {
+        didSet {
+            if !isApplyingPersistedState {
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// Gap above the launcher when it is placed on a display, as a percentage
+    /// of the display's visible height. The launcher is always centered horizontally.
+    @Published var launcherTopOffsetPercent: Double {
+        didSet {
+            if !isApplyingPersistedState {
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// Memory budget for decoded inline images shared by all editors.
+    @Published var imageCacheBudgetMegabytes: Int {
+        didSet {
+            InlineImageCache.shared.setBudget(megabytes: imageCacheBudgetMegabytes)
+            if !isApplyingPersistedState {
+                persistAllThemeSettings()
+            }
+        }
+    }
     @Published var editorDividerColor: Color {
         didSet {
             if !isApplyingPersistedState {
@@ -787,32 +961,178 @@ final class ThemeManager: ObservableObject {
         }
     }
 
+    /// When set, the theme switches between `lightThemeId` and `darkThemeId`
+    /// as the system appearance changes.
+    @Published var followSystemAppearance: Bool {
+        didSet {
+            if !isApplyingPersistedState {
+                if followSystemAppearance {
+                    stopFollowingTimeOfDay()
+                }
+                applyAppearanceThemeIfNeeded()
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// When set, the theme switches between `lightThemeId` and `darkThemeId`
+    /// at `lightThemeStartMinute` and `darkThemeStartMinute` local time, for
+    /// a dark theme at night whatever the system appearance.
+    @Published var followTimeOfDay: Bool {
+        didSet {
+            if !isApplyingPersistedState {
+                if followTimeOfDay {
+                    stopFollowingSystemAppearance()
+                }
+                applyAppearanceThemeIfNeeded()
+                persistAllThemeSettings()
+            }
+        }
+    }
+    /// Minutes after midnight.
+    @Published var lightThemeStartMinute: Int {
+        didSet {
+            if !isApplyingPersistedState {
+                applyAppearanceThemeIfNeeded()
+                persistAllThemeSettings()
+            }
+        }
+    }
+    @Published var darkThemeStartMinute: Int {
+        didSet {
+            if !isApplyingPersistedState {
+                applyAppearanceThemeIfNeeded()
+                persistAllThemeSettings()
+            }
+        }
+    }
+    @Published var lightThemeId: String {
+        didSet {
+            if !isApplyingPersistedState {
+                applyAppearanceThemeIfNeeded()
+                persistAllThemeSettings()
+            }
+        }
+    }
+    @Published var darkThemeId: String {
+        didSet {
+            if !isApplyingPersistedState {
+                applyAppearanceThemeIfNeeded()
+                persistAllThemeSettings()
+            }
+        }
+    }
+
     private var isApplyingPersistedState = false
+    private var appearanceObservation: NSKeyValueObservation?
+    private var timeOfDayTimer: Timer?
+    private var clockObservers: [NSObjectProtocol] = []
+    private var themesDirectoryWatcher: DispatchSourceFileSystemObject?
+    private var themeFileWatcher: DispatchSourceFileSystemObject?
+    private var previewStyleWatcher: DispatchSourceFileSystemObject?
     
     var colors: ThemeColors {
         currentTheme.isCustom ? customColors : currentTheme.colors
     }
+
+    var metrics: ThemeMetrics {
+        currentTheme.metrics
+    }
+
+    // Font sizes as drawn, with `uiScale` applied.
+    var scaledSearchFieldFontSize: CGFloat { searchFieldFontSize * uiScale }
+    var scaledItemTitleFontSize: CGFloat { itemTitleFontSize * uiScale }
+    var scaledItemSubtitleFontSize: CGFloat { itemSubtitleFontSize * uiScale }
+    var scaledEditorFontSize: CGFloat { editorFontSize * uiScale }
+
+    /// Themes that can be picked for the light and dark appearance.
+    var selectableThemes: [AppTheme] {
+        AppTheme.allThemes + userThemes
+    }
+
+    var isSystemAppearanceDark: Bool {
+        NSApplication.shared.effectiveAppearance.bestMatch(from: [.aqua, .darkAqua]) == .darkAqua
+    }
+
+    /// Whether `date` falls in the dark part of the time-of-day schedule. The
+    /// night runs from `darkThemeStartMinute` to `lightThemeStartMinute`, past
+    /// midnight; with both the same, it is never night.
+    func isNightTime(at date: Date) -> Bool {
+        let parts = Calendar.current.dateComponents([.hour, .minute], from: date)
+        let minute = (parts.hour ?? 0) * 60 + (parts.minute ?? 0)
+        if darkThemeStartMinute > lightThemeStartMinute {
+            return minute >= darkThemeStartMinute || minute < lightThemeStartMinute
+        }
+        return minute >= darkThemeStartMinute && minute < lightThemeStartMinute
+    }
+
+    var themesDirectoryURL: URL {
+        URL(fileURLWithPath: SettingsStore.shared.settingsDirectoryPath(), isDirectory: true)
+            .appendingPathComponent(Self.themesDirectoryName, isDirectory: true)
+    }
+
+    var previewStyleURL: URL {
+        themesDirectoryURL.appendingPathComponent(Self.previewStyleFileName)
+    }
     
     private init() {
         let persisted = Self.loadPersistedSettings()
         let loadedColors = persisted?.customColors.colors ?? AppTheme.defaultCustomColors
-        let loadedTheme = Self.themeForID(persisted?.selectedThemeId, customColors: loadedColors)
+        let (loadedUserThemes, loadErrors) = Self.loadUserThemes(
+            in: URL(fileURLWithPath: SettingsStore.shared.settingsDirectoryPath(), isDirectory: true)
+                .appendingPathComponent(Self.themesDirectoryName, isDirectory: true)
+        )
+        let loadedTheme = Self.themeForID(persisted?.selectedThemeId, customColors: loadedColors, userThemes: loadedUserThemes)
         let loadedFontSizes = persisted?.fontSizes ?? [:]
 
         currentTheme = loadedTheme
         customColors = loadedColors
+        userThemes = loadedUserThemes
+        userThemeErrors = loadErrors
         searchFieldFontSize = CGFloat(loadedFontSizes["searchField"] ?? 30)
         itemTitleFontSize = CGFloat(loadedFontSizes["itemTitle"] ?? 20)
         itemSubtitleFontSize = CGFloat(loadedFontSizes["itemSubtitle"] ?? 12)
         editorFontSize = CGFloat(loadedFontSizes["editor"] ?? 15)
+        uiScale = Self.clampedUIScale(persisted?.uiScale)
         editorSearchHighlightsEnabled = persisted?.editorSearchHighlightsEnabled ?? true
+        launcherPreviewEnabled = persisted?.launcherPreviewEnabled ?? false
+        editorMarkdownPreviewEnabled = persisted?.editorMarkdownPreviewEnabled ?? false
+        editorFocusModeEnabled = persisted?.editorFocusModeEnabled ?? false
+        launcherOpacity = Self.clampedLauncherOpacity(persisted?.launcherOpacity)
+        launcherBlurEnabled = persisted?.launcherBlurEnabled ?? false
+        launcherWidth = Self.clampedLauncherWidth(persisted?.launcherWidth)
+        launcherTopOffsetPercent = Self.clampedLauncherTopOffset(persisted?.launcherTopOffsetPercent)
+        imageCacheBudgetMegabytes = persisted?.imageCacheBudgetMegabytes ?? InlineImageCache.defaultBudgetMegabytes
         editorDividerColor = persisted?.editorDividerColor?.color ?? Color(red: 0.72, green: 0.86, blue: 0.98)
         editorDividerTopMargin = max(0, CGFloat(persisted?.editorDividerTopMargin ?? 6))
         editorDividerBottomMargin = max(0, CGFloat(persisted?.editorDividerBottomMargin ?? 6))
+        followSystemAppearance = persisted?.followSystemAppearance ?? false
+        lightThemeId = persisted?.lightThemeId ?? AppTheme.default.id
+        darkThemeId = persisted?.darkThemeId ?? AppTheme.dark.id
+        followTimeOfDay = persisted?.followTimeOfDay ?? false
+        lightThemeStartMinute = Self.clampedMinute(persisted?.lightThemeStartMinute, default: Self.defaultLightThemeStartMinute)
+        darkThemeStartMinute = Self.clampedMinute(persisted?.darkThemeStartMinute, default: Self.defaultDarkThemeStartMinute)
 
         if persisted == nil {
             persistAllThemeSettings()
         }
+        InlineImageCache.shared.setBudget(megabytes: imageCacheBudgetMegabytes)
+        applyAppearanceThemeIfNeeded()
+        watchThemeFiles()
+        reloadPreviewStyle()
+        appearanceObservation = NSApplication.shared.observe(\.effectiveAppearance) { [weak self] _, _ in
+            DispatchQueue.main.async {
+                self?.applyAppearanceThemeIfNeeded()
+            }
+        }
+        // Timers don't fire on time across sleep or clock and time zone changes.
+        let reapply: (Notification) -> Void = { [weak self] _ in
+            self?.applyAppearanceThemeIfNeeded()
+        }
+        clockObservers = [
+            NSWorkspace.shared.notificationCenter.addObserver(forName: NSWorkspace.didWakeNotification, object: nil, queue: .main, using: reapply),
+            NotificationCenter.default.addObserver(forName: .NSSystemClockDidChange, object: nil, queue: .main, using: reapply),
+            NotificationCenter.default.addObserver(forName: .NSSystemTimeZoneDidChange, object: nil, queue: .main, using: reapply),
+        ]
     }
     
     func updateCustomColors(_ newColors: ThemeColors) {
@@ -830,7 +1150,11 @@ final class ThemeManager: ObservableObject {
         }
     }
     
+    /// Selects a theme explicitly, which stops following the system appearance
+    /// or the time of day.
     func setTheme(_ theme: AppTheme) {
+        stopFollowingSystemAppearance()
+        stopFollowingTimeOfDay()
         if theme.isCustom {
             // Use our saved custom colors
             currentTheme = AppTheme(
@@ -843,10 +1167,13 @@ final class ThemeManager: ObservableObject {
             currentTheme = theme
         }
         saveThemePreference()
+        watchCurrentThemeFile()
         NotificationCenter.default.post(name: Self.themeChangedNotification, object: nil)
     }
     
     func setTheme(byId id: String) {
+        stopFollowingSystemAppearance()
+        stopFollowingTimeOfDay()
         if id == "custom" {
             currentTheme = AppTheme(
                 id: "custom",
@@ -854,10 +1181,11 @@ final class ThemeManager: ObservableObject {
                 colors: customColors,
                 isCustom: true
             )
-        } else if let theme = AppTheme.allThemes.first(where: { $0.id == id }) {
+        } else if let theme = (AppTheme.allThemes + userThemes).first(where: { $0.id == id }) {
             currentTheme = theme
         }
         saveThemePreference()
+        watchCurrentThemeFile()
         NotificationCenter.default.post(name: Self.themeChangedNotification, object: nil)
     }
     
@@ -876,24 +1204,257 @@ final class ThemeManager: ObservableObject {
         defer { isApplyingPersistedState = false }
 
         let loadedColors = persisted.customColors.colors
+        let (loadedUserThemes, loadErrors) = Self.loadUserThemes(in: themesDirectoryURL)
         customColors = loadedColors
-        currentTheme = Self.themeForID(persisted.selectedThemeId, customColors: loadedColors)
+        userThemes = loadedUserThemes
+        userThemeErrors = loadErrors
+        currentTheme = Self.themeForID(persisted.selectedThemeId, customColors: loadedColors, userThemes: loadedUserThemes)
         searchFieldFontSize = CGFloat(persisted.fontSizes["searchField"] ?? 30)
         itemTitleFontSize = CGFloat(persisted.fontSizes["itemTitle"] ?? 20)
         itemSubtitleFontSize = CGFloat(persisted.fontSizes["itemSubtitle"] ?? 12)
         editorFontSize = CGFloat(persisted.fontSizes["editor"] ?? 15)
+        uiScale = Self.clampedUIScale(persisted.uiScale)
         editorSearchHighlightsEnabled = persisted.editorSearchHighlightsEnabled ?? true
+        launcherPreviewEnabled = persisted.launcherPreviewEnabled ?? false
+        editorMarkdownPreviewEnabled = persisted.editorMarkdownPreviewEnabled ?? false
+        editorFocusModeEnabled = persisted.editorFocusModeEnabled ?? false
+        launcherOpacity = Self.clampedLauncherOpacity(persisted.launcherOpacity)
+        launcherBlurEnabled = persisted.launcherBlurEnabled ?? false
+        launcherWidth = Self.clampedLauncherWidth(persisted.launcherWidth)
+        launcherTopOffsetPercent = Self.clampedLauncherTopOffset(persisted.launcherTopOffsetPercent)
+        imageCacheBudgetMegabytes = persisted.imageCacheBudgetMegabytes ?? InlineImageCache.defaultBudgetMegabytes
         editorDividerColor = persisted.editorDividerColor?.color ?? Color(red: 0.72, green: 0.86, blue: 0.98)
         editorDividerTopMargin = max(0, CGFloat(persisted.editorDividerTopMargin ?? 6))
         editorDividerBottomMargin = max(0, CGFloat(persisted.editorDividerBottomMargin ?? 6))
+        followSystemAppearance = persisted.followSystemAppearance ?? false
+        lightThemeId = persisted.lightThemeId ?? AppTheme.default.id
+        darkThemeId = persisted.darkThemeId ?? AppTheme.dark.id
+        followTimeOfDay = persisted.followTimeOfDay ?? false
+        lightThemeStartMinute = Self.clampedMinute(persisted.lightThemeStartMinute, default: Self.defaultLightThemeStartMinute)
+        darkThemeStartMinute = Self.clampedMinute(persisted.darkThemeStartMinute, default: Self.defaultDarkThemeStartMinute)
 
+        applyAppearanceThemeIfNeeded()
+        watchThemeFiles()
+        reloadPreviewStyle()
         NotificationCenter.default.post(name: Self.themeChangedNotification, object: nil)
     }
 
+    /// Re-reads the themes folder and applies changes to the selected theme file.
+    func reloadUserThemes() {
+        let (loadedUserThemes, loadErrors) = Self.loadUserThemes(in: themesDirectoryURL)
+        userThemes = loadedUserThemes
+        userThemeErrors = loadErrors
+
+        if currentTheme.sourceURL != nil,
+           let reloaded = loadedUserThemes.first(where: { $0.id == currentTheme.id }) {
+            currentTheme = reloaded
+            NotificationCenter.default.post(name: Self.themeChangedNotification, object: nil)
+        }
+        applyAppearanceThemeIfNeeded()
+        watchCurrentThemeFile()
+        reloadPreviewStyle()
+    }
+
+    /// Re-reads `preview.css`; the preview redraws through `previewStyle`.
+    func reloadPreviewStyle() {
+        let source = try? String(contentsOf: previewStyleURL, encoding: .utf8)
+        let style = source.map { RustBridgeClient.previewStyle(source: $0) }
+        if style != previewStyle {
+            previewStyle = style
+        }
+        // Editors that save by replacing the file leave the old watcher behind.
+        previewStyleWatcher?.cancel()
+        previewStyleWatcher = Self.makeWatcher(for: previewStyleURL, events: [.write, .extend, .delete, .rename]) { [weak self] in
+            self?.reloadPreviewStyle()
+        }
+    }
+
+    /// The preview style file, created with every supported rule commented
+    /// out when it doesn't exist yet.
+    func previewStyleFile() throws -> URL {
+        let url = previewStyleURL
+        if !FileManager.default.fileExists(atPath: url.path) {
+            try FileManager.default.createDirectory(at: themesDirectoryURL, withIntermediateDirectories: true)
+            try Self.previewStyleTemplate.write(to: url, atomically: true, encoding: .utf8)
+            reloadPreviewStyle()
+        }
+        return url
+    }
+
+    private func stopFollowingSystemAppearance() {
+        guard followSystemAppearance else {
+            return
+        }
+        isApplyingPersistedState = true
+        followSystemAppearance = false
+        isApplyingPersistedState = false
+    }
+
+    private func stopFollowingTimeOfDay() {
+        guard followTimeOfDay else {
+            return
+        }
+        isApplyingPersistedState = true
+        followTimeOfDay = false
+        isApplyingPersistedState = false
+        scheduleTimeOfDaySwitch()
+    }
+
+    /// Fires `applyAppearanceThemeIfNeeded` at the next light or dark start
+    /// while following the time of day.
+    private func scheduleTimeOfDaySwitch() {
+        timeOfDayTimer?.invalidate()
+        timeOfDayTimer = nil
+        guard followTimeOfDay else {
+            return
+        }
+        let now = Date()
+        let nextSwitch = [lightThemeStartMinute, darkThemeStartMinute]
+            .compactMap { minute in
+                Calendar.current.nextDate(
+                    after: now,
+                    matching: DateComponents(hour: minute / 60, minute: minute % 60),
+                    matchingPolicy: .nextTime
+                )
+            }
+            .min()
+        guard let nextSwitch else {
+            return
+        }
+        let timer = Timer(fire: nextSwitch, interval: 0, repeats: false) { [weak self] _ in
+            self?.applyAppearanceThemeIfNeeded()
+        }
+        timer.tolerance = 5
+        RunLoop.main.add(timer, forMode: .common)
+        timeOfDayTimer = timer
+    }
+
+    /// Switches to the light or dark theme matching the time of day or the
+    /// current system appearance, whichever is followed.
+    private func applyAppearanceThemeIfNeeded() {
+        scheduleTimeOfDaySwitch()
+        guard followSystemAppearance || followTimeOfDay else {
+            return
+        }
+
+        let isDark = followTimeOfDay ? isNightTime(at: Date()) : isSystemAppearanceDark
+        let themeId = isDark ? darkThemeId : lightThemeId
+        let theme = Self.themeForID(themeId, customColors: customColors, userThemes: userThemes)
+        guard theme.id != currentTheme.id else {
+            return
+        }
+
+        currentTheme = theme
+        watchCurrentThemeFile()
+        NotificationCenter.default.post(name: Self.themeChangedNotification, object: nil)
+    }
+
+    /// Writes the current colors and metrics to a new file in the themes folder.
+    @discardableResult
+    func exportCurrentThemeFile() throws -> URL {
+        let directoryURL = themesDirectoryURL
+        try FileManager.default.createDirectory(at: directoryURL, withIntermediateDirectories: true)
+
+        var index = 1
+        var fileURL = directoryURL.appendingPathComponent("my-theme.\(ThemeFile.fileExtension)")
+        while FileManager.default.fileExists(atPath: fileURL.path) {
+            index += 1
+            fileURL = directoryURL.appendingPathComponent("my-theme-\(index).\(ThemeFile.fileExtension)")
+        }
+
+        let name = index == 1 ? "My Theme" : "My Theme \(index)"
+        let file = ThemeFile.template(name: name, colors: colors, metrics: metrics)
+        let encoder = JSONEncoder()
+        encoder.outputFormatting = [.prettyPrinted, .sortedKeys]
+        try encoder.encode(file).write(to: fileURL, options: [.atomic])
+        reloadUserThemes()
+        return fileURL
+    }
+
+    // MARK: Theme File Watching
+
+    /// Watches the themes folder for added, removed or replaced files.
+    private func watchThemeFiles() {
+        themesDirectoryWatcher?.cancel()
+        themesDirectoryWatcher = nil
+
+        let directoryURL = themesDirectoryURL
+        try? FileManager.default.createDirectory(at: directoryURL, withIntermediateDirectories: true)
+        themesDirectoryWatcher = Self.makeWatcher(for: directoryURL, events: .write) { [weak self] in
+            self?.reloadUserThemes()
+        }
+        watchCurrentThemeFile()
+    }
+
+    /// Watches the selected theme file itself, since in-place edits do not touch the folder.
+    private func watchCurrentThemeFile() {
+        themeFileWatcher?.cancel()
+        themeFileWatcher = nil
+
+        guard let sourceURL = currentTheme.sourceURL else {
+            return
+        }
+        themeFileWatcher = Self.makeWatcher(for: sourceURL, events: [.write, .extend, .delete, .rename]) { [weak self] in
+            self?.reloadUserThemes()
+        }
+    }
+
+    private static func makeWatcher(
+        for url: URL,
+        events: DispatchSource.FileSystemEvent,
+        onChange: @escaping () -> Void
+    ) -> DispatchSourceFileSystemObject? {
+        let descriptor = open(url.path, O_EVTONLY)
+        guard descriptor >= 0 else {
+            return nil
+        }
+
+        let source = DispatchSource.makeFileSystemObjectSource(
+            fileDescriptor: descriptor,
+            eventMask: events,
+            queue: .main
+        )
+        source.setEventHandler(handler: onChange)
+        source.setCancelHandler {
+            close(descriptor)
+        }
+        source.resume()
+        return source
+    }
+
+    private static func loadUserThemes(in directoryURL: URL) -> ([AppTheme], [String]) {
+        let fileURLs = (try? FileManager.default.contentsOfDirectory(
+            at: directoryURL,
+            includingPropertiesForKeys: nil,
+            options: [.skipsHiddenFiles]
+        )) ?? []
+
+        var themes: [AppTheme] = []
+        var errors: [String] = []
+        for fileURL in fileURLs where fileURL.pathExtension.lowercased() == ThemeFile.fileExtension {
+            do {
+                themes.append(try ThemeFile.loadTheme(from: fileURL, builtInThemes: AppTheme.allThemes))
+            } catch {
+                errors.append(error.localizedDescription)
+            }
+        }
+
+        themes.sort { $0.name.localizedStandardCompare($1.name) == .orderedAscending }
+        return (themes, errors.sorted())
+    }
+
     func toggleEditorSearchHighlightsEnabled() {
         editorSearchHighlightsEnabled.toggle()
     }
 
+    func toggleEditorMarkdownPreviewEnabled() {
+        editorMarkdownPreviewEnabled.toggle()
+    }
+
+    func toggleEditorFocusModeEnabled() {
+        editorFocusModeEnabled.toggle()
+    }
+
     func resetEditorDividerStyle() {
         editorDividerColor = Color(red: 0.72, green: 0.86, blue: 0.98)
         editorDividerTopMargin = 6
@@ -917,6 +1478,7 @@ final class ThemeManager: ObservableObject {
         itemTitleFontSize = 20
         itemSubtitleFontSize = 12
         editorFontSize = 15
+        uiScale = 1
     }
     
     func increaseEditorFontSize() {
@@ -940,11 +1502,46 @@ final class ThemeManager: ObservableObject {
             editorSearchHighlightsEnabled: editorSearchHighlightsEnabled,
             editorDividerColor: ColorComponents(color: editorDividerColor),
             editorDividerTopMargin: Double(editorDividerTopMargin),
-            editorDividerBottomMargin: Double(editorDividerBottomMargin)
+            editorDividerBottomMargin: Double(editorDividerBottomMargin),
+            followSystemAppearance: followSystemAppearance,
+            lightThemeId: lightThemeId,
+            darkThemeId: darkThemeId,
+            followTimeOfDay: followTimeOfDay,
+            lightThemeStartMinute: lightThemeStartMinute,
+            darkThemeStartMinute: darkThemeStartMinute,
+            launcherPreviewEnabled: launcherPreviewEnabled,
+            imageCacheBudgetMegabytes: imageCacheBudgetMegabytes,
+            uiScale: Double(uiScale),
+            launcherOpacity: launcherOpacity,
+            launcherBlurEnabled: launcherBlurEnabled,
+            editorMarkdownPreviewEnabled: editorMarkdownPreviewEnabled,
+            editorFocusModeEnabled: editorFocusModeEnabled,
+            launcherWidth: launcherWidth,
+            launcherTopOffsetPercent: launcherTopOffsetPercent
         )
         _ = SettingsStore.shared.saveJSON(settings, fileName: Self.settingsFileName)
     }
 
+    private static func clampedLauncherOpacity(_ value: Double?) -> Double {
+        min(max(value ?? 1, launcherOpacityRange.lowerBound), launcherOpacityRange.upperBound)
+    }
+
+    private static func clampedLauncherWidth(_ value: Double?) -> Double {
+        min(max(value ?? defaultLauncherWidth, launcherWidthRange.lowerBound), launcherWidthRange.upperBound)
+    }
+
+    private static func clampedLauncherTopOffset(_ value: Double?) -> Double {
+        min(max(value ?? defaultLauncherTopOffsetPercent, launcherTopOffsetRange.lowerBound), launcherTopOffsetRange.upperBound)
+    }
+
+    private static func clampedMinute(_ value: Int?, default defaultMinute: Int) -> Int {
+        min(max(value ?? defaultMinute, 0), 24 * 60 - 1)
+    }
+
+    private static func clampedUIScale(_ value: Double?) -> CGFloat {
+        min(max(CGFloat(value ?? 1), uiScaleRange.lowerBound), uiScaleRange.upperBound)
+    }
+
     private static func loadPersistedSettings() -> PersistedThemeSettings? {
         if let persisted: PersistedThemeSettings = SettingsStore.shared.loadJSON(
             PersistedThemeSettings.self,
@@ -987,13 +1584,28 @@ final class ThemeManager: ObservableObject {
             editorSearchHighlightsEnabled: true,
             editorDividerColor: ColorComponents(color: Color(red: 0.72, green: 0.86, blue: 0.98)),
             editorDividerTopMargin: 6,
-            editorDividerBottomMargin: 6
+            editorDividerBottomMargin: 6,
+            followSystemAppearance: nil,
+            lightThemeId: nil,
+            darkThemeId: nil,
+            followTimeOfDay: nil,
+            lightThemeStartMinute: nil,
+            darkThemeStartMinute: nil,
+            launcherPreviewEnabled: nil,
+            imageCacheBudgetMegabytes: nil,
+            uiScale: nil,
+            launcherOpacity: nil,
+            launcherBlurEnabled: nil,
+            editorMarkdownPreviewEnabled: nil,
+            editorFocusModeEnabled: nil,
+            launcherWidth: nil,
+            launcherTopOffsetPercent: nil
         )
         _ = SettingsStore.shared.saveJSON(migrated, fileName: settingsFileName)
         return migrated
     }
 
-    private static func themeForID(_ id: String?, customColors: ThemeColors) -> AppTheme {
+    private static func themeForID(_ id: String?, customColors: ThemeColors, userThemes: [AppTheme]) -> AppTheme {
         guard let id else {
             return .default
         }
@@ -1002,7 +1614,7 @@ final class ThemeManager: ObservableObject {
             return AppTheme(id: "custom", name: "Custom", colors: customColors, isCustom: true)
         }
 
-        return AppTheme.allThemes.first(where: { $0.id == id }) ?? .default
+        return (AppTheme.allThemes + userThemes).first(where: { $0.id == id }) ?? .default
     }
 
     private static func numericValue(from value: Any?) -> Double? {
@@ -0,0 +1,216 @@
+import SwiftUI
+import UniformTypeIdentifiers
+
+/// Tracked time per note for one week, Monday to Sunday, with a CSV export
+/// of the week's timer runs for invoicing.
+struct TimeReportView: View {
+    @EnvironmentObject private var viewModel: LauncherViewModel
+    @EnvironmentObject private var themeManager: ThemeManager
+    @Environment(\.openWindow) private var openWindow
+    @State private var weekDate = Date()
+    @State private var report: TimeReportRecord?
+    @State private var errorMessage: String?
+    @State private var window: NSWindow?
+
+    private static let dayKeyFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.calendar = Calendar(identifier: .gregorian)
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.dateFormat = "yyyy-MM-dd"
+        return formatter
+    }()
+
+    private static let dayLabelFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.setLocalizedDateFormatFromTemplate("EEE d")
+        return formatter
+    }()
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: 16) {
+            HStack {
+                Text("Timesheet")
+                    .font(.system(size: 20, weight: .semibold))
+                Spacer()
+                Button {
+                    moveWeek(by: -1)
+                } label: {
+                    Image(systemName: "chevron.left")
+                }
+                .buttonStyle(.borderless)
+                .help("Previous week")
+                Text(weekTitle)
+                    .font(.system(size: 13, weight: .medium))
+                    .monospacedDigit()
+                Button {
+                    moveWeek(by: 1)
+                } label: {
+                    Image(systemName: "chevron.right")
+                }
+                .buttonStyle(.borderless)
+                .help("Next week")
+                Button("This Week") {
+                    weekDate = Date()
+                    reload()
+                }
+                Button("Export CSV…", action: exportCSV)
+                    .disabled(report?.rows.isEmpty ?? true)
+            }
+
+            if let errorMessage {
+                Text(errorMessage)
+                    .font(.system(size: 12))
+                    .foregroundStyle(themeManager.colors.errorColor)
+            }
+
+            if let report {
+                if report.rows.isEmpty {
+                    Text("No time tracked this week. Start a timer with “track <note>” in the launcher or from the editor.")
+                        .font(.system(size: 12))
+                        .foregroundStyle(.secondary)
+                } else {
+                    reportGrid(report)
+                }
+            }
+
+            Spacer(minLength: 0)
+        }
+        .padding(16)
+        .frame(minWidth: 720, minHeight: 420, alignment: .topLeading)
+        .background(themeManager.colors.editorBackground)
+        .background(
+            WindowAccessor { resolved in
+                guard window !== resolved else { return }
+                window = resolved
+                viewModel.viewWindowDidOpen(resolved)
+            }
+        )
+        .onAppear(perform: reload)
+        .onDisappear {
+            viewModel.viewWindowDidClose(window)
+        }
+        .onChange(of: viewModel.viewWindowRefreshID) { _, _ in
+            reload()
+        }
+    }
+
+    private var weekTitle: String {
+        guard let first = report?.days.first, let last = report?.days.last else {
+            return ""
+        }
+        return "\(first) – \(last)"
+    }
+
+    private func reportGrid(_ report: TimeReportRecord) -> some View {
+        Grid(alignment: .trailing, horizontalSpacing: 12, verticalSpacing: 6) {
+            GridRow {
+                Text("Note")
+                    .gridColumnAlignment(.leading)
+                ForEach(report.days, id: \.self) { day in
+                    Text(dayLabel(day))
+                }
+                Text("Total")
+            }
+            .font(.system(size: 11, weight: .semibold))
+            .foregroundStyle(.secondary)
+
+            Divider()
+
+            ForEach(report.rows, id: \.itemId) { row in
+                GridRow {
+                    Button {
+                        open(itemId: row.itemId)
+                    } label: {
+                        Text(row.title)
+                            .foregroundStyle(themeManager.colors.itemTitleText)
+                            .lineLimit(1)
+                            .contentShape(Rectangle())
+                    }
+                    .buttonStyle(.plain)
+                    ForEach(Array(row.daySeconds.enumerated()), id: \.offset) { _, seconds in
+                        Text(seconds == 0 ? "–" : Self.duration(seconds))
+                            .foregroundStyle(seconds == 0 ? .secondary : .primary)
+                    }
+                    Text(Self.duration(row.totalSeconds))
+                        .fontWeight(.semibold)
+                }
+                .font(.system(size: 12))
+                .monospacedDigit()
+            }
+
+            Divider()
+
+            GridRow {
+                Text("Total")
+                ForEach(Array(report.dayTotals.enumerated()), id: \.offset) { _, seconds in
+                    Text(Self.duration(seconds))
+                }
+                Text(Self.duration(report.totalSeconds))
+            }
+            .font(.system(size: 12, weight: .semibold))
+            .monospacedDigit()
+        }
+    }
+
+    private func dayLabel(_ dayKey: String) -> String {
+        guard let date = Self.dayKeyFormatter.date(from: dayKey) else {
+            return dayKey
+        }
+        return Self.dayLabelFormatter.string(from: date)
+    }
+
+    /// Hours and minutes, e.g. "1:05".
+    static func duration(_ seconds: UInt64) -> String {
+        let minutes = seconds / 60
+        return String(format: "%d:%02d", minutes / 60, minutes % 60)
+    }
+
+    private var weekComponents: (year: Int32, month: UInt32, day: UInt32) {
+        let parts = Calendar.current.dateComponents([.year, .month, .day], from: weekDate)
+        return (Int32(parts.year ?? 1970), UInt32(parts.month ?? 1), UInt32(parts.day ?? 1))
+    }
+
+    private func moveWeek(by weeks: Int) {
+        weekDate = Calendar.current.date(byAdding: .day, value: 7 * weeks, to: weekDate) ?? weekDate
+        reload()
+    }
+
+    private func open(itemId: Int64) {
+        Task {
+            let opened = await viewModel.open(itemId: itemId)
+            if opened {
+                viewModel.beginEditorPresentation()
+                openWindow.openEditor(for: viewModel)
+            }
+        }
+    }
+
+    private func exportCSV() {
+        let week = weekComponents
+        let panel = NSSavePanel()
+        panel.title = "Export Time Entries as CSV"
+        panel.nameFieldStringValue = "timesheet-\(report?.days.first ?? "week").csv"
+        panel.allowedContentTypes = [.commaSeparatedText]
+        panel.canCreateDirectories = true
+        guard panel.runModal() == .OK, let url = panel.url else {
+            return
+        }
+        do {
+            let csv = try RustBridgeClient.exportTimeEntriesCSV(year: week.year, month: week.month, day: week.day)
+            try csv.write(to: url, atomically: true, encoding: .utf8)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func reload() {
+        let week = weekComponents
+        do {
+            report = try RustBridgeClient.weeklyTimeReport(year: week.year, month: week.month, day: week.day)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+}
@@ -0,0 +1,193 @@
+import AppKit
+import SwiftUI
+
+/// Collapsible editor panel that compares two recorded versions of the note
+/// with a word-level diff and lets text be copied back from the older one.
+struct VersionHistoryPanel: View {
+    let itemId: Int64
+    /// Changes whenever the note is saved so the version list can refresh.
+    let revision: UInt64
+    @EnvironmentObject private var themeManager: ThemeManager
+    @State private var isExpanded = false
+    @State private var versions: [NoteVersionRecord] = []
+    @State private var oldIndex: UInt32 = 0
+    @State private var newIndex: UInt32 = 0
+    @State private var segments: [DiffSegmentRecord] = []
+    @State private var errorMessage: String?
+
+    private static let timestampFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.dateStyle = .medium
+        formatter.timeStyle = .short
+        return formatter
+    }()
+
+    var body: some View {
+        DisclosureGroup(isExpanded: $isExpanded) {
+            content
+                .padding(.top, 8)
+        } label: {
+            HStack(spacing: 6) {
+                Text("Version history")
+                    .font(.system(size: 12, weight: .medium))
+                if !versions.isEmpty {
+                    Text("\(versions.count) versions")
+                        .font(.system(size: 12))
+                        .foregroundStyle(.secondary)
+                }
+            }
+        }
+        .onChange(of: itemId) { _, _ in
+            versions = []
+            segments = []
+            if isExpanded {
+                reloadVersions()
+            }
+        }
+        .onChange(of: revision) { _, _ in
+            if isExpanded {
+                reloadVersions()
+            }
+        }
+        .onChange(of: isExpanded) { _, expanded in
+            if expanded {
+                reloadVersions()
+            }
+        }
+        .onChange(of: oldIndex) { _, _ in
+            reloadDiff()
+        }
+        .onChange(of: newIndex) { _, _ in
+            reloadDiff()
+        }
+    }
+
+    @ViewBuilder
+    private var content: some View {
+        if let errorMessage {
+            Text(errorMessage)
+                .font(.system(size: 12))
+                .foregroundStyle(themeManager.colors.errorColor)
+        } else if versions.count < 2 {
+            Text("Versions are recorded as the note is edited; there is nothing to compare yet.")
+                .font(.system(size: 12))
+                .foregroundStyle(.secondary)
+        } else {
+            VStack(alignment: .leading, spacing: 8) {
+                HStack(spacing: 12) {
+                    versionPicker("From", selection: $oldIndex)
+                    versionPicker("To", selection: $newIndex)
+                    Spacer()
+                    Button("Copy Removed Text") {
+                        copyToPasteboard(removedText)
+                    }
+                    .disabled(removedText.isEmpty)
+                    Button("Copy Old Version", action: copyOldVersion)
+                }
+                .font(.system(size: 12))
+
+                ScrollView {
+                    Text(diffText)
+                        .font(.system(size: 13))
+                        .textSelection(.enabled)
+                        .frame(maxWidth: .infinity, alignment: .leading)
+                        .padding(8)
+                }
+                .frame(maxHeight: 220)
+                .background(themeManager.colors.editorTextBackground)
+                .clipShape(RoundedRectangle(cornerRadius: 8, style: .continuous))
+            }
+        }
+    }
+
+    private func versionPicker(_ title: String, selection: Binding<UInt32>) -> some View {
+        Picker(title, selection: selection) {
+            ForEach(versions.reversed(), id: \.index) { version in
+                Text(versionLabel(version)).tag(version.index)
+            }
+        }
+        .fixedSize()
+    }
+
+    private func versionLabel(_ version: NoteVersionRecord) -> String {
+        let date = Date(timeIntervalSince1970: TimeInterval(version.atUnixSeconds))
+        let timestamp = Self.timestampFormatter.string(from: date)
+        return "\(timestamp) · \(version.charCount) chars"
+    }
+
+    private var diffText: AttributedString {
+        var text = AttributedString()
+        for segment in segments {
+            var part = AttributedString(segment.text)
+            switch segment.kind {
+            case .equal:
+                part.foregroundColor = themeManager.colors.itemTitleText
+            case .insert:
+                part.foregroundColor = .green
+                part.backgroundColor = Color.green.opacity(0.15)
+            case .delete:
+                part.foregroundColor = .red
+                part.backgroundColor = Color.red.opacity(0.15)
+                part.strikethroughStyle = .single
+            }
+            text += part
+        }
+        return text
+    }
+
+    private var removedText: String {
+        segments
+            .filter { $0.kind == .delete }
+            .map { $0.text.trimmingCharacters(in: .whitespacesAndNewlines) }
+            .filter { !$0.isEmpty }
+            .joined(separator: "\n")
+    }
+
+    private func reloadVersions() {
+        do {
+            versions = try RustBridgeClient.itemVersions(itemId: itemId)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+            return
+        }
+
+        guard let latest = versions.last?.index else {
+            segments = []
+            return
+        }
+        // Reloads compare against the latest version but keep an older "From" choice.
+        newIndex = latest
+        if !versions.contains(where: { $0.index == oldIndex }) || oldIndex >= newIndex {
+            oldIndex = newIndex > 0 ? newIndex - 1 : 0
+        }
+        reloadDiff()
+    }
+
+    private func reloadDiff() {
+        guard versions.count >= 2 else {
+            segments = []
+            return
+        }
+        do {
+            segments = try RustBridgeClient.diffVersions(itemId: itemId, oldIndex: oldIndex, newIndex: newIndex)
+            errorMessage = nil
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func copyOldVersion() {
+        do {
+            copyToPasteboard(try RustBridgeClient.versionText(itemId: itemId, index: oldIndex))
+        } catch {
+            errorMessage = error.localizedDescription
+        }
+    }
+
+    private func copyToPasteboard(_ text: String) {
+        let pasteboard = NSPasteboard.general
+        pasteboard.clearContents()
+        pasteboard.setString(text, forType: .string)
+    }
+}
@@ -86,3 +86,20 @@ struct WindowConfigurator: NSViewRepresentable {
         window.setFrame(frame, display: false, animate: false)
     }
 }
+
+/// The system's behind-window blur, for the translucent launcher background.
+struct VisualEffectBackground: NSViewRepresentable {
+    var material: NSVisualEffectView.Material = .hudWindow
+
+    func makeNSView(context: Context) -> NSVisualEffectView {
+        let view = NSVisualEffectView()
+        view.blendingMode = .behindWindow
+        view.state = .active
+        view.material = material
+        return view
+    }
+
+    func updateNSView(_ nsView: NSVisualEffectView, context: Context) {
+        nsView.material = material
+    }
+}
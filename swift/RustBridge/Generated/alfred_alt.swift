@@ -413,6 +413,38 @@ fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt32: FfiConverterPrimitive {
+    typealias FfiType = Int32
+    typealias SwiftType = Int32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int32, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
+    typealias FfiType = UInt64
+    typealias SwiftType = UInt64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -429,6 +461,46 @@ fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
+    typealias FfiType = Double
+    typealias SwiftType = Double
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Double {
+        return try lift(readDouble(&buf))
+    }
+
+    public static func write(_ value: Double, into buf: inout [UInt8]) {
+        writeDouble(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterBool : FfiConverter {
+    typealias FfiType = Int8
+    typealias SwiftType = Bool
+
+    public static func lift(_ value: Int8) throws -> Bool {
+        return value != 0
+    }
+
+    public static func lower(_ value: Bool) -> Int8 {
+        return value ? 1 : 0
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Bool {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Bool, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -489,61 +561,55 @@ fileprivate struct FfiConverterData: FfiConverterRustBuffer {
 }
 
 
-public struct DeletedItemPreviewRecord {
-    public var archiveKey: String
-    public var id: Int64
-    public var title: String
-    public var note: String
-    public var deletedAtUnixSeconds: Int64
-    public var imageCount: Int64
+public struct ActivityDayRecord {
+    /**
+     * Local date as `YYYY-MM-DD`.
+     */
+    public var date: String
+    public var saves: UInt32
+    /**
+     * Note length in characters at the end of the day.
+     */
+    public var length: UInt64
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(archiveKey: String, id: Int64, title: String, note: String, deletedAtUnixSeconds: Int64, imageCount: Int64) {
-        self.archiveKey = archiveKey
-        self.id = id
-        self.title = title
-        self.note = note
-        self.deletedAtUnixSeconds = deletedAtUnixSeconds
-        self.imageCount = imageCount
+    public init(
+        /**
+         * Local date as `YYYY-MM-DD`.
+         */date: String, saves: UInt32, 
+        /**
+         * Note length in characters at the end of the day.
+         */length: UInt64) {
+        self.date = date
+        self.saves = saves
+        self.length = length
     }
 }
 
 #if compiler(>=6)
-extension DeletedItemPreviewRecord: Sendable {}
+extension ActivityDayRecord: Sendable {}
 #endif
 
 
-extension DeletedItemPreviewRecord: Equatable, Hashable {
-    public static func ==(lhs: DeletedItemPreviewRecord, rhs: DeletedItemPreviewRecord) -> Bool {
-        if lhs.archiveKey != rhs.archiveKey {
-            return false
-        }
-        if lhs.id != rhs.id {
-            return false
-        }
-        if lhs.title != rhs.title {
-            return false
-        }
-        if lhs.note != rhs.note {
+extension ActivityDayRecord: Equatable, Hashable {
+    public static func ==(lhs: ActivityDayRecord, rhs: ActivityDayRecord) -> Bool {
+        if lhs.date != rhs.date {
             return false
         }
-        if lhs.deletedAtUnixSeconds != rhs.deletedAtUnixSeconds {
+        if lhs.saves != rhs.saves {
             return false
         }
-        if lhs.imageCount != rhs.imageCount {
+        if lhs.length != rhs.length {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(archiveKey)
-        hasher.combine(id)
-        hasher.combine(title)
-        hasher.combine(note)
-        hasher.combine(deletedAtUnixSeconds)
-        hasher.combine(imageCount)
+        hasher.combine(date)
+        hasher.combine(saves)
+        hasher.combine(length)
     }
 }
 
@@ -552,26 +618,20 @@ extension DeletedItemPreviewRecord: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDeletedItemPreviewRecord: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeletedItemPreviewRecord {
+public struct FfiConverterTypeActivityDayRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ActivityDayRecord {
         return
-            try DeletedItemPreviewRecord(
-                archiveKey: FfiConverterString.read(from: &buf), 
-                id: FfiConverterInt64.read(from: &buf), 
-                title: FfiConverterString.read(from: &buf), 
-                note: FfiConverterString.read(from: &buf), 
-                deletedAtUnixSeconds: FfiConverterInt64.read(from: &buf), 
-                imageCount: FfiConverterInt64.read(from: &buf)
+            try ActivityDayRecord(
+                date: FfiConverterString.read(from: &buf), 
+                saves: FfiConverterUInt32.read(from: &buf), 
+                length: FfiConverterUInt64.read(from: &buf)
         )
     }
 
-    public static func write(_ value: DeletedItemPreviewRecord, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.archiveKey, into: &buf)
-        FfiConverterInt64.write(value.id, into: &buf)
-        FfiConverterString.write(value.title, into: &buf)
-        FfiConverterString.write(value.note, into: &buf)
-        FfiConverterInt64.write(value.deletedAtUnixSeconds, into: &buf)
-        FfiConverterInt64.write(value.imageCount, into: &buf)
+    public static func write(_ value: ActivityDayRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.date, into: &buf)
+        FfiConverterUInt32.write(value.saves, into: &buf)
+        FfiConverterUInt64.write(value.length, into: &buf)
     }
 }
 
@@ -579,67 +639,67 @@ public struct FfiConverterTypeDeletedItemPreviewRecord: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDeletedItemPreviewRecord_lift(_ buf: RustBuffer) throws -> DeletedItemPreviewRecord {
-    return try FfiConverterTypeDeletedItemPreviewRecord.lift(buf)
+public func FfiConverterTypeActivityDayRecord_lift(_ buf: RustBuffer) throws -> ActivityDayRecord {
+    return try FfiConverterTypeActivityDayRecord.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDeletedItemPreviewRecord_lower(_ value: DeletedItemPreviewRecord) -> RustBuffer {
-    return FfiConverterTypeDeletedItemPreviewRecord.lower(value)
+public func FfiConverterTypeActivityDayRecord_lower(_ value: ActivityDayRecord) -> RustBuffer {
+    return FfiConverterTypeActivityDayRecord.lower(value)
 }
 
 
-public struct DeletedItemRecord {
-    public var archiveKey: String
+public struct BoardCardRecord {
     public var id: Int64
     public var title: String
-    public var deletedAtUnixSeconds: Int64
-    public var imageCount: Int64
+    /**
+     * One of `board_statuses()`, without the leading `#`.
+     */
+    public var status: String
+    public var preview: String
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(archiveKey: String, id: Int64, title: String, deletedAtUnixSeconds: Int64, imageCount: Int64) {
-        self.archiveKey = archiveKey
+    public init(id: Int64, title: String, 
+        /**
+         * One of `board_statuses()`, without the leading `#`.
+         */status: String, preview: String) {
         self.id = id
         self.title = title
-        self.deletedAtUnixSeconds = deletedAtUnixSeconds
-        self.imageCount = imageCount
+        self.status = status
+        self.preview = preview
     }
 }
 
 #if compiler(>=6)
-extension DeletedItemRecord: Sendable {}
+extension BoardCardRecord: Sendable {}
 #endif
 
 
-extension DeletedItemRecord: Equatable, Hashable {
-    public static func ==(lhs: DeletedItemRecord, rhs: DeletedItemRecord) -> Bool {
-        if lhs.archiveKey != rhs.archiveKey {
-            return false
-        }
+extension BoardCardRecord: Equatable, Hashable {
+    public static func ==(lhs: BoardCardRecord, rhs: BoardCardRecord) -> Bool {
         if lhs.id != rhs.id {
             return false
         }
         if lhs.title != rhs.title {
             return false
         }
-        if lhs.deletedAtUnixSeconds != rhs.deletedAtUnixSeconds {
+        if lhs.status != rhs.status {
             return false
         }
-        if lhs.imageCount != rhs.imageCount {
+        if lhs.preview != rhs.preview {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(archiveKey)
         hasher.combine(id)
         hasher.combine(title)
-        hasher.combine(deletedAtUnixSeconds)
-        hasher.combine(imageCount)
+        hasher.combine(status)
+        hasher.combine(preview)
     }
 }
 
@@ -648,24 +708,22 @@ extension DeletedItemRecord: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDeletedItemRecord: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeletedItemRecord {
+public struct FfiConverterTypeBoardCardRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BoardCardRecord {
         return
-            try DeletedItemRecord(
-                archiveKey: FfiConverterString.read(from: &buf), 
+            try BoardCardRecord(
                 id: FfiConverterInt64.read(from: &buf), 
                 title: FfiConverterString.read(from: &buf), 
-                deletedAtUnixSeconds: FfiConverterInt64.read(from: &buf), 
-                imageCount: FfiConverterInt64.read(from: &buf)
+                status: FfiConverterString.read(from: &buf), 
+                preview: FfiConverterString.read(from: &buf)
         )
     }
 
-    public static func write(_ value: DeletedItemRecord, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.archiveKey, into: &buf)
+    public static func write(_ value: BoardCardRecord, into buf: inout [UInt8]) {
         FfiConverterInt64.write(value.id, into: &buf)
         FfiConverterString.write(value.title, into: &buf)
-        FfiConverterInt64.write(value.deletedAtUnixSeconds, into: &buf)
-        FfiConverterInt64.write(value.imageCount, into: &buf)
+        FfiConverterString.write(value.status, into: &buf)
+        FfiConverterString.write(value.preview, into: &buf)
     }
 }
 
@@ -673,61 +731,61 @@ public struct FfiConverterTypeDeletedItemRecord: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDeletedItemRecord_lift(_ buf: RustBuffer) throws -> DeletedItemRecord {
-    return try FfiConverterTypeDeletedItemRecord.lift(buf)
+public func FfiConverterTypeBoardCardRecord_lift(_ buf: RustBuffer) throws -> BoardCardRecord {
+    return try FfiConverterTypeBoardCardRecord.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDeletedItemRecord_lower(_ value: DeletedItemRecord) -> RustBuffer {
-    return FfiConverterTypeDeletedItemRecord.lower(value)
+public func FfiConverterTypeBoardCardRecord_lower(_ value: BoardCardRecord) -> RustBuffer {
+    return FfiConverterTypeBoardCardRecord.lower(value)
 }
 
 
-public struct EditableItemRecord {
-    public var id: Int64
+public struct CalendarEntryRecord {
+    public var day: UInt32
+    public var itemId: Int64
     public var title: String
-    public var note: String
-    public var images: [NoteImageRecord]
+    public var isJournal: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(id: Int64, title: String, note: String, images: [NoteImageRecord]) {
-        self.id = id
+    public init(day: UInt32, itemId: Int64, title: String, isJournal: Bool) {
+        self.day = day
+        self.itemId = itemId
         self.title = title
-        self.note = note
-        self.images = images
+        self.isJournal = isJournal
     }
 }
 
 #if compiler(>=6)
-extension EditableItemRecord: Sendable {}
+extension CalendarEntryRecord: Sendable {}
 #endif
 
 
-extension EditableItemRecord: Equatable, Hashable {
-    public static func ==(lhs: EditableItemRecord, rhs: EditableItemRecord) -> Bool {
-        if lhs.id != rhs.id {
+extension CalendarEntryRecord: Equatable, Hashable {
+    public static func ==(lhs: CalendarEntryRecord, rhs: CalendarEntryRecord) -> Bool {
+        if lhs.day != rhs.day {
             return false
         }
-        if lhs.title != rhs.title {
+        if lhs.itemId != rhs.itemId {
             return false
         }
-        if lhs.note != rhs.note {
+        if lhs.title != rhs.title {
             return false
         }
-        if lhs.images != rhs.images {
+        if lhs.isJournal != rhs.isJournal {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
+        hasher.combine(day)
+        hasher.combine(itemId)
         hasher.combine(title)
-        hasher.combine(note)
-        hasher.combine(images)
+        hasher.combine(isJournal)
     }
 }
 
@@ -736,22 +794,22 @@ extension EditableItemRecord: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeEditableItemRecord: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EditableItemRecord {
+public struct FfiConverterTypeCalendarEntryRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CalendarEntryRecord {
         return
-            try EditableItemRecord(
-                id: FfiConverterInt64.read(from: &buf), 
+            try CalendarEntryRecord(
+                day: FfiConverterUInt32.read(from: &buf), 
+                itemId: FfiConverterInt64.read(from: &buf), 
                 title: FfiConverterString.read(from: &buf), 
-                note: FfiConverterString.read(from: &buf), 
-                images: FfiConverterSequenceTypeNoteImageRecord.read(from: &buf)
+                isJournal: FfiConverterBool.read(from: &buf)
         )
     }
 
-    public static func write(_ value: EditableItemRecord, into buf: inout [UInt8]) {
-        FfiConverterInt64.write(value.id, into: &buf)
+    public static func write(_ value: CalendarEntryRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.day, into: &buf)
+        FfiConverterInt64.write(value.itemId, into: &buf)
         FfiConverterString.write(value.title, into: &buf)
-        FfiConverterString.write(value.note, into: &buf)
-        FfiConverterSequenceTypeNoteImageRecord.write(value.images, into: &buf)
+        FfiConverterBool.write(value.isJournal, into: &buf)
     }
 }
 
@@ -759,73 +817,49 @@ public struct FfiConverterTypeEditableItemRecord: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeEditableItemRecord_lift(_ buf: RustBuffer) throws -> EditableItemRecord {
-    return try FfiConverterTypeEditableItemRecord.lift(buf)
+public func FfiConverterTypeCalendarEntryRecord_lift(_ buf: RustBuffer) throws -> CalendarEntryRecord {
+    return try FfiConverterTypeCalendarEntryRecord.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeEditableItemRecord_lower(_ value: EditableItemRecord) -> RustBuffer {
-    return FfiConverterTypeEditableItemRecord.lower(value)
+public func FfiConverterTypeCalendarEntryRecord_lower(_ value: CalendarEntryRecord) -> RustBuffer {
+    return FfiConverterTypeCalendarEntryRecord.lower(value)
 }
 
 
-public struct ExportItemRecord {
-    public var id: Int64
-    public var title: String
-    public var subtitle: String
-    public var keywords: String
-    public var note: String
-    public var imageCount: Int64
+public struct ChecklistEntryRecord {
+    public var text: String
+    public var done: Bool
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(id: Int64, title: String, subtitle: String, keywords: String, note: String, imageCount: Int64) {
-        self.id = id
-        self.title = title
-        self.subtitle = subtitle
-        self.keywords = keywords
-        self.note = note
-        self.imageCount = imageCount
+    public init(text: String, done: Bool) {
+        self.text = text
+        self.done = done
     }
 }
 
 #if compiler(>=6)
-extension ExportItemRecord: Sendable {}
+extension ChecklistEntryRecord: Sendable {}
 #endif
 
 
-extension ExportItemRecord: Equatable, Hashable {
-    public static func ==(lhs: ExportItemRecord, rhs: ExportItemRecord) -> Bool {
-        if lhs.id != rhs.id {
-            return false
-        }
-        if lhs.title != rhs.title {
-            return false
-        }
-        if lhs.subtitle != rhs.subtitle {
-            return false
-        }
-        if lhs.keywords != rhs.keywords {
-            return false
-        }
-        if lhs.note != rhs.note {
+extension ChecklistEntryRecord: Equatable, Hashable {
+    public static func ==(lhs: ChecklistEntryRecord, rhs: ChecklistEntryRecord) -> Bool {
+        if lhs.text != rhs.text {
             return false
         }
-        if lhs.imageCount != rhs.imageCount {
+        if lhs.done != rhs.done {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
-        hasher.combine(title)
-        hasher.combine(subtitle)
-        hasher.combine(keywords)
-        hasher.combine(note)
-        hasher.combine(imageCount)
+        hasher.combine(text)
+        hasher.combine(done)
     }
 }
 
@@ -834,26 +868,18 @@ extension ExportItemRecord: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeExportItemRecord: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ExportItemRecord {
+public struct FfiConverterTypeChecklistEntryRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChecklistEntryRecord {
         return
-            try ExportItemRecord(
-                id: FfiConverterInt64.read(from: &buf), 
-                title: FfiConverterString.read(from: &buf), 
-                subtitle: FfiConverterString.read(from: &buf), 
-                keywords: FfiConverterString.read(from: &buf), 
-                note: FfiConverterString.read(from: &buf), 
-                imageCount: FfiConverterInt64.read(from: &buf)
+            try ChecklistEntryRecord(
+                text: FfiConverterString.read(from: &buf), 
+                done: FfiConverterBool.read(from: &buf)
         )
     }
 
-    public static func write(_ value: ExportItemRecord, into buf: inout [UInt8]) {
-        FfiConverterInt64.write(value.id, into: &buf)
-        FfiConverterString.write(value.title, into: &buf)
-        FfiConverterString.write(value.subtitle, into: &buf)
-        FfiConverterString.write(value.keywords, into: &buf)
-        FfiConverterString.write(value.note, into: &buf)
-        FfiConverterInt64.write(value.imageCount, into: &buf)
+    public static func write(_ value: ChecklistEntryRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterBool.write(value.done, into: &buf)
     }
 }
 
@@ -861,49 +887,49 @@ public struct FfiConverterTypeExportItemRecord: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeExportItemRecord_lift(_ buf: RustBuffer) throws -> ExportItemRecord {
-    return try FfiConverterTypeExportItemRecord.lift(buf)
+public func FfiConverterTypeChecklistEntryRecord_lift(_ buf: RustBuffer) throws -> ChecklistEntryRecord {
+    return try FfiConverterTypeChecklistEntryRecord.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeExportItemRecord_lower(_ value: ExportItemRecord) -> RustBuffer {
-    return FfiConverterTypeExportItemRecord.lower(value)
+public func FfiConverterTypeChecklistEntryRecord_lower(_ value: ChecklistEntryRecord) -> RustBuffer {
+    return FfiConverterTypeChecklistEntryRecord.lower(value)
 }
 
 
-public struct NoteImageRecord {
-    public var imageKey: String
-    public var bytes: Data
+public struct ChecklistProgressRecord {
+    public var done: UInt32
+    public var total: UInt32
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(imageKey: String, bytes: Data) {
-        self.imageKey = imageKey
-        self.bytes = bytes
+    public init(done: UInt32, total: UInt32) {
+        self.done = done
+        self.total = total
     }
 }
 
 #if compiler(>=6)
-extension NoteImageRecord: Sendable {}
+extension ChecklistProgressRecord: Sendable {}
 #endif
 
 
-extension NoteImageRecord: Equatable, Hashable {
-    public static func ==(lhs: NoteImageRecord, rhs: NoteImageRecord) -> Bool {
-        if lhs.imageKey != rhs.imageKey {
+extension ChecklistProgressRecord: Equatable, Hashable {
+    public static func ==(lhs: ChecklistProgressRecord, rhs: ChecklistProgressRecord) -> Bool {
+        if lhs.done != rhs.done {
             return false
         }
-        if lhs.bytes != rhs.bytes {
+        if lhs.total != rhs.total {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(imageKey)
-        hasher.combine(bytes)
+        hasher.combine(done)
+        hasher.combine(total)
     }
 }
 
@@ -912,18 +938,18 @@ extension NoteImageRecord: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNoteImageRecord: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NoteImageRecord {
+public struct FfiConverterTypeChecklistProgressRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChecklistProgressRecord {
         return
-            try NoteImageRecord(
-                imageKey: FfiConverterString.read(from: &buf), 
-                bytes: FfiConverterData.read(from: &buf)
+            try ChecklistProgressRecord(
+                done: FfiConverterUInt32.read(from: &buf), 
+                total: FfiConverterUInt32.read(from: &buf)
         )
     }
 
-    public static func write(_ value: NoteImageRecord, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.imageKey, into: &buf)
-        FfiConverterData.write(value.bytes, into: &buf)
+    public static func write(_ value: ChecklistProgressRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.done, into: &buf)
+        FfiConverterUInt32.write(value.total, into: &buf)
     }
 }
 
@@ -931,67 +957,149 @@ public struct FfiConverterTypeNoteImageRecord: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNoteImageRecord_lift(_ buf: RustBuffer) throws -> NoteImageRecord {
-    return try FfiConverterTypeNoteImageRecord.lift(buf)
+public func FfiConverterTypeChecklistProgressRecord_lift(_ buf: RustBuffer) throws -> ChecklistProgressRecord {
+    return try FfiConverterTypeChecklistProgressRecord.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNoteImageRecord_lower(_ value: NoteImageRecord) -> RustBuffer {
-    return FfiConverterTypeNoteImageRecord.lower(value)
+public func FfiConverterTypeChecklistProgressRecord_lower(_ value: ChecklistProgressRecord) -> RustBuffer {
+    return FfiConverterTypeChecklistProgressRecord.lower(value)
 }
 
 
-public struct SearchResultRecord {
-    public var id: Int64
-    public var title: String
-    public var subtitle: String
-    public var snippet: String?
-    public var snippetSource: String?
+public struct DailySearchesRecord {
+    /**
+     * Local date as `YYYY-MM-DD`.
+     */
+    public var date: String
+    public var searches: UInt32
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(id: Int64, title: String, subtitle: String, snippet: String?, snippetSource: String?) {
-        self.id = id
-        self.title = title
-        self.subtitle = subtitle
-        self.snippet = snippet
-        self.snippetSource = snippetSource
+    public init(
+        /**
+         * Local date as `YYYY-MM-DD`.
+         */date: String, searches: UInt32) {
+        self.date = date
+        self.searches = searches
     }
 }
 
 #if compiler(>=6)
-extension SearchResultRecord: Sendable {}
+extension DailySearchesRecord: Sendable {}
 #endif
 
 
-extension SearchResultRecord: Equatable, Hashable {
-    public static func ==(lhs: SearchResultRecord, rhs: SearchResultRecord) -> Bool {
+extension DailySearchesRecord: Equatable, Hashable {
+    public static func ==(lhs: DailySearchesRecord, rhs: DailySearchesRecord) -> Bool {
+        if lhs.date != rhs.date {
+            return false
+        }
+        if lhs.searches != rhs.searches {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(date)
+        hasher.combine(searches)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDailySearchesRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DailySearchesRecord {
+        return
+            try DailySearchesRecord(
+                date: FfiConverterString.read(from: &buf), 
+                searches: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DailySearchesRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.date, into: &buf)
+        FfiConverterUInt32.write(value.searches, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDailySearchesRecord_lift(_ buf: RustBuffer) throws -> DailySearchesRecord {
+    return try FfiConverterTypeDailySearchesRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDailySearchesRecord_lower(_ value: DailySearchesRecord) -> RustBuffer {
+    return FfiConverterTypeDailySearchesRecord.lower(value)
+}
+
+
+public struct DeletedItemPreviewRecord {
+    public var archiveKey: String
+    public var id: Int64
+    public var title: String
+    public var note: String
+    public var deletedAtUnixSeconds: Int64
+    public var imageCount: Int64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(archiveKey: String, id: Int64, title: String, note: String, deletedAtUnixSeconds: Int64, imageCount: Int64) {
+        self.archiveKey = archiveKey
+        self.id = id
+        self.title = title
+        self.note = note
+        self.deletedAtUnixSeconds = deletedAtUnixSeconds
+        self.imageCount = imageCount
+    }
+}
+
+#if compiler(>=6)
+extension DeletedItemPreviewRecord: Sendable {}
+#endif
+
+
+extension DeletedItemPreviewRecord: Equatable, Hashable {
+    public static func ==(lhs: DeletedItemPreviewRecord, rhs: DeletedItemPreviewRecord) -> Bool {
+        if lhs.archiveKey != rhs.archiveKey {
+            return false
+        }
         if lhs.id != rhs.id {
             return false
         }
         if lhs.title != rhs.title {
             return false
         }
-        if lhs.subtitle != rhs.subtitle {
+        if lhs.note != rhs.note {
             return false
         }
-        if lhs.snippet != rhs.snippet {
+        if lhs.deletedAtUnixSeconds != rhs.deletedAtUnixSeconds {
             return false
         }
-        if lhs.snippetSource != rhs.snippetSource {
+        if lhs.imageCount != rhs.imageCount {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
+        hasher.combine(archiveKey)
         hasher.combine(id)
         hasher.combine(title)
-        hasher.combine(subtitle)
-        hasher.combine(snippet)
-        hasher.combine(snippetSource)
+        hasher.combine(note)
+        hasher.combine(deletedAtUnixSeconds)
+        hasher.combine(imageCount)
     }
 }
 
@@ -1000,24 +1108,26 @@ extension SearchResultRecord: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSearchResultRecord: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResultRecord {
+public struct FfiConverterTypeDeletedItemPreviewRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeletedItemPreviewRecord {
         return
-            try SearchResultRecord(
+            try DeletedItemPreviewRecord(
+                archiveKey: FfiConverterString.read(from: &buf), 
                 id: FfiConverterInt64.read(from: &buf), 
                 title: FfiConverterString.read(from: &buf), 
-                subtitle: FfiConverterString.read(from: &buf), 
-                snippet: FfiConverterOptionString.read(from: &buf), 
-                snippetSource: FfiConverterOptionString.read(from: &buf)
+                note: FfiConverterString.read(from: &buf), 
+                deletedAtUnixSeconds: FfiConverterInt64.read(from: &buf), 
+                imageCount: FfiConverterInt64.read(from: &buf)
         )
     }
 
-    public static func write(_ value: SearchResultRecord, into buf: inout [UInt8]) {
+    public static func write(_ value: DeletedItemPreviewRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.archiveKey, into: &buf)
         FfiConverterInt64.write(value.id, into: &buf)
         FfiConverterString.write(value.title, into: &buf)
-        FfiConverterString.write(value.subtitle, into: &buf)
-        FfiConverterOptionString.write(value.snippet, into: &buf)
-        FfiConverterOptionString.write(value.snippetSource, into: &buf)
+        FfiConverterString.write(value.note, into: &buf)
+        FfiConverterInt64.write(value.deletedAtUnixSeconds, into: &buf)
+        FfiConverterInt64.write(value.imageCount, into: &buf)
     }
 }
 
@@ -1025,80 +1135,93 @@ public struct FfiConverterTypeSearchResultRecord: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSearchResultRecord_lift(_ buf: RustBuffer) throws -> SearchResultRecord {
-    return try FfiConverterTypeSearchResultRecord.lift(buf)
+public func FfiConverterTypeDeletedItemPreviewRecord_lift(_ buf: RustBuffer) throws -> DeletedItemPreviewRecord {
+    return try FfiConverterTypeDeletedItemPreviewRecord.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSearchResultRecord_lower(_ value: SearchResultRecord) -> RustBuffer {
-    return FfiConverterTypeSearchResultRecord.lower(value)
+public func FfiConverterTypeDeletedItemPreviewRecord_lower(_ value: DeletedItemPreviewRecord) -> RustBuffer {
+    return FfiConverterTypeDeletedItemPreviewRecord.lower(value)
 }
 
 
-public enum BackendError: Swift.Error {
+public struct DeletedItemRecord {
+    public var archiveKey: String
+    public var id: Int64
+    public var title: String
+    public var deletedAtUnixSeconds: Int64
+    public var imageCount: Int64
 
-    
-    
-    case Validation(String
-    )
-    case NotFound(String
-    )
-    case Storage(String
-    )
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(archiveKey: String, id: Int64, title: String, deletedAtUnixSeconds: Int64, imageCount: Int64) {
+        self.archiveKey = archiveKey
+        self.id = id
+        self.title = title
+        self.deletedAtUnixSeconds = deletedAtUnixSeconds
+        self.imageCount = imageCount
+    }
 }
 
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
+#if compiler(>=6)
+extension DeletedItemRecord: Sendable {}
 #endif
-public struct FfiConverterTypeBackendError: FfiConverterRustBuffer {
-    typealias SwiftType = BackendError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BackendError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
-
-        
-        case 1: return .Validation(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .NotFound(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .Storage(
-            try FfiConverterString.read(from: &buf)
-            )
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+extension DeletedItemRecord: Equatable, Hashable {
+    public static func ==(lhs: DeletedItemRecord, rhs: DeletedItemRecord) -> Bool {
+        if lhs.archiveKey != rhs.archiveKey {
+            return false
+        }
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.deletedAtUnixSeconds != rhs.deletedAtUnixSeconds {
+            return false
+        }
+        if lhs.imageCount != rhs.imageCount {
+            return false
         }
+        return true
     }
 
-    public static func write(_ value: BackendError, into buf: inout [UInt8]) {
-        switch value {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(archiveKey)
+        hasher.combine(id)
+        hasher.combine(title)
+        hasher.combine(deletedAtUnixSeconds)
+        hasher.combine(imageCount)
+    }
+}
 
-        
 
-        
-        
-        case let .Validation(v1):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .NotFound(v1):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .Storage(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(v1, into: &buf)
-            
-        }
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDeletedItemRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeletedItemRecord {
+        return
+            try DeletedItemRecord(
+                archiveKey: FfiConverterString.read(from: &buf), 
+                id: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                deletedAtUnixSeconds: FfiConverterInt64.read(from: &buf), 
+                imageCount: FfiConverterInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DeletedItemRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.archiveKey, into: &buf)
+        FfiConverterInt64.write(value.id, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterInt64.write(value.deletedAtUnixSeconds, into: &buf)
+        FfiConverterInt64.write(value.imageCount, into: &buf)
     }
 }
 
@@ -1106,289 +1229,7626 @@ public struct FfiConverterTypeBackendError: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeBackendError_lift(_ buf: RustBuffer) throws -> BackendError {
-    return try FfiConverterTypeBackendError.lift(buf)
+public func FfiConverterTypeDeletedItemRecord_lift(_ buf: RustBuffer) throws -> DeletedItemRecord {
+    return try FfiConverterTypeDeletedItemRecord.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeBackendError_lower(_ value: BackendError) -> RustBuffer {
-    return FfiConverterTypeBackendError.lower(value)
+public func FfiConverterTypeDeletedItemRecord_lower(_ value: DeletedItemRecord) -> RustBuffer {
+    return FfiConverterTypeDeletedItemRecord.lower(value)
 }
 
 
-extension BackendError: Equatable, Hashable {}
+public struct DiffSegmentRecord {
+    public var kind: DiffSegmentKind
+    public var text: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: DiffSegmentKind, text: String) {
+        self.kind = kind
+        self.text = text
+    }
+}
 
+#if compiler(>=6)
+extension DiffSegmentRecord: Sendable {}
+#endif
 
 
+extension DiffSegmentRecord: Equatable, Hashable {
+    public static func ==(lhs: DiffSegmentRecord, rhs: DiffSegmentRecord) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        return true
+    }
 
-extension BackendError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(text)
     }
 }
 
 
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
-    typealias SwiftType = UInt32?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterUInt32.write(value, into: &buf)
+public struct FfiConverterTypeDiffSegmentRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffSegmentRecord {
+        return
+            try DiffSegmentRecord(
+                kind: FfiConverterTypeDiffSegmentKind.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf)
+        )
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterUInt32.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
+    public static func write(_ value: DiffSegmentRecord, into buf: inout [UInt8]) {
+        FfiConverterTypeDiffSegmentKind.write(value.kind, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+public func FfiConverterTypeDiffSegmentRecord_lift(_ buf: RustBuffer) throws -> DiffSegmentRecord {
+    return try FfiConverterTypeDiffSegmentRecord.lift(buf)
+}
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
-    }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffSegmentRecord_lower(_ value: DiffSegmentRecord) -> RustBuffer {
+    return FfiConverterTypeDiffSegmentRecord.lower(value)
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
+
+public struct EditableItemRecord {
+    public var id: Int64
+    public var title: String
+    /**
+     * Shown under the title in the launcher's listing.
+     */
+    public var subtitle: String
+    /**
+     * Extra words the item is found by; the title by default.
+     */
+    public var keywords: String
+    public var note: String
+    public var images: [NoteImageRecord]
+    public var revision: UInt64
+    public var secure: Bool
+    /**
+     * Reason the item is not mirrored to the JSON storage folder, if excluded.
+     */
+    public var mirrorExclusion: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: Int64, title: String, 
+        /**
+         * Shown under the title in the launcher's listing.
+         */subtitle: String, 
+        /**
+         * Extra words the item is found by; the title by default.
+         */keywords: String, note: String, images: [NoteImageRecord], revision: UInt64, secure: Bool, 
+        /**
+         * Reason the item is not mirrored to the JSON storage folder, if excluded.
+         */mirrorExclusion: String?) {
+        self.id = id
+        self.title = title
+        self.subtitle = subtitle
+        self.keywords = keywords
+        self.note = note
+        self.images = images
+        self.revision = revision
+        self.secure = secure
+        self.mirrorExclusion = mirrorExclusion
     }
 }
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
+#if compiler(>=6)
+extension EditableItemRecord: Sendable {}
 #endif
-fileprivate struct FfiConverterSequenceTypeDeletedItemRecord: FfiConverterRustBuffer {
-    typealias SwiftType = [DeletedItemRecord]
 
-    public static func write(_ value: [DeletedItemRecord], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeDeletedItemRecord.write(item, into: &buf)
+
+extension EditableItemRecord: Equatable, Hashable {
+    public static func ==(lhs: EditableItemRecord, rhs: EditableItemRecord) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.subtitle != rhs.subtitle {
+            return false
+        }
+        if lhs.keywords != rhs.keywords {
+            return false
+        }
+        if lhs.note != rhs.note {
+            return false
+        }
+        if lhs.images != rhs.images {
+            return false
+        }
+        if lhs.revision != rhs.revision {
+            return false
+        }
+        if lhs.secure != rhs.secure {
+            return false
+        }
+        if lhs.mirrorExclusion != rhs.mirrorExclusion {
+            return false
         }
+        return true
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DeletedItemRecord] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [DeletedItemRecord]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeDeletedItemRecord.read(from: &buf))
-        }
-        return seq
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(title)
+        hasher.combine(subtitle)
+        hasher.combine(keywords)
+        hasher.combine(note)
+        hasher.combine(images)
+        hasher.combine(revision)
+        hasher.combine(secure)
+        hasher.combine(mirrorExclusion)
     }
 }
 
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeExportItemRecord: FfiConverterRustBuffer {
-    typealias SwiftType = [ExportItemRecord]
-
-    public static func write(_ value: [ExportItemRecord], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeExportItemRecord.write(item, into: &buf)
-        }
+public struct FfiConverterTypeEditableItemRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EditableItemRecord {
+        return
+            try EditableItemRecord(
+                id: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                subtitle: FfiConverterString.read(from: &buf), 
+                keywords: FfiConverterString.read(from: &buf), 
+                note: FfiConverterString.read(from: &buf), 
+                images: FfiConverterSequenceTypeNoteImageRecord.read(from: &buf), 
+                revision: FfiConverterUInt64.read(from: &buf), 
+                secure: FfiConverterBool.read(from: &buf), 
+                mirrorExclusion: FfiConverterOptionString.read(from: &buf)
+        )
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ExportItemRecord] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [ExportItemRecord]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeExportItemRecord.read(from: &buf))
-        }
-        return seq
+    public static func write(_ value: EditableItemRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.id, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterString.write(value.subtitle, into: &buf)
+        FfiConverterString.write(value.keywords, into: &buf)
+        FfiConverterString.write(value.note, into: &buf)
+        FfiConverterSequenceTypeNoteImageRecord.write(value.images, into: &buf)
+        FfiConverterUInt64.write(value.revision, into: &buf)
+        FfiConverterBool.write(value.secure, into: &buf)
+        FfiConverterOptionString.write(value.mirrorExclusion, into: &buf)
     }
 }
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeNoteImageRecord: FfiConverterRustBuffer {
-    typealias SwiftType = [NoteImageRecord]
+public func FfiConverterTypeEditableItemRecord_lift(_ buf: RustBuffer) throws -> EditableItemRecord {
+    return try FfiConverterTypeEditableItemRecord.lift(buf)
+}
 
-    public static func write(_ value: [NoteImageRecord], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeNoteImageRecord.write(item, into: &buf)
-        }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEditableItemRecord_lower(_ value: EditableItemRecord) -> RustBuffer {
+    return FfiConverterTypeEditableItemRecord.lower(value)
+}
+
+
+public struct ExportItemRecord {
+    public var id: Int64
+    public var title: String
+    public var subtitle: String
+    public var keywords: String
+    public var note: String
+    public var imageCount: Int64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: Int64, title: String, subtitle: String, keywords: String, note: String, imageCount: Int64) {
+        self.id = id
+        self.title = title
+        self.subtitle = subtitle
+        self.keywords = keywords
+        self.note = note
+        self.imageCount = imageCount
     }
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [NoteImageRecord] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [NoteImageRecord]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeNoteImageRecord.read(from: &buf))
+#if compiler(>=6)
+extension ExportItemRecord: Sendable {}
+#endif
+
+
+extension ExportItemRecord: Equatable, Hashable {
+    public static func ==(lhs: ExportItemRecord, rhs: ExportItemRecord) -> Bool {
+        if lhs.id != rhs.id {
+            return false
         }
-        return seq
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.subtitle != rhs.subtitle {
+            return false
+        }
+        if lhs.keywords != rhs.keywords {
+            return false
+        }
+        if lhs.note != rhs.note {
+            return false
+        }
+        if lhs.imageCount != rhs.imageCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(title)
+        hasher.combine(subtitle)
+        hasher.combine(keywords)
+        hasher.combine(note)
+        hasher.combine(imageCount)
     }
 }
 
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeSearchResultRecord: FfiConverterRustBuffer {
-    typealias SwiftType = [SearchResultRecord]
+public struct FfiConverterTypeExportItemRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ExportItemRecord {
+        return
+            try ExportItemRecord(
+                id: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                subtitle: FfiConverterString.read(from: &buf), 
+                keywords: FfiConverterString.read(from: &buf), 
+                note: FfiConverterString.read(from: &buf), 
+                imageCount: FfiConverterInt64.read(from: &buf)
+        )
+    }
 
-    public static func write(_ value: [SearchResultRecord], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeSearchResultRecord.write(item, into: &buf)
-        }
+    public static func write(_ value: ExportItemRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.id, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterString.write(value.subtitle, into: &buf)
+        FfiConverterString.write(value.keywords, into: &buf)
+        FfiConverterString.write(value.note, into: &buf)
+        FfiConverterInt64.write(value.imageCount, into: &buf)
     }
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SearchResultRecord] {
-        let len: Int32 = try readInt(&buf)
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExportItemRecord_lift(_ buf: RustBuffer) throws -> ExportItemRecord {
+    return try FfiConverterTypeExportItemRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExportItemRecord_lower(_ value: ExportItemRecord) -> RustBuffer {
+    return FfiConverterTypeExportItemRecord.lower(value)
+}
+
+
+/**
+ * A GitHub or GitLab account; `host` is set for self-hosted instances.
+ */
+public struct ForgeAccountRecord {
+    public var kind: ForgeKindRecord
+    public var host: String?
+    public var token: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: ForgeKindRecord, host: String?, token: String) {
+        self.kind = kind
+        self.host = host
+        self.token = token
+    }
+}
+
+#if compiler(>=6)
+extension ForgeAccountRecord: Sendable {}
+#endif
+
+
+extension ForgeAccountRecord: Equatable, Hashable {
+    public static func ==(lhs: ForgeAccountRecord, rhs: ForgeAccountRecord) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.host != rhs.host {
+            return false
+        }
+        if lhs.token != rhs.token {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(host)
+        hasher.combine(token)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeForgeAccountRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ForgeAccountRecord {
+        return
+            try ForgeAccountRecord(
+                kind: FfiConverterTypeForgeKindRecord.read(from: &buf), 
+                host: FfiConverterOptionString.read(from: &buf), 
+                token: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ForgeAccountRecord, into buf: inout [UInt8]) {
+        FfiConverterTypeForgeKindRecord.write(value.kind, into: &buf)
+        FfiConverterOptionString.write(value.host, into: &buf)
+        FfiConverterString.write(value.token, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeForgeAccountRecord_lift(_ buf: RustBuffer) throws -> ForgeAccountRecord {
+    return try FfiConverterTypeForgeAccountRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeForgeAccountRecord_lower(_ value: ForgeAccountRecord) -> RustBuffer {
+    return FfiConverterTypeForgeAccountRecord.lower(value)
+}
+
+
+public struct HashtagRecord {
+    /**
+     * Lowercased tag name without the `#`.
+     */
+    public var tag: String
+    /**
+     * Where the tag, including its `#`, sits in the note.
+     */
+    public var range: TextRangeRecord
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Lowercased tag name without the `#`.
+         */tag: String, 
+        /**
+         * Where the tag, including its `#`, sits in the note.
+         */range: TextRangeRecord) {
+        self.tag = tag
+        self.range = range
+    }
+}
+
+#if compiler(>=6)
+extension HashtagRecord: Sendable {}
+#endif
+
+
+extension HashtagRecord: Equatable, Hashable {
+    public static func ==(lhs: HashtagRecord, rhs: HashtagRecord) -> Bool {
+        if lhs.tag != rhs.tag {
+            return false
+        }
+        if lhs.range != rhs.range {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(tag)
+        hasher.combine(range)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHashtagRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HashtagRecord {
+        return
+            try HashtagRecord(
+                tag: FfiConverterString.read(from: &buf), 
+                range: FfiConverterTypeTextRangeRecord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HashtagRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.tag, into: &buf)
+        FfiConverterTypeTextRangeRecord.write(value.range, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHashtagRecord_lift(_ buf: RustBuffer) throws -> HashtagRecord {
+    return try FfiConverterTypeHashtagRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHashtagRecord_lower(_ value: HashtagRecord) -> RustBuffer {
+    return FfiConverterTypeHashtagRecord.lower(value)
+}
+
+
+/**
+ * Background wakeups since launch, for checking that the app idles when hidden.
+ */
+public struct IdleStatsRecord {
+    public var schedulerWakeups: UInt64
+    public var powerSavingSchedulerWakeups: UInt64
+    public var powerSaving: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(schedulerWakeups: UInt64, powerSavingSchedulerWakeups: UInt64, powerSaving: Bool) {
+        self.schedulerWakeups = schedulerWakeups
+        self.powerSavingSchedulerWakeups = powerSavingSchedulerWakeups
+        self.powerSaving = powerSaving
+    }
+}
+
+#if compiler(>=6)
+extension IdleStatsRecord: Sendable {}
+#endif
+
+
+extension IdleStatsRecord: Equatable, Hashable {
+    public static func ==(lhs: IdleStatsRecord, rhs: IdleStatsRecord) -> Bool {
+        if lhs.schedulerWakeups != rhs.schedulerWakeups {
+            return false
+        }
+        if lhs.powerSavingSchedulerWakeups != rhs.powerSavingSchedulerWakeups {
+            return false
+        }
+        if lhs.powerSaving != rhs.powerSaving {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(schedulerWakeups)
+        hasher.combine(powerSavingSchedulerWakeups)
+        hasher.combine(powerSaving)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeIdleStatsRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IdleStatsRecord {
+        return
+            try IdleStatsRecord(
+                schedulerWakeups: FfiConverterUInt64.read(from: &buf), 
+                powerSavingSchedulerWakeups: FfiConverterUInt64.read(from: &buf), 
+                powerSaving: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: IdleStatsRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.schedulerWakeups, into: &buf)
+        FfiConverterUInt64.write(value.powerSavingSchedulerWakeups, into: &buf)
+        FfiConverterBool.write(value.powerSaving, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIdleStatsRecord_lift(_ buf: RustBuffer) throws -> IdleStatsRecord {
+    return try FfiConverterTypeIdleStatsRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIdleStatsRecord_lower(_ value: IdleStatsRecord) -> RustBuffer {
+    return FfiConverterTypeIdleStatsRecord.lower(value)
+}
+
+
+/**
+ * Image files to read, in insertion order, and the files left out.
+ */
+public struct ImageImportPlanRecord {
+    public var paths: [String]
+    public var skipped: [SkippedImageRecord]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(paths: [String], skipped: [SkippedImageRecord]) {
+        self.paths = paths
+        self.skipped = skipped
+    }
+}
+
+#if compiler(>=6)
+extension ImageImportPlanRecord: Sendable {}
+#endif
+
+
+extension ImageImportPlanRecord: Equatable, Hashable {
+    public static func ==(lhs: ImageImportPlanRecord, rhs: ImageImportPlanRecord) -> Bool {
+        if lhs.paths != rhs.paths {
+            return false
+        }
+        if lhs.skipped != rhs.skipped {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(paths)
+        hasher.combine(skipped)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeImageImportPlanRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ImageImportPlanRecord {
+        return
+            try ImageImportPlanRecord(
+                paths: FfiConverterSequenceString.read(from: &buf), 
+                skipped: FfiConverterSequenceTypeSkippedImageRecord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ImageImportPlanRecord, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.paths, into: &buf)
+        FfiConverterSequenceTypeSkippedImageRecord.write(value.skipped, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageImportPlanRecord_lift(_ buf: RustBuffer) throws -> ImageImportPlanRecord {
+    return try FfiConverterTypeImageImportPlanRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageImportPlanRecord_lower(_ value: ImageImportPlanRecord) -> RustBuffer {
+    return FfiConverterTypeImageImportPlanRecord.lower(value)
+}
+
+
+public struct ImageLimitsRecord {
+    public var maxImagesPerNote: UInt32
+    public var maxImageBytes: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(maxImagesPerNote: UInt32, maxImageBytes: UInt64) {
+        self.maxImagesPerNote = maxImagesPerNote
+        self.maxImageBytes = maxImageBytes
+    }
+}
+
+#if compiler(>=6)
+extension ImageLimitsRecord: Sendable {}
+#endif
+
+
+extension ImageLimitsRecord: Equatable, Hashable {
+    public static func ==(lhs: ImageLimitsRecord, rhs: ImageLimitsRecord) -> Bool {
+        if lhs.maxImagesPerNote != rhs.maxImagesPerNote {
+            return false
+        }
+        if lhs.maxImageBytes != rhs.maxImageBytes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(maxImagesPerNote)
+        hasher.combine(maxImageBytes)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeImageLimitsRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ImageLimitsRecord {
+        return
+            try ImageLimitsRecord(
+                maxImagesPerNote: FfiConverterUInt32.read(from: &buf), 
+                maxImageBytes: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ImageLimitsRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.maxImagesPerNote, into: &buf)
+        FfiConverterUInt64.write(value.maxImageBytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageLimitsRecord_lift(_ buf: RustBuffer) throws -> ImageLimitsRecord {
+    return try FfiConverterTypeImageLimitsRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageLimitsRecord_lower(_ value: ImageLimitsRecord) -> RustBuffer {
+    return FfiConverterTypeImageLimitsRecord.lower(value)
+}
+
+
+public struct ImageReplacementRecord {
+    public var itemId: Int64
+    public var imageKey: String
+    /**
+     * Fingerprint from `list_stored_images`; the image is skipped if it changed.
+     */
+    public var fingerprint: UInt64
+    public var bytes: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemId: Int64, imageKey: String, 
+        /**
+         * Fingerprint from `list_stored_images`; the image is skipped if it changed.
+         */fingerprint: UInt64, bytes: Data) {
+        self.itemId = itemId
+        self.imageKey = imageKey
+        self.fingerprint = fingerprint
+        self.bytes = bytes
+    }
+}
+
+#if compiler(>=6)
+extension ImageReplacementRecord: Sendable {}
+#endif
+
+
+extension ImageReplacementRecord: Equatable, Hashable {
+    public static func ==(lhs: ImageReplacementRecord, rhs: ImageReplacementRecord) -> Bool {
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.imageKey != rhs.imageKey {
+            return false
+        }
+        if lhs.fingerprint != rhs.fingerprint {
+            return false
+        }
+        if lhs.bytes != rhs.bytes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemId)
+        hasher.combine(imageKey)
+        hasher.combine(fingerprint)
+        hasher.combine(bytes)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeImageReplacementRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ImageReplacementRecord {
+        return
+            try ImageReplacementRecord(
+                itemId: FfiConverterInt64.read(from: &buf), 
+                imageKey: FfiConverterString.read(from: &buf), 
+                fingerprint: FfiConverterUInt64.read(from: &buf), 
+                bytes: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ImageReplacementRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.itemId, into: &buf)
+        FfiConverterString.write(value.imageKey, into: &buf)
+        FfiConverterUInt64.write(value.fingerprint, into: &buf)
+        FfiConverterData.write(value.bytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageReplacementRecord_lift(_ buf: RustBuffer) throws -> ImageReplacementRecord {
+    return try FfiConverterTypeImageReplacementRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageReplacementRecord_lower(_ value: ImageReplacementRecord) -> RustBuffer {
+    return FfiConverterTypeImageReplacementRecord.lower(value)
+}
+
+
+public struct ImageTextRecord {
+    public var itemId: Int64
+    public var imageKey: String
+    /**
+     * Fingerprint from `list_images_without_text`; the image is skipped if it changed.
+     */
+    public var fingerprint: UInt64
+    /**
+     * Recognized text; empty marks an image without text so it isn't scanned again.
+     */
+    public var text: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemId: Int64, imageKey: String, 
+        /**
+         * Fingerprint from `list_images_without_text`; the image is skipped if it changed.
+         */fingerprint: UInt64, 
+        /**
+         * Recognized text; empty marks an image without text so it isn't scanned again.
+         */text: String) {
+        self.itemId = itemId
+        self.imageKey = imageKey
+        self.fingerprint = fingerprint
+        self.text = text
+    }
+}
+
+#if compiler(>=6)
+extension ImageTextRecord: Sendable {}
+#endif
+
+
+extension ImageTextRecord: Equatable, Hashable {
+    public static func ==(lhs: ImageTextRecord, rhs: ImageTextRecord) -> Bool {
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.imageKey != rhs.imageKey {
+            return false
+        }
+        if lhs.fingerprint != rhs.fingerprint {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemId)
+        hasher.combine(imageKey)
+        hasher.combine(fingerprint)
+        hasher.combine(text)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeImageTextRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ImageTextRecord {
+        return
+            try ImageTextRecord(
+                itemId: FfiConverterInt64.read(from: &buf), 
+                imageKey: FfiConverterString.read(from: &buf), 
+                fingerprint: FfiConverterUInt64.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ImageTextRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.itemId, into: &buf)
+        FfiConverterString.write(value.imageKey, into: &buf)
+        FfiConverterUInt64.write(value.fingerprint, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageTextRecord_lift(_ buf: RustBuffer) throws -> ImageTextRecord {
+    return try FfiConverterTypeImageTextRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeImageTextRecord_lower(_ value: ImageTextRecord) -> RustBuffer {
+    return FfiConverterTypeImageTextRecord.lower(value)
+}
+
+
+public struct ItemMarkdownRecord {
+    public var fileName: String
+    public var markdown: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(fileName: String, markdown: String) {
+        self.fileName = fileName
+        self.markdown = markdown
+    }
+}
+
+#if compiler(>=6)
+extension ItemMarkdownRecord: Sendable {}
+#endif
+
+
+extension ItemMarkdownRecord: Equatable, Hashable {
+    public static func ==(lhs: ItemMarkdownRecord, rhs: ItemMarkdownRecord) -> Bool {
+        if lhs.fileName != rhs.fileName {
+            return false
+        }
+        if lhs.markdown != rhs.markdown {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(fileName)
+        hasher.combine(markdown)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeItemMarkdownRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ItemMarkdownRecord {
+        return
+            try ItemMarkdownRecord(
+                fileName: FfiConverterString.read(from: &buf), 
+                markdown: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ItemMarkdownRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.fileName, into: &buf)
+        FfiConverterString.write(value.markdown, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemMarkdownRecord_lift(_ buf: RustBuffer) throws -> ItemMarkdownRecord {
+    return try FfiConverterTypeItemMarkdownRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemMarkdownRecord_lower(_ value: ItemMarkdownRecord) -> RustBuffer {
+    return FfiConverterTypeItemMarkdownRecord.lower(value)
+}
+
+
+public struct ItemMetadataRecord {
+    public var subtitle: String
+    public var keywords: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(subtitle: String, keywords: String) {
+        self.subtitle = subtitle
+        self.keywords = keywords
+    }
+}
+
+#if compiler(>=6)
+extension ItemMetadataRecord: Sendable {}
+#endif
+
+
+extension ItemMetadataRecord: Equatable, Hashable {
+    public static func ==(lhs: ItemMetadataRecord, rhs: ItemMetadataRecord) -> Bool {
+        if lhs.subtitle != rhs.subtitle {
+            return false
+        }
+        if lhs.keywords != rhs.keywords {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(subtitle)
+        hasher.combine(keywords)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeItemMetadataRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ItemMetadataRecord {
+        return
+            try ItemMetadataRecord(
+                subtitle: FfiConverterString.read(from: &buf), 
+                keywords: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ItemMetadataRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.subtitle, into: &buf)
+        FfiConverterString.write(value.keywords, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemMetadataRecord_lift(_ buf: RustBuffer) throws -> ItemMetadataRecord {
+    return try FfiConverterTypeItemMetadataRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemMetadataRecord_lower(_ value: ItemMetadataRecord) -> RustBuffer {
+    return FfiConverterTypeItemMetadataRecord.lower(value)
+}
+
+
+public struct ItemStatsRecord {
+    public var wordCount: UInt64
+    public var charCount: UInt64
+    /**
+     * 0 when the item predates timestamp tracking.
+     */
+    public var createdAtUnixSeconds: Int64
+    public var updatedAtUnixSeconds: Int64
+    public var versionCount: UInt32
+    /**
+     * Days with at least one save, oldest first.
+     */
+    public var days: [ActivityDayRecord]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(wordCount: UInt64, charCount: UInt64, 
+        /**
+         * 0 when the item predates timestamp tracking.
+         */createdAtUnixSeconds: Int64, updatedAtUnixSeconds: Int64, versionCount: UInt32, 
+        /**
+         * Days with at least one save, oldest first.
+         */days: [ActivityDayRecord]) {
+        self.wordCount = wordCount
+        self.charCount = charCount
+        self.createdAtUnixSeconds = createdAtUnixSeconds
+        self.updatedAtUnixSeconds = updatedAtUnixSeconds
+        self.versionCount = versionCount
+        self.days = days
+    }
+}
+
+#if compiler(>=6)
+extension ItemStatsRecord: Sendable {}
+#endif
+
+
+extension ItemStatsRecord: Equatable, Hashable {
+    public static func ==(lhs: ItemStatsRecord, rhs: ItemStatsRecord) -> Bool {
+        if lhs.wordCount != rhs.wordCount {
+            return false
+        }
+        if lhs.charCount != rhs.charCount {
+            return false
+        }
+        if lhs.createdAtUnixSeconds != rhs.createdAtUnixSeconds {
+            return false
+        }
+        if lhs.updatedAtUnixSeconds != rhs.updatedAtUnixSeconds {
+            return false
+        }
+        if lhs.versionCount != rhs.versionCount {
+            return false
+        }
+        if lhs.days != rhs.days {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(wordCount)
+        hasher.combine(charCount)
+        hasher.combine(createdAtUnixSeconds)
+        hasher.combine(updatedAtUnixSeconds)
+        hasher.combine(versionCount)
+        hasher.combine(days)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeItemStatsRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ItemStatsRecord {
+        return
+            try ItemStatsRecord(
+                wordCount: FfiConverterUInt64.read(from: &buf), 
+                charCount: FfiConverterUInt64.read(from: &buf), 
+                createdAtUnixSeconds: FfiConverterInt64.read(from: &buf), 
+                updatedAtUnixSeconds: FfiConverterInt64.read(from: &buf), 
+                versionCount: FfiConverterUInt32.read(from: &buf), 
+                days: FfiConverterSequenceTypeActivityDayRecord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ItemStatsRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.wordCount, into: &buf)
+        FfiConverterUInt64.write(value.charCount, into: &buf)
+        FfiConverterInt64.write(value.createdAtUnixSeconds, into: &buf)
+        FfiConverterInt64.write(value.updatedAtUnixSeconds, into: &buf)
+        FfiConverterUInt32.write(value.versionCount, into: &buf)
+        FfiConverterSequenceTypeActivityDayRecord.write(value.days, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemStatsRecord_lift(_ buf: RustBuffer) throws -> ItemStatsRecord {
+    return try FfiConverterTypeItemStatsRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemStatsRecord_lower(_ value: ItemStatsRecord) -> RustBuffer {
+    return FfiConverterTypeItemStatsRecord.lower(value)
+}
+
+
+public struct ItemStorageRecord {
+    public var itemId: Int64
+    public var title: String
+    public var bytes: UInt64
+    public var mirrored: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemId: Int64, title: String, bytes: UInt64, mirrored: Bool) {
+        self.itemId = itemId
+        self.title = title
+        self.bytes = bytes
+        self.mirrored = mirrored
+    }
+}
+
+#if compiler(>=6)
+extension ItemStorageRecord: Sendable {}
+#endif
+
+
+extension ItemStorageRecord: Equatable, Hashable {
+    public static func ==(lhs: ItemStorageRecord, rhs: ItemStorageRecord) -> Bool {
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.bytes != rhs.bytes {
+            return false
+        }
+        if lhs.mirrored != rhs.mirrored {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemId)
+        hasher.combine(title)
+        hasher.combine(bytes)
+        hasher.combine(mirrored)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeItemStorageRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ItemStorageRecord {
+        return
+            try ItemStorageRecord(
+                itemId: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                bytes: FfiConverterUInt64.read(from: &buf), 
+                mirrored: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ItemStorageRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.itemId, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterUInt64.write(value.bytes, into: &buf)
+        FfiConverterBool.write(value.mirrored, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemStorageRecord_lift(_ buf: RustBuffer) throws -> ItemStorageRecord {
+    return try FfiConverterTypeItemStorageRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeItemStorageRecord_lower(_ value: ItemStorageRecord) -> RustBuffer {
+    return FfiConverterTypeItemStorageRecord.lower(value)
+}
+
+
+/**
+ * What Return does on a Markdown list line in the editor.
+ */
+public struct ListContinuationRecord {
+    /**
+     * Typed after the line break to start the next item; empty when `ends_list`.
+     */
+    public var prefix: String
+    /**
+     * The item was empty: its marker is removed and no new item is started.
+     */
+    public var endsList: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Typed after the line break to start the next item; empty when `ends_list`.
+         */prefix: String, 
+        /**
+         * The item was empty: its marker is removed and no new item is started.
+         */endsList: Bool) {
+        self.prefix = prefix
+        self.endsList = endsList
+    }
+}
+
+#if compiler(>=6)
+extension ListContinuationRecord: Sendable {}
+#endif
+
+
+extension ListContinuationRecord: Equatable, Hashable {
+    public static func ==(lhs: ListContinuationRecord, rhs: ListContinuationRecord) -> Bool {
+        if lhs.prefix != rhs.prefix {
+            return false
+        }
+        if lhs.endsList != rhs.endsList {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(prefix)
+        hasher.combine(endsList)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeListContinuationRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ListContinuationRecord {
+        return
+            try ListContinuationRecord(
+                prefix: FfiConverterString.read(from: &buf), 
+                endsList: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ListContinuationRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.prefix, into: &buf)
+        FfiConverterBool.write(value.endsList, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeListContinuationRecord_lift(_ buf: RustBuffer) throws -> ListContinuationRecord {
+    return try FfiConverterTypeListContinuationRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeListContinuationRecord_lower(_ value: ListContinuationRecord) -> RustBuffer {
+    return FfiConverterTypeListContinuationRecord.lower(value)
+}
+
+
+public struct MergedNoteRecord {
+    public var text: String
+    /**
+     * Overlapping edits left between conflict markers.
+     */
+    public var conflictCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(text: String, 
+        /**
+         * Overlapping edits left between conflict markers.
+         */conflictCount: UInt32) {
+        self.text = text
+        self.conflictCount = conflictCount
+    }
+}
+
+#if compiler(>=6)
+extension MergedNoteRecord: Sendable {}
+#endif
+
+
+extension MergedNoteRecord: Equatable, Hashable {
+    public static func ==(lhs: MergedNoteRecord, rhs: MergedNoteRecord) -> Bool {
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.conflictCount != rhs.conflictCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(text)
+        hasher.combine(conflictCount)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMergedNoteRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MergedNoteRecord {
+        return
+            try MergedNoteRecord(
+                text: FfiConverterString.read(from: &buf), 
+                conflictCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MergedNoteRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterUInt32.write(value.conflictCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMergedNoteRecord_lift(_ buf: RustBuffer) throws -> MergedNoteRecord {
+    return try FfiConverterTypeMergedNoteRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMergedNoteRecord_lower(_ value: MergedNoteRecord) -> RustBuffer {
+    return FfiConverterTypeMergedNoteRecord.lower(value)
+}
+
+
+public struct NoteImageRecord {
+    public var imageKey: String
+    public var bytes: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(imageKey: String, bytes: Data) {
+        self.imageKey = imageKey
+        self.bytes = bytes
+    }
+}
+
+#if compiler(>=6)
+extension NoteImageRecord: Sendable {}
+#endif
+
+
+extension NoteImageRecord: Equatable, Hashable {
+    public static func ==(lhs: NoteImageRecord, rhs: NoteImageRecord) -> Bool {
+        if lhs.imageKey != rhs.imageKey {
+            return false
+        }
+        if lhs.bytes != rhs.bytes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(imageKey)
+        hasher.combine(bytes)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNoteImageRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NoteImageRecord {
+        return
+            try NoteImageRecord(
+                imageKey: FfiConverterString.read(from: &buf), 
+                bytes: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NoteImageRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.imageKey, into: &buf)
+        FfiConverterData.write(value.bytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNoteImageRecord_lift(_ buf: RustBuffer) throws -> NoteImageRecord {
+    return try FfiConverterTypeNoteImageRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNoteImageRecord_lower(_ value: NoteImageRecord) -> RustBuffer {
+    return FfiConverterTypeNoteImageRecord.lower(value)
+}
+
+
+public struct NoteTitleRecord {
+    public var itemId: Int64
+    public var title: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemId: Int64, title: String) {
+        self.itemId = itemId
+        self.title = title
+    }
+}
+
+#if compiler(>=6)
+extension NoteTitleRecord: Sendable {}
+#endif
+
+
+extension NoteTitleRecord: Equatable, Hashable {
+    public static func ==(lhs: NoteTitleRecord, rhs: NoteTitleRecord) -> Bool {
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemId)
+        hasher.combine(title)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNoteTitleRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NoteTitleRecord {
+        return
+            try NoteTitleRecord(
+                itemId: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NoteTitleRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.itemId, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNoteTitleRecord_lift(_ buf: RustBuffer) throws -> NoteTitleRecord {
+    return try FfiConverterTypeNoteTitleRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNoteTitleRecord_lower(_ value: NoteTitleRecord) -> RustBuffer {
+    return FfiConverterTypeNoteTitleRecord.lower(value)
+}
+
+
+public struct NoteVersionRecord {
+    /**
+     * Position in the item's history, oldest first.
+     */
+    public var index: UInt32
+    public var atUnixSeconds: Int64
+    /**
+     * Number of saves folded into this version.
+     */
+    public var saves: UInt32
+    public var charCount: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Position in the item's history, oldest first.
+         */index: UInt32, atUnixSeconds: Int64, 
+        /**
+         * Number of saves folded into this version.
+         */saves: UInt32, charCount: UInt64) {
+        self.index = index
+        self.atUnixSeconds = atUnixSeconds
+        self.saves = saves
+        self.charCount = charCount
+    }
+}
+
+#if compiler(>=6)
+extension NoteVersionRecord: Sendable {}
+#endif
+
+
+extension NoteVersionRecord: Equatable, Hashable {
+    public static func ==(lhs: NoteVersionRecord, rhs: NoteVersionRecord) -> Bool {
+        if lhs.index != rhs.index {
+            return false
+        }
+        if lhs.atUnixSeconds != rhs.atUnixSeconds {
+            return false
+        }
+        if lhs.saves != rhs.saves {
+            return false
+        }
+        if lhs.charCount != rhs.charCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(index)
+        hasher.combine(atUnixSeconds)
+        hasher.combine(saves)
+        hasher.combine(charCount)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNoteVersionRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NoteVersionRecord {
+        return
+            try NoteVersionRecord(
+                index: FfiConverterUInt32.read(from: &buf), 
+                atUnixSeconds: FfiConverterInt64.read(from: &buf), 
+                saves: FfiConverterUInt32.read(from: &buf), 
+                charCount: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NoteVersionRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.index, into: &buf)
+        FfiConverterInt64.write(value.atUnixSeconds, into: &buf)
+        FfiConverterUInt32.write(value.saves, into: &buf)
+        FfiConverterUInt64.write(value.charCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNoteVersionRecord_lift(_ buf: RustBuffer) throws -> NoteVersionRecord {
+    return try FfiConverterTypeNoteVersionRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNoteVersionRecord_lower(_ value: NoteVersionRecord) -> RustBuffer {
+    return FfiConverterTypeNoteVersionRecord.lower(value)
+}
+
+
+/**
+ * Outcome of importing a Notion export.
+ */
+public struct NotionImportRecord {
+    public var importedCount: UInt32
+    /**
+     * Embedded images left out because they were missing, unreachable or
+     * over the image limits.
+     */
+    public var skippedImageCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(importedCount: UInt32, 
+        /**
+         * Embedded images left out because they were missing, unreachable or
+         * over the image limits.
+         */skippedImageCount: UInt32) {
+        self.importedCount = importedCount
+        self.skippedImageCount = skippedImageCount
+    }
+}
+
+#if compiler(>=6)
+extension NotionImportRecord: Sendable {}
+#endif
+
+
+extension NotionImportRecord: Equatable, Hashable {
+    public static func ==(lhs: NotionImportRecord, rhs: NotionImportRecord) -> Bool {
+        if lhs.importedCount != rhs.importedCount {
+            return false
+        }
+        if lhs.skippedImageCount != rhs.skippedImageCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(importedCount)
+        hasher.combine(skippedImageCount)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNotionImportRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NotionImportRecord {
+        return
+            try NotionImportRecord(
+                importedCount: FfiConverterUInt32.read(from: &buf), 
+                skippedImageCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NotionImportRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.importedCount, into: &buf)
+        FfiConverterUInt32.write(value.skippedImageCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNotionImportRecord_lift(_ buf: RustBuffer) throws -> NotionImportRecord {
+    return try FfiConverterTypeNotionImportRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNotionImportRecord_lower(_ value: NotionImportRecord) -> RustBuffer {
+    return FfiConverterTypeNotionImportRecord.lower(value)
+}
+
+
+public struct OpenedItemRecord {
+    public var id: Int64
+    public var title: String
+    public var opens: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: Int64, title: String, opens: UInt32) {
+        self.id = id
+        self.title = title
+        self.opens = opens
+    }
+}
+
+#if compiler(>=6)
+extension OpenedItemRecord: Sendable {}
+#endif
+
+
+extension OpenedItemRecord: Equatable, Hashable {
+    public static func ==(lhs: OpenedItemRecord, rhs: OpenedItemRecord) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.opens != rhs.opens {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(title)
+        hasher.combine(opens)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOpenedItemRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OpenedItemRecord {
+        return
+            try OpenedItemRecord(
+                id: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                opens: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OpenedItemRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.id, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterUInt32.write(value.opens, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOpenedItemRecord_lift(_ buf: RustBuffer) throws -> OpenedItemRecord {
+    return try FfiConverterTypeOpenedItemRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOpenedItemRecord_lower(_ value: OpenedItemRecord) -> RustBuffer {
+    return FfiConverterTypeOpenedItemRecord.lower(value)
+}
+
+
+public struct OutlineEntryRecord {
+    /**
+     * 1 to 3, like preview headings.
+     */
+    public var level: UInt32
+    public var title: String
+    /**
+     * 0-based line of the note the heading is on.
+     */
+    public var line: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * 1 to 3, like preview headings.
+         */level: UInt32, title: String, 
+        /**
+         * 0-based line of the note the heading is on.
+         */line: UInt32) {
+        self.level = level
+        self.title = title
+        self.line = line
+    }
+}
+
+#if compiler(>=6)
+extension OutlineEntryRecord: Sendable {}
+#endif
+
+
+extension OutlineEntryRecord: Equatable, Hashable {
+    public static func ==(lhs: OutlineEntryRecord, rhs: OutlineEntryRecord) -> Bool {
+        if lhs.level != rhs.level {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.line != rhs.line {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(level)
+        hasher.combine(title)
+        hasher.combine(line)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOutlineEntryRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OutlineEntryRecord {
+        return
+            try OutlineEntryRecord(
+                level: FfiConverterUInt32.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                line: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OutlineEntryRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.level, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterUInt32.write(value.line, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOutlineEntryRecord_lift(_ buf: RustBuffer) throws -> OutlineEntryRecord {
+    return try FfiConverterTypeOutlineEntryRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOutlineEntryRecord_lower(_ value: OutlineEntryRecord) -> RustBuffer {
+    return FfiConverterTypeOutlineEntryRecord.lower(value)
+}
+
+
+/**
+ * A block of the editor's rendered preview; `text` keeps inline Markdown.
+ */
+public struct PreviewBlockRecord {
+    public var kind: PreviewBlockKindRecord
+    public var text: String
+    /**
+     * Heading level, or list nesting depth.
+     */
+    public var level: UInt32
+    public var marker: String
+    public var done: Bool
+    public var imageKey: String?
+    public var imageWidth: UInt32?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: PreviewBlockKindRecord, text: String, 
+        /**
+         * Heading level, or list nesting depth.
+         */level: UInt32, marker: String, done: Bool, imageKey: String?, imageWidth: UInt32?) {
+        self.kind = kind
+        self.text = text
+        self.level = level
+        self.marker = marker
+        self.done = done
+        self.imageKey = imageKey
+        self.imageWidth = imageWidth
+    }
+}
+
+#if compiler(>=6)
+extension PreviewBlockRecord: Sendable {}
+#endif
+
+
+extension PreviewBlockRecord: Equatable, Hashable {
+    public static func ==(lhs: PreviewBlockRecord, rhs: PreviewBlockRecord) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.level != rhs.level {
+            return false
+        }
+        if lhs.marker != rhs.marker {
+            return false
+        }
+        if lhs.done != rhs.done {
+            return false
+        }
+        if lhs.imageKey != rhs.imageKey {
+            return false
+        }
+        if lhs.imageWidth != rhs.imageWidth {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(text)
+        hasher.combine(level)
+        hasher.combine(marker)
+        hasher.combine(done)
+        hasher.combine(imageKey)
+        hasher.combine(imageWidth)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePreviewBlockRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PreviewBlockRecord {
+        return
+            try PreviewBlockRecord(
+                kind: FfiConverterTypePreviewBlockKindRecord.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                level: FfiConverterUInt32.read(from: &buf), 
+                marker: FfiConverterString.read(from: &buf), 
+                done: FfiConverterBool.read(from: &buf), 
+                imageKey: FfiConverterOptionString.read(from: &buf), 
+                imageWidth: FfiConverterOptionUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PreviewBlockRecord, into buf: inout [UInt8]) {
+        FfiConverterTypePreviewBlockKindRecord.write(value.kind, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterUInt32.write(value.level, into: &buf)
+        FfiConverterString.write(value.marker, into: &buf)
+        FfiConverterBool.write(value.done, into: &buf)
+        FfiConverterOptionString.write(value.imageKey, into: &buf)
+        FfiConverterOptionUInt32.write(value.imageWidth, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreviewBlockRecord_lift(_ buf: RustBuffer) throws -> PreviewBlockRecord {
+    return try FfiConverterTypePreviewBlockRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreviewBlockRecord_lower(_ value: PreviewBlockRecord) -> RustBuffer {
+    return FfiConverterTypePreviewBlockRecord.lower(value)
+}
+
+
+/**
+ * Style overrides for the rendered preview from the user's style file; unset
+ * fields keep the built-in look.
+ */
+public struct PreviewStyleRecord {
+    public var fontFamily: String?
+    public var fontSize: Double?
+    public var maxWidth: Double?
+    /**
+     * `h1` to `h3`.
+     */
+    public var headingSizes: [Double?]
+    public var codeFontFamily: String?
+    /**
+     * 0xRRGGBBAA.
+     */
+    public var codeColor: UInt32?
+    public var codeBackground: UInt32?
+    /**
+     * Rules and declarations that were skipped, for the settings screen.
+     */
+    public var warnings: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(fontFamily: String?, fontSize: Double?, maxWidth: Double?, 
+        /**
+         * `h1` to `h3`.
+         */headingSizes: [Double?], codeFontFamily: String?, 
+        /**
+         * 0xRRGGBBAA.
+         */codeColor: UInt32?, codeBackground: UInt32?, 
+        /**
+         * Rules and declarations that were skipped, for the settings screen.
+         */warnings: [String]) {
+        self.fontFamily = fontFamily
+        self.fontSize = fontSize
+        self.maxWidth = maxWidth
+        self.headingSizes = headingSizes
+        self.codeFontFamily = codeFontFamily
+        self.codeColor = codeColor
+        self.codeBackground = codeBackground
+        self.warnings = warnings
+    }
+}
+
+#if compiler(>=6)
+extension PreviewStyleRecord: Sendable {}
+#endif
+
+
+extension PreviewStyleRecord: Equatable, Hashable {
+    public static func ==(lhs: PreviewStyleRecord, rhs: PreviewStyleRecord) -> Bool {
+        if lhs.fontFamily != rhs.fontFamily {
+            return false
+        }
+        if lhs.fontSize != rhs.fontSize {
+            return false
+        }
+        if lhs.maxWidth != rhs.maxWidth {
+            return false
+        }
+        if lhs.headingSizes != rhs.headingSizes {
+            return false
+        }
+        if lhs.codeFontFamily != rhs.codeFontFamily {
+            return false
+        }
+        if lhs.codeColor != rhs.codeColor {
+            return false
+        }
+        if lhs.codeBackground != rhs.codeBackground {
+            return false
+        }
+        if lhs.warnings != rhs.warnings {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(fontFamily)
+        hasher.combine(fontSize)
+        hasher.combine(maxWidth)
+        hasher.combine(headingSizes)
+        hasher.combine(codeFontFamily)
+        hasher.combine(codeColor)
+        hasher.combine(codeBackground)
+        hasher.combine(warnings)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePreviewStyleRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PreviewStyleRecord {
+        return
+            try PreviewStyleRecord(
+                fontFamily: FfiConverterOptionString.read(from: &buf), 
+                fontSize: FfiConverterOptionDouble.read(from: &buf), 
+                maxWidth: FfiConverterOptionDouble.read(from: &buf), 
+                headingSizes: FfiConverterSequenceOptionDouble.read(from: &buf), 
+                codeFontFamily: FfiConverterOptionString.read(from: &buf), 
+                codeColor: FfiConverterOptionUInt32.read(from: &buf), 
+                codeBackground: FfiConverterOptionUInt32.read(from: &buf), 
+                warnings: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PreviewStyleRecord, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.fontFamily, into: &buf)
+        FfiConverterOptionDouble.write(value.fontSize, into: &buf)
+        FfiConverterOptionDouble.write(value.maxWidth, into: &buf)
+        FfiConverterSequenceOptionDouble.write(value.headingSizes, into: &buf)
+        FfiConverterOptionString.write(value.codeFontFamily, into: &buf)
+        FfiConverterOptionUInt32.write(value.codeColor, into: &buf)
+        FfiConverterOptionUInt32.write(value.codeBackground, into: &buf)
+        FfiConverterSequenceString.write(value.warnings, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreviewStyleRecord_lift(_ buf: RustBuffer) throws -> PreviewStyleRecord {
+    return try FfiConverterTypePreviewStyleRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreviewStyleRecord_lower(_ value: PreviewStyleRecord) -> RustBuffer {
+    return FfiConverterTypePreviewStyleRecord.lower(value)
+}
+
+
+/**
+ * What the app does after a provider result was picked; `value` holds the
+ * URL or text.
+ */
+public struct ProviderOutcomeRecord {
+    public var kind: ProviderOutcomeKind
+    public var value: String?
+    public var itemId: Int64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: ProviderOutcomeKind, value: String?, itemId: Int64?) {
+        self.kind = kind
+        self.value = value
+        self.itemId = itemId
+    }
+}
+
+#if compiler(>=6)
+extension ProviderOutcomeRecord: Sendable {}
+#endif
+
+
+extension ProviderOutcomeRecord: Equatable, Hashable {
+    public static func ==(lhs: ProviderOutcomeRecord, rhs: ProviderOutcomeRecord) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.value != rhs.value {
+            return false
+        }
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(value)
+        hasher.combine(itemId)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProviderOutcomeRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProviderOutcomeRecord {
+        return
+            try ProviderOutcomeRecord(
+                kind: FfiConverterTypeProviderOutcomeKind.read(from: &buf), 
+                value: FfiConverterOptionString.read(from: &buf), 
+                itemId: FfiConverterOptionInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ProviderOutcomeRecord, into buf: inout [UInt8]) {
+        FfiConverterTypeProviderOutcomeKind.write(value.kind, into: &buf)
+        FfiConverterOptionString.write(value.value, into: &buf)
+        FfiConverterOptionInt64.write(value.itemId, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProviderOutcomeRecord_lift(_ buf: RustBuffer) throws -> ProviderOutcomeRecord {
+    return try FfiConverterTypeProviderOutcomeRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProviderOutcomeRecord_lower(_ value: ProviderOutcomeRecord) -> RustBuffer {
+    return FfiConverterTypeProviderOutcomeRecord.lower(value)
+}
+
+
+/**
+ * Outcome of `read <url>`: the new item, or that the page was unreachable
+ * and queued to be fetched again.
+ */
+public struct ReadLaterRecord {
+    public var itemId: Int64?
+    public var queued: Bool
+    /**
+     * Why the page was queued.
+     */
+    public var message: String?
+    public var skippedImageCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemId: Int64?, queued: Bool, 
+        /**
+         * Why the page was queued.
+         */message: String?, skippedImageCount: UInt32) {
+        self.itemId = itemId
+        self.queued = queued
+        self.message = message
+        self.skippedImageCount = skippedImageCount
+    }
+}
+
+#if compiler(>=6)
+extension ReadLaterRecord: Sendable {}
+#endif
+
+
+extension ReadLaterRecord: Equatable, Hashable {
+    public static func ==(lhs: ReadLaterRecord, rhs: ReadLaterRecord) -> Bool {
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.queued != rhs.queued {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.skippedImageCount != rhs.skippedImageCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemId)
+        hasher.combine(queued)
+        hasher.combine(message)
+        hasher.combine(skippedImageCount)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReadLaterRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReadLaterRecord {
+        return
+            try ReadLaterRecord(
+                itemId: FfiConverterOptionInt64.read(from: &buf), 
+                queued: FfiConverterBool.read(from: &buf), 
+                message: FfiConverterOptionString.read(from: &buf), 
+                skippedImageCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ReadLaterRecord, into buf: inout [UInt8]) {
+        FfiConverterOptionInt64.write(value.itemId, into: &buf)
+        FfiConverterBool.write(value.queued, into: &buf)
+        FfiConverterOptionString.write(value.message, into: &buf)
+        FfiConverterUInt32.write(value.skippedImageCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReadLaterRecord_lift(_ buf: RustBuffer) throws -> ReadLaterRecord {
+    return try FfiConverterTypeReadLaterRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReadLaterRecord_lower(_ value: ReadLaterRecord) -> RustBuffer {
+    return FfiConverterTypeReadLaterRecord.lower(value)
+}
+
+
+public struct RunningTimerRecord {
+    public var itemId: Int64
+    public var title: String
+    public var startedAtUnixSeconds: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemId: Int64, title: String, startedAtUnixSeconds: UInt64) {
+        self.itemId = itemId
+        self.title = title
+        self.startedAtUnixSeconds = startedAtUnixSeconds
+    }
+}
+
+#if compiler(>=6)
+extension RunningTimerRecord: Sendable {}
+#endif
+
+
+extension RunningTimerRecord: Equatable, Hashable {
+    public static func ==(lhs: RunningTimerRecord, rhs: RunningTimerRecord) -> Bool {
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.startedAtUnixSeconds != rhs.startedAtUnixSeconds {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemId)
+        hasher.combine(title)
+        hasher.combine(startedAtUnixSeconds)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRunningTimerRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RunningTimerRecord {
+        return
+            try RunningTimerRecord(
+                itemId: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                startedAtUnixSeconds: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RunningTimerRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.itemId, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterUInt64.write(value.startedAtUnixSeconds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRunningTimerRecord_lift(_ buf: RustBuffer) throws -> RunningTimerRecord {
+    return try FfiConverterTypeRunningTimerRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRunningTimerRecord_lower(_ value: RunningTimerRecord) -> RustBuffer {
+    return FfiConverterTypeRunningTimerRecord.lower(value)
+}
+
+
+public struct ScheduleRecord {
+    public var id: String
+    public var name: String
+    public var titleTemplate: String
+    public var noteTemplate: String
+    public var recurrence: RecurrenceKind
+    /**
+     * Day of week for weekly schedules, Monday = 0; ignored for daily ones.
+     */
+    public var weekday: UInt32
+    public var hour: UInt32
+    public var minute: UInt32
+    public var enabled: Bool
+    /**
+     * Read-only; ignored by `save_schedule`.
+     */
+    public var nextRunUnixSeconds: Int64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, name: String, titleTemplate: String, noteTemplate: String, recurrence: RecurrenceKind, 
+        /**
+         * Day of week for weekly schedules, Monday = 0; ignored for daily ones.
+         */weekday: UInt32, hour: UInt32, minute: UInt32, enabled: Bool, 
+        /**
+         * Read-only; ignored by `save_schedule`.
+         */nextRunUnixSeconds: Int64?) {
+        self.id = id
+        self.name = name
+        self.titleTemplate = titleTemplate
+        self.noteTemplate = noteTemplate
+        self.recurrence = recurrence
+        self.weekday = weekday
+        self.hour = hour
+        self.minute = minute
+        self.enabled = enabled
+        self.nextRunUnixSeconds = nextRunUnixSeconds
+    }
+}
+
+#if compiler(>=6)
+extension ScheduleRecord: Sendable {}
+#endif
+
+
+extension ScheduleRecord: Equatable, Hashable {
+    public static func ==(lhs: ScheduleRecord, rhs: ScheduleRecord) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.titleTemplate != rhs.titleTemplate {
+            return false
+        }
+        if lhs.noteTemplate != rhs.noteTemplate {
+            return false
+        }
+        if lhs.recurrence != rhs.recurrence {
+            return false
+        }
+        if lhs.weekday != rhs.weekday {
+            return false
+        }
+        if lhs.hour != rhs.hour {
+            return false
+        }
+        if lhs.minute != rhs.minute {
+            return false
+        }
+        if lhs.enabled != rhs.enabled {
+            return false
+        }
+        if lhs.nextRunUnixSeconds != rhs.nextRunUnixSeconds {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(name)
+        hasher.combine(titleTemplate)
+        hasher.combine(noteTemplate)
+        hasher.combine(recurrence)
+        hasher.combine(weekday)
+        hasher.combine(hour)
+        hasher.combine(minute)
+        hasher.combine(enabled)
+        hasher.combine(nextRunUnixSeconds)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeScheduleRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ScheduleRecord {
+        return
+            try ScheduleRecord(
+                id: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                titleTemplate: FfiConverterString.read(from: &buf), 
+                noteTemplate: FfiConverterString.read(from: &buf), 
+                recurrence: FfiConverterTypeRecurrenceKind.read(from: &buf), 
+                weekday: FfiConverterUInt32.read(from: &buf), 
+                hour: FfiConverterUInt32.read(from: &buf), 
+                minute: FfiConverterUInt32.read(from: &buf), 
+                enabled: FfiConverterBool.read(from: &buf), 
+                nextRunUnixSeconds: FfiConverterOptionInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ScheduleRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.titleTemplate, into: &buf)
+        FfiConverterString.write(value.noteTemplate, into: &buf)
+        FfiConverterTypeRecurrenceKind.write(value.recurrence, into: &buf)
+        FfiConverterUInt32.write(value.weekday, into: &buf)
+        FfiConverterUInt32.write(value.hour, into: &buf)
+        FfiConverterUInt32.write(value.minute, into: &buf)
+        FfiConverterBool.write(value.enabled, into: &buf)
+        FfiConverterOptionInt64.write(value.nextRunUnixSeconds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeScheduleRecord_lift(_ buf: RustBuffer) throws -> ScheduleRecord {
+    return try FfiConverterTypeScheduleRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeScheduleRecord_lower(_ value: ScheduleRecord) -> RustBuffer {
+    return FfiConverterTypeScheduleRecord.lower(value)
+}
+
+
+public struct SearchResultRecord {
+    public var id: Int64
+    public var title: String
+    public var subtitle: String
+    public var snippet: String?
+    public var snippetSource: String?
+    public var kind: SearchResultKind
+    /**
+     * Group the launcher lists the result under.
+     */
+    public var section: SearchResultSection
+    public var icon: String?
+    public var commandKey: String?
+    public var pinned: Bool
+    public var thumbnailKey: String?
+    /**
+     * Parts of `title` matching the query, for highlighting.
+     */
+    public var titleMatchRanges: [TextRangeRecord]
+    public var checklistProgress: ChecklistProgressRecord?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: Int64, title: String, subtitle: String, snippet: String?, snippetSource: String?, kind: SearchResultKind, 
+        /**
+         * Group the launcher lists the result under.
+         */section: SearchResultSection, icon: String?, commandKey: String?, pinned: Bool, thumbnailKey: String?, 
+        /**
+         * Parts of `title` matching the query, for highlighting.
+         */titleMatchRanges: [TextRangeRecord], checklistProgress: ChecklistProgressRecord?) {
+        self.id = id
+        self.title = title
+        self.subtitle = subtitle
+        self.snippet = snippet
+        self.snippetSource = snippetSource
+        self.kind = kind
+        self.section = section
+        self.icon = icon
+        self.commandKey = commandKey
+        self.pinned = pinned
+        self.thumbnailKey = thumbnailKey
+        self.titleMatchRanges = titleMatchRanges
+        self.checklistProgress = checklistProgress
+    }
+}
+
+#if compiler(>=6)
+extension SearchResultRecord: Sendable {}
+#endif
+
+
+extension SearchResultRecord: Equatable, Hashable {
+    public static func ==(lhs: SearchResultRecord, rhs: SearchResultRecord) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.subtitle != rhs.subtitle {
+            return false
+        }
+        if lhs.snippet != rhs.snippet {
+            return false
+        }
+        if lhs.snippetSource != rhs.snippetSource {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.section != rhs.section {
+            return false
+        }
+        if lhs.icon != rhs.icon {
+            return false
+        }
+        if lhs.commandKey != rhs.commandKey {
+            return false
+        }
+        if lhs.pinned != rhs.pinned {
+            return false
+        }
+        if lhs.thumbnailKey != rhs.thumbnailKey {
+            return false
+        }
+        if lhs.titleMatchRanges != rhs.titleMatchRanges {
+            return false
+        }
+        if lhs.checklistProgress != rhs.checklistProgress {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(title)
+        hasher.combine(subtitle)
+        hasher.combine(snippet)
+        hasher.combine(snippetSource)
+        hasher.combine(kind)
+        hasher.combine(section)
+        hasher.combine(icon)
+        hasher.combine(commandKey)
+        hasher.combine(pinned)
+        hasher.combine(thumbnailKey)
+        hasher.combine(titleMatchRanges)
+        hasher.combine(checklistProgress)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchResultRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResultRecord {
+        return
+            try SearchResultRecord(
+                id: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                subtitle: FfiConverterString.read(from: &buf), 
+                snippet: FfiConverterOptionString.read(from: &buf), 
+                snippetSource: FfiConverterOptionString.read(from: &buf), 
+                kind: FfiConverterTypeSearchResultKind.read(from: &buf), 
+                section: FfiConverterTypeSearchResultSection.read(from: &buf), 
+                icon: FfiConverterOptionString.read(from: &buf), 
+                commandKey: FfiConverterOptionString.read(from: &buf), 
+                pinned: FfiConverterBool.read(from: &buf), 
+                thumbnailKey: FfiConverterOptionString.read(from: &buf), 
+                titleMatchRanges: FfiConverterSequenceTypeTextRangeRecord.read(from: &buf), 
+                checklistProgress: FfiConverterOptionTypeChecklistProgressRecord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchResultRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.id, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterString.write(value.subtitle, into: &buf)
+        FfiConverterOptionString.write(value.snippet, into: &buf)
+        FfiConverterOptionString.write(value.snippetSource, into: &buf)
+        FfiConverterTypeSearchResultKind.write(value.kind, into: &buf)
+        FfiConverterTypeSearchResultSection.write(value.section, into: &buf)
+        FfiConverterOptionString.write(value.icon, into: &buf)
+        FfiConverterOptionString.write(value.commandKey, into: &buf)
+        FfiConverterBool.write(value.pinned, into: &buf)
+        FfiConverterOptionString.write(value.thumbnailKey, into: &buf)
+        FfiConverterSequenceTypeTextRangeRecord.write(value.titleMatchRanges, into: &buf)
+        FfiConverterOptionTypeChecklistProgressRecord.write(value.checklistProgress, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResultRecord_lift(_ buf: RustBuffer) throws -> SearchResultRecord {
+    return try FfiConverterTypeSearchResultRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResultRecord_lower(_ value: SearchResultRecord) -> RustBuffer {
+    return FfiConverterTypeSearchResultRecord.lower(value)
+}
+
+
+/**
+ * Markers to put around the editor's selection, or how many characters to
+ * take off each of its ends.
+ */
+public struct SelectionWrapRecord {
+    public var `open`: String
+    public var close: String
+    /**
+     * UTF-16 units removed from each end of the selection; 0 when wrapping.
+     */
+    public var unwrapLength: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(`open`: String, close: String, 
+        /**
+         * UTF-16 units removed from each end of the selection; 0 when wrapping.
+         */unwrapLength: UInt32) {
+        self.`open` = `open`
+        self.close = close
+        self.unwrapLength = unwrapLength
+    }
+}
+
+#if compiler(>=6)
+extension SelectionWrapRecord: Sendable {}
+#endif
+
+
+extension SelectionWrapRecord: Equatable, Hashable {
+    public static func ==(lhs: SelectionWrapRecord, rhs: SelectionWrapRecord) -> Bool {
+        if lhs.`open` != rhs.`open` {
+            return false
+        }
+        if lhs.close != rhs.close {
+            return false
+        }
+        if lhs.unwrapLength != rhs.unwrapLength {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(`open`)
+        hasher.combine(close)
+        hasher.combine(unwrapLength)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSelectionWrapRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SelectionWrapRecord {
+        return
+            try SelectionWrapRecord(
+                open: FfiConverterString.read(from: &buf), 
+                close: FfiConverterString.read(from: &buf), 
+                unwrapLength: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SelectionWrapRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.`open`, into: &buf)
+        FfiConverterString.write(value.close, into: &buf)
+        FfiConverterUInt32.write(value.unwrapLength, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSelectionWrapRecord_lift(_ buf: RustBuffer) throws -> SelectionWrapRecord {
+    return try FfiConverterTypeSelectionWrapRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSelectionWrapRecord_lower(_ value: SelectionWrapRecord) -> RustBuffer {
+    return FfiConverterTypeSelectionWrapRecord.lower(value)
+}
+
+
+public struct SkippedImageRecord {
+    public var fileName: String
+    public var reason: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(fileName: String, reason: String) {
+        self.fileName = fileName
+        self.reason = reason
+    }
+}
+
+#if compiler(>=6)
+extension SkippedImageRecord: Sendable {}
+#endif
+
+
+extension SkippedImageRecord: Equatable, Hashable {
+    public static func ==(lhs: SkippedImageRecord, rhs: SkippedImageRecord) -> Bool {
+        if lhs.fileName != rhs.fileName {
+            return false
+        }
+        if lhs.reason != rhs.reason {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(fileName)
+        hasher.combine(reason)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSkippedImageRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SkippedImageRecord {
+        return
+            try SkippedImageRecord(
+                fileName: FfiConverterString.read(from: &buf), 
+                reason: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SkippedImageRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.fileName, into: &buf)
+        FfiConverterString.write(value.reason, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSkippedImageRecord_lift(_ buf: RustBuffer) throws -> SkippedImageRecord {
+    return try FfiConverterTypeSkippedImageRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSkippedImageRecord_lower(_ value: SkippedImageRecord) -> RustBuffer {
+    return FfiConverterTypeSkippedImageRecord.lower(value)
+}
+
+
+public struct StorageAlertRecord {
+    public var level: StorageAlertLevel
+    public var message: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(level: StorageAlertLevel, message: String) {
+        self.level = level
+        self.message = message
+    }
+}
+
+#if compiler(>=6)
+extension StorageAlertRecord: Sendable {}
+#endif
+
+
+extension StorageAlertRecord: Equatable, Hashable {
+    public static func ==(lhs: StorageAlertRecord, rhs: StorageAlertRecord) -> Bool {
+        if lhs.level != rhs.level {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(level)
+        hasher.combine(message)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStorageAlertRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StorageAlertRecord {
+        return
+            try StorageAlertRecord(
+                level: FfiConverterTypeStorageAlertLevel.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StorageAlertRecord, into buf: inout [UInt8]) {
+        FfiConverterTypeStorageAlertLevel.write(value.level, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStorageAlertRecord_lift(_ buf: RustBuffer) throws -> StorageAlertRecord {
+    return try FfiConverterTypeStorageAlertRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStorageAlertRecord_lower(_ value: StorageAlertRecord) -> RustBuffer {
+    return FfiConverterTypeStorageAlertRecord.lower(value)
+}
+
+
+public struct StorageReportRecord {
+    public var root: String
+    /**
+     * Name of the sync service the root lives in, if any.
+     */
+    public var cloudProvider: String?
+    public var availableBytes: UInt64?
+    public var totalBytes: UInt64?
+    public var mirrorBytes: UInt64
+    public var budgetBytes: UInt64?
+    /**
+     * Most severe first; empty when storage is healthy.
+     */
+    public var alerts: [StorageAlertRecord]
+    public var largestItems: [ItemStorageRecord]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(root: String, 
+        /**
+         * Name of the sync service the root lives in, if any.
+         */cloudProvider: String?, availableBytes: UInt64?, totalBytes: UInt64?, mirrorBytes: UInt64, budgetBytes: UInt64?, 
+        /**
+         * Most severe first; empty when storage is healthy.
+         */alerts: [StorageAlertRecord], largestItems: [ItemStorageRecord]) {
+        self.root = root
+        self.cloudProvider = cloudProvider
+        self.availableBytes = availableBytes
+        self.totalBytes = totalBytes
+        self.mirrorBytes = mirrorBytes
+        self.budgetBytes = budgetBytes
+        self.alerts = alerts
+        self.largestItems = largestItems
+    }
+}
+
+#if compiler(>=6)
+extension StorageReportRecord: Sendable {}
+#endif
+
+
+extension StorageReportRecord: Equatable, Hashable {
+    public static func ==(lhs: StorageReportRecord, rhs: StorageReportRecord) -> Bool {
+        if lhs.root != rhs.root {
+            return false
+        }
+        if lhs.cloudProvider != rhs.cloudProvider {
+            return false
+        }
+        if lhs.availableBytes != rhs.availableBytes {
+            return false
+        }
+        if lhs.totalBytes != rhs.totalBytes {
+            return false
+        }
+        if lhs.mirrorBytes != rhs.mirrorBytes {
+            return false
+        }
+        if lhs.budgetBytes != rhs.budgetBytes {
+            return false
+        }
+        if lhs.alerts != rhs.alerts {
+            return false
+        }
+        if lhs.largestItems != rhs.largestItems {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(root)
+        hasher.combine(cloudProvider)
+        hasher.combine(availableBytes)
+        hasher.combine(totalBytes)
+        hasher.combine(mirrorBytes)
+        hasher.combine(budgetBytes)
+        hasher.combine(alerts)
+        hasher.combine(largestItems)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStorageReportRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StorageReportRecord {
+        return
+            try StorageReportRecord(
+                root: FfiConverterString.read(from: &buf), 
+                cloudProvider: FfiConverterOptionString.read(from: &buf), 
+                availableBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                totalBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                mirrorBytes: FfiConverterUInt64.read(from: &buf), 
+                budgetBytes: FfiConverterOptionUInt64.read(from: &buf), 
+                alerts: FfiConverterSequenceTypeStorageAlertRecord.read(from: &buf), 
+                largestItems: FfiConverterSequenceTypeItemStorageRecord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StorageReportRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.root, into: &buf)
+        FfiConverterOptionString.write(value.cloudProvider, into: &buf)
+        FfiConverterOptionUInt64.write(value.availableBytes, into: &buf)
+        FfiConverterOptionUInt64.write(value.totalBytes, into: &buf)
+        FfiConverterUInt64.write(value.mirrorBytes, into: &buf)
+        FfiConverterOptionUInt64.write(value.budgetBytes, into: &buf)
+        FfiConverterSequenceTypeStorageAlertRecord.write(value.alerts, into: &buf)
+        FfiConverterSequenceTypeItemStorageRecord.write(value.largestItems, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStorageReportRecord_lift(_ buf: RustBuffer) throws -> StorageReportRecord {
+    return try FfiConverterTypeStorageReportRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStorageReportRecord_lower(_ value: StorageReportRecord) -> RustBuffer {
+    return FfiConverterTypeStorageReportRecord.lower(value)
+}
+
+
+public struct StoredImageRecord {
+    public var itemId: Int64
+    public var imageKey: String
+    public var bytes: UInt64
+    public var fingerprint: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemId: Int64, imageKey: String, bytes: UInt64, fingerprint: UInt64) {
+        self.itemId = itemId
+        self.imageKey = imageKey
+        self.bytes = bytes
+        self.fingerprint = fingerprint
+    }
+}
+
+#if compiler(>=6)
+extension StoredImageRecord: Sendable {}
+#endif
+
+
+extension StoredImageRecord: Equatable, Hashable {
+    public static func ==(lhs: StoredImageRecord, rhs: StoredImageRecord) -> Bool {
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.imageKey != rhs.imageKey {
+            return false
+        }
+        if lhs.bytes != rhs.bytes {
+            return false
+        }
+        if lhs.fingerprint != rhs.fingerprint {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemId)
+        hasher.combine(imageKey)
+        hasher.combine(bytes)
+        hasher.combine(fingerprint)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStoredImageRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StoredImageRecord {
+        return
+            try StoredImageRecord(
+                itemId: FfiConverterInt64.read(from: &buf), 
+                imageKey: FfiConverterString.read(from: &buf), 
+                bytes: FfiConverterUInt64.read(from: &buf), 
+                fingerprint: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StoredImageRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.itemId, into: &buf)
+        FfiConverterString.write(value.imageKey, into: &buf)
+        FfiConverterUInt64.write(value.bytes, into: &buf)
+        FfiConverterUInt64.write(value.fingerprint, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStoredImageRecord_lift(_ buf: RustBuffer) throws -> StoredImageRecord {
+    return try FfiConverterTypeStoredImageRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStoredImageRecord_lower(_ value: StoredImageRecord) -> RustBuffer {
+    return FfiConverterTypeStoredImageRecord.lower(value)
+}
+
+
+/**
+ * The table's lines after a table command, and what to select in line `row`.
+ */
+public struct TableEditRecord {
+    public var lines: [String]
+    public var row: UInt32
+    /**
+     * UTF-16 range within line `row`.
+     */
+    public var selection: TextRangeRecord
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(lines: [String], row: UInt32, 
+        /**
+         * UTF-16 range within line `row`.
+         */selection: TextRangeRecord) {
+        self.lines = lines
+        self.row = row
+        self.selection = selection
+    }
+}
+
+#if compiler(>=6)
+extension TableEditRecord: Sendable {}
+#endif
+
+
+extension TableEditRecord: Equatable, Hashable {
+    public static func ==(lhs: TableEditRecord, rhs: TableEditRecord) -> Bool {
+        if lhs.lines != rhs.lines {
+            return false
+        }
+        if lhs.row != rhs.row {
+            return false
+        }
+        if lhs.selection != rhs.selection {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(lines)
+        hasher.combine(row)
+        hasher.combine(selection)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTableEditRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TableEditRecord {
+        return
+            try TableEditRecord(
+                lines: FfiConverterSequenceString.read(from: &buf), 
+                row: FfiConverterUInt32.read(from: &buf), 
+                selection: FfiConverterTypeTextRangeRecord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TableEditRecord, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.lines, into: &buf)
+        FfiConverterUInt32.write(value.row, into: &buf)
+        FfiConverterTypeTextRangeRecord.write(value.selection, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTableEditRecord_lift(_ buf: RustBuffer) throws -> TableEditRecord {
+    return try FfiConverterTypeTableEditRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTableEditRecord_lower(_ value: TableEditRecord) -> RustBuffer {
+    return FfiConverterTypeTableEditRecord.lower(value)
+}
+
+
+/**
+ * A Markdown table in the editor, as its rows' cells between their pipes.
+ */
+public struct TableLayoutRecord {
+    public var rows: [[TextRangeRecord]]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(rows: [[TextRangeRecord]]) {
+        self.rows = rows
+    }
+}
+
+#if compiler(>=6)
+extension TableLayoutRecord: Sendable {}
+#endif
+
+
+extension TableLayoutRecord: Equatable, Hashable {
+    public static func ==(lhs: TableLayoutRecord, rhs: TableLayoutRecord) -> Bool {
+        if lhs.rows != rhs.rows {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(rows)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTableLayoutRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TableLayoutRecord {
+        return
+            try TableLayoutRecord(
+                rows: FfiConverterSequenceSequenceTypeTextRangeRecord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TableLayoutRecord, into buf: inout [UInt8]) {
+        FfiConverterSequenceSequenceTypeTextRangeRecord.write(value.rows, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTableLayoutRecord_lift(_ buf: RustBuffer) throws -> TableLayoutRecord {
+    return try FfiConverterTypeTableLayoutRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTableLayoutRecord_lower(_ value: TableLayoutRecord) -> RustBuffer {
+    return FfiConverterTypeTableLayoutRecord.lower(value)
+}
+
+
+/**
+ * A range of a string in UTF-16 code units, as Swift strings index them.
+ */
+public struct TextRangeRecord {
+    public var start: UInt32
+    public var length: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(start: UInt32, length: UInt32) {
+        self.start = start
+        self.length = length
+    }
+}
+
+#if compiler(>=6)
+extension TextRangeRecord: Sendable {}
+#endif
+
+
+extension TextRangeRecord: Equatable, Hashable {
+    public static func ==(lhs: TextRangeRecord, rhs: TextRangeRecord) -> Bool {
+        if lhs.start != rhs.start {
+            return false
+        }
+        if lhs.length != rhs.length {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(start)
+        hasher.combine(length)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTextRangeRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextRangeRecord {
+        return
+            try TextRangeRecord(
+                start: FfiConverterUInt32.read(from: &buf), 
+                length: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TextRangeRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.start, into: &buf)
+        FfiConverterUInt32.write(value.length, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextRangeRecord_lift(_ buf: RustBuffer) throws -> TextRangeRecord {
+    return try FfiConverterTypeTextRangeRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextRangeRecord_lower(_ value: TextRangeRecord) -> RustBuffer {
+    return FfiConverterTypeTextRangeRecord.lower(value)
+}
+
+
+public struct TimeReportRecord {
+    /**
+     * Local dates of the week as `YYYY-MM-DD`, Monday first.
+     */
+    public var days: [String]
+    /**
+     * Notes with time in the week, most tracked first.
+     */
+    public var rows: [TimeReportRowRecord]
+    public var dayTotals: [UInt64]
+    public var totalSeconds: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Local dates of the week as `YYYY-MM-DD`, Monday first.
+         */days: [String], 
+        /**
+         * Notes with time in the week, most tracked first.
+         */rows: [TimeReportRowRecord], dayTotals: [UInt64], totalSeconds: UInt64) {
+        self.days = days
+        self.rows = rows
+        self.dayTotals = dayTotals
+        self.totalSeconds = totalSeconds
+    }
+}
+
+#if compiler(>=6)
+extension TimeReportRecord: Sendable {}
+#endif
+
+
+extension TimeReportRecord: Equatable, Hashable {
+    public static func ==(lhs: TimeReportRecord, rhs: TimeReportRecord) -> Bool {
+        if lhs.days != rhs.days {
+            return false
+        }
+        if lhs.rows != rhs.rows {
+            return false
+        }
+        if lhs.dayTotals != rhs.dayTotals {
+            return false
+        }
+        if lhs.totalSeconds != rhs.totalSeconds {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(days)
+        hasher.combine(rows)
+        hasher.combine(dayTotals)
+        hasher.combine(totalSeconds)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTimeReportRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TimeReportRecord {
+        return
+            try TimeReportRecord(
+                days: FfiConverterSequenceString.read(from: &buf), 
+                rows: FfiConverterSequenceTypeTimeReportRowRecord.read(from: &buf), 
+                dayTotals: FfiConverterSequenceUInt64.read(from: &buf), 
+                totalSeconds: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TimeReportRecord, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.days, into: &buf)
+        FfiConverterSequenceTypeTimeReportRowRecord.write(value.rows, into: &buf)
+        FfiConverterSequenceUInt64.write(value.dayTotals, into: &buf)
+        FfiConverterUInt64.write(value.totalSeconds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTimeReportRecord_lift(_ buf: RustBuffer) throws -> TimeReportRecord {
+    return try FfiConverterTypeTimeReportRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTimeReportRecord_lower(_ value: TimeReportRecord) -> RustBuffer {
+    return FfiConverterTypeTimeReportRecord.lower(value)
+}
+
+
+public struct TimeReportRowRecord {
+    public var itemId: Int64
+    public var title: String
+    /**
+     * Seconds per day, Monday first.
+     */
+    public var daySeconds: [UInt64]
+    public var totalSeconds: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemId: Int64, title: String, 
+        /**
+         * Seconds per day, Monday first.
+         */daySeconds: [UInt64], totalSeconds: UInt64) {
+        self.itemId = itemId
+        self.title = title
+        self.daySeconds = daySeconds
+        self.totalSeconds = totalSeconds
+    }
+}
+
+#if compiler(>=6)
+extension TimeReportRowRecord: Sendable {}
+#endif
+
+
+extension TimeReportRowRecord: Equatable, Hashable {
+    public static func ==(lhs: TimeReportRowRecord, rhs: TimeReportRowRecord) -> Bool {
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.title != rhs.title {
+            return false
+        }
+        if lhs.daySeconds != rhs.daySeconds {
+            return false
+        }
+        if lhs.totalSeconds != rhs.totalSeconds {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemId)
+        hasher.combine(title)
+        hasher.combine(daySeconds)
+        hasher.combine(totalSeconds)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTimeReportRowRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TimeReportRowRecord {
+        return
+            try TimeReportRowRecord(
+                itemId: FfiConverterInt64.read(from: &buf), 
+                title: FfiConverterString.read(from: &buf), 
+                daySeconds: FfiConverterSequenceUInt64.read(from: &buf), 
+                totalSeconds: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TimeReportRowRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.itemId, into: &buf)
+        FfiConverterString.write(value.title, into: &buf)
+        FfiConverterSequenceUInt64.write(value.daySeconds, into: &buf)
+        FfiConverterUInt64.write(value.totalSeconds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTimeReportRowRecord_lift(_ buf: RustBuffer) throws -> TimeReportRowRecord {
+    return try FfiConverterTypeTimeReportRowRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTimeReportRowRecord_lower(_ value: TimeReportRowRecord) -> RustBuffer {
+    return FfiConverterTypeTimeReportRowRecord.lower(value)
+}
+
+
+/**
+ * A Jira or Linear workspace; Jira also needs `site` and `email`.
+ */
+public struct TrackerAccountRecord {
+    public var kind: TrackerKindRecord
+    public var site: String?
+    public var email: String?
+    public var token: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: TrackerKindRecord, site: String?, email: String?, token: String) {
+        self.kind = kind
+        self.site = site
+        self.email = email
+        self.token = token
+    }
+}
+
+#if compiler(>=6)
+extension TrackerAccountRecord: Sendable {}
+#endif
+
+
+extension TrackerAccountRecord: Equatable, Hashable {
+    public static func ==(lhs: TrackerAccountRecord, rhs: TrackerAccountRecord) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.site != rhs.site {
+            return false
+        }
+        if lhs.email != rhs.email {
+            return false
+        }
+        if lhs.token != rhs.token {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(site)
+        hasher.combine(email)
+        hasher.combine(token)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTrackerAccountRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrackerAccountRecord {
+        return
+            try TrackerAccountRecord(
+                kind: FfiConverterTypeTrackerKindRecord.read(from: &buf), 
+                site: FfiConverterOptionString.read(from: &buf), 
+                email: FfiConverterOptionString.read(from: &buf), 
+                token: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TrackerAccountRecord, into buf: inout [UInt8]) {
+        FfiConverterTypeTrackerKindRecord.write(value.kind, into: &buf)
+        FfiConverterOptionString.write(value.site, into: &buf)
+        FfiConverterOptionString.write(value.email, into: &buf)
+        FfiConverterString.write(value.token, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackerAccountRecord_lift(_ buf: RustBuffer) throws -> TrackerAccountRecord {
+    return try FfiConverterTypeTrackerAccountRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackerAccountRecord_lower(_ value: TrackerAccountRecord) -> RustBuffer {
+    return FfiConverterTypeTrackerAccountRecord.lower(value)
+}
+
+
+public struct TriggerLogEntryRecord {
+    public var atUnixSeconds: Int64
+    public var event: TriggerEventKind
+    public var itemId: Int64
+    public var attempt: UInt32
+    public var success: Bool
+    public var message: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(atUnixSeconds: Int64, event: TriggerEventKind, itemId: Int64, attempt: UInt32, success: Bool, message: String) {
+        self.atUnixSeconds = atUnixSeconds
+        self.event = event
+        self.itemId = itemId
+        self.attempt = attempt
+        self.success = success
+        self.message = message
+    }
+}
+
+#if compiler(>=6)
+extension TriggerLogEntryRecord: Sendable {}
+#endif
+
+
+extension TriggerLogEntryRecord: Equatable, Hashable {
+    public static func ==(lhs: TriggerLogEntryRecord, rhs: TriggerLogEntryRecord) -> Bool {
+        if lhs.atUnixSeconds != rhs.atUnixSeconds {
+            return false
+        }
+        if lhs.event != rhs.event {
+            return false
+        }
+        if lhs.itemId != rhs.itemId {
+            return false
+        }
+        if lhs.attempt != rhs.attempt {
+            return false
+        }
+        if lhs.success != rhs.success {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(atUnixSeconds)
+        hasher.combine(event)
+        hasher.combine(itemId)
+        hasher.combine(attempt)
+        hasher.combine(success)
+        hasher.combine(message)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTriggerLogEntryRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TriggerLogEntryRecord {
+        return
+            try TriggerLogEntryRecord(
+                atUnixSeconds: FfiConverterInt64.read(from: &buf), 
+                event: FfiConverterTypeTriggerEventKind.read(from: &buf), 
+                itemId: FfiConverterInt64.read(from: &buf), 
+                attempt: FfiConverterUInt32.read(from: &buf), 
+                success: FfiConverterBool.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TriggerLogEntryRecord, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.atUnixSeconds, into: &buf)
+        FfiConverterTypeTriggerEventKind.write(value.event, into: &buf)
+        FfiConverterInt64.write(value.itemId, into: &buf)
+        FfiConverterUInt32.write(value.attempt, into: &buf)
+        FfiConverterBool.write(value.success, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerLogEntryRecord_lift(_ buf: RustBuffer) throws -> TriggerLogEntryRecord {
+    return try FfiConverterTypeTriggerLogEntryRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerLogEntryRecord_lower(_ value: TriggerLogEntryRecord) -> RustBuffer {
+    return FfiConverterTypeTriggerLogEntryRecord.lower(value)
+}
+
+
+public struct TriggerRecord {
+    public var id: String
+    public var name: String
+    public var event: TriggerEventKind
+    public var tag: String?
+    public var actionKind: TriggerActionKind
+    public var target: String
+    public var enabled: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, name: String, event: TriggerEventKind, tag: String?, actionKind: TriggerActionKind, target: String, enabled: Bool) {
+        self.id = id
+        self.name = name
+        self.event = event
+        self.tag = tag
+        self.actionKind = actionKind
+        self.target = target
+        self.enabled = enabled
+    }
+}
+
+#if compiler(>=6)
+extension TriggerRecord: Sendable {}
+#endif
+
+
+extension TriggerRecord: Equatable, Hashable {
+    public static func ==(lhs: TriggerRecord, rhs: TriggerRecord) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.event != rhs.event {
+            return false
+        }
+        if lhs.tag != rhs.tag {
+            return false
+        }
+        if lhs.actionKind != rhs.actionKind {
+            return false
+        }
+        if lhs.target != rhs.target {
+            return false
+        }
+        if lhs.enabled != rhs.enabled {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(name)
+        hasher.combine(event)
+        hasher.combine(tag)
+        hasher.combine(actionKind)
+        hasher.combine(target)
+        hasher.combine(enabled)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTriggerRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TriggerRecord {
+        return
+            try TriggerRecord(
+                id: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                event: FfiConverterTypeTriggerEventKind.read(from: &buf), 
+                tag: FfiConverterOptionString.read(from: &buf), 
+                actionKind: FfiConverterTypeTriggerActionKind.read(from: &buf), 
+                target: FfiConverterString.read(from: &buf), 
+                enabled: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TriggerRecord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeTriggerEventKind.write(value.event, into: &buf)
+        FfiConverterOptionString.write(value.tag, into: &buf)
+        FfiConverterTypeTriggerActionKind.write(value.actionKind, into: &buf)
+        FfiConverterString.write(value.target, into: &buf)
+        FfiConverterBool.write(value.enabled, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerRecord_lift(_ buf: RustBuffer) throws -> TriggerRecord {
+    return try FfiConverterTypeTriggerRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerRecord_lower(_ value: TriggerRecord) -> RustBuffer {
+    return FfiConverterTypeTriggerRecord.lower(value)
+}
+
+
+public struct UsageStatsRecord {
+    public var itemCount: UInt64
+    public var imageCount: UInt64
+    public var imageBytes: UInt64
+    /**
+     * One entry per day, oldest first.
+     */
+    public var searchesPerDay: [DailySearchesRecord]
+    /**
+     * Most opened first.
+     */
+    public var mostOpened: [OpenedItemRecord]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(itemCount: UInt64, imageCount: UInt64, imageBytes: UInt64, 
+        /**
+         * One entry per day, oldest first.
+         */searchesPerDay: [DailySearchesRecord], 
+        /**
+         * Most opened first.
+         */mostOpened: [OpenedItemRecord]) {
+        self.itemCount = itemCount
+        self.imageCount = imageCount
+        self.imageBytes = imageBytes
+        self.searchesPerDay = searchesPerDay
+        self.mostOpened = mostOpened
+    }
+}
+
+#if compiler(>=6)
+extension UsageStatsRecord: Sendable {}
+#endif
+
+
+extension UsageStatsRecord: Equatable, Hashable {
+    public static func ==(lhs: UsageStatsRecord, rhs: UsageStatsRecord) -> Bool {
+        if lhs.itemCount != rhs.itemCount {
+            return false
+        }
+        if lhs.imageCount != rhs.imageCount {
+            return false
+        }
+        if lhs.imageBytes != rhs.imageBytes {
+            return false
+        }
+        if lhs.searchesPerDay != rhs.searchesPerDay {
+            return false
+        }
+        if lhs.mostOpened != rhs.mostOpened {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(itemCount)
+        hasher.combine(imageCount)
+        hasher.combine(imageBytes)
+        hasher.combine(searchesPerDay)
+        hasher.combine(mostOpened)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUsageStatsRecord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UsageStatsRecord {
+        return
+            try UsageStatsRecord(
+                itemCount: FfiConverterUInt64.read(from: &buf), 
+                imageCount: FfiConverterUInt64.read(from: &buf), 
+                imageBytes: FfiConverterUInt64.read(from: &buf), 
+                searchesPerDay: FfiConverterSequenceTypeDailySearchesRecord.read(from: &buf), 
+                mostOpened: FfiConverterSequenceTypeOpenedItemRecord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UsageStatsRecord, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.itemCount, into: &buf)
+        FfiConverterUInt64.write(value.imageCount, into: &buf)
+        FfiConverterUInt64.write(value.imageBytes, into: &buf)
+        FfiConverterSequenceTypeDailySearchesRecord.write(value.searchesPerDay, into: &buf)
+        FfiConverterSequenceTypeOpenedItemRecord.write(value.mostOpened, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUsageStatsRecord_lift(_ buf: RustBuffer) throws -> UsageStatsRecord {
+    return try FfiConverterTypeUsageStatsRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUsageStatsRecord_lower(_ value: UsageStatsRecord) -> RustBuffer {
+    return FfiConverterTypeUsageStatsRecord.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Who is changing data, as shown in the activity log.
+ */
+
+public enum ActivityOriginKind {
+    
+    case gui
+    case uniffi
+    case cli
+    case sync
+}
+
+
+#if compiler(>=6)
+extension ActivityOriginKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeActivityOriginKind: FfiConverterRustBuffer {
+    typealias SwiftType = ActivityOriginKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ActivityOriginKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .gui
+        
+        case 2: return .uniffi
+        
+        case 3: return .cli
+        
+        case 4: return .sync
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ActivityOriginKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .gui:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .uniffi:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .cli:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .sync:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeActivityOriginKind_lift(_ buf: RustBuffer) throws -> ActivityOriginKind {
+    return try FfiConverterTypeActivityOriginKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeActivityOriginKind_lower(_ value: ActivityOriginKind) -> RustBuffer {
+    return FfiConverterTypeActivityOriginKind.lower(value)
+}
+
+
+extension ActivityOriginKind: Equatable, Hashable {}
+
+
+
+
+
+
+
+public enum BackendError: Swift.Error {
+
+    
+    
+    case Validation(String
+    )
+    case NotFound(String
+    )
+    case Storage(String
+    )
+    case Conflict(String
+    )
+    /**
+     * A secure item was asked for while the app is locked.
+     */
+    case Locked(String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBackendError: FfiConverterRustBuffer {
+    typealias SwiftType = BackendError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BackendError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Validation(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .NotFound(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .Storage(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .Conflict(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 5: return .Locked(
+            try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BackendError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .Validation(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .NotFound(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Storage(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Conflict(v1):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Locked(v1):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(v1, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBackendError_lift(_ buf: RustBuffer) throws -> BackendError {
+    return try FfiConverterTypeBackendError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBackendError_lower(_ value: BackendError) -> RustBuffer {
+    return FfiConverterTypeBackendError.lower(value)
+}
+
+
+extension BackendError: Equatable, Hashable {}
+
+
+
+
+extension BackendError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum BrowseSortOrder {
+    
+    case relevance
+    case updated
+    case created
+    case title
+}
+
+
+#if compiler(>=6)
+extension BrowseSortOrder: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBrowseSortOrder: FfiConverterRustBuffer {
+    typealias SwiftType = BrowseSortOrder
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BrowseSortOrder {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .relevance
+        
+        case 2: return .updated
+        
+        case 3: return .created
+        
+        case 4: return .title
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BrowseSortOrder, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .relevance:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .updated:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .created:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .title:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBrowseSortOrder_lift(_ buf: RustBuffer) throws -> BrowseSortOrder {
+    return try FfiConverterTypeBrowseSortOrder.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBrowseSortOrder_lower(_ value: BrowseSortOrder) -> RustBuffer {
+    return FfiConverterTypeBrowseSortOrder.lower(value)
+}
+
+
+extension BrowseSortOrder: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum DiffSegmentKind {
+    
+    case equal
+    case insert
+    case delete
+}
+
+
+#if compiler(>=6)
+extension DiffSegmentKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDiffSegmentKind: FfiConverterRustBuffer {
+    typealias SwiftType = DiffSegmentKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DiffSegmentKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .equal
+        
+        case 2: return .insert
+        
+        case 3: return .delete
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DiffSegmentKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .equal:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .insert:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .delete:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffSegmentKind_lift(_ buf: RustBuffer) throws -> DiffSegmentKind {
+    return try FfiConverterTypeDiffSegmentKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDiffSegmentKind_lower(_ value: DiffSegmentKind) -> RustBuffer {
+    return FfiConverterTypeDiffSegmentKind.lower(value)
+}
+
+
+extension DiffSegmentKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ForgeKindRecord {
+    
+    case gitHub
+    case gitLab
+}
+
+
+#if compiler(>=6)
+extension ForgeKindRecord: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeForgeKindRecord: FfiConverterRustBuffer {
+    typealias SwiftType = ForgeKindRecord
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ForgeKindRecord {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .gitHub
+        
+        case 2: return .gitLab
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ForgeKindRecord, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .gitHub:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .gitLab:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeForgeKindRecord_lift(_ buf: RustBuffer) throws -> ForgeKindRecord {
+    return try FfiConverterTypeForgeKindRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeForgeKindRecord_lower(_ value: ForgeKindRecord) -> RustBuffer {
+    return FfiConverterTypeForgeKindRecord.lower(value)
+}
+
+
+extension ForgeKindRecord: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum PreviewBlockKindRecord {
+    
+    case paragraph
+    case heading
+    case listItem
+    case checklistItem
+    case quote
+    case code
+    case divider
+    case image
+    case footnote
+}
+
+
+#if compiler(>=6)
+extension PreviewBlockKindRecord: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePreviewBlockKindRecord: FfiConverterRustBuffer {
+    typealias SwiftType = PreviewBlockKindRecord
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PreviewBlockKindRecord {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .paragraph
+        
+        case 2: return .heading
+        
+        case 3: return .listItem
+        
+        case 4: return .checklistItem
+        
+        case 5: return .quote
+        
+        case 6: return .code
+        
+        case 7: return .divider
+        
+        case 8: return .image
+        
+        case 9: return .footnote
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PreviewBlockKindRecord, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .paragraph:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .heading:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .listItem:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .checklistItem:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .quote:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .code:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .divider:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .image:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .footnote:
+            writeInt(&buf, Int32(9))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreviewBlockKindRecord_lift(_ buf: RustBuffer) throws -> PreviewBlockKindRecord {
+    return try FfiConverterTypePreviewBlockKindRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePreviewBlockKindRecord_lower(_ value: PreviewBlockKindRecord) -> RustBuffer {
+    return FfiConverterTypePreviewBlockKindRecord.lower(value)
+}
+
+
+extension PreviewBlockKindRecord: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ProviderOutcomeKind {
+    
+    case done
+    case openUrl
+    case copyText
+    /**
+     * A note was captured from the result; open `item_id`.
+     */
+    case openItem
+}
+
+
+#if compiler(>=6)
+extension ProviderOutcomeKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProviderOutcomeKind: FfiConverterRustBuffer {
+    typealias SwiftType = ProviderOutcomeKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProviderOutcomeKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .done
+        
+        case 2: return .openUrl
+        
+        case 3: return .copyText
+        
+        case 4: return .openItem
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ProviderOutcomeKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .done:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .openUrl:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .copyText:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .openItem:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProviderOutcomeKind_lift(_ buf: RustBuffer) throws -> ProviderOutcomeKind {
+    return try FfiConverterTypeProviderOutcomeKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProviderOutcomeKind_lower(_ value: ProviderOutcomeKind) -> RustBuffer {
+    return FfiConverterTypeProviderOutcomeKind.lower(value)
+}
+
+
+extension ProviderOutcomeKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RecurrenceKind {
+    
+    case daily
+    case weekly
+}
+
+
+#if compiler(>=6)
+extension RecurrenceKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRecurrenceKind: FfiConverterRustBuffer {
+    typealias SwiftType = RecurrenceKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecurrenceKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .daily
+        
+        case 2: return .weekly
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RecurrenceKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .daily:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .weekly:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecurrenceKind_lift(_ buf: RustBuffer) throws -> RecurrenceKind {
+    return try FfiConverterTypeRecurrenceKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecurrenceKind_lower(_ value: RecurrenceKind) -> RustBuffer {
+    return FfiConverterTypeRecurrenceKind.lower(value)
+}
+
+
+extension RecurrenceKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SearchResultKind {
+    
+    case item
+    case systemCommand
+    case appCommand
+    case queryPrefix
+    case answer
+    case readLater
+    case provider
+    case timer
+}
+
+
+#if compiler(>=6)
+extension SearchResultKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchResultKind: FfiConverterRustBuffer {
+    typealias SwiftType = SearchResultKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResultKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .item
+        
+        case 2: return .systemCommand
+        
+        case 3: return .appCommand
+        
+        case 4: return .queryPrefix
+        
+        case 5: return .answer
+        
+        case 6: return .readLater
+        
+        case 7: return .provider
+        
+        case 8: return .timer
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SearchResultKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .item:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .systemCommand:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .appCommand:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .queryPrefix:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .answer:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .readLater:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .provider:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .timer:
+            writeInt(&buf, Int32(8))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResultKind_lift(_ buf: RustBuffer) throws -> SearchResultKind {
+    return try FfiConverterTypeSearchResultKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResultKind_lower(_ value: SearchResultKind) -> RustBuffer {
+    return FfiConverterTypeSearchResultKind.lower(value)
+}
+
+
+extension SearchResultKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SearchResultSection {
+    
+    case answers
+    case commands
+    case notes
+    case providers
+}
+
+
+#if compiler(>=6)
+extension SearchResultSection: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchResultSection: FfiConverterRustBuffer {
+    typealias SwiftType = SearchResultSection
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResultSection {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .answers
+        
+        case 2: return .commands
+        
+        case 3: return .notes
+        
+        case 4: return .providers
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SearchResultSection, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .answers:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .commands:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .notes:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .providers:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResultSection_lift(_ buf: RustBuffer) throws -> SearchResultSection {
+    return try FfiConverterTypeSearchResultSection.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResultSection_lower(_ value: SearchResultSection) -> RustBuffer {
+    return FfiConverterTypeSearchResultSection.lower(value)
+}
+
+
+extension SearchResultSection: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum StorageAlertLevel {
+    
+    case warning
+    case critical
+}
+
+
+#if compiler(>=6)
+extension StorageAlertLevel: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStorageAlertLevel: FfiConverterRustBuffer {
+    typealias SwiftType = StorageAlertLevel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StorageAlertLevel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .warning
+        
+        case 2: return .critical
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: StorageAlertLevel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .warning:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .critical:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStorageAlertLevel_lift(_ buf: RustBuffer) throws -> StorageAlertLevel {
+    return try FfiConverterTypeStorageAlertLevel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStorageAlertLevel_lower(_ value: StorageAlertLevel) -> RustBuffer {
+    return FfiConverterTypeStorageAlertLevel.lower(value)
+}
+
+
+extension StorageAlertLevel: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * An editor command on the Markdown table at the caret.
+ */
+
+public enum TableCommandKind {
+    
+    case nextCell
+    case previousCell
+    case insertRow
+    case insertColumn
+}
+
+
+#if compiler(>=6)
+extension TableCommandKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTableCommandKind: FfiConverterRustBuffer {
+    typealias SwiftType = TableCommandKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TableCommandKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .nextCell
+        
+        case 2: return .previousCell
+        
+        case 3: return .insertRow
+        
+        case 4: return .insertColumn
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TableCommandKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .nextCell:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .previousCell:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .insertRow:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .insertColumn:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTableCommandKind_lift(_ buf: RustBuffer) throws -> TableCommandKind {
+    return try FfiConverterTypeTableCommandKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTableCommandKind_lower(_ value: TableCommandKind) -> RustBuffer {
+    return FfiConverterTypeTableCommandKind.lower(value)
+}
+
+
+extension TableCommandKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * An editor command that rewrites the selected text.
+ */
+
+public enum TextTransformKind {
+    
+    case uppercase
+    case lowercase
+    case titleCase
+    case sortLines
+    case deduplicateLines
+    case joinLines
+    case urlEncode
+    case urlDecode
+}
+
+
+#if compiler(>=6)
+extension TextTransformKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTextTransformKind: FfiConverterRustBuffer {
+    typealias SwiftType = TextTransformKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TextTransformKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .uppercase
+        
+        case 2: return .lowercase
+        
+        case 3: return .titleCase
+        
+        case 4: return .sortLines
+        
+        case 5: return .deduplicateLines
+        
+        case 6: return .joinLines
+        
+        case 7: return .urlEncode
+        
+        case 8: return .urlDecode
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TextTransformKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .uppercase:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .lowercase:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .titleCase:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .sortLines:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .deduplicateLines:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .joinLines:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .urlEncode:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .urlDecode:
+            writeInt(&buf, Int32(8))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextTransformKind_lift(_ buf: RustBuffer) throws -> TextTransformKind {
+    return try FfiConverterTypeTextTransformKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTextTransformKind_lower(_ value: TextTransformKind) -> RustBuffer {
+    return FfiConverterTypeTextTransformKind.lower(value)
+}
+
+
+extension TextTransformKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TrackerKindRecord {
+    
+    case jira
+    case linear
+}
+
+
+#if compiler(>=6)
+extension TrackerKindRecord: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTrackerKindRecord: FfiConverterRustBuffer {
+    typealias SwiftType = TrackerKindRecord
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrackerKindRecord {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .jira
+        
+        case 2: return .linear
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TrackerKindRecord, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .jira:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .linear:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackerKindRecord_lift(_ buf: RustBuffer) throws -> TrackerKindRecord {
+    return try FfiConverterTypeTrackerKindRecord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackerKindRecord_lower(_ value: TrackerKindRecord) -> RustBuffer {
+    return FfiConverterTypeTrackerKindRecord.lower(value)
+}
+
+
+extension TrackerKindRecord: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TriggerActionKind {
+    
+    case runScript
+    case postUrl
+}
+
+
+#if compiler(>=6)
+extension TriggerActionKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTriggerActionKind: FfiConverterRustBuffer {
+    typealias SwiftType = TriggerActionKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TriggerActionKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .runScript
+        
+        case 2: return .postUrl
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TriggerActionKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .runScript:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .postUrl:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerActionKind_lift(_ buf: RustBuffer) throws -> TriggerActionKind {
+    return try FfiConverterTypeTriggerActionKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerActionKind_lower(_ value: TriggerActionKind) -> RustBuffer {
+    return FfiConverterTypeTriggerActionKind.lower(value)
+}
+
+
+extension TriggerActionKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TriggerEventKind {
+    
+    case created
+    case saved
+    case deleted
+}
+
+
+#if compiler(>=6)
+extension TriggerEventKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTriggerEventKind: FfiConverterRustBuffer {
+    typealias SwiftType = TriggerEventKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TriggerEventKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .created
+        
+        case 2: return .saved
+        
+        case 3: return .deleted
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TriggerEventKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .created:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .saved:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .deleted:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerEventKind_lift(_ buf: RustBuffer) throws -> TriggerEventKind {
+    return try FfiConverterTypeTriggerEventKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerEventKind_lower(_ value: TriggerEventKind) -> RustBuffer {
+    return FfiConverterTypeTriggerEventKind.lower(value)
+}
+
+
+extension TriggerEventKind: Equatable, Hashable {}
+
+
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionInt64: FfiConverterRustBuffer {
+    typealias SwiftType = Int64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeChecklistProgressRecord: FfiConverterRustBuffer {
+    typealias SwiftType = ChecklistProgressRecord?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeChecklistProgressRecord.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeChecklistProgressRecord.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeItemMetadataRecord: FfiConverterRustBuffer {
+    typealias SwiftType = ItemMetadataRecord?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeItemMetadataRecord.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeItemMetadataRecord.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeListContinuationRecord: FfiConverterRustBuffer {
+    typealias SwiftType = ListContinuationRecord?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeListContinuationRecord.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeListContinuationRecord.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeRunningTimerRecord: FfiConverterRustBuffer {
+    typealias SwiftType = RunningTimerRecord?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRunningTimerRecord.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRunningTimerRecord.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTableEditRecord: FfiConverterRustBuffer {
+    typealias SwiftType = TableEditRecord?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTableEditRecord.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTableEditRecord.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt64]
+
+    public static func write(_ value: [UInt64], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt64.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt64] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt64]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt64.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceInt64: FfiConverterRustBuffer {
+    typealias SwiftType = [Int64]
+
+    public static func write(_ value: [Int64], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterInt64.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Int64] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Int64]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterInt64.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeActivityDayRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [ActivityDayRecord]
+
+    public static func write(_ value: [ActivityDayRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeActivityDayRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ActivityDayRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ActivityDayRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeActivityDayRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeBoardCardRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [BoardCardRecord]
+
+    public static func write(_ value: [BoardCardRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeBoardCardRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [BoardCardRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [BoardCardRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeBoardCardRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCalendarEntryRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [CalendarEntryRecord]
+
+    public static func write(_ value: [CalendarEntryRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCalendarEntryRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CalendarEntryRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CalendarEntryRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCalendarEntryRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeChecklistEntryRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [ChecklistEntryRecord]
+
+    public static func write(_ value: [ChecklistEntryRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeChecklistEntryRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ChecklistEntryRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ChecklistEntryRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeChecklistEntryRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeDailySearchesRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [DailySearchesRecord]
+
+    public static func write(_ value: [DailySearchesRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDailySearchesRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DailySearchesRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DailySearchesRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDailySearchesRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeDeletedItemRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [DeletedItemRecord]
+
+    public static func write(_ value: [DeletedItemRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDeletedItemRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DeletedItemRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DeletedItemRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDeletedItemRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeDiffSegmentRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [DiffSegmentRecord]
+
+    public static func write(_ value: [DiffSegmentRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDiffSegmentRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DiffSegmentRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DiffSegmentRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDiffSegmentRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeExportItemRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [ExportItemRecord]
+
+    public static func write(_ value: [ExportItemRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeExportItemRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ExportItemRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ExportItemRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeExportItemRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeForgeAccountRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [ForgeAccountRecord]
+
+    public static func write(_ value: [ForgeAccountRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeForgeAccountRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ForgeAccountRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ForgeAccountRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeForgeAccountRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeHashtagRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [HashtagRecord]
+
+    public static func write(_ value: [HashtagRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHashtagRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HashtagRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HashtagRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHashtagRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeImageReplacementRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [ImageReplacementRecord]
+
+    public static func write(_ value: [ImageReplacementRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeImageReplacementRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ImageReplacementRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ImageReplacementRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeImageReplacementRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeImageTextRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [ImageTextRecord]
+
+    public static func write(_ value: [ImageTextRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeImageTextRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ImageTextRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ImageTextRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeImageTextRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeItemStorageRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [ItemStorageRecord]
+
+    public static func write(_ value: [ItemStorageRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeItemStorageRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ItemStorageRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ItemStorageRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeItemStorageRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeNoteImageRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [NoteImageRecord]
+
+    public static func write(_ value: [NoteImageRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeNoteImageRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [NoteImageRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [NoteImageRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeNoteImageRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeNoteTitleRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [NoteTitleRecord]
+
+    public static func write(_ value: [NoteTitleRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeNoteTitleRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [NoteTitleRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [NoteTitleRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeNoteTitleRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeNoteVersionRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [NoteVersionRecord]
+
+    public static func write(_ value: [NoteVersionRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeNoteVersionRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [NoteVersionRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [NoteVersionRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeNoteVersionRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeOpenedItemRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [OpenedItemRecord]
+
+    public static func write(_ value: [OpenedItemRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeOpenedItemRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OpenedItemRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [OpenedItemRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeOpenedItemRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeOutlineEntryRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [OutlineEntryRecord]
+
+    public static func write(_ value: [OutlineEntryRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeOutlineEntryRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OutlineEntryRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [OutlineEntryRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeOutlineEntryRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypePreviewBlockRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [PreviewBlockRecord]
+
+    public static func write(_ value: [PreviewBlockRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePreviewBlockRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PreviewBlockRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PreviewBlockRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePreviewBlockRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeScheduleRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [ScheduleRecord]
+
+    public static func write(_ value: [ScheduleRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeScheduleRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ScheduleRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ScheduleRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeScheduleRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSearchResultRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [SearchResultRecord]
+
+    public static func write(_ value: [SearchResultRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSearchResultRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SearchResultRecord] {
+        let len: Int32 = try readInt(&buf)
         var seq = [SearchResultRecord]()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeSearchResultRecord.read(from: &buf))
+            seq.append(try FfiConverterTypeSearchResultRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSkippedImageRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [SkippedImageRecord]
+
+    public static func write(_ value: [SkippedImageRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSkippedImageRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SkippedImageRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SkippedImageRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSkippedImageRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeStorageAlertRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [StorageAlertRecord]
+
+    public static func write(_ value: [StorageAlertRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeStorageAlertRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [StorageAlertRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [StorageAlertRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeStorageAlertRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeStoredImageRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [StoredImageRecord]
+
+    public static func write(_ value: [StoredImageRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeStoredImageRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [StoredImageRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [StoredImageRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeStoredImageRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTableLayoutRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [TableLayoutRecord]
+
+    public static func write(_ value: [TableLayoutRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTableLayoutRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TableLayoutRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TableLayoutRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTableLayoutRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTextRangeRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [TextRangeRecord]
+
+    public static func write(_ value: [TextRangeRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTextRangeRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TextRangeRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TextRangeRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTextRangeRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTimeReportRowRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [TimeReportRowRecord]
+
+    public static func write(_ value: [TimeReportRowRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTimeReportRowRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TimeReportRowRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TimeReportRowRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTimeReportRowRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTrackerAccountRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [TrackerAccountRecord]
+
+    public static func write(_ value: [TrackerAccountRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTrackerAccountRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TrackerAccountRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TrackerAccountRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTrackerAccountRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTriggerLogEntryRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [TriggerLogEntryRecord]
+
+    public static func write(_ value: [TriggerLogEntryRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTriggerLogEntryRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TriggerLogEntryRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TriggerLogEntryRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTriggerLogEntryRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeTriggerRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [TriggerRecord]
+
+    public static func write(_ value: [TriggerRecord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTriggerRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TriggerRecord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TriggerRecord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTriggerRecord.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = [Double?]
+
+    public static func write(_ value: [Double?], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterOptionDouble.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Double?] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Double?]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterOptionDouble.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceSequenceTypeTextRangeRecord: FfiConverterRustBuffer {
+    typealias SwiftType = [[TextRangeRecord]]
+
+    public static func write(_ value: [[TextRangeRecord]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterSequenceTypeTextRangeRecord.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[TextRangeRecord]] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [[TextRangeRecord]]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterSequenceTypeTextRangeRecord.read(from: &buf))
         }
         return seq
     }
 }
-public func backendVersion() -> String  {
+public func backendVersion() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_backend_version($0
+    )
+})
+}
+/**
+ * Board column tags in workflow order.
+ */
+public func boardStatuses() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_board_statuses($0
+    )
+})
+}
+/**
+ * Every item matching `query` (filter modifiers like `tag:` included; an
+ * empty query lists all) for the browse window, in `sort` order.
+ */
+public func browseItems(query: String, sort: BrowseSortOrder)throws  -> [SearchResultRecord]  {
+    return try  FfiConverterSequenceTypeSearchResultRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_browse_items(
+        FfiConverterString.lower(query),
+        FfiConverterTypeBrowseSortOrder_lower(sort),$0
+    )
+})
+}
+/**
+ * Turns on the prefixes of saved accounts with the tokens the app kept for
+ * them, without saving anything; call after `warm_up_store`.
+ */
+public func connectForgeAccounts(accounts: [ForgeAccountRecord])throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_connect_forge_accounts(
+        FfiConverterSequenceTypeForgeAccountRecord.lower(accounts),$0
+    )
+}
+}
+/**
+ * Turns on the prefixes of saved workspaces with the tokens the app kept
+ * for them, like `connect_forge_accounts`.
+ */
+public func connectTrackerAccounts(accounts: [TrackerAccountRecord])throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_connect_tracker_accounts(
+        FfiConverterSequenceTypeTrackerAccountRecord.lower(accounts),$0
+    )
+}
+}
+/**
+ * What Return does at the editor's caret, given its line up to the caret;
+ * `None` when the line isn't a Markdown list item or checkbox.
+ */
+public func continueMarkdownList(lineBeforeCaret: String) -> ListContinuationRecord?  {
+    return try!  FfiConverterOptionTypeListContinuationRecord.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_continue_markdown_list(
+        FfiConverterString.lower(lineBeforeCaret),$0
+    )
+})
+}
+public func createItem(title: String)throws  -> Int64  {
+    return try  FfiConverterInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_create_item(
+        FfiConverterString.lower(title),$0
+    )
+})
+}
+/**
+ * Deletes an item and returns the archive key that `restore_deleted_item` takes
+ * to undo it.
+ */
+public func deleteItem(itemId: Int64)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_delete_item(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * Deletes several items at once; nothing is deleted if one id is unknown.
+ */
+public func deleteItems(itemIds: [Int64])throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_delete_items(
+        FfiConverterSequenceInt64.lower(itemIds),$0
+    )
+}
+}
+public func deleteSchedule(scheduleId: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_delete_schedule(
+        FfiConverterString.lower(scheduleId),$0
+    )
+}
+}
+public func deleteTrigger(triggerId: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_delete_trigger(
+        FfiConverterString.lower(triggerId),$0
+    )
+}
+}
+/**
+ * Word-level diff from version `old_index` to version `new_index`.
+ */
+public func diffItemVersions(itemId: Int64, oldIndex: UInt32, newIndex: UInt32)throws  -> [DiffSegmentRecord]  {
+    return try  FfiConverterSequenceTypeDiffSegmentRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_diff_item_versions(
+        FfiConverterInt64.lower(itemId),
+        FfiConverterUInt32.lower(oldIndex),
+        FfiConverterUInt32.lower(newIndex),$0
+    )
+})
+}
+/**
+ * Runs a table command in the table made of `lines`, with the caret at UTF-16
+ * offset `caret` of line `row`; `None` when that line isn't a table row.
+ */
+public func editMarkdownTable(lines: [String], row: UInt32, caret: UInt32, command: TableCommandKind) -> TableEditRecord?  {
+    return try!  FfiConverterOptionTypeTableEditRecord.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_edit_markdown_table(
+        FfiConverterSequenceString.lower(lines),
+        FfiConverterUInt32.lower(row),
+        FfiConverterUInt32.lower(caret),
+        FfiConverterTypeTableCommandKind_lower(command),$0
+    )
+})
+}
+public func exportItems()throws  -> [ExportItemRecord]  {
+    return try  FfiConverterSequenceTypeExportItemRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_export_items($0
+    )
+})
+}
+/**
+ * Returns every item as a CSV inventory (no note bodies) for spreadsheets.
+ * While locked, secure items are listed by title only.
+ */
+public func exportItemsCsv()throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_export_items_csv($0
+    )
+})
+}
+/**
+ * Returns the selected items as pretty-printed JSON for saving to a file.
+ */
+public func exportItemsJson(itemIds: [Int64])throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_export_items_json(
+        FfiConverterSequenceInt64.lower(itemIds),$0
+    )
+})
+}
+/**
+ * Returns the time entries of the week containing a date as CSV, one row per
+ * timer run, for invoicing.
+ */
+public func exportTimeEntriesCsv(year: Int32, month: UInt32, day: UInt32)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_export_time_entries_csv(
+        FfiConverterInt32.lower(year),
+        FfiConverterUInt32.lower(month),
+        FfiConverterUInt32.lower(day),$0
+    )
+})
+}
+/**
+ * A Markdown link to the web page at `url`, named after its title, for
+ * links pasted into the editor. Pages that can't be fetched are an error.
+ */
+public func fetchLinkMarkdown(url: String)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_fetch_link_markdown(
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+public func getDeletedItemPreview(archiveKey: String)throws  -> DeletedItemPreviewRecord  {
+    return try  FfiConverterTypeDeletedItemPreviewRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_deleted_item_preview(
+        FfiConverterString.lower(archiveKey),$0
+    )
+})
+}
+public func getIdleStats() -> IdleStatsRecord  {
+    return try!  FfiConverterTypeIdleStatsRecord_lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_get_idle_stats($0
+    )
+})
+}
+public func getItem(itemId: Int64)throws  -> EditableItemRecord  {
+    return try  FfiConverterTypeEditableItemRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_item(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * The `alfredalt://item/<id>` link that opens the item.
+ */
+public func getItemDeepLink(itemId: Int64)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_item_deep_link(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * Returns the bytes of one image of an item, e.g. a result row thumbnail.
+ */
+public func getItemImage(itemId: Int64, imageKey: String)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_item_image(
+        FfiConverterInt64.lower(itemId),
+        FfiConverterString.lower(imageKey),$0
+    )
+})
+}
+public func getItemJsonPath(itemId: Int64)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_item_json_path(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * Returns the item as a Markdown document plus a file name to save it under.
+ */
+public func getItemMarkdown(itemId: Int64)throws  -> ItemMarkdownRecord  {
+    return try  FfiConverterTypeItemMarkdownRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_item_markdown(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * Returns the note of an item as plain text, without inline images or style markup.
+ */
+public func getItemPlainText(itemId: Int64)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_item_plain_text(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * Word counts plus per-day save activity from the item's version history.
+ */
+public func getItemStats(itemId: Int64)throws  -> ItemStatsRecord  {
+    return try  FfiConverterTypeItemStatsRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_item_stats(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * Plain text of one recorded version, e.g. to copy text back from it.
+ */
+public func getItemVersionText(itemId: Int64, index: UInt32)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_item_version_text(
+        FfiConverterInt64.lower(itemId),
+        FfiConverterUInt32.lower(index),$0
+    )
+})
+}
+/**
+ * Free space, mirror size and budget alerts for the JSON storage folder.
+ */
+public func getStorageReport(largestLimit: UInt32?)throws  -> StorageReportRecord  {
+    return try  FfiConverterTypeStorageReportRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_storage_report(
+        FfiConverterOptionUInt32.lower(largestLimit),$0
+    )
+})
+}
+/**
+ * Library totals, searches per day over the last `days` days and the
+ * `top_limit` most opened items.
+ */
+public func getUsageStats(days: UInt32, topLimit: UInt32)throws  -> UsageStatsRecord  {
+    return try  FfiConverterTypeUsageStatsRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_get_usage_stats(
+        FfiConverterUInt32.lower(days),
+        FfiConverterUInt32.lower(topLimit),$0
+    )
+})
+}
+/**
+ * Whether a lock key is enrolled.
+ */
+public func hasLockKey()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_has_lock_key($0
+    )
+})
+}
+/**
+ * Adds every page of a Notion HTML export (the ZIP or its unpacked folder)
+ * as a new item.
+ */
+public func importNotionExport(path: String)throws  -> NotionImportRecord  {
+    return try  FfiConverterTypeNotionImportRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_import_notion_export(
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+/**
+ * Imports notes edited or added in the vault; returns how many items changed.
+ */
+public func importObsidianVaultChanges()throws  -> UInt32  {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_import_obsidian_vault_changes($0
+    )
+})
+}
+/**
+ * Whether this run has the note store turned off.
+ */
+public func isLauncherOnly() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_is_launcher_only($0
+    )
+})
+}
+/**
+ * Whether secure items are locked: a lock key is enrolled and hasn't been
+ * presented since launch or the last `lock`.
+ */
+public func isLocked()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_is_locked($0
+    )
+})
+}
+public func isStoreReady() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_is_store_ready($0
+    )
+})
+}
+/**
+ * The item an `alfredalt://` link opens, or `None` for other links.
+ */
+public func itemIdForDeepLink(link: String) -> Int64?  {
+    return try!  FfiConverterOptionInt64.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_item_id_for_deep_link(
+        FfiConverterString.lower(link),$0
+    )
+})
+}
+/**
+ * The note titled `title`, ignoring case, or `None` when there is none.
+ */
+public func itemIdForNoteTitle(title: String)throws  -> Int64?  {
+    return try  FfiConverterOptionInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_item_id_for_note_title(
+        FfiConverterString.lower(title),$0
+    )
+})
+}
+/**
+ * The newest entries of the append-only log of creates, updates, deletes,
+ * imports and exports, newest first, one line each in local time.
+ */
+public func listActivityLines(limit: UInt32?)throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_activity_lines(
+        FfiConverterOptionUInt32.lower(limit),$0
+    )
+})
+}
+public func listBoardCards()throws  -> [BoardCardRecord]  {
+    return try  FfiConverterSequenceTypeBoardCardRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_board_cards($0
+    )
+})
+}
+public func listCalendarMonth(year: Int32, month: UInt32)throws  -> [CalendarEntryRecord]  {
+    return try  FfiConverterSequenceTypeCalendarEntryRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_calendar_month(
+        FfiConverterInt32.lower(year),
+        FfiConverterUInt32.lower(month),$0
+    )
+})
+}
+public func listChecklistEntries(itemId: Int64)throws  -> [ChecklistEntryRecord]  {
+    return try  FfiConverterSequenceTypeChecklistEntryRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_checklist_entries(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+public func listDeletedItems(limit: UInt32?)throws  -> [DeletedItemRecord]  {
+    return try  FfiConverterSequenceTypeDeletedItemRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_deleted_items(
+        FfiConverterOptionUInt32.lower(limit),$0
+    )
+})
+}
+/**
+ * Images whose text hasn't been recognized yet, for background OCR.
+ */
+public func listImagesWithoutText(limit: UInt32)throws  -> [StoredImageRecord]  {
+    return try  FfiConverterSequenceTypeStoredImageRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_images_without_text(
+        FfiConverterUInt32.lower(limit),$0
+    )
+})
+}
+/**
+ * Image keys of an item for the gallery: in note order, then any images the
+ * note no longer references.
+ */
+public func listItemImageKeys(itemId: Int64)throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_item_image_keys(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * Recorded versions of an item, oldest first.
+ */
+public func listItemVersions(itemId: Int64)throws  -> [NoteVersionRecord]  {
+    return try  FfiConverterSequenceTypeNoteVersionRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_item_versions(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
+}
+/**
+ * Lists pinned items, then the most recently opened or edited ones, for the
+ * empty-query launcher state.
+ */
+public func listRecentItems(limit: UInt32?)throws  -> [SearchResultRecord]  {
+    return try  FfiConverterSequenceTypeSearchResultRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_recent_items(
+        FfiConverterOptionUInt32.lower(limit),$0
+    )
+})
+}
+public func listSchedules()throws  -> [ScheduleRecord]  {
+    return try  FfiConverterSequenceTypeScheduleRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_schedules($0
+    )
+})
+}
+/**
+ * All stored note images, largest first, for background re-encoding.
+ */
+public func listStoredImages()throws  -> [StoredImageRecord]  {
+    return try  FfiConverterSequenceTypeStoredImageRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_stored_images($0
+    )
+})
+}
+public func listTriggerLog(triggerId: String, limit: UInt32?)throws  -> [TriggerLogEntryRecord]  {
+    return try  FfiConverterSequenceTypeTriggerLogEntryRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_trigger_log(
+        FfiConverterString.lower(triggerId),
+        FfiConverterOptionUInt32.lower(limit),$0
+    )
+})
+}
+public func listTriggers()throws  -> [TriggerRecord]  {
+    return try  FfiConverterSequenceTypeTriggerRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_list_triggers($0
+    )
+})
+}
+/**
+ * The GitHub and GitLab accounts behind the "gh " and "gl " prefixes. Their
+ * tokens are empty: the app keeps them in the Keychain and passes them in.
+ */
+public func loadForgeAccounts()throws  -> [ForgeAccountRecord]  {
+    return try  FfiConverterSequenceTypeForgeAccountRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_forge_accounts($0
+    )
+})
+}
+public func loadHotkey()throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_hotkey($0
+    )
+})
+}
+/**
+ * Image count and size limits enforced when saving a note.
+ */
+public func loadImageLimits()throws  -> ImageLimitsRecord  {
+    return try  FfiConverterTypeImageLimitsRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_image_limits($0
+    )
+})
+}
+public func loadJsonStoragePath()throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_json_storage_path($0
+    )
+})
+}
+/**
+ * The launcher-only setting for the next launch.
+ */
+public func loadLauncherOnly()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_launcher_only($0
+    )
+})
+}
+/**
+ * Tags whose items are kept out of the JSON storage folder.
+ */
+public func loadMirrorExcludedTags()throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_mirror_excluded_tags($0
+    )
+})
+}
+/**
+ * Whether the storage folder is kept as an Obsidian vault of Markdown notes
+ * instead of JSON files.
+ */
+public func loadObsidianVaultMode()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_obsidian_vault_mode($0
+    )
+})
+}
+/**
+ * The Rhai script that reorders launcher results; empty for the built-in order.
+ */
+public func loadRankingScript()throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_ranking_script($0
+    )
+})
+}
+/**
+ * Whether launcher queries match items containing any term rather than all.
+ */
+public func loadSearchMatchAny()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_search_match_any($0
+    )
+})
+}
+/**
+ * The Jira and Linear workspaces behind the "jira " and "lin " prefixes.
+ * Their tokens are empty, as with forge accounts.
+ */
+public func loadTrackerAccounts()throws  -> [TrackerAccountRecord]  {
+    return try  FfiConverterSequenceTypeTrackerAccountRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_load_tracker_accounts($0
+    )
+})
+}
+public func lock()  {try! rustCall() {
+    uniffi_alfred_alt_fn_func_lock($0
+    )
+}
+}
+public func markItemOpened(itemId: Int64)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_mark_item_opened(
+        FfiConverterInt64.lower(itemId),$0
+    )
+}
+}
+/**
+ * Merges the editor's text with a version saved elsewhere since `base`,
+ * the note both started from.
+ */
+public func mergeNoteEdits(base: String, ours: String, theirs: String) -> MergedNoteRecord  {
+    return try!  FfiConverterTypeMergedNoteRecord_lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_merge_note_edits(
+        FfiConverterString.lower(base),
+        FfiConverterString.lower(ours),
+        FfiConverterString.lower(theirs),$0
+    )
+})
+}
+/**
+ * Moves an item to the given board column and returns its new revision.
+ */
+public func moveBoardCard(itemId: Int64, status: String)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_move_board_card(
+        FfiConverterInt64.lower(itemId),
+        FfiConverterString.lower(status),$0
+    )
+})
+}
+/**
+ * Unsaved note text with image `image_index` moved before line `target_line`,
+ * for dragging an image in the editor.
+ */
+public func moveNoteImageToLine(note: String, imageIndex: UInt32, targetLine: UInt32)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_move_note_image_to_line(
+        FfiConverterString.lower(note),
+        FfiConverterUInt32.lower(imageIndex),
+        FfiConverterUInt32.lower(targetLine),$0
+    )
+})
+}
+/**
+ * The `#tag`s in note text with their UTF-16 ranges, for the editor's tag chips.
+ */
+public func noteHashtags(text: String) -> [HashtagRecord]  {
+    return try!  FfiConverterSequenceTypeHashtagRecord.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_note_hashtags(
+        FfiConverterString.lower(text),$0
+    )
+})
+}
+/**
+ * The headings of unsaved note text for the editor's outline sidebar.
+ */
+public func noteOutline(note: String) -> [OutlineEntryRecord]  {
+    return try!  FfiConverterSequenceTypeOutlineEntryRecord.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_note_outline(
+        FfiConverterString.lower(note),$0
+    )
+})
+}
+/**
+ * The editor preview's blocks for unsaved note text.
+ */
+public func notePreviewBlocks(note: String) -> [PreviewBlockRecord]  {
+    return try!  FfiConverterSequenceTypePreviewBlockRecord.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_note_preview_blocks(
+        FfiConverterString.lower(note),$0
+    )
+})
+}
+/**
+ * The Markdown tables in note text with their cells' UTF-16 ranges, for
+ * lining up the editor's table columns.
+ */
+public func noteTableLayouts(text: String) -> [TableLayoutRecord]  {
+    return try!  FfiConverterSequenceTypeTableLayoutRecord.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_note_table_layouts(
+        FfiConverterString.lower(text),$0
+    )
+})
+}
+/**
+ * The `alfredalt://note/<title>` link a `[[title]]` note link opens.
+ */
+public func noteTitleDeepLink(title: String) -> String  {
     return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_alfred_alt_fn_func_backend_version($0
+    uniffi_alfred_alt_fn_func_note_title_deep_link(
+        FfiConverterString.lower(title),$0
     )
 })
 }
-public func createItem(title: String)throws  -> Int64  {
+/**
+ * The note title of an `alfredalt://note/<title>` link, or `None` for other
+ * links.
+ */
+public func noteTitleForDeepLink(link: String) -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_note_title_for_deep_link(
+        FfiConverterString.lower(link),$0
+    )
+})
+}
+/**
+ * Returns the id of the daily journal entry for a local date, creating it if needed.
+ */
+public func openJournalEntry(year: Int32, month: UInt32, day: UInt32)throws  -> Int64  {
     return try  FfiConverterInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_create_item(
-        FfiConverterString.lower(title),$0
+    uniffi_alfred_alt_fn_func_open_journal_entry(
+        FfiConverterInt32.lower(year),
+        FfiConverterUInt32.lower(month),
+        FfiConverterUInt32.lower(day),$0
     )
 })
 }
-public func deleteItem(itemId: Int64)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_delete_item(
-        FfiConverterInt64.lower(itemId),$0
+/**
+ * Parses a CSS-like preview style file (`body`, `h1`–`h3` and `code` rules).
+ */
+public func parsePreviewStyle(source: String) -> PreviewStyleRecord  {
+    return try!  FfiConverterTypePreviewStyleRecord_lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_parse_preview_style(
+        FfiConverterString.lower(source),$0
+    )
+})
+}
+/**
+ * The title typed so far when the editor's caret is inside an unclosed
+ * `[[`, given the note text before the caret.
+ */
+public func pendingNoteLinkQuery(textBeforeCaret: String) -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_pending_note_link_query(
+        FfiConverterString.lower(textBeforeCaret),$0
     )
+})
 }
+/**
+ * Carries out a picked `Provider` result, given its `command_key`.
+ */
+public func performProviderAction(commandKey: String)throws  -> ProviderOutcomeRecord  {
+    return try  FfiConverterTypeProviderOutcomeRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_perform_provider_action(
+        FfiConverterString.lower(commandKey),$0
+    )
+})
 }
-public func exportItems()throws  -> [ExportItemRecord]  {
-    return try  FfiConverterSequenceTypeExportItemRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_export_items($0
+public func permanentlyDeleteDeletedItem(archiveKey: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_permanently_delete_deleted_item(
+        FfiConverterString.lower(archiveKey),$0
+    )
+}
+}
+/**
+ * Picks the images in `folder` that still fit into a note holding
+ * `existing_image_count` images; the editor reads and inserts them.
+ */
+public func planImageFolderImport(folder: String, existingImageCount: UInt32)throws  -> ImageImportPlanRecord  {
+    return try  FfiConverterTypeImageImportPlanRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_plan_image_folder_import(
+        FfiConverterString.lower(folder),
+        FfiConverterUInt32.lower(existingImageCount),$0
     )
 })
 }
-public func getDeletedItemPreview(archiveKey: String)throws  -> DeletedItemPreviewRecord  {
-    return try  FfiConverterTypeDeletedItemPreviewRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_get_deleted_item_preview(
+/**
+ * Counts a launcher search the user acted on, for `get_usage_stats`.
+ */
+public func recordSearch()  {try! rustCall() {
+    uniffi_alfred_alt_fn_func_record_search($0
+    )
+}
+}
+/**
+ * Turns the lock off, which needs the app to be unlocked.
+ */
+public func removeLockKey()throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_remove_lock_key($0
+    )
+}
+}
+public func renameItem(itemId: Int64, title: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_rename_item(
+        FfiConverterInt64.lower(itemId),
+        FfiConverterString.lower(title),$0
+    )
+}
+}
+/**
+ * Replaces stored images with re-encoded bytes and returns how many were applied.
+ */
+public func replaceStoredImages(replacements: [ImageReplacementRecord])throws  -> UInt32  {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_replace_stored_images(
+        FfiConverterSequenceTypeImageReplacementRecord.lower(replacements),$0
+    )
+})
+}
+public func restoreDeletedItem(archiveKey: String)throws  -> Int64  {
+    return try  FfiConverterInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_restore_deleted_item(
         FfiConverterString.lower(archiveKey),$0
     )
 })
 }
-public func getItem(itemId: Int64)throws  -> EditableItemRecord  {
-    return try  FfiConverterTypeEditableItemRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_get_item(
-        FfiConverterInt64.lower(itemId),$0
+/**
+ * Fetches the queued read-later pages again and returns how many were
+ * saved. Pages that turn out unsavable leave the queue.
+ */
+public func retryReadLaterQueue()throws  -> UInt32  {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_retry_read_later_queue($0
+    )
+})
+}
+public func runSystemCommand(commandKey: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_run_system_command(
+        FfiConverterString.lower(commandKey),$0
+    )
+}
+}
+public func runningTimer()throws  -> RunningTimerRecord?  {
+    return try  FfiConverterOptionTypeRunningTimerRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_running_timer($0
+    )
+})
+}
+/**
+ * Saves the accounts, at most one per forge, and updates the launcher's
+ * prefixes to match.
+ */
+public func saveForgeAccounts(accounts: [ForgeAccountRecord])throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_forge_accounts(
+        FfiConverterSequenceTypeForgeAccountRecord.lower(accounts),$0
+    )
+}
+}
+public func saveHotkey(hotkey: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_hotkey(
+        FfiConverterString.lower(hotkey),$0
+    )
+}
+}
+/**
+ * Overrides the image limits; `None` restores a default. Values are clamped to
+ * the supported range and the limits in effect are returned.
+ */
+public func saveImageLimits(maxImagesPerNote: UInt32?, maxImageBytes: UInt64?)throws  -> ImageLimitsRecord  {
+    return try  FfiConverterTypeImageLimitsRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_image_limits(
+        FfiConverterOptionUInt32.lower(maxImagesPerNote),
+        FfiConverterOptionUInt64.lower(maxImageBytes),$0
+    )
+})
+}
+/**
+ * Stores text recognized in images, searched like note text, and returns how
+ * many images were updated.
+ */
+public func saveImageTexts(texts: [ImageTextRecord])throws  -> UInt32  {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_image_texts(
+        FfiConverterSequenceTypeImageTextRecord.lower(texts),$0
+    )
+})
+}
+/**
+ * Saves the note and images of an item, and its subtitle and keywords when
+ * `metadata` is given, and returns the new revision.
+ *
+ * Pass the revision obtained from `get_item` as `expected_revision` to fail with
+ * `BackendError::Conflict` instead of overwriting a newer save from another client.
+ */
+public func saveItem(itemId: Int64, note: String, images: [NoteImageRecord], expectedRevision: UInt64?, metadata: ItemMetadataRecord? = nil)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_item(
+        FfiConverterInt64.lower(itemId),
+        FfiConverterString.lower(note),
+        FfiConverterSequenceTypeNoteImageRecord.lower(images),
+        FfiConverterOptionUInt64.lower(expectedRevision),
+        FfiConverterOptionTypeItemMetadataRecord.lower(metadata),$0
+    )
+})
+}
+public func saveJsonStoragePath(path: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_json_storage_path(
+        FfiConverterString.lower(path),$0
+    )
+}
+}
+/**
+ * Turns the note store off or on; takes effect when the app next starts.
+ */
+public func saveLauncherOnly(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_launcher_only(
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+public func saveMirrorExcludedTags(tags: [String])throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_mirror_excluded_tags(
+        FfiConverterSequenceString.lower(tags),$0
+    )
+}
+}
+public func saveObsidianVaultMode(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_obsidian_vault_mode(
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+/**
+ * Rejects a script that doesn't compile and keeps the previous one.
+ */
+public func saveRankingScript(source: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_ranking_script(
+        FfiConverterString.lower(source),$0
+    )
+}
+}
+/**
+ * Fetches the page at `url` and adds its article as a note tagged
+ * `#readlater`. Unreachable pages are queued for `retry_read_later_queue`;
+ * pages that can't be saved (like a 404 or a PDF) are a validation error.
+ */
+public func saveReadLaterArticle(url: String)throws  -> ReadLaterRecord  {
+    return try  FfiConverterTypeReadLaterRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_read_later_article(
+        FfiConverterString.lower(url),$0
+    )
+})
+}
+/**
+ * Creates or updates a recurring note schedule and returns its id.
+ */
+public func saveSchedule(schedule: ScheduleRecord)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_schedule(
+        FfiConverterTypeScheduleRecord_lower(schedule),$0
+    )
+})
+}
+public func saveSearchMatchAny(matchAny: Bool)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_search_match_any(
+        FfiConverterBool.lower(matchAny),$0
+    )
+}
+}
+/**
+ * Sets the mirror size budget in bytes; `None` or 0 removes it.
+ */
+public func saveStorageBudget(budgetBytes: UInt64?)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_storage_budget(
+        FfiConverterOptionUInt64.lower(budgetBytes),$0
+    )
+}
+}
+/**
+ * Saves the accounts, at most one per tracker, and updates the launcher's
+ * prefixes to match.
+ */
+public func saveTrackerAccounts(accounts: [TrackerAccountRecord])throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_tracker_accounts(
+        FfiConverterSequenceTypeTrackerAccountRecord.lower(accounts),$0
+    )
+}
+}
+/**
+ * Creates (empty `id`) or replaces a trigger and returns its id.
+ */
+public func saveTrigger(trigger: TriggerRecord)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_save_trigger(
+        FfiConverterTypeTriggerRecord_lower(trigger),$0
+    )
+})
+}
+public func searchItems(query: String, limit: UInt32?)throws  -> [SearchResultRecord]  {
+    return try  FfiConverterSequenceTypeSearchResultRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_search_items(
+        FfiConverterString.lower(query),
+        FfiConverterOptionUInt32.lower(limit),$0
     )
 })
 }
-public func getItemJsonPath(itemId: Int64)throws  -> String  {
-    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_get_item_json_path(
+/**
+ * Marks the data changes this process makes from now on in the activity
+ * log; callers that never set it are logged as `uniffi`.
+ */
+public func setActivityOrigin(origin: ActivityOriginKind)  {try! rustCall() {
+    uniffi_alfred_alt_fn_func_set_activity_origin(
+        FfiConverterTypeActivityOriginKind_lower(origin),$0
+    )
+}
+}
+/**
+ * Pins or unpins an item; pinned items are listed first among matching results.
+ */
+public func setItemPinned(itemId: Int64, pinned: Bool)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_set_item_pinned(
+        FfiConverterInt64.lower(itemId),
+        FfiConverterBool.lower(pinned),$0
+    )
+}
+}
+/**
+ * Secure items are never written to the JSON storage folder.
+ */
+public func setItemSecure(itemId: Int64, secure: Bool)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_set_item_secure(
+        FfiConverterInt64.lower(itemId),
+        FfiConverterBool.lower(secure),$0
+    )
+}
+}
+/**
+ * Enrolls `key` as the lock key, replacing the current one. Secure items stay
+ * unlocked until the next `lock`.
+ */
+public func setLockKey(key: Data)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_set_lock_key(
+        FfiConverterData.lower(key),$0
+    )
+}
+}
+/**
+ * Lets background workers sleep until they have work while the app is hidden.
+ */
+public func setPowerSaving(enabled: Bool)  {try! rustCall() {
+    uniffi_alfred_alt_fn_func_set_power_saving(
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+/**
+ * Starts a timer on an item, stopping the timer running on another item.
+ */
+public func startTimer(itemId: Int64)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_start_timer(
         FfiConverterInt64.lower(itemId),$0
     )
-})
 }
-public func listDeletedItems(limit: UInt32?)throws  -> [DeletedItemRecord]  {
-    return try  FfiConverterSequenceTypeDeletedItemRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_list_deleted_items(
-        FfiConverterOptionUInt32.lower(limit),$0
+}
+/**
+ * Stops the running timer and returns the seconds it ran, or 0 when no timer
+ * was running.
+ */
+public func stopTimer()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_stop_timer($0
     )
 })
 }
-public func loadHotkey()throws  -> String  {
-    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_load_hotkey($0
+/**
+ * Note titles matching what was typed after `[[`, best match first.
+ */
+public func suggestNoteLinkTitles(query: String, limit: UInt32)throws  -> [NoteTitleRecord]  {
+    return try  FfiConverterSequenceTypeNoteTitleRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_suggest_note_link_titles(
+        FfiConverterString.lower(query),
+        FfiConverterUInt32.lower(limit),$0
     )
 })
 }
-public func loadJsonStoragePath()throws  -> String  {
-    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_load_json_storage_path($0
+/**
+ * Adds `#tag` to each item's note and returns how many notes changed.
+ */
+public func tagItems(itemIds: [Int64], tag: String)throws  -> UInt32  {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_tag_items(
+        FfiConverterSequenceInt64.lower(itemIds),
+        FfiConverterString.lower(tag),$0
     )
 })
 }
-public func permanentlyDeleteDeletedItem(archiveKey: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_permanently_delete_deleted_item(
-        FfiConverterString.lower(archiveKey),$0
+/**
+ * Launcher query that lists the items tagged `tag`.
+ */
+public func tagSearchQuery(tag: String) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_tag_search_query(
+        FfiConverterString.lower(tag),$0
     )
+})
 }
-}
-public func renameItem(itemId: Int64, title: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_rename_item(
+/**
+ * Checks or unchecks entry `index` of the item's checklist and returns the
+ * item's new revision.
+ */
+public func toggleChecklistEntry(itemId: Int64, index: UInt32)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_toggle_checklist_entry(
         FfiConverterInt64.lower(itemId),
-        FfiConverterString.lower(title),$0
+        FfiConverterUInt32.lower(index),$0
     )
+})
 }
+/**
+ * Total seconds tracked on an item, including its running timer.
+ */
+public func trackedSeconds(itemId: Int64)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_tracked_seconds(
+        FfiConverterInt64.lower(itemId),$0
+    )
+})
 }
-public func restoreDeletedItem(archiveKey: String)throws  -> Int64  {
-    return try  FfiConverterInt64.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_restore_deleted_item(
-        FfiConverterString.lower(archiveKey),$0
+/**
+ * Selected note text rewritten by an editor command; style and image tokens
+ * are kept.
+ */
+public func transformNoteText(text: String, transform: TextTransformKind) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_alfred_alt_fn_func_transform_note_text(
+        FfiConverterString.lower(text),
+        FfiConverterTypeTextTransformKind_lower(transform),$0
     )
 })
 }
-public func saveHotkey(hotkey: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_save_hotkey(
-        FfiConverterString.lower(hotkey),$0
+/**
+ * Unlocks secure items until `lock` is called or the app quits.
+ */
+public func unlockWithKey(key: Data)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_unlock_with_key(
+        FfiConverterData.lower(key),$0
     )
 }
 }
-public func saveItem(itemId: Int64, note: String, images: [NoteImageRecord])throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_save_item(
-        FfiConverterInt64.lower(itemId),
-        FfiConverterString.lower(note),
-        FfiConverterSequenceTypeNoteImageRecord.lower(images),$0
+/**
+ * Opens the store and index ahead of the first search; call off the main thread.
+ */
+public func warmUpStore()throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_warm_up_store($0
     )
 }
 }
-public func saveJsonStoragePath(path: String)throws   {try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_save_json_storage_path(
-        FfiConverterString.lower(path),$0
+/**
+ * Tracked time per note in the local week (Monday to Sunday) containing a date.
+ */
+public func weeklyTimeReport(year: Int32, month: UInt32, day: UInt32)throws  -> TimeReportRecord  {
+    return try  FfiConverterTypeTimeReportRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_weekly_time_report(
+        FfiConverterInt32.lower(year),
+        FfiConverterUInt32.lower(month),
+        FfiConverterUInt32.lower(day),$0
     )
+})
 }
-}
-public func searchItems(query: String, limit: UInt32?)throws  -> [SearchResultRecord]  {
-    return try  FfiConverterSequenceTypeSearchResultRecord.lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
-    uniffi_alfred_alt_fn_func_search_items(
-        FfiConverterString.lower(query),
-        FfiConverterOptionUInt32.lower(limit),$0
+/**
+ * How to wrap the selected text in `marker` (`*`, `**`, `_` or a backtick);
+ * with `toggle`, text already wrapped in it is unwrapped instead.
+ */
+public func wrapMarkdownSelection(selected: String, marker: String, toggle: Bool)throws  -> SelectionWrapRecord  {
+    return try  FfiConverterTypeSelectionWrapRecord_lift(try rustCallWithError(FfiConverterTypeBackendError_lift) {
+    uniffi_alfred_alt_fn_func_wrap_markdown_selection(
+        FfiConverterString.lower(selected),
+        FfiConverterString.lower(marker),
+        FfiConverterBool.lower(toggle),$0
     )
 })
 }
@@ -1411,54 +8871,363 @@ private let initializationResult: InitializationResult = {
     if (uniffi_alfred_alt_checksum_func_backend_version() != 7201) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_board_statuses() != 27509) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_browse_items() != 13768) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_connect_forge_accounts() != 30064) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_connect_tracker_accounts() != 13445) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_continue_markdown_list() != 13033) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_create_item() != 4631) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_alfred_alt_checksum_func_delete_item() != 34952) {
+    if (uniffi_alfred_alt_checksum_func_delete_item() != 60352) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_delete_items() != 8721) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_delete_schedule() != 11450) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_delete_trigger() != 40153) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_diff_item_versions() != 9232) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_edit_markdown_table() != 15158) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_alfred_alt_checksum_func_export_items() != 36696) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_export_items_csv() != 54482) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_export_items_json() != 42003) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_export_time_entries_csv() != 16313) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_fetch_link_markdown() != 57944) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_get_deleted_item_preview() != 22060) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_get_idle_stats() != 31185) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_get_item() != 23813) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_get_item_deep_link() != 7231) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_get_item_image() != 31336) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_get_item_json_path() != 32002) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_get_item_markdown() != 61679) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_get_item_plain_text() != 43119) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_get_item_stats() != 8794) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_get_item_version_text() != 34360) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_get_storage_report() != 12658) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_get_usage_stats() != 17112) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_has_lock_key() != 34913) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_import_notion_export() != 38094) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_import_obsidian_vault_changes() != 54114) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_is_launcher_only() != 3755) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_is_locked() != 35043) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_is_store_ready() != 52330) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_item_id_for_deep_link() != 12965) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_item_id_for_note_title() != 9965) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_activity_lines() != 45484) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_board_cards() != 31961) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_calendar_month() != 20695) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_checklist_entries() != 10310) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_list_deleted_items() != 36453) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_list_images_without_text() != 12974) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_item_image_keys() != 4312) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_item_versions() != 37291) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_recent_items() != 63841) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_schedules() != 62684) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_stored_images() != 48288) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_trigger_log() != 56983) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_list_triggers() != 44186) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_load_forge_accounts() != 34475) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_load_hotkey() != 36564) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_load_image_limits() != 22345) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_load_json_storage_path() != 17743) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_load_launcher_only() != 41104) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_load_mirror_excluded_tags() != 18063) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_load_obsidian_vault_mode() != 29288) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_load_ranking_script() != 41157) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_load_search_match_any() != 51656) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_load_tracker_accounts() != 21381) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_lock() != 38934) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_mark_item_opened() != 4796) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_merge_note_edits() != 60217) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_move_board_card() != 35846) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_move_note_image_to_line() != 30447) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_note_hashtags() != 56581) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_note_outline() != 33874) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_note_preview_blocks() != 30851) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_note_table_layouts() != 23397) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_note_title_deep_link() != 49138) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_note_title_for_deep_link() != 65334) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_open_journal_entry() != 63696) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_parse_preview_style() != 35002) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_pending_note_link_query() != 24542) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_perform_provider_action() != 61173) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_permanently_delete_deleted_item() != 8099) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_plan_image_folder_import() != 28122) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_record_search() != 51882) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_remove_lock_key() != 31232) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_rename_item() != 28376) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_replace_stored_images() != 54407) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_restore_deleted_item() != 3969) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_retry_read_later_queue() != 9348) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_run_system_command() != 28395) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_running_timer() != 57465) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_forge_accounts() != 8669) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_save_hotkey() != 49443) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_alfred_alt_checksum_func_save_item() != 60654) {
+    if (uniffi_alfred_alt_checksum_func_save_image_limits() != 41488) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_image_texts() != 1989) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_item() != 36080) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_alfred_alt_checksum_func_save_json_storage_path() != 45047) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_save_launcher_only() != 45344) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_mirror_excluded_tags() != 38096) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_obsidian_vault_mode() != 17007) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_ranking_script() != 49584) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_read_later_article() != 51436) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_schedule() != 31866) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_search_match_any() != 9410) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_storage_budget() != 49329) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_tracker_accounts() != 20617) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_save_trigger() != 26128) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_alfred_alt_checksum_func_search_items() != 22056) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_alfred_alt_checksum_func_set_activity_origin() != 45735) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_set_item_pinned() != 13102) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_set_item_secure() != 20685) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_set_lock_key() != 3322) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_set_power_saving() != 58411) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_start_timer() != 5839) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_stop_timer() != 45076) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_suggest_note_link_titles() != 26254) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_tag_items() != 53045) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_tag_search_query() != 48001) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_toggle_checklist_entry() != 15498) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_tracked_seconds() != 45655) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_transform_note_text() != 61287) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_unlock_with_key() != 40789) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_warm_up_store() != 26553) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_weekly_time_report() != 11080) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_alfred_alt_checksum_func_wrap_markdown_selection() != 16188) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
     return InitializationResult.ok
 }()
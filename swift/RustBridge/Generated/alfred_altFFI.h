@@ -255,6 +255,32 @@ typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStr
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_BACKEND_VERSION
 RustBuffer uniffi_alfred_alt_fn_func_backend_version(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_BOARD_STATUSES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_BOARD_STATUSES
+RustBuffer uniffi_alfred_alt_fn_func_board_statuses(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_BROWSE_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_BROWSE_ITEMS
+RustBuffer uniffi_alfred_alt_fn_func_browse_items(RustBuffer query, RustBuffer sort, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_CONNECT_FORGE_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_CONNECT_FORGE_ACCOUNTS
+void uniffi_alfred_alt_fn_func_connect_forge_accounts(RustBuffer accounts, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_CONNECT_TRACKER_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_CONNECT_TRACKER_ACCOUNTS
+void uniffi_alfred_alt_fn_func_connect_tracker_accounts(RustBuffer accounts, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_CONTINUE_MARKDOWN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_CONTINUE_MARKDOWN_LIST
+RustBuffer uniffi_alfred_alt_fn_func_continue_markdown_list(RustBuffer line_before_caret, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_CREATE_ITEM
@@ -264,18 +290,70 @@ int64_t uniffi_alfred_alt_fn_func_create_item(RustBuffer title, RustCallStatus *
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DELETE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DELETE_ITEM
-void uniffi_alfred_alt_fn_func_delete_item(int64_t item_id, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_alfred_alt_fn_func_delete_item(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DELETE_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DELETE_ITEMS
+void uniffi_alfred_alt_fn_func_delete_items(RustBuffer item_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DELETE_SCHEDULE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DELETE_SCHEDULE
+void uniffi_alfred_alt_fn_func_delete_schedule(RustBuffer schedule_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DELETE_TRIGGER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DELETE_TRIGGER
+void uniffi_alfred_alt_fn_func_delete_trigger(RustBuffer trigger_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DIFF_ITEM_VERSIONS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_DIFF_ITEM_VERSIONS
+RustBuffer uniffi_alfred_alt_fn_func_diff_item_versions(int64_t item_id, uint32_t old_index, uint32_t new_index, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EDIT_MARKDOWN_TABLE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EDIT_MARKDOWN_TABLE
+RustBuffer uniffi_alfred_alt_fn_func_edit_markdown_table(RustBuffer lines, uint32_t row, uint32_t caret, RustBuffer command, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EXPORT_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EXPORT_ITEMS
 RustBuffer uniffi_alfred_alt_fn_func_export_items(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EXPORT_ITEMS_CSV
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EXPORT_ITEMS_CSV
+RustBuffer uniffi_alfred_alt_fn_func_export_items_csv(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EXPORT_ITEMS_JSON
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EXPORT_ITEMS_JSON
+RustBuffer uniffi_alfred_alt_fn_func_export_items_json(RustBuffer item_ids, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EXPORT_TIME_ENTRIES_CSV
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_EXPORT_TIME_ENTRIES_CSV
+RustBuffer uniffi_alfred_alt_fn_func_export_time_entries_csv(int32_t year, uint32_t month, uint32_t day, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_FETCH_LINK_MARKDOWN
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_FETCH_LINK_MARKDOWN
+RustBuffer uniffi_alfred_alt_fn_func_fetch_link_markdown(RustBuffer url, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_DELETED_ITEM_PREVIEW
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_DELETED_ITEM_PREVIEW
 RustBuffer uniffi_alfred_alt_fn_func_get_deleted_item_preview(RustBuffer archive_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_IDLE_STATS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_IDLE_STATS
+RustBuffer uniffi_alfred_alt_fn_func_get_idle_stats(RustCallStatus *_Nonnull out_status
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM
@@ -283,31 +361,321 @@ RustBuffer uniffi_alfred_alt_fn_func_get_deleted_item_preview(RustBuffer archive
 RustBuffer uniffi_alfred_alt_fn_func_get_item(int64_t item_id, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_DEEP_LINK
+RustBuffer uniffi_alfred_alt_fn_func_get_item_deep_link(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_IMAGE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_IMAGE
+RustBuffer uniffi_alfred_alt_fn_func_get_item_image(int64_t item_id, RustBuffer image_key, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_JSON_PATH
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_JSON_PATH
 RustBuffer uniffi_alfred_alt_fn_func_get_item_json_path(int64_t item_id, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_MARKDOWN
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_MARKDOWN
+RustBuffer uniffi_alfred_alt_fn_func_get_item_markdown(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_PLAIN_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_PLAIN_TEXT
+RustBuffer uniffi_alfred_alt_fn_func_get_item_plain_text(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_STATS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_STATS
+RustBuffer uniffi_alfred_alt_fn_func_get_item_stats(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_VERSION_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_ITEM_VERSION_TEXT
+RustBuffer uniffi_alfred_alt_fn_func_get_item_version_text(int64_t item_id, uint32_t index, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_STORAGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_STORAGE_REPORT
+RustBuffer uniffi_alfred_alt_fn_func_get_storage_report(RustBuffer largest_limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_USAGE_STATS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_GET_USAGE_STATS
+RustBuffer uniffi_alfred_alt_fn_func_get_usage_stats(uint32_t days, uint32_t top_limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_HAS_LOCK_KEY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_HAS_LOCK_KEY
+int8_t uniffi_alfred_alt_fn_func_has_lock_key(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IMPORT_NOTION_EXPORT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IMPORT_NOTION_EXPORT
+RustBuffer uniffi_alfred_alt_fn_func_import_notion_export(RustBuffer path, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IMPORT_OBSIDIAN_VAULT_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IMPORT_OBSIDIAN_VAULT_CHANGES
+uint32_t uniffi_alfred_alt_fn_func_import_obsidian_vault_changes(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IS_LAUNCHER_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IS_LAUNCHER_ONLY
+int8_t uniffi_alfred_alt_fn_func_is_launcher_only(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IS_LOCKED
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IS_LOCKED
+int8_t uniffi_alfred_alt_fn_func_is_locked(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IS_STORE_READY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_IS_STORE_READY
+int8_t uniffi_alfred_alt_fn_func_is_store_ready(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_ITEM_ID_FOR_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_ITEM_ID_FOR_DEEP_LINK
+RustBuffer uniffi_alfred_alt_fn_func_item_id_for_deep_link(RustBuffer link, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_ITEM_ID_FOR_NOTE_TITLE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_ITEM_ID_FOR_NOTE_TITLE
+RustBuffer uniffi_alfred_alt_fn_func_item_id_for_note_title(RustBuffer title, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_ACTIVITY_LINES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_ACTIVITY_LINES
+RustBuffer uniffi_alfred_alt_fn_func_list_activity_lines(RustBuffer limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_BOARD_CARDS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_BOARD_CARDS
+RustBuffer uniffi_alfred_alt_fn_func_list_board_cards(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_CALENDAR_MONTH
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_CALENDAR_MONTH
+RustBuffer uniffi_alfred_alt_fn_func_list_calendar_month(int32_t year, uint32_t month, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_CHECKLIST_ENTRIES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_CHECKLIST_ENTRIES
+RustBuffer uniffi_alfred_alt_fn_func_list_checklist_entries(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_DELETED_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_DELETED_ITEMS
 RustBuffer uniffi_alfred_alt_fn_func_list_deleted_items(RustBuffer limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_IMAGES_WITHOUT_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_IMAGES_WITHOUT_TEXT
+RustBuffer uniffi_alfred_alt_fn_func_list_images_without_text(uint32_t limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_ITEM_IMAGE_KEYS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_ITEM_IMAGE_KEYS
+RustBuffer uniffi_alfred_alt_fn_func_list_item_image_keys(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_ITEM_VERSIONS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_ITEM_VERSIONS
+RustBuffer uniffi_alfred_alt_fn_func_list_item_versions(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_RECENT_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_RECENT_ITEMS
+RustBuffer uniffi_alfred_alt_fn_func_list_recent_items(RustBuffer limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_SCHEDULES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_SCHEDULES
+RustBuffer uniffi_alfred_alt_fn_func_list_schedules(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_STORED_IMAGES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_STORED_IMAGES
+RustBuffer uniffi_alfred_alt_fn_func_list_stored_images(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_TRIGGER_LOG
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_TRIGGER_LOG
+RustBuffer uniffi_alfred_alt_fn_func_list_trigger_log(RustBuffer trigger_id, RustBuffer limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_TRIGGERS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LIST_TRIGGERS
+RustBuffer uniffi_alfred_alt_fn_func_list_triggers(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_FORGE_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_FORGE_ACCOUNTS
+RustBuffer uniffi_alfred_alt_fn_func_load_forge_accounts(RustCallStatus *_Nonnull out_status
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_HOTKEY
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_HOTKEY
 RustBuffer uniffi_alfred_alt_fn_func_load_hotkey(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_IMAGE_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_IMAGE_LIMITS
+RustBuffer uniffi_alfred_alt_fn_func_load_image_limits(RustCallStatus *_Nonnull out_status
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_JSON_STORAGE_PATH
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_JSON_STORAGE_PATH
 RustBuffer uniffi_alfred_alt_fn_func_load_json_storage_path(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_LAUNCHER_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_LAUNCHER_ONLY
+int8_t uniffi_alfred_alt_fn_func_load_launcher_only(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_MIRROR_EXCLUDED_TAGS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_MIRROR_EXCLUDED_TAGS
+RustBuffer uniffi_alfred_alt_fn_func_load_mirror_excluded_tags(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_OBSIDIAN_VAULT_MODE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_OBSIDIAN_VAULT_MODE
+int8_t uniffi_alfred_alt_fn_func_load_obsidian_vault_mode(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_RANKING_SCRIPT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_RANKING_SCRIPT
+RustBuffer uniffi_alfred_alt_fn_func_load_ranking_script(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_SEARCH_MATCH_ANY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_SEARCH_MATCH_ANY
+int8_t uniffi_alfred_alt_fn_func_load_search_match_any(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_TRACKER_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOAD_TRACKER_ACCOUNTS
+RustBuffer uniffi_alfred_alt_fn_func_load_tracker_accounts(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOCK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_LOCK
+void uniffi_alfred_alt_fn_func_lock(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_MARK_ITEM_OPENED
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_MARK_ITEM_OPENED
+void uniffi_alfred_alt_fn_func_mark_item_opened(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_MERGE_NOTE_EDITS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_MERGE_NOTE_EDITS
+RustBuffer uniffi_alfred_alt_fn_func_merge_note_edits(RustBuffer base, RustBuffer ours, RustBuffer theirs, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_MOVE_BOARD_CARD
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_MOVE_BOARD_CARD
+uint64_t uniffi_alfred_alt_fn_func_move_board_card(int64_t item_id, RustBuffer status, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_MOVE_NOTE_IMAGE_TO_LINE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_MOVE_NOTE_IMAGE_TO_LINE
+RustBuffer uniffi_alfred_alt_fn_func_move_note_image_to_line(RustBuffer note, uint32_t image_index, uint32_t target_line, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_HASHTAGS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_HASHTAGS
+RustBuffer uniffi_alfred_alt_fn_func_note_hashtags(RustBuffer text, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_OUTLINE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_OUTLINE
+RustBuffer uniffi_alfred_alt_fn_func_note_outline(RustBuffer note, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_PREVIEW_BLOCKS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_PREVIEW_BLOCKS
+RustBuffer uniffi_alfred_alt_fn_func_note_preview_blocks(RustBuffer note, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_TABLE_LAYOUTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_TABLE_LAYOUTS
+RustBuffer uniffi_alfred_alt_fn_func_note_table_layouts(RustBuffer text, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_TITLE_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_TITLE_DEEP_LINK
+RustBuffer uniffi_alfred_alt_fn_func_note_title_deep_link(RustBuffer title, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_TITLE_FOR_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_NOTE_TITLE_FOR_DEEP_LINK
+RustBuffer uniffi_alfred_alt_fn_func_note_title_for_deep_link(RustBuffer link, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_OPEN_JOURNAL_ENTRY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_OPEN_JOURNAL_ENTRY
+int64_t uniffi_alfred_alt_fn_func_open_journal_entry(int32_t year, uint32_t month, uint32_t day, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PARSE_PREVIEW_STYLE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PARSE_PREVIEW_STYLE
+RustBuffer uniffi_alfred_alt_fn_func_parse_preview_style(RustBuffer source, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PENDING_NOTE_LINK_QUERY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PENDING_NOTE_LINK_QUERY
+RustBuffer uniffi_alfred_alt_fn_func_pending_note_link_query(RustBuffer text_before_caret, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PERFORM_PROVIDER_ACTION
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PERFORM_PROVIDER_ACTION
+RustBuffer uniffi_alfred_alt_fn_func_perform_provider_action(RustBuffer command_key, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PERMANENTLY_DELETE_DELETED_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PERMANENTLY_DELETE_DELETED_ITEM
 void uniffi_alfred_alt_fn_func_permanently_delete_deleted_item(RustBuffer archive_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PLAN_IMAGE_FOLDER_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_PLAN_IMAGE_FOLDER_IMPORT
+RustBuffer uniffi_alfred_alt_fn_func_plan_image_folder_import(RustBuffer folder, uint32_t existing_image_count, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RECORD_SEARCH
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RECORD_SEARCH
+void uniffi_alfred_alt_fn_func_record_search(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_REMOVE_LOCK_KEY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_REMOVE_LOCK_KEY
+void uniffi_alfred_alt_fn_func_remove_lock_key(RustCallStatus *_Nonnull out_status
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RENAME_ITEM
@@ -315,19 +683,56 @@ void uniffi_alfred_alt_fn_func_permanently_delete_deleted_item(RustBuffer archiv
 void uniffi_alfred_alt_fn_func_rename_item(int64_t item_id, RustBuffer title, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_REPLACE_STORED_IMAGES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_REPLACE_STORED_IMAGES
+uint32_t uniffi_alfred_alt_fn_func_replace_stored_images(RustBuffer replacements, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RESTORE_DELETED_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RESTORE_DELETED_ITEM
 int64_t uniffi_alfred_alt_fn_func_restore_deleted_item(RustBuffer archive_key, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RETRY_READ_LATER_QUEUE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RETRY_READ_LATER_QUEUE
+uint32_t uniffi_alfred_alt_fn_func_retry_read_later_queue(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RUN_SYSTEM_COMMAND
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RUN_SYSTEM_COMMAND
+void uniffi_alfred_alt_fn_func_run_system_command(RustBuffer command_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RUNNING_TIMER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_RUNNING_TIMER
+RustBuffer uniffi_alfred_alt_fn_func_running_timer(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_FORGE_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_FORGE_ACCOUNTS
+void uniffi_alfred_alt_fn_func_save_forge_accounts(RustBuffer accounts, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_HOTKEY
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_HOTKEY
 void uniffi_alfred_alt_fn_func_save_hotkey(RustBuffer hotkey, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_IMAGE_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_IMAGE_LIMITS
+RustBuffer uniffi_alfred_alt_fn_func_save_image_limits(RustBuffer max_images_per_note, RustBuffer max_image_bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_IMAGE_TEXTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_IMAGE_TEXTS
+uint32_t uniffi_alfred_alt_fn_func_save_image_texts(RustBuffer texts, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_ITEM
-void uniffi_alfred_alt_fn_func_save_item(int64_t item_id, RustBuffer note, RustBuffer images, RustCallStatus *_Nonnull out_status
+uint64_t uniffi_alfred_alt_fn_func_save_item(int64_t item_id, RustBuffer note, RustBuffer images, RustBuffer expected_revision, RustBuffer metadata, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_JSON_STORAGE_PATH
@@ -335,11 +740,148 @@ void uniffi_alfred_alt_fn_func_save_item(int64_t item_id, RustBuffer note, RustB
 void uniffi_alfred_alt_fn_func_save_json_storage_path(RustBuffer path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_LAUNCHER_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_LAUNCHER_ONLY
+void uniffi_alfred_alt_fn_func_save_launcher_only(int8_t enabled, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_MIRROR_EXCLUDED_TAGS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_MIRROR_EXCLUDED_TAGS
+void uniffi_alfred_alt_fn_func_save_mirror_excluded_tags(RustBuffer tags, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_OBSIDIAN_VAULT_MODE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_OBSIDIAN_VAULT_MODE
+void uniffi_alfred_alt_fn_func_save_obsidian_vault_mode(int8_t enabled, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_RANKING_SCRIPT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_RANKING_SCRIPT
+void uniffi_alfred_alt_fn_func_save_ranking_script(RustBuffer source, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_READ_LATER_ARTICLE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_READ_LATER_ARTICLE
+RustBuffer uniffi_alfred_alt_fn_func_save_read_later_article(RustBuffer url, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_SCHEDULE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_SCHEDULE
+RustBuffer uniffi_alfred_alt_fn_func_save_schedule(RustBuffer schedule, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_SEARCH_MATCH_ANY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_SEARCH_MATCH_ANY
+void uniffi_alfred_alt_fn_func_save_search_match_any(int8_t match_any, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_STORAGE_BUDGET
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_STORAGE_BUDGET
+void uniffi_alfred_alt_fn_func_save_storage_budget(RustBuffer budget_bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_TRACKER_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_TRACKER_ACCOUNTS
+void uniffi_alfred_alt_fn_func_save_tracker_accounts(RustBuffer accounts, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_TRIGGER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SAVE_TRIGGER
+RustBuffer uniffi_alfred_alt_fn_func_save_trigger(RustBuffer trigger, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SEARCH_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SEARCH_ITEMS
 RustBuffer uniffi_alfred_alt_fn_func_search_items(RustBuffer query, RustBuffer limit, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_ACTIVITY_ORIGIN
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_ACTIVITY_ORIGIN
+void uniffi_alfred_alt_fn_func_set_activity_origin(RustBuffer origin, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_ITEM_PINNED
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_ITEM_PINNED
+void uniffi_alfred_alt_fn_func_set_item_pinned(int64_t item_id, int8_t pinned, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_ITEM_SECURE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_ITEM_SECURE
+void uniffi_alfred_alt_fn_func_set_item_secure(int64_t item_id, int8_t secure, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_LOCK_KEY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_LOCK_KEY
+void uniffi_alfred_alt_fn_func_set_lock_key(RustBuffer key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_POWER_SAVING
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SET_POWER_SAVING
+void uniffi_alfred_alt_fn_func_set_power_saving(int8_t enabled, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_START_TIMER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_START_TIMER
+void uniffi_alfred_alt_fn_func_start_timer(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_STOP_TIMER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_STOP_TIMER
+uint64_t uniffi_alfred_alt_fn_func_stop_timer(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SUGGEST_NOTE_LINK_TITLES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_SUGGEST_NOTE_LINK_TITLES
+RustBuffer uniffi_alfred_alt_fn_func_suggest_note_link_titles(RustBuffer query, uint32_t limit, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TAG_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TAG_ITEMS
+uint32_t uniffi_alfred_alt_fn_func_tag_items(RustBuffer item_ids, RustBuffer tag, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TAG_SEARCH_QUERY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TAG_SEARCH_QUERY
+RustBuffer uniffi_alfred_alt_fn_func_tag_search_query(RustBuffer tag, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TOGGLE_CHECKLIST_ENTRY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TOGGLE_CHECKLIST_ENTRY
+uint64_t uniffi_alfred_alt_fn_func_toggle_checklist_entry(int64_t item_id, uint32_t index, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TRACKED_SECONDS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TRACKED_SECONDS
+uint64_t uniffi_alfred_alt_fn_func_tracked_seconds(int64_t item_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TRANSFORM_NOTE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_TRANSFORM_NOTE_TEXT
+RustBuffer uniffi_alfred_alt_fn_func_transform_note_text(RustBuffer text, RustBuffer transform, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_UNLOCK_WITH_KEY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_UNLOCK_WITH_KEY
+void uniffi_alfred_alt_fn_func_unlock_with_key(RustBuffer key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_WARM_UP_STORE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_WARM_UP_STORE
+void uniffi_alfred_alt_fn_func_warm_up_store(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_WEEKLY_TIME_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_WEEKLY_TIME_REPORT
+RustBuffer uniffi_alfred_alt_fn_func_weekly_time_report(int32_t year, uint32_t month, uint32_t day, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_WRAP_MARKDOWN_SELECTION
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_FN_FUNC_WRAP_MARKDOWN_SELECTION
+RustBuffer uniffi_alfred_alt_fn_func_wrap_markdown_selection(RustBuffer selected, RustBuffer marker, int8_t toggle, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_FFI_ALFRED_ALT_RUSTBUFFER_ALLOC
 #define UNIFFI_FFIDEF_FFI_ALFRED_ALT_RUSTBUFFER_ALLOC
 RustBuffer ffi_alfred_alt_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
@@ -626,82 +1168,538 @@ uint16_t uniffi_alfred_alt_checksum_func_backend_version(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CREATE_ITEM
-#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CREATE_ITEM
-uint16_t uniffi_alfred_alt_checksum_func_create_item(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_BOARD_STATUSES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_BOARD_STATUSES
+uint16_t uniffi_alfred_alt_checksum_func_board_statuses(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_ITEM
-#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_ITEM
-uint16_t uniffi_alfred_alt_checksum_func_delete_item(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_BROWSE_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_BROWSE_ITEMS
+uint16_t uniffi_alfred_alt_checksum_func_browse_items(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_ITEMS
-#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_ITEMS
-uint16_t uniffi_alfred_alt_checksum_func_export_items(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CONNECT_FORGE_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CONNECT_FORGE_ACCOUNTS
+uint16_t uniffi_alfred_alt_checksum_func_connect_forge_accounts(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_DELETED_ITEM_PREVIEW
-#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_DELETED_ITEM_PREVIEW
-uint16_t uniffi_alfred_alt_checksum_func_get_deleted_item_preview(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CONNECT_TRACKER_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CONNECT_TRACKER_ACCOUNTS
+uint16_t uniffi_alfred_alt_checksum_func_connect_tracker_accounts(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM
-#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM
-uint16_t uniffi_alfred_alt_checksum_func_get_item(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CONTINUE_MARKDOWN_LIST
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CONTINUE_MARKDOWN_LIST
+uint16_t uniffi_alfred_alt_checksum_func_continue_markdown_list(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_JSON_PATH
-#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_JSON_PATH
-uint16_t uniffi_alfred_alt_checksum_func_get_item_json_path(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CREATE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_CREATE_ITEM
+uint16_t uniffi_alfred_alt_checksum_func_create_item(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_DELETED_ITEMS
-#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_DELETED_ITEMS
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_ITEM
+uint16_t uniffi_alfred_alt_checksum_func_delete_item(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_ITEMS
+uint16_t uniffi_alfred_alt_checksum_func_delete_items(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_SCHEDULE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_SCHEDULE
+uint16_t uniffi_alfred_alt_checksum_func_delete_schedule(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_TRIGGER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DELETE_TRIGGER
+uint16_t uniffi_alfred_alt_checksum_func_delete_trigger(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DIFF_ITEM_VERSIONS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_DIFF_ITEM_VERSIONS
+uint16_t uniffi_alfred_alt_checksum_func_diff_item_versions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EDIT_MARKDOWN_TABLE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EDIT_MARKDOWN_TABLE
+uint16_t uniffi_alfred_alt_checksum_func_edit_markdown_table(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_ITEMS
+uint16_t uniffi_alfred_alt_checksum_func_export_items(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_ITEMS_CSV
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_ITEMS_CSV
+uint16_t uniffi_alfred_alt_checksum_func_export_items_csv(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_ITEMS_JSON
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_ITEMS_JSON
+uint16_t uniffi_alfred_alt_checksum_func_export_items_json(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_TIME_ENTRIES_CSV
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_EXPORT_TIME_ENTRIES_CSV
+uint16_t uniffi_alfred_alt_checksum_func_export_time_entries_csv(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_FETCH_LINK_MARKDOWN
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_FETCH_LINK_MARKDOWN
+uint16_t uniffi_alfred_alt_checksum_func_fetch_link_markdown(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_DELETED_ITEM_PREVIEW
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_DELETED_ITEM_PREVIEW
+uint16_t uniffi_alfred_alt_checksum_func_get_deleted_item_preview(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_IDLE_STATS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_IDLE_STATS
+uint16_t uniffi_alfred_alt_checksum_func_get_idle_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM
+uint16_t uniffi_alfred_alt_checksum_func_get_item(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_DEEP_LINK
+uint16_t uniffi_alfred_alt_checksum_func_get_item_deep_link(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_IMAGE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_IMAGE
+uint16_t uniffi_alfred_alt_checksum_func_get_item_image(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_JSON_PATH
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_JSON_PATH
+uint16_t uniffi_alfred_alt_checksum_func_get_item_json_path(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_MARKDOWN
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_MARKDOWN
+uint16_t uniffi_alfred_alt_checksum_func_get_item_markdown(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_PLAIN_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_PLAIN_TEXT
+uint16_t uniffi_alfred_alt_checksum_func_get_item_plain_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_STATS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_STATS
+uint16_t uniffi_alfred_alt_checksum_func_get_item_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_VERSION_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_ITEM_VERSION_TEXT
+uint16_t uniffi_alfred_alt_checksum_func_get_item_version_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_STORAGE_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_STORAGE_REPORT
+uint16_t uniffi_alfred_alt_checksum_func_get_storage_report(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_USAGE_STATS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_GET_USAGE_STATS
+uint16_t uniffi_alfred_alt_checksum_func_get_usage_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_HAS_LOCK_KEY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_HAS_LOCK_KEY
+uint16_t uniffi_alfred_alt_checksum_func_has_lock_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IMPORT_NOTION_EXPORT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IMPORT_NOTION_EXPORT
+uint16_t uniffi_alfred_alt_checksum_func_import_notion_export(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IMPORT_OBSIDIAN_VAULT_CHANGES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IMPORT_OBSIDIAN_VAULT_CHANGES
+uint16_t uniffi_alfred_alt_checksum_func_import_obsidian_vault_changes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IS_LAUNCHER_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IS_LAUNCHER_ONLY
+uint16_t uniffi_alfred_alt_checksum_func_is_launcher_only(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IS_LOCKED
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IS_LOCKED
+uint16_t uniffi_alfred_alt_checksum_func_is_locked(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IS_STORE_READY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_IS_STORE_READY
+uint16_t uniffi_alfred_alt_checksum_func_is_store_ready(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_ITEM_ID_FOR_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_ITEM_ID_FOR_DEEP_LINK
+uint16_t uniffi_alfred_alt_checksum_func_item_id_for_deep_link(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_ITEM_ID_FOR_NOTE_TITLE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_ITEM_ID_FOR_NOTE_TITLE
+uint16_t uniffi_alfred_alt_checksum_func_item_id_for_note_title(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_ACTIVITY_LINES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_ACTIVITY_LINES
+uint16_t uniffi_alfred_alt_checksum_func_list_activity_lines(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_BOARD_CARDS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_BOARD_CARDS
+uint16_t uniffi_alfred_alt_checksum_func_list_board_cards(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_CALENDAR_MONTH
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_CALENDAR_MONTH
+uint16_t uniffi_alfred_alt_checksum_func_list_calendar_month(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_CHECKLIST_ENTRIES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_CHECKLIST_ENTRIES
+uint16_t uniffi_alfred_alt_checksum_func_list_checklist_entries(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_DELETED_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_DELETED_ITEMS
 uint16_t uniffi_alfred_alt_checksum_func_list_deleted_items(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_IMAGES_WITHOUT_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_IMAGES_WITHOUT_TEXT
+uint16_t uniffi_alfred_alt_checksum_func_list_images_without_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_ITEM_IMAGE_KEYS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_ITEM_IMAGE_KEYS
+uint16_t uniffi_alfred_alt_checksum_func_list_item_image_keys(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_ITEM_VERSIONS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_ITEM_VERSIONS
+uint16_t uniffi_alfred_alt_checksum_func_list_item_versions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_RECENT_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_RECENT_ITEMS
+uint16_t uniffi_alfred_alt_checksum_func_list_recent_items(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_SCHEDULES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_SCHEDULES
+uint16_t uniffi_alfred_alt_checksum_func_list_schedules(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_STORED_IMAGES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_STORED_IMAGES
+uint16_t uniffi_alfred_alt_checksum_func_list_stored_images(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_TRIGGER_LOG
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_TRIGGER_LOG
+uint16_t uniffi_alfred_alt_checksum_func_list_trigger_log(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_TRIGGERS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LIST_TRIGGERS
+uint16_t uniffi_alfred_alt_checksum_func_list_triggers(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_FORGE_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_FORGE_ACCOUNTS
+uint16_t uniffi_alfred_alt_checksum_func_load_forge_accounts(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_HOTKEY
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_HOTKEY
 uint16_t uniffi_alfred_alt_checksum_func_load_hotkey(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_IMAGE_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_IMAGE_LIMITS
+uint16_t uniffi_alfred_alt_checksum_func_load_image_limits(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_JSON_STORAGE_PATH
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_JSON_STORAGE_PATH
 uint16_t uniffi_alfred_alt_checksum_func_load_json_storage_path(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_LAUNCHER_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_LAUNCHER_ONLY
+uint16_t uniffi_alfred_alt_checksum_func_load_launcher_only(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_MIRROR_EXCLUDED_TAGS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_MIRROR_EXCLUDED_TAGS
+uint16_t uniffi_alfred_alt_checksum_func_load_mirror_excluded_tags(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_OBSIDIAN_VAULT_MODE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_OBSIDIAN_VAULT_MODE
+uint16_t uniffi_alfred_alt_checksum_func_load_obsidian_vault_mode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_RANKING_SCRIPT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_RANKING_SCRIPT
+uint16_t uniffi_alfred_alt_checksum_func_load_ranking_script(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_SEARCH_MATCH_ANY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_SEARCH_MATCH_ANY
+uint16_t uniffi_alfred_alt_checksum_func_load_search_match_any(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_TRACKER_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOAD_TRACKER_ACCOUNTS
+uint16_t uniffi_alfred_alt_checksum_func_load_tracker_accounts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOCK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_LOCK
+uint16_t uniffi_alfred_alt_checksum_func_lock(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_MARK_ITEM_OPENED
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_MARK_ITEM_OPENED
+uint16_t uniffi_alfred_alt_checksum_func_mark_item_opened(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_MERGE_NOTE_EDITS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_MERGE_NOTE_EDITS
+uint16_t uniffi_alfred_alt_checksum_func_merge_note_edits(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_MOVE_BOARD_CARD
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_MOVE_BOARD_CARD
+uint16_t uniffi_alfred_alt_checksum_func_move_board_card(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_MOVE_NOTE_IMAGE_TO_LINE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_MOVE_NOTE_IMAGE_TO_LINE
+uint16_t uniffi_alfred_alt_checksum_func_move_note_image_to_line(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_HASHTAGS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_HASHTAGS
+uint16_t uniffi_alfred_alt_checksum_func_note_hashtags(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_OUTLINE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_OUTLINE
+uint16_t uniffi_alfred_alt_checksum_func_note_outline(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_PREVIEW_BLOCKS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_PREVIEW_BLOCKS
+uint16_t uniffi_alfred_alt_checksum_func_note_preview_blocks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_TABLE_LAYOUTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_TABLE_LAYOUTS
+uint16_t uniffi_alfred_alt_checksum_func_note_table_layouts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_TITLE_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_TITLE_DEEP_LINK
+uint16_t uniffi_alfred_alt_checksum_func_note_title_deep_link(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_TITLE_FOR_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_NOTE_TITLE_FOR_DEEP_LINK
+uint16_t uniffi_alfred_alt_checksum_func_note_title_for_deep_link(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_OPEN_JOURNAL_ENTRY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_OPEN_JOURNAL_ENTRY
+uint16_t uniffi_alfred_alt_checksum_func_open_journal_entry(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PARSE_PREVIEW_STYLE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PARSE_PREVIEW_STYLE
+uint16_t uniffi_alfred_alt_checksum_func_parse_preview_style(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PENDING_NOTE_LINK_QUERY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PENDING_NOTE_LINK_QUERY
+uint16_t uniffi_alfred_alt_checksum_func_pending_note_link_query(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PERFORM_PROVIDER_ACTION
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PERFORM_PROVIDER_ACTION
+uint16_t uniffi_alfred_alt_checksum_func_perform_provider_action(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PERMANENTLY_DELETE_DELETED_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PERMANENTLY_DELETE_DELETED_ITEM
 uint16_t uniffi_alfred_alt_checksum_func_permanently_delete_deleted_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PLAN_IMAGE_FOLDER_IMPORT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_PLAN_IMAGE_FOLDER_IMPORT
+uint16_t uniffi_alfred_alt_checksum_func_plan_image_folder_import(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RECORD_SEARCH
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RECORD_SEARCH
+uint16_t uniffi_alfred_alt_checksum_func_record_search(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_REMOVE_LOCK_KEY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_REMOVE_LOCK_KEY
+uint16_t uniffi_alfred_alt_checksum_func_remove_lock_key(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RENAME_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RENAME_ITEM
 uint16_t uniffi_alfred_alt_checksum_func_rename_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_REPLACE_STORED_IMAGES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_REPLACE_STORED_IMAGES
+uint16_t uniffi_alfred_alt_checksum_func_replace_stored_images(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RESTORE_DELETED_ITEM
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RESTORE_DELETED_ITEM
 uint16_t uniffi_alfred_alt_checksum_func_restore_deleted_item(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RETRY_READ_LATER_QUEUE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RETRY_READ_LATER_QUEUE
+uint16_t uniffi_alfred_alt_checksum_func_retry_read_later_queue(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RUN_SYSTEM_COMMAND
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RUN_SYSTEM_COMMAND
+uint16_t uniffi_alfred_alt_checksum_func_run_system_command(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RUNNING_TIMER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_RUNNING_TIMER
+uint16_t uniffi_alfred_alt_checksum_func_running_timer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_FORGE_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_FORGE_ACCOUNTS
+uint16_t uniffi_alfred_alt_checksum_func_save_forge_accounts(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_HOTKEY
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_HOTKEY
 uint16_t uniffi_alfred_alt_checksum_func_save_hotkey(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_IMAGE_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_IMAGE_LIMITS
+uint16_t uniffi_alfred_alt_checksum_func_save_image_limits(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_IMAGE_TEXTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_IMAGE_TEXTS
+uint16_t uniffi_alfred_alt_checksum_func_save_image_texts(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_ITEM
@@ -714,12 +1712,174 @@ uint16_t uniffi_alfred_alt_checksum_func_save_item(void
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_JSON_STORAGE_PATH
 uint16_t uniffi_alfred_alt_checksum_func_save_json_storage_path(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_LAUNCHER_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_LAUNCHER_ONLY
+uint16_t uniffi_alfred_alt_checksum_func_save_launcher_only(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_MIRROR_EXCLUDED_TAGS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_MIRROR_EXCLUDED_TAGS
+uint16_t uniffi_alfred_alt_checksum_func_save_mirror_excluded_tags(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_OBSIDIAN_VAULT_MODE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_OBSIDIAN_VAULT_MODE
+uint16_t uniffi_alfred_alt_checksum_func_save_obsidian_vault_mode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_RANKING_SCRIPT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_RANKING_SCRIPT
+uint16_t uniffi_alfred_alt_checksum_func_save_ranking_script(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_READ_LATER_ARTICLE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_READ_LATER_ARTICLE
+uint16_t uniffi_alfred_alt_checksum_func_save_read_later_article(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_SCHEDULE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_SCHEDULE
+uint16_t uniffi_alfred_alt_checksum_func_save_schedule(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_SEARCH_MATCH_ANY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_SEARCH_MATCH_ANY
+uint16_t uniffi_alfred_alt_checksum_func_save_search_match_any(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_STORAGE_BUDGET
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_STORAGE_BUDGET
+uint16_t uniffi_alfred_alt_checksum_func_save_storage_budget(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_TRACKER_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_TRACKER_ACCOUNTS
+uint16_t uniffi_alfred_alt_checksum_func_save_tracker_accounts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_TRIGGER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SAVE_TRIGGER
+uint16_t uniffi_alfred_alt_checksum_func_save_trigger(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SEARCH_ITEMS
 #define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SEARCH_ITEMS
 uint16_t uniffi_alfred_alt_checksum_func_search_items(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_ACTIVITY_ORIGIN
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_ACTIVITY_ORIGIN
+uint16_t uniffi_alfred_alt_checksum_func_set_activity_origin(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_ITEM_PINNED
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_ITEM_PINNED
+uint16_t uniffi_alfred_alt_checksum_func_set_item_pinned(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_ITEM_SECURE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_ITEM_SECURE
+uint16_t uniffi_alfred_alt_checksum_func_set_item_secure(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_LOCK_KEY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_LOCK_KEY
+uint16_t uniffi_alfred_alt_checksum_func_set_lock_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_POWER_SAVING
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SET_POWER_SAVING
+uint16_t uniffi_alfred_alt_checksum_func_set_power_saving(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_START_TIMER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_START_TIMER
+uint16_t uniffi_alfred_alt_checksum_func_start_timer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_STOP_TIMER
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_STOP_TIMER
+uint16_t uniffi_alfred_alt_checksum_func_stop_timer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SUGGEST_NOTE_LINK_TITLES
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_SUGGEST_NOTE_LINK_TITLES
+uint16_t uniffi_alfred_alt_checksum_func_suggest_note_link_titles(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TAG_ITEMS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TAG_ITEMS
+uint16_t uniffi_alfred_alt_checksum_func_tag_items(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TAG_SEARCH_QUERY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TAG_SEARCH_QUERY
+uint16_t uniffi_alfred_alt_checksum_func_tag_search_query(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TOGGLE_CHECKLIST_ENTRY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TOGGLE_CHECKLIST_ENTRY
+uint16_t uniffi_alfred_alt_checksum_func_toggle_checklist_entry(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TRACKED_SECONDS
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TRACKED_SECONDS
+uint16_t uniffi_alfred_alt_checksum_func_tracked_seconds(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TRANSFORM_NOTE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_TRANSFORM_NOTE_TEXT
+uint16_t uniffi_alfred_alt_checksum_func_transform_note_text(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_UNLOCK_WITH_KEY
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_UNLOCK_WITH_KEY
+uint16_t uniffi_alfred_alt_checksum_func_unlock_with_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_WARM_UP_STORE
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_WARM_UP_STORE
+uint16_t uniffi_alfred_alt_checksum_func_warm_up_store(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_WEEKLY_TIME_REPORT
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_WEEKLY_TIME_REPORT
+uint16_t uniffi_alfred_alt_checksum_func_weekly_time_report(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_WRAP_MARKDOWN_SELECTION
+#define UNIFFI_FFIDEF_UNIFFI_ALFRED_ALT_CHECKSUM_FUNC_WRAP_MARKDOWN_SELECTION
+uint16_t uniffi_alfred_alt_checksum_func_wrap_markdown_selection(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_ALFRED_ALT_UNIFFI_CONTRACT_VERSION